@@ -4,7 +4,7 @@ pub use service::ProjectStatusAudit;
 pub use service::VulnerabilityServiceFormat;
 pub use service::osv;
 pub use types::{
-    AdverseStatus, Dependency, Finding, ProjectStatus, Vulnerability, VulnerabilityID,
+    AdverseStatus, Dependency, Finding, ProjectStatus, Severity, Vulnerability, VulnerabilityID,
 };
 
 mod service;
@@ -4,8 +4,12 @@
 //! dependencies in a single round-trip (handling pagination as needed), then
 //! fetch full vulnerability records from `/v1/vulns/{id}` concurrently.
 //!
+//! Alternatively, an [`OsvSnapshot`] matches dependencies against a local copy
+//! of the OSV records, without any network access.
+//!
 //! [OSV]: https://osv.dev/
 
+use std::path::{Path, PathBuf};
 use std::str::FromStr as _;
 use std::sync::LazyLock;
 
@@ -20,6 +24,7 @@ use serde::{Deserialize, Serialize};
 use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_client::{CacheControl, CachedClient, CachedClientError};
 use uv_configuration::Concurrency;
+use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 
@@ -46,6 +51,16 @@ pub enum Error {
         #[source]
         err: reqwest_middleware::Error,
     },
+    /// An error when reading a local OSV snapshot.
+    #[error("Failed to read OSV snapshot at `{}`", .0.display())]
+    Snapshot(PathBuf, #[source] std::io::Error),
+    /// An error when a local OSV snapshot contains an invalid vulnerability record.
+    #[error("OSV snapshot contains a malformed vulnerability record at `{}`", .path.display())]
+    MalformedSnapshotRecord {
+        path: PathBuf,
+        #[source]
+        err: serde_json::Error,
+    },
 }
 
 /// Package specification for OSV queries.
@@ -73,13 +88,13 @@ struct QueryRequest {
 #[serde(rename_all = "snake_case")]
 enum Event {
     /// A version that introduces the vulnerability.
-    Introduced(#[allow(dead_code)] String),
+    Introduced(String),
     /// A version that fixes the vulnerability.
     Fixed(String),
     /// The last known affected version.
-    LastAffected(#[allow(dead_code)] String),
+    LastAffected(String),
     /// An upper limit on the range.
-    Limit(#[allow(dead_code)] String),
+    Limit(String),
 }
 
 /// The type of a version range in an OSV vulnerability record.
@@ -110,10 +125,63 @@ struct Range {
     events: Vec<Event>,
 }
 
+impl Range {
+    /// Returns `true` if the given version is within this range.
+    ///
+    /// Only `ECOSYSTEM` ranges are evaluated, since their events are PEP 440 versions. Per the OSV
+    /// schema, the events are applied in version order, such that the last event at or below the
+    /// given version determines whether it's affected.
+    fn contains(&self, version: &Version) -> bool {
+        if !matches!(self.range_type, RangeType::Ecosystem) {
+            return false;
+        }
+
+        let mut events = self
+            .events
+            .iter()
+            .filter_map(|event| {
+                let (Event::Introduced(raw)
+                | Event::Fixed(raw)
+                | Event::LastAffected(raw)
+                | Event::Limit(raw)) = event;
+                // An introduced version of `0` indicates that all versions are affected.
+                let bound = if raw == "0" {
+                    Version::new([0])
+                } else {
+                    Version::from_str(raw).ok()?
+                };
+                Some((bound, event))
+            })
+            .collect::<Vec<_>>();
+        events.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+        let mut affected = false;
+        for (bound, event) in &events {
+            match event {
+                Event::Introduced(_) if version >= bound => affected = true,
+                Event::Fixed(_) | Event::Limit(_) if version >= bound => affected = false,
+                Event::LastAffected(_) if version > bound => affected = false,
+                _ => {}
+            }
+        }
+        affected
+    }
+}
+
+/// The package to which an [`Affected`] entry applies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AffectedPackage {
+    ecosystem: String,
+    name: String,
+}
+
 /// Package affected by a vulnerability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Affected {
+    package: Option<AffectedPackage>,
     ranges: Option<Vec<Range>>,
+    /// An explicit list of affected versions, in addition to the ranges.
+    versions: Option<Vec<String>>,
     // TODO: Enable these fields if/when they contain information that's
     // useful to us, e.g. metadata that constrains a vulnerability to specific
     // Python runtime versions, specific distributions of a version, etc.
@@ -149,6 +217,56 @@ struct Reference {
     url: DisplaySafeUrl,
 }
 
+impl Affected {
+    /// Returns `true` if this entry applies to the given version of the given PyPI package.
+    fn contains(&self, name: &PackageName, version: &Version) -> bool {
+        let Some(package) = &self.package else {
+            return false;
+        };
+        if package.ecosystem != "PyPI"
+            || PackageName::from_str(&package.name).ok().as_ref() != Some(name)
+        {
+            return false;
+        }
+
+        self.versions
+            .iter()
+            .flatten()
+            .any(|affected| Version::from_str(affected).is_ok_and(|affected| affected == *version))
+            || self
+                .ranges
+                .iter()
+                .flatten()
+                .any(|range| range.contains(version))
+    }
+}
+
+/// The type of a severity score in an OSV vulnerability record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SeverityType {
+    #[serde(rename = "CVSS_V3")]
+    CvssV3,
+    /// Some other score type, e.g., `CVSS_V4`, which we don't evaluate.
+    #[serde(other)]
+    Other,
+}
+
+/// A severity score for a vulnerability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SeverityScore {
+    #[serde(rename = "type")]
+    severity_type: SeverityType,
+    score: String,
+}
+
+/// Database-specific metadata for a vulnerability.
+///
+/// Records from the GitHub Advisory Database include a qualitative `severity`, e.g., `HIGH`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DatabaseSpecific {
+    severity: Option<String>,
+}
+
 /// A full vulnerability record from OSV.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Vulnerability {
@@ -167,6 +285,116 @@ struct Vulnerability {
     affected: Option<Vec<Affected>>,
     aliases: Option<Vec<String>>,
     references: Option<Vec<Reference>>,
+    severity: Option<Vec<SeverityScore>>,
+    database_specific: Option<DatabaseSpecific>,
+}
+
+impl Vulnerability {
+    /// Determine the severity of this vulnerability, preferring the qualitative severity assigned
+    /// by the source database, and falling back to the CVSS v3 base score.
+    fn severity(&self) -> Option<types::Severity> {
+        let qualitative = self
+            .database_specific
+            .as_ref()
+            .and_then(|database_specific| database_specific.severity.as_deref())
+            .and_then(|severity| match severity.to_ascii_uppercase().as_str() {
+                "LOW" => Some(types::Severity::Low),
+                "MODERATE" | "MEDIUM" => Some(types::Severity::Medium),
+                "HIGH" => Some(types::Severity::High),
+                "CRITICAL" => Some(types::Severity::Critical),
+                _ => None,
+            });
+        qualitative.or_else(|| {
+            self.severity
+                .iter()
+                .flatten()
+                .filter(|score| matches!(score.severity_type, SeverityType::CvssV3))
+                .filter_map(|score| cvss3_base_score(&score.score))
+                .filter_map(types::Severity::from_score)
+                .max()
+        })
+    }
+}
+
+/// Compute the base score of a CVSS v3 vector, e.g., `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+///
+/// See: <https://www.first.org/cvss/v3.1/specification-document#7-1-Base-Metrics-Equations>
+fn cvss3_base_score(vector: &str) -> Option<f64> {
+    let mut parts = vector.split('/');
+    if !parts.next()?.starts_with("CVSS:3.") {
+        return None;
+    }
+    let metrics = parts
+        .map(|part| part.split_once(':'))
+        .collect::<Option<FxHashMap<_, _>>>()?;
+    let metric = |name: &str| metrics.get(name).copied();
+
+    let changed = match metric("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let attack_vector = match metric("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let attack_complexity = match metric("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let privileges_required = match (metric("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let user_interaction = match metric("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let impact_metric = |name: &str| match metric(name)? {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    };
+
+    let impact_subscore = 1.0
+        - (1.0 - impact_metric("C")?) * (1.0 - impact_metric("I")?) * (1.0 - impact_metric("A")?);
+    let impact = if changed {
+        7.52 * (impact_subscore - 0.029) - 3.25 * (impact_subscore - 0.02).powi(15)
+    } else {
+        6.42 * impact_subscore
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability =
+        8.22 * attack_vector * attack_complexity * privileges_required * user_interaction;
+    let score = if changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+    Some(round_up(score.min(10.0)))
+}
+
+/// Round up to one decimal place, avoiding floating-point artifacts, per Appendix A of the CVSS
+/// v3.1 specification.
+fn round_up(value: f64) -> f64 {
+    let scaled = (value * 100_000.0).round();
+    if scaled % 10_000.0 == 0.0 {
+        scaled / 100_000.0
+    } else {
+        ((scaled / 10_000.0).floor() + 1.0) / 10.0
+    }
 }
 
 /// Request body for the batch query API.
@@ -474,6 +702,8 @@ impl Osv {
             })
             .collect();
 
+        let severity = vuln.severity();
+
         // Extract aliases
         let aliases = vuln
             .aliases
@@ -493,12 +723,89 @@ impl Osv {
                 aliases,
                 vuln.published,
                 Some(vuln.modified),
+                severity,
             )
             .into(),
         )
     }
 }
 
+/// A local snapshot of OSV vulnerability records, for auditing without network access.
+///
+/// The snapshot is a directory of OSV records in JSON format, e.g., as extracted from the
+/// PyPI archive at <https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip>.
+pub struct OsvSnapshot {
+    /// The vulnerability records, indexed by the names of the packages they affect.
+    records: FxHashMap<PackageName, Vec<Vulnerability>>,
+}
+
+impl OsvSnapshot {
+    /// Read the OSV records in the given directory.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let mut records: FxHashMap<PackageName, Vec<Vulnerability>> = FxHashMap::default();
+        for entry in
+            fs_err::read_dir(path).map_err(|err| Error::Snapshot(path.to_path_buf(), err))?
+        {
+            let entry = entry.map_err(|err| Error::Snapshot(path.to_path_buf(), err))?;
+            let path = entry.path();
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+            let contents = fs_err::read(&path).map_err(|err| Error::Snapshot(path.clone(), err))?;
+            let vuln: Vulnerability = serde_json::from_slice(&contents)
+                .map_err(|err| Error::MalformedSnapshotRecord { path, err })?;
+
+            let names = vuln
+                .affected
+                .iter()
+                .flatten()
+                .filter_map(|affected| affected.package.as_ref())
+                .filter(|package| package.ecosystem == "PyPI")
+                .filter_map(|package| PackageName::from_str(&package.name).ok())
+                .collect::<FxHashSet<_>>();
+            for name in names {
+                records.entry(name).or_default().push(vuln.clone());
+            }
+        }
+
+        // Sort the records by ID, such that findings are reported in a stable order.
+        for vulns in records.values_mut() {
+            vulns.sort_by(|first, second| first.id.cmp(&second.id));
+        }
+
+        trace!(
+            "Read OSV snapshot with records for {} packages from `{}`",
+            records.len(),
+            path.display()
+        );
+        Ok(Self { records })
+    }
+
+    /// Match the given dependencies against the snapshot, returning full vulnerability records.
+    pub fn query_batch(
+        &self,
+        dependencies: &[types::Dependency],
+        filter: Filter,
+    ) -> Vec<types::Finding> {
+        dependencies
+            .iter()
+            .flat_map(|dependency| {
+                self.records
+                    .get(dependency.name())
+                    .into_iter()
+                    .flatten()
+                    .filter(|vuln| filter.matches(&vuln.id))
+                    .filter(|vuln| {
+                        vuln.affected.iter().flatten().any(|affected| {
+                            affected.contains(dependency.name(), dependency.version())
+                        })
+                    })
+                    .map(|vuln| Osv::vulnerability_to_finding(dependency, vuln.clone()))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -514,10 +821,9 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     use crate::service::osv::{Filter, RangeType};
-    use crate::types::{Dependency, Finding};
+    use crate::types::{Dependency, Finding, Severity};
 
-    use super::Event;
-    use super::Osv;
+    use super::{Event, Osv, OsvSnapshot, Range, Vulnerability};
 
     /// Create a [`CachedClient`] suitable for tests (no retries, no cache).
     fn test_client() -> CachedClient {
@@ -569,6 +875,161 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn test_range_contains() {
+        let range: Range = serde_json::from_value(json!({
+            "type": "ECOSYSTEM",
+            "events": [
+                { "introduced": "0" },
+                { "fixed": "1.2.0" },
+                { "introduced": "2.0.0" },
+                { "last_affected": "2.1.0" },
+            ]
+        }))
+        .unwrap();
+
+        let contains = |version: &str| range.contains(&Version::from_str(version).unwrap());
+        assert!(contains("1.0.0"));
+        assert!(contains("1.1.9"));
+        assert!(!contains("1.2.0"));
+        assert!(!contains("1.5.0"));
+        assert!(contains("2.0.0"));
+        assert!(contains("2.1.0"));
+        assert!(!contains("2.1.1"));
+    }
+
+    #[test]
+    fn test_severity() {
+        let severity = |record: serde_json::Value| {
+            serde_json::from_value::<Vulnerability>(record)
+                .unwrap()
+                .severity()
+        };
+
+        // The qualitative severity takes precedence over the CVSS score.
+        assert_eq!(
+            severity(json!({
+                "id": "GHSA-xxxx-yyyy",
+                "modified": "2026-01-01T00:00:00Z",
+                "severity": [{
+                    "type": "CVSS_V3",
+                    "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                }],
+                "database_specific": { "severity": "MODERATE" },
+            })),
+            Some(Severity::Medium)
+        );
+
+        // A base score of 9.8.
+        assert_eq!(
+            severity(json!({
+                "id": "PYSEC-2023-0001",
+                "modified": "2026-01-01T00:00:00Z",
+                "severity": [{
+                    "type": "CVSS_V3",
+                    "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                }],
+            })),
+            Some(Severity::Critical)
+        );
+
+        // A base score of 6.1.
+        assert_eq!(
+            severity(json!({
+                "id": "PYSEC-2023-0002",
+                "modified": "2026-01-01T00:00:00Z",
+                "severity": [{
+                    "type": "CVSS_V3",
+                    "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N",
+                }],
+            })),
+            Some(Severity::Medium)
+        );
+
+        // No severity information.
+        assert_eq!(
+            severity(json!({
+                "id": "PYSEC-2023-0003",
+                "modified": "2026-01-01T00:00:00Z",
+            })),
+            None
+        );
+    }
+
+    /// Ensure that an `OsvSnapshot` only reports records whose affected ranges or versions
+    /// include the dependency.
+    #[test]
+    fn test_snapshot_query_batch() {
+        let snapshot = tempfile::tempdir().unwrap();
+        fs_err::write(
+            snapshot.path().join("PYSEC-2023-0001.json"),
+            json!({
+                "id": "PYSEC-2023-0001",
+                "modified": "2026-01-01T00:00:00Z",
+                "affected": [{
+                    "package": { "ecosystem": "PyPI", "name": "Package_A" },
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [{ "introduced": "0" }, { "fixed": "1.1.0" }]
+                    }]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        fs_err::write(
+            snapshot.path().join("PYSEC-2023-0002.json"),
+            json!({
+                "id": "PYSEC-2023-0002",
+                "modified": "2026-01-01T00:00:00Z",
+                "affected": [{
+                    "package": { "ecosystem": "PyPI", "name": "package-b" },
+                    "versions": ["2.0.0"]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let snapshot = OsvSnapshot::read(snapshot.path()).unwrap();
+
+        let dependencies = vec![
+            Dependency::new(
+                PackageName::from_str("package-a").unwrap(),
+                Version::from_str("1.0.0").unwrap(),
+            ),
+            Dependency::new(
+                PackageName::from_str("package-a").unwrap(),
+                Version::from_str("1.1.0").unwrap(),
+            ),
+            Dependency::new(
+                PackageName::from_str("package-b").unwrap(),
+                Version::from_str("2.0.0").unwrap(),
+            ),
+        ];
+
+        let findings = snapshot
+            .query_batch(&dependencies, Filter::All)
+            .into_iter()
+            .map(|finding| match finding {
+                Finding::Vulnerability(vulnerability) => format!(
+                    "{} {}: {}",
+                    vulnerability.dependency.name(),
+                    vulnerability.dependency.version(),
+                    vulnerability.id.as_str()
+                ),
+                Finding::ProjectStatus(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            findings,
+            [
+                "package-a 1.0.0: PYSEC-2023-0001",
+                "package-b 2.0.0: PYSEC-2023-0002",
+            ]
+        );
+    }
+
     /// Ensure that `query_identifiers` returns the correct vulnerability ID mapping.
     #[tokio::test]
     async fn test_query_identifiers() {
@@ -760,6 +1221,7 @@ mod tests {
                     modified: Some(
                         2026-01-01T00:00:00Z,
                     ),
+                    severity: None,
                 },
             ),
             Vulnerability(
@@ -798,6 +1260,7 @@ mod tests {
                     modified: Some(
                         2026-01-02T00:00:00Z,
                     ),
+                    severity: None,
                 },
             ),
         ]
@@ -78,6 +78,48 @@ impl std::fmt::Display for AdverseStatus {
     }
 }
 
+/// The severity of a vulnerability, in increasing order.
+///
+/// These correspond to the qualitative ratings of the [CVSS v3.1] specification, which are also
+/// used by the GitHub Advisory Database.
+///
+/// [CVSS v3.1]: https://www.first.org/cvss/v3.1/specification-document#Qualitative-Severity-Rating-Scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// Determine the severity from a CVSS base score, if the score indicates any severity at all.
+    pub fn from_score(score: f64) -> Option<Self> {
+        if score >= 9.0 {
+            Some(Self::Critical)
+        } else if score >= 7.0 {
+            Some(Self::High)
+        } else if score >= 4.0 {
+            Some(Self::Medium)
+        } else if score > 0.0 {
+            Some(Self::Low)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        })
+    }
+}
+
 /// A vulnerability within a dependency.
 #[derive(Debug)]
 pub struct Vulnerability {
@@ -99,6 +141,8 @@ pub struct Vulnerability {
     pub published: Option<Timestamp>,
     /// The timestamp when this vulnerability was last modified, if available.
     pub modified: Option<Timestamp>,
+    /// The severity of this vulnerability, if known.
+    pub severity: Option<Severity>,
 }
 
 impl Vulnerability {
@@ -112,6 +156,7 @@ impl Vulnerability {
         aliases: Vec<VulnerabilityID>,
         published: Option<Timestamp>,
         modified: Option<Timestamp>,
+        severity: Option<Severity>,
     ) -> Self {
         // Vulnerability summaries often contain excess whitespace, as well as newlines.
         // We normalize these out.
@@ -127,6 +172,7 @@ impl Vulnerability {
             aliases,
             published,
             modified,
+            severity,
         }
     }
 
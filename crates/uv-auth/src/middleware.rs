@@ -189,6 +189,9 @@ pub struct AuthMiddleware {
     keyring: Option<KeyringProvider>,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     cache: Arc<CredentialsCache>,
+    /// Whether to cache credentials discovered by the middleware (e.g., from the netrc file or
+    /// the keyring) for use in subsequent requests.
+    cache_credentials: bool,
     /// Auth policies for specific URLs.
     indexes: Indexes,
     /// Set all endpoints as needing authentication. We never try to send an
@@ -223,6 +226,7 @@ impl AuthMiddleware {
             keyring: None,
             // TODO(konsti): There shouldn't be a credential cache without that in the initializer.
             cache: Arc::new(CredentialsCache::default()),
+            cache_credentials: true,
             indexes: Indexes::new(),
             only_authenticated: false,
             base_client: None,
@@ -291,6 +295,17 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure whether credentials discovered by the middleware are cached.
+    ///
+    /// When disabled, credentials are re-fetched from their source (e.g., the netrc file or the
+    /// keyring) for every request, rather than reused from a previous request. Credentials that
+    /// were explicitly seeded into the [`CredentialsCache`] are still used.
+    #[must_use]
+    pub fn with_cache_credentials(mut self, cache_credentials: bool) -> Self {
+        self.cache_credentials = cache_credentials;
+        self
+    }
+
     /// Configure the [`AuthPolicy`]s to use for URLs.
     #[must_use]
     pub fn with_indexes(mut self, indexes: Indexes) -> Self {
@@ -596,9 +611,10 @@ impl AuthMiddleware {
         let result = next.run(request, extensions).await;
 
         // Update the cache with new credentials on a successful request
-        if result
-            .as_ref()
-            .is_ok_and(|response| response.error_for_status_ref().is_ok())
+        if self.cache_credentials
+            && result
+                .as_ref()
+                .is_ok_and(|response| response.error_for_status_ref().is_ok())
         {
             // TODO(zanieb): Consider also updating the system keyring after successful use
             trace!("Updating cached credentials for {url} to {credentials:?}");
@@ -713,7 +729,9 @@ impl AuthMiddleware {
         } else {
             (FetchUrl::Realm(Realm::from(&**url)), username)
         };
-        if let Some(credentials) = self.cache().fetches.register_or_wait(&key).await {
+        if self.cache_credentials
+            && let Some(credentials) = self.cache().fetches.register_or_wait(&key).await
+        {
             if credentials.is_some() {
                 trace!("Using credentials from previous fetch for {}", key.0);
             } else {
@@ -732,7 +750,7 @@ impl AuthMiddleware {
             .map(Arc::new)
         {
             debug!("Found Hugging Face credentials for {url}");
-            self.cache().fetches.done(key, Some(credentials.clone()));
+            self.fetched(key, Some(credentials.clone()));
             return Some(credentials);
         }
 
@@ -753,7 +771,7 @@ impl AuthMiddleware {
 
             if let Some(credentials) = credentials {
                 debug!("Found S3 credentials for {url}");
-                self.cache().fetches.done(key, Some(credentials.clone()));
+                self.fetched(key, Some(credentials.clone()));
                 return Some(credentials);
             }
         }
@@ -775,7 +793,7 @@ impl AuthMiddleware {
 
             if let Some(credentials) = credentials {
                 debug!("Found GCS credentials for {url}");
-                self.cache().fetches.done(key, Some(credentials.clone()));
+                self.fetched(key, Some(credentials.clone()));
                 return Some(credentials);
             }
         }
@@ -797,7 +815,7 @@ impl AuthMiddleware {
 
             if let Some(credentials) = credentials {
                 debug!("Found Azure credentials for {url}");
-                self.cache().fetches.done(key, Some(credentials.clone()));
+                self.fetched(key, Some(credentials.clone()));
                 return Some(credentials);
             }
         }
@@ -957,10 +975,18 @@ impl AuthMiddleware {
         let credentials = credentials.map(Authentication::from).map(Arc::new);
 
         // Register the fetch for this key
-        self.cache().fetches.done(key, credentials.clone());
+        self.fetched(key, credentials.clone());
 
         credentials
     }
+
+    /// Record the result of a credential fetch, such that other requests for the same key can
+    /// reuse it, unless credential caching is disabled.
+    fn fetched(&self, key: (FetchUrl, Username), credentials: Option<Arc<Authentication>>) {
+        if self.cache_credentials {
+            self.cache().fetches.done(key, credentials);
+        }
+    }
 }
 
 fn tracing_url(request: &Request, credentials: Option<&Authentication>) -> DisplaySafeUrl {
@@ -1096,6 +1122,43 @@ mod tests {
         Ok(())
     }
 
+    /// With credential caching disabled, authenticated requests are not reused
+    #[test(tokio::test)]
+    async fn test_credentials_in_url_no_cache() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_cache_credentials(false),
+            )
+            .build();
+
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some(password)).unwrap();
+        assert_eq!(client.get(url.clone()).send().await?.status(), 200);
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            401,
+            "Subsequent requests should not reuse the credentials"
+        );
+
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            200,
+            "Requests with credentials should still succeed"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_credentials_in_url_seed() -> Result<(), Error> {
         let username = "user";
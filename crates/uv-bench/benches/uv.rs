@@ -217,6 +217,7 @@ fn install_wheel_many_files(c: &mut Criterion<WallTime>) {
                     None::<&()>,
                     Some("uv"),
                     true,
+                    true,
                     LinkMode::default(),
                     &state,
                 )
@@ -6,7 +6,7 @@
 use std::error::Error as _;
 use std::fmt;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
 use std::task::{Context, Poll};
@@ -16,8 +16,9 @@ use futures::{StreamExt, TryStreamExt};
 use reqwest_retry::Retryable;
 use reqwest_retry::policies::ExponentialBackoff;
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use thiserror::Error;
-use tokio::io::{AsyncRead, ReadBuf};
+use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use url::Url;
 use uv_client::retryable_on_request_failure;
@@ -325,6 +326,9 @@ struct BinArtifact {
     platform: String,
     url: String,
     archive_format: String,
+    /// The SHA-256 digest of the archive, if published.
+    #[serde(default)]
+    sha256: Option<String>,
 }
 
 /// A resolved version with its artifact information.
@@ -336,6 +340,8 @@ pub struct ResolvedVersion {
     artifact_urls: Vec<DisplaySafeUrl>,
     /// The archive format.
     archive_format: ArchiveFormat,
+    /// The expected SHA-256 digest of the archive, if known.
+    sha256: Option<String>,
 }
 
 impl ResolvedVersion {
@@ -354,8 +360,19 @@ impl ResolvedVersion {
             version,
             artifact_urls,
             archive_format,
+            sha256: None,
         })
     }
+
+    /// The ordered list of download URLs for the archive.
+    pub fn artifact_urls(&self) -> &[DisplaySafeUrl] {
+        &self.artifact_urls
+    }
+
+    /// The expected SHA-256 digest of the archive, as published in the versions manifest.
+    pub fn sha256(&self) -> Option<&str> {
+        self.sha256.as_deref()
+    }
 }
 
 /// Errors that can occur during binary download and installation.
@@ -445,6 +462,18 @@ pub enum Error {
     #[error("Unsupported archive format: {0}")]
     UnsupportedArchiveFormat(String),
 
+    #[error("No checksum was published for {binary} {version}")]
+    MissingChecksum { binary: Binary, version: Version },
+
+    #[error(
+        "Hash mismatch for `{url}`\n\nExpected:\n  sha256:{expected}\n\nComputed:\n  sha256:{actual}"
+    )]
+    HashMismatch {
+        url: DisplaySafeUrl,
+        expected: String,
+        actual: String,
+    },
+
     #[error(transparent)]
     SystemTime(#[from] SystemTimeError),
 }
@@ -515,6 +544,44 @@ pub async fn find_matching_version(
     exclude_newer: Option<jiff::Timestamp>,
     client: &BaseClient,
     retry_policy: &ExponentialBackoff,
+) -> Result<ResolvedVersion, Error> {
+    find_version(
+        binary,
+        constraints,
+        exclude_newer,
+        true,
+        client,
+        retry_policy,
+    )
+    .await
+}
+
+/// Like [`find_matching_version`], but skips pre-release and development versions.
+pub async fn find_matching_stable_version(
+    binary: Binary,
+    constraints: Option<&uv_pep440::VersionSpecifiers>,
+    exclude_newer: Option<jiff::Timestamp>,
+    client: &BaseClient,
+    retry_policy: &ExponentialBackoff,
+) -> Result<ResolvedVersion, Error> {
+    find_version(
+        binary,
+        constraints,
+        exclude_newer,
+        false,
+        client,
+        retry_policy,
+    )
+    .await
+}
+
+async fn find_version(
+    binary: Binary,
+    constraints: Option<&uv_pep440::VersionSpecifiers>,
+    exclude_newer: Option<jiff::Timestamp>,
+    prereleases: bool,
+    client: &BaseClient,
+    retry_policy: &ExponentialBackoff,
 ) -> Result<ResolvedVersion, Error> {
     let platform = Platform::from_env()?;
     let platform_name = platform.as_cargo_dist_triple();
@@ -530,6 +597,7 @@ pub async fn find_matching_version(
                 binary,
                 constraints,
                 exclude_newer,
+                prereleases,
                 &platform_name,
                 url,
                 client,
@@ -547,6 +615,7 @@ async fn fetch_and_find_matching_version(
     binary: Binary,
     constraints: Option<&uv_pep440::VersionSpecifiers>,
     exclude_newer: Option<jiff::Timestamp>,
+    prereleases: bool,
     platform_name: &str,
     manifest_url: DisplaySafeUrl,
     client: &BaseClient,
@@ -580,6 +649,7 @@ async fn fetch_and_find_matching_version(
             &version_info,
             constraints,
             exclude_newer,
+            prereleases,
             platform_name,
         )
     };
@@ -635,6 +705,7 @@ fn check_version_match(
     version_info: &BinVersionInfo,
     constraints: Option<&uv_pep440::VersionSpecifiers>,
     exclude_newer: Option<jiff::Timestamp>,
+    prereleases: bool,
     platform_name: &str,
 ) -> Result<Option<ResolvedVersion>, Error> {
     // Skip versions newer than the exclude_newer cutoff
@@ -644,6 +715,11 @@ fn check_version_match(
         return Ok(None);
     }
 
+    // Skip pre-releases, unless requested
+    if !prereleases && !version_info.version.is_stable() {
+        return Ok(None);
+    }
+
     // Skip versions that don't match the constraints
     if let Some(constraints) = constraints
         && !constraints.contains(&version_info.version)
@@ -672,12 +748,115 @@ fn check_version_match(
             version: version_info.version.clone(),
             artifact_urls: binary.mirror_urls(canonical_url)?,
             archive_format,
+            sha256: artifact.sha256.clone(),
         }));
     }
 
     Ok(None)
 }
 
+/// Download the archive for a [`ResolvedVersion`], verify it against the SHA-256 digest
+/// published in the versions manifest, and unpack it into `target`.
+///
+/// The archive is downloaded once, to a temporary file, and only unpacked once it has been
+/// verified, such that the unpacked files are exactly those that were verified.
+///
+/// Returns the directory that contains the unpacked executables, or an error if the manifest did
+/// not publish a digest for the archive.
+pub async fn download_verified_artifact(
+    binary: Binary,
+    resolved: &ResolvedVersion,
+    client: &BaseClient,
+    retry_policy: &ExponentialBackoff,
+    target: &Path,
+) -> Result<PathBuf, Error> {
+    let Some(expected) = resolved.sha256.as_deref() else {
+        return Err(Error::MissingChecksum {
+            binary,
+            version: resolved.version.clone(),
+        });
+    };
+
+    let archive = tempfile::NamedTempFile::new_in(target)?.into_temp_path();
+    let download_url = fetch_with_url_fallback(
+        &resolved.artifact_urls,
+        *retry_policy,
+        &format!("`{binary}`"),
+        |url| download_and_verify(client, url, expected, &archive),
+    )
+    .await?;
+
+    let unpacked = target.join("unpacked");
+    let reader = fs_err::tokio::File::open(&archive).await?;
+    stream::archive(
+        &download_url,
+        reader,
+        resolved.archive_format.into(),
+        &unpacked,
+    )
+    .await
+    .map_err(|err| Error::Extract { source: err })?;
+
+    match resolved.archive_format {
+        // Windows ZIP archives contain the executables directly in the root.
+        ArchiveFormat::Zip => Ok(unpacked),
+        // tar.gz archives contain the executables in a subdirectory.
+        ArchiveFormat::TarGz => {
+            uv_extract::strip_component(&unpacked).map_err(|err| Error::Extract { source: err })
+        }
+    }
+}
+
+/// Download an archive from a single URL to the given path, computing its SHA-256 digest as it
+/// streams.
+///
+/// Returns the URL the archive was downloaded from.
+async fn download_and_verify(
+    client: &BaseClient,
+    download_url: DisplaySafeUrl,
+    expected: &str,
+    path: &Path,
+) -> Result<DisplaySafeUrl, Error> {
+    let response = client
+        .for_host(&download_url)
+        .get(Url::from(download_url.clone()))
+        .send()
+        .await
+        .map_err(|err| Error::Download {
+            url: download_url.clone(),
+            source: err,
+        })?;
+
+    let response = response.error_for_status().map_err(|err| Error::Download {
+        url: download_url.clone(),
+        source: reqwest_middleware::Error::Reqwest(err),
+    })?;
+
+    let mut file = fs_err::tokio::File::create(path).await?;
+    let mut hasher = Sha256::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| Error::Stream {
+            url: download_url.clone(),
+            source: err,
+        })?;
+        hasher.update(&chunk);
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+
+    let actual = format!("{:x}", hasher.finalize());
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(Error::HashMismatch {
+            url: download_url,
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+
+    Ok(download_url)
+}
+
 /// Install the given binary from a [`ResolvedVersion`].
 pub async fn bin_install(
     binary: Binary,
@@ -926,6 +1105,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use flate2::write::GzEncoder;
     use serde_json::json;
     use std::io::Write;
     use uv_client::{BaseClientBuilder, fetch_with_url_fallback, retryable_on_request_failure};
@@ -992,6 +1172,7 @@ mod tests {
                 Binary::Uv,
                 constraints,
                 None,
+                true,
                 &platform_name,
                 url,
                 &client,
@@ -1325,6 +1506,121 @@ mod tests {
         assert_eq!(canonical_server.received_requests().await.unwrap().len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_manifest_skips_prereleases_for_stable() {
+        let platform = Platform::from_env().unwrap();
+        let platform_name = platform.as_cargo_dist_triple();
+        let manifest = format!(
+            "{}{}",
+            uv_manifest_line("1.3.0rc1", &platform_name),
+            uv_manifest_line("1.2.3", &platform_name)
+        );
+        let (url, _server) = spawn_manifest_server(manifest_response(&manifest)).await;
+        let client = BaseClientBuilder::default()
+            .retries(0)
+            .build()
+            .expect("failed to build base client");
+
+        let resolved = fetch_and_find_matching_version(
+            Binary::Uv,
+            None,
+            None,
+            false,
+            &platform_name,
+            url.clone(),
+            &client,
+        )
+        .await
+        .unwrap();
+        assert_eq!(resolved.version, Version::new([1, 2, 3]));
+
+        let resolved = fetch_and_find_matching_version(
+            Binary::Uv,
+            None,
+            None,
+            true,
+            &platform_name,
+            url,
+            &client,
+        )
+        .await
+        .unwrap();
+        assert_eq!(resolved.version, Version::from_str("1.3.0rc1").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_download_verified_artifact() {
+        let mut tar = tokio_tar::Builder::new(Vec::new());
+        let mut header = tokio_tar::Header::new_gnu();
+        header.set_size(2);
+        header.set_mode(0o755);
+        header.set_cksum();
+        tar.append_data(&mut header, "uv-x86_64-unknown-linux-gnu/uv", &b"uv"[..])
+            .await
+            .unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&tar.into_inner().await.unwrap()).unwrap();
+        let archive = encoder.finish().unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/uv.tar.gz"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(archive.clone()))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let url = DisplaySafeUrl::parse(&format!("{}/uv.tar.gz", server.uri())).unwrap();
+        let client_builder = BaseClientBuilder::default().retries(0);
+        let retry_policy = client_builder.retry_policy();
+        let client = client_builder.build().expect("failed to build base client");
+
+        let resolved = |sha256: Option<&str>| ResolvedVersion {
+            version: Version::new([1, 2, 3]),
+            artifact_urls: vec![url.clone()],
+            archive_format: ArchiveFormat::TarGz,
+            sha256: sha256.map(ToString::to_string),
+        };
+
+        let target = tempfile::tempdir().unwrap();
+        let mismatched = "0".repeat(64);
+        let err = download_verified_artifact(
+            Binary::Uv,
+            &resolved(Some(&mismatched)),
+            &client,
+            &retry_policy,
+            target.path(),
+        )
+        .await
+        .expect_err("a mismatched digest should be rejected");
+        assert!(matches!(err, Error::HashMismatch { .. }));
+        assert!(!target.path().join("unpacked").exists());
+
+        let target = tempfile::tempdir().unwrap();
+        let expected = format!("{:x}", Sha256::digest(&archive));
+        let unpacked = download_verified_artifact(
+            Binary::Uv,
+            &resolved(Some(&expected)),
+            &client,
+            &retry_policy,
+            target.path(),
+        )
+        .await
+        .expect("a matching digest should be accepted");
+        assert_eq!(fs_err::read(unpacked.join("uv")).unwrap(), b"uv");
+
+        let target = tempfile::tempdir().unwrap();
+        let err = download_verified_artifact(
+            Binary::Uv,
+            &resolved(None),
+            &client,
+            &retry_policy,
+            target.path(),
+        )
+        .await
+        .expect_err("a missing digest should be rejected");
+        assert!(matches!(err, Error::MissingChecksum { .. }));
+    }
+
     /// Verify that `should_try_next_url` returns `true` even for streaming errors
     /// that `retryable_on_request_failure` does not recognise as transient.
     ///
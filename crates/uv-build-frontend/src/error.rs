@@ -4,6 +4,7 @@ use std::io;
 use std::path::PathBuf;
 use std::process::ExitStatus;
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use crate::PythonRunnerOutput;
 use owo_colors::OwoColorize;
@@ -84,6 +85,10 @@ pub enum Error {
     BuildBackend(#[from] BuildBackendError),
     #[error("The build backend returned an error")]
     MissingHeader(#[from] Box<MissingHeaderError>),
+    #[error("{0}: the build backend did not complete within {}s", _1.as_secs())]
+    BuildBackendTimeout(String, Duration),
+    #[error("{0}: the build sandbox blocked {1}")]
+    SandboxDenied(String, SandboxResource, Option<PackageName>),
     #[error("Failed to build PATH for build script")]
     BuildScriptPath(#[source] env::JoinPathsError),
     // For the convenience of typing `setup_build` properly.
@@ -118,6 +123,8 @@ impl IsBuildBackendError for Error {
             Self::CommandFailed(_, _)
             | Self::BuildBackend(_)
             | Self::MissingHeader(_)
+            | Self::BuildBackendTimeout(_, _)
+            | Self::SandboxDenied(_, _, _)
             | Self::BuildScriptPath(_) => true,
         }
     }
@@ -130,6 +137,11 @@ impl Hint for Error {
                 "Build failures usually indicate a problem with the package or the build environment",
             ),
             Self::MissingHeader(err) => Hints::from(err.cause.to_string()),
+            Self::SandboxDenied(_, _, Some(name)) => Hints::from(format!(
+                "If `{}` requires this access to build, add it to `{}`",
+                name.cyan(),
+                "build-sandbox-exempt".green()
+            )),
             Self::Lowering(err) => err.hints(),
             Self::RequirementsResolve(_, err) | Self::RequirementsInstall(_, err) => err.hints(),
             _ => Hints::none(),
@@ -137,6 +149,24 @@ impl Hint for Error {
     }
 }
 
+/// A resource that the build sandbox denied to a build backend.
+#[derive(Debug, Clone)]
+pub enum SandboxResource {
+    /// Access to the network.
+    Network,
+    /// Access to a path outside of the sandbox.
+    Path(PathBuf),
+}
+
+impl Display for SandboxResource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network => write!(f, "network access"),
+            Self::Path(path) => write!(f, "access to `{}`", path.simplified_display()),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum MissingLibrary {
     Header(String),
@@ -379,6 +409,14 @@ impl Error {
         version: Option<&Version>,
         version_id: Option<&str>,
     ) -> Self {
+        if let Some(timeout) = output.timed_out {
+            return Self::BuildBackendTimeout(message, timeout);
+        }
+
+        if let Some(resource) = &output.sandbox_denial {
+            return Self::SandboxDenied(message, resource.clone(), name.cloned());
+        }
+
         // In the cases I've seen it was the 5th and 3rd last line (see test case), 10 seems like a reasonable cutoff.
         let missing_library = output.stderr.iter().rev().take(10).find_map(|line| {
             if let Some((_, [header])) = MISSING_HEADER_RE_GCC
@@ -465,7 +503,7 @@ impl Error {
 
 #[cfg(test)]
 mod test {
-    use crate::{Error, PythonRunnerOutput};
+    use crate::{Error, PythonRunnerOutput, SandboxResource};
     use indoc::indoc;
     use std::process::ExitStatus;
     use std::str::FromStr;
@@ -488,6 +526,8 @@ mod test {
     fn missing_header() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            timed_out: None,
+            sandbox_denial: None,
             stdout: indoc!(r"
                 running bdist_wheel
                 running build
@@ -550,6 +590,8 @@ mod test {
     fn missing_linker_library() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            timed_out: None,
+            sandbox_denial: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -592,6 +634,8 @@ mod test {
     fn missing_wheel_package() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            timed_out: None,
+            sandbox_denial: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -641,6 +685,8 @@ mod test {
     fn missing_distutils() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            timed_out: None,
+            sandbox_denial: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -673,4 +719,35 @@ mod test {
         hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `pygraphviz >1.11`) to avoid building a version of `pygraphviz` that depends on `distutils`.
         ");
     }
+
+    #[test]
+    fn sandbox_denied() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            timed_out: None,
+            sandbox_denial: Some(SandboxResource::Network),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Debug,
+            Some(&PackageName::from_str("pygraphviz").unwrap()),
+            Some(&Version::new([1, 11])),
+            Some("pygraphviz-1.11"),
+        );
+        assert!(matches!(
+            err,
+            Error::SandboxDenied(_, SandboxResource::Network, _)
+        ));
+        let formatted = ErrorWithHints::new(err.to_string(), err.hints()).to_string();
+        let formatted = anstream::adapter::strip_str(&formatted).to_string();
+        insta::assert_snapshot!(formatted, @"
+        Failed building wheel through setup.py: the build sandbox blocked network access
+
+        hint: If `pygraphviz` requires this access to build, add it to `build-sandbox-exempt`
+        ");
+    }
 }
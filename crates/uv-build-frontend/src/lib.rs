@@ -4,6 +4,7 @@
 
 mod error;
 mod pipreqs;
+mod sandbox;
 
 use std::borrow::Cow;
 use std::ffi::OsString;
@@ -14,6 +15,7 @@ use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 use std::{env, iter};
 
 use fs_err as fs;
@@ -30,7 +32,7 @@ use tracing::{Instrument, debug, info_span, instrument, warn};
 use uv_auth::CredentialsCache;
 use uv_cache::Cache;
 use uv_cache_key::cache_digest;
-use uv_configuration::{BuildKind, BuildOutput, NoSources};
+use uv_configuration::{BuildKind, BuildOutput, BuildSandbox, NoSources};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Requirement,
@@ -48,7 +50,8 @@ use uv_types::{
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
-pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::error::{Error, MissingHeaderCause, SandboxResource};
+use crate::sandbox::Sandbox;
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -224,6 +227,8 @@ pub struct SourceBuildContext {
     default_resolution: Arc<Mutex<Option<ResolvedRequirements>>>,
     /// A shared semaphore to limit the number of concurrent builds.
     concurrent_build_slots: Arc<Semaphore>,
+    /// The maximum duration of each call to a build backend.
+    timeout: Option<Duration>,
 }
 
 impl SourceBuildContext {
@@ -232,8 +237,17 @@ impl SourceBuildContext {
         Self {
             default_resolution: Arc::default(),
             concurrent_build_slots,
+            timeout: None,
         }
     }
+
+    /// Set the maximum duration of each call to a build backend, after which the build
+    /// subprocess is terminated.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -444,7 +458,31 @@ impl SourceBuild {
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already set up.
-        let runner = PythonRunner::new(source_build_context.concurrent_build_slots.clone(), level);
+        let sandbox = match build_context.build_options().sandbox(package_name.as_ref()) {
+            BuildSandbox::Disabled => None,
+            BuildSandbox::Strict => {
+                debug!("Running build backend in a sandbox");
+                Some(Sandbox::new(
+                    vec![
+                        source_tree.clone(),
+                        install_path.to_path_buf(),
+                        venv.root().to_path_buf(),
+                    ],
+                    vec![
+                        interpreter.sys_prefix().to_path_buf(),
+                        interpreter.sys_base_prefix().to_path_buf(),
+                        interpreter.stdlib().to_path_buf(),
+                    ],
+                    temp_dir.path().join("tmp"),
+                )?)
+            }
+        };
+        let runner = PythonRunner::new(
+            source_build_context.concurrent_build_slots.clone(),
+            source_build_context.timeout,
+            sandbox,
+            level,
+        );
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
@@ -829,6 +867,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                None,
             )
             .instrument(span)
             .await?;
@@ -951,6 +990,7 @@ impl SourceBuild {
                 &self.source_tree,
                 &self.environment_variables,
                 &self.modified_path,
+                Some(output_dir),
             )
             .instrument(span)
             .await?;
@@ -1067,6 +1107,7 @@ async fn create_pep517_build_environment(
             source_tree,
             environment_variables,
             modified_path,
+            None,
         )
         .instrument(span)
         .await?;
@@ -1170,6 +1211,8 @@ async fn create_pep517_build_environment(
 #[derive(Debug)]
 struct PythonRunner {
     concurrent_build_slots: Arc<Semaphore>,
+    timeout: Option<Duration>,
+    sandbox: Option<Sandbox>,
     level: BuildOutput,
 }
 
@@ -1178,13 +1221,25 @@ struct PythonRunnerOutput {
     stdout: Vec<String>,
     stderr: Vec<String>,
     status: ExitStatus,
+    /// The timeout that was exceeded, if the process was terminated for running too long.
+    timed_out: Option<Duration>,
+    /// The resource that the sandbox denied to the process, if it failed as a result.
+    sandbox_denial: Option<SandboxResource>,
 }
 
 impl PythonRunner {
-    /// Create a `PythonRunner` with the provided shared concurrency semaphore and output level.
-    fn new(concurrent_build_slots: Arc<Semaphore>, level: BuildOutput) -> Self {
+    /// Create a `PythonRunner` with the provided shared concurrency semaphore, timeout, sandbox,
+    /// and output level.
+    fn new(
+        concurrent_build_slots: Arc<Semaphore>,
+        timeout: Option<Duration>,
+        sandbox: Option<Sandbox>,
+        level: BuildOutput,
+    ) -> Self {
         Self {
             concurrent_build_slots,
+            timeout,
+            sandbox,
             level,
         }
     }
@@ -1192,7 +1247,8 @@ impl PythonRunner {
     /// Spawn a process that runs a python script in the provided environment.
     ///
     /// If the concurrency limit has been reached this method will wait until a pending
-    /// script completes before spawning this one.
+    /// script completes before spawning this one. If the script exceeds the timeout, the process
+    /// is killed. If a sandbox is configured, the script may additionally write to `output_dir`.
     ///
     /// Note: It is the caller's responsibility to create an informative span.
     async fn run_script(
@@ -1202,6 +1258,7 @@ impl PythonRunner {
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
+        output_dir: Option<&Path>,
     ) -> Result<PythonRunnerOutput, Error> {
         /// Read lines from a reader and store them in a buffer.
         async fn read_from(
@@ -1224,27 +1281,37 @@ impl PythonRunner {
 
         let _permit = self.concurrent_build_slots.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
-            .args(["-c", script])
-            .current_dir(source_tree.simplified())
-            .envs(environment_variables)
-            .env(EnvVars::UV_INTERNAL__BUILD_DIR, source_tree)
-            .env(EnvVars::PATH, modified_path)
-            .env(EnvVars::VIRTUAL_ENV, venv.root())
-            // NOTE: it would be nice to get colored output from build backends,
-            // but setting CLICOLOR_FORCE=1 changes the output of underlying
-            // tools, which might mess with wrappers trying to parse their
-            // output.
-            .env(EnvVars::PYTHONIOENCODING, "utf-8:backslashreplace")
-            // Remove potentially-sensitive environment variables.
-            .env_remove(EnvVars::PYX_API_KEY)
-            .env_remove(EnvVars::UV_API_KEY)
-            .env_remove(EnvVars::PYX_AUTH_TOKEN)
-            .env_remove(EnvVars::UV_AUTH_TOKEN)
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
-            .spawn()
-            .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        let configure = |command: &mut Command| {
+            command
+                .current_dir(source_tree.simplified())
+                .envs(environment_variables)
+                .env(EnvVars::UV_INTERNAL__BUILD_DIR, source_tree)
+                .env(EnvVars::PATH, modified_path)
+                .env(EnvVars::VIRTUAL_ENV, venv.root())
+                // NOTE: it would be nice to get colored output from build backends,
+                // but setting CLICOLOR_FORCE=1 changes the output of underlying
+                // tools, which might mess with wrappers trying to parse their
+                // output.
+                .env(EnvVars::PYTHONIOENCODING, "utf-8:backslashreplace")
+                // Remove potentially-sensitive environment variables.
+                .env_remove(EnvVars::PYX_API_KEY)
+                .env_remove(EnvVars::UV_API_KEY)
+                .env_remove(EnvVars::PYX_AUTH_TOKEN)
+                .env_remove(EnvVars::UV_AUTH_TOKEN)
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped());
+        };
+        let (mut child, sandbox_report) = if let Some(sandbox) = &self.sandbox {
+            sandbox
+                .spawn(venv.python_executable(), script, output_dir, configure)
+                .map(|(child, report)| (child, Some(report)))
+        } else {
+            let mut command = Command::new(venv.python_executable());
+            command.args(["-c", script]);
+            configure(&mut command);
+            command.spawn().map(|child| (child, None))
+        }
+        .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
         // Create buffers to capture `stdout` and `stderr`.
         let mut stdout_buf = Vec::with_capacity(1024);
@@ -1254,32 +1321,54 @@ impl PythonRunner {
         let stdout_reader = tokio::io::BufReader::new(child.stdout.take().unwrap()).split(b'\n');
         let stderr_reader = tokio::io::BufReader::new(child.stderr.take().unwrap()).split(b'\n');
 
-        // Asynchronously read from the in-memory pipes.
+        // Asynchronously read from the in-memory pipes, and wait for the child process to finish.
         let printer = Printer::from(self.level);
-        let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
-        );
-        match result {
-            (Ok(()), Ok(())) => {}
-            (Err(err), _) | (_, Err(err)) => {
-                return Err(Error::CommandFailed(
-                    venv.python_executable().to_path_buf(),
-                    err,
-                ));
+        let run = async {
+            let result = tokio::join!(
+                read_from(stdout_reader, printer, &mut stdout_buf),
+                read_from(stderr_reader, printer, &mut stderr_buf),
+            );
+            match result {
+                (Ok(()), Ok(())) => {}
+                (Err(err), _) | (_, Err(err)) => return Err(err),
             }
-        }
-
-        // Wait for the child process to finish.
-        let status = child
-            .wait()
-            .await
+            child.wait().await
+        };
+        let (status, timed_out) = if let Some(timeout) = self.timeout {
+            match tokio::time::timeout(timeout, run).await {
+                Ok(status) => (status, None),
+                Err(_) => {
+                    debug!(
+                        "Build backend exceeded timeout of {}s; terminating",
+                        timeout.as_secs()
+                    );
+                    let status = match child.kill().await {
+                        Ok(()) => child.wait().await,
+                        Err(err) => Err(err),
+                    };
+                    (status, Some(timeout))
+                }
+            }
+        } else {
+            (run.await, None)
+        };
+        let status = status
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
+        // If the process failed in a sandbox, determine whether the sandbox denied it a resource.
+        let sandbox_denial = match (&self.sandbox, sandbox_report) {
+            (Some(sandbox), Some(report)) if !status.success() => {
+                sandbox.denied_resource(&report, output_dir)
+            }
+            _ => None,
+        };
+
         Ok(PythonRunnerOutput {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
+            timed_out,
+            sandbox_denial,
         })
     }
 }
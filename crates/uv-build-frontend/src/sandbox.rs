@@ -0,0 +1,597 @@
+//! Run build backends in an OS-level sandbox.
+//!
+//! On Linux, filesystem access is restricted with [Landlock](https://docs.kernel.org/userspace-api/landlock.html)
+//! and network access is removed by moving the build backend into a fresh network namespace
+//! (falling back to Landlock's TCP restrictions where unprivileged user namespaces are disabled).
+//! On macOS, the build backend is run under `sandbox-exec` with a generated profile. Elsewhere,
+//! builds run without isolation.
+//!
+//! When a build backend fails, the resource that the sandbox denied (if any) is reported by the
+//! Python process itself, from the exception that caused it to fail.
+
+use std::ffi::OsStr;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempPath;
+use tokio::process::{Child, Command};
+use tracing::debug;
+
+use uv_static::EnvVars;
+
+use crate::error::SandboxResource;
+
+/// A Python prelude that installs an exception hook, which records the resource behind an
+/// unhandled `OSError` (i.e., a path, or the network) to the file named by
+/// `UV_INTERNAL__SANDBOX_REPORT`.
+///
+/// Errors without a filename are attributed to the network only if the process attempted to use
+/// a socket, as observed by an audit hook.
+const DENIAL_REPORTER: &str = r#"
+def _uv_report_sandbox_denials():
+    import errno
+    import os
+    import socket
+    import sys
+
+    report = os.environ.pop("UV_INTERNAL__SANDBOX_REPORT", None)
+    if not report:
+        return
+
+    network = []
+
+    def audit(event, args):
+        if not network and event in ("socket.connect", "socket.getaddrinfo"):
+            network.append(event)
+
+    sys.addaudithook(audit)
+
+    def denied(exc):
+        seen = set()
+        while exc is not None and id(exc) not in seen:
+            seen.add(id(exc))
+            if isinstance(exc, socket.gaierror):
+                return "network"
+            if isinstance(exc, OSError):
+                filename = exc.filename
+                if isinstance(filename, (str, bytes)) and exc.errno in (errno.EACCES, errno.EPERM):
+                    return "path\n" + os.path.abspath(os.fsdecode(filename))
+                if filename is None and network and exc.errno in (
+                    errno.ENETUNREACH,
+                    errno.EHOSTUNREACH,
+                    errno.EACCES,
+                    errno.EPERM,
+                ):
+                    return "network"
+            reason = getattr(exc, "reason", None)
+            if isinstance(reason, BaseException):
+                exc = reason
+            else:
+                exc = exc.__cause__ or exc.__context__
+        return None
+
+    excepthook = sys.excepthook
+
+    def report_denial(exc_type, exc, tb):
+        resource = denied(exc)
+        if resource is not None:
+            try:
+                with open(report, "w", encoding="utf-8") as file:
+                    file.write(resource)
+            except OSError:
+                pass
+        excepthook(exc_type, exc, tb)
+
+    sys.excepthook = report_denial
+
+
+_uv_report_sandbox_denials()
+del _uv_report_sandbox_denials
+"#;
+
+/// The resources a sandboxed build backend may access.
+#[derive(Debug, Clone)]
+pub(crate) struct Sandbox {
+    /// Directories that the build backend may read and write.
+    writable: Vec<PathBuf>,
+    /// Directories that the build backend may read and execute.
+    readable: Vec<PathBuf>,
+    /// A private temporary directory, used in lieu of the system temporary directory.
+    temp_dir: PathBuf,
+}
+
+impl Sandbox {
+    /// Create a [`Sandbox`] that permits writes to the given directories, and reads from the
+    /// given directories in addition to the system toolchain directories.
+    ///
+    /// The build backend's temporary files are written to `temp_dir`, which is created if it
+    /// doesn't exist.
+    pub(crate) fn new(
+        writable: Vec<PathBuf>,
+        readable: Vec<PathBuf>,
+        temp_dir: PathBuf,
+    ) -> io::Result<Self> {
+        fs_err::create_dir_all(&temp_dir)?;
+        let writable = writable
+            .into_iter()
+            .chain(std::iter::once(temp_dir.clone()))
+            .chain(SYSTEM_WRITABLE_DIRECTORIES.iter().map(PathBuf::from))
+            .collect();
+        let readable = readable
+            .into_iter()
+            .chain(SYSTEM_DIRECTORIES.iter().map(PathBuf::from))
+            .collect();
+        Ok(Self {
+            writable,
+            readable,
+            temp_dir,
+        })
+    }
+
+    /// Spawn the given Python script inside the sandbox.
+    ///
+    /// `configure` is applied to the [`Command`] before spawning, to set the working directory,
+    /// environment, and standard streams. `output_dir` is an additional directory that the build
+    /// backend may write to.
+    ///
+    /// Returns the child process, along with the path to which it reports the resource that the
+    /// sandbox denied, if it fails; see [`Sandbox::denied_resource`].
+    pub(crate) fn spawn(
+        &self,
+        python: &Path,
+        script: &str,
+        output_dir: Option<&Path>,
+        configure: impl Fn(&mut Command),
+    ) -> io::Result<(Child, TempPath)> {
+        let report = tempfile::NamedTempFile::new_in(&self.temp_dir)?.into_temp_path();
+        let script = format!("{DENIAL_REPORTER}\n{script}");
+        let args = [OsStr::new("-c"), OsStr::new(&script)];
+        let configure = |command: &mut Command| {
+            configure(command);
+            command
+                .env(EnvVars::TMPDIR, &self.temp_dir)
+                .env(EnvVars::UV_INTERNAL__SANDBOX_REPORT, &report);
+        };
+        let writable = self
+            .writable
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(output_dir)
+            .collect::<Vec<_>>();
+        let child = platform::spawn(python, &args, &writable, &self.readable, configure)?;
+        Ok((child, report))
+    }
+
+    /// Read the resource that the sandbox denied to a failed build backend from its report.
+    ///
+    /// Paths are only reported if they're outside of the directories the build backend may write
+    /// to, as the failure is otherwise unrelated to the sandbox.
+    pub(crate) fn denied_resource(
+        &self,
+        report: &Path,
+        output_dir: Option<&Path>,
+    ) -> Option<SandboxResource> {
+        let contents = match fs_err::read_to_string(report) {
+            Ok(contents) => contents,
+            Err(err) => {
+                debug!("Failed to read sandbox report: {err}");
+                return None;
+            }
+        };
+        if contents == "network" {
+            return Some(SandboxResource::Network);
+        }
+        let path = PathBuf::from(contents.strip_prefix("path\n")?);
+        let permitted = self
+            .writable
+            .iter()
+            .map(PathBuf::as_path)
+            .chain(output_dir)
+            .any(|directory| path.starts_with(directory));
+        if permitted {
+            return None;
+        }
+        Some(SandboxResource::Path(path))
+    }
+}
+
+/// Directories containing the compilers, headers, and shared libraries used by build backends.
+#[cfg(target_os = "linux")]
+const SYSTEM_DIRECTORIES: &[&str] = &[
+    "/bin", "/sbin", "/usr", "/lib", "/lib32", "/lib64", "/libx32", "/etc", "/opt", "/nix",
+    "/proc", "/sys", "/dev",
+];
+
+/// Directories containing the compilers, headers, and shared libraries used by build backends.
+#[cfg(target_os = "macos")]
+const SYSTEM_DIRECTORIES: &[&str] = &[
+    "/bin",
+    "/sbin",
+    "/usr",
+    "/System",
+    "/Library",
+    "/Applications/Xcode.app",
+    "/opt",
+    "/private/etc",
+    "/dev",
+];
+
+/// Directories containing the compilers, headers, and shared libraries used by build backends.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+const SYSTEM_DIRECTORIES: &[&str] = &[];
+
+/// Directories that build backends may write to, e.g., to redirect output to `/dev/null`.
+#[cfg(target_os = "linux")]
+const SYSTEM_WRITABLE_DIRECTORIES: &[&str] = &["/dev"];
+
+/// Directories that build backends may write to.
+///
+/// On macOS, the writable devices are enumerated in the sandbox profile.
+#[cfg(not(target_os = "linux"))]
+const SYSTEM_WRITABLE_DIRECTORIES: &[&str] = &[];
+
+#[cfg(target_os = "linux")]
+mod platform {
+    // Landlock has no wrapper in `nix`, so we issue the system calls directly.
+    #![allow(unsafe_code)]
+
+    use std::ffi::{CStr, OsStr};
+    use std::io;
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::path::{Path, PathBuf};
+    use std::sync::LazyLock;
+
+    use fs_err::os::unix::fs::OpenOptionsExt;
+    use nix::libc;
+    use nix::sched::{CloneFlags, unshare};
+    use tokio::process::Command;
+    use tracing::debug;
+
+    use uv_warnings::warn_user_once;
+
+    const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+    const LANDLOCK_RULE_PATH_BENEATH: libc::c_int = 1;
+
+    const ACCESS_FS_EXECUTE: u64 = 1 << 0;
+    const ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+    const ACCESS_FS_READ_FILE: u64 = 1 << 2;
+    const ACCESS_FS_READ_DIR: u64 = 1 << 3;
+    const ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+    const ACCESS_FS_IOCTL_DEV: u64 = 1 << 15;
+
+    const ACCESS_NET_BIND_TCP: u64 = 1 << 0;
+    const ACCESS_NET_CONNECT_TCP: u64 = 1 << 1;
+
+    /// The access rights that apply to files, as opposed to directories.
+    const ACCESS_FILE: u64 = ACCESS_FS_EXECUTE
+        | ACCESS_FS_WRITE_FILE
+        | ACCESS_FS_READ_FILE
+        | ACCESS_FS_TRUNCATE
+        | ACCESS_FS_IOCTL_DEV;
+
+    /// The access rights granted on readable directories.
+    const ACCESS_READ: u64 = ACCESS_FS_EXECUTE | ACCESS_FS_READ_FILE | ACCESS_FS_READ_DIR;
+
+    /// `struct landlock_ruleset_attr`.
+    #[repr(C)]
+    struct RulesetAttr {
+        handled_access_fs: u64,
+        handled_access_net: u64,
+    }
+
+    /// `struct landlock_path_beneath_attr`.
+    #[repr(C, packed)]
+    struct PathBeneathAttr {
+        allowed_access: u64,
+        parent_fd: i32,
+    }
+
+    /// The Landlock ABI version supported by the running kernel, if any.
+    static LANDLOCK_ABI: LazyLock<Option<i64>> = LazyLock::new(|| {
+        // SAFETY: Querying the ABI version takes no pointers.
+        let abi = unsafe {
+            libc::syscall(
+                libc::SYS_landlock_create_ruleset,
+                std::ptr::null::<RulesetAttr>(),
+                0usize,
+                LANDLOCK_CREATE_RULESET_VERSION,
+            )
+        };
+        if abi < 1 {
+            debug!("Landlock is not available: {}", io::Error::last_os_error());
+            None
+        } else {
+            debug!("Using Landlock ABI version {abi}");
+            Some(abi)
+        }
+    });
+
+    /// Return the filesystem access rights handled by the given Landlock ABI version.
+    fn handled_access_fs(abi: i64) -> u64 {
+        // ABI 1 handles the first 13 access rights; ABI 2 adds `REFER`, ABI 3 adds `TRUNCATE`,
+        // and ABI 5 adds `IOCTL_DEV`.
+        let mut access = (1 << 13) - 1;
+        if abi >= 2 {
+            access |= 1 << 13;
+        }
+        if abi >= 3 {
+            access |= ACCESS_FS_TRUNCATE;
+        }
+        if abi >= 5 {
+            access |= ACCESS_FS_IOCTL_DEV;
+        }
+        access
+    }
+
+    /// Create a Landlock ruleset that permits the given access, denying TCP if supported.
+    fn create_ruleset(abi: i64, writable: &[&Path], readable: &[PathBuf]) -> io::Result<OwnedFd> {
+        let handled_access_fs = handled_access_fs(abi);
+        let attr = RulesetAttr {
+            handled_access_fs,
+            handled_access_net: if abi >= 4 {
+                ACCESS_NET_BIND_TCP | ACCESS_NET_CONNECT_TCP
+            } else {
+                0
+            },
+        };
+
+        // SAFETY: `attr` is a valid `landlock_ruleset_attr` for the duration of the call.
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_landlock_create_ruleset,
+                &raw const attr,
+                size_of::<RulesetAttr>(),
+                0u32,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = i32::try_from(fd).map_err(io::Error::other)?;
+        // SAFETY: The kernel returned a new file descriptor that we now own.
+        let ruleset = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        let rules = writable
+            .iter()
+            .map(|path| (*path, handled_access_fs))
+            .chain(readable.iter().map(|path| (path.as_path(), ACCESS_READ)));
+        for (path, access) in rules {
+            let Ok(file) = fs_err::File::options()
+                .read(true)
+                .custom_flags(libc::O_PATH | libc::O_CLOEXEC)
+                .open(path)
+            else {
+                continue;
+            };
+            let access = if file.metadata().is_ok_and(|metadata| metadata.is_dir()) {
+                access
+            } else {
+                access & ACCESS_FILE
+            };
+            let rule = PathBeneathAttr {
+                allowed_access: access & handled_access_fs,
+                parent_fd: file.as_raw_fd(),
+            };
+            // SAFETY: `rule` is a valid `landlock_path_beneath_attr` for the duration of the call.
+            let result = unsafe {
+                libc::syscall(
+                    libc::SYS_landlock_add_rule,
+                    ruleset.as_raw_fd(),
+                    LANDLOCK_RULE_PATH_BENEATH,
+                    &raw const rule,
+                    0u32,
+                )
+            };
+            if result != 0 {
+                let err = io::Error::last_os_error();
+                return Err(io::Error::new(
+                    err.kind(),
+                    format!(
+                        "failed to add Landlock rule for `{}`: {err}",
+                        path.display()
+                    ),
+                ));
+            }
+        }
+
+        Ok(ruleset)
+    }
+
+    /// Write the given contents to a file, e.g., in `/proc/self`.
+    ///
+    /// Only performs async-signal-safe system calls, without allocating, such that it can be
+    /// called between `fork` and `exec`.
+    fn write_file(path: &CStr, contents: &[u8]) -> io::Result<()> {
+        // SAFETY: `path` is NUL-terminated, and `contents` is valid for reads of its length.
+        unsafe {
+            let fd = libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let written = libc::write(fd, contents.as_ptr().cast(), contents.len());
+            let result = if written < 0 {
+                Err(io::Error::last_os_error())
+            } else if usize::try_from(written).ok() != Some(contents.len()) {
+                Err(io::Error::from(io::ErrorKind::WriteZero))
+            } else {
+                Ok(())
+            };
+            libc::close(fd);
+            result
+        }
+    }
+
+    pub(super) fn spawn(
+        program: &Path,
+        args: &[&OsStr],
+        writable: &[&Path],
+        readable: &[PathBuf],
+        configure: impl Fn(&mut Command),
+    ) -> io::Result<tokio::process::Child> {
+        // Map the current user and group into the new user namespace, such that the build backend
+        // runs as the same user (rather than as the overflow user), and files it creates are owned
+        // by that user. The mappings are formatted upfront, since `pre_exec` can't allocate.
+        // SAFETY: `getuid` and `getgid` are always successful.
+        let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+        let uid_map = format!("{uid} {uid} 1\n");
+        let gid_map = format!("{gid} {gid} 1\n");
+
+        let ruleset = match *LANDLOCK_ABI {
+            Some(abi) => Some(create_ruleset(abi, writable, readable)?),
+            None => {
+                warn_user_once!(
+                    "Filesystem isolation for build backends requires Landlock (Linux 5.13+), which is not available; building without it"
+                );
+                None
+            }
+        };
+        let restricts_tcp = LANDLOCK_ABI.is_some_and(|abi| abi >= 4);
+
+        let spawn = |isolate_network: bool| {
+            let mut command = Command::new(program);
+            command.args(args);
+            configure(&mut command);
+            let ruleset = ruleset.as_ref().map(AsRawFd::as_raw_fd);
+            let uid_map = uid_map.clone();
+            let gid_map = gid_map.clone();
+            // SAFETY: The closure only performs async-signal-safe system calls.
+            unsafe {
+                command.pre_exec(move || {
+                    if isolate_network {
+                        unshare(CloneFlags::CLONE_NEWUSER | CloneFlags::CLONE_NEWNET)?;
+                        // Unprivileged processes must deny `setgroups` before writing a GID map.
+                        write_file(c"/proc/self/setgroups", b"deny")?;
+                        write_file(c"/proc/self/uid_map", uid_map.as_bytes())?;
+                        write_file(c"/proc/self/gid_map", gid_map.as_bytes())?;
+                    }
+                    if let Some(ruleset) = ruleset {
+                        nix::sys::prctl::set_no_new_privs()?;
+                        if libc::syscall(libc::SYS_landlock_restrict_self, ruleset, 0u32) != 0 {
+                            return Err(io::Error::last_os_error());
+                        }
+                    }
+                    Ok(())
+                });
+            }
+            command.spawn()
+        };
+
+        match spawn(true) {
+            Ok(child) => Ok(child),
+            Err(err) => {
+                // Unprivileged user namespaces may be disabled (e.g., by AppArmor); retry without
+                // a network namespace. If the error came from elsewhere, the retry fails too.
+                debug!("Failed to spawn build backend in a network namespace: {err}");
+                let child = spawn(false)?;
+                if !restricts_tcp {
+                    warn_user_once!(
+                        "Network isolation for build backends requires unprivileged user namespaces or Landlock (Linux 6.7+), neither of which is available; building with network access"
+                    );
+                }
+                Ok(child)
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::OsStr;
+    use std::fmt::Write;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use tokio::process::Command;
+
+    use uv_warnings::warn_user_once;
+
+    const SANDBOX_EXEC: &str = "/usr/bin/sandbox-exec";
+
+    /// Quote a path for use in a sandbox profile.
+    fn quote(path: &Path) -> String {
+        // The sandbox matches on resolved paths, e.g., `/private/tmp` rather than `/tmp`.
+        let path = fs_err::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let path = path.to_string_lossy();
+        format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// Generate a sandbox profile that denies network access, denies writes outside of the
+    /// writable directories, and denies reads of the user's home directory outside of the
+    /// permitted directories.
+    fn profile(writable: &[&Path], readable: &[PathBuf]) -> String {
+        let mut profile = String::from(
+            "(version 1)\n\
+             (allow default)\n\
+             (deny network*)\n\
+             (allow network* (remote unix-socket))\n\
+             (deny file-write*)\n\
+             (allow file-write* (literal \"/dev/null\") (literal \"/dev/zero\") (regex #\"^/dev/fd/\") (regex #\"^/dev/tty\"))\n",
+        );
+        for path in writable {
+            let _ = writeln!(profile, "(allow file-write* (subpath {}))", quote(path));
+        }
+        if let Some(home) = std::env::home_dir() {
+            let _ = writeln!(profile, "(deny file-read-data (subpath {}))", quote(&home));
+        }
+        for path in writable
+            .iter()
+            .copied()
+            .chain(readable.iter().map(PathBuf::as_path))
+        {
+            let _ = writeln!(profile, "(allow file-read-data (subpath {}))", quote(path));
+        }
+        profile
+    }
+
+    pub(super) fn spawn(
+        program: &Path,
+        args: &[&OsStr],
+        writable: &[&Path],
+        readable: &[PathBuf],
+        configure: impl Fn(&mut Command),
+    ) -> io::Result<tokio::process::Child> {
+        let mut command = if Path::new(SANDBOX_EXEC).is_file() {
+            let mut command = Command::new(SANDBOX_EXEC);
+            command
+                .arg("-p")
+                .arg(profile(writable, readable))
+                .arg(program);
+            command
+        } else {
+            warn_user_once!(
+                "Isolation for build backends requires `{SANDBOX_EXEC}`, which is not available; building without it"
+            );
+            Command::new(program)
+        };
+        command.args(args);
+        configure(&mut command);
+        command.spawn()
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod platform {
+    use std::ffi::OsStr;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use tokio::process::Command;
+
+    use uv_warnings::warn_user_once;
+
+    pub(super) fn spawn(
+        program: &Path,
+        args: &[&OsStr],
+        _writable: &[&Path],
+        _readable: &[PathBuf],
+        configure: impl Fn(&mut Command),
+    ) -> io::Result<tokio::process::Child> {
+        warn_user_once!(
+            "Isolation for build backends is not supported on this platform; building without it"
+        );
+        let mut command = Command::new(program);
+        command.args(args);
+        configure(&mut command);
+        command.spawn()
+    }
+}
@@ -1188,6 +1188,16 @@ pub enum CacheBucket {
     /// Cache structure:
     ///  * `osv-v0/vulnerability/<vuln_id>.msgpack` — cached full vulnerability records
     Osv,
+    /// Remote scripts downloaded by `uv run`, keyed by URL and content hash.
+    ///
+    /// Cache structure:
+    ///  * `scripts-v0/<digest(url)>/<sha256>/<name>.py`
+    Scripts,
+    /// Lockfiles produced by previous resolutions, keyed by a digest of the resolver inputs.
+    ///
+    /// Cache structure:
+    ///  * `resolutions-v0/<digest(inputs)>.toml`
+    Resolutions,
 }
 
 impl CacheBucket {
@@ -1198,10 +1208,10 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v5",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_clean.rs`.
-            Self::Simple => "simple-v21",
+            Self::Simple => "simple-v23",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/build/cache_prune.rs`.
             Self::Wheels => "wheels-v6",
@@ -1213,6 +1223,8 @@ impl CacheBucket {
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
             Self::Osv => "osv-v0",
+            Self::Scripts => "scripts-v0",
+            Self::Resolutions => "resolutions-v0",
         }
     }
 
@@ -1314,6 +1326,11 @@ impl CacheBucket {
                 let root = cache.bucket(self);
                 summary += rm_rf(root)?;
             }
+            Self::Resolutions => {
+                // Any cached resolution may include the package, so we remove them all.
+                let root = cache.bucket(self);
+                summary += rm_rf(root)?;
+            }
             Self::Git
             | Self::Interpreter
             | Self::Archive
@@ -1321,7 +1338,8 @@ impl CacheBucket {
             | Self::Environments
             | Self::Python
             | Self::Binaries
-            | Self::Osv => {
+            | Self::Osv
+            | Self::Scripts => {
                 // Nothing to do.
             }
         }
@@ -1343,6 +1361,8 @@ impl CacheBucket {
             Self::Python,
             Self::Binaries,
             Self::Osv,
+            Self::Scripts,
+            Self::Resolutions,
         ]
         .iter()
         .copied()
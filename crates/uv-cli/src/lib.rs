@@ -15,8 +15,9 @@ use uv_audit::VulnerabilityServiceFormat;
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ConstraintsScope, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    PipCompileFormat, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
@@ -59,6 +60,35 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonInstallFormat {
+    /// Plain text (for humans).
+    #[default]
+    Text,
+    /// Newline-delimited JSON progress events (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ConfigFormat {
+    /// Display the settings in a human-readable format.
+    #[default]
+    Text,
+    /// Display the settings in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonListSort {
+    /// Sort by implementation, then by version (newest first).
+    #[default]
+    Implementation,
+    /// Sort by version (newest first), regardless of implementation.
+    Version,
+    /// Sort installed interpreters by path, followed by available downloads.
+    Path,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -79,6 +109,17 @@ pub enum AuditOutputFormat {
     Sarif,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuditFailOn {
+    /// Fail if any vulnerability is found.
+    #[default]
+    Any,
+    /// Fail if a vulnerability with a high or critical severity is found.
+    High,
+    /// Fail if a vulnerability with a critical severity is found.
+    Critical,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -218,7 +259,7 @@ pub struct GlobalArgs {
     /// Use quiet output.
     ///
     /// Repeating this option, e.g., `-qq`, will enable a silent mode in which
-    /// uv will write no output to stdout.
+    /// uv will write no output to stdout and will only write errors to stderr.
     #[arg(global = true, action = clap::ArgAction::Count, long, short, conflicts_with = "verbose")]
     pub quiet: u8,
 
@@ -346,12 +387,26 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// Log the negotiated TLS protocol version and cipher suite for each host.
+    ///
+    /// The information is logged at the trace level (i.e., with `-vv`).
+    #[arg(global = true, long, hide = true)]
+    pub tls_info: bool,
+
     /// Hide all progress outputs [env: UV_NO_PROGRESS=]
     ///
     /// For example, spinners or progress bars.
     #[arg(global = true, long, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// Show performance statistics for the command.
+    ///
+    /// For example, the number of package versions whose metadata was fetched speculatively during
+    /// resolution and how many of them were used, or the throughput of hashing downloaded
+    /// distributions.
+    #[arg(global = true, long)]
+    pub timings: bool,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and
     /// `direct_url.json`) to site-packages `.dist-info` directories [env: UV_NO_INSTALLER_METADATA=]
     #[arg(global = true, long, hide = true, value_parser = clap::builder::BoolishValueParser::new())]
@@ -359,7 +414,8 @@ pub struct GlobalArgs {
 
     /// Change to the given directory prior to running the command.
     ///
-    /// Relative paths are resolved with the given directory as the base.
+    /// Relative paths are resolved with the given directory as the base, including the paths
+    /// provided to `--project`, `--env-file`, and `--with-requirements`.
     ///
     /// See `--project` to only change the project root directory.
     #[arg(global = true, long, env = EnvVars::UV_WORKING_DIR, value_hint = ValueHint::DirPath)]
@@ -374,6 +430,9 @@ pub struct GlobalArgs {
     /// Other command-line arguments (such as relative paths) will be resolved relative
     /// to the current working directory.
     ///
+    /// When combined with `--directory`, a relative project path is resolved from the given
+    /// directory.
+    ///
     /// See `--directory` to change the working directory entirely.
     ///
     /// This setting has no effect when used in the `uv pip` interface.
@@ -560,6 +619,12 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Inspect uv's configuration.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -569,6 +634,11 @@ pub enum Commands {
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
+    /// Print dynamic completion candidates for the word being completed.
+    ///
+    /// Invoked by the scripts emitted by `uv generate-shell-completion`.
+    #[command(name = "__complete", hide = true)]
+    Complete(CompleteArgs),
     /// Display documentation for a command.
     // To avoid showing the global options when displaying help for the help command, we are
     // responsible for maintaining the options using the `after_help`.
@@ -864,20 +934,42 @@ pub enum SelfCommand {
 
 #[derive(Args, Debug)]
 pub struct SelfUpdateArgs {
-    /// Update to the specified version. If not provided, uv will update to the latest version.
+    /// Update to the specified version.
+    ///
+    /// If not provided, uv will update to the version pinned in a `.uv-version` file, if one is
+    /// found in the current directory, its parents, or the user configuration directory.
+    /// Otherwise, uv will update to the latest version on the selected channel.
     #[arg(value_hint = ValueHint::Other)]
     pub target_version: Option<String>,
 
+    /// The release channel to update from.
+    ///
+    /// The `stable` channel only considers final releases, while the `preview` channel also
+    /// considers pre-releases. Ignored when updating to a pinned version.
+    #[arg(long, value_enum, conflicts_with = "target_version")]
+    pub channel: Option<SelfUpdateChannel>,
+
     /// A GitHub token for authentication.
     /// A token is not required but can be used to reduce the chance of encountering rate limits.
     #[arg(long, env = EnvVars::UV_GITHUB_TOKEN, value_hint = ValueHint::Other)]
     pub token: Option<String>,
 
     /// Run without performing the update.
+    ///
+    /// Prints the version that would be installed, along with its download URL.
     #[arg(long)]
     pub dry_run: bool,
 }
 
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, ValueEnum)]
+pub enum SelfUpdateChannel {
+    /// Only consider final releases.
+    #[default]
+    Stable,
+    /// Consider pre-releases in addition to final releases.
+    Preview,
+}
+
 #[derive(Args)]
 pub struct CacheNamespace {
     #[command(subcommand)]
@@ -913,6 +1005,61 @@ pub enum CacheCommand {
     Size(SizeArgs),
 }
 
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// List the effective value of every setting.
+    ///
+    /// Settings are read from the command line, environment variables, and configuration files
+    /// (`uv.toml` or the `[tool.uv]` table in `pyproject.toml`), following the same precedence as
+    /// other commands. Settings that aren't set anywhere are shown with their default value, if
+    /// any.
+    ///
+    /// Only global flags (e.g., `uv --offline config list`) are considered from the command line.
+    List(ConfigListArgs),
+    /// Show the effective value of a setting.
+    ///
+    /// Settings in a section are addressed with a dotted key, e.g., `pip.no-build-isolation`.
+    Get(ConfigGetArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigListArgs {
+    /// Show where each value was read from.
+    ///
+    /// The origin is a command-line flag, an environment variable, a key in a configuration file,
+    /// or the default value.
+    #[arg(long)]
+    pub show_origin: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ConfigFormat::default())]
+    pub format: ConfigFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigGetArgs {
+    /// The setting to show, e.g., `index-url` or `pip.no-build-isolation`.
+    #[arg(value_hint = ValueHint::Other)]
+    pub key: String,
+
+    /// Show where the value was read from.
+    ///
+    /// The origin is a command-line flag, an environment variable, a key in a configuration file,
+    /// or the default value.
+    #[arg(long)]
+    pub show_origin: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = ConfigFormat::default())]
+    pub format: ConfigFormat,
+}
+
 #[derive(Args, Debug)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
@@ -1521,6 +1668,29 @@ pub struct PipCompileArgs {
     #[arg(long, short, value_hint = ValueHint::FilePath)]
     pub output_file: Option<PathBuf>,
 
+    /// Write a separate output file for the base dependencies and for each extra.
+    ///
+    /// Every file is derived from a single resolution of the base dependencies and the requested
+    /// extras, such that packages shared between files are pinned to the same version.
+    ///
+    /// Requires `--output-dir`, and a single `pyproject.toml` with static `dependencies` and
+    /// `optional-dependencies` as input. The paths of the generated files are written to stdout.
+    #[arg(long, requires = "output_dir", conflicts_with = "output_file")]
+    pub split_extras: bool,
+
+    /// The directory in which to write the output files when using `--split-extras`.
+    #[arg(long, requires = "split_extras", value_hint = ValueHint::DirPath)]
+    pub output_dir: Option<PathBuf>,
+
+    /// The file name template for the output files when using `--split-extras`.
+    ///
+    /// The `{extra}` placeholder is replaced with the name of each extra, or with `base` for the
+    /// base dependencies.
+    ///
+    /// Defaults to `requirements-{extra}.txt`.
+    #[arg(long, requires = "split_extras")]
+    pub output_template: Option<String>,
+
     /// The format in which the resolution should be output.
     ///
     /// Supports both `requirements.txt` and `pylock.toml` (PEP 751) output formats.
@@ -1697,9 +1867,24 @@ pub struct PipCompileArgs {
     ///
     /// When targeting Android, the default minimum Android API level is `24`. Use
     /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
-    #[arg(long)]
+    #[arg(long, alias = "platform", env = EnvVars::UV_PYTHON_PLATFORM)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The value of the `platform_release` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_release` marker (e.g., the kernel version on
+    /// Linux), so it's left empty by default. Set it to ensure that dependencies gated on
+    /// `platform_release` are resolved as intended, e.g., `6.8.0`.
+    #[arg(long, requires = "python_platform")]
+    pub platform_release: Option<String>,
+
+    /// The value of the `platform_version` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_version` marker, so it's left empty by
+    /// default.
+    #[arg(long, requires = "python_platform")]
+    pub platform_version: Option<String>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1861,6 +2046,14 @@ pub struct PipSyncArgs {
     #[command(flatten)]
     pub installer: InstallerArgs,
 
+    /// Compile Python files to bytecode after installation, but only for the specified package.
+    ///
+    /// Unlike `--compile-bytecode`, which processes the entire site-packages directory, only the
+    /// files recorded as belonging to the given package are compiled. May be provided more than
+    /// once.
+    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other)]
+    pub compile_package: Vec<PackageName>,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
@@ -2065,9 +2258,24 @@ pub struct PipSyncArgs {
     /// platform. Conversely, any distributions that are built from source may be incompatible with
     /// the _target_ platform, as they will be built for the _current_ platform. The
     /// `--python-platform` option is intended for advanced use cases.
-    #[arg(long)]
+    #[arg(long, alias = "platform")]
     pub python_platform: Option<TargetTriple>,
 
+    /// The value of the `platform_release` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_release` marker (e.g., the kernel version on
+    /// Linux), so it's left empty by default. Set it to ensure that dependencies gated on
+    /// `platform_release` are installed as intended, e.g., `6.8.0`.
+    #[arg(long, requires = "python_platform")]
+    pub platform_release: Option<String>,
+
+    /// The value of the `platform_version` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_version` marker, so it's left empty by
+    /// default.
+    #[arg(long, requires = "python_platform")]
+    pub platform_version: Option<String>,
+
     /// Validate the Python environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2211,6 +2419,28 @@ pub struct PipInstallArgs {
     )]
     pub build_constraints: Vec<Maybe<PathBuf>>,
 
+    /// Constrain both runtime and build dependencies using the given requirements files.
+    ///
+    /// Equivalent to providing each file to both `--constraints` and `--build-constraints`, such
+    /// that a single set of pins applies when resolving the requested packages and when building
+    /// source distributions.
+    #[arg(
+        long,
+        alias = "constraint-all",
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub constraints_all: Vec<Maybe<PathBuf>>,
+
+    /// The environments to which the constraints provided via `--constraints` apply.
+    ///
+    /// By default, constraints only apply when resolving the requested packages. With `build`,
+    /// they only apply when resolving the build dependencies of source distributions (as with
+    /// `--build-constraints`), and with `both`, they apply to both (as with `--constraints-all`).
+    #[arg(long, value_enum, default_value_t = ConstraintsScope::default())]
+    pub constraints_scope: ConstraintsScope,
+
     /// Include optional dependencies from the specified extra name; may be provided more than once.
     ///
     /// Only applies to `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
@@ -2235,12 +2465,159 @@ pub struct PipInstallArgs {
     #[arg(long, group = "sources")]
     pub group: Vec<PipGroupName>,
 
+    /// Read dependency groups from the given TOML file, rather than the `pyproject.toml` in the
+    /// working directory.
+    ///
+    /// The file may contain a standalone `[dependency-groups]` table, without a `[project]`
+    /// section. Applies to any `--group` that does not specify its own path.
+    #[arg(long, requires = "group", value_hint = ValueHint::FilePath)]
+    pub group_file: Option<PathBuf>,
+
+    /// Install the locked resolution from the given `uv.lock` file, without resolving.
+    ///
+    /// By default, the project at the root of the lockfile is installed alongside its
+    /// dependencies. If `--group` is provided, only the dependencies in the specified groups are
+    /// installed.
+    ///
+    /// Unlike `uv sync`, the lockfile is not checked against the project's `pyproject.toml`, and
+    /// packages that are not part of the lockfile are not removed from the environment.
+    #[arg(
+        long,
+        group = "sources",
+        conflicts_with_all = ["package", "requirements", "editable", "group_file"],
+        value_hint = ValueHint::FilePath,
+    )]
+    pub from_lock: Option<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
+    /// Compile Python files to bytecode after installation, but only for the specified package.
+    ///
+    /// Unlike `--compile-bytecode`, which processes the entire site-packages directory, only the
+    /// files recorded as belonging to the given package are compiled. May be provided more than
+    /// once.
+    #[arg(long, help_heading = "Installer options", value_hint = ValueHint::Other)]
+    pub compile_package: Vec<PackageName>,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
+    /// The maximum age (in seconds) of cached index metadata.
+    ///
+    /// Cached responses from package indexes that are older than the given duration are
+    /// revalidated, even if the index allows them to be cached for longer. Useful for
+    /// frequently-updated private indexes.
+    #[arg(long, value_name = "SECONDS", help_heading = "Cache options")]
+    pub index_cache_ttl: Option<u64>,
+
+    /// Don't reuse credentials across requests.
+    ///
+    /// By default, credentials that are discovered for a request (e.g., from the netrc file or
+    /// the keyring) are reused for subsequent requests to the same index or realm. With this
+    /// flag, credentials are instead re-fetched from their source for every request. Credentials
+    /// that are configured for an index directly are still applied.
+    #[arg(long, help_heading = "Index options")]
+    pub no_index_cache_credentials: bool,
+
+    /// The username to use when authenticating to the index provided via `--index-url`.
+    ///
+    /// The credentials are applied to the index as if they were embedded in its URL, but without
+    /// including them in the URL itself, such that they don't appear in logs.
+    #[arg(long, help_heading = "Index options", value_hint = ValueHint::Other)]
+    pub index_username: Option<String>,
+
+    /// The password to use when authenticating to the index provided via `--index-url`.
+    ///
+    /// The credentials are applied to the index as if they were embedded in its URL, but without
+    /// including them in the URL itself, such that they don't appear in logs.
+    #[arg(long, help_heading = "Index options", value_hint = ValueHint::Other)]
+    pub index_password: Option<String>,
+
+    /// Check that each configured index is reachable before resolving.
+    ///
+    /// Sends a request to the root of each index, and fails early if any of them can't be
+    /// reached, rather than partway through the resolution. Ignored when running offline.
+    #[arg(long, help_heading = "Index options")]
+    pub check_index: bool,
+
+    /// Abort the resolution on the first requested or selected distribution that fails to
+    /// download or build.
+    ///
+    /// By default, uv continues to fetch metadata for other packages in the background after a
+    /// distribution fails, and only reports the error once the resolver reaches it. With
+    /// `--fail-fast`, any in-flight requests are cancelled, and the error is reported immediately.
+    /// Errors for distributions that are prefetched speculatively are still deferred until the
+    /// resolver reaches them.
+    #[arg(long, help_heading = "Resolver options")]
+    pub fail_fast: bool,
+
+    /// Check the licenses of the resolved packages against the `tool.uv.license-policy` in the
+    /// `pyproject.toml` file in the current directory.
+    ///
+    /// Packages with a denied license fail the installation, and packages with a license that's
+    /// flagged as a warning are reported, but installed. The policy is otherwise only enforced by
+    /// `uv lock`.
+    #[arg(long, help_heading = "Resolver options")]
+    pub check_licenses: bool,
+
+    /// Require a PEP 740 provenance object for every distribution fetched from an index.
+    ///
+    /// Distributions whose index doesn't publish a provenance object (e.g., via the
+    /// `data-provenance` attribute of the Simple API) fail the installation, and are listed. The
+    /// attestations themselves are not verified.
+    #[arg(long, conflicts_with = "from_lock", help_heading = "Resolver options")]
+    pub require_attestations: bool,
+
+    /// Read additional dependency metadata from a JSON lines file, or from stdin with `-`.
+    ///
+    /// Each line must contain a single entry in the same format as the `dependency-metadata`
+    /// setting, e.g., `{"name": "anyio", "version": "4.3.0", "requires-dist": ["idna"]}`.
+    /// Entries are combined with any `dependency-metadata` from the configuration.
+    #[arg(long, value_hint = ValueHint::FilePath, help_heading = "Resolver options")]
+    pub dependency_metadata: Option<PathBuf>,
+
+    /// Cache resolutions in the given directory, and reuse them for identical runs.
+    ///
+    /// The cache is keyed on the inputs to the resolver, e.g., the requirements, constraints, and
+    /// overrides, the target environment's markers and installed packages, and the index and
+    /// resolver settings. On an exact match, the cached resolution is installed without querying
+    /// the index; any change to the inputs results in a new resolution.
+    ///
+    /// Only resolutions that consist entirely of packages from a registry are cached. Cached
+    /// resolutions never expire, and so may miss newer releases until the directory is removed.
+    #[arg(long, value_hint = ValueHint::DirPath, help_heading = "Resolver options")]
+    pub resolution_cache: Option<PathBuf>,
+
+    /// Reinstall any installed packages that appear to be broken.
+    ///
+    /// A package is considered broken if files listed in its `RECORD` are missing from the
+    /// environment, or if its `RECORD` or `METADATA` file is missing or can't be parsed (e.g.,
+    /// after an interrupted installation). Broken packages are reinstalled at their currently
+    /// installed version, if possible.
+    ///
+    /// May be provided without any requirements, in which case only the broken packages are
+    /// reinstalled.
+    #[arg(long, group = "sources", help_heading = "Installer options")]
+    pub reinstall_broken: bool,
+
+    /// The maximum time (in seconds) to wait for each call to a build backend.
+    ///
+    /// If a build backend hook (e.g., `build_wheel`) does not complete within the given duration,
+    /// the build subprocess is terminated and the build fails. By default, builds are not subject
+    /// to a timeout.
+    #[arg(long, value_name = "SECONDS", help_heading = "Build options")]
+    pub build_backend_timeout: Option<u64>,
+
+    /// Remove packages that a build left behind in the environment when build isolation is
+    /// disabled.
+    ///
+    /// With `--no-build-isolation` (or `--no-build-isolation-package`), build backends run in the
+    /// target environment, and any packages they install there persist after the build. By
+    /// default, uv warns about such packages; with `--clean-build-deps`, uv uninstalls them.
+    #[arg(long, help_heading = "Build options")]
+    pub clean_build_deps: bool,
+
     /// Ignore package dependencies, instead only installing those packages explicitly listed
     /// on the command line or in the requirements files.
     #[arg(long, overrides_with("deps"))]
@@ -2443,9 +2820,24 @@ pub struct PipInstallArgs {
     /// platform. Conversely, any distributions that are built from source may be incompatible with
     /// the _target_ platform, as they will be built for the _current_ platform. The
     /// `--python-platform` option is intended for advanced use cases.
-    #[arg(long)]
+    #[arg(long, alias = "platform", env = EnvVars::UV_PYTHON_PLATFORM)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The value of the `platform_release` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_release` marker (e.g., the kernel version on
+    /// Linux), so it's left empty by default. Set it to ensure that dependencies gated on
+    /// `platform_release` are installed as intended, e.g., `6.8.0`.
+    #[arg(long, requires = "python_platform")]
+    pub platform_release: Option<String>,
+
+    /// The value of the `platform_version` marker for the `--python-platform` target.
+    ///
+    /// Target triples don't determine the `platform_version` marker, so it's left empty by
+    /// default.
+    #[arg(long, requires = "python_platform")]
+    pub platform_version: Option<String>,
+
     /// Do not remove extraneous packages present in the environment.
     #[arg(long, overrides_with("exact"), alias = "no-exact", hide = true)]
     pub inexact: bool,
@@ -2471,6 +2863,47 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Explain the version selected for the given package.
+    ///
+    /// After resolving, uv will print the requirements that constrained the version of the
+    /// package, and highlight those that provided the tightest lower and upper bounds.
+    ///
+    /// Implies that the requirements will be resolved, even if they're already satisfied by the
+    /// environment.
+    #[arg(long, value_name = "PACKAGE", help_heading = "Resolver options")]
+    pub resolution_explain: Vec<PackageName>,
+
+    /// Report which of the resolved packages are missing hashes, rather than installing them.
+    ///
+    /// Resolves the requirements (including any transitive dependencies) and lists each package
+    /// without a hash in the requirements or constraints files, along with the `--hash` lines
+    /// reported by the index, which can be pasted into the requirements file.
+    ///
+    /// Missing hashes are reported, rather than rejected, even when `--require-hashes` is
+    /// enabled.
+    #[arg(long, conflicts_with = "no_verify_hashes")]
+    pub hash_coverage_report: bool,
+
+    /// Require a matching hash for the given package, without requiring hashes for all
+    /// requirements.
+    ///
+    /// If the package is included in the resolution, either directly or as a transitive
+    /// dependency, it must be pinned to an exact version and include a hash in the requirements
+    /// or constraints files. May be provided multiple times, to increase hash coverage package by
+    /// package before enabling `--require-hashes`.
+    #[arg(long, value_name = "PACKAGE", conflicts_with = "no_verify_hashes")]
+    pub require_hashes_package: Vec<PackageName>,
+
+    /// Write a JSON report of the installed packages to the given path.
+    ///
+    /// For each installed package, the report includes the number of bytes downloaded
+    /// (`download_size`), whether the package was installed from the cache without downloading it
+    /// (`cached`), and whether it was built from source (`build_required`).
+    ///
+    /// Use `-` to write the report to stdout.
+    #[arg(long, value_name = "PATH", conflicts_with = "dry_run")]
+    pub report: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2567,6 +3000,22 @@ pub struct PipUninstallArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub no_break_system_packages: bool,
 
+    /// Allow uv to uninstall packages that are managed by the system package manager.
+    ///
+    /// On Debian and Ubuntu, packages installed via `apt` live in a `dist-packages` directory
+    /// (e.g., `/usr/lib/python3/dist-packages`) that is owned by the system package manager. By
+    /// default, uv refuses to uninstall such packages, even with `--break-system-packages`.
+    #[arg(long)]
+    pub allow_system_managed: bool,
+
+    /// Only uninstall packages that were explicitly requested.
+    ///
+    /// Installers mark explicitly requested packages with a `REQUESTED` file in their `.dist-info`
+    /// directory. With this flag, any of the given packages that were instead installed as a
+    /// dependency of another package are left in place.
+    #[arg(long)]
+    pub requested_only: bool,
+
     /// Uninstall packages from the specified `--target` directory.
     #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
     pub target: Option<PathBuf>,
@@ -2579,6 +3028,13 @@ pub struct PipUninstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Report the installed packages whose dependencies would be missing after uninstalling.
+    ///
+    /// Like `--dry-run`, the environment is not modified. Packages that depend on the packages
+    /// to be uninstalled are listed, but are not uninstalled themselves.
+    #[arg(long)]
+    pub simulate_deps: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipUninstallCompatArgs,
 }
@@ -2793,7 +3249,7 @@ pub struct PipCheckArgs {
     ///
     /// When targeting Android, the default minimum Android API level is `24`. Use
     /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
-    #[arg(long)]
+    #[arg(long, alias = "platform")]
     pub python_platform: Option<TargetTriple>,
 }
 
@@ -3249,6 +3705,9 @@ pub struct VenvArgs {
     #[arg(long, overrides_with("relocatable"), hide = true)]
     pub no_relocatable: bool,
 
+    #[command(flatten)]
+    pub sync_args: VenvSyncArgs,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -3327,6 +3786,79 @@ pub struct VenvArgs {
     pub compat_args: compat::VenvCompatArgs,
 }
 
+/// Arguments for syncing the project into the virtual environment created by `uv venv`.
+///
+/// The flags mirror those of `uv sync`, and are resolved in the same way.
+#[derive(Args)]
+pub struct VenvSyncArgs {
+    /// Sync the project into the virtual environment after creating it.
+    ///
+    /// Equivalent to running `uv sync` after `uv venv`, but performed in a single invocation. The
+    /// project's lockfile is created or updated as needed, as with `uv sync`.
+    ///
+    /// Requires a project; the virtual environment is always created at the project environment
+    /// path.
+    #[arg(long, conflicts_with_all = ["path", "no_project"])]
+    pub sync: bool,
+
+    /// Remove the virtual environment if the sync fails.
+    ///
+    /// Existing environments that are retained via `--allow-existing` are never removed.
+    #[arg(long, requires = "sync")]
+    pub rm_on_failure: bool,
+
+    /// Include optional dependencies from the specified extra name when syncing.
+    ///
+    /// May be provided more than once.
+    #[arg(
+        long,
+        requires = "sync",
+        conflicts_with = "all_extras",
+        value_delimiter = ',',
+        value_parser = extra_name_with_clap_error,
+        value_hint = ValueHint::Other,
+    )]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies when syncing.
+    #[arg(long, requires = "sync", conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Disable the development dependency group when syncing [env: UV_NO_DEV=]
+    ///
+    /// This option is an alias of `--no-group dev`.
+    #[arg(long, requires = "sync", value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_dev: bool,
+
+    /// Include dependencies from the specified dependency group when syncing.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "sync", conflicts_with = "only_group", value_hint = ValueHint::Other)]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group when syncing [env: `UV_NO_GROUP`=]
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "sync", value_delimiter = ' ', value_hint = ValueHint::Other)]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups when syncing.
+    #[arg(long, requires = "sync")]
+    pub no_default_groups: bool,
+
+    /// Only include dependencies from the specified dependency group when syncing.
+    ///
+    /// The project and its dependencies will be omitted.
+    ///
+    /// May be provided multiple times. Implies `--no-default-groups`.
+    #[arg(long, requires = "sync", conflicts_with_all = ["group", "all_groups"], value_hint = ValueHint::Other)]
+    pub only_group: Vec<GroupName>,
+
+    /// Include dependencies from all dependency groups when syncing.
+    #[arg(long, requires = "sync", conflicts_with = "only_group")]
+    pub all_groups: bool,
+}
+
 #[derive(Parser, Debug, Clone)]
 pub enum ExternalCommand {
     #[command(external_subcommand)]
@@ -3371,6 +3903,20 @@ pub enum AuthorFrom {
     None,
 }
 
+/// The starter content for a new script created with `uv init --script`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ScriptTemplate {
+    /// A `main` function that prints a greeting.
+    #[default]
+    Default,
+    /// A command-line interface built with `argparse`.
+    Cli,
+    /// A script split into `# %%` cells, as exported from a notebook.
+    NotebookExport,
+    /// A FastAPI application served with `uvicorn`.
+    Fastapi,
+}
+
 #[derive(Args)]
 pub struct InitArgs {
     /// The path to use for the project/script.
@@ -3455,9 +4001,34 @@ pub struct InitArgs {
     ///
     /// By default, adds a requirement on the system Python version; use `--python` to specify an
     /// alternative Python version requirement.
+    ///
+    /// New scripts start with a `uv run --script` shebang and, on Unix, are made executable.
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Add the given requirements to the script's inline metadata.
+    ///
+    /// Accepts a comma-separated list of requirements, and may be provided multiple times.
+    #[arg(long, requires = "script", value_hint = ValueHint::Other)]
+    pub dependencies: Vec<comma::CommaSeparatedRequirements>,
+
+    /// The starter content to use for the script.
+    ///
+    /// Templates may add their own requirements to the script's inline metadata, e.g., `fastapi`
+    /// adds `fastapi` and `uvicorn`.
+    #[arg(long, value_enum, requires = "script", conflicts_with_all = ["bare", "convert"])]
+    pub template: Option<ScriptTemplate>,
+
+    /// Overwrite the script if the file already exists.
+    #[arg(long, requires = "script", conflicts_with = "convert")]
+    pub force: bool,
+
+    /// Add inline metadata to an existing script, preserving its contents.
+    ///
+    /// By default, `uv init --script` refuses to modify an existing file.
+    #[arg(long, requires = "script")]
+    pub convert: bool,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description", value_hint = ValueHint::Other)]
     pub description: Option<String>,
@@ -3520,6 +4091,38 @@ pub struct InitArgs {
     #[arg(long, alias = "no-project")]
     pub no_workspace: bool,
 
+    /// Add a dependency on the given workspace member(s) to the new project.
+    ///
+    /// Each dependency is added to `project.dependencies` with a `{ workspace = true }` source.
+    /// The named packages must be members of the enclosing workspace.
+    ///
+    /// Accepts a comma-separated list of package names, and may be provided multiple times.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        conflicts_with_all = ["script", "no_workspace"],
+        value_hint = ValueHint::Other,
+    )]
+    pub depends_on: Vec<PackageName>,
+
+    /// Avoid updating the workspace lockfile after adding the project to a workspace.
+    ///
+    /// By default, if the project is added to a workspace with an existing `uv.lock`, the
+    /// lockfile is updated to include the new member.
+    #[arg(long, conflicts_with = "script")]
+    pub no_lock: bool,
+
+    /// Infer the minimum supported Python version from the existing codebase.
+    ///
+    /// Scans the Python files in the project directory for syntax that requires a newer Python
+    /// version (e.g., `match` statements or type parameter syntax), and reads any `python_requires`
+    /// or trove classifiers declared in a `setup.cfg` or `setup.py`. The lowest compatible version
+    /// is written to the `requires-python` field, along with a justification.
+    ///
+    /// The inference is only a suggestion; provide `--python` to use a specific version instead.
+    #[arg(long, conflicts_with = "script", help_heading = "Python options")]
+    pub infer_requires_python: bool,
+
     /// The Python interpreter to use to determine the minimum supported Python version.
     ///
     /// See `uv help python` to view supported request formats.
@@ -3629,6 +4232,12 @@ pub struct RunArgs {
     #[arg(short, long, conflicts_with_all = ["script", "gui_script"])]
     pub module: bool,
 
+    /// Run a snippet of Python code.
+    ///
+    /// Equivalent to `python -c <code>`. Any further arguments are available in `sys.argv[1:]`.
+    #[arg(short = 'c', long = "code", conflicts_with_all = ["module", "script", "gui_script"])]
+    pub code: bool,
+
     /// Only include the development dependency group.
     ///
     /// The project and its dependencies will be omitted.
@@ -3770,6 +4379,13 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Run a remote script without prompting for confirmation.
+    ///
+    /// By default, running a script from a URL requires confirmation, unless the URL pins the
+    /// script's contents with a hash fragment, e.g., `https://example.com/script.py#sha256=...`.
+    #[arg(long)]
+    pub yes: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3867,7 +4483,7 @@ pub struct RunArgs {
     pub python_platform: Option<TargetTriple>,
 }
 
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct SyncArgs {
     /// Include optional dependencies from the specified extra name.
     ///
@@ -3987,8 +4603,9 @@ pub struct SyncArgs {
 
     /// Do not remove extraneous packages present in the environment.
     ///
-    /// When enabled, uv will make the minimum necessary changes to satisfy the requirements.
-    /// By default, syncing will remove any extraneous packages from the environment
+    /// When enabled, uv will make the minimum necessary changes to satisfy the requirements, and
+    /// will list any extraneous packages that were retained. By default, syncing will remove any
+    /// extraneous packages from the environment, unless `sync-exact = false` is set.
     #[arg(long, overrides_with("exact"), alias = "no-exact")]
     pub inexact: bool,
 
@@ -3996,6 +4613,13 @@ pub struct SyncArgs {
     #[arg(long, overrides_with("inexact"), hide = true)]
     pub exact: bool,
 
+    /// Remove the given extraneous package(s) from the environment, retaining any others.
+    ///
+    /// Only applies to inexact syncs (e.g., with `--inexact`), as exact syncs remove all
+    /// extraneous packages.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub remove_extraneous: Vec<PackageName>,
+
     /// Sync dependencies to the active virtual environment.
     ///
     /// Instead of creating or updating the virtual environment for the project or script, the
@@ -4097,6 +4721,16 @@ pub struct SyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Allow locked packages to be resolved from a different index than the one recorded in the
+    /// lockfile.
+    ///
+    /// By default, if updating the lockfile would resolve a locked version of a package from a
+    /// different index (e.g., because the locked index is unreachable and another index provides
+    /// the same version), uv will exit with an error rather than installing the package from the
+    /// other index.
+    #[arg(long, conflicts_with_all = ["locked", "frozen"])]
+    pub allow_index_fallback: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4243,6 +4877,27 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Display the forks of the universal resolution.
+    ///
+    /// After locking, uv will print the marker expression of each resolver fork, along with the
+    /// packages whose versions differ between forks. This can be used to understand why the
+    /// lockfile contains multiple versions of a package, e.g., due to the `fork-strategy`.
+    #[arg(long)]
+    pub show_forks: bool,
+
+    /// Re-download the artifacts for any direct URL dependencies, and record their current hashes.
+    ///
+    /// By default, uv reuses the hashes recorded in the lockfile for direct URL dependencies, such
+    /// that `uv sync` fails if the remote artifact changes. Use this option to intentionally accept
+    /// a changed artifact.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "locked"
+    )]
+    pub refresh_url_hashes: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4314,6 +4969,9 @@ pub struct AddArgs {
     /// requirement that's installed. The constraints will _not_ be added to the project's
     /// `pyproject.toml` file, but _will_ be respected during dependency resolution.
     ///
+    /// When used with `--script`, the constraints are recorded in the script's inline
+    /// `tool.uv.constraint-dependencies`, such that they're respected in subsequent runs.
+    ///
     /// This is equivalent to pip's `--constraint` option.
     #[arg(
         long,
@@ -4871,7 +5529,8 @@ pub struct TreeArgs {
 pub struct ExportArgs {
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 JSON, and conda
+    /// `environment.yml` output formats.
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
@@ -5005,6 +5664,14 @@ pub struct ExportArgs {
     #[arg(long, overrides_with("emit_find_links"), hide = true)]
     pub no_emit_find_links: bool,
 
+    /// Fail if any package can't be represented in the output format.
+    ///
+    /// By default, packages that can't be represented (i.e., editable and local path dependencies)
+    /// are omitted from `environment.yml` output with a warning. Only supported when exporting to
+    /// `environment.yml`.
+    #[arg(long)]
+    pub strict: bool,
+
     /// Export any non-editable dependencies, including the project and any workspace members, as
     /// editable.
     #[arg(long, overrides_with = "no_editable", hide = true)]
@@ -5497,10 +6164,42 @@ pub struct AuditArgs {
     #[arg(long, conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Audit the packages installed in the current environment, rather than the lockfile.
+    ///
+    /// The environment is discovered in the same way as for `uv pip list`, i.e., the active
+    /// virtual environment or a `.venv` in the current directory or any parent directory.
+    ///
+    /// Editable installations are excluded, as are checks for adverse project statuses, since
+    /// the index from which a package was installed is not known.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "locked",
+            "frozen",
+            "script",
+            "python_version",
+            "python_platform",
+            "no_extra",
+            "no_dev",
+            "no_group",
+            "only_group",
+            "only_dev",
+        ]
+    )]
+    pub installed: bool,
+
     /// Select the output format.
     #[arg(long, value_enum, default_value_t = AuditOutputFormat::default())]
     pub output_format: AuditOutputFormat,
 
+    /// The minimum severity of a vulnerability that causes the audit to fail.
+    ///
+    /// By default, uv exits with a non-zero status if any vulnerability is found. With `high` or
+    /// `critical`, vulnerabilities below that severity are still reported, but don't cause a
+    /// failure. Vulnerabilities without a known severity only cause a failure with `any`.
+    #[arg(long, value_enum, default_value_t = AuditFailOn::default())]
+    pub fail_on: AuditFailOn,
+
     #[command(flatten)]
     pub build: BuildOptionsArgs,
 
@@ -5571,6 +6270,17 @@ pub struct AuditArgs {
     /// format was requested by `--service-format`.
     #[arg(long, value_hint = ValueHint::Url)]
     pub service_url: Option<String>,
+
+    /// Read vulnerability records from a local snapshot, rather than querying the vulnerability
+    /// service.
+    ///
+    /// For OSV, the snapshot is a directory of OSV records in JSON format, e.g., as extracted from
+    /// <https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip>.
+    ///
+    /// Since no requests are made to the vulnerability service, a snapshot allows auditing with
+    /// `--offline`.
+    #[arg(long, value_hint = ValueHint::DirPath, conflicts_with = "service_url")]
+    pub service_snapshot: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -6096,6 +6806,13 @@ pub struct ToolListArgs {
     #[arg(long)]
     pub show_python: bool,
 
+    /// Whether to display the resolver and installer options stored for each tool.
+    ///
+    /// These options are recorded when the tool is installed and are re-applied when the tool is
+    /// upgraded or reinstalled.
+    #[arg(long)]
+    pub show_options: bool,
+
     /// List outdated tools.
     ///
     /// The latest version of each tool will be shown alongside the installed version. Up-to-date
@@ -6324,6 +7041,19 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    #[command(flatten)]
+    pub prefetch: PrefetchArgs,
+
+    /// Disable the reuse of cached resolutions.
+    ///
+    /// By default, uv caches the result of each resolution, keyed by the inputs to the resolver
+    /// (e.g., the requirements, constraints, indexes, and settings). If the inputs are unchanged,
+    /// and the index responses for the resolved packages haven't been updated since, the cached
+    /// resolution is reused. Cached resolutions are ignored when the cache is refreshed (e.g.,
+    /// with `--refresh`) or packages are upgraded (e.g., with `--upgrade`).
+    #[arg(long, help_heading = "Resolver options")]
+    pub no_resolution_cache: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6565,6 +7295,9 @@ pub enum PythonCommand {
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 
+    /// Inspect the Python executables installed by uv.
+    Shims(PythonShimsNamespace),
+
     /// Ensure that the Python executable directory is on the `PATH`.
     ///
     /// If the Python executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -6579,6 +7312,21 @@ pub enum PythonCommand {
     UpdateShell,
 }
 
+#[derive(Args)]
+pub struct PythonShimsNamespace {
+    #[command(subcommand)]
+    pub command: PythonShimsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum PythonShimsCommand {
+    /// List the Python executables installed by uv.
+    ///
+    /// Displays each executable (e.g., `python3`), the managed Python installation it links to,
+    /// and whether another executable earlier on the `PATH` shadows it, e.g., a pyenv or asdf shim.
+    List,
+}
+
 #[derive(Args)]
 pub struct PythonListArgs {
     /// A Python request to filter by.
@@ -6622,6 +7370,14 @@ pub struct PythonListArgs {
     #[arg(long)]
     pub show_urls: bool,
 
+    /// Select the order in which Python versions are listed.
+    #[arg(long, value_enum, default_value_t = PythonListSort::default())]
+    pub sort: PythonListSort,
+
+    /// Reverse the order in which Python versions are listed.
+    #[arg(long)]
+    pub reverse: bool,
+
     /// Select the output format.
     #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
     pub output_format: PythonListFormat,
@@ -6784,6 +7540,15 @@ pub struct PythonInstallArgs {
     #[arg(long, short = 'U')]
     pub upgrade: bool,
 
+    /// Exit successfully without any network access if the requested versions are already
+    /// installed.
+    ///
+    /// By default, uv fetches the list of available Python downloads before determining whether the
+    /// requested versions are installed. With `--skip-if-present`, uv first checks the existing
+    /// managed installations and, if every request is satisfied, exits immediately.
+    #[arg(long, conflicts_with_all = ["reinstall", "force", "upgrade"])]
+    pub skip_if_present: bool,
+
     /// Use as the default Python version.
     ///
     /// By default, only a `python{major}.{minor}` executable is installed, e.g., `python3.10`. When
@@ -6798,6 +7563,21 @@ pub struct PythonInstallArgs {
     #[arg(long, conflicts_with("no_bin"))]
     pub default: bool,
 
+    /// Skip validating newly installed Python versions.
+    ///
+    /// By default, uv runs each newly installed interpreter to ensure the standard library modules
+    /// that depend on system libraries (e.g., `ssl`, `zlib`, and `ctypes`) can be imported, and
+    /// reports any shared libraries that are missing from the system.
+    #[arg(long)]
+    pub no_verify: bool,
+
+    /// Select the output format.
+    ///
+    /// With `json`, uv writes progress events for each download and extraction to stdout as
+    /// newline-delimited JSON objects, followed by a final event summarizing the changes.
+    #[arg(long, value_enum, default_value_t = PythonInstallFormat::default())]
+    pub output_format: PythonInstallFormat,
+
     #[command(flatten)]
     pub compile_bytecode: PythonInstallCompileBytecodeArgs,
 }
@@ -6861,6 +7641,14 @@ pub struct PythonUpgradeArgs {
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
 
+    /// Skip validating newly installed Python versions.
+    ///
+    /// By default, uv runs each upgraded interpreter to ensure the standard library modules that
+    /// depend on system libraries (e.g., `ssl`, `zlib`, and `ctypes`) can be imported, and reports
+    /// any shared libraries that are missing from the system.
+    #[arg(long)]
+    pub no_verify: bool,
+
     #[command(flatten)]
     pub compile_bytecode: PythonInstallCompileBytecodeArgs,
 }
@@ -6891,6 +7679,20 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Uninstall all managed Python versions older than the given version, e.g., `3.11`.
+    ///
+    /// Applies to managed installations of every implementation (e.g., CPython and PyPy) based on
+    /// their Python version. System interpreters are never removed.
+    #[arg(long, conflicts_with_all = ["targets", "all"], value_hint = ValueHint::Other)]
+    pub older_than: Option<PythonVersion>,
+
+    /// Restore the `python` and `python3` executables replaced by `uv python install --default`.
+    ///
+    /// Each executable is restored to its state before uv first replaced it, or removed if it did
+    /// not previously exist. Python installations are not uninstalled.
+    #[arg(long, conflicts_with_all = ["targets", "all", "older_than"])]
+    pub default_shims: bool,
 }
 
 #[derive(Args)]
@@ -6952,6 +7754,12 @@ pub struct PythonFindArgs {
     #[arg(long)]
     pub resolve_links: bool,
 
+    /// Show all Python interpreters that satisfy the request, rather than only the first.
+    ///
+    /// Interpreters are shown one per line, in the order in which uv would consider them.
+    #[arg(long, conflicts_with = "script")]
+    pub all: bool,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
@@ -7121,6 +7929,13 @@ pub enum AuthHelperCommand {
     Get,
 }
 
+#[derive(Args)]
+pub struct CompleteArgs {
+    /// The words on the command line, up to and including the word being completed.
+    #[arg(last = true, allow_hyphen_values = true)]
+    pub words: Vec<String>,
+}
+
 #[derive(Args)]
 pub struct GenerateShellCompletionArgs {
     /// The shell to generate the completion script for
@@ -7159,7 +7974,7 @@ pub struct GenerateShellCompletionArgs {
     pub version: bool,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone, Default)]
 pub struct IndexArgs {
     /// The URLs to use when resolving dependencies, in addition to the default index.
     ///
@@ -7263,7 +8078,7 @@ pub struct IndexArgs {
     pub no_index: bool,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone, Default)]
 pub struct RefreshArgs {
     /// Refresh all cached data.
     #[arg(long, overrides_with("no_refresh"), help_heading = "Cache options")]
@@ -7282,7 +8097,21 @@ pub struct RefreshArgs {
     refresh_package: Vec<PackageName>,
 }
 
-#[derive(Args)]
+#[derive(Args, Clone, Default)]
+pub struct PrefetchArgs {
+    /// Disable prefetching of package metadata during resolution.
+    ///
+    /// By default, uv fetches the metadata for the likely versions of the packages it needs in
+    /// parallel, along with batches of further versions for packages that require backtracking.
+    /// Disabling prefetching reduces the number of requests made to the index, at the cost of
+    /// slower resolution.
+    ///
+    /// Equivalent to setting `prefetch-limit = 0`.
+    #[arg(long, help_heading = "Resolver options")]
+    no_prefetch: bool,
+}
+
+#[derive(Args, Default)]
 pub struct BuildOptionsArgs {
     /// Don't build source distributions.
     ///
@@ -7646,6 +8475,19 @@ pub struct ResolverArgs {
     )]
     fork_strategy: Option<ForkStrategy>,
 
+    #[command(flatten)]
+    prefetch: PrefetchArgs,
+
+    /// Disable the reuse of cached resolutions.
+    ///
+    /// By default, uv caches the result of each resolution, keyed by the inputs to the resolver
+    /// (e.g., the requirements, constraints, indexes, and settings). If the inputs are unchanged,
+    /// and the index responses for the resolved packages haven't been updated since, the cached
+    /// resolution is reused. Cached resolutions are ignored when the cache is refreshed (e.g.,
+    /// with `--refresh`) or packages are upgraded (e.g., with `--upgrade`).
+    #[arg(long, help_heading = "Resolver options")]
+    no_resolution_cache: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -7761,7 +8603,7 @@ pub struct ResolverArgs {
 }
 
 /// Arguments that are used by commands that need to resolve and install packages.
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct ResolverInstallerArgs {
     #[command(flatten)]
     pub index_args: IndexArgs,
@@ -7891,6 +8733,19 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    #[command(flatten)]
+    pub prefetch: PrefetchArgs,
+
+    /// Disable the reuse of cached resolutions.
+    ///
+    /// By default, uv caches the result of each resolution, keyed by the inputs to the resolver
+    /// (e.g., the requirements, constraints, indexes, and settings). If the inputs are unchanged,
+    /// and the index responses for the resolved packages haven't been updated since, the cached
+    /// resolution is reused. Cached resolutions are ignored when the cache is refreshed (e.g.,
+    /// with `--refresh`) or packages are upgraded (e.g., with `--upgrade`).
+    #[arg(long, help_heading = "Resolver options")]
+    pub no_resolution_cache: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -8293,6 +9148,14 @@ pub enum WorkspaceCommand {
     ///
     /// Displays newline separated names of workspace members.
     List(WorkspaceListArgs),
+    /// Validate the members of a workspace.
+    ///
+    /// Reports members with invalid `pyproject.toml` files, members that share a package name, and
+    /// dependency cycles between members (via `workspace = true` sources), without resolving any
+    /// dependencies.
+    ///
+    /// Exits with a non-zero status if any problems are found.
+    Check,
 }
 #[derive(Args)]
 pub struct MetadataArgs {
@@ -10,8 +10,8 @@ use uv_settings::{Combine, EnvFlag, PipOptions, ResolverInstallerOptions, Resolv
 use uv_warnings::owo_colors::OwoColorize;
 
 use crate::{
-    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, RefreshArgs, ResolverArgs,
-    ResolverInstallerArgs,
+    BuildOptionsArgs, FetchArgs, IndexArgs, InstallerArgs, Maybe, PrefetchArgs, RefreshArgs,
+    ResolverArgs, ResolverInstallerArgs,
 };
 
 /// Given a boolean flag pair (like `--upgrade` and `--no-upgrade`), resolve the value of the flag.
@@ -224,6 +224,13 @@ impl From<RefreshArgs> for Refresh {
     }
 }
 
+impl PrefetchArgs {
+    /// The prefetch limit implied by the arguments, if any.
+    pub fn prefetch_limit(&self) -> Option<usize> {
+        if self.no_prefetch { Some(0) } else { None }
+    }
+}
+
 /// Extract the `--index` and `--default-index` values from [`IndexArgs`].
 pub fn indexes_from_args(
     default_index: Option<&Maybe<Index>>,
@@ -259,6 +266,8 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            prefetch,
+            no_resolution_cache: _,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -288,6 +297,7 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            prefetch_limit: prefetch.prefetch_limit(),
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -383,6 +393,8 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            prefetch,
+            no_resolution_cache: _,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -421,6 +433,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            prefetch_limit: prefetch.prefetch_limit(),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -512,6 +525,8 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        prefetch,
+        no_resolution_cache,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -570,6 +585,12 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        prefetch_limit: prefetch.prefetch_limit(),
+        resolution_cache: if no_resolution_cache {
+            Some(false)
+        } else {
+            None
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -642,6 +663,8 @@ pub fn resolver_installer_options_with_indexes(
         prerelease,
         pre,
         fork_strategy,
+        prefetch,
+        no_resolution_cache,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -703,6 +726,12 @@ pub fn resolver_installer_options_with_indexes(
             prerelease
         },
         fork_strategy,
+        prefetch_limit: prefetch.prefetch_limit(),
+        resolution_cache: if no_resolution_cache {
+            Some(false)
+        } else {
+            None
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::env;
 use std::fmt::{Debug, Write};
 use std::num::ParseIntError;
@@ -26,7 +27,7 @@ use uv_auth::{
 };
 use uv_configuration::ProxyUrlKind;
 use uv_configuration::{KeyringProviderType, ProxyUrl, TrustedHost};
-use uv_distribution_types::IndexCredentialsError;
+use uv_distribution_types::{IndexCredentialsError, TlsVersion};
 use uv_git::GitHttpSettings;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -40,6 +41,9 @@ use uv_warnings::warn_user_once;
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
 use crate::tls::{Certificates, read_identity};
+use crate::tls_policy::{
+    TlsMiddleware, TlsPolicy, TlsProbe, required_tls_version, reqwest_tls_version,
+};
 use crate::{Connectivity, RetriableError, RetryState, UvRetryableStrategy};
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -100,6 +104,8 @@ pub struct BaseClientBuilder<'a> {
     auth_integration: AuthIntegration,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
+    /// Whether to cache credentials discovered during requests.
+    cache_credentials: bool,
     indexes: Indexes,
     read_timeout: Duration,
     connect_timeout: Duration,
@@ -121,6 +127,12 @@ pub struct BaseClientBuilder<'a> {
     client_name: Option<&'static str>,
     /// Whether to disable retry delays (for testing).
     no_retry_delay: bool,
+    /// The minimum TLS version to accept for all hosts.
+    min_tls_version: Option<TlsVersion>,
+    /// Stricter minimum TLS versions for the hosts of individual indexes.
+    tls_policies: Vec<TlsPolicy>,
+    /// Whether to log the negotiated TLS parameters for each host.
+    tls_info: bool,
 }
 
 /// The policy for handling HTTP redirects.
@@ -171,6 +183,7 @@ impl Default for BaseClientBuilder<'_> {
             platform: None,
             auth_integration: AuthIntegration::default(),
             credentials_cache: Arc::new(CredentialsCache::default()),
+            cache_credentials: true,
             indexes: Indexes::new(),
             read_timeout: DEFAULT_READ_TIMEOUT,
             connect_timeout: DEFAULT_CONNECT_TIMEOUT,
@@ -185,6 +198,9 @@ impl Default for BaseClientBuilder<'_> {
             subcommand: None,
             client_name: None,
             no_retry_delay: env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some(),
+            min_tls_version: None,
+            tls_policies: vec![],
+            tls_info: false,
         }
     }
 }
@@ -228,6 +244,14 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Whether to cache credentials discovered during requests (e.g., from the netrc file or the
+    /// keyring) for reuse in subsequent requests.
+    #[must_use]
+    pub fn cache_credentials(mut self, cache_credentials: bool) -> Self {
+        self.cache_credentials = cache_credentials;
+        self
+    }
+
     #[must_use]
     pub fn allow_insecure_host(mut self, allow_insecure_host: Vec<TrustedHost>) -> Self {
         self.allow_insecure_host = allow_insecure_host;
@@ -287,6 +311,24 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn min_tls_version(mut self, min_tls_version: Option<TlsVersion>) -> Self {
+        self.min_tls_version = min_tls_version;
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn tls_policies(mut self, tls_policies: Vec<TlsPolicy>) -> Self {
+        self.tls_policies = tls_policies;
+        self
+    }
+
+    #[must_use]
+    pub fn tls_info(mut self, tls_info: bool) -> Self {
+        self.tls_info = tls_info;
+        self
+    }
+
     #[must_use]
     pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
         self.read_timeout = read_timeout;
@@ -404,19 +446,58 @@ impl<'a> BaseClientBuilder<'a> {
         // Use the custom client if provided, otherwise create a new one
         let (raw_client, raw_dangerous_client) = match &self.custom_client {
             Some(client) => (client.clone(), client.clone()),
-            None => {
-                self.create_secure_and_insecure_clients(self.read_timeout, self.connect_timeout)?
-            }
+            None => self.create_secure_and_insecure_clients(
+                self.read_timeout,
+                self.connect_timeout,
+                self.min_tls_version,
+            )?,
+        };
+
+        // Create the clients used to explain TLS version failures and, if requested, to log the
+        // negotiated TLS version for each host.
+        let tls_probe = if self.custom_client.is_none()
+            && (self.tls_info || self.min_tls_version.is_some() || !self.tls_policies.is_empty())
+        {
+            Some(self.create_tls_probe()?)
+        } else {
+            None
+        };
+
+        // Create dedicated clients for any hosts that require a stricter minimum TLS version.
+        let strict_clients = if self.custom_client.is_some() {
+            vec![]
+        } else {
+            self.tls_policies
+                .iter()
+                .map(TlsPolicy::min_tls_version)
+                .filter(|version| Some(*version) > self.min_tls_version)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|version| {
+                    let (raw_client, raw_dangerous_client) = self
+                        .create_secure_and_insecure_clients(
+                            self.read_timeout,
+                            self.connect_timeout,
+                            Some(version),
+                        )?;
+                    Ok(self.strict_client(
+                        version,
+                        raw_client,
+                        raw_dangerous_client,
+                        tls_probe.as_ref(),
+                    ))
+                })
+                .collect::<Result<Vec<_>, ClientBuildError>>()?
         };
 
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_client.clone()),
+            client: self.apply_middleware(raw_client.clone(), tls_probe.as_ref()),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_dangerous_client.clone()),
+            client: self.apply_middleware(raw_dangerous_client.clone(), tls_probe.as_ref()),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -430,22 +511,54 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            strict_clients,
+            min_tls_version: self.min_tls_version,
+            tls_policies: self.tls_policies.clone(),
+            tls_probe,
             read_timeout: self.read_timeout,
             connect_timeout: self.connect_timeout,
             credentials_cache: self.credentials_cache.clone(),
         })
     }
 
+    /// Wrap the clients for hosts that require the given minimum TLS version in middleware.
+    fn strict_client(
+        &self,
+        min_tls_version: TlsVersion,
+        raw_client: Client,
+        raw_dangerous_client: Client,
+        tls_probe: Option<&TlsProbe>,
+    ) -> StrictTlsClient {
+        StrictTlsClient {
+            min_tls_version,
+            client: RedirectClientWithMiddleware {
+                client: self.apply_middleware(raw_client.clone(), tls_probe),
+                redirect_policy: self.redirect_policy,
+                cross_origin_credentials_policy: self.cross_origin_credential_policy,
+            },
+            dangerous_client: RedirectClientWithMiddleware {
+                client: self.apply_middleware(raw_dangerous_client.clone(), tls_probe),
+                redirect_policy: self.redirect_policy,
+                cross_origin_credentials_policy: self.cross_origin_credential_policy,
+            },
+            raw_client,
+            raw_dangerous_client,
+        }
+    }
+
     /// Share the underlying client between two different middleware configurations.
     pub(crate) fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_client.clone()),
+            client: self.apply_middleware(existing.raw_client.clone(), existing.tls_probe.as_ref()),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_dangerous_client.clone()),
+            client: self.apply_middleware(
+                existing.raw_dangerous_client.clone(),
+                existing.tls_probe.as_ref(),
+            ),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -459,6 +572,21 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            strict_clients: existing
+                .strict_clients
+                .iter()
+                .map(|strict| {
+                    self.strict_client(
+                        strict.min_tls_version,
+                        strict.raw_client.clone(),
+                        strict.raw_dangerous_client.clone(),
+                        existing.tls_probe.as_ref(),
+                    )
+                })
+                .collect(),
+            min_tls_version: existing.min_tls_version,
+            tls_policies: existing.tls_policies.clone(),
+            tls_probe: existing.tls_probe.clone(),
             read_timeout: existing.read_timeout,
             connect_timeout: existing.connect_timeout,
             credentials_cache: existing.credentials_cache.clone(),
@@ -469,6 +597,7 @@ impl<'a> BaseClientBuilder<'a> {
         &self,
         read_timeout: Duration,
         connect_timeout: Duration,
+        min_tls_version: Option<TlsVersion>,
     ) -> Result<(Client, Client), ClientBuildError> {
         // Create user agent.
         let mut user_agent_string = format!("uv/{}", version());
@@ -490,6 +619,8 @@ impl<'a> BaseClientBuilder<'a> {
             custom_certs.clone(),
             Security::Secure,
             self.redirect_policy,
+            min_tls_version,
+            None,
         )?;
 
         // Create an insecure client that accepts invalid certificates.
@@ -500,11 +631,14 @@ impl<'a> BaseClientBuilder<'a> {
             custom_certs,
             Security::Insecure,
             self.redirect_policy,
+            min_tls_version,
+            None,
         )?;
 
         Ok((raw_client, raw_dangerous_client))
     }
 
+    #[expect(clippy::too_many_arguments)]
     fn create_client(
         &self,
         user_agent: &str,
@@ -513,6 +647,8 @@ impl<'a> BaseClientBuilder<'a> {
         custom_certs: Option<Vec<Certificate>>,
         security: Security,
         redirect_policy: RedirectPolicy,
+        min_tls_version: Option<TlsVersion>,
+        max_tls_version: Option<TlsVersion>,
     ) -> Result<Client, ClientBuildError> {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -531,6 +667,20 @@ impl<'a> BaseClientBuilder<'a> {
 
         let client_builder = client_builder.tls_backend_rustls();
 
+        // Enforce the minimum TLS version, if any.
+        let client_builder = if let Some(min_tls_version) = min_tls_version {
+            client_builder.tls_version_min(reqwest_tls_version(min_tls_version))
+        } else {
+            client_builder
+        };
+
+        // Enforce the maximum TLS version, if any.
+        let client_builder = if let Some(max_tls_version) = max_tls_version {
+            client_builder.tls_version_max(reqwest_tls_version(max_tls_version))
+        } else {
+            client_builder
+        };
+
         // Configure the certificate source.
         //
         // `SSL_CERT_FILE` and `SSL_CERT_DIR` override the default certificate source when they
@@ -582,7 +732,49 @@ impl<'a> BaseClientBuilder<'a> {
         client_builder.build().map_err(Into::into)
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    /// Create the clients used to determine the TLS versions supported by a host, each restricted
+    /// to a single TLS version, along with the TLS configuration used to determine the parameters
+    /// that a host negotiates.
+    fn create_tls_probe(&self) -> Result<TlsProbe, ClientBuildError> {
+        let user_agent = format!("uv/{}", version());
+        let custom_certs = Certificates::from_env();
+        let clients = [TlsVersion::Tls1_3, TlsVersion::Tls1_2]
+            .into_iter()
+            .map(|tls_version| {
+                let client = self.create_client(
+                    &user_agent,
+                    self.read_timeout,
+                    self.connect_timeout,
+                    custom_certs.as_ref().map(Certificates::to_reqwest_certs),
+                    Security::Secure,
+                    RedirectPolicy::NoRedirect,
+                    Some(tls_version),
+                    Some(tls_version),
+                )?;
+                Ok((tls_version, client))
+            })
+            .collect::<Result<Vec<_>, ClientBuildError>>()?;
+
+        // Trust the same root certificates as the clients.
+        let roots = if let Some(custom_certs) = custom_certs {
+            custom_certs
+        } else if self.system_certs {
+            Certificates::from(rustls_native_certs::load_native_certs())
+        } else {
+            Certificates::webpki_roots()
+        };
+        let config = rustls::ClientConfig::builder()
+            .with_root_certificates(roots.to_root_store())
+            .with_no_client_auth();
+
+        Ok(TlsProbe::new(clients, config, self.connect_timeout))
+    }
+
+    fn apply_middleware(
+        &self,
+        client: Client,
+        tls_probe: Option<&TlsProbe>,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 // Create a base client to using in the authentication middleware.
@@ -633,6 +825,7 @@ impl<'a> BaseClientBuilder<'a> {
                     AuthIntegration::Default => {
                         let mut auth_middleware = AuthMiddleware::new()
                             .with_cache_arc(self.credentials_cache.clone())
+                            .with_cache_credentials(self.cache_credentials)
                             .with_base_client(base_client)
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider())
@@ -645,6 +838,7 @@ impl<'a> BaseClientBuilder<'a> {
                     AuthIntegration::OnlyAuthenticated => {
                         let mut auth_middleware = AuthMiddleware::new()
                             .with_cache_arc(self.credentials_cache.clone())
+                            .with_cache_credentials(self.cache_credentials)
                             .with_base_client(base_client)
                             .with_indexes(self.indexes.clone())
                             .with_keyring(self.keyring.to_provider())
@@ -660,6 +854,16 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Explain TLS version failures and, if requested, log the negotiated TLS version.
+                if let Some(tls_probe) = tls_probe {
+                    client = client.with(TlsMiddleware::new(
+                        self.min_tls_version,
+                        self.tls_policies.clone(),
+                        self.tls_info,
+                        tls_probe.clone(),
+                    ));
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -680,6 +884,14 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for hosts that require a stricter minimum TLS version.
+    strict_clients: Vec<StrictTlsClient>,
+    /// The minimum TLS version to accept for all hosts.
+    min_tls_version: Option<TlsVersion>,
+    /// Stricter minimum TLS versions for the hosts of individual indexes.
+    tls_policies: Vec<TlsPolicy>,
+    /// The clients used to determine the TLS versions supported by a host, if any.
+    tls_probe: Option<TlsProbe>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client read timeout.
@@ -696,6 +908,21 @@ pub struct BaseClient {
     credentials_cache: Arc<CredentialsCache>,
 }
 
+/// The HTTP clients for hosts that require a minimum TLS version stricter than the global minimum.
+#[derive(Debug, Clone)]
+struct StrictTlsClient {
+    /// The minimum TLS version enforced by the clients.
+    min_tls_version: TlsVersion,
+    /// The underlying HTTP client that enforces valid certificates.
+    client: RedirectClientWithMiddleware,
+    /// The underlying HTTP client that accepts invalid certificates.
+    dangerous_client: RedirectClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+    /// The HTTP client that accepts invalid certificates without middleware.
+    raw_dangerous_client: Client,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -707,6 +934,13 @@ enum Security {
 impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
+        if let Some(strict) = self.strict_client(url) {
+            return if self.disable_ssl(url) {
+                &strict.dangerous_client
+            } else {
+                &strict.client
+            };
+        }
         if self.disable_ssl(url) {
             &self.dangerous_client
         } else {
@@ -714,6 +948,18 @@ impl BaseClient {
         }
     }
 
+    /// Selects the clients for hosts that require a stricter minimum TLS version, if any.
+    fn strict_client(&self, url: &DisplaySafeUrl) -> Option<&StrictTlsClient> {
+        if self.strict_clients.is_empty() {
+            return None;
+        }
+        let (min_tls_version, _) =
+            required_tls_version(url, self.min_tls_version, &self.tls_policies)?;
+        self.strict_clients
+            .iter()
+            .find(|strict| strict.min_tls_version == min_tls_version)
+    }
+
     /// Executes a request, applying redirect policy.
     pub async fn execute(&self, req: Request) -> reqwest_middleware::Result<Response> {
         let client = self.for_host(&DisplaySafeUrl::from_url(req.url().clone()));
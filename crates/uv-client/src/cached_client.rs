@@ -177,6 +177,9 @@ pub enum CacheControl {
     AllowStale,
     /// Override the cache control header with a custom value.
     Override(http::HeaderValue),
+    /// Apply `max-age` to the request, such that cached responses older than the given duration
+    /// are revalidated.
+    MaxAge(Duration),
 }
 
 impl From<Freshness> for CacheControl {
@@ -464,11 +467,22 @@ impl CachedClient {
         cached: DataWithCachePolicy,
     ) -> Result<CachedResponse, Error> {
         // Apply the cache control header, if necessary.
-        if matches!(&cache_control, CacheControl::MustRevalidate) {
-            req.headers_mut().insert(
-                http::header::CACHE_CONTROL,
-                http::HeaderValue::from_static("no-cache"),
-            );
+        match &cache_control {
+            CacheControl::MustRevalidate => {
+                req.headers_mut().insert(
+                    http::header::CACHE_CONTROL,
+                    http::HeaderValue::from_static("no-cache"),
+                );
+            }
+            CacheControl::MaxAge(max_age) => {
+                if let Ok(header) =
+                    http::HeaderValue::try_from(format!("max-age={}", max_age.as_secs()))
+                {
+                    req.headers_mut()
+                        .insert(http::header::CACHE_CONTROL, header);
+                }
+            }
+            CacheControl::None | CacheControl::AllowStale | CacheControl::Override(_) => {}
         }
         let url = DisplaySafeUrl::from_url(req.url().clone());
         Ok(match cached.cache_policy.before_request(&mut req) {
@@ -477,7 +491,10 @@ impl CachedClient {
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate | CacheControl::Override(_) => {
+                CacheControl::None
+                | CacheControl::MustRevalidate
+                | CacheControl::Override(_)
+                | CacheControl::MaxAge(_) => {
                     debug!("Found stale response for: {url}");
                     self.send_cached_handle_stale(
                         req,
@@ -408,6 +408,24 @@ pub enum ErrorKind {
     #[error("Package `{0}` was not found in the registry")]
     RemotePackageNotFound(PackageName),
 
+    /// The package is pinned to an index via `pinned-only`, but was requested from another index.
+    #[error(
+        "Package `{0}` was requested from `{1}`, but is pinned to `{2}` (via `pinned-only = true`)"
+    )]
+    PinnedIndexMismatch(PackageName, IndexUrl, IndexUrl),
+
+    /// The package is pinned to multiple indexes via `pinned-only`.
+    #[error(
+        "Package `{0}` is pinned to multiple indexes (via `pinned-only = true`): `{1}` and `{2}`"
+    )]
+    ConflictingPinnedIndexes(PackageName, IndexUrl, IndexUrl),
+
+    /// The package was requested from an index that doesn't serve it, per `only-packages`.
+    #[error(
+        "Package `{0}` was requested from `{1}`, but doesn't match any of the index's `only-packages` patterns"
+    )]
+    PackageNotServedByIndex(PackageName, IndexUrl),
+
     /// The package was not found in the local (file-based) index.
     #[error("Package `{0}` was not found in the local index")]
     LocalPackageNotFound(PackageName),
@@ -534,11 +552,33 @@ impl WrappedReqwestError {
         problem_details: Option<ProblemDetails>,
     ) -> Self {
         Self {
-            error: Self::filter_retries_from_error(error),
+            error: Self::redact_url(Self::filter_retries_from_error(error)),
             problem_details: problem_details.map(Box::new),
         }
     }
 
+    /// Redact any credentials in the URL attached to the underlying [`reqwest::Error`], which is
+    /// included in its `Display` output (e.g., `error sending request for url (...)`).
+    fn redact_url(error: reqwest_middleware::Error) -> reqwest_middleware::Error {
+        match error {
+            reqwest_middleware::Error::Reqwest(mut err) => {
+                if let Some(url) = err.url_mut() {
+                    DisplaySafeUrl::redact(url);
+                }
+                reqwest_middleware::Error::Reqwest(err)
+            }
+            reqwest_middleware::Error::Middleware(mut err) => {
+                if let Some(url) = err
+                    .downcast_mut::<reqwest::Error>()
+                    .and_then(reqwest::Error::url_mut)
+                {
+                    DisplaySafeUrl::redact(url);
+                }
+                reqwest_middleware::Error::Middleware(err)
+            }
+        }
+    }
+
     /// Drop `RetryError::WithRetries` to avoid reporting the number of retries twice.
     ///
     /// We attach the number of errors outside by adding the retry counts from the retry middleware
@@ -625,7 +665,7 @@ impl From<reqwest::Error> for WrappedReqwestError {
     fn from(error: reqwest::Error) -> Self {
         Self {
             // No need to filter retries as this error does not have retries.
-            error: error.into(),
+            error: Self::redact_url(error.into()),
             problem_details: None,
         }
     }
@@ -634,7 +674,7 @@ impl From<reqwest::Error> for WrappedReqwestError {
 impl From<reqwest_middleware::Error> for WrappedReqwestError {
     fn from(error: reqwest_middleware::Error) -> Self {
         Self {
-            error: Self::filter_retries_from_error(error),
+            error: Self::redact_url(Self::filter_retries_from_error(error)),
             problem_details: None,
         }
     }
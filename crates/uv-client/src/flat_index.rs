@@ -2,6 +2,8 @@ use std::path::{Path, PathBuf};
 
 use futures::{FutureExt, StreamExt};
 use reqwest::Response;
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
 use tracing::{Instrument, debug, info_span, warn};
 use url::Url;
 
@@ -9,7 +11,7 @@ use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::cache_digest;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
-use uv_pypi_types::HashDigests;
+use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests};
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
@@ -55,6 +57,44 @@ impl FlatIndexEntry {
     pub fn into_parts(self) -> (DistFilename, File, IndexUrl) {
         (self.filename, self.file, self.index)
     }
+
+    /// Compute the SHA-256 hash and size of the distribution, if it's a local file without any
+    /// known hashes.
+    pub(crate) async fn hash_local(&mut self) -> Result<(), std::io::Error> {
+        if !self.file.hashes.is_empty() {
+            return Ok(());
+        }
+        let Some(path) = self
+            .file
+            .url
+            .to_url()
+            .ok()
+            .filter(|url| url.scheme() == "file")
+            .and_then(|url| url.to_file_path().ok())
+        else {
+            return Ok(());
+        };
+
+        let mut file = fs_err::tokio::File::open(&path).await?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0; 64 * 1024];
+        let mut size = 0;
+        loop {
+            let read = file.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+            size += read as u64;
+        }
+
+        self.file.hashes = HashDigests::from(HashDigest {
+            algorithm: HashAlgorithm::Sha256,
+            digest: format!("{:x}", hasher.finalize()).into(),
+        });
+        self.file.size = Some(size);
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -324,6 +364,7 @@ impl<'a> FlatIndexClient<'a> {
                 dist_info_metadata: false,
                 filename: filename.into(),
                 hashes: HashDigests::empty(),
+                provenance: None,
                 requires_python: None,
                 size: None,
                 upload_time_utc_ms: None,
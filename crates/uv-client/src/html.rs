@@ -284,6 +284,14 @@ impl SimpleDetailHTML {
             None
         };
 
+        // Extract the `provenance` field, which should be set on the `data-provenance` attribute.
+        // See: <https://peps.python.org/pep-0740/>
+        let provenance = attribute(link, "data-provenance").map(|provenance| {
+            html_escape::decode_html_entities(&provenance)
+                .as_ref()
+                .into()
+        });
+
         // Extract the `size` field, which should be set on the `data-size` attribute. This isn't
         // included in PEP 700, which omits the HTML API, but we respect it anyway. Since this
         // field isn't standardized, we discard errors.
@@ -303,6 +311,7 @@ impl SimpleDetailHTML {
             yanked,
             requires_python,
             hashes,
+            provenance,
             filename: filename.into(),
             url: path.into(),
             size,
@@ -445,6 +454,71 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
+                    requires_python: None,
+                    size: None,
+                    upload_time: None,
+                    url: "/whl/Jinja2-3.1.2-py3-none-any.whl",
+                    yanked: None,
+                },
+            ],
+        }
+        "#);
+    }
+
+    #[test]
+    fn parse_provenance() {
+        let text = r#"
+<!DOCTYPE html>
+<html>
+<body>
+<h1>Links for jinja2</h1>
+<a href="/whl/Jinja2-3.1.2-py3-none-any.whl#sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61" data-provenance="https://pypi.org/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance">Jinja2-3.1.2-py3-none-any.whl</a><br/>
+</body>
+</html>
+<!--TIMESTAMP 1703347410-->
+    "#;
+        let base = DisplaySafeUrl::parse("https://download.pytorch.org/whl/jinja2/").unwrap();
+        let result = SimpleDetailHTML::parse(text, &base).unwrap();
+        insta::assert_debug_snapshot!(result, @r#"
+        SimpleDetailHTML {
+            project_status: ProjectStatus {
+                status: Active,
+                reason: None,
+            },
+            base: BaseUrl(
+                DisplaySafeUrl {
+                    scheme: "https",
+                    cannot_be_a_base: false,
+                    username: "",
+                    password: None,
+                    host: Some(
+                        Domain(
+                            "download.pytorch.org",
+                        ),
+                    ),
+                    port: None,
+                    path: "/whl/jinja2/",
+                    query: None,
+                    fragment: None,
+                },
+            ),
+            files: [
+                PypiFile {
+                    core_metadata: None,
+                    filename: "Jinja2-3.1.2-py3-none-any.whl",
+                    hashes: Hashes {
+                        md5: None,
+                        sha256: Some(
+                            "6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61",
+                        ),
+                        sha384: None,
+                        sha512: None,
+                        blake2b: None,
+                    },
+                    provenance: Some(
+                        "https://pypi.org/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance",
+                    ),
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -506,6 +580,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -570,6 +645,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -631,6 +707,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -692,6 +769,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -751,6 +829,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -810,6 +889,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -953,6 +1033,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1012,6 +1093,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1072,6 +1154,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1133,6 +1216,7 @@ mod tests {
                             sha512: None,
                             blake2b: None,
                         },
+                        provenance: None,
                         requires_python: None,
                         size: None,
                         upload_time: None,
@@ -1213,6 +1297,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1229,6 +1314,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1301,6 +1387,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1319,6 +1406,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1337,6 +1425,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1408,6 +1497,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1487,6 +1577,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1507,6 +1598,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1527,6 +1619,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1547,6 +1640,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1567,6 +1661,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1700,6 +1795,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: Some(
                         Ok(
                             VersionSpecifiers(
@@ -1735,6 +1831,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
@@ -1751,6 +1848,7 @@ mod tests {
                         sha512: None,
                         blake2b: None,
                     },
+                    provenance: None,
                     requires_python: None,
                     size: None,
                     upload_time: None,
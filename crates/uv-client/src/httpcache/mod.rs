@@ -302,7 +302,11 @@ impl ArchivedCachePolicy {
     ///
     /// [RFC 9111 S4]: https://www.rfc-editor.org/rfc/rfc9111.html#section-4
     pub(crate) fn before_request(&self, request: &mut reqwest::Request) -> BeforeRequest {
-        let now = SystemTime::now();
+        self.before_request_at(request, SystemTime::now())
+    }
+
+    /// Like [`ArchivedCachePolicy::before_request`], but evaluates freshness at the given time.
+    fn before_request_at(&self, request: &mut reqwest::Request, now: SystemTime) -> BeforeRequest {
         // If the response was never storable, then we just bail out
         // completely.
         if !self.is_storable() {
@@ -1398,3 +1402,62 @@ fn parse_seconds(value: &[u8]) -> Option<u64> {
     }
     std::str::from_utf8(value).ok()?.parse().ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{BeforeRequest, CachePolicyBuilder};
+
+    fn request(cache_control: Option<&'static str>) -> reqwest::Request {
+        let mut request = reqwest::Request::new(
+            http::Method::GET,
+            "https://example.com/simple/example/".parse().unwrap(),
+        );
+        if let Some(cache_control) = cache_control {
+            request.headers_mut().insert(
+                http::header::CACHE_CONTROL,
+                http::HeaderValue::from_static(cache_control),
+            );
+        }
+        request
+    }
+
+    #[test]
+    fn request_max_age_revalidates() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header(http::header::CACHE_CONTROL, "max-age=600")
+                .body("")
+                .unwrap(),
+        );
+        let policy = CachePolicyBuilder::new(&request(None))
+            .build(&response)
+            .to_archived();
+        let now = SystemTime::now();
+
+        // Without a `max-age` on the request, the response is fresh for the server's lifetime.
+        assert!(matches!(
+            policy.before_request_at(&mut request(None), now + Duration::from_secs(120)),
+            BeforeRequest::Fresh
+        ));
+
+        // With a `max-age` on the request, the response is fresh until it elapses...
+        assert!(matches!(
+            policy.before_request_at(
+                &mut request(Some("max-age=60")),
+                now + Duration::from_secs(30)
+            ),
+            BeforeRequest::Fresh
+        ));
+
+        // ...and must be revalidated afterwards.
+        assert!(matches!(
+            policy.before_request_at(
+                &mut request(Some("max-age=60")),
+                now + Duration::from_secs(120)
+            ),
+            BeforeRequest::Stale(_)
+        ));
+    }
+}
@@ -27,3 +27,4 @@ mod remote_metadata;
 mod retry;
 mod rkyvutil;
 mod tls;
+mod tls_policy;
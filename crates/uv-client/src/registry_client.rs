@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, StreamExt, TryStreamExt};
@@ -16,12 +16,13 @@ use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
 
 use uv_auth::{CredentialsCache, Indexes, PyxTokenStore};
-use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
+use uv_cache_key::CanonicalUrl;
 use uv_configuration::IndexStrategy;
 use uv_configuration::KeyringProviderType;
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    BuiltDist, File, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
+    BuiltDist, File, Index, IndexCapabilities, IndexFormat, IndexLocations, IndexMetadataRef,
     IndexStatusCodeDecision, IndexStatusCodeStrategy, IndexUrl, Name,
 };
 use uv_git::{GIT_LFS, GitError, GitHttpSettings, GitResolver, Reporter};
@@ -44,6 +45,7 @@ use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleDetailHTML;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
+use crate::tls_policy::TlsPolicy;
 use crate::{
     BaseClient, CachedClient, Error, ErrorKind, FlatIndexClient, RedirectClientWithMiddleware,
 };
@@ -54,6 +56,7 @@ pub struct RegistryClientBuilder<'a> {
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchStrategy>,
+    index_cache_ttl: Option<Duration>,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -64,6 +67,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_locations: IndexLocations::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            index_cache_ttl: None,
             cache,
             base_client_builder: base_client_builder.redirect(RedirectPolicy::RetriggerMiddleware),
         }
@@ -93,6 +97,14 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    /// Revalidate cached index metadata that is older than the given duration, even if the index
+    /// allows it to be cached for longer.
+    #[must_use]
+    pub fn index_cache_ttl(mut self, index_cache_ttl: Option<Duration>) -> Self {
+        self.index_cache_ttl = index_cache_ttl;
+        self
+    }
+
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
         self.base_client_builder = self.base_client_builder.keyring(keyring_type);
@@ -182,7 +194,8 @@ impl<'a> RegistryClientBuilder<'a> {
         // Wrap in any relevant middleware and handle connectivity.
         let builder = self
             .base_client_builder
-            .indexes(Indexes::from(&self.index_locations));
+            .indexes(Indexes::from(&self.index_locations))
+            .tls_policies(TlsPolicy::from_index_locations(&self.index_locations));
         let client = if let Some(existing) = existing {
             builder.wrap_existing(existing)
         } else {
@@ -199,6 +212,7 @@ impl<'a> RegistryClientBuilder<'a> {
             indexes: self.index_locations,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            index_cache_ttl: self.index_cache_ttl,
             cache: self.cache,
             connectivity,
             client,
@@ -218,6 +232,8 @@ pub struct RegistryClient {
     index_strategy: IndexStrategy,
     /// The strategy to use when selecting a PyTorch backend, if any.
     torch_backend: Option<TorchStrategy>,
+    /// The maximum age of cached index metadata before it's revalidated, if any.
+    index_cache_ttl: Option<Duration>,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -253,6 +269,15 @@ impl RegistryClient {
         self.client.uncached().for_host(url)
     }
 
+    /// Determine the [`CacheControl`] for a Simple API request, applying the index cache TTL to
+    /// cache entries that would otherwise be considered fresh.
+    fn simple_api_cache_control(&self, freshness: Freshness) -> CacheControl {
+        match (CacheControl::from(freshness), self.index_cache_ttl) {
+            (CacheControl::None, Some(index_cache_ttl)) => CacheControl::MaxAge(index_cache_ttl),
+            (cache_control, _) => cache_control,
+        }
+    }
+
     /// Return the [`GitHttpSettings`] for fetching from the given URL.
     pub fn git_http_settings(&self, url: &DisplaySafeUrl) -> GitHttpSettings {
         self.client.uncached().git_http_settings(url)
@@ -291,6 +316,25 @@ impl RegistryClient {
             })
     }
 
+    /// Return the index that the given [`PackageName`] is pinned to via `pinned-only`, if any.
+    fn pinned_index_for(&self, package_name: &PackageName) -> Result<Option<&Index>, Error> {
+        let mut pinned = self.indexes.pinned_indexes_for(package_name);
+        let Some(first) = pinned.next() else {
+            return Ok(None);
+        };
+        if let Some(second) = pinned
+            .find(|index| CanonicalUrl::new(index.url.url()) != CanonicalUrl::new(first.url.url()))
+        {
+            return Err(ErrorKind::ConflictingPinnedIndexes(
+                package_name.clone(),
+                first.url.clone(),
+                second.url.clone(),
+            )
+            .into());
+        }
+        Ok(Some(first))
+    }
+
     /// Return the appropriate [`IndexStrategy`] for the given [`PackageName`].
     fn index_strategy_for(&self, package_name: &PackageName) -> IndexStrategy {
         self.torch_backend
@@ -324,10 +368,39 @@ impl RegistryClient {
             return Err(ErrorKind::NoIndex(package_name.to_string()).into());
         }
 
+        // If the package is pinned to an index via `pinned-only`, it may only be fetched from that
+        // index, regardless of the index strategy.
+        let pinned = self.pinned_index_for(package_name)?;
+
+        // Distributions in a local directory that was explicitly assigned to the package (e.g., via
+        // `tool.uv.sources`) are hashed, such that the hashes can be recorded in the lockfile.
+        let hash_local = index.is_some();
+
         let indexes = if let Some(index) = index {
+            if let Some(pinned) = pinned {
+                if CanonicalUrl::new(pinned.url.url()) != CanonicalUrl::new(index.url.url()) {
+                    return Err(ErrorKind::PinnedIndexMismatch(
+                        package_name.clone(),
+                        index.url.clone(),
+                        pinned.url.clone(),
+                    )
+                    .into());
+                }
+            } else if !self.indexes.serves(index.url, package_name) {
+                return Err(ErrorKind::PackageNotServedByIndex(
+                    package_name.clone(),
+                    index.url.clone(),
+                )
+                .into());
+            }
             Either::Left(std::iter::once(index))
+        } else if let Some(pinned) = pinned {
+            Either::Left(std::iter::once(IndexMetadataRef::from(pinned)))
         } else {
-            Either::Right(self.index_urls_for(package_name))
+            Either::Right(
+                self.index_urls_for(package_name)
+                    .filter(|index| self.indexes.serves(index.url, package_name)),
+            )
         };
 
         let mut results = Vec::new();
@@ -367,7 +440,9 @@ impl RegistryClient {
                             }
                         }
                         IndexFormat::Flat => {
-                            let entries = self.flat_single_index(package_name, index.url).await?;
+                            let entries = self
+                                .flat_single_index(package_name, index.url, hash_local)
+                                .await?;
                             if !entries.is_empty() {
                                 results.push((index.url, MetadataFormat::Flat(entries)));
                                 break;
@@ -402,8 +477,9 @@ impl RegistryClient {
                                 Ok((index.url, metadata.map(MetadataFormat::Simple)))
                             }
                             IndexFormat::Flat => {
-                                let entries =
-                                    self.flat_single_index(package_name, index.url).await?;
+                                let entries = self
+                                    .flat_single_index(package_name, index.url, hash_local)
+                                    .await?;
                                 Ok((index.url, Some(MetadataFormat::Flat(entries))))
                             }
                         }
@@ -441,7 +517,8 @@ impl RegistryClient {
         Ok(futures::stream::iter(self.indexes.flat_indexes())
             .map(async |index| {
                 let _permit = download_concurrency.acquire().await;
-                self.flat_single_index(package_name, index.url()).await
+                self.flat_single_index(package_name, index.url(), false)
+                    .await
             })
             .buffered(8)
             .try_collect::<Vec<_>>()
@@ -452,10 +529,13 @@ impl RegistryClient {
     }
 
     /// Fetch the [`FlatIndexEntry`] entries for a given package from a single `--find-links` index.
+    ///
+    /// If `hash_local` is set, the hashes of any distributions in a local directory are computed.
     async fn flat_single_index(
         &self,
         package_name: &PackageName,
         index: &IndexUrl,
+        hash_local: bool,
     ) -> Result<Vec<FlatIndexEntry>, Error> {
         // Each flat index gets its own slot, so lookups for the same index share a fetch while
         // unrelated indexes can proceed concurrently.
@@ -465,37 +545,45 @@ impl RegistryClient {
         };
         let mut flat_index = flat_index_slot.lock().await;
 
-        if let Some(entries) = flat_index.as_ref() {
-            return Ok(entries.get(package_name).cloned().unwrap_or_default());
-        }
+        if flat_index.is_none() {
+            let client = FlatIndexClient::new(self.cached_client(), self.connectivity, &self.cache);
 
-        let client = FlatIndexClient::new(self.cached_client(), self.connectivity, &self.cache);
+            // Fetch the entries for the index.
+            let (entries, _) = client
+                .fetch_index(index)
+                .await
+                .map_err(ErrorKind::Flat)?
+                .into_parts();
+
+            // Index by package name.
+            let mut entries_by_package: FxHashMap<PackageName, Vec<FlatIndexEntry>> =
+                FxHashMap::default();
+            for entry in entries {
+                entries_by_package
+                    .entry(entry.filename().name().clone())
+                    .or_default()
+                    .push(entry);
+            }
 
-        // Fetch the entries for the index.
-        let (entries, _) = client
-            .fetch_index(index)
-            .await
-            .map_err(ErrorKind::Flat)?
-            .into_parts();
-
-        // Index by package name.
-        let mut entries_by_package: FxHashMap<PackageName, Vec<FlatIndexEntry>> =
-            FxHashMap::default();
-        for entry in entries {
-            entries_by_package
-                .entry(entry.filename().name().clone())
-                .or_default()
-                .push(entry);
+            // Write to the cache.
+            *flat_index = Some(entries_by_package);
         }
-        let package_entries = entries_by_package
-            .get(package_name)
-            .cloned()
-            .unwrap_or_default();
 
-        // Write to the cache.
-        *flat_index = Some(entries_by_package);
+        let Some(package_entries) = flat_index
+            .as_mut()
+            .and_then(|entries_by_package| entries_by_package.get_mut(package_name))
+        else {
+            return Ok(Vec::new());
+        };
+
+        // Hash any local distributions, retaining the hashes in the cache.
+        if hash_local && matches!(index, IndexUrl::Path(_)) {
+            for entry in package_entries.iter_mut() {
+                entry.hash_local().await.map_err(ErrorKind::Io)?;
+            }
+        }
 
-        Ok(package_entries)
+        Ok(package_entries.clone())
     }
 
     /// Fetch the [`SimpleDetailMetadata`] from a single index for a given package.
@@ -531,7 +619,7 @@ impl RegistryClient {
                 if let Some(header) = self.indexes.simple_api_cache_control_for(index) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
+                    self.simple_api_cache_control(
                         self.cache
                             .freshness(&cache_entry, Some(package_name), None)
                             .map_err(ErrorKind::Io)?,
@@ -736,6 +824,33 @@ impl RegistryClient {
         OwnedArchive::from_unarchived(&metadata)
     }
 
+    /// Check that the given index is reachable, by sending a `HEAD` request to its root.
+    ///
+    /// Any response other than a server error is considered reachable, since indexes aren't
+    /// required to serve their root (e.g., some respond with a `404` or `405`). Local indexes are
+    /// not checked.
+    pub async fn check_index(&self, index_url: &IndexUrl) -> Result<(), Error> {
+        let url = index_url.url();
+        if !matches!(url.scheme(), "http" | "https") {
+            return Ok(());
+        }
+
+        let start = Instant::now();
+        let response = self
+            .uncached_client(url)
+            .head(Url::from(url.clone()))
+            .send()
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(url.clone(), err, start))?;
+        if response.status().is_server_error()
+            && let Err(err) = response.error_for_status()
+        {
+            return Err(ErrorKind::from_reqwest(url.clone(), err).into());
+        }
+
+        Ok(())
+    }
+
     /// Fetch the list of projects from a Simple API index at a remote URL.
     ///
     /// This fetches the root of a Simple API index (e.g., `https://pypi.org/simple/`)
@@ -791,7 +906,7 @@ impl RegistryClient {
                 if let Some(header) = self.indexes.simple_api_cache_control_for(index) {
                     CacheControl::Override(header)
                 } else {
-                    CacheControl::from(
+                    self.simple_api_cache_control(
                         self.cache
                             .freshness(&cache_entry, None, None)
                             .map_err(ErrorKind::Io)?,
@@ -1299,7 +1414,7 @@ impl RegistryClient {
     }
 
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
-    fn handle_response_errors(&self, err: reqwest::Error) -> std::io::Error {
+    fn handle_response_errors(&self, mut err: reqwest::Error) -> std::io::Error {
         if err.is_timeout() {
             // Assumption: The connect timeout with the 10s default is not the culprit.
             std::io::Error::new(
@@ -1310,6 +1425,10 @@ impl RegistryClient {
                 ),
             )
         } else {
+            // The error's `Display` includes the request URL.
+            if let Some(url) = err.url_mut() {
+                DisplaySafeUrl::redact(url);
+            }
             std::io::Error::other(err)
         }
     }
@@ -1577,6 +1696,7 @@ impl SimpleDetailMetadata {
                                 requires_python: metadata.requires_python,
                                 provides_extra: metadata.provides_extra,
                                 dynamic: false,
+                                license_expression: None,
                             });
                     SimpleDetailMetadatum {
                         version,
@@ -2144,6 +2264,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -2236,6 +2357,7 @@ mod tests {
                                             },
                                         ],
                                     ),
+                                    provenance: None,
                                     requires_python: Some(
                                         VersionSpecifiers(
                                             [
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::error::Error;
 use std::time::{Duration, SystemTime, SystemTimeError};
 use std::{io, iter};
@@ -10,7 +11,6 @@ use reqwest_retry::{
     RetryPolicy, Retryable, RetryableStrategy, default_on_request_error, default_on_request_success,
 };
 use tracing::{debug, trace};
-use url::Url;
 
 use uv_redacted::DisplaySafeUrl;
 
@@ -37,9 +37,15 @@ impl RetryableStrategy for UvRetryableStrategy {
                     let context = iter::successors(err.source(), |&err| err.source())
                         .map(|err| format!("  Caused by: {err}"))
                         .join("\n");
+                    let message = format!("{err}\n{context}");
+                    // The `reqwest` error includes the request URL, credentials and all.
+                    let url = err.url().map(DisplaySafeUrl::ref_cast);
+                    let message = url.map_or(Cow::Borrowed(message.as_str()), |url| {
+                        url.redact_credentials_in(&message)
+                    });
                     debug!(
-                        "Transient request failure for {}, retrying: {err}\n{context}",
-                        err.url().map(Url::as_str).unwrap_or("unknown URL")
+                        "Transient request failure for {}, retrying: {message}",
+                        url.map_or_else(|| "unknown URL".to_string(), ToString::to_string)
                     );
                 }
             }
@@ -130,6 +136,26 @@ impl RetryState {
     }
 }
 
+/// Find the URL of the failed request in an error chain, if any.
+fn find_request_url<'a>(err: &'a (dyn Error + 'static)) -> Option<&'a DisplaySafeUrl> {
+    iter::successors(Some(err), |&err| err.source()).find_map(|source| {
+        let reqwest_err = if let Some(reqwest_err) = source.downcast_ref::<reqwest::Error>() {
+            Some(reqwest_err)
+        } else if let Some(reqwest_middleware::Error::Reqwest(reqwest_err)) =
+            source.downcast_ref::<reqwest_middleware::Error>()
+        {
+            Some(reqwest_err)
+        } else {
+            source
+                .downcast_ref::<WrappedReqwestError>()
+                .and_then(WrappedReqwestError::inner)
+        };
+        reqwest_err
+            .and_then(reqwest::Error::url)
+            .map(DisplaySafeUrl::ref_cast)
+    })
+}
+
 /// Whether the error looks like a network error that should be retried.
 ///
 /// This is an extension over [`reqwest_middleware::default_on_request_failure`], which is missing
@@ -148,7 +174,11 @@ pub fn retryable_on_request_failure(err: &(dyn Error + 'static)) -> Option<Retry
             url = DisplaySafeUrl::from_url(url.clone())
         );
     } else {
-        trace!("Considering retry of error: {err:?}");
+        let message = format!("{err:?}");
+        let message = find_request_url(err).map_or(Cow::Borrowed(message.as_str()), |url| {
+            url.redact_credentials_in(&message)
+        });
+        trace!("Considering retry of error: {message}");
     }
 
     let mut has_known_error = false;
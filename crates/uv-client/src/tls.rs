@@ -420,6 +420,16 @@ impl Certificates {
             .collect()
     }
 
+    /// Convert certificates to a [`rustls::RootCertStore`], skipping any that can't be parsed.
+    pub(crate) fn to_root_store(&self) -> rustls::RootCertStore {
+        let mut store = rustls::RootCertStore::empty();
+        let (_, ignored) = store.add_parsable_certificates(self.0.iter().cloned());
+        if ignored > 0 {
+            debug!("Ignored {ignored} unparsable root certificates");
+        }
+        store
+    }
+
     /// Iterate over raw DER certificates.
     #[cfg(test)]
     fn iter(&self) -> impl Iterator<Item = &CertificateDer<'static>> {
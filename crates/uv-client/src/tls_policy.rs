@@ -0,0 +1,474 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use http::Extensions;
+use reqwest::{Client, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashSet;
+use rustls::{CipherSuite, ClientConfig, ProtocolVersion};
+use rustls_pki_types::ServerName;
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tracing::{debug, trace};
+use url::{Host, Url};
+
+use uv_distribution_types::{IndexLocations, TlsVersion};
+use uv_redacted::DisplaySafeUrl;
+
+/// A minimum TLS version required for requests to an index's host.
+#[derive(Debug, Clone)]
+pub(crate) struct TlsPolicy {
+    /// The name of the index, if any.
+    name: Option<String>,
+    /// The URL of the index, without credentials.
+    url: DisplaySafeUrl,
+    /// The minimum TLS version required by the index.
+    min_tls_version: TlsVersion,
+}
+
+impl TlsPolicy {
+    /// Collect the [`TlsPolicy`] for every index that sets `min-tls-version`.
+    pub(crate) fn from_index_locations(index_locations: &IndexLocations) -> Vec<Self> {
+        index_locations
+            .allowed_indexes()
+            .into_iter()
+            .filter_map(|index| {
+                let min_tls_version = index.min_tls_version()?;
+                Some(Self {
+                    name: index.name.as_ref().map(ToString::to_string),
+                    url: index.url().without_credentials().into_owned(),
+                    min_tls_version,
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn min_tls_version(&self) -> TlsVersion {
+        self.min_tls_version
+    }
+
+    /// Returns `true` if the policy applies to requests to the given URL, i.e., if the URL shares
+    /// an origin with the index.
+    pub(crate) fn matches(&self, url: &Url) -> bool {
+        self.url.scheme() == url.scheme()
+            && self.url.host() == url.host()
+            && self.url.port_or_known_default() == url.port_or_known_default()
+    }
+}
+
+/// Return the strictest minimum TLS version that applies to the given URL, along with the
+/// [`TlsPolicy`] that imposed it (or `None`, if it was imposed by the global setting).
+pub(crate) fn required_tls_version<'a>(
+    url: &Url,
+    min_tls_version: Option<TlsVersion>,
+    policies: &'a [TlsPolicy],
+) -> Option<(TlsVersion, Option<&'a TlsPolicy>)> {
+    let global = min_tls_version.map(|version| (version, None));
+    let index = policies
+        .iter()
+        .filter(|policy| policy.matches(url))
+        .max_by_key(|policy| policy.min_tls_version)
+        .map(|policy| (policy.min_tls_version, Some(policy)));
+    match (global, index) {
+        (Some(global), Some(index)) if index.0 > global.0 => Some(index),
+        (Some(global), _) => Some(global),
+        (None, index) => index,
+    }
+}
+
+pub(crate) fn reqwest_tls_version(version: TlsVersion) -> reqwest::tls::Version {
+    match version {
+        TlsVersion::Tls1_2 => reqwest::tls::Version::TLS_1_2,
+        TlsVersion::Tls1_3 => reqwest::tls::Version::TLS_1_3,
+    }
+}
+
+/// The TLS parameters negotiated with a host.
+#[derive(Debug, Clone, Copy)]
+struct TlsParameters {
+    protocol_version: ProtocolVersion,
+    cipher_suite: CipherSuite,
+}
+
+impl Display for TlsParameters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ({:?})", self.protocol_version, self.cipher_suite)
+    }
+}
+
+/// A set of clients, each restricted to a single TLS version, used to determine the TLS versions
+/// supported by a host, along with a TLS configuration used to determine the parameters that a
+/// host negotiates.
+///
+/// The clients share the proxy, certificate, and timeout configuration of the client that sends
+/// the request, such that the probe reaches the host in the same way. Certificates are verified
+/// as for any other request, and only the origin of the URL is requested, without any credentials.
+#[derive(Debug, Clone)]
+pub(crate) struct TlsProbe {
+    /// The client for each TLS version, from the newest version to the oldest.
+    clients: Vec<(TlsVersion, Client)>,
+    /// The TLS configuration used to perform a handshake with a host, trusting the same root
+    /// certificates as the clients.
+    config: Arc<ClientConfig>,
+    /// The timeout for connecting to a host and completing the handshake.
+    timeout: Duration,
+}
+
+impl TlsProbe {
+    pub(crate) fn new(
+        mut clients: Vec<(TlsVersion, Client)>,
+        config: ClientConfig,
+        timeout: Duration,
+    ) -> Self {
+        clients.sort_by_key(|(version, _)| std::cmp::Reverse(*version));
+        Self {
+            clients,
+            config: Arc::new(config),
+            timeout,
+        }
+    }
+
+    /// Perform a TLS handshake with the host of the given URL, returning the protocol version and
+    /// cipher suite of the established connection.
+    ///
+    /// The server's certificate is verified, as for any other request. Unlike the clients, the
+    /// handshake connects to the host directly, rather than through a proxy.
+    async fn handshake(&self, url: &Url) -> io::Result<TlsParameters> {
+        let port = url.port_or_known_default().unwrap_or(443);
+        let (server_name, host) = match url.host() {
+            Some(Host::Domain(domain)) => (
+                ServerName::try_from(domain.to_string())
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?,
+                domain.to_string(),
+            ),
+            Some(Host::Ipv4(ip)) => (ServerName::IpAddress(IpAddr::V4(ip).into()), ip.to_string()),
+            Some(Host::Ipv6(ip)) => (ServerName::IpAddress(IpAddr::V6(ip).into()), ip.to_string()),
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("URL has no host: {url}"),
+                ));
+            }
+        };
+
+        let connect = async {
+            let stream = TcpStream::connect((host.as_str(), port)).await?;
+            TlsConnector::from(self.config.clone())
+                .connect(server_name, stream)
+                .await
+        };
+        let stream = tokio::time::timeout(self.timeout, connect)
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "TLS handshake timed out"))??;
+
+        let (_, connection) = stream.get_ref();
+        match (
+            connection.protocol_version(),
+            connection.negotiated_cipher_suite(),
+        ) {
+            (Some(protocol_version), Some(cipher_suite)) => Ok(TlsParameters {
+                protocol_version,
+                cipher_suite: cipher_suite.suite(),
+            }),
+            _ => Err(io::Error::other("TLS handshake did not complete")),
+        }
+    }
+
+    /// Determine the newest TLS version that the host of the given URL can negotiate.
+    ///
+    /// Returns `None` if the host can't negotiate any of the supported TLS versions.
+    async fn negotiate(&self, url: &Url) -> Result<Option<TlsVersion>, reqwest::Error> {
+        let mut origin = url.clone();
+        origin.set_path("/");
+        origin.set_query(None);
+        origin.set_fragment(None);
+        let _ = origin.set_username("");
+        let _ = origin.set_password(None);
+
+        for (version, client) in &self.clients {
+            match client.head(origin.clone()).send().await {
+                Ok(_) => return Ok(Some(*version)),
+                Err(err) if is_handshake_error(&err) => {
+                    trace!("Failed to negotiate {version} with {origin}: {err}");
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Returns `true` if the error was caused by a failed TLS handshake, as opposed to, e.g., a
+/// failure to connect to the host or proxy, or an untrusted certificate.
+fn is_handshake_error(err: &reqwest::Error) -> bool {
+    let is_version_error = |err: &rustls::Error| {
+        !matches!(
+            err,
+            rustls::Error::InvalidCertificate(_) | rustls::Error::InvalidCertRevocationList(_)
+        )
+    };
+    let mut source = err.source();
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<rustls::Error>() {
+            return is_version_error(err);
+        }
+        // I/O errors hide the error they wrap from the source chain.
+        if let Some(err) = err
+            .downcast_ref::<io::Error>()
+            .and_then(|err| err.get_ref())
+            .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+        {
+            return is_version_error(err);
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// An error indicating that a host can't negotiate the minimum required TLS version.
+#[derive(Debug)]
+pub(crate) struct TlsVersionError {
+    url: DisplaySafeUrl,
+    policy: Option<TlsPolicy>,
+    /// The newest TLS version the host can negotiate, if any.
+    negotiated: Option<TlsVersion>,
+    required: TlsVersion,
+}
+
+impl Display for TlsVersionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.policy {
+            Some(TlsPolicy {
+                name: Some(name),
+                url,
+                ..
+            }) => write!(f, "Index `{name}` ({url})")?,
+            Some(TlsPolicy { url, .. }) => write!(f, "Index `{url}`")?,
+            None => write!(f, "Host `{}`", self.url.host_str().unwrap_or_default())?,
+        }
+        match self.negotiated {
+            Some(negotiated) => write!(f, " negotiated {negotiated}")?,
+            None => write!(f, " negotiated a version older than TLS 1.2")?,
+        }
+        write!(f, ", but `min-tls-version` requires {}", self.required)
+    }
+}
+
+impl std::error::Error for TlsVersionError {}
+
+/// A middleware that explains connection failures caused by a minimum TLS version and, if
+/// requested, logs the negotiated TLS protocol version and cipher suite for each host.
+pub(crate) struct TlsMiddleware {
+    min_tls_version: Option<TlsVersion>,
+    policies: Vec<TlsPolicy>,
+    tls_info: bool,
+    probe: TlsProbe,
+    /// The origins for which the TLS parameters have already been logged.
+    seen: Mutex<FxHashSet<String>>,
+}
+
+impl TlsMiddleware {
+    pub(crate) fn new(
+        min_tls_version: Option<TlsVersion>,
+        policies: Vec<TlsPolicy>,
+        tls_info: bool,
+        probe: TlsProbe,
+    ) -> Self {
+        Self {
+            min_tls_version,
+            policies,
+            tls_info,
+            probe,
+            seen: Mutex::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for TlsMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if req.url().scheme() != "https" {
+            return next.run(req, extensions).await;
+        }
+
+        let url = req.url().clone();
+        let required = required_tls_version(&url, self.min_tls_version, &self.policies);
+
+        if self.tls_info {
+            let origin = url.origin().ascii_serialization();
+            let first = self
+                .seen
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(origin.clone());
+            if first {
+                match self.probe.handshake(&url).await {
+                    Ok(parameters) => trace!("Negotiated {parameters} with {origin}"),
+                    Err(err) => {
+                        trace!("Failed to determine the TLS parameters for {origin}: {err}");
+                    }
+                }
+            }
+        }
+
+        let result = next.run(req, extensions).await;
+
+        // If the connection failed, determine whether the host is unable to meet the minimum
+        // version.
+        let Some((required, policy)) = required else {
+            return result;
+        };
+        let Err(reqwest_middleware::Error::Reqwest(err)) = &result else {
+            return result;
+        };
+        if !err.is_connect() {
+            return result;
+        }
+        match self.probe.negotiate(&url).await {
+            Ok(negotiated) if negotiated.is_none_or(|version| version < required) => {
+                Err(reqwest_middleware::Error::middleware(TlsVersionError {
+                    url: DisplaySafeUrl::from_url(url),
+                    policy: policy.cloned(),
+                    negotiated,
+                    required,
+                }))
+            }
+            Ok(_) => result,
+            Err(err) => {
+                debug!("Failed to determine the TLS version supported by {url}: {err}");
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rcgen::{BasicConstraints, CertificateParams, IsCa, Issuer, KeyPair};
+    use rustls::{RootCertStore, ServerConfig};
+    use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+
+    use super::*;
+
+    fn policy(url: &str, min_tls_version: TlsVersion) -> TlsPolicy {
+        TlsPolicy {
+            name: None,
+            url: DisplaySafeUrl::from_str(url).unwrap(),
+            min_tls_version,
+        }
+    }
+
+    #[test]
+    fn required_version() {
+        let policies = [policy(
+            "https://internal.example.com/simple",
+            TlsVersion::Tls1_3,
+        )];
+
+        let internal = Url::parse("https://internal.example.com/files/a.whl").unwrap();
+        let other = Url::parse("https://pypi.org/simple").unwrap();
+        let other_port = Url::parse("https://internal.example.com:8443/simple").unwrap();
+
+        // The index policy applies to the index's origin.
+        assert_eq!(
+            required_tls_version(&internal, None, &policies).map(|(version, _)| version),
+            Some(TlsVersion::Tls1_3)
+        );
+        assert_eq!(
+            required_tls_version(&other, None, &policies).map(|(version, _)| version),
+            None
+        );
+        assert_eq!(
+            required_tls_version(&other_port, None, &policies).map(|(version, _)| version),
+            None
+        );
+
+        // The global setting applies elsewhere, but doesn't weaken the index policy.
+        assert_eq!(
+            required_tls_version(&other, Some(TlsVersion::Tls1_2), &policies)
+                .map(|(version, _)| version),
+            Some(TlsVersion::Tls1_2)
+        );
+        let (version, policy) =
+            required_tls_version(&internal, Some(TlsVersion::Tls1_2), &policies).unwrap();
+        assert_eq!(version, TlsVersion::Tls1_3);
+        assert!(policy.is_some());
+    }
+
+    /// Start a TLS server for `localhost` that accepts a single handshake, returning its port and
+    /// the certificate of the CA that issued the server certificate.
+    async fn tls_server() -> anyhow::Result<(u16, CertificateDer<'static>)> {
+        let mut ca_params = CertificateParams::new(Vec::<String>::new())?;
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca_key = KeyPair::generate()?;
+        let ca_cert = ca_params.self_signed(&ca_key)?;
+        let issuer = Issuer::new(ca_params, &ca_key);
+
+        let server_key = KeyPair::generate()?;
+        let server_cert = CertificateParams::new(vec!["localhost".to_string()])?
+            .signed_by(&server_key, &issuer)?;
+        let config = ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![server_cert.der().clone()],
+                PrivateKeyDer::try_from(server_key.serialize_der()).map_err(anyhow::Error::msg)?,
+            )?;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let port = listener.local_addr()?.port();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            TlsAcceptor::from(Arc::new(config)).accept(stream).await?;
+            anyhow::Ok(())
+        });
+
+        Ok((port, ca_cert.der().clone()))
+    }
+
+    #[tokio::test]
+    async fn handshake_parameters() -> anyhow::Result<()> {
+        let (port, ca_cert) = tls_server().await?;
+        let mut roots = RootCertStore::empty();
+        roots.add(ca_cert)?;
+        let config = ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let probe = TlsProbe::new(Vec::new(), config, Duration::from_secs(10));
+
+        let url = Url::parse(&format!("https://localhost:{port}/simple"))?;
+        let parameters = probe.handshake(&url).await?;
+        assert_eq!(parameters.protocol_version, ProtocolVersion::TLSv1_3);
+        assert!(
+            parameters.to_string().starts_with("TLSv1_3 (TLS13_"),
+            "Expected a TLS 1.3 cipher suite, found: {parameters}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn handshake_verifies_certificate() -> anyhow::Result<()> {
+        let (port, _) = tls_server().await?;
+        let config = ClientConfig::builder()
+            .with_root_certificates(RootCertStore::empty())
+            .with_no_client_auth();
+        let probe = TlsProbe::new(Vec::new(), config, Duration::from_secs(10));
+
+        // The server's certificate isn't trusted, so the handshake fails.
+        let url = Url::parse(&format!("https://localhost:{port}/simple"))?;
+        assert!(probe.handshake(&url).await.is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 
+use uv_distribution_types::PackageNameGlob;
 use uv_normalize::PackageName;
 
 use crate::{PackageNameSpecifier, PackageNameSpecifiers};
@@ -35,11 +36,39 @@ pub enum BuildOutput {
     Quiet,
 }
 
+/// The isolation to apply to build backend processes.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum BuildSandbox {
+    /// Run build backends with the privileges of the current user.
+    #[default]
+    Disabled,
+    /// Run build backends without network access, and with filesystem access limited to the
+    /// build directory, the cache, and the interpreter.
+    Strict,
+}
+
+impl BuildSandbox {
+    /// Returns `true` if build backends run without a sandbox.
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, Self::Disabled)
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct BuildOptions {
     no_binary: NoBinary,
     no_build: NoBuild,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    allow_build: Option<Vec<PackageNameGlob>>,
+    #[serde(default, skip_serializing_if = "BuildSandbox::is_disabled")]
+    sandbox: BuildSandbox,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sandbox_exempt: Vec<PackageName>,
 }
 
 impl BuildOptions {
@@ -47,6 +76,30 @@ impl BuildOptions {
         Self {
             no_binary,
             no_build,
+            allow_build: None,
+            sandbox: BuildSandbox::Disabled,
+            sandbox_exempt: Vec::new(),
+        }
+    }
+
+    /// Restrict builds from source to the packages matching the given patterns.
+    ///
+    /// If `None`, builds are governed by the [`NoBuild`] strategy alone.
+    #[must_use]
+    pub fn with_allow_build(self, allow_build: Option<Vec<PackageNameGlob>>) -> Self {
+        Self {
+            allow_build,
+            ..self
+        }
+    }
+
+    /// Run build backends in the given sandbox, except for the exempted packages.
+    #[must_use]
+    pub fn with_sandbox(self, sandbox: BuildSandbox, sandbox_exempt: Vec<PackageName>) -> Self {
+        Self {
+            sandbox,
+            sandbox_exempt,
+            ..self
         }
     }
 
@@ -55,6 +108,9 @@ impl BuildOptions {
         Self {
             no_binary: self.no_binary.combine(no_binary),
             no_build: self.no_build.combine(no_build),
+            allow_build: self.allow_build,
+            sandbox: self.sandbox,
+            sandbox_exempt: self.sandbox_exempt,
         }
     }
 
@@ -71,6 +127,9 @@ impl BuildOptions {
     }
 
     pub fn no_build_package(&self, package_name: &PackageName) -> bool {
+        if !self.allow_build_package(package_name) {
+            return true;
+        }
         match &self.no_build {
             NoBuild::All => match &self.no_binary {
                 // Allow `all` to be overridden by specific binary exclusions
@@ -93,6 +152,34 @@ impl BuildOptions {
         matches!(self.no_build, NoBuild::All)
     }
 
+    /// Returns `true` if the package is permitted to build from source by the `allow-build`
+    /// allowlist, if any.
+    ///
+    /// Unlike [`BuildOptions::no_build_package`], this does not consider the [`NoBuild`] strategy.
+    pub fn allow_build_package(&self, package_name: &PackageName) -> bool {
+        self.allow_build.as_ref().is_none_or(|allow_build| {
+            allow_build
+                .iter()
+                .any(|pattern| pattern.matches(package_name))
+        })
+    }
+
+    /// Return the `allow-build` allowlist, if any.
+    pub fn allow_build(&self) -> Option<&[PackageNameGlob]> {
+        self.allow_build.as_deref()
+    }
+
+    /// Return the sandbox in which to run the build backend for the given package.
+    ///
+    /// Packages listed in `build-sandbox-exempt` are built without a sandbox.
+    pub fn sandbox(&self, package_name: Option<&PackageName>) -> BuildSandbox {
+        if package_name.is_some_and(|package_name| self.sandbox_exempt.contains(package_name)) {
+            BuildSandbox::Disabled
+        } else {
+            self.sandbox
+        }
+    }
+
     /// Return the [`NoBuild`] strategy to use.
     pub fn no_build(&self) -> &NoBuild {
         &self.no_build
@@ -405,4 +492,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn allow_build() -> Result<(), Error> {
+        let build_options =
+            BuildOptions::new(NoBinary::None, NoBuild::None).with_allow_build(Some(vec![
+                PackageNameGlob::from_str("numpy")?,
+                PackageNameGlob::from_str("internal-*")?,
+            ]));
+        assert!(!build_options.no_build_package(&PackageName::from_str("numpy")?));
+        assert!(!build_options.no_build_package(&PackageName::from_str("internal-utils")?));
+        assert!(build_options.no_build_package(&PackageName::from_str("pandas")?));
+
+        // `--no-build-package` still applies to allowlisted packages.
+        let build_options = BuildOptions::new(
+            NoBinary::None,
+            NoBuild::Packages(vec![PackageName::from_str("numpy")?]),
+        )
+        .with_allow_build(Some(vec![PackageNameGlob::from_str("numpy")?]));
+        assert!(build_options.no_build_package(&PackageName::from_str("numpy")?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sandbox_exempt() -> Result<(), Error> {
+        let build_options = BuildOptions::new(NoBinary::None, NoBuild::None)
+            .with_sandbox(BuildSandbox::Strict, vec![PackageName::from_str("grpcio")?]);
+        assert_eq!(
+            build_options.sandbox(Some(&PackageName::from_str("numpy")?)),
+            BuildSandbox::Strict
+        );
+        assert_eq!(
+            build_options.sandbox(Some(&PackageName::from_str("grpcio")?)),
+            BuildSandbox::Disabled
+        );
+        assert_eq!(build_options.sandbox(None), BuildSandbox::Strict);
+
+        Ok(())
+    }
 }
@@ -85,3 +85,31 @@ impl Constraints {
         })
     }
 }
+
+/// The environments to which the constraints from a constraints file apply.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ConstraintsScope {
+    /// Apply the constraints when resolving the requested packages, but not when resolving the
+    /// build dependencies of source distributions.
+    #[default]
+    Runtime,
+    /// Apply the constraints when resolving the build dependencies of source distributions, but
+    /// not when resolving the requested packages.
+    Build,
+    /// Apply the constraints when resolving both the requested packages and the build
+    /// dependencies of source distributions.
+    Both,
+}
+
+impl ConstraintsScope {
+    /// Returns `true` if the constraints apply when resolving the requested packages.
+    pub fn includes_runtime(self) -> bool {
+        matches!(self, Self::Runtime | Self::Both)
+    }
+
+    /// Returns `true` if the constraints apply when resolving build dependencies.
+    pub fn includes_build(self) -> bool {
+        matches!(self, Self::Build | Self::Both)
+    }
+}
@@ -22,6 +22,13 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export in conda `environment.yml` format.
+    #[serde(rename = "environment.yml", alias = "environment-yml")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "environment.yml", alias = "environment-yml")
+    )]
+    EnvironmentYml,
 }
 
 /// The output format to use in `uv pip compile`.
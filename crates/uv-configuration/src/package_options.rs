@@ -353,3 +353,45 @@ impl BuildIsolation {
         }
     }
 }
+
+/// Whether to compile Python files to bytecode after installation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum CompileBytecode {
+    /// Don't compile any files.
+    #[default]
+    None,
+
+    /// Compile all files in the environment.
+    All,
+
+    /// Compile only the files of the specified packages.
+    Packages(Vec<PackageName>),
+}
+
+impl CompileBytecode {
+    /// Determine the compilation strategy from the command-line arguments.
+    pub fn from_args(compile_bytecode: bool, compile_package: Vec<PackageName>) -> Self {
+        if compile_bytecode {
+            Self::All
+        } else if compile_package.is_empty() {
+            Self::None
+        } else {
+            Self::Packages(compile_package)
+        }
+    }
+
+    /// Returns `true` if no files should be compiled.
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+impl From<bool> for CompileBytecode {
+    fn from(compile_bytecode: bool) -> Self {
+        if compile_bytecode {
+            Self::All
+        } else {
+            Self::None
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use tracing::debug;
 
 use uv_pep508::MarkerEnvironment;
+use uv_platform::ArchVariant;
 use uv_platform_tags::{Arch, Os, Platform};
 use uv_static::EnvVars;
 
@@ -67,7 +68,7 @@ pub enum TargetTriple {
     #[serde(rename = "aarch64-unknown-linux-gnu")]
     Aarch64UnknownLinuxGnu,
 
-    /// An ARM64 Linux target.
+    /// An ARM64 Linux target. Equivalent to `aarch64-musllinux_1_2`.
     #[cfg_attr(feature = "clap", value(name = "aarch64-unknown-linux-musl"))]
     #[serde(rename = "aarch64-unknown-linux-musl")]
     Aarch64UnknownLinuxMusl,
@@ -213,6 +214,24 @@ pub enum TargetTriple {
     #[serde(alias = "manylinux_2_40_x86_64")]
     X8664Manylinux240,
 
+    /// An `x86_64` target for the `manylinux_2_28` platform, restricted to CPUs that support the
+    /// `x86-64-v2` microarchitecture level.
+    #[cfg_attr(feature = "clap", value(name = "x86_64_v2-manylinux_2_28"))]
+    #[serde(rename = "x86_64_v2-manylinux_2_28")]
+    X8664V2Manylinux228,
+
+    /// An `x86_64` target for the `manylinux_2_28` platform, restricted to CPUs that support the
+    /// `x86-64-v3` microarchitecture level.
+    #[cfg_attr(feature = "clap", value(name = "x86_64_v3-manylinux_2_28"))]
+    #[serde(rename = "x86_64_v3-manylinux_2_28")]
+    X8664V3Manylinux228,
+
+    /// An `x86_64` target for the `manylinux_2_28` platform, restricted to CPUs that support the
+    /// `x86-64-v4` microarchitecture level.
+    #[cfg_attr(feature = "clap", value(name = "x86_64_v4-manylinux_2_28"))]
+    #[serde(rename = "x86_64_v4-manylinux_2_28")]
+    X8664V4Manylinux228,
+
     /// An ARM64 target for the `manylinux2014` platform. Equivalent to `aarch64-manylinux_2_17`.
     #[cfg_attr(
         feature = "clap",
@@ -342,6 +361,26 @@ pub enum TargetTriple {
     #[serde(alias = "manylinux_2_40_aarch64")]
     Aarch64Manylinux240,
 
+    /// An ARM64 target for the `musllinux_1_1` platform.
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-musllinux_1_1", alias = "musllinux_1_1_aarch64")
+    )]
+    #[serde(rename = "aarch64-musllinux_1_1")]
+    #[serde(alias = "aarch64-musllinux11")]
+    #[serde(alias = "musllinux_1_1_aarch64")]
+    Aarch64Musllinux11,
+
+    /// An ARM64 target for the `musllinux_1_2` platform.
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "aarch64-musllinux_1_2", alias = "musllinux_1_2_aarch64")
+    )]
+    #[serde(rename = "aarch64-musllinux_1_2")]
+    #[serde(alias = "aarch64-musllinux12")]
+    #[serde(alias = "musllinux_1_2_aarch64")]
+    Aarch64Musllinux12,
+
     /// An ARM64 Android target.
     ///
     /// By default uses Android API level 24, but respects
@@ -455,7 +494,10 @@ impl TargetTriple {
                 },
                 Arch::X86_64,
             ),
-            Self::X8664Manylinux228 => Platform::new(
+            Self::X8664Manylinux228
+            | Self::X8664V2Manylinux228
+            | Self::X8664V3Manylinux228
+            | Self::X8664V4Manylinux228 => Platform::new(
                 Os::Manylinux {
                     major: 2,
                     minor: 28,
@@ -623,6 +665,12 @@ impl TargetTriple {
                 },
                 Arch::Aarch64,
             ),
+            Self::Aarch64Musllinux11 => {
+                Platform::new(Os::Musllinux { major: 1, minor: 1 }, Arch::Aarch64)
+            }
+            Self::Aarch64Musllinux12 => {
+                Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::Aarch64)
+            }
             Self::Wasm32Pyodide2024 => Platform::new(
                 Os::Pyodide {
                     major: 2024,
@@ -712,6 +760,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "x86_64",
             Self::X8664Manylinux217 => "x86_64",
             Self::X8664Manylinux228 => "x86_64",
+            Self::X8664V2Manylinux228 => "x86_64",
+            Self::X8664V3Manylinux228 => "x86_64",
+            Self::X8664V4Manylinux228 => "x86_64",
             Self::X8664Manylinux231 => "x86_64",
             Self::X8664Manylinux232 => "x86_64",
             Self::X8664Manylinux233 => "x86_64",
@@ -735,6 +786,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "aarch64",
             Self::Aarch64Manylinux239 => "aarch64",
             Self::Aarch64Manylinux240 => "aarch64",
+            Self::Aarch64Musllinux11 => "aarch64",
+            Self::Aarch64Musllinux12 => "aarch64",
             Self::Aarch64LinuxAndroid => "aarch64",
             Self::X8664LinuxAndroid => "x86_64",
             Self::Wasm32Pyodide2024 => "wasm32",
@@ -761,6 +814,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "Linux",
             Self::X8664Manylinux217 => "Linux",
             Self::X8664Manylinux228 => "Linux",
+            Self::X8664V2Manylinux228 => "Linux",
+            Self::X8664V3Manylinux228 => "Linux",
+            Self::X8664V4Manylinux228 => "Linux",
             Self::X8664Manylinux231 => "Linux",
             Self::X8664Manylinux232 => "Linux",
             Self::X8664Manylinux233 => "Linux",
@@ -784,6 +840,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "Linux",
             Self::Aarch64Manylinux239 => "Linux",
             Self::Aarch64Manylinux240 => "Linux",
+            Self::Aarch64Musllinux11 => "Linux",
+            Self::Aarch64Musllinux12 => "Linux",
             Self::Aarch64LinuxAndroid => "Android",
             Self::X8664LinuxAndroid => "Android",
             Self::Wasm32Pyodide2024 => "Emscripten",
@@ -810,6 +868,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "",
             Self::X8664Manylinux217 => "",
             Self::X8664Manylinux228 => "",
+            Self::X8664V2Manylinux228 => "",
+            Self::X8664V3Manylinux228 => "",
+            Self::X8664V4Manylinux228 => "",
             Self::X8664Manylinux231 => "",
             Self::X8664Manylinux232 => "",
             Self::X8664Manylinux233 => "",
@@ -833,6 +894,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "",
             Self::Aarch64Manylinux239 => "",
             Self::Aarch64Manylinux240 => "",
+            Self::Aarch64Musllinux11 => "",
+            Self::Aarch64Musllinux12 => "",
             Self::Aarch64LinuxAndroid => "",
             Self::X8664LinuxAndroid => "",
             // This is the value Emscripten gives for its version:
@@ -862,6 +925,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "",
             Self::X8664Manylinux217 => "",
             Self::X8664Manylinux228 => "",
+            Self::X8664V2Manylinux228 => "",
+            Self::X8664V3Manylinux228 => "",
+            Self::X8664V4Manylinux228 => "",
             Self::X8664Manylinux231 => "",
             Self::X8664Manylinux232 => "",
             Self::X8664Manylinux233 => "",
@@ -885,6 +951,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "",
             Self::Aarch64Manylinux239 => "",
             Self::Aarch64Manylinux240 => "",
+            Self::Aarch64Musllinux11 => "",
+            Self::Aarch64Musllinux12 => "",
             Self::Aarch64LinuxAndroid => "",
             Self::X8664LinuxAndroid => "",
             // This is the Emscripten compiler version for Pyodide 2024.
@@ -914,6 +982,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "posix",
             Self::X8664Manylinux217 => "posix",
             Self::X8664Manylinux228 => "posix",
+            Self::X8664V2Manylinux228 => "posix",
+            Self::X8664V3Manylinux228 => "posix",
+            Self::X8664V4Manylinux228 => "posix",
             Self::X8664Manylinux231 => "posix",
             Self::X8664Manylinux232 => "posix",
             Self::X8664Manylinux233 => "posix",
@@ -937,6 +1008,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "posix",
             Self::Aarch64Manylinux239 => "posix",
             Self::Aarch64Manylinux240 => "posix",
+            Self::Aarch64Musllinux11 => "posix",
+            Self::Aarch64Musllinux12 => "posix",
             Self::Aarch64LinuxAndroid => "posix",
             Self::X8664LinuxAndroid => "posix",
             Self::Wasm32Pyodide2024 => "posix",
@@ -963,6 +1036,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => "linux",
             Self::X8664Manylinux217 => "linux",
             Self::X8664Manylinux228 => "linux",
+            Self::X8664V2Manylinux228 => "linux",
+            Self::X8664V3Manylinux228 => "linux",
+            Self::X8664V4Manylinux228 => "linux",
             Self::X8664Manylinux231 => "linux",
             Self::X8664Manylinux232 => "linux",
             Self::X8664Manylinux233 => "linux",
@@ -986,6 +1062,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => "linux",
             Self::Aarch64Manylinux239 => "linux",
             Self::Aarch64Manylinux240 => "linux",
+            Self::Aarch64Musllinux11 => "linux",
+            Self::Aarch64Musllinux12 => "linux",
             Self::Aarch64LinuxAndroid => "android",
             Self::X8664LinuxAndroid => "android",
             Self::Wasm32Pyodide2024 => "emscripten",
@@ -1012,6 +1090,9 @@ impl TargetTriple {
             Self::X8664Manylinux2014 => true,
             Self::X8664Manylinux217 => true,
             Self::X8664Manylinux228 => true,
+            Self::X8664V2Manylinux228 => true,
+            Self::X8664V3Manylinux228 => true,
+            Self::X8664V4Manylinux228 => true,
             Self::X8664Manylinux231 => true,
             Self::X8664Manylinux232 => true,
             Self::X8664Manylinux233 => true,
@@ -1035,6 +1116,8 @@ impl TargetTriple {
             Self::Aarch64Manylinux238 => true,
             Self::Aarch64Manylinux239 => true,
             Self::Aarch64Manylinux240 => true,
+            Self::Aarch64Musllinux11 => true,
+            Self::Aarch64Musllinux12 => true,
             Self::Aarch64LinuxAndroid => false,
             Self::X8664LinuxAndroid => false,
             Self::Wasm32Pyodide2024 => false,
@@ -1045,22 +1128,89 @@ impl TargetTriple {
         }
     }
 
+    /// Return the minimum glibc version implied by the target, as a `(major, minor)` tuple.
+    ///
+    /// For example, `x86_64-manylinux_2_28` implies glibc 2.28. Returns `None` for targets that
+    /// don't use glibc (e.g., musl, Windows, or macOS).
+    pub fn glibc_version(self) -> Option<(u16, u16)> {
+        match self.platform().os() {
+            Os::Manylinux { major, minor } => Some((*major, *minor)),
+            _ => None,
+        }
+    }
+
+    /// Return `true` if the target typically supports GPU-accelerated (i.e., CUDA) builds of
+    /// packages like PyTorch.
+    ///
+    /// This is the case for `x86_64` Linux and Windows, along with ARM64 glibc-based Linux. Other
+    /// targets (e.g., macOS, musl-based Linux, and WebAssembly) only receive CPU builds.
+    pub fn is_gpu_capable(self) -> bool {
+        let platform = self.platform();
+        match (platform.os(), platform.arch()) {
+            (Os::Manylinux { .. }, Arch::X86_64 | Arch::Aarch64) => true,
+            (Os::Windows, Arch::X86_64) => true,
+            _ => false,
+        }
+    }
+
+    /// Return the `x86_64` microarchitecture level implied by the target, if any.
+    ///
+    /// For example, `x86_64_v3-manylinux_2_28` implies `x86-64-v3`. Returns `None` for targets
+    /// that don't specify a microarchitecture level.
+    pub fn arch_variant(self) -> Option<ArchVariant> {
+        match self {
+            Self::X8664V2Manylinux228 => Some(ArchVariant::V2),
+            Self::X8664V3Manylinux228 => Some(ArchVariant::V3),
+            Self::X8664V4Manylinux228 => Some(ArchVariant::V4),
+            _ => None,
+        }
+    }
+
     /// Return a [`MarkerEnvironment`] compatible with the given [`TargetTriple`], based on
     /// a base [`MarkerEnvironment`].
     ///
-    /// The returned [`MarkerEnvironment`] will preserve the base environment's Python version
-    /// markers, but override its platform markers.
-    pub fn markers(self, base: &MarkerEnvironment) -> MarkerEnvironment {
+    /// The returned [`MarkerEnvironment`] will override the base environment's platform markers
+    /// (`os_name`, `platform_machine`, `platform_system`, `sys_platform`, `platform_release`, and
+    /// `platform_version`), and preserve all other markers, including the Python version and
+    /// implementation markers. The `platform_release` and `platform_version` markers are taken
+    /// from the given [`PlatformMarkerOverrides`], if set.
+    pub fn markers(
+        self,
+        base: &MarkerEnvironment,
+        overrides: &PlatformMarkerOverrides,
+    ) -> MarkerEnvironment {
         base.clone()
             .with_os_name(self.os_name())
             .with_platform_machine(self.platform_machine())
             .with_platform_system(self.platform_system())
             .with_sys_platform(self.sys_platform())
-            .with_platform_release(self.platform_release())
-            .with_platform_version(self.platform_version())
+            .with_platform_release(
+                overrides
+                    .platform_release
+                    .as_deref()
+                    .unwrap_or(self.platform_release()),
+            )
+            .with_platform_version(
+                overrides
+                    .platform_version
+                    .as_deref()
+                    .unwrap_or(self.platform_version()),
+            )
     }
 }
 
+/// User-provided values for the platform markers that can't be inferred from a [`TargetTriple`].
+///
+/// For example, the `platform_release` marker corresponds to the kernel version on Linux, which
+/// varies across machines with the same target triple.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct PlatformMarkerOverrides {
+    /// The value of the `platform_release` marker (e.g., `6.8.0`).
+    pub platform_release: Option<String>,
+    /// The value of the `platform_version` marker.
+    pub platform_version: Option<String>,
+}
+
 /// Return the macOS deployment target as parsed from the environment.
 fn macos_deployment_target() -> Option<(u16, u16)> {
     let version = std::env::var(EnvVars::MACOSX_DEPLOYMENT_TARGET).ok()?;
@@ -1098,3 +1248,229 @@ fn android_api_level() -> Option<u16> {
 
     Some(api_level)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag, Tags, TagsOptions};
+
+    use super::*;
+
+    #[test]
+    fn glibc_version() {
+        assert_eq!(
+            TargetTriple::X8664Manylinux2014.glibc_version(),
+            Some((2, 17))
+        );
+        assert_eq!(
+            TargetTriple::X8664Manylinux228.glibc_version(),
+            Some((2, 28))
+        );
+        assert_eq!(
+            TargetTriple::Aarch64Manylinux231.glibc_version(),
+            Some((2, 31))
+        );
+        assert_eq!(
+            TargetTriple::X8664Manylinux240.glibc_version(),
+            Some((2, 40))
+        );
+        assert_eq!(
+            TargetTriple::Riscv64UnknownLinuxGnu.glibc_version(),
+            Some((2, 39))
+        );
+        assert_eq!(
+            TargetTriple::X8664UnknownLinuxGnu.glibc_version(),
+            Some((2, 28))
+        );
+    }
+
+    #[test]
+    fn glibc_version_non_glibc() {
+        assert_eq!(TargetTriple::X8664UnknownLinuxMusl.glibc_version(), None);
+        assert_eq!(TargetTriple::Aarch64Musllinux11.glibc_version(), None);
+        assert_eq!(TargetTriple::X8664PcWindowsMsvc.glibc_version(), None);
+        assert_eq!(TargetTriple::Aarch64AppleDarwin.glibc_version(), None);
+        assert_eq!(TargetTriple::Wasm32Pyodide2024.glibc_version(), None);
+    }
+
+    #[test]
+    fn is_gpu_capable() {
+        assert!(TargetTriple::Linux.is_gpu_capable());
+        assert!(TargetTriple::X8664Manylinux228.is_gpu_capable());
+        assert!(TargetTriple::Aarch64UnknownLinuxGnu.is_gpu_capable());
+        assert!(TargetTriple::Windows.is_gpu_capable());
+        assert!(TargetTriple::X8664PcWindowsMsvc.is_gpu_capable());
+
+        assert!(!TargetTriple::Macos.is_gpu_capable());
+        assert!(!TargetTriple::Aarch64AppleDarwin.is_gpu_capable());
+        assert!(!TargetTriple::X8664AppleDarwin.is_gpu_capable());
+        assert!(!TargetTriple::Aarch64PcWindowsMsvc.is_gpu_capable());
+        assert!(!TargetTriple::X8664UnknownLinuxMusl.is_gpu_capable());
+        assert!(!TargetTriple::Wasm32Pyodide2024.is_gpu_capable());
+        assert!(!TargetTriple::Arm64Ios.is_gpu_capable());
+    }
+
+    #[test]
+    fn x86_64_microarchitecture_level() {
+        let triple: TargetTriple = serde_json::from_str("\"x86_64_v3-manylinux_2_28\"").unwrap();
+        assert_eq!(triple, TargetTriple::X8664V3Manylinux228);
+        assert_eq!(triple.arch_variant(), Some(ArchVariant::V3));
+        assert_eq!(triple.glibc_version(), Some((2, 28)));
+        assert_eq!(
+            triple.platform(),
+            TargetTriple::X8664Manylinux228.platform()
+        );
+
+        assert_eq!(
+            TargetTriple::X8664V2Manylinux228.arch_variant(),
+            Some(ArchVariant::V2)
+        );
+        assert_eq!(
+            TargetTriple::X8664V4Manylinux228.arch_variant(),
+            Some(ArchVariant::V4)
+        );
+        assert_eq!(TargetTriple::X8664Manylinux228.arch_variant(), None);
+        assert_eq!(TargetTriple::Aarch64Manylinux228.arch_variant(), None);
+    }
+
+    #[test]
+    fn x86_64_microarchitecture_level_tags() {
+        let tags = |triple: TargetTriple| {
+            Tags::from_env(
+                &triple.platform(),
+                (3, 12),
+                "cpython",
+                (3, 12),
+                TagsOptions {
+                    manylinux_compatible: triple.manylinux_compatible(),
+                    x86_64_level: triple.arch_variant().map(ArchVariant::level),
+                    ..TagsOptions::default()
+                },
+            )
+            .unwrap()
+        };
+        let compatibility = |tags: &Tags, platform_tag: &str| {
+            tags.compatibility(
+                &[LanguageTag::from_str("cp312").unwrap()],
+                &[AbiTag::from_str("cp312").unwrap()],
+                &[PlatformTag::from_str(platform_tag).unwrap()],
+            )
+        };
+
+        // The baseline target only accepts baseline wheels.
+        let baseline = tags(TargetTriple::X8664Manylinux228);
+        assert!(compatibility(&baseline, "manylinux_2_28_x86_64").is_compatible());
+        assert!(!compatibility(&baseline, "manylinux_2_28_x86_64_v2").is_compatible());
+        assert!(!compatibility(&baseline, "manylinux_2_28_x86_64_v3").is_compatible());
+
+        // `x86-64-v3` accepts `v3` and `v2` wheels, in addition to baseline wheels, but not `v4`.
+        let v3 = tags(TargetTriple::X8664V3Manylinux228);
+        assert!(compatibility(&v3, "manylinux_2_28_x86_64").is_compatible());
+        assert!(compatibility(&v3, "manylinux_2_28_x86_64_v2").is_compatible());
+        assert!(compatibility(&v3, "manylinux_2_17_x86_64_v3").is_compatible());
+        assert!(!compatibility(&v3, "manylinux_2_28_x86_64_v4").is_compatible());
+
+        // Wheels for the requested level are preferred over lower levels and the baseline.
+        assert!(
+            compatibility(&v3, "manylinux_2_28_x86_64_v3")
+                > compatibility(&v3, "manylinux_2_28_x86_64_v2")
+        );
+        assert!(
+            compatibility(&v3, "manylinux_2_28_x86_64_v2")
+                > compatibility(&v3, "manylinux_2_28_x86_64")
+        );
+
+        // `x86-64-v2` rejects `v3` wheels.
+        let v2 = tags(TargetTriple::X8664V2Manylinux228);
+        assert!(compatibility(&v2, "manylinux_2_28_x86_64_v2").is_compatible());
+        assert!(!compatibility(&v2, "manylinux_2_28_x86_64_v3").is_compatible());
+    }
+
+    #[test]
+    fn aarch64_musllinux() {
+        let triple: TargetTriple = serde_json::from_str("\"aarch64-musllinux_1_1\"").unwrap();
+        assert_eq!(triple, TargetTriple::Aarch64Musllinux11);
+        assert_eq!(
+            triple.platform(),
+            Platform::new(Os::Musllinux { major: 1, minor: 1 }, Arch::Aarch64)
+        );
+
+        let triple: TargetTriple = serde_json::from_str("\"musllinux_1_2_aarch64\"").unwrap();
+        assert_eq!(triple, TargetTriple::Aarch64Musllinux12);
+        assert_eq!(
+            triple.platform(),
+            TargetTriple::Aarch64UnknownLinuxMusl.platform()
+        );
+    }
+
+    #[test]
+    fn aarch64_musllinux_tags() {
+        let tags = |triple: TargetTriple| {
+            Tags::from_env(
+                &triple.platform(),
+                (3, 12),
+                "cpython",
+                (3, 12),
+                TagsOptions {
+                    manylinux_compatible: triple.manylinux_compatible(),
+                    ..TagsOptions::default()
+                },
+            )
+            .unwrap()
+        };
+        let compatible = |tags: &Tags, platform_tag: &str| {
+            tags.is_compatible(
+                &[LanguageTag::from_str("cp312").unwrap()],
+                &[AbiTag::from_str("cp312").unwrap()],
+                &[PlatformTag::from_str(platform_tag).unwrap()],
+            )
+        };
+
+        // `musllinux_1_1` accepts `musllinux_1_1` wheels, but not `musllinux_1_2` wheels.
+        let musllinux_1_1 = tags(TargetTriple::Aarch64Musllinux11);
+        assert!(compatible(&musllinux_1_1, "musllinux_1_1_aarch64"));
+        assert!(!compatible(&musllinux_1_1, "musllinux_1_2_aarch64"));
+        assert!(!compatible(&musllinux_1_1, "musllinux_1_1_x86_64"));
+
+        // `musllinux_1_2` accepts both.
+        let musllinux_1_2 = tags(TargetTriple::Aarch64Musllinux12);
+        assert!(compatible(&musllinux_1_2, "musllinux_1_1_aarch64"));
+        assert!(compatible(&musllinux_1_2, "musllinux_1_2_aarch64"));
+        assert!(!compatible(&musllinux_1_2, "manylinux_2_17_aarch64"));
+    }
+
+    #[test]
+    fn markers_preserve_non_platform_markers() {
+        let base = MarkerEnvironment::try_from(uv_pep508::MarkerEnvironmentBuilder {
+            implementation_name: "pypy",
+            implementation_version: "7.3.17",
+            os_name: "posix",
+            platform_machine: "x86_64",
+            platform_python_implementation: "PyPy",
+            platform_release: "6.8.0",
+            platform_system: "Linux",
+            platform_version: "#1 SMP PREEMPT_DYNAMIC",
+            python_full_version: "3.10.14",
+            python_version: "3.10",
+            sys_platform: "linux",
+        })
+        .unwrap();
+
+        let markers =
+            TargetTriple::Aarch64AppleDarwin.markers(&base, &PlatformMarkerOverrides::default());
+
+        // The platform markers are overridden.
+        assert_eq!(markers.os_name(), "posix");
+        assert_eq!(markers.platform_machine(), "arm64");
+        assert_eq!(markers.platform_system(), "Darwin");
+        assert_eq!(markers.sys_platform(), "darwin");
+
+        // All other markers are preserved from the base environment.
+        assert_eq!(markers.implementation_name(), "pypy");
+        assert_eq!(markers.implementation_version().string, "7.3.17");
+        assert_eq!(markers.platform_python_implementation(), "PyPy");
+        assert_eq!(markers.python_full_version().string, "3.10.14");
+        assert_eq!(markers.python_version().string, "3.10");
+    }
+}
@@ -32,13 +32,20 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         interpreter.sys_executable().to_path_buf()
     };
 
-    let files = uv_installer::compile_tree(
+    let summary = uv_installer::compile_tree(
         &fs_err::canonicalize(args.root)?,
         &interpreter,
         &Concurrency::default(),
         cache.root(),
     )
     .await?;
-    info!("Compiled {files} files");
+    info!("Compiled {} files", summary.files);
+    for failure in summary.failures {
+        info!(
+            "Failed to compile {}: {}",
+            failure.path.display(),
+            failure.message
+        );
+    }
     Ok(())
 }
@@ -4,6 +4,7 @@
 
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use futures::FutureExt;
@@ -203,6 +204,13 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the maximum duration of each call to a build backend.
+    #[must_use]
+    pub fn with_build_backend_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.source_build_context = self.source_build_context.with_timeout(timeout);
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -1,10 +1,35 @@
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
+use uv_fs::Simplified;
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::Requirement;
 use uv_pypi_types::{ResolutionMetadata, VerbatimParsedUrl};
+use uv_warnings::warn_user;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DependencyMetadataError {
+    #[error("Failed to read dependency metadata directory: `{}`", _0.user_display())]
+    ReadDirectory(PathBuf, #[source] std::io::Error),
+    #[error("Failed to read dependency metadata file: `{}`", _0.user_display())]
+    ReadFile(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse dependency metadata file: `{}`", _0.user_display())]
+    ParseFile(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to read dependency metadata from: `{}`", _0.user_display())]
+    ReadJsonLines(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse dependency metadata from `{}` on line {_1}", _0.user_display())]
+    ParseJsonLine(PathBuf, usize, #[source] serde_json::Error),
+    #[error(
+        "Found conflicting dependency metadata entries for `{_0}{}`; entries for the same package and version must be identical",
+        _1.as_ref().map(|version| format!("=={version}")).unwrap_or_default()
+    )]
+    Conflict(PackageName, Option<Version>),
+}
 
 /// Pre-defined [`StaticMetadata`] entries, indexed by [`PackageName`] and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -20,6 +45,112 @@ impl DependencyMetadata {
         map
     }
 
+    /// Validate that a set of [`StaticMetadata`] entries is free of contradictory duplicates.
+    ///
+    /// Entries with the same name and version (or that are both global) must be identical, since
+    /// [`DependencyMetadata::get`] would otherwise pick one of them arbitrarily. Identical
+    /// duplicates are allowed.
+    pub fn validate<'a>(
+        entries: impl IntoIterator<Item = &'a StaticMetadata>,
+    ) -> Result<(), DependencyMetadataError> {
+        let mut seen = FxHashMap::default();
+        for entry in entries {
+            if let Some(existing) = seen.insert((&entry.name, entry.version.as_ref()), entry)
+                && existing != entry
+            {
+                return Err(DependencyMetadataError::Conflict(
+                    entry.name.clone(),
+                    entry.version.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Read a set of [`StaticMetadata`] entries from a directory of per-package TOML files (e.g.,
+    /// `metadata/<package>.toml`).
+    ///
+    /// Each file contains a single entry. Files without a `.toml` extension are ignored. If the
+    /// `name` in a file doesn't match the file name, a warning is emitted and the `name` takes
+    /// precedence.
+    pub fn from_directory(directory: &Path) -> Result<Self, DependencyMetadataError> {
+        let mut paths = fs_err::read_dir(directory)
+            .map_err(|err| DependencyMetadataError::ReadDirectory(directory.to_path_buf(), err))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| DependencyMetadataError::ReadDirectory(directory.to_path_buf(), err))?;
+
+        // Read the files in a deterministic order.
+        paths.sort();
+
+        let mut entries = Vec::with_capacity(paths.len());
+        for path in paths {
+            if !path.is_file() || path.extension().is_none_or(|extension| extension != "toml") {
+                continue;
+            }
+
+            let contents = fs_err::read_to_string(&path)
+                .map_err(|err| DependencyMetadataError::ReadFile(path.clone(), err))?;
+            let entry = toml::from_str::<StaticMetadata>(&contents)
+                .map_err(|err| DependencyMetadataError::ParseFile(path.clone(), Box::new(err)))?;
+
+            // Validate that the file name matches the package name.
+            let expected = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| PackageName::from_str(stem).ok());
+            if expected.as_ref() != Some(&entry.name) {
+                warn_user!(
+                    "Dependency metadata file `{}` declares package `{}`, which does not match the file name",
+                    path.user_display(),
+                    entry.name
+                );
+            }
+
+            entries.push(entry);
+        }
+
+        Self::validate(&entries)?;
+
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Read a set of [`StaticMetadata`] entries from a JSON lines file, or from stdin if the path
+    /// is `-`.
+    ///
+    /// Each line contains a single entry, in the same format as the `dependency-metadata`
+    /// setting. Blank lines are ignored.
+    pub fn read_json_lines(path: &Path) -> Result<Vec<StaticMetadata>, DependencyMetadataError> {
+        if path == Path::new("-") {
+            Self::parse_json_lines(path, std::io::stdin().lock())
+        } else {
+            let file = fs_err::File::open(path)
+                .map_err(|err| DependencyMetadataError::ReadJsonLines(path.to_path_buf(), err))?;
+            Self::parse_json_lines(path, std::io::BufReader::new(file))
+        }
+    }
+
+    /// Parse a set of [`StaticMetadata`] entries from JSON lines.
+    fn parse_json_lines(
+        path: &Path,
+        reader: impl BufRead,
+    ) -> Result<Vec<StaticMetadata>, DependencyMetadataError> {
+        let mut entries = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line
+                .map_err(|err| DependencyMetadataError::ReadJsonLines(path.to_path_buf(), err))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = serde_json::from_str::<StaticMetadata>(&line).map_err(|err| {
+                DependencyMetadataError::ParseJsonLine(path.to_path_buf(), index + 1, err)
+            })?;
+            entries.push(entry);
+        }
+        Self::validate(&entries)?;
+        Ok(entries)
+    }
+
     /// Retrieve a [`StaticMetadata`] entry by [`PackageName`] and [`Version`].
     pub fn get(
         &self,
@@ -51,6 +182,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                license_expression: None,
             })
         } else {
             // If no version was requested (i.e., it's a direct URL dependency), allow a single
@@ -72,6 +204,7 @@ impl DependencyMetadata {
                 requires_python: metadata.requires_python.clone(),
                 provides_extra: metadata.provides_extra.clone(),
                 dynamic: false,
+                license_expression: None,
             })
         }
     }
@@ -112,3 +245,151 @@ pub struct StaticMetadata {
     #[serde(default, alias = "provides-extras")]
     pub provides_extra: Box<[ExtraName]>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::{DependencyMetadata, DependencyMetadataError};
+
+    #[test]
+    fn from_directory() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(
+            temp_dir.path().join("anyio.toml"),
+            r#"
+            name = "anyio"
+            version = "4.3.0"
+            requires-dist = ["idna>=2.8", "sniffio>=1.1"]
+            requires-python = ">=3.8"
+            "#,
+        )?;
+        fs_err::write(
+            temp_dir.path().join("idna.toml"),
+            r#"
+            name = "idna"
+            requires-python = ">=3.5"
+            "#,
+        )?;
+        fs_err::write(
+            temp_dir.path().join("sniffio.toml"),
+            r#"
+            name = "sniffio"
+            version = "1.3.1"
+            provides-extra = ["test"]
+            "#,
+        )?;
+        // Non-TOML files are ignored.
+        fs_err::write(temp_dir.path().join("README.md"), "# Metadata")?;
+
+        let metadata = DependencyMetadata::from_directory(temp_dir.path())?;
+        assert_eq!(metadata.values().count(), 3);
+
+        let anyio = PackageName::from_str("anyio")?;
+        let entry = metadata
+            .get(&anyio, Some(&Version::from_str("4.3.0")?))
+            .expect("versioned entry");
+        assert_eq!(entry.requires_dist.len(), 2);
+
+        // A global entry matches any version.
+        let idna = PackageName::from_str("idna")?;
+        assert!(
+            metadata
+                .get(&idna, Some(&Version::from_str("3.6")?))
+                .is_some()
+        );
+
+        // Without a requested version, the single versioned entry is used.
+        let sniffio = PackageName::from_str("sniffio")?;
+        let entry = metadata.get(&sniffio, None).expect("versioned entry");
+        assert_eq!(entry.version, Version::from_str("1.3.1")?);
+        assert_eq!(entry.provides_extra.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_json_lines() -> anyhow::Result<()> {
+        let contents = concat!(
+            r#"{"name": "anyio", "version": "4.3.0", "requires-dist": ["idna>=2.8", "sniffio>=1.1"]}"#,
+            "\n\n",
+            r#"{"name": "idna", "requires-python": ">=3.5"}"#,
+            "\n",
+        );
+
+        let entries = DependencyMetadata::parse_json_lines(Path::new("-"), contents.as_bytes())?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].requires_dist.len(), 2);
+        assert_eq!(entries[1].version, None);
+
+        // Unknown fields are rejected, and reported with their line number.
+        let err = DependencyMetadata::parse_json_lines(
+            Path::new("-"),
+            r#"{"name": "anyio", "unknown": true}"#.as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse dependency metadata from `-` on line 1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn conflicting_entries() -> anyhow::Result<()> {
+        let contents = concat!(
+            r#"{"name": "anyio", "version": "4.3.0", "requires-dist": ["idna>=2.8"]}"#,
+            "\n",
+            r#"{"name": "anyio", "version": "4.3.0", "requires-dist": ["sniffio>=1.1"]}"#,
+            "\n",
+        );
+        let err =
+            DependencyMetadata::parse_json_lines(Path::new("-"), contents.as_bytes()).unwrap_err();
+        assert!(matches!(err, DependencyMetadataError::Conflict(..)));
+        assert_eq!(
+            err.to_string(),
+            "Found conflicting dependency metadata entries for `anyio==4.3.0`; entries for the same package and version must be identical"
+        );
+
+        // Identical duplicates, and entries for different versions, are allowed.
+        let contents = concat!(
+            r#"{"name": "anyio", "version": "4.3.0", "requires-dist": ["idna>=2.8"]}"#,
+            "\n",
+            r#"{"name": "anyio", "version": "4.3.0", "requires-dist": ["idna>=2.8"]}"#,
+            "\n",
+            r#"{"name": "anyio", "version": "4.4.0", "requires-dist": ["sniffio>=1.1"]}"#,
+            "\n",
+        );
+        let entries = DependencyMetadata::parse_json_lines(Path::new("-"), contents.as_bytes())?;
+        assert_eq!(entries.len(), 3);
+
+        // Conflicting global entries are rejected when reading a directory.
+        let temp_dir = tempfile::tempdir()?;
+        fs_err::write(
+            temp_dir.path().join("idna.toml"),
+            r#"
+            name = "idna"
+            requires-python = ">=3.5"
+            "#,
+        )?;
+        fs_err::write(
+            temp_dir.path().join("idna-legacy.toml"),
+            r#"
+            name = "idna"
+            requires-python = ">=2.7"
+            "#,
+        )?;
+        let err = DependencyMetadata::from_directory(temp_dir.path()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Found conflicting dependency metadata entries for `idna`; entries for the same package and version must be identical"
+        );
+
+        Ok(())
+    }
+}
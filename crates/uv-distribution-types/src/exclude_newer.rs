@@ -214,11 +214,20 @@ impl FromStr for ExcludeNewerValue {
     type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // Timestamps with a UTC offset (e.g., `2024-01-01T00:00:00+09:00`) are normalized to UTC,
+        // which is how upload times are reported by the index.
         if let Ok(timestamp) = input.parse::<Timestamp>() {
             return Ok(Self::absolute(timestamp));
         }
 
         let date_err = match input.parse::<jiff::civil::Date>() {
+            // A date is parsed even if it's followed by a time, which would be silently discarded;
+            // require an explicit offset instead of guessing the time zone.
+            Ok(date) if input.trim().contains(['T', 't', ' ']) => {
+                return Err(format!(
+                    "`{input}` is missing a UTC offset; use a timestamp with an offset (e.g., `{input}Z` or `{input}+09:00`) or a date without a time (e.g., `{date}`)"
+                ));
+            }
             Ok(date) => {
                 let timestamp = date
                     .checked_add(1.day())
@@ -421,3 +430,34 @@ impl serde::Serialize for ExcludeNewerOverride {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exclude_newer_offset_timestamp() {
+        let value = ExcludeNewerValue::from_str("2024-01-01T00:00:00+09:00").unwrap();
+        assert_eq!(
+            value.timestamp(),
+            "2023-12-31T15:00:00Z".parse::<Timestamp>().unwrap()
+        );
+        assert_eq!(value.to_string(), "2023-12-31T15:00:00Z");
+
+        // The same wall-clock time in UTC is nine hours later.
+        let utc = ExcludeNewerValue::from_str("2024-01-01T00:00:00Z").unwrap();
+        assert!(value < utc);
+
+        let negative = ExcludeNewerValue::from_str("2024-01-01T00:00:00-05:00").unwrap();
+        assert_eq!(negative.to_string(), "2024-01-01T05:00:00Z");
+    }
+
+    #[test]
+    fn exclude_newer_timestamp_without_offset() {
+        let err = ExcludeNewerValue::from_str("2024-01-01T12:00:00").unwrap_err();
+        assert_eq!(
+            err,
+            "`2024-01-01T12:00:00` is missing a UTC offset; use a timestamp with an offset (e.g., `2024-01-01T12:00:00Z` or `2024-01-01T12:00:00+09:00`) or a date without a time (e.g., `2024-01-01`)"
+        );
+    }
+}
@@ -7,13 +7,15 @@ use thiserror::Error;
 use url::Url;
 
 use uv_auth::{AuthPolicy, Credentials, CredentialsFromUrlError};
+use uv_normalize::PackageName;
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 
 use crate::exclude_newer::ExcludeNewerOverride;
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
-use crate::{IndexStatusCodeStrategy, IndexUrl, IndexUrlError, SerializableStatusCode};
+use crate::package_name_glob::PackageNameGlob;
+use crate::{IndexStatusCodeStrategy, IndexUrl, IndexUrlError, SerializableStatusCode, TlsVersion};
 
 /// Cache control configuration for an index.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Default)]
@@ -253,6 +255,50 @@ pub struct Index {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "schemars", schemars(with = "ExcludeNewerOverride"))]
     pub exclude_newer: Option<ExcludeNewerOverride>,
+    /// Restrict the index to packages whose names match any of the given patterns.
+    ///
+    /// Patterns may include `*` wildcards. When set, the index is skipped for any package that
+    /// doesn't match.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// only-packages = ["acme-*"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_packages: Option<Vec<PackageNameGlob>>,
+    /// Pin the packages matched by `only-packages` to this index.
+    ///
+    /// When enabled, packages matching `only-packages` are _only_ fetched from this index,
+    /// regardless of the index strategy, and requesting them from any other index (e.g., via
+    /// `[tool.uv.sources]`) is an error. This guards against dependency confusion attacks, in which
+    /// a same-named package is published to a public index.
+    ///
+    /// Requires `only-packages` to be set.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// only-packages = ["acme-*"]
+    /// pinned-only = true
+    /// ```
+    #[serde(default)]
+    pub pinned_only: bool,
+    /// The minimum TLS version to accept when connecting to the index.
+    ///
+    /// When set, connections to the index's host that can't negotiate at least the given version
+    /// will fail, overriding the global `min-tls-version` if it is less strict.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// min-tls-version = "1.3"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_tls_version: Option<TlsVersion>,
 }
 
 #[derive(Debug, Error)]
@@ -277,6 +323,9 @@ impl PartialEq for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            only_packages,
+            pinned_only,
+            min_tls_version,
         } = self;
         *url == other.url
             && *name == other.name
@@ -288,6 +337,9 @@ impl PartialEq for Index {
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
             && *exclude_newer == other.exclude_newer
+            && *only_packages == other.only_packages
+            && *pinned_only == other.pinned_only
+            && *min_tls_version == other.min_tls_version
     }
 }
 
@@ -313,6 +365,9 @@ impl Ord for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            only_packages,
+            pinned_only,
+            min_tls_version,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -324,6 +379,9 @@ impl Ord for Index {
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
             .then_with(|| exclude_newer.cmp(&other.exclude_newer))
+            .then_with(|| only_packages.cmp(&other.only_packages))
+            .then_with(|| pinned_only.cmp(&other.pinned_only))
+            .then_with(|| min_tls_version.cmp(&other.min_tls_version))
     }
 }
 
@@ -341,6 +399,9 @@ impl std::hash::Hash for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            only_packages,
+            pinned_only,
+            min_tls_version,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -352,6 +413,9 @@ impl std::hash::Hash for Index {
         ignore_error_codes.hash(state);
         cache_control.hash(state);
         exclude_newer.hash(state);
+        only_packages.hash(state);
+        pinned_only.hash(state);
+        min_tls_version.hash(state);
     }
 }
 
@@ -393,6 +457,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }
     }
 
@@ -410,6 +477,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }
     }
 
@@ -427,6 +497,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }
     }
 
@@ -549,6 +622,24 @@ impl Index {
     pub(crate) fn exclude_newer(&self) -> Option<&ExcludeNewerOverride> {
         self.exclude_newer.as_ref()
     }
+
+    /// Return whether this index may serve the given package, per `only-packages`.
+    pub fn serves(&self, package_name: &PackageName) -> bool {
+        self.only_packages
+            .as_ref()
+            .is_none_or(|patterns| patterns.iter().any(|pattern| pattern.matches(package_name)))
+    }
+
+    /// Return the minimum TLS version to accept when connecting to the index, if any.
+    pub fn min_tls_version(&self) -> Option<TlsVersion> {
+        self.min_tls_version
+    }
+
+    /// Return whether this index is the only index from which the given package may be fetched,
+    /// per `pinned-only`.
+    pub fn pins(&self, package_name: &PackageName) -> bool {
+        self.pinned_only && self.only_packages.is_some() && self.serves(package_name)
+    }
 }
 
 impl From<IndexUrl> for Index {
@@ -565,6 +656,9 @@ impl From<IndexUrl> for Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }
     }
 }
@@ -591,6 +685,9 @@ impl FromStr for Index {
                 ignore_error_codes: None,
                 cache_control: None,
                 exclude_newer: None,
+                only_packages: None,
+                pinned_only: false,
+                min_tls_version: None,
             });
         }
 
@@ -608,6 +705,9 @@ impl FromStr for Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         })
     }
 }
@@ -701,6 +801,12 @@ struct IndexWire {
     cache_control: Option<IndexCacheControl>,
     #[serde(default)]
     exclude_newer: Option<ExcludeNewerOverride>,
+    #[serde(default)]
+    only_packages: Option<Vec<PackageNameGlob>>,
+    #[serde(default)]
+    pinned_only: bool,
+    #[serde(default)]
+    min_tls_version: Option<TlsVersion>,
 }
 
 impl<'de> Deserialize<'de> for Index {
@@ -717,6 +823,13 @@ impl<'de> Deserialize<'de> for Index {
             )));
         }
 
+        if wire.pinned_only && wire.only_packages.is_none() {
+            return Err(serde::de::Error::custom(format!(
+                "An index with `pinned-only = true` requires `only-packages`: {}",
+                wire.url
+            )));
+        }
+
         Ok(Self {
             name: wire.name,
             url: wire.url,
@@ -729,6 +842,9 @@ impl<'de> Deserialize<'de> for Index {
             ignore_error_codes: wire.ignore_error_codes,
             cache_control: wire.cache_control,
             exclude_newer: wire.exclude_newer,
+            only_packages: wire.only_packages,
+            pinned_only: wire.pinned_only,
+            min_tls_version: wire.min_tls_version,
         })
     }
 }
@@ -866,4 +982,52 @@ mod tests {
             Some(ExcludeNewerOverride::Enabled(_))
         ));
     }
+
+    #[test]
+    fn test_index_pinned_only() {
+        let toml_str = r#"
+            name = "internal"
+            url = "https://internal.example.com/simple"
+            only-packages = ["acme-*"]
+            pinned-only = true
+        "#;
+
+        let index: Index = toml::from_str(toml_str).unwrap();
+        let acme = PackageName::from_str("acme-utils").unwrap();
+        let other = PackageName::from_str("requests").unwrap();
+        assert!(index.serves(&acme));
+        assert!(index.pins(&acme));
+        assert!(!index.serves(&other));
+        assert!(!index.pins(&other));
+
+        // `pinned-only` requires `only-packages`.
+        let toml_str = r#"
+            name = "internal"
+            url = "https://internal.example.com/simple"
+            pinned-only = true
+        "#;
+        let err = toml::from_str::<Index>(toml_str).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("An index with `pinned-only = true` requires `only-packages`")
+        );
+    }
+
+    #[test]
+    fn test_index_min_tls_version() {
+        let toml_str = r#"
+            name = "internal"
+            url = "https://internal.example.com/simple"
+            min-tls-version = "1.3"
+        "#;
+        let index: Index = toml::from_str(toml_str).unwrap();
+        assert_eq!(index.min_tls_version(), Some(TlsVersion::Tls1_3));
+
+        let toml_str = r#"
+            name = "internal"
+            url = "https://internal.example.com/simple"
+            min-tls-version = "1.1"
+        "#;
+        assert!(toml::from_str::<Index>(toml_str).is_err());
+    }
 }
@@ -12,6 +12,7 @@ use thiserror::Error;
 use url::{ParseError, Url};
 use uv_auth::RealmRef;
 use uv_cache_key::CanonicalUrl;
+use uv_normalize::PackageName;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
@@ -495,6 +496,27 @@ impl<'a> IndexLocations {
             .find(|index| is_same_index(index.url(), url))
     }
 
+    /// Return the indexes that pin the given package via `pinned-only`, in order of definition.
+    ///
+    /// Unlike [`IndexLocations::indexes`], this includes explicit indexes.
+    pub fn pinned_indexes_for<'b>(
+        &'a self,
+        package_name: &'b PackageName,
+    ) -> impl Iterator<Item = &'a Index> + 'b
+    where
+        'a: 'b,
+    {
+        self.defined_indexes()
+            .filter(move |index| index.pins(package_name))
+    }
+
+    /// Return whether the index at the given URL may serve the given package, per
+    /// `only-packages`.
+    pub fn serves(&self, url: &IndexUrl, package_name: &PackageName) -> bool {
+        self.index_for_url(url)
+            .is_none_or(|index| index.serves(package_name))
+    }
+
     /// Return the [`IndexStatusCodeStrategy`] for an [`IndexUrl`].
     pub fn status_code_strategy_for(&self, url: &IndexUrl) -> IndexStatusCodeStrategy {
         self.index_for_url(url).map_or(
@@ -701,6 +723,9 @@ mod tests {
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
                 exclude_newer: None,
+                only_packages: None,
+                pinned_only: false,
+                min_tls_version: None,
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -714,6 +739,9 @@ mod tests {
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
                 exclude_newer: None,
+                only_packages: None,
+                pinned_only: false,
+                min_tls_version: None,
             },
         ];
 
@@ -753,6 +781,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -789,6 +820,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -820,6 +854,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            only_packages: None,
+            pinned_only: false,
+            min_tls_version: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -346,6 +346,30 @@ impl InstalledDist {
         Ok(None)
     }
 
+    /// Determine the name of the distribution at the given path from the path alone, without
+    /// reading from disk.
+    ///
+    /// Returns `None` if the name can't be inferred from the path (e.g., for `.egg-link` files,
+    /// which require reading the linked metadata), or if the path is not a distribution.
+    pub fn name_from_path(path: &Path) -> Option<PackageName> {
+        let file_stem = path.file_stem()?.to_str()?;
+
+        // Ex) `cffi-1.16.0.dist-info`
+        if path.extension().is_some_and(|ext| ext == "dist-info") {
+            let (name, _) = file_stem.split_once('-')?;
+            return PackageName::from_str(name).ok();
+        }
+
+        // Ex) `zstandard-0.22.0-py3.12.egg-info` or `vtk-9.2.6.egg-info`
+        if path.extension().is_some_and(|ext| ext == "egg-info") {
+            return EggInfoFilename::parse(file_stem)
+                .ok()
+                .map(|file_name| file_name.name);
+        }
+
+        None
+    }
+
     /// Return the [`Path`] at which the distribution is stored on-disk.
     pub fn install_path(&self) -> &Path {
         match &self.kind {
@@ -492,6 +516,19 @@ impl InstalledDist {
         Ok(self.tags_cache.get().expect("tags should be set").as_ref())
     }
 
+    /// Return true if the distribution was explicitly requested by the user, as indicated by the
+    /// presence of a `REQUESTED` file in its `.dist-info` directory.
+    pub fn is_requested(&self) -> bool {
+        match &self.kind {
+            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_) => {
+                self.install_path().join("REQUESTED").is_file()
+            }
+            InstalledDistKind::EggInfoFile(_)
+            | InstalledDistKind::EggInfoDirectory(_)
+            | InstalledDistKind::LegacyEditable(_) => false,
+        }
+    }
+
     /// Return true if the distribution is editable.
     pub fn is_editable(&self) -> bool {
         matches!(
@@ -76,6 +76,7 @@ pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
+pub use crate::package_name_glob::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
 pub use crate::requested::*;
@@ -85,6 +86,7 @@ pub use crate::resolution::*;
 pub use crate::resolved::*;
 pub use crate::specified_requirement::*;
 pub use crate::status_code_strategy::*;
+pub use crate::tls_version::*;
 pub use crate::traits::*;
 
 mod annotation;
@@ -109,6 +111,7 @@ mod installed;
 mod installed_modules;
 mod known_platform;
 mod origin;
+mod package_name_glob;
 mod pip_index;
 mod prioritized_distribution;
 mod requested;
@@ -118,6 +121,7 @@ mod resolution;
 mod resolved;
 mod specified_requirement;
 mod status_code_strategy;
+mod tls_version;
 mod traits;
 
 #[derive(Debug, Clone)]
@@ -608,7 +612,7 @@ impl Dist {
     }
 
     /// Return true if the distribution refers to a local file or directory.
-    fn is_local(&self) -> bool {
+    pub fn is_local(&self) -> bool {
         match self {
             Self::Source(dist) => dist.is_local(),
             Self::Built(dist) => dist.is_local(),
@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+use uv_small_str::SmallString;
+
+/// A glob pattern that matches normalized package names, e.g., `acme-*`.
+///
+/// Patterns may contain letters, digits, hyphens, underscores, periods, and `*` wildcards, which
+/// match any sequence of characters. Patterns are normalized in the same way as package names, such
+/// that `Acme_*` and `acme-*` are equivalent.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageNameGlob(SmallString);
+
+impl PackageNameGlob {
+    /// Validates and normalizes the given pattern.
+    fn new(pattern: &str) -> Result<Self, PackageNameGlobError> {
+        if pattern.is_empty() {
+            return Err(PackageNameGlobError::Empty);
+        }
+
+        let mut normalized = String::with_capacity(pattern.len());
+        let mut last = None;
+        for c in pattern.chars() {
+            match c {
+                'a'..='z' | '0'..='9' | '*' => normalized.push(c),
+                'A'..='Z' => normalized.push(c.to_ascii_lowercase()),
+                '-' | '_' | '.' => {
+                    if !matches!(last, Some('-' | '_' | '.')) {
+                        normalized.push('-');
+                    }
+                }
+                c => {
+                    return Err(PackageNameGlobError::UnsupportedCharacter(
+                        c,
+                        pattern.to_string(),
+                    ));
+                }
+            }
+            last = Some(c);
+        }
+        Ok(Self(SmallString::from(normalized)))
+    }
+
+    /// Returns `true` if the pattern matches the given [`PackageName`].
+    pub fn matches(&self, name: &PackageName) -> bool {
+        let mut name = name.as_str();
+        let mut parts = self.0.split('*');
+
+        // The first part must match the start of the name.
+        let Some(rest) = parts.next().and_then(|first| name.strip_prefix(first)) else {
+            return false;
+        };
+        name = rest;
+
+        // Without any wildcards, the pattern must match the name exactly.
+        let parts = parts.collect::<Vec<_>>();
+        let Some((last, middle)) = parts.split_last() else {
+            return name.is_empty();
+        };
+
+        // Otherwise, match each intermediate part as early as possible, then require that the
+        // remainder ends with the last part.
+        for part in middle {
+            let Some(index) = name.find(part) else {
+                return false;
+            };
+            name = &name[index + part.len()..];
+        }
+        name.ends_with(last)
+    }
+}
+
+impl FromStr for PackageNameGlob {
+    type Err = PackageNameGlobError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for PackageNameGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = Cow::<'_, str>::deserialize(deserializer)?;
+        Self::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PackageNameGlob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An error that can occur when parsing a [`PackageNameGlob`].
+#[derive(Error, Debug)]
+pub enum PackageNameGlobError {
+    #[error("Package name patterns must not be empty")]
+    Empty,
+    #[error(
+        "Package name patterns may only contain letters, digits, hyphens, underscores, periods, and `*`, but found unsupported character (`{0}`) in: `{1}`"
+    )]
+    UnsupportedCharacter(char, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        PackageNameGlob::from_str(pattern)
+            .unwrap()
+            .matches(&PackageName::from_str(name).unwrap())
+    }
+
+    #[test]
+    fn package_name_glob() {
+        assert!(matches("acme-*", "acme-utils"));
+        assert!(matches("Acme_*", "acme-utils"));
+        assert!(matches("acme", "acme"));
+        assert!(!matches("acme", "acme-utils"));
+        assert!(!matches("acme-*", "acme"));
+        assert!(!matches("acme-*", "not-acme-utils"));
+        assert!(matches("*-internal", "acme-internal"));
+        assert!(matches("acme-*-sdk", "acme-cloud-sdk"));
+        assert!(!matches("acme-*-sdk", "acme-sdk"));
+        assert!(matches("*", "anything"));
+        assert!(PackageNameGlob::from_str("acme/*").is_err());
+    }
+}
@@ -569,6 +569,16 @@ impl PrioritizedDist {
         self.0.best_wheel_index.map(|i| &self.0.wheels[i])
     }
 
+    /// Returns an iterator over all wheels, along with their compatibility.
+    pub fn wheels(&self) -> impl Iterator<Item = &(RegistryBuiltWheel, WheelCompatibility)> {
+        self.0.wheels.iter()
+    }
+
+    /// Returns the best source distribution, along with its compatibility, if any.
+    pub fn source(&self) -> Option<&(RegistrySourceDist, SourceDistCompatibility)> {
+        self.0.source.as_ref()
+    }
+
     /// Returns an iterator of all wheels and the source distribution, if any.
     pub fn files(&self) -> impl Iterator<Item = &File> {
         self.0
@@ -909,6 +919,19 @@ fn implied_platform_markers(filename: &WheelFilename) -> MarkerTree {
                 }));
                 marker.or(tag_marker);
             }
+            PlatformTag::ManylinuxX8664Level { .. } => {
+                let mut tag_marker = MarkerTree::expression(MarkerExpression::String {
+                    key: MarkerValueString::SysPlatform,
+                    operator: MarkerOperator::Equal,
+                    value: arcstr::literal!("linux"),
+                });
+                tag_marker.and(MarkerTree::expression(MarkerExpression::String {
+                    key: MarkerValueString::PlatformMachine,
+                    operator: MarkerOperator::Equal,
+                    value: arcstr::literal!("x86_64"),
+                }));
+                marker.or(tag_marker);
+            }
 
             tag => {
                 debug!("Unknown platform tag in wheel tag: {tag}");
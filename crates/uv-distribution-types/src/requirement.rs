@@ -886,11 +886,21 @@ enum RequirementSourceWire {
     Registry {
         #[serde(skip_serializing_if = "VersionSpecifiers::is_empty", default)]
         specifier: VersionSpecifiers,
-        index: Option<DisplaySafeUrl>,
+        index: Option<RegistryIndexWire>,
         conflict: Option<ConflictItem>,
     },
 }
 
+/// The index for a registry requirement, which could be a URL or a relative path.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum RegistryIndexWire {
+    /// Ex) `index = "https://pypi.org/simple"`
+    Url(DisplaySafeUrl),
+    /// Ex) `index = "../path/to/local/index"`
+    Path(PortablePathBuf),
+}
+
 impl From<RequirementSource> for RequirementSourceWire {
     fn from(value: RequirementSource) -> Self {
         match value {
@@ -899,9 +909,18 @@ impl From<RequirementSource> for RequirementSourceWire {
                 index,
                 conflict,
             } => {
-                let index = index.map(|index| index.url.into_url()).map(|mut index| {
-                    index.remove_credentials();
-                    index
+                let index = index.map(|index| {
+                    // Retain relative paths to local indexes (e.g., `find-links` sources), such
+                    // that the lockfile remains portable.
+                    if let IndexUrl::Path(url) = &index.url
+                        && let Some(given) = url.given()
+                        && !url.was_given_absolute()
+                    {
+                        return RegistryIndexWire::Path(PortablePathBuf::from(given));
+                    }
+                    let mut url = index.url.into_url();
+                    url.remove_credentials();
+                    RegistryIndexWire::Url(url)
                 });
                 Self::Registry {
                     specifier,
@@ -1063,12 +1082,27 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                 specifier,
                 index,
                 conflict,
-            } => Ok(Self::Registry {
-                specifier,
-                index: index
-                    .map(|index| IndexMetadata::from(IndexUrl::from(VerbatimUrl::from_url(index)))),
-                conflict,
-            }),
+            } => {
+                let index = match index {
+                    Some(RegistryIndexWire::Url(url)) => Some(VerbatimUrl::from_url(url)),
+                    // As with the path sources below, the use of `CWD` here is incorrect; when
+                    // comparing these sources in the lockfile, the given path is resolved against
+                    // the workspace root instead.
+                    Some(RegistryIndexWire::Path(path)) => {
+                        let path = Box::<Path>::from(path);
+                        let url =
+                            VerbatimUrl::from_normalized_path(normalize_path(CWD.join(&path)))?
+                                .with_given(PortablePath::from(&path).to_string());
+                        Some(url)
+                    }
+                    None => None,
+                };
+                Ok(Self::Registry {
+                    specifier,
+                    index: index.map(|url| IndexMetadata::from(IndexUrl::from(url))),
+                    conflict,
+                })
+            }
             RequirementSourceWire::Git { git } => {
                 let mut repository = DisplaySafeUrl::parse(&git)?;
 
@@ -83,6 +83,28 @@ impl RequiresPython {
         Some(Self { specifiers, range })
     }
 
+    /// Returns a [`RequiresPython`] spanning the union of the given version specifiers.
+    ///
+    /// For example, given `==3.9.*` and `>=3.12`, this would return `>=3.9`. Since a
+    /// `Requires-Python` bound is a single range, any gaps between the specifiers are included.
+    pub fn union<'a>(specifiers: impl Iterator<Item = &'a VersionSpecifiers>) -> Option<Self> {
+        // Convert to PubGrub range and perform a union.
+        let range = specifiers
+            .map(|specs| release_specifiers_to_ranges(specs.clone()))
+            .reduce(|acc, r| acc.union(&r))?;
+
+        // If the union is empty, return `None`.
+        if range.is_empty() {
+            return None;
+        }
+
+        // Extract the bounds, and convert back to PEP 440 specifiers.
+        let range = RequiresPythonRange::from_range(&range);
+        let specifiers = range.specifiers();
+
+        Some(Self { specifiers, range })
+    }
+
     /// Split the [`RequiresPython`] at the given version.
     ///
     /// For example, if the current requirement is `>=3.10`, and the split point is `3.11`, then
@@ -715,6 +737,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn requires_python_union() {
+        let specifiers = [
+            VersionSpecifiers::from_str("==3.9.*").unwrap(),
+            VersionSpecifiers::from_str(">=3.12").unwrap(),
+        ];
+        let requires_python = RequiresPython::union(specifiers.iter()).unwrap();
+        assert_eq!(requires_python.to_string(), ">=3.9");
+
+        let specifiers = [
+            VersionSpecifiers::from_str(">=3.10, <3.11").unwrap(),
+            VersionSpecifiers::from_str(">=3.8, <3.9").unwrap(),
+        ];
+        let requires_python = RequiresPython::union(specifiers.iter()).unwrap();
+        assert_eq!(requires_python.to_string(), ">=3.8, <3.11");
+
+        assert_eq!(RequiresPython::union(std::iter::empty()), None);
+    }
+
     #[test]
     fn lower_bound_ordering() {
         let versions = &[
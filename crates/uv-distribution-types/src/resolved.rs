@@ -85,6 +85,22 @@ impl ResolvedDist {
         }
     }
 
+    /// Returns the URL of the [PEP 740] provenance object for the distribution, if available.
+    ///
+    /// [PEP 740]: https://peps.python.org/pep-0740/
+    pub fn provenance(&self) -> Option<&str> {
+        match self {
+            Self::Installable { dist, .. } => match dist.as_ref() {
+                Dist::Source(SourceDist::Registry(sdist)) => sdist.file.provenance.as_deref(),
+                Dist::Built(BuiltDist::Registry(wheel)) => {
+                    wheel.best_wheel().file.provenance.as_deref()
+                }
+                _ => None,
+            },
+            Self::Installed { .. } => None,
+        }
+    }
+
     /// Returns the version of the distribution, if available.
     pub fn version(&self) -> Option<&Version> {
         match self {
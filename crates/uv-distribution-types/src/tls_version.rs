@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A TLS protocol version, used to enforce a minimum version when connecting to a host.
+#[derive(
+    Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TlsVersion {
+    /// TLS 1.2.
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    /// TLS 1.3.
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+impl FromStr for TlsVersion {
+    type Err = TlsVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1.2" => Ok(Self::Tls1_2),
+            "1.3" => Ok(Self::Tls1_3),
+            _ => Err(TlsVersionError(s.to_string())),
+        }
+    }
+}
+
+impl Display for TlsVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tls1_2 => write!(f, "TLS 1.2"),
+            Self::Tls1_3 => write!(f, "TLS 1.3"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unsupported TLS version `{0}` (expected `1.2` or `1.3`)")]
+pub struct TlsVersionError(String);
@@ -93,7 +93,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
     }
 
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
-    fn handle_response_errors(&self, err: reqwest::Error) -> io::Error {
+    fn handle_response_errors(&self, mut err: reqwest::Error) -> io::Error {
         if err.is_timeout() {
             // Assumption: The connect timeout with the 10s default is not the culprit.
             io::Error::new(
@@ -104,6 +104,10 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 ),
             )
         } else {
+            // The error's `Display` includes the request URL.
+            if let Some(url) = err.url_mut() {
+                DisplaySafeUrl::redact(url);
+            }
             io::Error::other(err)
         }
     }
@@ -931,6 +935,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     }
                 }
 
+                // Exhaust the reader to compute the hash.
+                hasher.finish().await.map_err(Error::HashExhaustion)?;
+
                 // Unzip the wheel to a temporary directory.
                 let temp_dir = tempfile::tempdir_in(self.build_context.cache().root())
                     .map_err(Error::CacheWrite)?;
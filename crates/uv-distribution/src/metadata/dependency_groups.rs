@@ -7,7 +7,7 @@ use uv_configuration::NoSources;
 use uv_distribution_types::{IndexLocations, Requirement};
 use uv_normalize::{GroupName, PackageName};
 use uv_workspace::dependency_groups::FlatDependencyGroups;
-use uv_workspace::pyproject::{Sources, ToolUvSources};
+use uv_workspace::pyproject::{PyProjectToml, Sources, ToolUvSources};
 use uv_workspace::{
     DiscoveryOptions, MemberDiscovery, VirtualProject, WorkspaceCache, WorkspaceError,
     WorkspaceErrorKind,
@@ -193,6 +193,41 @@ impl SourcedDependencyGroups {
         })
     }
 
+    /// Read the dependency groups from a standalone TOML file (e.g., `groups.toml`).
+    ///
+    /// Unlike [`SourcedDependencyGroups::from_virtual_project`], the file is not treated as part
+    /// of a project or workspace, so `tool.uv.sources` and `tool.uv.index` are not applied.
+    pub async fn from_groups_file(path: &Path) -> Result<Self, MetadataError> {
+        // If the file doesn't exist, fail early.
+        if !path.is_file() {
+            return Err(MetadataError::MissingGroupsFile(path.to_path_buf()));
+        }
+
+        let contents = fs_err::tokio::read_to_string(path)
+            .await
+            .map_err(|err| WorkspaceError::from(WorkspaceErrorKind::Io(err)))?;
+        let pyproject_toml = PyProjectToml::from_string(contents, path).map_err(|err| {
+            WorkspaceError::from(WorkspaceErrorKind::Toml(path.to_path_buf(), Box::new(err)))
+        })?;
+
+        let dependency_groups = FlatDependencyGroups::from_pyproject_toml(path, &pyproject_toml)?;
+
+        Ok(Self {
+            name: None,
+            dependency_groups: dependency_groups
+                .into_iter()
+                .map(|(name, group)| {
+                    let requirements = group
+                        .requirements
+                        .into_iter()
+                        .map(Requirement::from)
+                        .collect();
+                    (name, requirements)
+                })
+                .collect(),
+        })
+    }
+
     /// Validate the sources.
     ///
     /// If a source is requested with `group`, ensure that the relevant dependency is
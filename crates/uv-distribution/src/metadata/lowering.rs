@@ -8,10 +8,10 @@ use thiserror::Error;
 use uv_auth::CredentialsCache;
 use uv_distribution_filename::DistExtension;
 use uv_distribution_types::{
-    Index, IndexCredentialsError, IndexLocations, IndexMetadata, IndexName, Origin, Requirement,
-    RequirementSource,
+    Index, IndexCredentialsError, IndexFormat, IndexLocations, IndexMetadata, IndexName, IndexUrl,
+    Origin, Requirement, RequirementSource,
 };
-use uv_fs::{Simplified, normalize_absolute_path, normalize_path};
+use uv_fs::{PortablePath, Simplified, normalize_absolute_path, normalize_path, relative_to};
 use uv_git_types::{GitLfs, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
@@ -132,6 +132,14 @@ impl LoweredRequirement {
                                 ),
                             )));
                         }
+                        Source::FindLinks { .. } => {
+                            return Either::Left(std::iter::once(Err(
+                                LoweringError::NonWorkspaceSource(
+                                    requirement.name.clone(),
+                                    SourceKind::FindLinks,
+                                ),
+                            )));
+                        }
                         Source::Workspace { .. } => {
                             // OK
                         }
@@ -264,6 +272,34 @@ impl LoweredRequirement {
                             let source = registry_source(&requirement, index, conflict);
                             (source, marker)
                         }
+                        Source::FindLinks {
+                            find_links,
+                            marker,
+                            extra,
+                            group,
+                        } => {
+                            let base = match origin {
+                                RequirementOrigin::Project => project_dir,
+                                RequirementOrigin::Workspace => workspace.install_path(),
+                            };
+                            let conflict = project_name.and_then(|project_name| {
+                                if let Some(extra) = extra {
+                                    Some(ConflictItem::from((project_name.clone(), extra)))
+                                } else {
+                                    group.map(|group| {
+                                        ConflictItem::from((project_name.clone(), group))
+                                    })
+                                }
+                            });
+                            let source = find_links_source(
+                                &requirement,
+                                find_links,
+                                base,
+                                workspace.install_path(),
+                                conflict,
+                            )?;
+                            (source, marker)
+                        }
                         Source::Workspace {
                             workspace: is_workspace,
                             marker,
@@ -496,6 +532,13 @@ impl LoweredRequirement {
                             let source = registry_source(&requirement, index, conflict);
                             (source, marker)
                         }
+                        Source::FindLinks {
+                            find_links, marker, ..
+                        } => {
+                            let source =
+                                find_links_source(&requirement, find_links, dir, dir, None)?;
+                            (source, marker)
+                        }
                         Source::Workspace { .. } => {
                             return Err(LoweringError::WorkspaceMember);
                         }
@@ -641,6 +684,7 @@ pub enum SourceKind {
     Url,
     Git,
     Registry,
+    FindLinks,
 }
 
 impl std::fmt::Display for SourceKind {
@@ -650,6 +694,7 @@ impl std::fmt::Display for SourceKind {
             Self::Url => write!(f, "URL"),
             Self::Git => write!(f, "Git"),
             Self::Registry => write!(f, "registry"),
+            Self::FindLinks => write!(f, "`find-links` directory"),
         }
     }
 }
@@ -816,6 +861,38 @@ fn registry_source(
     }
 }
 
+/// Convert a `find-links` path to a registry source, backed by a flat index over the local
+/// directory.
+///
+/// Relative paths are resolved against the given base, but retained relative to the workspace
+/// root, such that the lockfile remains portable. The directory itself is validated at install
+/// time.
+fn find_links_source(
+    requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
+    path: impl AsRef<Path>,
+    base: &Path,
+    root: &Path,
+    conflict: Option<ConflictItem>,
+) -> Result<RequirementSource, LoweringError> {
+    let path = path.as_ref();
+    let url = VerbatimUrl::from_path(path, base)?;
+    let url = if path.is_absolute() {
+        url.with_given(path.to_string_lossy())
+    } else {
+        let directory = url.to_file_path().map_err(|()| {
+            LoweringError::RelativeTo(io::Error::other("Invalid path in file URL"))
+        })?;
+        let relative = relative_to(&directory, root).map_err(LoweringError::RelativeTo)?;
+        url.with_given(PortablePath::from(&relative).to_string())
+    };
+
+    let index = IndexMetadata {
+        url: IndexUrl::from(url),
+        format: IndexFormat::Flat,
+    };
+    Ok(registry_source(requirement, index, conflict))
+}
+
 /// Convert a path string to a file or directory source.
 fn path_source(
     path: impl AsRef<Path>,
@@ -32,6 +32,8 @@ pub enum MetadataError {
     DependencyGroup(#[from] DependencyGroupError),
     #[error("No pyproject.toml found at: {0}")]
     MissingPyprojectToml(PathBuf),
+    #[error("No dependency groups file found at: {0}")]
+    MissingGroupsFile(PathBuf),
     #[error("Failed to parse entry: `{0}`")]
     LoweringError(PackageName, #[source] Box<LoweringError>),
     #[error("Failed to parse entry in group `{0}`: `{1}`")]
@@ -74,6 +76,7 @@ pub struct Metadata {
     pub provides_extra: Box<[ExtraName]>,
     pub dependency_groups: BTreeMap<GroupName, Box<[Requirement]>>,
     pub dynamic: bool,
+    pub license_expression: Option<String>,
 }
 
 impl Metadata {
@@ -90,6 +93,7 @@ impl Metadata {
             provides_extra: metadata.provides_extra,
             dependency_groups: BTreeMap::default(),
             dynamic: metadata.dynamic,
+            license_expression: metadata.license_expression,
         }
     }
 
@@ -141,6 +145,7 @@ impl Metadata {
             provides_extra,
             dependency_groups,
             dynamic,
+            license_expression: metadata.license_expression,
         })
     }
 }
@@ -234,6 +234,7 @@ pub fn write_error_chain_with_options<C: DynColor + Copy, W: fmt::Write>(
     let width = get_wrap_width(width_override);
 
     let main_msg = err.to_string();
+    uv_redacted::debug_assert_redacted(&main_msg);
     let main_padding = " ".repeat(level.len() + 2);
     let wrapped_main = wrap_text(&main_msg, width, &main_padding, &main_padding);
     writeln!(
@@ -246,6 +247,7 @@ pub fn write_error_chain_with_options<C: DynColor + Copy, W: fmt::Write>(
 
     for source in iter::successors(err.source(), |&err| err.source()) {
         let msg = source.to_string();
+        uv_redacted::debug_assert_redacted(&msg);
         let padding = "  ";
         let cause = "Caused by";
         let child_padding = " ".repeat(padding.len() + cause.len() + 2);
@@ -139,3 +139,35 @@ impl Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn streaming_unsupported() {
+        let err = Error::AsyncZip(async_zip::error::ZipError::FeatureNotSupported(
+            "data descriptors",
+        ));
+        assert!(err.is_http_streaming_unsupported());
+        assert!(!err.is_http_streaming_failed());
+    }
+
+    #[test]
+    fn streaming_failed() {
+        let err = Error::AsyncZip(async_zip::error::ZipError::UpstreamReadError(
+            std::io::Error::other("connection reset"),
+        ));
+        assert!(!err.is_http_streaming_unsupported());
+        assert!(err.is_http_streaming_failed());
+    }
+
+    #[test]
+    fn streaming_io_error() {
+        // Local I/O errors (e.g., while writing to the cache) shouldn't trigger a fallback to
+        // downloading the archive to disk.
+        let err = Error::Io(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert!(!err.is_http_streaming_unsupported());
+        assert!(!err.is_http_streaming_failed());
+    }
+}
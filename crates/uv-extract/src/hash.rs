@@ -1,17 +1,53 @@
+use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, Instant};
+
+use aws_lc_rs::digest;
 use blake2::digest::consts::U32;
 use sha2::Digest;
-use std::pin::Pin;
-use std::task::{Context, Poll};
 use tokio::io::{AsyncReadExt, ReadBuf};
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use tokio_util::sync::PollSender;
+use tracing::debug;
 
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 
-#[derive(Debug)]
+/// The number of chunks that can be queued for hashing before reads are paused, such that memory
+/// usage stays bounded when hashing falls behind the underlying reader.
+const HASH_QUEUE_CAPACITY: usize = 16;
+
+/// The number of [`HashWorker`]s that can run at once, i.e., one per available core.
+///
+/// Readers that are created while every worker is busy hash their chunks inline instead, such that
+/// the number of blocking threads (and queued chunks) stays bounded regardless of how many
+/// artifacts are read concurrently.
+static HASH_WORKERS: LazyLock<Arc<Semaphore>> = LazyLock::new(|| {
+    Arc::new(Semaphore::new(
+        std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    ))
+});
+
+/// The total number of bytes hashed by all [`HashReader`]s.
+static HASHED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The total time spent hashing by all [`HashReader`]s, in nanoseconds.
+static HASHING_NANOS: AtomicU64 = AtomicU64::new(0);
+
+/// A hasher for a single [`HashAlgorithm`].
+///
+/// The SHA-2 family is backed by AWS-LC, which dispatches to the SHA-NI (x86) and SHA-2 (ARMv8)
+/// instructions at runtime, when available.
+#[derive(Clone)]
 pub enum Hasher {
     Md5(md5::Md5),
-    Sha256(sha2::Sha256),
-    Sha384(sha2::Sha384),
-    Sha512(sha2::Sha512),
+    Sha256(digest::Context),
+    Sha384(digest::Context),
+    Sha512(digest::Context),
     Blake2b(blake2::Blake2b<U32>),
 }
 
@@ -25,15 +61,32 @@ impl Hasher {
             Self::Blake2b(hasher) => hasher.update(data),
         }
     }
+
+    /// Return the [`HashAlgorithm`] used by this hasher.
+    fn algorithm(&self) -> HashAlgorithm {
+        match self {
+            Self::Md5(_) => HashAlgorithm::Md5,
+            Self::Sha256(_) => HashAlgorithm::Sha256,
+            Self::Sha384(_) => HashAlgorithm::Sha384,
+            Self::Sha512(_) => HashAlgorithm::Sha512,
+            Self::Blake2b(_) => HashAlgorithm::Blake2b,
+        }
+    }
+}
+
+impl std::fmt::Debug for Hasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Hasher").field(&self.algorithm()).finish()
+    }
 }
 
 impl From<HashAlgorithm> for Hasher {
     fn from(algorithm: HashAlgorithm) -> Self {
         match algorithm {
             HashAlgorithm::Md5 => Self::Md5(md5::Md5::new()),
-            HashAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
-            HashAlgorithm::Sha384 => Self::Sha384(sha2::Sha384::new()),
-            HashAlgorithm::Sha512 => Self::Sha512(sha2::Sha512::new()),
+            HashAlgorithm::Sha256 => Self::Sha256(digest::Context::new(&digest::SHA256)),
+            HashAlgorithm::Sha384 => Self::Sha384(digest::Context::new(&digest::SHA384)),
+            HashAlgorithm::Sha512 => Self::Sha512(digest::Context::new(&digest::SHA512)),
             HashAlgorithm::Blake2b => Self::Blake2b(blake2::Blake2b::new()),
         }
     }
@@ -41,34 +94,130 @@ impl From<HashAlgorithm> for Hasher {
 
 impl From<Hasher> for HashDigest {
     fn from(hasher: Hasher) -> Self {
-        match hasher {
-            Hasher::Md5(hasher) => Self {
-                algorithm: HashAlgorithm::Md5,
-                digest: format!("{:x}", hasher.finalize()).into(),
-            },
-            Hasher::Sha256(hasher) => Self {
-                algorithm: HashAlgorithm::Sha256,
-                digest: format!("{:x}", hasher.finalize()).into(),
-            },
-            Hasher::Sha384(hasher) => Self {
-                algorithm: HashAlgorithm::Sha384,
-                digest: format!("{:x}", hasher.finalize()).into(),
-            },
-            Hasher::Sha512(hasher) => Self {
-                algorithm: HashAlgorithm::Sha512,
-                digest: format!("{:x}", hasher.finalize()).into(),
-            },
-            Hasher::Blake2b(hasher) => Self {
-                algorithm: HashAlgorithm::Blake2b,
-                digest: format!("{:x}", hasher.finalize()).into(),
-            },
+        let algorithm = hasher.algorithm();
+        let digest = match hasher {
+            Hasher::Md5(hasher) => format!("{:x}", hasher.finalize()),
+            Hasher::Sha256(hasher) | Hasher::Sha384(hasher) | Hasher::Sha512(hasher) => hasher
+                .finish()
+                .as_ref()
+                .iter()
+                .fold(String::new(), |mut digest, byte| {
+                    let _ = write!(digest, "{byte:02x}");
+                    digest
+                }),
+            Hasher::Blake2b(hasher) => format!("{:x}", hasher.finalize()),
+        };
+        Self {
+            algorithm,
+            digest: digest.into(),
         }
     }
 }
 
+/// A reader that computes the digests of the underlying stream as it's read.
+///
+/// When running within a Tokio runtime, the digests are computed on the blocking thread pool,
+/// such that multiple artifacts can be hashed in parallel, and hashing doesn't stall the runtime.
+/// Once [`HASH_WORKERS`] readers are hashing in the background, further readers hash inline. The
+/// digests are only written back to the hashers by [`HashReader::finish`].
 pub struct HashReader<'a, R> {
     reader: R,
     hashers: &'a mut [Hasher],
+    worker: Option<HashWorker>,
+    /// The throughput of any chunks hashed inline, i.e., without a [`HashWorker`].
+    statistics: HashStatistics,
+}
+
+/// A task that hashes the chunks read by a [`HashReader`] on the blocking thread pool.
+struct HashWorker {
+    sender: PollSender<Vec<u8>>,
+    handle: JoinHandle<(Vec<Hasher>, HashStatistics)>,
+}
+
+impl HashWorker {
+    /// Spawn a [`HashWorker`] for the given hashers, if running within a Tokio runtime and fewer
+    /// than [`HASH_WORKERS`] workers are already running.
+    fn spawn(hashers: &[Hasher]) -> Option<Self> {
+        if hashers.is_empty() {
+            return None;
+        }
+        let runtime = tokio::runtime::Handle::try_current().ok()?;
+        let permit = HASH_WORKERS.clone().try_acquire_owned().ok()?;
+        let mut hashers = hashers.to_vec();
+        let (sender, mut receiver) = tokio::sync::mpsc::channel::<Vec<u8>>(HASH_QUEUE_CAPACITY);
+        let handle = runtime.spawn_blocking(move || {
+            let _permit = permit;
+            let mut statistics = HashStatistics::default();
+            while let Some(chunk) = receiver.blocking_recv() {
+                statistics.update(&mut hashers, &chunk);
+            }
+            (hashers, statistics)
+        });
+        Some(Self {
+            sender: PollSender::new(sender),
+            handle,
+        })
+    }
+}
+
+/// The hashing throughput, for a single [`HashReader`] or across all of them (see
+/// [`HashStatistics::total`]).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HashStatistics {
+    /// The number of bytes hashed.
+    pub bytes: u64,
+    /// The time spent hashing, excluding time spent waiting on the underlying reader.
+    pub elapsed: Duration,
+}
+
+impl HashStatistics {
+    /// Return the total throughput of all [`HashReader`]s that have finished so far.
+    pub fn total() -> Self {
+        Self {
+            bytes: HASHED_BYTES.load(Ordering::Relaxed),
+            elapsed: Duration::from_nanos(HASHING_NANOS.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Return the throughput, in bytes per second.
+    pub fn throughput(&self) -> f64 {
+        #[expect(clippy::cast_precision_loss)]
+        let bytes = self.bytes as f64;
+        bytes / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Hash the given chunk, recording the time spent.
+    fn update(&mut self, hashers: &mut [Hasher], chunk: &[u8]) {
+        let start = Instant::now();
+        for hasher in hashers {
+            hasher.update(chunk);
+        }
+        self.bytes += chunk.len() as u64;
+        self.elapsed += start.elapsed();
+    }
+
+    /// Add the statistics for a finished [`HashReader`] to the totals.
+    fn record(&self, hashers: &[Hasher]) {
+        if self.bytes == 0 {
+            return;
+        }
+        HASHED_BYTES.fetch_add(self.bytes, Ordering::Relaxed);
+        HASHING_NANOS.fetch_add(
+            u64::try_from(self.elapsed.as_nanos()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+        debug!(
+            "Computed {} of {} bytes in {:.2}ms ({:.1} MiB/s)",
+            hashers
+                .iter()
+                .map(|hasher| hasher.algorithm().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.bytes,
+            self.elapsed.as_secs_f64() * 1000.0,
+            self.throughput() / (1024.0 * 1024.0),
+        );
+    }
 }
 
 impl<'a, R> HashReader<'a, R>
@@ -76,12 +225,31 @@ where
     R: tokio::io::AsyncRead + Unpin,
 {
     pub fn new(reader: R, hashers: &'a mut [Hasher]) -> Self {
-        HashReader { reader, hashers }
+        let worker = HashWorker::spawn(hashers);
+        HashReader {
+            reader,
+            hashers,
+            worker,
+            statistics: HashStatistics::default(),
+        }
     }
 
-    /// Exhaust the underlying reader.
+    /// Exhaust the underlying reader, and write the computed digests back to the hashers.
     pub async fn finish(&mut self) -> Result<(), std::io::Error> {
-        while self.read(&mut vec![0; 8192]).await? > 0 {}
+        let mut buffer = vec![0; 64 * 1024];
+        while self.read(&mut buffer).await? > 0 {}
+
+        if let Some(HashWorker { sender, handle }) = self.worker.take() {
+            // Close the channel, such that the worker exits once it's hashed the remaining chunks.
+            drop(sender);
+            let (hashers, statistics) = handle.await.map_err(std::io::Error::other)?;
+            statistics.record(&hashers);
+            for (target, hasher) in self.hashers.iter_mut().zip(hashers) {
+                *target = hasher;
+            }
+        } else {
+            std::mem::take(&mut self.statistics).record(self.hashers);
+        }
 
         Ok(())
     }
@@ -96,15 +264,134 @@ where
         cx: &mut Context<'_>,
         buf: &mut ReadBuf<'_>,
     ) -> Poll<std::io::Result<()>> {
-        let reader = Pin::new(&mut self.reader);
-        match reader.poll_read(cx, buf) {
-            Poll::Ready(Ok(())) => {
-                for hasher in self.hashers.iter_mut() {
-                    hasher.update(buf.filled());
-                }
-                Poll::Ready(Ok(()))
+        let this = &mut *self;
+
+        // Wait for room in the queue before reading, to apply back-pressure to the reader.
+        if let Some(worker) = &mut this.worker {
+            ready!(worker.sender.poll_reserve(cx))
+                .map_err(|_| std::io::Error::other("Hashing task exited unexpectedly"))?;
+        }
+
+        let filled = buf.filled().len();
+        ready!(Pin::new(&mut this.reader).poll_read(cx, buf))?;
+        let chunk = &buf.filled()[filled..];
+
+        match &mut this.worker {
+            Some(worker) if chunk.is_empty() => {
+                worker.sender.abort_send();
+            }
+            Some(worker) => {
+                worker
+                    .sender
+                    .send_item(chunk.to_vec())
+                    .map_err(|_| std::io::Error::other("Hashing task exited unexpectedly"))?;
+            }
+            None => {
+                this.statistics.update(this.hashers, chunk);
             }
-            other => other,
         }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+    use super::{HASH_WORKERS, HashReader, HashStatistics, Hasher};
+
+    /// Serializes the tests that acquire [`HASH_WORKERS`].
+    static WORKERS: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    #[tokio::test]
+    async fn digests() {
+        let _guard = WORKERS.lock().await;
+        let mut hashers = [
+            HashAlgorithm::Md5,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Blake2b,
+        ]
+        .into_iter()
+        .map(Hasher::from)
+        .collect::<Vec<_>>();
+
+        let mut reader = HashReader::new(b"abc".as_slice(), &mut hashers);
+        reader.finish().await.unwrap();
+
+        let digests = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .map(|digest| digest.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            digests,
+            [
+                "md5:900150983cd24fb0d6963f7d28e17f72",
+                "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "sha384:cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+                "sha512:ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+                "blake2b:bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+            ]
+        );
+    }
+
+    /// Inputs that span multiple reads should produce the same digest as a single read, when hashed
+    /// on the blocking thread pool.
+    #[tokio::test]
+    async fn digest_large() {
+        let _guard = WORKERS.lock().await;
+        let contents = vec![b'a'; 1_000_000];
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader = HashReader::new(contents.as_slice(), &mut hashers);
+        assert!(reader.worker.is_some());
+        reader.finish().await.unwrap();
+
+        let digest = HashDigest::from(hashers.pop().unwrap());
+        assert_eq!(
+            digest.to_string(),
+            "sha256:cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+
+    /// Once every worker is busy, further readers should hash inline, and still produce the same
+    /// digests.
+    #[tokio::test]
+    async fn digest_bounded_workers() {
+        let _guard = WORKERS.lock().await;
+        let permits = u32::try_from(HASH_WORKERS.available_permits()).unwrap();
+        let busy = HASH_WORKERS.acquire_many(permits).await.unwrap();
+
+        let contents = vec![b'a'; 1_000_000];
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader = HashReader::new(contents.as_slice(), &mut hashers);
+        assert!(reader.worker.is_none());
+        reader.finish().await.unwrap();
+        drop(busy);
+
+        let digest = HashDigest::from(hashers.pop().unwrap());
+        assert_eq!(
+            digest.to_string(),
+            "sha256:cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+        assert!(HashStatistics::total().bytes >= 1_000_000);
+    }
+
+    /// Outside of a Tokio runtime, the digests should be computed inline.
+    #[test]
+    fn digest_without_runtime() {
+        let contents = vec![b'a'; 1_000_000];
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut reader = HashReader::new(contents.as_slice(), &mut hashers);
+        assert!(reader.worker.is_none());
+        futures::executor::block_on(reader.finish()).unwrap();
+
+        let digest = HashDigest::from(hashers.pop().unwrap());
+        assert_eq!(
+            digest.to_string(),
+            "sha256:cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
     }
 }
@@ -7,6 +7,7 @@ bitflags::bitflags! {
     pub struct EnvironmentFlags: u32 {
         const SKIP_WHEEL_FILENAME_CHECK = 1 << 0;
         const HIDE_BUILD_OUTPUT = 1 << 1;
+        const TIMINGS = 1 << 2;
     }
 }
 
@@ -3,9 +3,9 @@
 
 use std::io;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, LazyLock, Mutex};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 use uv_warnings::warn_user_once;
 use walkdir::WalkDir;
@@ -15,7 +15,7 @@ use walkdir::WalkDir;
 /// Defaults to [`LinkMode::Clone`] on macOS and Linux (which support copy-on-write on
 /// APFS and btrfs/xfs/bcachefs respectively), and [`LinkMode::Hardlink`] on other
 /// platforms.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "serde",
@@ -25,6 +25,9 @@ use walkdir::WalkDir;
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum LinkMode {
     /// Clone (i.e., copy-on-write) packages from the source into the destination.
+    ///
+    /// Also accepted as `reflink`. Falls back to hard linking, then copying, if the filesystem
+    /// does not support copy-on-write.
     #[cfg_attr(feature = "serde", serde(alias = "reflink"))]
     #[cfg_attr(feature = "clap", value(alias = "reflink"))]
     Clone,
@@ -72,6 +75,10 @@ pub enum OnExistingDirectory {
 /// Returns the [`LinkMode`] that was actually used, which may differ from the requested mode if a
 /// fallback was needed, e.g., if hard linking was requested but the source and destination are on
 /// different filesystems.
+///
+/// The mode that was used is cached for the pair of source and destination devices, such that
+/// subsequent invocations (e.g., for each wheel installed from the cache into the same virtual
+/// environment) start from the supported mode rather than re-attempting the unsupported one.
 pub fn link_dir<F>(
     src: &Path,
     dst: &Path,
@@ -80,9 +87,78 @@ pub fn link_dir<F>(
 where
     F: Fn(&Path) -> bool,
 {
-    match options.mode {
-        LinkMode::Clone => clone_dir(src, dst, options),
-        mode => walk_and_link(src, dst, mode, options),
+    let devices = DevicePair::new(src, dst);
+    let mode = devices
+        .and_then(|devices| LINK_CAPABILITIES.get(options.mode, devices))
+        .unwrap_or(options.mode);
+
+    let used = match mode {
+        LinkMode::Clone => clone_dir(src, dst, options)?,
+        mode => walk_and_link(src, dst, mode, options)?,
+    };
+
+    if let Some(devices) = devices {
+        LINK_CAPABILITIES.insert(options.mode, devices, used);
+    }
+
+    Ok(used)
+}
+
+/// The [`LinkMode`] that was used for each requested mode and pair of devices.
+static LINK_CAPABILITIES: LazyLock<LinkCapabilities> = LazyLock::new(LinkCapabilities::default);
+
+/// The devices of the source and destination of a link operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DevicePair {
+    src: u64,
+    dst: u64,
+}
+
+impl DevicePair {
+    /// Determine the devices of `src` and `dst`, using the nearest existing ancestor of `dst` if
+    /// it does not yet exist.
+    #[cfg(unix)]
+    fn new(src: &Path, dst: &Path) -> Option<Self> {
+        use std::os::unix::fs::MetadataExt;
+
+        let src = fs_err::metadata(src).ok()?.dev();
+        let dst = dst
+            .ancestors()
+            .find_map(|path| fs_err::metadata(path).ok())?
+            .dev();
+        Some(Self { src, dst })
+    }
+
+    /// Device identifiers are not available on this platform, so capabilities are not cached.
+    #[cfg(not(unix))]
+    fn new(_src: &Path, _dst: &Path) -> Option<Self> {
+        None
+    }
+}
+
+/// A cache of the link modes supported between pairs of devices.
+///
+/// We can't detect whether a filesystem supports a given link mode ahead of time; instead, we
+/// attempt it on the first file and fall back on failure (see [`LinkAttempt`]). Caching the outcome
+/// avoids paying for (and logging) the failed attempt on every [`link_dir`] invocation.
+#[derive(Debug, Default)]
+struct LinkCapabilities(Mutex<FxHashMap<(LinkMode, DevicePair), LinkMode>>);
+
+impl LinkCapabilities {
+    /// Return the mode that was used for a previous `requested` link between `devices`, if any.
+    fn get(&self, requested: LinkMode, devices: DevicePair) -> Option<LinkMode> {
+        self.0.lock().unwrap().get(&(requested, devices)).copied()
+    }
+
+    /// Record the mode that was used for a `requested` link between `devices`.
+    fn insert(&self, requested: LinkMode, devices: DevicePair, used: LinkMode) {
+        let previous = self.0.lock().unwrap().insert((requested, devices), used);
+        if previous != Some(used) {
+            debug!(
+                "Using link mode `{used:?}` (requested `{requested:?}`) from device {} to device {}",
+                devices.src, devices.dst
+            );
+        }
     }
 }
 
@@ -140,6 +216,8 @@ pub struct LinkOptions<'a, F = fn(&Path) -> bool> {
     copy_locks: Option<&'a CopyLocks>,
     /// What to do when the destination directory already exists.
     on_existing_directory: OnExistingDirectory,
+    /// Files, relative to the source directory, that should be left untouched at the destination.
+    skip: Option<&'a FxHashSet<PathBuf>>,
 }
 
 impl LinkOptions<'static> {
@@ -150,6 +228,7 @@ impl LinkOptions<'static> {
             needs_mutable_copy: |_| false,
             copy_locks: None,
             on_existing_directory: OnExistingDirectory::default(),
+            skip: None,
         }
     }
 }
@@ -174,6 +253,7 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: f,
             copy_locks: self.copy_locks,
             on_existing_directory: self.on_existing_directory,
+            skip: self.skip,
         }
     }
 
@@ -188,6 +268,7 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: self.needs_mutable_copy,
             copy_locks: Some(locks),
             on_existing_directory: self.on_existing_directory,
+            skip: self.skip,
         }
     }
 
@@ -199,6 +280,22 @@ impl<'a, F> LinkOptions<'a, F> {
             needs_mutable_copy: self.needs_mutable_copy,
             copy_locks: self.copy_locks,
             on_existing_directory,
+            skip: self.skip,
+        }
+    }
+
+    /// Set the files that should not be linked, as paths relative to the source directory.
+    ///
+    /// Intended for files that already exist with identical contents at the destination, e.g.,
+    /// when upgrading a package to a version in which most files are unchanged.
+    #[must_use]
+    pub fn with_skip(self, skip: &'a FxHashSet<PathBuf>) -> Self {
+        LinkOptions {
+            mode: self.mode,
+            needs_mutable_copy: self.needs_mutable_copy,
+            copy_locks: self.copy_locks,
+            on_existing_directory: self.on_existing_directory,
+            skip: Some(skip),
         }
     }
 
@@ -328,18 +425,21 @@ fn clone_dir<F>(src: &Path, dst: &Path, options: &LinkOptions<'_, F>) -> Result<
 where
     F: Fn(&Path) -> bool,
 {
-    // On macOS, try to clone the entire directory in one syscall.
+    // On macOS, try to clone the entire directory in one syscall, unless some files need to be
+    // skipped.
     #[cfg(target_os = "macos")]
     {
-        match try_clone_dir_recursive(src, dst, options) {
-            Ok(()) => return Ok(LinkMode::Clone),
-            Err(e) => {
-                debug!(
-                    "Failed to clone `{}` to `{}`: {}, falling back to per-file reflink",
-                    src.display(),
-                    dst.display(),
-                    e
-                );
+        if options.skip.is_none() {
+            match try_clone_dir_recursive(src, dst, options) {
+                Ok(()) => return Ok(LinkMode::Clone),
+                Err(e) => {
+                    debug!(
+                        "Failed to clone `{}` to `{}`: {}, falling back to per-file reflink",
+                        src.display(),
+                        dst.display(),
+                        e
+                    );
+                }
             }
         }
     }
@@ -380,6 +480,10 @@ where
             continue;
         }
 
+        if options.skip.is_some_and(|skip| skip.contains(relative)) {
+            continue;
+        }
+
         state = link_file(path, &target, state, options)?;
     }
 
@@ -983,6 +1087,73 @@ mod tests {
         verify_test_tree(dst_dir.path());
     }
 
+    #[test]
+    fn test_link_mode_cached_per_device_pair() {
+        let src_dir = test_tempdir();
+        let dst_dir = test_tempdir();
+
+        create_test_tree(src_dir.path());
+
+        let options = LinkOptions::new(LinkMode::Clone);
+        let result = link_dir(
+            src_dir.path(),
+            dst_dir.path().join("first").as_path(),
+            &options,
+        )
+        .unwrap();
+        verify_test_tree(&dst_dir.path().join("first"));
+
+        // The mode that was used is cached for the pair of devices.
+        #[cfg(unix)]
+        {
+            let devices = DevicePair::new(src_dir.path(), dst_dir.path()).unwrap();
+            assert_eq!(
+                LINK_CAPABILITIES.get(LinkMode::Clone, devices),
+                Some(result)
+            );
+        }
+
+        // Subsequent links between the same devices use the cached mode.
+        let second = link_dir(
+            src_dir.path(),
+            dst_dir.path().join("second").as_path(),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(second, result);
+        verify_test_tree(&dst_dir.path().join("second"));
+    }
+
+    #[test]
+    fn test_link_dir_skip() {
+        let src_dir = test_tempdir();
+        let dst_dir = test_tempdir();
+
+        create_test_tree(src_dir.path());
+
+        // An existing file at the destination that should be left untouched.
+        fs_err::write(dst_dir.path().join("file1.txt"), "existing").unwrap();
+
+        let skip = FxHashSet::from_iter([PathBuf::from("file1.txt")]);
+        let options = LinkOptions::new(LinkMode::Copy)
+            .with_on_existing_directory(OnExistingDirectory::Merge)
+            .with_skip(&skip);
+        link_dir(src_dir.path(), dst_dir.path(), &options).unwrap();
+
+        assert_eq!(
+            fs_err::read_to_string(dst_dir.path().join("file1.txt")).unwrap(),
+            "existing"
+        );
+        assert_eq!(
+            fs_err::read_to_string(dst_dir.path().join("file2.txt")).unwrap(),
+            "content2"
+        );
+        assert_eq!(
+            fs_err::read_to_string(dst_dir.path().join("subdir/nested.txt")).unwrap(),
+            "nested content"
+        );
+    }
+
     #[test]
     fn test_hardlink_dir_basic() {
         let src_dir = test_tempdir();
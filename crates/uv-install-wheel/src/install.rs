@@ -36,6 +36,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     build_info: Option<&Build>,
     installer: Option<&str>,
     installer_metadata: bool,
+    requested: bool,
     link_mode: LinkMode,
     state: &InstallState,
 ) -> Result<(), Error> {
@@ -139,7 +140,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
         write_installer_metadata(
             site_packages,
             &dist_info_prefix,
-            true,
+            requested,
             direct_url,
             cache_info,
             build_info,
@@ -14,12 +14,17 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{InstallState, LinkMode};
 pub use record::RecordEntry;
-pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
+pub use reinstall::unchanged_files;
+pub use uninstall::{
+    Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel,
+    uninstall_wheel_preserving,
+};
 pub use wheel::{WheelFile, read_record, validate_and_heal_record};
 
 mod install;
 mod linker;
 mod record;
+mod reinstall;
 mod script;
 mod uninstall;
 mod wheel;
@@ -6,12 +6,13 @@ use std::time::SystemTime;
 
 use fs_err as fs;
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, instrument};
 
 use uv_distribution_filename::WheelFilename;
 use uv_fs::Simplified;
 use uv_fs::link::{CopyLocks, LinkOptions, OnExistingDirectory, link_dir};
+use uv_normalize::PackageName;
 use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
@@ -29,6 +30,9 @@ pub struct InstallState {
     site_packages_paths: Mutex<FxHashMap<PathBuf, BTreeSet<(WheelFilename, PathBuf)>>>,
     /// Preview settings for feature flags.
     preview: Preview,
+    /// Files, relative to site-packages, that are already installed with identical contents and
+    /// should not be relinked, by package.
+    unchanged: FxHashMap<PackageName, FxHashSet<PathBuf>>,
 }
 
 impl InstallState {
@@ -38,9 +42,21 @@ impl InstallState {
             locks: CopyLocks::default(),
             site_packages_paths: Mutex::new(FxHashMap::default()),
             preview,
+            unchanged: FxHashMap::default(),
         }
     }
 
+    /// Set the files that are already installed with identical contents, by package.
+    ///
+    /// These files are left in place rather than being relinked from the wheel.
+    #[must_use]
+    pub fn with_unchanged_files(
+        self,
+        unchanged: FxHashMap<PackageName, FxHashSet<PathBuf>>,
+    ) -> Self {
+        Self { unchanged, ..self }
+    }
+
     /// Get the underlying copy locks for use with [`uv_fs::link::link_dir`] functions.
     fn copy_locks(&self) -> &CopyLocks {
         &self.locks
@@ -266,7 +282,11 @@ pub(crate) fn link_wheel_files(
         .with_mutable_copy_filter(|p: &Path| p.ends_with("RECORD"))
         .with_copy_locks(state.copy_locks())
         .with_on_existing_directory(OnExistingDirectory::Merge);
-    let used_link_mode = link_dir(wheel, site_packages, &options)?;
+    let used_link_mode = if let Some(unchanged) = state.unchanged.get(&filename.name) {
+        link_dir(wheel, site_packages, &options.with_skip(unchanged))?
+    } else {
+        link_dir(wheel, site_packages, &options)?
+    };
 
     if used_link_mode == LinkMode::Clone {
         // The directory mtime is not updated when cloning and the mtime is
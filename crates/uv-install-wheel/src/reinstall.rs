@@ -0,0 +1,335 @@
+//! Incremental reinstalls, in which the files that are identical between an installed
+//! distribution and the wheel that replaces it are left in place.
+
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::{debug, trace};
+
+use crate::wheel::{LibKind, WheelFile, copy_and_hash, find_dist_info, read_record};
+use crate::{Error, Layout};
+
+/// Determine the files of the distribution installed at `dist_info` that are identical in the
+/// unpacked `wheel`.
+///
+/// Returns the paths, relative to site-packages, of the files that can be left in place when
+/// replacing the installed distribution with the wheel. Returns `None` if the distribution needs to
+/// be fully reinstalled, i.e., if either `RECORD` is missing or lacks hashes, if the wheel installs
+/// scripts or data files, or if the entry points changed.
+///
+/// A file is only considered unchanged if its hash is identical in both `RECORD` files _and_ the
+/// file on disk still matches that hash, such that files that were modified after installation are
+/// repaired.
+pub fn unchanged_files(
+    dist_info: &Path,
+    wheel: &Path,
+    layout: &Layout,
+) -> Result<Option<FxHashSet<PathBuf>>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(None);
+    };
+    let Some(installed_prefix) = dist_info
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".dist-info"))
+    else {
+        return Ok(None);
+    };
+
+    // Read the `RECORD` of the installed distribution.
+    let installed_record = match fs_err::File::open(dist_info.join("RECORD")) {
+        Ok(file) => read_record(file)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let dist_info_prefix = find_dist_info(wheel)?;
+
+    // The wheel must be installed into the same site-packages directory as the existing
+    // distribution (`purelib` and `platlib` can differ).
+    let wheel_text =
+        fs_err::read_to_string(wheel.join(format!("{dist_info_prefix}.dist-info/WHEEL")))?;
+    let target = match WheelFile::parse(&wheel_text)?.lib_kind() {
+        LibKind::Pure => &layout.scheme.purelib,
+        LibKind::Plat => &layout.scheme.platlib,
+    };
+    if target != site_packages {
+        debug!(
+            "Installed distribution is in `{}`, but the wheel targets `{}`",
+            site_packages.display(),
+            target.display()
+        );
+        return Ok(None);
+    }
+
+    // Scripts and data files are moved (and, in the case of scripts, rewritten) during
+    // installation, so their installed contents can't be compared against the wheel.
+    if wheel.join(format!("{dist_info_prefix}.data")).exists() {
+        debug!("Wheel contains a `.data` directory");
+        return Ok(None);
+    }
+
+    // Entry points are used to generate scripts at install time.
+    let installed_entry_points = read_if_exists(&dist_info.join("entry_points.txt"))?;
+    let entry_points =
+        read_if_exists(&wheel.join(format!("{dist_info_prefix}.dist-info/entry_points.txt")))?;
+    if installed_entry_points != entry_points {
+        debug!("Entry points changed");
+        return Ok(None);
+    }
+
+    // Read the `RECORD` of the wheel, and index the hashes of its files. The `.dist-info`
+    // directory is always replaced, so it's excluded.
+    let record = read_record(fs_err::File::open(
+        wheel.join(format!("{dist_info_prefix}.dist-info/RECORD")),
+    )?)?;
+    let mut hashes = FxHashMap::default();
+    for entry in &record {
+        if is_in_dist_info(&entry.path, &dist_info_prefix) {
+            continue;
+        }
+        let Some(hash) = entry.hash.as_deref() else {
+            debug!("Wheel `RECORD` entry `{}` is missing a hash", entry.path);
+            return Ok(None);
+        };
+        hashes.insert(entry.path.as_str(), hash);
+    }
+
+    let mut unchanged = FxHashSet::default();
+    for entry in &installed_record {
+        if is_in_dist_info(&entry.path, installed_prefix) {
+            continue;
+        }
+        let Some(hash) = entry.hash.as_deref() else {
+            debug!(
+                "Installed `RECORD` entry `{}` is missing a hash",
+                entry.path
+            );
+            return Ok(None);
+        };
+
+        // Only consider files within site-packages.
+        let relative = Path::new(&entry.path);
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            continue;
+        }
+
+        // Skip any files that changed between the two versions.
+        if hashes.get(entry.path.as_str()) != Some(&hash) {
+            continue;
+        }
+
+        // Skip any files that were modified since installation.
+        let Some(expected) = hash.strip_prefix("sha256=") else {
+            continue;
+        };
+        let path = site_packages.join(relative);
+        let mut file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual.strip_prefix("sha256=") != Some(expected) {
+            trace!("Installed file was modified: {}", path.display());
+            continue;
+        }
+
+        unchanged.insert(relative.to_path_buf());
+    }
+
+    Ok(Some(unchanged))
+}
+
+/// Returns `true` if the `RECORD` path is within the `.dist-info` directory for `prefix`.
+fn is_in_dist_info(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.starts_with(".dist-info/"))
+}
+
+/// Read a file to a string, returning `None` if it doesn't exist.
+fn read_if_exists(path: &Path) -> Result<Option<String>, Error> {
+    match fs_err::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+
+    use uv_pypi_types::Scheme;
+
+    use super::unchanged_files;
+    use crate::Layout;
+
+    const WHEEL: &str = indoc! {"
+        Wheel-Version: 1.0
+        Generator: test
+        Root-Is-Purelib: true
+        Tag: py3-none-any
+    "};
+
+    fn layout(venv: &assert_fs::TempDir) -> Layout {
+        let site_packages = venv.path().join("lib/python3.12/site-packages");
+        Layout {
+            sys_executable: venv.path().join("bin/python"),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.clone(),
+                platlib: site_packages,
+                scripts: venv.path().join("bin"),
+                data: venv.path().to_path_buf(),
+                include: venv.path().join("include/python3.12"),
+            },
+        }
+    }
+
+    /// Compute the `RECORD` hash of the given contents.
+    fn hash(contents: &str) -> String {
+        let (_, hash) =
+            crate::wheel::copy_and_hash(&mut contents.as_bytes(), &mut std::io::sink()).unwrap();
+        hash
+    }
+
+    /// Write a `.dist-info` directory, with a `RECORD` listing the given files.
+    fn write_dist_info(dir: &Path, prefix: &str, files: &[(&str, &str)]) {
+        let mut record = String::new();
+        for (path, contents) in files {
+            record.push_str(&format!("{path},{},{}\n", hash(contents), contents.len()));
+        }
+        record.push_str(&format!(
+            "{prefix}.dist-info/WHEEL,{},{}\n",
+            hash(WHEEL),
+            WHEEL.len()
+        ));
+        record.push_str(&format!("{prefix}.dist-info/RECORD,,\n"));
+        let dist_info = dir.join(format!("{prefix}.dist-info"));
+        fs_err::create_dir_all(&dist_info).unwrap();
+        fs_err::write(dist_info.join("RECORD"), record).unwrap();
+        fs_err::write(dist_info.join("WHEEL"), WHEEL).unwrap();
+    }
+
+    #[test]
+    fn unchanged() {
+        let venv = assert_fs::TempDir::new().unwrap();
+        let layout = layout(&venv);
+        let site_packages = venv.child("lib/python3.12/site-packages");
+        let wheel = assert_fs::TempDir::new().unwrap();
+
+        // Install version 1.0.0, and modify one of its files in place.
+        let installed = [
+            ("pkg/__init__.py", "same"),
+            ("pkg/changed.py", "old"),
+            ("pkg/corrupted.py", "same"),
+            ("pkg/removed.py", "removed"),
+        ];
+        for (path, contents) in installed {
+            site_packages.child(path).write_str(contents).unwrap();
+        }
+        site_packages
+            .child("pkg/corrupted.py")
+            .write_str("corrupted")
+            .unwrap();
+        write_dist_info(site_packages.path(), "pkg-1.0.0", &installed);
+
+        // Unpack version 2.0.0.
+        let unpacked = [
+            ("pkg/__init__.py", "same"),
+            ("pkg/changed.py", "new"),
+            ("pkg/corrupted.py", "same"),
+            ("pkg/added.py", "added"),
+        ];
+        for (path, contents) in unpacked {
+            wheel.child(path).write_str(contents).unwrap();
+        }
+        write_dist_info(wheel.path(), "pkg-2.0.0", &unpacked);
+
+        let unchanged = unchanged_files(
+            &site_packages.path().join("pkg-1.0.0.dist-info"),
+            wheel.path(),
+            &layout,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            unchanged.into_iter().collect::<Vec<_>>(),
+            vec![PathBuf::from("pkg/__init__.py")]
+        );
+    }
+
+    #[test]
+    fn missing_hash() {
+        let venv = assert_fs::TempDir::new().unwrap();
+        let layout = layout(&venv);
+        let site_packages = venv.child("lib/python3.12/site-packages");
+        let wheel = assert_fs::TempDir::new().unwrap();
+
+        site_packages
+            .child("pkg/__init__.py")
+            .write_str("same")
+            .unwrap();
+        site_packages
+            .child("pkg-1.0.0.dist-info/RECORD")
+            .write_str("pkg/__init__.py,,\npkg-1.0.0.dist-info/RECORD,,\n")
+            .unwrap();
+
+        wheel.child("pkg/__init__.py").write_str("same").unwrap();
+        write_dist_info(wheel.path(), "pkg-2.0.0", &[("pkg/__init__.py", "same")]);
+
+        assert!(
+            unchanged_files(
+                &site_packages.path().join("pkg-1.0.0.dist-info"),
+                wheel.path(),
+                &layout,
+            )
+            .unwrap()
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn entry_points_changed() {
+        let venv = assert_fs::TempDir::new().unwrap();
+        let layout = layout(&venv);
+        let site_packages = venv.child("lib/python3.12/site-packages");
+        let wheel = assert_fs::TempDir::new().unwrap();
+
+        site_packages
+            .child("pkg/__init__.py")
+            .write_str("same")
+            .unwrap();
+        write_dist_info(
+            site_packages.path(),
+            "pkg-1.0.0",
+            &[("pkg/__init__.py", "same")],
+        );
+
+        wheel.child("pkg/__init__.py").write_str("same").unwrap();
+        write_dist_info(wheel.path(), "pkg-2.0.0", &[("pkg/__init__.py", "same")]);
+        wheel
+            .child("pkg-2.0.0.dist-info/entry_points.txt")
+            .write_str("[console_scripts]\npkg = pkg:main\n")
+            .unwrap();
+
+        assert!(
+            unchanged_files(
+                &site_packages.path().join("pkg-1.0.0.dist-info"),
+                wheel.path(),
+                &layout,
+            )
+            .unwrap()
+            .is_none()
+        );
+    }
+}
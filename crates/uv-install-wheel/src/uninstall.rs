@@ -3,6 +3,7 @@ use std::fmt::Display;
 use std::path::{Component, Path, PathBuf};
 use std::sync::{LazyLock, Mutex, OnceLock};
 
+use rustc_hash::FxHashSet;
 use tracing::trace;
 
 use uv_fs::write_atomic_sync;
@@ -17,6 +18,20 @@ pub fn uninstall_wheel(
     dist_info: &Path,
     distribution: impl Display,
     layout: &Layout,
+) -> Result<Uninstall, Error> {
+    uninstall_wheel_preserving(dist_info, distribution, layout, &FxHashSet::default())
+}
+
+/// Uninstall the wheel represented by the given `.dist-info` directory, leaving the files in
+/// `preserve` (as paths relative to site-packages) in place.
+///
+/// Used when replacing a distribution with a wheel that contains some of the same files (see
+/// [`crate::unchanged_files`]).
+pub fn uninstall_wheel_preserving(
+    dist_info: &Path,
+    distribution: impl Display,
+    layout: &Layout,
+    preserve: &FxHashSet<PathBuf>,
 ) -> Result<Uninstall, Error> {
     let Some(site_packages) = dist_info.parent() else {
         return Err(Error::BrokenVenv(
@@ -24,6 +39,11 @@ pub fn uninstall_wheel(
         ));
     };
 
+    // Use an absolute, normalized path, such that the paths derived from the RECORD can be compared
+    // against the installation scheme, even if the site-packages directory was provided as a
+    // relative path (e.g., `--target ./target`).
+    let site_packages = &normalize_path(&std::path::absolute(site_packages)?);
+
     // Read the RECORD file.
     let record = {
         let record_path = dist_info.join("RECORD");
@@ -52,6 +72,11 @@ pub fn uninstall_wheel(
             continue;
         }
 
+        if preserve.contains(Path::new(&entry.path)) {
+            trace!("Preserved file: {}", path.display());
+            continue;
+        }
+
         // On Windows, deleting the current executable is a special case.
         #[cfg(windows)]
         if let Some(itself) = itself.as_ref() {
@@ -84,6 +109,18 @@ pub fn uninstall_wheel(
                 if let Some(parent) = path.parent() {
                     visited.insert(normalize_path(parent));
                 }
+
+                // Remove any bytecode compiled from the module, which is rarely listed in the
+                // RECORD (e.g., if it was compiled at import time).
+                if path.extension().is_some_and(|ext| ext == "py") {
+                    let removed = remove_bytecode(&path)?;
+                    if removed > 0 {
+                        file_count += removed;
+                        if let Some(parent) = path.parent() {
+                            visited.insert(normalize_path(&parent.join("__pycache__")));
+                        }
+                    }
+                }
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => match fs_err::remove_dir_all(&path) {
@@ -115,30 +152,36 @@ pub fn uninstall_wheel(
                 break;
             }
 
-            // If the directory contains a `__pycache__` directory, always remove it. `__pycache__`
-            // may or may not be listed in the RECORD, but installers are expected to be smart
-            // enough to remove it either way.
-            let pycache = path.join("__pycache__");
-            match fs_err::remove_dir_all(&pycache) {
-                Ok(()) => {
-                    trace!("Removed directory: {}", pycache.display());
-                    dir_count += 1;
-                }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-                Err(err) => return Err(err.into()),
-            }
-
             // Try to read from the directory. If it doesn't exist, assume we deleted it in a
             // previous iteration.
-            let mut read_dir = match fs_err::read_dir(path) {
-                Ok(read_dir) => read_dir,
+            let entries = match fs_err::read_dir(path) {
+                Ok(read_dir) => read_dir
+                    .map(|entry| entry.map(|entry| entry.file_name()))
+                    .collect::<Result<Vec<_>, _>>()?,
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
                 Err(err) => return Err(err.into()),
             };
 
-            // If the directory is not empty, we're done.
-            if read_dir.next().is_some() {
-                break;
+            // If the directory contains nothing but a `__pycache__` directory, remove it.
+            // `__pycache__` may or may not be listed in the RECORD, but installers are expected
+            // to be smart enough to remove it either way. In a directory shared with other
+            // distributions (e.g., a namespace package), the bytecode of the other
+            // distributions' modules is left in place.
+            match entries.as_slice() {
+                [] => {}
+                [entry] if entry == "__pycache__" => {
+                    let pycache = path.join("__pycache__");
+                    match fs_err::remove_dir_all(&pycache) {
+                        Ok(()) => {
+                            trace!("Removed directory: {}", pycache.display());
+                            dir_count += 1;
+                        }
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+                // If the directory is not empty, we're done.
+                _ => break,
             }
 
             fs_err::remove_dir(path)?;
@@ -160,6 +203,61 @@ pub fn uninstall_wheel(
     })
 }
 
+/// Remove the bytecode compiled from the given Python source file, i.e., any
+/// `__pycache__/{module}.{tag}.pyc` (and `.opt-N.pyc`) files next to it.
+///
+/// Returns the number of removed files.
+fn remove_bytecode(source: &Path) -> Result<usize, Error> {
+    let (Some(parent), Some(stem)) = (source.parent(), source.file_stem()) else {
+        return Ok(0);
+    };
+    let Some(stem) = stem.to_str() else {
+        return Ok(0);
+    };
+
+    let pycache = parent.join("__pycache__");
+    let read_dir = match fs_err::read_dir(&pycache) {
+        Ok(read_dir) => read_dir,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut count = 0;
+    for entry in read_dir {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+
+        // Match `{module}.{tag}.pyc`, where the tag may carry an optimization suffix, e.g.,
+        // `foo.cpython-312.pyc` or `foo.cpython-312.opt-1.pyc`.
+        let is_bytecode = file_name
+            .strip_prefix(stem)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .and_then(|rest| rest.strip_suffix(".pyc"))
+            .is_some_and(|tag| {
+                let tag = tag.split_once(".opt-").map_or(tag, |(tag, _)| tag);
+                !tag.is_empty() && !tag.contains('.')
+            });
+        if !is_bytecode {
+            continue;
+        }
+
+        let path = entry.path();
+        match fs_err::remove_file(&path) {
+            Ok(()) => {
+                trace!("Removed file: {}", path.display());
+                count += 1;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(count)
+}
+
 static WARNED_FOR_RECORD_ENTRY_PACKAGE: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
 static WARNED_FOR_EGG_TOP_LEVEL_PACKAGE: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
 
@@ -176,6 +274,12 @@ fn is_path_in_scheme(
     layout: &Layout,
 ) -> bool {
     let normalized = normalize_path(&site_packages.join(path));
+    let in_scheme = |directory: &Path| {
+        let directory = std::path::absolute(directory)
+            .map(|directory| normalize_path(&directory))
+            .unwrap_or_else(|_| directory.to_path_buf());
+        normalized.starts_with(directory)
+    };
 
     // `purelib` or `platlib` are site-packages (depending on `Root-Is-Purelib`). As
     // `.data/*` goes into the directories of `scheme`, `.dist-info` goes into site-packages
@@ -186,11 +290,11 @@ fn is_path_in_scheme(
     // `.data/data`. For a system environment, wheels are allowed to write to
     // whole system directories, for example `data` is `/usr/local` for system Python on
     // Ubuntu 24.04.
-    if normalized.starts_with(&layout.scheme.data)
-        || normalized.starts_with(&layout.scheme.purelib)
-        || normalized.starts_with(&layout.scheme.platlib)
-        || normalized.starts_with(&layout.scheme.scripts)
-        || normalized.starts_with(&layout.scheme.include)
+    if in_scheme(&layout.scheme.data)
+        || in_scheme(&layout.scheme.purelib)
+        || in_scheme(&layout.scheme.platlib)
+        || in_scheme(&layout.scheme.scripts)
+        || in_scheme(&layout.scheme.include)
     {
         true
     } else {
@@ -659,4 +763,78 @@ mod tests {
         );
         assert!(sibling_init.exists(), "sibling package must not be removed");
     }
+
+    /// Uninstall removes the bytecode of the distribution's modules and prunes the directories
+    /// left empty, without touching bytecode that belongs to other distributions.
+    #[test]
+    fn test_uninstall_removes_bytecode_and_empty_dirs() {
+        let venv = assert_fs::TempDir::new().unwrap();
+        let site_packages = venv.child("lib/python3.12/site-packages");
+
+        let dist_info = site_packages.child("mypkg-0.1.0.dist-info");
+        dist_info.create_dir_all().unwrap();
+        dist_info
+            .child("RECORD")
+            .write_str(
+                "ns/mypkg/__init__.py,,0\n\
+                 ns/mypkg/sub/module.py,,0\n\
+                 mymodule.py,,0\n\
+                 mypkg-0.1.0.dist-info/METADATA,,0\n\
+                 mypkg-0.1.0.dist-info/RECORD,,\n",
+            )
+            .unwrap();
+        dist_info.child("METADATA").touch().unwrap();
+
+        // The distribution's modules, with bytecode compiled at import time.
+        for path in [
+            "ns/mypkg/__init__.py",
+            "ns/mypkg/__pycache__/__init__.cpython-312.pyc",
+            "ns/mypkg/sub/module.py",
+            "ns/mypkg/sub/__pycache__/module.cpython-312.pyc",
+            "ns/mypkg/sub/__pycache__/module.cpython-312.opt-1.pyc",
+            "mymodule.py",
+            "__pycache__/mymodule.cpython-312.pyc",
+        ] {
+            site_packages.child(path).touch().unwrap();
+        }
+
+        // Another distribution sharing the `ns` namespace package and the top-level
+        // `__pycache__`.
+        let sibling_init = site_packages.child("ns/theirs/__init__.py");
+        sibling_init.touch().unwrap();
+        let sibling_pyc = site_packages.child("ns/theirs/__pycache__/__init__.cpython-312.pyc");
+        sibling_pyc.touch().unwrap();
+        let sibling_module_pyc = site_packages.child("__pycache__/mymodule_extra.cpython-312.pyc");
+        sibling_module_pyc.touch().unwrap();
+
+        let layout = Layout {
+            sys_executable: venv.path().join("bin/python"),
+            python_version: (3, 12),
+            os_name: "posix".to_string(),
+            scheme: Scheme {
+                purelib: site_packages.to_path_buf(),
+                platlib: site_packages.to_path_buf(),
+                scripts: venv.path().join("bin"),
+                data: venv.path().to_path_buf(),
+                include: venv.path().join("include/python3.12"),
+            },
+        };
+
+        uninstall_wheel(dist_info.path(), "mypkg 0.1.0", &layout).unwrap();
+
+        // The package, its bytecode, and the directories it left empty are gone.
+        assert!(!site_packages.child("ns/mypkg").exists());
+        assert!(!site_packages.child("mymodule.py").exists());
+        assert!(
+            !site_packages
+                .child("__pycache__/mymodule.cpython-312.pyc")
+                .exists()
+        );
+        assert!(!dist_info.exists());
+
+        // The sibling distribution, including its bytecode, survives.
+        assert!(sibling_init.exists());
+        assert!(sibling_pyc.exists());
+        assert!(sibling_module_pyc.exists());
+    }
 }
@@ -1,7 +1,9 @@
+use std::ffi::OsString;
+use std::io::Read;
 use std::panic::AssertUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 use std::{env, io, panic};
 
 use async_channel::{Receiver, SendError};
@@ -48,22 +50,41 @@ pub enum CompileError {
         #[source]
         err: Box<Self>,
     },
-    #[error("Bytecode timed out ({}s) compiling file: `{}`", elapsed.as_secs_f32(), source_file)]
-    CompileTimeout {
-        elapsed: Duration,
-        source_file: String,
-    },
     #[error("Python startup timed out ({}s)", _0.as_secs_f32())]
     StartupTimeout(Duration),
     #[error("Got invalid value from environment for {var}: {message}.")]
     EnvironmentError { var: &'static str, message: String },
 }
 
+/// A Python source file that could not be bytecode compiled.
+#[derive(Debug, Clone)]
+pub struct CompileFailure {
+    /// The path to the source file.
+    pub path: PathBuf,
+    /// The error reported by the interpreter, e.g., `SyntaxError: invalid syntax`.
+    pub message: String,
+}
+
+/// The outcome of bytecode compiling a directory.
+#[derive(Debug, Default)]
+pub struct CompileSummary {
+    /// The number of Python source files found.
+    pub files: usize,
+    /// The number of source files skipped since their bytecode was already up-to-date.
+    pub up_to_date: usize,
+    /// The source files that failed to compile, sorted by path.
+    pub failures: Vec<CompileFailure>,
+}
+
 /// Bytecode compile all file in `dir` using a pool of Python interpreters running a Python script
 /// that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
-/// a broken `python`.
+/// Compilation errors don't abort the compilation, instead they are collected in the returned
+/// [`CompileSummary`]. Files with an up-to-date timestamp-based `.pyc` are skipped without
+/// involving the interpreter.
+///
+/// There is a 60s timeout for each file to handle a broken `python`. A worker that times out
+/// records the file as failed and restarts its interpreter.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
@@ -75,12 +96,76 @@ pub async fn compile_tree(
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
-) -> Result<usize, CompileError> {
+) -> Result<CompileSummary, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths: `{}`",
         dir.display()
     );
+
+    // Find all `.py` files in the directory.
+    let sources = WalkDir::new(dir)
+        .into_iter()
+        // Otherwise we stumble over temporary files from `compileall`.
+        .filter_entry(|dir| dir.file_name() != "__pycache__")
+        .filter_map(|entry| {
+            // Retrieve the entry and its metadata, with shared handling for IO errors
+            match entry.and_then(|entry| entry.metadata().map(|metadata| (entry, metadata))) {
+                // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
+                Ok((entry, metadata)) => (metadata.is_file()
+                    && entry.path().extension().is_some_and(|ext| ext == "py"))
+                .then(|| Ok(entry.into_path())),
+                Err(err) => {
+                    if err
+                        .io_error()
+                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound)
+                    {
+                        // The directory was removed, just ignore it
+                        None
+                    } else {
+                        Some(Err(err.into()))
+                    }
+                }
+            }
+        });
+
+    compile(dir, sources, python_executable, concurrency, cache).await
+}
+
+/// Bytecode compile the given `.py` files, which must be absolute paths within `dir`.
+///
+/// Like [`compile_tree`], but limited to a known set of files, e.g., those belonging to specific
+/// packages.
+#[instrument(skip(files, python_executable))]
+pub async fn compile_files(
+    dir: &Path,
+    files: Vec<PathBuf>,
+    python_executable: &Path,
+    concurrency: &Concurrency,
+    cache: &Path,
+) -> Result<CompileSummary, CompileError> {
+    debug_assert!(
+        files.iter().all(|file| file.is_absolute()),
+        "compileall doesn't work with relative paths"
+    );
+    compile(
+        dir,
+        files.into_iter().map(Ok),
+        python_executable,
+        concurrency,
+        cache,
+    )
+    .await
+}
+
+/// Bytecode compile the given source files using a pool of Python interpreters.
+async fn compile(
+    dir: &Path,
+    sources: impl Iterator<Item = Result<PathBuf, CompileError>>,
+    python_executable: &Path,
+    concurrency: &Concurrency,
+    cache: &Path,
+) -> Result<CompileSummary, CompileError> {
     let worker_count = concurrency.installs;
 
     // A larger buffer is significantly faster than just 1 or the worker count.
@@ -153,37 +238,16 @@ pub async fn compile_tree(
     // Start the producer, sending all `.py` files to workers.
     let mut source_files = 0;
     let mut send_error = None;
-    let walker = WalkDir::new(dir)
-        .into_iter()
-        // Otherwise we stumble over temporary files from `compileall`.
-        .filter_entry(|dir| dir.file_name() != "__pycache__");
-    for entry in walker {
-        // Retrieve the entry and its metadata, with shared handling for IO errors
-        let (entry, metadata) =
-            match entry.and_then(|entry| entry.metadata().map(|metadata| (entry, metadata))) {
-                Ok((entry, metadata)) => (entry, metadata),
-                Err(err) => {
-                    if err
-                        .io_error()
-                        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound)
-                    {
-                        // The directory was removed, just ignore it
-                        continue;
-                    }
-                    return Err(err.into());
-                }
-            };
-        // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
-        if metadata.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
-            source_files += 1;
-            if let Err(err) = sender.send(entry.path().to_owned()).await {
-                // The workers exited.
-                // If e.g. something with the Python interpreter is wrong, the workers have exited
-                // with an error. We try to report this informative error and only if that fails,
-                // report the send error.
-                send_error = Some(err);
-                break;
-            }
+    for source in sources {
+        let source = source?;
+        source_files += 1;
+        if let Err(err) = sender.send(source).await {
+            // The workers exited.
+            // If e.g. something with the Python interpreter is wrong, the workers have exited
+            // with an error. We try to report this informative error and only if that fails,
+            // report the send error.
+            send_error = Some(err);
+            break;
         }
     }
 
@@ -192,13 +256,20 @@ pub async fn compile_tree(
     drop(sender);
 
     // Make sure all workers exit regularly, avoid hiding errors.
+    let mut summary = CompileSummary {
+        files: source_files,
+        ..CompileSummary::default()
+    };
     for result in futures::future::join_all(worker_handles).await {
         match result {
             // There spawning earlier errored due to a panic in a task.
             Err(_) => return Err(CompileError::Join),
             // The worker reports an error.
             Ok(Err(compile_error)) => return Err(compile_error),
-            Ok(Ok(())) => {}
+            Ok(Ok(worker_summary)) => {
+                summary.up_to_date += worker_summary.up_to_date;
+                summary.failures.extend(worker_summary.failures);
+            }
         }
     }
 
@@ -208,7 +279,17 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
-    Ok(source_files)
+    if summary.up_to_date > 0 {
+        debug!(
+            "Skipped {} files with up-to-date bytecode",
+            summary.up_to_date
+        );
+    }
+    summary
+        .failures
+        .sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(summary)
 }
 
 async fn worker(
@@ -217,81 +298,101 @@ async fn worker(
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+) -> Result<CompileSummary, CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
         .map_err(CompileError::TempFile)?;
 
-    // Sometimes, the first time we read from stdout, we get an empty string back (no newline). If
-    // we try to write to stdin, it will often be a broken pipe. In this case, we have to restart
-    // the child process
-    // https://github.com/astral-sh/uv/issues/2245
-    let wait_until_ready = async {
-        loop {
-            // If the interpreter started successful, return it, else retry.
-            if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
-            {
-                break Ok::<_, CompileError>(child);
+    let mut summary = CompileSummary::default();
+    loop {
+        // Sometimes, the first time we read from stdout, we get an empty string back (no newline).
+        // If we try to write to stdin, it will often be a broken pipe. In this case, we have to
+        // restart the child process
+        // https://github.com/astral-sh/uv/issues/2245
+        let wait_until_ready = async {
+            loop {
+                // If the interpreter started successful, return it, else retry.
+                if let Some(child) =
+                    launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                {
+                    break Ok::<_, CompileError>(child);
+                }
             }
-        }
-    };
+        };
 
-    // Handle a broken `python` by using a timeout, one that's higher than any compilation
-    // should ever take.
-    let (mut bytecode_compiler, child_stdin, mut child_stdout, mut child_stderr) =
-        if let Some(duration) = timeout {
-            tokio::time::timeout(duration, wait_until_ready)
-                .await
-                .map_err(|_| CompileError::StartupTimeout(timeout.unwrap()))??
+        // Handle a broken `python` by using a timeout, one that's higher than any compilation
+        // should ever take.
+        let (mut bytecode_compiler, child_stdin, mut child_stdout, mut child_stderr) =
+            if let Some(duration) = timeout {
+                tokio::time::timeout(duration, wait_until_ready)
+                    .await
+                    .map_err(|_| CompileError::StartupTimeout(duration))??
+            } else {
+                wait_until_ready.await?
+            };
+
+        let stderr_reader = tokio::task::spawn(async move {
+            let mut child_stderr_collected: Vec<u8> = Vec::new();
+            child_stderr
+                .read_to_end(&mut child_stderr_collected)
+                .await?;
+            Ok(child_stderr_collected)
+        });
+
+        let result = worker_main_loop(
+            &receiver,
+            child_stdin,
+            &mut child_stdout,
+            timeout,
+            &mut summary,
+        )
+        .await;
+        // Reap the process to avoid zombies.
+        let _ = bytecode_compiler.kill().await;
+
+        // If there was something printed to stderr (which shouldn't happen, we capture all
+        // compilation errors), tell the user, otherwise only forward the result.
+        let child_stderr_collected = stderr_reader
+            .await
+            .map_err(|_| CompileError::Join)?
+            .map_err(|err| CompileError::ChildStdio {
+                device: "stderr",
+                err,
+            })?;
+        let result = if child_stderr_collected.is_empty() {
+            result
         } else {
-            wait_until_ready.await?
+            let stderr = String::from_utf8_lossy(&child_stderr_collected);
+            match result {
+                Ok(exit) => {
+                    debug!(
+                        "Bytecode compilation `python` at {} stderr:\n{}\n---",
+                        interpreter.user_display(),
+                        stderr
+                    );
+                    Ok(exit)
+                }
+                Err(err) => Err(CompileError::ErrorWithStderr {
+                    stderr: stderr.trim().to_string(),
+                    err: Box::new(err),
+                }),
+            }
         };
 
-    let stderr_reader = tokio::task::spawn(async move {
-        let mut child_stderr_collected: Vec<u8> = Vec::new();
-        child_stderr
-            .read_to_end(&mut child_stderr_collected)
-            .await?;
-        Ok(child_stderr_collected)
-    });
-
-    let result = worker_main_loop(receiver, child_stdin, &mut child_stdout, timeout).await;
-    // Reap the process to avoid zombies.
-    let _ = bytecode_compiler.kill().await;
-
-    // If there was something printed to stderr (which shouldn't happen, we muted all errors), tell
-    // the user, otherwise only forward the result.
-    let child_stderr_collected = stderr_reader
-        .await
-        .map_err(|_| CompileError::Join)?
-        .map_err(|err| CompileError::ChildStdio {
-            device: "stderr",
-            err,
-        })?;
-    let result = if child_stderr_collected.is_empty() {
-        result
-    } else {
-        let stderr = String::from_utf8_lossy(&child_stderr_collected);
         match result {
-            Ok(()) => {
-                debug!(
-                    "Bytecode compilation `python` at {} stderr:\n{}\n---",
-                    interpreter.user_display(),
-                    stderr
-                );
-                Ok(())
+            Ok(WorkerExit::Finished) => {
+                debug!("Bytecode compilation worker exiting");
+                return Ok(summary);
+            }
+            Ok(WorkerExit::TimedOut) => {
+                debug!("Restarting bytecode compilation worker after a timeout");
+            }
+            Err(err) => {
+                debug!("Bytecode compilation worker exiting: {err:?}");
+                return Err(err);
             }
-            Err(err) => Err(CompileError::ErrorWithStderr {
-                stderr: stderr.trim().to_string(),
-                err: Box::new(err),
-            }),
         }
-    };
-
-    debug!("Bytecode compilation worker exiting: {:?}", result);
-
-    result
+    }
 }
 
 /// Returns the child and stdin/stdout/stderr on a successful launch or `None` for a broken interpreter state.
@@ -366,20 +467,54 @@ async fn launch_bytecode_compiler(
     }
 }
 
+/// Why a worker stopped processing files.
+#[derive(Debug)]
+enum WorkerExit {
+    /// All files were processed.
+    Finished,
+    /// The interpreter timed out on a file and needs to be restarted.
+    TimedOut,
+}
+
 /// We use stdin/stdout as a sort of bounded channel. We write one path to stdin, then wait until
 /// we get the same path back from stdout. This way we ensure one worker is only working on one
 /// piece of work at the same time.
+///
+/// If compilation fails, the path is followed by a tab and the error message.
 async fn worker_main_loop(
-    receiver: Receiver<PathBuf>,
+    receiver: &Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
     timeout: Option<Duration>,
-) -> Result<(), CompileError> {
+    summary: &mut CompileSummary,
+) -> Result<WorkerExit, CompileError> {
+    // The script reports the cache tag and magic number if it writes timestamp-based `.pyc`
+    // files, and an empty line otherwise.
     let mut out_line = String::new();
-    while let Ok(source_file) = receiver.recv().await {
-        let source_file = source_file.display().to_string();
-        if source_file.contains(['\r', '\n']) {
-            warn_user!("Path contains newline, skipping: {source_file:?}");
+    child_stdout
+        .read_line(&mut out_line)
+        .await
+        .map_err(|err| CompileError::ChildStdio {
+            device: "stdout",
+            err,
+        })?;
+    if !out_line.ends_with('\n') {
+        return Err(CompileError::WrongPath("<cache tag>".to_string(), out_line));
+    }
+    let pyc_header = PycHeader::parse(out_line.trim_end());
+
+    while let Ok(source_path) = receiver.recv().await {
+        if pyc_header
+            .as_ref()
+            .is_some_and(|pyc_header| pyc_header.is_up_to_date(&source_path))
+        {
+            summary.up_to_date += 1;
+            continue;
+        }
+
+        let source_file = source_path.display().to_string();
+        if source_file.contains(['\r', '\n', '\t']) {
+            warn_user!("Path contains a control character, skipping: {source_file:?}");
             continue;
         }
         // Luckily, LF alone works on windows too
@@ -405,14 +540,17 @@ async fn worker_main_loop(
         };
 
         // Handle a broken `python` by using a timeout, one that's higher than any compilation
-        // should ever take.
+        // should ever take. The timeout only affects this worker: we record the file as failed and
+        // let the caller restart the interpreter.
         if let Some(duration) = timeout {
-            tokio::time::timeout(duration, python_handle)
-                .await
-                .map_err(|_| CompileError::CompileTimeout {
-                    elapsed: duration,
-                    source_file: source_file.clone(),
-                })??;
+            let Ok(result) = tokio::time::timeout(duration, python_handle).await else {
+                summary.failures.push(CompileFailure {
+                    path: source_path,
+                    message: format!("Timed out after {}s", duration.as_secs_f32()),
+                });
+                return Ok(WorkerExit::TimedOut);
+            };
+            result?;
         } else {
             python_handle.await?;
         }
@@ -420,9 +558,79 @@ async fn worker_main_loop(
         // This is a sanity check, if we don't get the path back something has gone wrong, e.g.
         // we're not actually running a python interpreter.
         let actual = out_line.trim_end_matches(['\n', '\r']);
-        if actual != source_file {
+        if let Some(message) = actual
+            .strip_prefix(source_file.as_str())
+            .and_then(|rest| rest.strip_prefix('\t'))
+        {
+            debug!("Failed to compile `{source_file}`: {message}");
+            summary.failures.push(CompileFailure {
+                path: source_path,
+                message: message.to_string(),
+            });
+        } else if actual != source_file {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
     }
-    Ok(())
+    Ok(WorkerExit::Finished)
+}
+
+/// The interpreter-specific parts of a timestamp-based `.pyc` file.
+#[derive(Debug)]
+struct PycHeader {
+    /// The cache tag used in `.pyc` file names, e.g., `cpython-312`.
+    cache_tag: String,
+    /// The bytecode magic number, read as a little-endian integer.
+    magic: u32,
+}
+
+impl PycHeader {
+    /// Parse the `<cache tag> <magic number>` line reported by the compile script.
+    fn parse(line: &str) -> Option<Self> {
+        let (cache_tag, magic) = line.split_once(' ')?;
+        Some(Self {
+            cache_tag: cache_tag.to_string(),
+            magic: magic.parse().ok()?,
+        })
+    }
+
+    /// Whether `source` already has a `.pyc` file that matches its modification time and size.
+    ///
+    /// This is the same check `compileall` performs before recompiling a file, but avoids a round
+    /// trip to the interpreter, so re-compiling an unchanged environment is cheap.
+    fn is_up_to_date(&self, source: &Path) -> bool {
+        let (Some(parent), Some(stem)) = (source.parent(), source.file_stem()) else {
+            return false;
+        };
+        let Ok(metadata) = fs_err::metadata(source) else {
+            return false;
+        };
+        let Some(mtime) = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(SystemTime::UNIX_EPOCH).ok())
+        else {
+            return false;
+        };
+
+        let mut file_name = OsString::from(stem);
+        file_name.push(format!(".{}.pyc", self.cache_tag));
+        let pyc = parent.join("__pycache__").join(file_name);
+
+        let mut header = [0u8; 16];
+        if fs_err::File::open(pyc)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .is_err()
+        {
+            return false;
+        }
+
+        // The header consists of the magic number, the flags (zero for timestamp-based `.pyc`
+        // files), and the source modification time and size, both truncated to 32 bits.
+        #[expect(clippy::cast_possible_truncation)]
+        let expected = [self.magic, 0, mtime.as_secs() as u32, metadata.len() as u32];
+        header
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .eq(expected)
+    }
 }
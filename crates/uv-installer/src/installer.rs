@@ -1,15 +1,18 @@
 use std::convert;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::{FxHashMap, FxHashSet};
 use tokio::sync::oneshot;
 use tracing::{instrument, warn};
 
 use uv_cache::Cache;
 use uv_configuration::initialize_rayon_once;
-use uv_distribution_types::CachedDist;
+use uv_distribution_types::{CachedDist, Name};
 use uv_install_wheel::{Layout, LinkMode};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
 
@@ -22,8 +25,12 @@ pub struct Installer<'a> {
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// The packages that were explicitly requested, or `None` if all packages were requested.
+    requested: Option<FxHashSet<PackageName>>,
     /// Preview settings for the installer.
     preview: Preview,
+    /// Files that are already installed with identical contents, by package.
+    unchanged: FxHashMap<PackageName, FxHashSet<PathBuf>>,
 }
 
 impl<'a> Installer<'a> {
@@ -36,7 +43,9 @@ impl<'a> Installer<'a> {
             reporter: None,
             name: Some("uv".to_string()),
             metadata: true,
+            requested: None,
             preview,
+            unchanged: FxHashMap::default(),
         }
     }
 
@@ -82,6 +91,31 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the packages that were explicitly requested by the user, as opposed to installed as
+    /// dependencies of other packages.
+    ///
+    /// Only requested packages are marked with a `REQUESTED` file. By default, all packages are
+    /// considered requested.
+    #[must_use]
+    pub fn with_requested(self, requested: FxHashSet<PackageName>) -> Self {
+        Self {
+            requested: Some(requested),
+            ..self
+        }
+    }
+
+    /// Set the files that are already installed with identical contents, by package, such that
+    /// they're left in place rather than being relinked.
+    ///
+    /// See [`uv_install_wheel::unchanged_files`].
+    #[must_use]
+    pub fn with_unchanged_files(
+        self,
+        unchanged: FxHashMap<PackageName, FxHashSet<PathBuf>>,
+    ) -> Self {
+        Self { unchanged, ..self }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -92,7 +126,9 @@ impl<'a> Installer<'a> {
             reporter,
             name: installer_name,
             metadata: installer_metadata,
+            requested,
             preview,
+            unchanged,
         } = self;
 
         if cache.is_some_and(Cache::is_temporary) {
@@ -118,7 +154,9 @@ impl<'a> Installer<'a> {
                 reporter.as_ref(),
                 relocatable,
                 installer_metadata,
+                requested.as_ref(),
                 preview,
+                unchanged,
             );
 
             // This may fail if the main task was cancelled.
@@ -149,7 +187,9 @@ impl<'a> Installer<'a> {
             self.reporter.as_ref(),
             self.venv.relocatable(),
             self.metadata,
+            self.requested.as_ref(),
             self.preview,
+            self.unchanged,
         )
     }
 }
@@ -164,11 +204,13 @@ fn install(
     reporter: Option<&Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
+    requested: Option<&FxHashSet<PackageName>>,
     preview: Preview,
+    unchanged: FxHashMap<PackageName, FxHashSet<PathBuf>>,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
     initialize_rayon_once();
-    let state = uv_install_wheel::InstallState::new(preview);
+    let state = uv_install_wheel::InstallState::new(preview).with_unchanged_files(unchanged);
     wheels.par_iter().try_for_each(|wheel| {
         uv_install_wheel::install_wheel(
             layout,
@@ -187,6 +229,7 @@ fn install(
             wheel.build_info(),
             installer_name,
             installer_metadata,
+            requested.is_none_or(|requested| requested.contains(wheel.name())),
             link_mode,
             &state,
         )
@@ -1,11 +1,12 @@
-pub use compile::{CompileError, compile_tree};
+pub use compile::{CompileError, CompileFailure, CompileSummary, compile_files, compile_tree};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{IncompatibleWheelError, Plan, Planner};
 pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter};
 pub use site_packages::{
-    InstallationStrategy, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
+    BrokenDistribution, BrokenReason, InstallationStrategy, LazySitePackages, SatisfiesResult,
+    SitePackages, SitePackagesDiagnostic, SitePackagesDirectory,
 };
-pub use uninstall::{UninstallError, uninstall};
+pub use uninstall::{UninstallError, uninstall, uninstall_preserving};
 
 mod compile;
 mod preparer;
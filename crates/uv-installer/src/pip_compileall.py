@@ -5,14 +5,18 @@ https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pi
 pip silently just swallows all pyc compilation errors, but `python -m compileall` does
 not have such a flag, so we adapt the pip code. This is relevant, e.g., for
 `debugpy-1.5.1-cp38-cp38-manylinux_2_5_x86_64.manylinux1_x86_64.manylinux_2_12_x86_64.manylinux2010_x86_64`,
-which contains some vendored Python 2 code which fails to compile.
+which contains some vendored Python 2 code which fails to compile. Instead of aborting, we
+report the error for each file back to uv, which summarizes them once at the end.
 """
 
 import compileall
+import importlib.util
+import io
 import os
 import py_compile
 import sys
 import warnings
+from contextlib import redirect_stdout
 
 with warnings.catch_warnings():
     warnings.filterwarnings("ignore")
@@ -51,17 +55,37 @@ with warnings.catch_warnings():
         # based and has a matching mtime (unless force=True).
         force = True
 
+    # Report the cache tag and magic number of timestamp-based pyc files, so uv can skip files
+    # with an up-to-date pyc without asking us. An empty line disables that check.
+    if (
+        invalidation_mode == py_compile.PycInvalidationMode.TIMESTAMP
+        and sys.implementation.cache_tag is not None
+    ):
+        magic = int.from_bytes(importlib.util.MAGIC_NUMBER, "little")
+        print(f"{sys.implementation.cache_tag} {magic}")
+    else:
+        print()
+
     # In rust, we provide one line per file to compile.
     for path in sys.stdin:
         # Remove trailing newlines.
         path = path.strip()
         if not path:
             continue
-        # Unlike pip, we set quiet=2, so we don't have to capture stdout.
-        # We'd like to show those errors, but given that pip thinks that's totally fine,
-        # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
-        )
+        # Unlike pip, we capture the error message that `compile_file` prints to stdout, so we
+        # can report it. `compile_file` needs an encoding on stdout, which `StringIO` lacks.
+        output = io.TextIOWrapper(io.BytesIO(), encoding="utf-8", errors="replace")
+        with redirect_stdout(output):
+            success = compileall.compile_file(
+                path, invalidation_mode=invalidation_mode, force=force, quiet=1
+            )
         # We're ready for the next file.
-        print(path)
+        if success:
+            print(path)
+        else:
+            output.flush()
+            lines = output.buffer.getvalue().decode("utf-8", errors="replace")
+            lines = [line.strip() for line in lines.splitlines() if line.strip()]
+            # The last line is the exception, e.g., `SyntaxError: invalid syntax`.
+            message = lines[-1] if lines else "Failed to compile"
+            print(f"{path}\t{message}")
@@ -296,6 +296,10 @@ impl<'a> Planner<'a> {
         let mut reinstalls = vec![];
         let mut extraneous = vec![];
 
+        // Distributions that are managed by the system package manager (e.g., in Debian's
+        // `dist-packages`) are never removed; any new installation shadows them instead.
+        let system_managed = site_packages.system_managed_paths();
+
         // TODO(charlie): There are a few assumptions here that are hard to spot:
         //
         // 1. Apparently, we never return direct URL distributions as [`ResolvedDist::Installed`].
@@ -731,6 +735,15 @@ impl<'a> Planner<'a> {
             remote.push(dist.clone());
         }
 
+        reinstalls.retain(|dist_info| {
+            if system_managed.contains(dist_info.install_path()) {
+                debug!("Preserving system-managed package: {dist_info}");
+                false
+            } else {
+                true
+            }
+        });
+
         // Remove any unnecessary packages.
         if site_packages.any() {
             // Retain seed packages unless: (1) the virtual environment was created by uv and
@@ -742,6 +755,11 @@ impl<'a> Planner<'a> {
                     continue;
                 }
 
+                if system_managed.contains(dist_info.install_path()) {
+                    debug!("Preserving system-managed package: {dist_info}");
+                    continue;
+                }
+
                 debug!("Unnecessary package: {dist_info}");
                 extraneous.push(dist_info);
             }
@@ -917,6 +935,7 @@ mod tests {
                 gil_disabled: true,
                 debug_enabled: false,
                 is_cross: false,
+                x86_64_level: None,
             },
         )
         .unwrap();
@@ -953,6 +972,7 @@ mod tests {
                 gil_disabled: true,
                 debug_enabled: false,
                 is_cross: false,
+                x86_64_level: None,
             },
         )
         .unwrap();
@@ -989,6 +1009,7 @@ mod tests {
                 gil_disabled: false,
                 debug_enabled: false,
                 is_cross: false,
+                x86_64_level: None,
             },
         )
         .unwrap();
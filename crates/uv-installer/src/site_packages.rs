@@ -1,7 +1,8 @@
 use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
@@ -42,6 +43,10 @@ pub struct SitePackages {
     by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The installed editable distributions, keyed by URL.
     by_url: FxHashMap<DisplaySafeUrl, Vec<usize>>,
+    /// The directories from which the distributions were read.
+    directories: Vec<SitePackagesDirectory>,
+    /// The index of the directory containing each distribution, parallel to `distributions`.
+    locations: Vec<usize>,
 }
 
 impl SitePackages {
@@ -52,96 +57,7 @@ impl SitePackages {
 
     /// Build an index of installed packages from the given Python executable.
     pub fn from_interpreter(interpreter: &Interpreter) -> Result<Self> {
-        let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
-        let mut by_name = FxHashMap::default();
-        let mut by_url = FxHashMap::default();
-
-        for site_packages in interpreter.site_packages() {
-            // Read the site-packages directory.
-            let site_packages = match fs::read_dir(site_packages.as_ref()) {
-                Ok(read_dir) => {
-                    // Collect sorted directory paths; `read_dir` is not stable across platforms
-                    let dist_likes: BTreeSet<_> = read_dir
-                        .filter_map(|read_dir| match read_dir {
-                            Ok(entry) => match entry.file_type() {
-                                Ok(file_type) => (file_type.is_dir()
-                                    || entry
-                                        .path()
-                                        .extension()
-                                        .is_some_and(|ext| ext == "egg-link" || ext == "egg-info"))
-                                .then_some(Ok(entry.path())),
-                                Err(err) => Some(Err(err)),
-                            },
-                            Err(err) => Some(Err(err)),
-                        })
-                        .collect::<Result<_, std::io::Error>>()
-                        .with_context(|| {
-                            format!(
-                                "Failed to read site-packages directory contents: {}",
-                                site_packages.user_display()
-                            )
-                        })?;
-                    dist_likes
-                }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    return Ok(Self {
-                        interpreter: interpreter.clone(),
-                        distributions,
-                        by_name,
-                        by_url,
-                    });
-                }
-                Err(err) => return Err(err).context("Failed to read site-packages directory"),
-            };
-
-            // Index all installed packages by name.
-            for path in site_packages {
-                let dist_info = match InstalledDist::try_from_path(&path) {
-                    Ok(Some(dist_info)) => dist_info,
-                    Ok(None) => continue,
-                    Err(_)
-                        if path.file_name().is_some_and(|name| {
-                            name.to_str().is_some_and(|name| name.starts_with('~'))
-                        }) =>
-                    {
-                        warn_user!(
-                            "Ignoring dangling temporary directory: `{}`",
-                            path.simplified_display().cyan()
-                        );
-                        continue;
-                    }
-                    Err(err) => {
-                        return Err(err).context(format!(
-                            "Failed to read metadata from: `{}`",
-                            path.simplified_display()
-                        ));
-                    }
-                };
-
-                let idx = distributions.len();
-
-                // Index the distribution by name.
-                by_name
-                    .entry(dist_info.name().clone())
-                    .or_default()
-                    .push(idx);
-
-                // Index the distribution by URL.
-                if let InstalledDistKind::Url(dist) = &dist_info.kind {
-                    by_url.entry(dist.url.clone()).or_default().push(idx);
-                }
-
-                // Add the distribution to the database.
-                distributions.push(Some(dist_info));
-            }
-        }
-
-        Ok(Self {
-            interpreter: interpreter.clone(),
-            distributions,
-            by_name,
-            by_url,
-        })
+        LazySitePackages::from_interpreter(interpreter)?.index()
     }
 
     /// Returns the [`Interpreter`] used to install the packages.
@@ -187,6 +103,37 @@ impl SitePackages {
             .collect()
     }
 
+    /// Returns the directory from which the given distribution was read, if it's part of the
+    /// index.
+    pub fn directory(&self, dist: &InstalledDist) -> Option<&SitePackagesDirectory> {
+        let idx = self.distributions.iter().position(|installed| {
+            installed
+                .as_ref()
+                .is_some_and(|installed| installed.install_path() == dist.install_path())
+        })?;
+        Some(&self.directories[self.locations[idx]])
+    }
+
+    /// Returns the directory containing the given distribution if that directory is managed by
+    /// the system package manager (e.g., Debian's `dist-packages`).
+    pub fn system_managed(&self, dist: &InstalledDist) -> Option<&Path> {
+        self.directory(dist)
+            .filter(|directory| directory.is_system_managed())
+            .map(SitePackagesDirectory::path)
+    }
+
+    /// Returns the install paths of the distributions that were read from a directory managed by
+    /// the system package manager.
+    pub(crate) fn system_managed_paths(&self) -> FxHashSet<PathBuf> {
+        self.distributions
+            .iter()
+            .zip(&self.locations)
+            .filter(|(_, location)| self.directories[**location].is_system_managed())
+            .filter_map(|(dist, _)| dist.as_ref())
+            .map(|dist| dist.install_path().to_path_buf())
+            .collect()
+    }
+
     /// Returns `true` if there are any installed packages.
     pub(crate) fn any(&self) -> bool {
         self.distributions.iter().any(Option::is_some)
@@ -317,6 +264,120 @@ impl SitePackages {
         Ok(diagnostics)
     }
 
+    /// Identify the dependencies of the remaining installed packages that would no longer be
+    /// satisfied if the given distributions were removed.
+    ///
+    /// Dependencies that are already missing are not reported.
+    pub fn missing_after_removal(
+        &self,
+        removed: &[&InstalledDist],
+        markers: &ResolverMarkerEnvironment,
+    ) -> Vec<SitePackagesDiagnostic> {
+        let removed = removed
+            .iter()
+            .map(|dist| dist.install_path())
+            .collect::<FxHashSet<_>>();
+
+        let mut diagnostics = Vec::new();
+        for distribution in self.iter() {
+            if removed.contains(distribution.install_path()) {
+                continue;
+            }
+
+            // Packages without readable metadata are reported by `uv pip check`.
+            let Ok(metadata) = distribution.read_metadata() else {
+                continue;
+            };
+
+            for dependency in &metadata.requires_dist {
+                if !dependency.evaluate_markers(markers, &[]) {
+                    continue;
+                }
+
+                let installed = self.get_packages(&dependency.name);
+                if !installed.is_empty()
+                    && installed
+                        .iter()
+                        .all(|dist| removed.contains(dist.install_path()))
+                {
+                    diagnostics.push(SitePackagesDiagnostic::MissingDependency {
+                        package: distribution.name().clone(),
+                        requirement: dependency.clone(),
+                    });
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Identify the installed distributions that are broken or incomplete, e.g., due to an
+    /// interrupted installation or files that were removed from the environment by hand.
+    ///
+    /// Only `.dist-info` distributions are checked, since `.egg-info` distributions don't include
+    /// a `RECORD` file.
+    pub fn broken(&self) -> Vec<BrokenDistribution> {
+        let mut broken = Vec::new();
+        for distribution in self.iter() {
+            if !matches!(
+                distribution.kind,
+                InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+            ) {
+                continue;
+            }
+
+            if let Some(reason) = Self::check_distribution(distribution) {
+                broken.push(BrokenDistribution {
+                    dist: distribution.clone(),
+                    reason,
+                });
+            }
+        }
+        broken
+    }
+
+    /// Check that the `RECORD` and `METADATA` of a `.dist-info` distribution are intact.
+    fn check_distribution(distribution: &InstalledDist) -> Option<BrokenReason> {
+        let dist_info = distribution.install_path();
+
+        let record = match fs::File::open(dist_info.join("RECORD")) {
+            Ok(record) => record,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Some(BrokenReason::MissingRecord);
+            }
+            Err(err) => return Some(BrokenReason::InvalidRecord(err.to_string())),
+        };
+        let record = match uv_install_wheel::read_record(record) {
+            Ok(record) => record,
+            Err(err) => return Some(BrokenReason::InvalidRecord(err.to_string())),
+        };
+
+        // `RECORD` paths are relative to the directory containing the `.dist-info` directory.
+        let site_packages = dist_info.parent().unwrap_or(dist_info);
+        let missing = record
+            .iter()
+            .map(|entry| entry.path.as_str())
+            // Bytecode may be removed or regenerated at any time, so it's never required.
+            .filter(|path| {
+                !path.ends_with(".pyc")
+                    && !Path::new(path)
+                        .components()
+                        .any(|component| component.as_os_str() == "__pycache__")
+            })
+            .filter(|path| !site_packages.join(path).exists())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Some(BrokenReason::MissingFiles(missing));
+        }
+
+        if let Err(err) = distribution.read_metadata() {
+            return Some(BrokenReason::InvalidMetadata(err.to_string()));
+        }
+
+        None
+    }
+
     /// Returns if the installed packages satisfy the given requirements.
     pub fn satisfies_spec(
         &self,
@@ -333,105 +394,13 @@ impl SitePackages {
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
     ) -> Result<SatisfiesResult> {
-        // First, map all unnamed requirements to named requirements.
-        let requirements = {
-            let mut named = Vec::with_capacity(requirements.len());
-            for requirement in requirements {
-                match &requirement.requirement {
-                    UnresolvedRequirement::Named(requirement) => {
-                        named.push(Cow::Borrowed(requirement));
-                    }
-                    UnresolvedRequirement::Unnamed(requirement) => {
-                        match self.get_urls(requirement.url.verbatim.raw()).as_slice() {
-                            [] => {
-                                return Ok(SatisfiesResult::Unsatisfied(
-                                    requirement.url.verbatim.raw().to_string(),
-                                ));
-                            }
-                            [distribution] => {
-                                let requirement = uv_pep508::Requirement {
-                                    name: distribution.name().clone(),
-                                    version_or_url: Some(VersionOrUrl::Url(
-                                        requirement.url.clone(),
-                                    )),
-                                    marker: requirement.marker,
-                                    extras: requirement.extras.clone(),
-                                    origin: requirement.origin.clone(),
-                                };
-                                named.push(Cow::Owned(Requirement::from(requirement)));
-                            }
-                            _ => {
-                                return Ok(SatisfiesResult::Unsatisfied(
-                                    requirement.url.verbatim.raw().to_string(),
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
-            named
-        };
-
-        // Second, map all overrides to named requirements. We assume that all overrides are
-        // relevant.
-        let overrides = {
-            let mut named = Vec::with_capacity(overrides.len());
-            for requirement in overrides {
-                match &requirement.requirement {
-                    UnresolvedRequirement::Named(requirement) => {
-                        named.push(Cow::Borrowed(requirement));
-                    }
-                    UnresolvedRequirement::Unnamed(requirement) => {
-                        match self.get_urls(requirement.url.verbatim.raw()).as_slice() {
-                            [] => {
-                                return Ok(SatisfiesResult::Unsatisfied(
-                                    requirement.url.verbatim.raw().to_string(),
-                                ));
-                            }
-                            [distribution] => {
-                                let requirement = uv_pep508::Requirement {
-                                    name: distribution.name().clone(),
-                                    version_or_url: Some(VersionOrUrl::Url(
-                                        requirement.url.clone(),
-                                    )),
-                                    marker: requirement.marker,
-                                    extras: requirement.extras.clone(),
-                                    origin: requirement.origin.clone(),
-                                };
-                                named.push(Cow::Owned(Requirement::from(requirement)));
-                            }
-                            _ => {
-                                return Ok(SatisfiesResult::Unsatisfied(
-                                    requirement.url.verbatim.raw().to_string(),
-                                ));
-                            }
-                        }
-                    }
-                }
-            }
-            named
-        };
-
-        let overrides = Overrides::from_entries(
-            override_dependencies
-                .iter()
-                .cloned()
-                .chain(
-                    overrides
-                        .iter()
-                        .map(Cow::as_ref)
-                        .cloned()
-                        .map(Override::Requirement),
-                )
-                .collect(),
-        )?;
-        let excludes = Excludes::from_entries(exclude_dependencies.iter().cloned());
-
-        self.satisfies_requirements(
-            requirements.iter().map(Cow::as_ref),
-            constraints.iter().map(|constraint| &constraint.requirement),
-            &overrides,
-            &excludes,
+        satisfies_spec(
+            self,
+            requirements,
+            constraints,
+            overrides,
+            override_dependencies,
+            exclude_dependencies,
             installation,
             markers,
             tags,
@@ -457,47 +426,551 @@ impl SitePackages {
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
     ) -> Result<SatisfiesResult> {
-        // Collect the constraints by package name.
-        let constraints: FxHashMap<&PackageName, Vec<&Requirement>> =
-            constraints.fold(FxHashMap::default(), |mut constraints, constraint| {
-                constraints
-                    .entry(&constraint.name)
+        satisfies_requirements(
+            self,
+            requirements,
+            constraints,
+            overrides,
+            excludes,
+            installation,
+            markers,
+            tags,
+            config_settings,
+            config_settings_package,
+            extra_build_requires,
+            extra_build_variables,
+        )
+    }
+}
+
+/// A lazily-populated index over the packages installed in an environment.
+///
+/// Unlike [`SitePackages`], which reads every distribution in the environment upfront, packages are
+/// indexed by the name in their `.dist-info` (or `.egg-info`) path, and each distribution is only
+/// read when it's first requested. This makes it cheap to check whether a handful of requirements
+/// are satisfied by a large environment.
+///
+/// Use [`LazySitePackages::index`] to read the remaining distributions when a full view of the
+/// environment is required.
+#[derive(Debug)]
+pub struct LazySitePackages {
+    interpreter: Interpreter,
+    /// The directories from which the distributions are read.
+    directories: Vec<SitePackagesDirectory>,
+    /// The distribution-like paths in the environment, along with the index of the directory
+    /// containing each path and the distribution at each path, once read. The `by_name` index
+    /// indexes into this vector.
+    entries: Vec<(PathBuf, usize, OnceCell<Option<InstalledDist>>)>,
+    /// The entries, keyed by the name inferred from their path.
+    by_name: FxHashMap<PackageName, Vec<usize>>,
+}
+
+impl LazySitePackages {
+    /// Build a lazy index of installed packages from the given Python environment.
+    pub fn from_environment(environment: &PythonEnvironment) -> Result<Self> {
+        Self::from_interpreter(environment.interpreter())
+    }
+
+    /// Build a lazy index of installed packages from the given Python executable.
+    ///
+    /// In addition to the interpreter's `site-packages` directories, any `dist-packages`
+    /// directories managed by the system package manager are indexed (see
+    /// [`Interpreter::system_site_packages`]).
+    pub fn from_interpreter(interpreter: &Interpreter) -> Result<Self> {
+        let directories = interpreter
+            .site_packages()
+            .map(|path| SitePackagesDirectory::new(path.into_owned(), false))
+            .chain(
+                interpreter
+                    .system_site_packages()
+                    .map(|path| SitePackagesDirectory::new(path.to_path_buf(), true)),
+            )
+            .collect::<Vec<_>>();
+
+        let (entries, by_name) = read_directories(&directories)?;
+
+        Ok(Self {
+            interpreter: interpreter.clone(),
+            directories,
+            entries,
+            by_name,
+        })
+    }
+
+    /// Returns if the installed packages satisfy the given requirements.
+    ///
+    /// Only the distributions that are reachable from the requirements are read.
+    pub fn satisfies_spec(
+        &self,
+        requirements: &[UnresolvedRequirementSpecification],
+        constraints: &[NameRequirementSpecification],
+        overrides: &[UnresolvedRequirementSpecification],
+        override_dependencies: &[Override<Requirement>],
+        exclude_dependencies: &[ExcludeDependency],
+        installation: InstallationStrategy,
+        markers: &ResolverMarkerEnvironment,
+        tags: &Tags,
+        config_settings: &ConfigSettings,
+        config_settings_package: &PackageConfigSettings,
+        extra_build_requires: &ExtraBuildRequires,
+        extra_build_variables: &ExtraBuildVariables,
+    ) -> Result<SatisfiesResult> {
+        satisfies_spec(
+            self,
+            requirements,
+            constraints,
+            overrides,
+            override_dependencies,
+            exclude_dependencies,
+            installation,
+            markers,
+            tags,
+            config_settings,
+            config_settings_package,
+            extra_build_requires,
+            extra_build_variables,
+        )
+    }
+
+    /// Read all remaining distributions, returning a complete [`SitePackages`] index.
+    pub fn index(self) -> Result<SitePackages> {
+        let mut distributions: Vec<Option<InstalledDist>> = Vec::with_capacity(self.entries.len());
+        let mut by_name = FxHashMap::default();
+        let mut by_url = FxHashMap::default();
+
+        let mut locations = Vec::with_capacity(self.entries.len());
+
+        for (path, location, dist_info) in self.entries {
+            let dist_info = match dist_info.into_inner() {
+                Some(dist_info) => dist_info,
+                None => read_installed_dist(&path)?,
+            };
+            let Some(dist_info) = dist_info else {
+                continue;
+            };
+
+            let idx = distributions.len();
+
+            // Index the distribution by name.
+            by_name
+                .entry(dist_info.name().clone())
+                .or_default()
+                .push(idx);
+
+            // Index the distribution by URL.
+            if let InstalledDistKind::Url(dist) = &dist_info.kind {
+                by_url.entry(dist.url.clone()).or_default().push(idx);
+            }
+
+            // Add the distribution to the database.
+            distributions.push(Some(dist_info));
+            locations.push(location);
+        }
+
+        Ok(SitePackages {
+            interpreter: self.interpreter,
+            distributions,
+            by_name,
+            by_url,
+            directories: self.directories,
+            locations,
+        })
+    }
+
+    /// Returns the distribution at the given index, reading it if necessary.
+    fn read(&self, idx: usize) -> Result<Option<&InstalledDist>> {
+        let (path, _, dist_info) = &self.entries[idx];
+        if let Some(dist_info) = dist_info.get() {
+            return Ok(dist_info.as_ref());
+        }
+        let read = read_installed_dist(path)?;
+        Ok(dist_info.get_or_init(|| read).as_ref())
+    }
+}
+
+/// An index over installed distributions, against which requirements can be checked.
+trait InstalledIndex {
+    /// Returns the installed distributions for a given package.
+    fn get_packages(&self, name: &PackageName) -> Result<Vec<&InstalledDist>>;
+
+    /// Returns the distributions installed from the given URL, if any.
+    fn get_urls(&self, url: &DisplaySafeUrl) -> Result<Vec<&InstalledDist>>;
+}
+
+impl InstalledIndex for SitePackages {
+    fn get_packages(&self, name: &PackageName) -> Result<Vec<&InstalledDist>> {
+        Ok(Self::get_packages(self, name))
+    }
+
+    fn get_urls(&self, url: &DisplaySafeUrl) -> Result<Vec<&InstalledDist>> {
+        Ok(Self::get_urls(self, url))
+    }
+}
+
+impl InstalledIndex for LazySitePackages {
+    fn get_packages(&self, name: &PackageName) -> Result<Vec<&InstalledDist>> {
+        let Some(indexes) = self.by_name.get(name) else {
+            return Ok(Vec::new());
+        };
+        let mut installed = Vec::with_capacity(indexes.len());
+        for &idx in indexes {
+            installed.extend(self.read(idx)?);
+        }
+        Ok(installed)
+    }
+
+    fn get_urls(&self, url: &DisplaySafeUrl) -> Result<Vec<&InstalledDist>> {
+        // URLs are only known once the distribution is read, so this requires reading every
+        // distribution in the environment.
+        let mut installed = Vec::new();
+        for idx in 0..self.entries.len() {
+            if let Some(dist_info) = self.read(idx)? {
+                if matches!(&dist_info.kind, InstalledDistKind::Url(dist) if dist.url == *url) {
+                    installed.push(dist_info);
+                }
+            }
+        }
+        Ok(installed)
+    }
+}
+
+/// A directory from which installed distributions are read, e.g., `site-packages`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitePackagesDirectory {
+    path: PathBuf,
+    /// Whether the directory is managed by the system package manager (e.g., `apt`), rather than
+    /// by Python packaging tools.
+    system_managed: bool,
+}
+
+impl SitePackagesDirectory {
+    fn new(path: PathBuf, system_managed: bool) -> Self {
+        Self {
+            path,
+            system_managed,
+        }
+    }
+
+    /// The path to the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if the directory is managed by the system package manager.
+    pub fn is_system_managed(&self) -> bool {
+        self.system_managed
+    }
+}
+
+/// The entries read from a set of [`SitePackagesDirectory`], along with the entries keyed by name.
+type DirectoryEntries = (
+    Vec<(PathBuf, usize, OnceCell<Option<InstalledDist>>)>,
+    FxHashMap<PackageName, Vec<usize>>,
+);
+
+/// Read the distribution-like paths in each of the given directories, attributing each path to
+/// the index of the directory that contains it.
+///
+/// Directories that don't exist are skipped, as are directories that were already read (e.g., if
+/// `purelib` is a symbolic link to `platlib`).
+fn read_directories(directories: &[SitePackagesDirectory]) -> Result<DirectoryEntries> {
+    let mut entries = Vec::new();
+    let mut by_name: FxHashMap<PackageName, Vec<usize>> = FxHashMap::default();
+    let mut seen = FxHashSet::default();
+
+    for (location, directory) in directories.iter().enumerate() {
+        let Some(paths) = read_site_packages(directory.path())? else {
+            continue;
+        };
+
+        let canonical =
+            fs::canonicalize(directory.path()).unwrap_or_else(|_| directory.path().to_path_buf());
+        if !seen.insert(canonical) {
+            continue;
+        }
+
+        for path in paths {
+            let idx = entries.len();
+
+            // If the name can't be inferred from the path (e.g., for `.egg-link` files), read the
+            // distribution eagerly.
+            if let Some(name) = InstalledDist::name_from_path(&path) {
+                by_name.entry(name).or_default().push(idx);
+                entries.push((path, location, OnceCell::new()));
+            } else {
+                let Some(dist_info) = read_installed_dist(&path)? else {
+                    continue;
+                };
+                by_name
+                    .entry(dist_info.name().clone())
                     .or_default()
-                    .push(constraint);
-                constraints
-            });
-        let mut stack = Vec::with_capacity(requirements.len());
-        let mut seen = FxHashSet::with_capacity_and_hasher(requirements.len(), FxBuildHasher);
-
-        // Add the direct requirements to the queue.
-        for requirement in overrides
-            .apply(requirements)
-            .filter(|requirement| !excludes.contains(&requirement.name))
+                    .push(idx);
+                entries.push((path, location, OnceCell::from(Some(dist_info))));
+            }
+        }
+    }
+
+    Ok((entries, by_name))
+}
+
+/// Read the sorted distribution-like paths in a site-packages directory.
+///
+/// Returns `None` if the directory doesn't exist.
+fn read_site_packages(site_packages: &Path) -> Result<Option<BTreeSet<PathBuf>>> {
+    match fs::read_dir(site_packages) {
+        Ok(read_dir) => {
+            // Collect sorted directory paths; `read_dir` is not stable across platforms
+            let dist_likes: BTreeSet<_> = read_dir
+                .filter_map(|read_dir| match read_dir {
+                    Ok(entry) => match entry.file_type() {
+                        Ok(file_type) => (file_type.is_dir()
+                            || entry
+                                .path()
+                                .extension()
+                                .is_some_and(|ext| ext == "egg-link" || ext == "egg-info"))
+                        .then_some(Ok(entry.path())),
+                        Err(err) => Some(Err(err)),
+                    },
+                    Err(err) => Some(Err(err)),
+                })
+                .collect::<Result<_, std::io::Error>>()
+                .with_context(|| {
+                    format!(
+                        "Failed to read site-packages directory contents: {}",
+                        site_packages.user_display()
+                    )
+                })?;
+            Ok(Some(dist_likes))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).context("Failed to read site-packages directory"),
+    }
+}
+
+/// Read the installed distribution at the given path, if any.
+fn read_installed_dist(path: &Path) -> Result<Option<InstalledDist>> {
+    match InstalledDist::try_from_path(path) {
+        Ok(dist_info) => Ok(dist_info),
+        Err(_)
+            if path
+                .file_name()
+                .is_some_and(|name| name.to_str().is_some_and(|name| name.starts_with('~'))) =>
         {
-            if requirement.evaluate_markers(Some(markers), &[]) {
-                let requirement = requirement.into_owned();
-                if seen.insert(requirement.clone()) {
-                    stack.push(requirement);
+            warn_user!(
+                "Ignoring dangling temporary directory: `{}`",
+                path.simplified_display().cyan()
+            );
+            Ok(None)
+        }
+        Err(err) => Err(err).context(format!(
+            "Failed to read metadata from: `{}`",
+            path.simplified_display()
+        )),
+    }
+}
+
+/// Returns if the distributions in the index satisfy the given requirements.
+fn satisfies_spec(
+    index: &impl InstalledIndex,
+    requirements: &[UnresolvedRequirementSpecification],
+    constraints: &[NameRequirementSpecification],
+    overrides: &[UnresolvedRequirementSpecification],
+    override_dependencies: &[Override<Requirement>],
+    exclude_dependencies: &[ExcludeDependency],
+    installation: InstallationStrategy,
+    markers: &ResolverMarkerEnvironment,
+    tags: &Tags,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    extra_build_requires: &ExtraBuildRequires,
+    extra_build_variables: &ExtraBuildVariables,
+) -> Result<SatisfiesResult> {
+    // First, map all unnamed requirements to named requirements.
+    let requirements = {
+        let mut named = Vec::with_capacity(requirements.len());
+        for requirement in requirements {
+            match &requirement.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    named.push(Cow::Borrowed(requirement));
+                }
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    match index.get_urls(requirement.url.verbatim.raw())?.as_slice() {
+                        [] => {
+                            return Ok(SatisfiesResult::Unsatisfied(
+                                requirement.url.verbatim.raw().to_string(),
+                            ));
+                        }
+                        [distribution] => {
+                            let requirement = uv_pep508::Requirement {
+                                name: distribution.name().clone(),
+                                version_or_url: Some(VersionOrUrl::Url(requirement.url.clone())),
+                                marker: requirement.marker,
+                                extras: requirement.extras.clone(),
+                                origin: requirement.origin.clone(),
+                            };
+                            named.push(Cow::Owned(Requirement::from(requirement)));
+                        }
+                        _ => {
+                            return Ok(SatisfiesResult::Unsatisfied(
+                                requirement.url.verbatim.raw().to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        named
+    };
+
+    // Second, map all overrides to named requirements. We assume that all overrides are
+    // relevant.
+    let overrides = {
+        let mut named = Vec::with_capacity(overrides.len());
+        for requirement in overrides {
+            match &requirement.requirement {
+                UnresolvedRequirement::Named(requirement) => {
+                    named.push(Cow::Borrowed(requirement));
+                }
+                UnresolvedRequirement::Unnamed(requirement) => {
+                    match index.get_urls(requirement.url.verbatim.raw())?.as_slice() {
+                        [] => {
+                            return Ok(SatisfiesResult::Unsatisfied(
+                                requirement.url.verbatim.raw().to_string(),
+                            ));
+                        }
+                        [distribution] => {
+                            let requirement = uv_pep508::Requirement {
+                                name: distribution.name().clone(),
+                                version_or_url: Some(VersionOrUrl::Url(requirement.url.clone())),
+                                marker: requirement.marker,
+                                extras: requirement.extras.clone(),
+                                origin: requirement.origin.clone(),
+                            };
+                            named.push(Cow::Owned(Requirement::from(requirement)));
+                        }
+                        _ => {
+                            return Ok(SatisfiesResult::Unsatisfied(
+                                requirement.url.verbatim.raw().to_string(),
+                            ));
+                        }
+                    }
                 }
             }
         }
+        named
+    };
+
+    let overrides = Overrides::from_entries(
+        override_dependencies
+            .iter()
+            .cloned()
+            .chain(
+                overrides
+                    .iter()
+                    .map(Cow::as_ref)
+                    .cloned()
+                    .map(Override::Requirement),
+            )
+            .collect(),
+    )?;
+    let excludes = Excludes::from_entries(exclude_dependencies.iter().cloned());
+
+    satisfies_requirements(
+        index,
+        requirements.iter().map(Cow::as_ref),
+        constraints.iter().map(|constraint| &constraint.requirement),
+        &overrides,
+        &excludes,
+        installation,
+        markers,
+        tags,
+        config_settings,
+        config_settings_package,
+        extra_build_requires,
+        extra_build_variables,
+    )
+}
+
+/// Like [`satisfies_spec`], but with resolved names for all requirements.
+fn satisfies_requirements<'a>(
+    index: &impl InstalledIndex,
+    requirements: impl ExactSizeIterator<Item = &'a Requirement>,
+    constraints: impl Iterator<Item = &'a Requirement>,
+    overrides: &'a Overrides,
+    excludes: &'a Excludes,
+    installation: InstallationStrategy,
+    markers: &ResolverMarkerEnvironment,
+    tags: &Tags,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    extra_build_requires: &ExtraBuildRequires,
+    extra_build_variables: &ExtraBuildVariables,
+) -> Result<SatisfiesResult> {
+    // Collect the constraints by package name.
+    let constraints: FxHashMap<&PackageName, Vec<&Requirement>> =
+        constraints.fold(FxHashMap::default(), |mut constraints, constraint| {
+            constraints
+                .entry(&constraint.name)
+                .or_default()
+                .push(constraint);
+            constraints
+        });
+    let mut stack = Vec::with_capacity(requirements.len());
+    let mut seen = FxHashSet::with_capacity_and_hasher(requirements.len(), FxBuildHasher);
+
+    // Add the direct requirements to the queue.
+    for requirement in overrides
+        .apply(requirements)
+        .filter(|requirement| !excludes.contains(&requirement.name))
+    {
+        if requirement.evaluate_markers(Some(markers), &[]) {
+            let requirement = requirement.into_owned();
+            if seen.insert(requirement.clone()) {
+                stack.push(requirement);
+            }
+        }
+    }
 
-        // Verify that all non-editable requirements are met.
-        while let Some(requirement) = stack.pop() {
-            let name = &requirement.name;
-            let installed = self.get_packages(name);
-            match installed.as_slice() {
-                [] => {
-                    // The package isn't installed.
-                    return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
+    // Verify that all non-editable requirements are met.
+    while let Some(requirement) = stack.pop() {
+        let name = &requirement.name;
+        let installed = index.get_packages(name)?;
+        match installed.as_slice() {
+            [] => {
+                // The package isn't installed.
+                return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
+            }
+            [distribution] => {
+                // Validate that the requirement is satisfied.
+                if requirement.evaluate_markers(Some(markers), &[]) {
+                    match RequirementSatisfaction::check(
+                        name,
+                        distribution,
+                        &requirement.source,
+                        None,
+                        installation,
+                        tags,
+                        config_settings,
+                        config_settings_package,
+                        extra_build_requires,
+                        extra_build_variables,
+                    ) {
+                        RequirementSatisfaction::Mismatch
+                        | RequirementSatisfaction::OutOfDate
+                        | RequirementSatisfaction::CacheInvalid => {
+                            return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
+                        }
+                        RequirementSatisfaction::Satisfied => {}
+                    }
                 }
-                [distribution] => {
-                    // Validate that the requirement is satisfied.
-                    if requirement.evaluate_markers(Some(markers), &[]) {
+
+                // Validate that the installed version satisfies the constraints.
+                for constraint in constraints.get(name).into_iter().flatten() {
+                    if constraint.evaluate_markers(Some(markers), &[]) {
                         match RequirementSatisfaction::check(
                             name,
                             distribution,
-                            &requirement.source,
+                            &constraint.source,
                             None,
                             installation,
                             tags,
@@ -514,71 +987,44 @@ impl SitePackages {
                             RequirementSatisfaction::Satisfied => {}
                         }
                     }
+                }
 
-                    // Validate that the installed version satisfies the constraints.
-                    for constraint in constraints.get(name).into_iter().flatten() {
-                        if constraint.evaluate_markers(Some(markers), &[]) {
-                            match RequirementSatisfaction::check(
-                                name,
-                                distribution,
-                                &constraint.source,
-                                None,
-                                installation,
-                                tags,
-                                config_settings,
-                                config_settings_package,
-                                extra_build_requires,
-                                extra_build_variables,
-                            ) {
-                                RequirementSatisfaction::Mismatch
-                                | RequirementSatisfaction::OutOfDate
-                                | RequirementSatisfaction::CacheInvalid => {
-                                    return Ok(SatisfiesResult::Unsatisfied(
-                                        requirement.to_string(),
-                                    ));
-                                }
-                                RequirementSatisfaction::Satisfied => {}
-                            }
-                        }
-                    }
-
-                    // Recurse into the dependencies.
-                    let metadata = distribution
-                        .read_metadata()
-                        .with_context(|| format!("Failed to read metadata for: {distribution}"))?;
+                // Recurse into the dependencies.
+                let metadata = distribution
+                    .read_metadata()
+                    .with_context(|| format!("Failed to read metadata for: {distribution}"))?;
 
-                    // Add the dependencies to the queue.
-                    let dependencies = metadata
-                        .requires_dist
-                        .iter()
-                        .cloned()
-                        .map(Requirement::from)
-                        .collect::<Vec<_>>();
-                    for dependency in overrides
-                        .apply_for(name, distribution.version(), &dependencies)
-                        .filter(|dependency| {
-                            !excludes.contains_for(name, distribution.version(), &dependency.name)
-                        })
-                    {
-                        if dependency.evaluate_markers(Some(markers), &requirement.extras) {
-                            let dependency = dependency.into_owned();
-                            if seen.insert(dependency.clone()) {
-                                stack.push(dependency);
-                            }
+                // Add the dependencies to the queue.
+                let dependencies = metadata
+                    .requires_dist
+                    .iter()
+                    .cloned()
+                    .map(Requirement::from)
+                    .collect::<Vec<_>>();
+                for dependency in overrides
+                    .apply_for(name, distribution.version(), &dependencies)
+                    .filter(|dependency| {
+                        !excludes.contains_for(name, distribution.version(), &dependency.name)
+                    })
+                {
+                    if dependency.evaluate_markers(Some(markers), &requirement.extras) {
+                        let dependency = dependency.into_owned();
+                        if seen.insert(dependency.clone()) {
+                            stack.push(dependency);
                         }
                     }
                 }
-                _ => {
-                    // There are multiple installed distributions for the same package.
-                    return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
-                }
+            }
+            _ => {
+                // There are multiple installed distributions for the same package.
+                return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
             }
         }
-
-        Ok(SatisfiesResult::Fresh {
-            recursive_requirements: seen,
-        })
     }
+
+    Ok(SatisfiesResult::Fresh {
+        recursive_requirements: seen,
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -624,6 +1070,45 @@ impl IntoIterator for SitePackages {
     }
 }
 
+/// An installed distribution that is broken or incomplete.
+#[derive(Debug, Clone)]
+pub struct BrokenDistribution {
+    /// The broken distribution.
+    pub dist: InstalledDist,
+    /// The reason the distribution is considered broken.
+    pub reason: BrokenReason,
+}
+
+/// The reason an installed distribution is considered broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrokenReason {
+    /// The `RECORD` file is missing.
+    MissingRecord,
+    /// The `RECORD` file could not be read or parsed.
+    InvalidRecord(String),
+    /// Files listed in the `RECORD` are missing from the environment.
+    MissingFiles(Vec<String>),
+    /// The `METADATA` file is missing or could not be parsed.
+    InvalidMetadata(String),
+}
+
+impl std::fmt::Display for BrokenReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingRecord => write!(f, "missing `RECORD` file"),
+            Self::InvalidRecord(err) => write!(f, "unable to read `RECORD` file: {err}"),
+            Self::MissingFiles(files) => match files.as_slice() {
+                [file] => write!(f, "missing file `{file}`"),
+                [file, rest @ ..] => {
+                    write!(f, "missing file `{file}` (and {} more)", rest.len())
+                }
+                [] => write!(f, "missing files"),
+            },
+            Self::InvalidMetadata(err) => write!(f, "unable to read `METADATA`: {err}"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum SitePackagesDiagnostic {
     MetadataUnavailable {
@@ -746,3 +1231,79 @@ impl InstalledPackagesProvider for SitePackages {
         self.get_packages(name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use fs_err as fs;
+    use uv_normalize::PackageName;
+
+    use super::{SitePackagesDirectory, read_directories};
+
+    fn dist_info(directory: &Path, name: &str) {
+        fs::create_dir_all(directory.join(name)).unwrap();
+    }
+
+    /// Simulate the Debian layout, in which `pip` installs into
+    /// `/usr/local/lib/python3.X/dist-packages` and `apt` installs into
+    /// `/usr/lib/python3/dist-packages`.
+    #[test]
+    fn read_debian_directories() {
+        let root = tempfile::tempdir().unwrap();
+        let local = root.path().join("usr/local/lib/python3.12/dist-packages");
+        let system = root.path().join("usr/lib/python3/dist-packages");
+        dist_info(&local, "requests-2.32.0.dist-info");
+        dist_info(&system, "requests-2.31.0.dist-info");
+        dist_info(&system, "six-1.16.0.dist-info");
+
+        let directories = vec![
+            SitePackagesDirectory::new(local.clone(), false),
+            // Missing directories are skipped.
+            SitePackagesDirectory::new(root.path().join("usr/lib/python3.12/dist-packages"), true),
+            SitePackagesDirectory::new(system.clone(), true),
+        ];
+        let (entries, by_name) = read_directories(&directories).unwrap();
+
+        let locations = entries
+            .iter()
+            .map(|(path, location, _)| {
+                (
+                    path.file_name().unwrap().to_str().unwrap(),
+                    directories[*location].path(),
+                    directories[*location].is_system_managed(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locations,
+            vec![
+                ("requests-2.32.0.dist-info", local.as_path(), false),
+                ("requests-2.31.0.dist-info", system.as_path(), true),
+                ("six-1.16.0.dist-info", system.as_path(), true),
+            ]
+        );
+        assert_eq!(
+            by_name[&PackageName::from_str("requests").unwrap()],
+            vec![0, 1]
+        );
+    }
+
+    /// A directory that's listed twice (e.g., via a symbolic link) is only read once.
+    #[test]
+    fn read_duplicate_directories() {
+        let root = tempfile::tempdir().unwrap();
+        let site_packages = root.path().join("site-packages");
+        dist_info(&site_packages, "six-1.16.0.dist-info");
+
+        let directories = vec![
+            SitePackagesDirectory::new(site_packages.clone(), false),
+            SitePackagesDirectory::new(site_packages.join("."), true),
+        ];
+        let (entries, _) = read_directories(&directories).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1, 0);
+    }
+}
@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use rustc_hash::FxHashSet;
+
 use uv_distribution_types::{InstalledDist, InstalledDistKind, InstalledEggInfoFile};
 use uv_install_wheel::Layout;
 
@@ -5,14 +9,32 @@ use uv_install_wheel::Layout;
 pub async fn uninstall(
     dist: &InstalledDist,
     layout: &Layout,
+) -> Result<uv_install_wheel::Uninstall, UninstallError> {
+    uninstall_preserving(dist, layout, FxHashSet::default()).await
+}
+
+/// Uninstall a package from the specified Python environment, leaving the given files (relative
+/// to site-packages) in place.
+///
+/// The files are only preserved for wheel-based installations; see
+/// [`uv_install_wheel::unchanged_files`].
+pub async fn uninstall_preserving(
+    dist: &InstalledDist,
+    layout: &Layout,
+    preserve: FxHashSet<PathBuf>,
 ) -> Result<uv_install_wheel::Uninstall, UninstallError> {
     let uninstall = tokio::task::spawn_blocking({
         let dist = dist.clone();
         let layout = layout.clone();
         move || match dist.kind {
-            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_) => Ok(
-                uv_install_wheel::uninstall_wheel(dist.install_path(), &dist, &layout)?,
-            ),
+            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_) => {
+                Ok(uv_install_wheel::uninstall_wheel_preserving(
+                    dist.install_path(),
+                    &dist,
+                    &layout,
+                    &preserve,
+                )?)
+            }
             InstalledDistKind::EggInfoDirectory(_) => {
                 Ok(uv_install_wheel::uninstall_egg(dist.install_path(), &dist)?)
             }
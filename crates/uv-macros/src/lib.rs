@@ -39,6 +39,61 @@ fn impl_combine(ast: &DeriveInput) -> TokenStream {
         }
     });
 
+    // Record the origin of each field, following the same merge semantics as `combine`. Flattened
+    // fields share the key prefix of the parent, while option groups (e.g., `pip`) extend it.
+    let mut records = Vec::new();
+    let mut merged = Vec::new();
+    for field in fields {
+        if has_serde_flag(&field.attrs, "skip") {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("Expected named fields");
+        let ty = &field.ty;
+        let key = LitStr::new(&ident.to_string().replace('_', "-"), ident.span());
+        if has_serde_flag(&field.attrs, "flatten") {
+            records.push(quote! {
+                crate::CombineOrigins::record_origins(&self.#ident, prefix, source, provenance);
+            });
+            merged.push(quote! {
+                <#ty as crate::CombineOrigins>::merged_settings(prefix, settings);
+            });
+        } else if field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("option_group"))
+        {
+            records.push(quote! {
+                crate::CombineOrigins::record_origins(
+                    &self.#ident,
+                    &crate::setting_key(prefix, #key),
+                    source,
+                    provenance,
+                );
+            });
+            merged.push(quote! {
+                <#ty as crate::CombineOrigins>::merged_settings(
+                    &crate::setting_key(prefix, #key),
+                    settings,
+                );
+            });
+        } else {
+            records.push(quote! {
+                if self.#ident.is_some() {
+                    provenance.record(
+                        crate::setting_key(prefix, #key),
+                        source,
+                        <#ty as crate::Combine>::MERGE,
+                    );
+                }
+            });
+            merged.push(quote! {
+                if <#ty as crate::Combine>::MERGE {
+                    settings.insert(crate::setting_key(prefix, #key));
+                }
+            });
+        }
+    }
+
     let stream = quote! {
         impl crate::Combine for #name {
             fn combine(self, other: #name) -> #name {
@@ -47,10 +102,48 @@ fn impl_combine(ast: &DeriveInput) -> TokenStream {
                 }
             }
         }
+
+        impl crate::CombineOrigins for #name {
+            fn record_origins(
+                &self,
+                prefix: &str,
+                source: usize,
+                provenance: &mut crate::Provenance,
+            ) {
+                #(#records)*
+            }
+
+            fn merged_settings(
+                prefix: &str,
+                settings: &mut std::collections::BTreeSet<String>,
+            ) {
+                #(#merged)*
+            }
+        }
     };
     stream.into()
 }
 
+/// Returns `true` if the attributes include `#[serde(...)]` with the given flag, e.g., `flatten`.
+fn has_serde_flag(attrs: &[Attribute], flag: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .any(|attr| {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(flag) {
+                    found = true;
+                } else if meta.input.peek(syn::Token![=]) {
+                    // Skip the value of any other key-value flag, e.g., `rename = "..."`.
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
 fn get_doc_comment(attrs: &[Attribute]) -> String {
     attrs
         .iter()
@@ -0,0 +1,82 @@
+//! Install a set of requirements into the active virtual environment.
+//!
+//! ```console
+//! $ cargo run -p uv-operations --example install -- "flask>=3" requests
+//! ```
+#![expect(clippy::print_stdout, clippy::print_stderr)]
+
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use uv_operations::{EnvironmentRequest, Options, Reporter, find_environment, install, resolve};
+
+/// A [`Reporter`] that logs each event to stderr.
+struct StderrReporter;
+
+impl Reporter for StderrReporter {
+    fn on_resolve_progress(&self, name: &str, version: &str) {
+        eprintln!("Resolved {name} {version}");
+    }
+
+    fn on_download_start(&self, name: &str, size: Option<u64>) -> usize {
+        match size {
+            Some(size) => eprintln!("Downloading {name} ({size} bytes)"),
+            None => eprintln!("Downloading {name}"),
+        }
+        0
+    }
+
+    fn on_build_start(&self, source: &str) -> usize {
+        eprintln!("Building {source}");
+        0
+    }
+
+    fn on_install_progress(&self, name: &str, version: &str) {
+        eprintln!("Installed {name} {version}");
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    let requirements = std::env::args().skip(1).collect::<Vec<_>>();
+    if requirements.is_empty() {
+        eprintln!("Usage: install <REQUIREMENT>...");
+        return ExitCode::FAILURE;
+    }
+
+    match run(&requirements).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            // Render the full chain of causes.
+            let mut source: Option<&dyn std::error::Error> = Some(&err);
+            while let Some(err) = source {
+                eprintln!("error: {err}");
+                source = err.source();
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(requirements: &[String]) -> Result<(), uv_operations::Error> {
+    let options = Options::new();
+    let reporter: Arc<dyn Reporter> = Arc::new(StderrReporter);
+
+    let environment = find_environment(&EnvironmentRequest::Discover, &options).await?;
+    eprintln!(
+        "Using Python {} environment at: {}",
+        environment.python_version(),
+        environment.root().display()
+    );
+
+    let resolution = resolve(requirements, &environment, &options, Some(reporter.clone())).await?;
+    let changes = install(&resolution, &environment, &options, Some(reporter)).await?;
+
+    for package in changes.uninstalled() {
+        println!("- {package}");
+    }
+    for package in changes.installed() {
+        println!("+ {package}");
+    }
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use uv_cache::Cache;
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
+};
+use uv_configuration::{
+    BuildOptions, Concurrency, Constraints, IndexStrategy, NoBinary, NoBuild, NoSources,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution_types::{
+    ConfigSettings, DependencyMetadata, ExtraBuildRequires, ExtraBuildVariables, Index,
+    IndexLocations, IndexUrl, PackageConfigSettings,
+};
+use uv_install_wheel::LinkMode;
+use uv_preview::Preview;
+use uv_python::Interpreter;
+use uv_resolver::{ExcludeNewer, FlatIndex};
+use uv_types::{BuildIsolation, HashStrategy, SourceTreeEditablePolicy};
+use uv_workspace::WorkspaceCache;
+
+use crate::{Error, Options};
+
+/// Initialize the [`Cache`] described by the [`Options`].
+pub(crate) async fn cache(options: &Options) -> Result<Cache, Error> {
+    // Use the same cache directory as the command-line interface, absent an explicit override.
+    let cache = if let Some(cache_dir) = &options.cache_dir {
+        Cache::from_path(cache_dir)
+    } else if let Some(cache_dir) = uv_dirs::legacy_user_cache_dir().filter(|dir| dir.exists()) {
+        Cache::from_path(cache_dir)
+    } else if let Some(cache_dir) = uv_dirs::user_cache_dir() {
+        if cfg!(windows) {
+            Cache::from_path(cache_dir.join("cache"))
+        } else {
+            Cache::from_path(cache_dir)
+        }
+    } else {
+        Cache::temp().map_err(|err| Error::Cache(std::env::temp_dir(), err.into()))?
+    };
+    let root = cache.root().to_path_buf();
+    cache
+        .init()
+        .await
+        .map_err(|err| Error::Cache(root, err.into()))
+}
+
+/// Construct a [`BaseClientBuilder`] for the [`Options`].
+pub(crate) fn client_builder(options: &Options) -> BaseClientBuilder<'static> {
+    BaseClientBuilder::default().connectivity(if options.offline {
+        Connectivity::Offline
+    } else {
+        Connectivity::Online
+    })
+}
+
+/// The state required to resolve and install distributions for a given interpreter.
+pub(crate) struct Context {
+    pub(crate) cache: Cache,
+    pub(crate) client: RegistryClient,
+    pub(crate) index_locations: IndexLocations,
+    pub(crate) flat_index: FlatIndex,
+    pub(crate) build_options: BuildOptions,
+    pub(crate) hasher: HashStrategy,
+    pub(crate) concurrency: Concurrency,
+    build_constraints: Constraints,
+    dependency_metadata: DependencyMetadata,
+    config_settings: ConfigSettings,
+    config_settings_package: PackageConfigSettings,
+    extra_build_requires: ExtraBuildRequires,
+    extra_build_variables: ExtraBuildVariables,
+}
+
+impl Context {
+    pub(crate) async fn new(options: &Options, interpreter: &Interpreter) -> Result<Self, Error> {
+        let cache = cache(options).await?;
+
+        let index_locations = IndexLocations::new(
+            options
+                .extra_index_urls
+                .iter()
+                .map(|url| parse_index_url(url).map(Index::from_extra_index_url))
+                .chain(
+                    options
+                        .index_url
+                        .iter()
+                        .map(|url| parse_index_url(url).map(Index::from_index_url)),
+                )
+                .collect::<Result<_, _>>()?,
+            options
+                .find_links
+                .iter()
+                .map(|url| parse_index_url(url).map(Index::from_find_links))
+                .collect::<Result<_, _>>()?,
+            options.no_index,
+        );
+
+        let client = RegistryClientBuilder::new(client_builder(options), cache.clone())
+            .index_locations(index_locations.clone())
+            .markers(interpreter.markers())
+            .platform(interpreter.platform())
+            .build()
+            .map_err(|err| Error::Client(err.into()))?;
+
+        let build_options = BuildOptions::new(
+            if options.no_binary {
+                NoBinary::All
+            } else {
+                NoBinary::None
+            },
+            if options.no_build {
+                NoBuild::All
+            } else {
+                NoBuild::None
+            },
+        );
+        let hasher = HashStrategy::None;
+
+        // Resolve the flat indexes from `--find-links`.
+        let flat_index = {
+            let tags = interpreter
+                .tags()
+                .map_err(|err| Error::Platform(err.into()))?;
+            let client =
+                FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+            let entries = client
+                .fetch_all(index_locations.flat_indexes().map(Index::url))
+                .await
+                .map_err(|err| Error::Client(err.into()))?;
+            FlatIndex::from_entries(entries, Some(tags), &hasher, &build_options)
+        };
+
+        Ok(Self {
+            cache,
+            client,
+            index_locations,
+            flat_index,
+            build_options,
+            hasher,
+            concurrency: Concurrency::default(),
+            build_constraints: Constraints::default(),
+            dependency_metadata: DependencyMetadata::default(),
+            config_settings: ConfigSettings::default(),
+            config_settings_package: PackageConfigSettings::default(),
+            extra_build_requires: ExtraBuildRequires::default(),
+            extra_build_variables: ExtraBuildVariables::default(),
+        })
+    }
+
+    /// Create a [`BuildDispatch`] for building source distributions against the interpreter.
+    pub(crate) fn build_dispatch<'a>(&'a self, interpreter: &'a Interpreter) -> BuildDispatch<'a> {
+        BuildDispatch::new(
+            &self.client,
+            &self.cache,
+            &self.build_constraints,
+            interpreter,
+            &self.index_locations,
+            &self.flat_index,
+            &self.dependency_metadata,
+            SharedState::default(),
+            IndexStrategy::default(),
+            &self.config_settings,
+            &self.config_settings_package,
+            BuildIsolation::default(),
+            &self.extra_build_requires,
+            &self.extra_build_variables,
+            LinkMode::default(),
+            &self.build_options,
+            &self.hasher,
+            ExcludeNewer::default(),
+            NoSources::default(),
+            SourceTreeEditablePolicy::Project,
+            WorkspaceCache::default(),
+            self.concurrency.clone(),
+            Preview::default(),
+        )
+    }
+}
+
+fn parse_index_url(url: &str) -> Result<IndexUrl, Error> {
+    IndexUrl::from_str(url).map_err(|err| Error::InvalidIndexUrl(url.to_string(), err.into()))
+}
@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+
+use crate::{Error, Options, context};
+
+/// A request for a Python environment.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnvironmentRequest {
+    /// Discover a virtual environment, as with `uv pip install`: the active virtual environment
+    /// (e.g., via `VIRTUAL_ENV`), or a `.venv` in the current directory or any parent directory.
+    #[default]
+    Discover,
+    /// Discover a virtual environment whose interpreter satisfies the given Python request,
+    /// e.g., `3.12` or `cpython@3.13`.
+    Python(String),
+    /// Use the environment at the given root directory, which may be a virtual environment or a
+    /// Python installation.
+    Path(PathBuf),
+}
+
+/// A Python environment, into which packages can be installed.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    pub(crate) inner: PythonEnvironment,
+}
+
+impl Environment {
+    /// The root directory of the environment, e.g., the `.venv` directory.
+    pub fn root(&self) -> &Path {
+        self.inner.root()
+    }
+
+    /// The path to the Python executable in the environment.
+    pub fn python_executable(&self) -> &Path {
+        self.inner.python_executable()
+    }
+
+    /// The full version of the Python interpreter, e.g., `3.12.4`.
+    pub fn python_version(&self) -> String {
+        self.inner.interpreter().python_full_version().to_string()
+    }
+
+    /// Returns `true` if the environment is a virtual environment.
+    pub fn is_virtualenv(&self) -> bool {
+        self.inner.interpreter().is_virtualenv()
+    }
+}
+
+/// Find an existing Python environment.
+pub async fn find_environment(
+    request: &EnvironmentRequest,
+    options: &Options,
+) -> Result<Environment, Error> {
+    let cache = context::cache(options).await?;
+
+    let environment = match request {
+        EnvironmentRequest::Discover => PythonEnvironment::find(
+            &PythonRequest::Any,
+            EnvironmentPreference::OnlyVirtual,
+            PythonPreference::default(),
+            &cache,
+        ),
+        EnvironmentRequest::Python(python) => PythonEnvironment::find(
+            &PythonRequest::parse(python),
+            EnvironmentPreference::OnlyVirtual,
+            PythonPreference::default(),
+            &cache,
+        ),
+        EnvironmentRequest::Path(root) => PythonEnvironment::from_root(root, &cache),
+    }
+    .map_err(|err| Error::EnvironmentNotFound(err.into()))?;
+
+    Ok(Environment { inner: environment })
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+/// A boxed error from one of uv's internal crates.
+///
+/// The concrete types are considered internal, and so are erased; use the [`std::error::Error`]
+/// source chain to render the full context.
+type Source = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// An error that can occur when performing an operation.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A requirement could not be parsed.
+    #[error("Failed to parse requirement: `{0}`")]
+    InvalidRequirement(String, #[source] Source),
+
+    /// An index or `--find-links` URL could not be parsed.
+    #[error("Invalid index URL: `{0}`")]
+    InvalidIndexUrl(String, #[source] Source),
+
+    /// The cache could not be initialized.
+    #[error("Failed to initialize cache at: `{}`", .0.display())]
+    Cache(PathBuf, #[source] Source),
+
+    /// The HTTP client could not be initialized, or an index could not be read.
+    #[error("Failed to connect to the package index")]
+    Client(#[source] Source),
+
+    /// No Python environment matched the request.
+    #[error("Failed to find a Python environment")]
+    EnvironmentNotFound(#[source] Source),
+
+    /// No Python interpreter matched the request, and one could not be downloaded.
+    #[error("Failed to find or install Python")]
+    Python(#[source] Source),
+
+    /// The platform tags of the interpreter could not be determined.
+    #[error("Failed to determine the platform tags for the interpreter")]
+    Platform(#[source] Source),
+
+    /// The requirements are unsatisfiable.
+    ///
+    /// The source renders a report explaining why no solution exists.
+    #[error("No solution found when resolving dependencies")]
+    NoSolution(#[source] Source),
+
+    /// The resolver failed for a reason other than an unsatisfiable set of requirements, e.g., a
+    /// distribution could not be downloaded or built.
+    #[error("Failed to resolve dependencies")]
+    Resolve(#[source] Source),
+
+    /// One or more distributions could not be downloaded or built.
+    #[error("Failed to prepare distributions")]
+    Prepare(#[source] Source),
+
+    /// A distribution could not be removed from the environment.
+    #[error("Failed to uninstall `{0}`")]
+    Uninstall(String, #[source] Source),
+
+    /// The distributions could not be installed into the environment.
+    #[error("Failed to install distributions")]
+    Install(#[source] Source),
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use uv_configuration::Reinstall;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::Name;
+use uv_installer::{InstallationStrategy, Installer, Plan, Planner, Preparer, SitePackages};
+use uv_preview::Preview;
+use uv_types::{BuildContext, InFlight};
+
+use crate::context::Context;
+use crate::reporter::Facade;
+use crate::{Environment, Error, Options, Package, Reporter, Resolution};
+
+/// A summary of the changes made to an environment by [`install`].
+#[derive(Debug, Default, Clone)]
+pub struct Changes {
+    installed: Vec<Package>,
+    uninstalled: Vec<Package>,
+}
+
+impl Changes {
+    /// The packages that were installed, including any that were reinstalled or upgraded.
+    pub fn installed(&self) -> &[Package] {
+        &self.installed
+    }
+
+    /// The packages that were removed, including any that were reinstalled or upgraded.
+    pub fn uninstalled(&self) -> &[Package] {
+        &self.uninstalled
+    }
+
+    /// Returns `true` if the environment was not modified.
+    pub fn is_empty(&self) -> bool {
+        self.installed.is_empty() && self.uninstalled.is_empty()
+    }
+}
+
+/// Install a [`Resolution`] into an [`Environment`].
+///
+/// Packages that are already installed at the resolved version are left in place. Packages that
+/// are installed but not part of the resolution are only removed if [`Options::exact`] is set.
+pub async fn install(
+    resolution: &Resolution,
+    environment: &Environment,
+    options: &Options,
+    reporter: Option<Arc<dyn Reporter>>,
+) -> Result<Changes, Error> {
+    let venv = &environment.inner;
+    let interpreter = venv.interpreter();
+    let tags = interpreter
+        .tags()
+        .map_err(|err| Error::Platform(err.into()))?;
+
+    let context = Context::new(options, interpreter).await?;
+    let build_dispatch = context.build_dispatch(interpreter);
+    let facade = reporter.map(Facade::new);
+
+    let reinstall = if options.reinstall {
+        Reinstall::All
+    } else {
+        Reinstall::None
+    };
+
+    let site_packages =
+        SitePackages::from_environment(venv).map_err(|err| Error::Install(err.into()))?;
+
+    // Partition into those that should be linked from the cache (`cached`), those that need to be
+    // downloaded (`remote`), and those that should be removed (`extraneous`).
+    let Plan {
+        cached,
+        remote,
+        reinstalls,
+        extraneous,
+    } = Planner::new(&resolution.inner)
+        .build(
+            site_packages,
+            InstallationStrategy::Permissive,
+            &reinstall,
+            &context.build_options,
+            &context.hasher,
+            build_dispatch.locations(),
+            build_dispatch.config_settings(),
+            build_dispatch.config_settings_package(),
+            build_dispatch.extra_build_requires(),
+            build_dispatch.extra_build_variables(),
+            &context.cache,
+            venv,
+            tags,
+        )
+        .map_err(|err| Error::Install(err.into()))?;
+
+    let extraneous = if options.exact { extraneous } else { vec![] };
+
+    // Download, build, and unzip any missing distributions.
+    let wheels = if remote.is_empty() {
+        vec![]
+    } else {
+        let mut preparer = Preparer::new(
+            &context.cache,
+            tags,
+            &context.hasher,
+            &context.build_options,
+            DistributionDatabase::new(
+                &context.client,
+                &build_dispatch,
+                context.concurrency.downloads_semaphore.clone(),
+            ),
+        );
+        if let Some(facade) = &facade {
+            preparer = preparer.with_reporter(Arc::new(facade.clone()));
+        }
+        preparer
+            .prepare(remote, &InFlight::default(), &resolution.inner)
+            .await
+            .map_err(|err| Error::Prepare(err.into()))?
+    };
+
+    // Remove any upgraded or extraneous installations.
+    let mut uninstalled = Vec::new();
+    let layout = interpreter.layout();
+    for dist_info in extraneous.into_iter().chain(reinstalls) {
+        uv_installer::uninstall(&dist_info, &layout)
+            .await
+            .map_err(|err| Error::Uninstall(dist_info.name().to_string(), err.into()))?;
+        uninstalled.push(Package::new(
+            dist_info.name().to_string(),
+            Some(dist_info.version().to_string()),
+        ));
+    }
+
+    // Install the resolved distributions.
+    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+    let installed = if wheels.is_empty() {
+        vec![]
+    } else {
+        let mut installer = Installer::new(venv, Preview::default()).with_cache(&context.cache);
+        if let Some(facade) = facade {
+            installer = installer.with_reporter(Arc::new(facade));
+        }
+        installer
+            .install(wheels)
+            .await
+            .map_err(|err| Error::Install(err.into()))?
+    };
+
+    let mut installed = installed
+        .iter()
+        .map(|wheel| {
+            Package::new(
+                wheel.name().to_string(),
+                Some(wheel.filename().version.to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+    installed.sort();
+    uninstalled.sort();
+
+    Ok(Changes {
+        installed,
+        uninstalled,
+    })
+}
@@ -0,0 +1,45 @@
+//! A stable Rust API for resolving and installing Python packages with uv.
+//!
+//! Unlike uv's other crates, `uv-operations` follows semantic versioning: its public API only
+//! exposes types defined in this crate, so that changes to uv's internals don't propagate to
+//! consumers. The entry points are:
+//!
+//! - [`find_environment`], to discover an existing Python environment.
+//! - [`install_python`], to find or download a Python interpreter.
+//! - [`resolve`], to resolve a set of requirements for an environment.
+//! - [`install`], to install a [`Resolution`] into an environment.
+//!
+//! Each operation accepts [`Options`], which configure the cache, the package indexes, and the
+//! resolver, along with an optional [`Reporter`] to observe progress.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), uv_operations::Error> {
+//! use uv_operations::{EnvironmentRequest, Options, find_environment, install, resolve};
+//!
+//! let options = Options::new().index_url("https://pypi.org/simple");
+//! let environment = find_environment(&EnvironmentRequest::default(), &options).await?;
+//! let resolution = resolve(&["flask>=3"], &environment, &options, None).await?;
+//! let changes = install(&resolution, &environment, &options, None).await?;
+//! for package in changes.installed() {
+//!     println!("Installed {package}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub use environment::{Environment, EnvironmentRequest, find_environment};
+pub use error::Error;
+pub use install::{Changes, install};
+pub use options::{Options, Prereleases, ResolutionStrategy};
+pub use python::{PythonInstallation, install_python};
+pub use reporter::Reporter;
+pub use resolve::{Package, Resolution, resolve};
+
+mod context;
+mod environment;
+mod error;
+mod install;
+mod options;
+mod python;
+mod reporter;
+mod resolve;
@@ -0,0 +1,192 @@
+use std::path::PathBuf;
+
+/// The strategy to use when selecting between the different compatible versions of a package.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResolutionStrategy {
+    /// Resolve the highest compatible version of each package.
+    #[default]
+    Highest,
+    /// Resolve the lowest compatible version of each package.
+    Lowest,
+    /// Resolve the lowest compatible version of any direct dependencies, and the highest
+    /// compatible version of any transitive dependencies.
+    LowestDirect,
+}
+
+impl From<ResolutionStrategy> for uv_resolver::ResolutionMode {
+    fn from(strategy: ResolutionStrategy) -> Self {
+        match strategy {
+            ResolutionStrategy::Highest => Self::Highest,
+            ResolutionStrategy::Lowest => Self::Lowest,
+            ResolutionStrategy::LowestDirect => Self::LowestDirect,
+        }
+    }
+}
+
+/// The strategy to use when considering pre-release versions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Prereleases {
+    /// Disallow all pre-release versions.
+    Disallow,
+    /// Allow all pre-release versions.
+    Allow,
+    /// Allow pre-release versions if all versions of a package are pre-release, or if the package
+    /// has an explicit pre-release marker in its version requirements.
+    #[default]
+    IfNecessaryOrExplicit,
+}
+
+impl From<Prereleases> for uv_resolver::PrereleaseMode {
+    fn from(prereleases: Prereleases) -> Self {
+        match prereleases {
+            Prereleases::Disallow => Self::Disallow,
+            Prereleases::Allow => Self::Allow,
+            Prereleases::IfNecessaryOrExplicit => Self::IfNecessaryOrExplicit,
+        }
+    }
+}
+
+/// The options shared by each operation.
+///
+/// By default, packages are resolved against PyPI, and the cache is stored in the same location
+/// as the uv command-line interface.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) index_url: Option<String>,
+    pub(crate) extra_index_urls: Vec<String>,
+    pub(crate) find_links: Vec<String>,
+    pub(crate) no_index: bool,
+    pub(crate) offline: bool,
+    pub(crate) resolution: ResolutionStrategy,
+    pub(crate) prereleases: Prereleases,
+    pub(crate) no_build: bool,
+    pub(crate) no_binary: bool,
+    pub(crate) reinstall: bool,
+    pub(crate) exact: bool,
+    pub(crate) python_downloads: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            cache_dir: None,
+            index_url: None,
+            extra_index_urls: Vec::new(),
+            find_links: Vec::new(),
+            no_index: false,
+            offline: false,
+            resolution: ResolutionStrategy::default(),
+            prereleases: Prereleases::default(),
+            no_build: false,
+            no_binary: false,
+            reinstall: false,
+            exact: false,
+            python_downloads: true,
+        }
+    }
+}
+
+impl Options {
+    /// Create a new set of [`Options`] with the default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory to use for the cache.
+    #[must_use]
+    pub fn cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// The URL of the default package index, in place of PyPI.
+    #[must_use]
+    pub fn index_url(mut self, index_url: impl Into<String>) -> Self {
+        self.index_url = Some(index_url.into());
+        self
+    }
+
+    /// An additional package index to use, with a higher priority than the default index.
+    ///
+    /// When called multiple times, earlier indexes take priority over later ones.
+    #[must_use]
+    pub fn extra_index_url(mut self, extra_index_url: impl Into<String>) -> Self {
+        self.extra_index_urls.push(extra_index_url.into());
+        self
+    }
+
+    /// A URL or local directory to search for distributions, as with `--find-links`.
+    #[must_use]
+    pub fn find_links(mut self, find_links: impl Into<String>) -> Self {
+        self.find_links.push(find_links.into());
+        self
+    }
+
+    /// Ignore the package indexes, relying only on [`Options::find_links`].
+    #[must_use]
+    pub fn no_index(mut self, no_index: bool) -> Self {
+        self.no_index = no_index;
+        self
+    }
+
+    /// Disable network access, relying only on the cache and local files.
+    #[must_use]
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// The strategy to use when selecting between versions of a package.
+    #[must_use]
+    pub fn resolution(mut self, resolution: ResolutionStrategy) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// The strategy to use when considering pre-release versions.
+    #[must_use]
+    pub fn prereleases(mut self, prereleases: Prereleases) -> Self {
+        self.prereleases = prereleases;
+        self
+    }
+
+    /// Never build source distributions; only install pre-built wheels.
+    #[must_use]
+    pub fn no_build(mut self, no_build: bool) -> Self {
+        self.no_build = no_build;
+        self
+    }
+
+    /// Never install pre-built wheels; always build from source distributions.
+    #[must_use]
+    pub fn no_binary(mut self, no_binary: bool) -> Self {
+        self.no_binary = no_binary;
+        self
+    }
+
+    /// Reinstall all packages, even if they're already installed.
+    #[must_use]
+    pub fn reinstall(mut self, reinstall: bool) -> Self {
+        self.reinstall = reinstall;
+        self
+    }
+
+    /// Remove any packages from the environment that aren't part of the [`crate::Resolution`]
+    /// when installing, as with `uv pip sync`.
+    #[must_use]
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Allow [`crate::install_python`] to download a managed Python interpreter, if no installed
+    /// interpreter satisfies the request. Enabled by default.
+    #[must_use]
+    pub fn python_downloads(mut self, python_downloads: bool) -> Self {
+        self.python_downloads = python_downloads;
+        self
+    }
+}
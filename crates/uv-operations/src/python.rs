@@ -0,0 +1,66 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use uv_python::{EnvironmentPreference, PythonDownloads, PythonPreference, PythonRequest};
+
+use crate::reporter::Facade;
+use crate::{Error, Options, Reporter, context};
+
+/// A Python interpreter, which may be used to create an environment.
+#[derive(Debug, Clone)]
+pub struct PythonInstallation {
+    inner: uv_python::PythonInstallation,
+}
+
+impl PythonInstallation {
+    /// The path to the Python executable.
+    pub fn executable(&self) -> &Path {
+        self.inner.interpreter().sys_executable()
+    }
+
+    /// The full version of the Python interpreter, e.g., `3.12.4`.
+    pub fn version(&self) -> String {
+        self.inner.interpreter().python_full_version().to_string()
+    }
+}
+
+/// Find a Python interpreter that satisfies the request (e.g., `3.12` or `pypy@3.10`), or
+/// download a managed interpreter if none is installed.
+///
+/// If no request is provided, any Python interpreter is accepted. Downloads can be disabled with
+/// [`Options::python_downloads`].
+pub async fn install_python(
+    request: Option<&str>,
+    options: &Options,
+    reporter: Option<Arc<dyn Reporter>>,
+) -> Result<PythonInstallation, Error> {
+    let cache = context::cache(options).await?;
+    let client_builder = context::client_builder(options);
+    let request = request.map(PythonRequest::parse);
+    let facade = reporter.map(Facade::new);
+
+    let installation = uv_python::PythonInstallation::find_or_download(
+        request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        PythonPreference::default(),
+        if options.python_downloads {
+            PythonDownloads::Automatic
+        } else {
+            PythonDownloads::Never
+        },
+        &client_builder,
+        &cache,
+        facade
+            .as_ref()
+            .map(|facade| facade as &dyn uv_python::downloads::Reporter),
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|err| Error::Python(err.into()))?;
+
+    Ok(PythonInstallation {
+        inner: installation,
+    })
+}
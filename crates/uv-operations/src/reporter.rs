@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use uv_distribution_types::{
+    BuildableSource, CachedDist, DistributionMetadata, Name, VersionOrUrlRef,
+};
+use uv_normalize::PackageName;
+use uv_python::PythonInstallationKey;
+use uv_python::downloads::Direction;
+use uv_redacted::DisplaySafeUrl;
+
+/// A callback-based reporter for observing the progress of an operation.
+///
+/// Every method has a no-op default implementation, so implementors only need to override the
+/// events they're interested in. Methods that return an ID are paired with a completion callback
+/// that receives the same ID, to support rendering multiple concurrent progress bars.
+pub trait Reporter: Send + Sync {
+    /// Called when a package is selected during resolution.
+    fn on_resolve_progress(&self, _name: &str, _version: &str) {}
+
+    /// Called when the resolution is complete.
+    fn on_resolve_complete(&self) {}
+
+    /// Called when a download is started, with the size of the download, if known.
+    fn on_download_start(&self, _name: &str, _size: Option<u64>) -> usize {
+        0
+    }
+
+    /// Called when some number of bytes have been downloaded.
+    fn on_download_progress(&self, _id: usize, _bytes: u64) {}
+
+    /// Called when a download is complete.
+    fn on_download_complete(&self, _name: &str, _id: usize) {}
+
+    /// Called when a source distribution build is started.
+    fn on_build_start(&self, _source: &str) -> usize {
+        0
+    }
+
+    /// Called when a source distribution build is complete.
+    fn on_build_complete(&self, _source: &str, _id: usize) {}
+
+    /// Called when a Git repository checkout is started.
+    fn on_checkout_start(&self, _url: &str, _rev: &str) -> usize {
+        0
+    }
+
+    /// Called when a Git repository checkout is complete.
+    fn on_checkout_complete(&self, _url: &str, _rev: &str, _id: usize) {}
+
+    /// Called when a distribution has been downloaded (and, if necessary, built) and is ready to
+    /// be installed.
+    fn on_prepare_progress(&self, _name: &str, _version: &str) {}
+
+    /// Called when a distribution is installed into the environment.
+    fn on_install_progress(&self, _name: &str, _version: &str) {}
+
+    /// Called when the installation is complete.
+    fn on_install_complete(&self) {}
+
+    /// Called when a Python download is started, with the size of the download, if known.
+    ///
+    /// If a cached archive is reused, this reports the extraction of the archive instead.
+    fn on_python_download_start(&self, _key: &str, _size: Option<u64>) -> usize {
+        0
+    }
+
+    /// Called when some number of bytes of a Python download have been processed.
+    fn on_python_download_progress(&self, _id: usize, _bytes: u64) {}
+
+    /// Called when a Python download is complete.
+    fn on_python_download_complete(&self, _id: usize) {}
+}
+
+/// A facade for converting from [`Reporter`] to the reporter traits of uv's internal crates.
+#[derive(Clone)]
+pub(crate) struct Facade {
+    reporter: Arc<dyn Reporter>,
+}
+
+impl Facade {
+    pub(crate) fn new(reporter: Arc<dyn Reporter>) -> Self {
+        Self { reporter }
+    }
+}
+
+impl uv_resolver::ResolverReporter for Facade {
+    fn on_progress(&self, name: &PackageName, version: &VersionOrUrlRef) {
+        self.reporter
+            .on_resolve_progress(name.as_str(), &version.to_string());
+    }
+
+    fn on_complete(&self) {
+        self.reporter.on_resolve_complete();
+    }
+
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_start(&source.to_string())
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_complete(&source.to_string(), id);
+    }
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        self.reporter.on_download_start(name.as_str(), size)
+    }
+
+    fn on_download_progress(&self, id: usize, bytes: u64) {
+        self.reporter.on_download_progress(id, bytes);
+    }
+
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name.as_str(), id);
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        self.reporter.on_checkout_start(&url.to_string(), rev)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        self.reporter
+            .on_checkout_complete(&url.to_string(), rev, id);
+    }
+}
+
+impl uv_distribution::Reporter for Facade {
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_start(&source.to_string())
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_complete(&source.to_string(), id);
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        self.reporter.on_checkout_start(&url.to_string(), rev)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        self.reporter
+            .on_checkout_complete(&url.to_string(), rev, id);
+    }
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        self.reporter.on_download_start(name.as_str(), size)
+    }
+
+    fn on_download_progress(&self, id: usize, bytes: u64) {
+        self.reporter.on_download_progress(id, bytes);
+    }
+
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name.as_str(), id);
+    }
+}
+
+impl uv_installer::PrepareReporter for Facade {
+    fn on_progress(&self, dist: &CachedDist) {
+        self.reporter
+            .on_prepare_progress(dist.name().as_str(), &dist.version_or_url().to_string());
+    }
+
+    fn on_complete(&self) {}
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        self.reporter.on_download_start(name.as_str(), size)
+    }
+
+    fn on_download_progress(&self, id: usize, bytes: u64) {
+        self.reporter.on_download_progress(id, bytes);
+    }
+
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_complete(name.as_str(), id);
+    }
+
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_start(&source.to_string())
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_complete(&source.to_string(), id);
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        self.reporter.on_checkout_start(&url.to_string(), rev)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        self.reporter
+            .on_checkout_complete(&url.to_string(), rev, id);
+    }
+}
+
+impl uv_installer::InstallReporter for Facade {
+    fn on_install_progress(&self, wheel: &CachedDist) {
+        self.reporter
+            .on_install_progress(wheel.name().as_str(), &wheel.version_or_url().to_string());
+    }
+
+    fn on_install_complete(&self) {
+        self.reporter.on_install_complete();
+    }
+}
+
+impl uv_python::downloads::Reporter for Facade {
+    fn on_request_start(
+        &self,
+        _direction: Direction,
+        name: &PythonInstallationKey,
+        size: Option<u64>,
+    ) -> usize {
+        self.reporter
+            .on_python_download_start(&name.to_string(), size)
+    }
+
+    fn on_request_progress(&self, id: usize, inc: u64) {
+        self.reporter.on_python_download_progress(id, inc);
+    }
+
+    fn on_request_complete(&self, _direction: Direction, id: usize) {
+        self.reporter.on_python_download_complete(id);
+    }
+}
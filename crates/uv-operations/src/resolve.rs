@@ -0,0 +1,220 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use uv_configuration::{Constraints, Excludes, Overrides};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Name, Requirement};
+use uv_pypi_types::Conflicts;
+use uv_requirements::LookaheadResolver;
+use uv_resolver::{
+    InMemoryIndex, Manifest, OptionsBuilder, PythonRequirement, ResolveError, Resolver,
+    ResolverEnvironment,
+};
+use uv_types::EmptyInstalledPackages;
+
+use crate::context::Context;
+use crate::reporter::Facade;
+use crate::{Environment, Error, Options, Reporter};
+
+/// A resolved package, with its name and version.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Package {
+    name: String,
+    version: Option<String>,
+}
+
+impl Package {
+    pub(crate) fn new(name: String, version: Option<String>) -> Self {
+        Self { name, version }
+    }
+
+    /// The normalized name of the package, e.g., `typing-extensions`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The version of the package, if known.
+    ///
+    /// The version may be unknown for packages that are resolved from a direct URL.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+}
+
+impl Display for Package {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(version) = &self.version {
+            write!(f, "{}=={version}", self.name)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+/// A set of packages resolved for a specific [`Environment`].
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    pub(crate) inner: uv_distribution_types::Resolution,
+    packages: Vec<Package>,
+}
+
+impl Resolution {
+    /// The resolved packages, sorted by name.
+    pub fn packages(&self) -> &[Package] {
+        &self.packages
+    }
+
+    /// The number of resolved packages.
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    /// Returns `true` if no packages were resolved.
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+impl From<uv_distribution_types::Resolution> for Resolution {
+    fn from(inner: uv_distribution_types::Resolution) -> Self {
+        let mut packages = inner
+            .distributions()
+            .map(|dist| {
+                Package::new(
+                    dist.name().to_string(),
+                    dist.version().map(ToString::to_string),
+                )
+            })
+            .collect::<Vec<_>>();
+        packages.sort();
+        Self { inner, packages }
+    }
+}
+
+/// Resolve a set of requirements (e.g., `flask>=3` or `anyio[trio] @ https://...`) for the
+/// interpreter of the given [`Environment`].
+///
+/// The resolution does not take the packages that are already installed in the environment into
+/// account.
+pub async fn resolve<S: AsRef<str>>(
+    requirements: &[S],
+    environment: &Environment,
+    options: &Options,
+    reporter: Option<Arc<dyn Reporter>>,
+) -> Result<Resolution, Error> {
+    let requirements = requirements
+        .iter()
+        .map(|requirement| {
+            let requirement = requirement.as_ref();
+            uv_pep508::Requirement::from_str(requirement)
+                .map(Requirement::from)
+                .map_err(|err| Error::InvalidRequirement(requirement.to_string(), err.into()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let interpreter = environment.inner.interpreter();
+    let tags = interpreter
+        .tags()
+        .map_err(|err| Error::Platform(err.into()))?;
+
+    let context = Context::new(options, interpreter).await?;
+    let build_dispatch = context.build_dispatch(interpreter);
+    let index = InMemoryIndex::default();
+    let facade = reporter.map(Facade::new);
+
+    let resolver_env = ResolverEnvironment::specific(interpreter.resolver_marker_environment());
+    let python_requirement = PythonRequirement::from_interpreter(interpreter);
+    let constraints = Constraints::default();
+    let overrides = Overrides::default();
+    let excludes = Excludes::default();
+
+    // Determine any lookahead requirements, e.g., transitive direct URL dependencies.
+    let mut lookahead_resolver = LookaheadResolver::new(
+        &requirements,
+        &constraints,
+        &overrides,
+        &excludes,
+        &context.hasher,
+        &index,
+        DistributionDatabase::new(
+            &context.client,
+            &build_dispatch,
+            context.concurrency.downloads_semaphore.clone(),
+        ),
+    );
+    if let Some(facade) = &facade {
+        lookahead_resolver = lookahead_resolver.with_reporter(Arc::new(facade.clone()));
+    }
+    let (lookaheads, hasher) = lookahead_resolver
+        .resolve(&resolver_env)
+        .await
+        .map_err(|err| Error::Resolve(err.into()))?;
+
+    let manifest = Manifest::simple(requirements).with_lookaheads(lookaheads);
+
+    let resolver_options = OptionsBuilder::new()
+        .resolution_mode(options.resolution.into())
+        .prerelease_mode(options.prereleases.into())
+        .build_options(context.build_options.clone())
+        .build();
+
+    let mut resolver = Resolver::new(
+        manifest,
+        resolver_options,
+        &python_requirement,
+        resolver_env,
+        interpreter.markers(),
+        Conflicts::empty(),
+        Some(tags),
+        &context.flat_index,
+        &index,
+        &hasher,
+        &build_dispatch,
+        EmptyInstalledPackages,
+        DistributionDatabase::new(
+            &context.client,
+            &build_dispatch,
+            context.concurrency.downloads_semaphore.clone(),
+        ),
+    )
+    .map_err(resolve_error)?;
+    if let Some(facade) = facade {
+        resolver = resolver.with_reporter(Arc::new(facade));
+    }
+
+    let output = resolver.resolve().await.map_err(resolve_error)?;
+
+    Ok(Resolution::from(uv_distribution_types::Resolution::from(
+        output,
+    )))
+}
+
+fn resolve_error(err: ResolveError) -> Error {
+    match err {
+        ResolveError::NoSolution(err) => Error::NoSolution(err),
+        err => Error::Resolve(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Package, Resolution};
+
+    #[test]
+    fn package_display() {
+        let package = Package::new("flask".to_string(), Some("3.0.0".to_string()));
+        assert_eq!(package.to_string(), "flask==3.0.0");
+
+        let package = Package::new("flask".to_string(), None);
+        assert_eq!(package.to_string(), "flask");
+    }
+
+    #[test]
+    fn empty_resolution() {
+        let resolution = Resolution::from(uv_distribution_types::Resolution::default());
+        assert!(resolution.is_empty());
+        assert_eq!(resolution.len(), 0);
+        assert!(resolution.packages().is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Result, bail};
+
+use uv_operations::{
+    EnvironmentRequest, Error, Options, find_environment, install, install_python, resolve,
+};
+
+/// The directory of pre-built test wheels, used as a `--find-links` source.
+fn links() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("test")
+        .join("links")
+}
+
+/// Resolve and install packages into a fresh virtual environment, using only a local
+/// `--find-links` directory.
+#[tokio::test]
+async fn resolve_and_install() -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let options = Options::new()
+        .cache_dir(temp_dir.path().join("cache"))
+        .find_links(links().to_string_lossy())
+        .no_index(true)
+        .python_downloads(false);
+
+    // Find an installed interpreter, and create a virtual environment from it.
+    let python = install_python(None, &options, None).await?;
+    let venv = temp_dir.path().join(".venv");
+    let status = Command::new(python.executable())
+        .arg("-m")
+        .arg("venv")
+        .arg("--without-pip")
+        .arg(&venv)
+        .status()?;
+    if !status.success() {
+        bail!(
+            "Failed to create a virtual environment at: `{}`",
+            venv.display()
+        );
+    }
+
+    let environment = find_environment(&EnvironmentRequest::Path(venv.clone()), &options).await?;
+    assert!(environment.is_virtualenv());
+    assert_eq!(environment.python_version(), python.version());
+
+    // The highest version is resolved and installed.
+    let resolution = resolve(&["ok"], &environment, &options, None).await?;
+    let packages = resolution
+        .packages()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(packages, ["ok==2.0.0"]);
+
+    let changes = install(&resolution, &environment, &options, None).await?;
+    let installed = changes
+        .installed()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(installed, ["ok==2.0.0"]);
+    assert!(changes.uninstalled().is_empty());
+
+    // Installing the same resolution again leaves the environment unchanged.
+    let changes = install(&resolution, &environment, &options, None).await?;
+    assert!(changes.is_empty());
+
+    // Installing a different version replaces the installed version.
+    let resolution = resolve(&["ok<2"], &environment, &options, None).await?;
+    let changes = install(&resolution, &environment, &options, None).await?;
+    let installed = changes
+        .installed()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let uninstalled = changes
+        .uninstalled()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(installed, ["ok==1.0.0"]);
+    assert_eq!(uninstalled, ["ok==2.0.0"]);
+
+    // Unsatisfiable requirements are reported as such.
+    let err = resolve(&["ok>3"], &environment, &options, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, Error::NoSolution(_)),
+        "Expected no solution, found: {err}"
+    );
+
+    Ok(())
+}
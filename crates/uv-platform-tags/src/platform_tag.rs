@@ -123,6 +123,10 @@ pub enum PlatformTag {
         /// Not to be confused with the Linux mulitarch concept.
         multiarch: IosMultiarch,
     },
+    /// Ex) `manylinux_2_28_x86_64_v3`
+    ///
+    /// A `manylinux` tag for a specific `x86_64` microarchitecture level (e.g., `x86-64-v3`).
+    ManylinuxX8664Level { major: u16, minor: u16, level: u8 },
 }
 
 impl PlatformTag {
@@ -131,6 +135,7 @@ impl PlatformTag {
         match self {
             Self::Any => None,
             Self::Manylinux { .. } => Some("Linux"),
+            Self::ManylinuxX8664Level { .. } => Some("Linux"),
             Self::Manylinux1 { .. } => Some("Linux"),
             Self::Manylinux2010 { .. } => Some("Linux"),
             Self::Manylinux2014 { .. } => Some("Linux"),
@@ -167,6 +172,7 @@ impl PlatformTag {
         matches!(
             self,
             Self::Manylinux { .. }
+                | Self::ManylinuxX8664Level { .. }
                 | Self::Manylinux1 { .. }
                 | Self::Manylinux2010 { .. }
                 | Self::Manylinux2014 { .. }
@@ -178,6 +184,7 @@ impl PlatformTag {
         matches!(
             self,
             Self::Manylinux { .. }
+                | Self::ManylinuxX8664Level { .. }
                 | Self::Manylinux1 { .. }
                 | Self::Manylinux2010 { .. }
                 | Self::Manylinux2014 { .. }
@@ -269,6 +276,7 @@ impl PlatformTag {
                 multiarch: IosMultiarch::X86_64Simulator,
                 ..
             } | Self::WinAmd64
+                | Self::ManylinuxX8664Level { .. }
         )
     }
 
@@ -443,6 +451,11 @@ impl std::fmt::Display for PlatformTag {
             Self::Manylinux { major, minor, arch } => {
                 write!(f, "manylinux_{major}_{minor}_{arch}")
             }
+            Self::ManylinuxX8664Level {
+                major,
+                minor,
+                level,
+            } => write!(f, "manylinux_{major}_{minor}_x86_64_v{level}"),
             Self::Manylinux1 { arch } => write!(f, "manylinux1_{arch}"),
             Self::Manylinux2010 { arch } => write!(f, "manylinux2010_{arch}"),
             Self::Manylinux2014 { arch } => write!(f, "manylinux2014_{arch}"),
@@ -533,6 +546,19 @@ impl FromStr for PlatformTag {
                 });
             }
 
+            // Ex) manylinux_2_28_x86_64_v3
+            if let Some(level) = arch_str
+                .strip_prefix("x86_64_v")
+                .and_then(|level| level.parse::<u8>().ok())
+                .filter(|level| (2..=4).contains(level))
+            {
+                return Ok(Self::ManylinuxX8664Level {
+                    major,
+                    minor,
+                    level,
+                });
+            }
+
             let arch = arch_str
                 .parse()
                 .map_err(|_| ParsePlatformTagError::InvalidArch {
@@ -1029,6 +1055,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn manylinux_x86_64_level_platform() {
+        let tag = PlatformTag::ManylinuxX8664Level {
+            major: 2,
+            minor: 28,
+            level: 3,
+        };
+        assert_eq!(
+            PlatformTag::from_str("manylinux_2_28_x86_64_v3").as_ref(),
+            Ok(&tag)
+        );
+        assert_eq!(tag.to_string(), "manylinux_2_28_x86_64_v3");
+        assert!(tag.is_x86_64());
+        assert!(tag.is_manylinux());
+
+        assert_eq!(
+            PlatformTag::from_str("manylinux_2_28_x86_64_v5"),
+            Err(ParsePlatformTagError::InvalidArch {
+                platform: "manylinux",
+                tag: "manylinux_2_28_x86_64_v5".to_string()
+            })
+        );
+    }
+
     #[test]
     fn manylinux1_platform() {
         let tag = PlatformTag::Manylinux1 { arch: Arch::X86_64 };
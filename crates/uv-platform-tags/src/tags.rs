@@ -31,6 +31,11 @@ pub struct TagsOptions {
     pub gil_disabled: bool,
     pub debug_enabled: bool,
     pub is_cross: bool,
+    /// The `x86_64` microarchitecture level of the target (e.g., `3` for `x86-64-v3`), if any.
+    ///
+    /// When set on an `x86_64` `manylinux` platform, wheels tagged for that level (or any lower
+    /// level) are accepted and preferred over the baseline `x86_64` wheels.
+    pub x86_64_level: Option<u8>,
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd, Copy, Clone)]
@@ -183,6 +188,9 @@ impl Tags {
             if matches!(platform.os(), Os::Manylinux { .. }) && !options.manylinux_compatible {
                 platform_tags.retain(|tag| !tag.is_manylinux());
             }
+            if let Some(level) = options.x86_64_level {
+                platform_tags = with_x86_64_level(platform_tags, level);
+            }
             platform_tags
         };
 
@@ -547,6 +555,32 @@ impl Implementation {
     }
 }
 
+/// Expand the `x86_64` `manylinux` tags with their microarchitecture-specific variants.
+///
+/// For example, given `x86-64-v3`, `manylinux_2_28_x86_64` is preceded by
+/// `manylinux_2_28_x86_64_v3` and `manylinux_2_28_x86_64_v2`, in that order.
+fn with_x86_64_level(platform_tags: Vec<PlatformTag>, level: u8) -> Vec<PlatformTag> {
+    let mut expanded = Vec::with_capacity(platform_tags.len());
+    for tag in platform_tags {
+        if let PlatformTag::Manylinux {
+            major,
+            minor,
+            arch: Arch::X86_64,
+        } = tag
+        {
+            for level in (2..=level).rev() {
+                expanded.push(PlatformTag::ManylinuxX8664Level {
+                    major,
+                    minor,
+                    level,
+                });
+            }
+        }
+        expanded.push(tag);
+    }
+    expanded
+}
+
 /// Returns the compatible tags for the current [`Platform`] (e.g., `manylinux_2_17`,
 /// `macosx_11_0_arm64`, or `win_amd64`).
 ///
@@ -3000,4 +3034,46 @@ mod tests {
         assert!(non_debug_compatibility.is_compatible());
         assert!(debug_compatibility > non_debug_compatibility);
     }
+
+    #[test]
+    fn test_system_tags_abi3_older_python() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 28,
+                },
+                Arch::X86_64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            TagsOptions {
+                manylinux_compatible: true,
+                ..TagsOptions::default()
+            },
+        )
+        .unwrap();
+
+        let compatibility = |python_tag: &str, abi_tag: &str| {
+            tags.compatibility(
+                &[LanguageTag::from_str(python_tag).unwrap()],
+                &[AbiTag::from_str(abi_tag).unwrap()],
+                &[PlatformTag::from_str("manylinux_2_17_x86_64").unwrap()],
+            )
+        };
+
+        // A `cp38-abi3` wheel is compatible with CPython 3.12.
+        let cp38_abi3 = compatibility("cp38", "abi3");
+        assert!(cp38_abi3.is_compatible());
+
+        // An `abi3` wheel built against a newer CPython is not.
+        assert!(!compatibility("cp313", "abi3").is_compatible());
+
+        // Version-specific wheels are preferred over `abi3` wheels, and `abi3` wheels built against
+        // newer versions are preferred over those built against older versions.
+        let cp311_abi3 = compatibility("cp311", "abi3");
+        assert!(compatibility("cp312", "cp312") > cp311_abi3);
+        assert!(cp311_abi3 > cp38_abi3);
+    }
 }
@@ -158,6 +158,17 @@ impl FromStr for Arch {
     }
 }
 
+impl ArchVariant {
+    /// Return the numeric microarchitecture level (e.g., `3` for `x86-64-v3`).
+    pub fn level(self) -> u8 {
+        match self {
+            Self::V2 => 2,
+            Self::V3 => 3,
+            Self::V4 => 4,
+        }
+    }
+}
+
 impl FromStr for ArchVariant {
     type Err = ();
 
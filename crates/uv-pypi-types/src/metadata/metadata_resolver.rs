@@ -12,7 +12,7 @@ use uv_pep508::Requirement;
 
 use crate::lenient_requirement::LenientRequirement;
 use crate::metadata::Headers;
-use crate::metadata::pyproject_toml::PyProjectToml;
+use crate::metadata::pyproject_toml::{License, PyProjectToml};
 use crate::{LenientVersionSpecifiers, MetadataError, VerbatimParsedUrl, metadata};
 
 /// A subset of the full core metadata specification, including only the
@@ -36,6 +36,9 @@ pub struct ResolutionMetadata {
     /// Whether the version field is dynamic.
     #[serde(default)]
     pub dynamic: bool,
+    /// The SPDX license expression of the package, per the `License-Expression` field.
+    #[serde(default)]
+    pub license_expression: Option<String>,
 }
 
 /// From <https://github.com/PyO3/python-pkginfo-rs/blob/d719988323a0cfea86d4737116d7917f30e819e2/src/metadata.rs#LL78C2-L91C26>
@@ -80,6 +83,7 @@ impl ResolutionMetadata {
         let dynamic = headers
             .get_all_values("Dynamic")
             .any(|field| field == "Version");
+        let license_expression = headers.get_first_value("License-Expression");
 
         Ok(Self {
             name,
@@ -88,6 +92,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license_expression,
         })
     }
 
@@ -157,6 +162,7 @@ impl ResolutionMetadata {
                 },
             )
             .collect::<Box<_>>();
+        let license_expression = headers.get_first_value("License-Expression");
 
         Ok(Self {
             name,
@@ -165,6 +171,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license_expression,
         })
     }
 
@@ -257,6 +264,12 @@ impl ResolutionMetadata {
             .into_keys()
             .collect::<Box<_>>();
 
+        // Extract the license, if it's declared as an SPDX expression.
+        let license_expression = match project.license {
+            Some(License::Spdx(license)) => Some(license),
+            Some(License::Legacy(_)) | None => None,
+        };
+
         Ok(Self {
             name,
             version,
@@ -264,6 +277,7 @@ impl ResolutionMetadata {
             requires_python,
             provides_extra,
             dynamic,
+            license_expression,
         })
     }
 }
@@ -306,6 +320,13 @@ mod tests {
         let s = "Metadata-Version: 1.0\nName: =?utf-8?q?=C3=A4_space?= <x@y.org>\nVersion: 1.0";
         let meta = ResolutionMetadata::parse_metadata(s.as_bytes());
         assert!(matches!(meta, Err(MetadataError::InvalidName(_))));
+
+        let s = "Metadata-Version: 2.4\nName: asdf\nVersion: 1.0\nLicense-Expression: MIT OR Apache-2.0";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(
+            meta.license_expression.as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
     }
 
     #[test]
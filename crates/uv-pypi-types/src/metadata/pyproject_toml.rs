@@ -76,11 +76,23 @@ pub struct Project {
     pub dependencies: Option<Vec<String>>,
     /// Optional dependencies
     pub optional_dependencies: Option<IndexMap<ExtraName, Vec<String>>>,
+    /// The license of the project
+    pub license: Option<License>,
     /// Specifies which fields listed by PEP 621 were intentionally unspecified
     /// so another tool can/will provide such metadata dynamically.
     pub dynamic: Option<Vec<String>>,
 }
 
+/// The license of a project, as declared in `project.license`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum License {
+    /// An SPDX license expression, as specified in PEP 639.
+    Spdx(String),
+    /// A legacy license table, with a `file` or `text` key.
+    Legacy(serde::de::IgnoredAny),
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 struct PyprojectTomlWire {
@@ -89,6 +101,7 @@ struct PyprojectTomlWire {
     requires_python: Option<String>,
     dependencies: Option<Vec<String>>,
     optional_dependencies: Option<IndexMap<ExtraName, Vec<String>>>,
+    license: Option<License>,
     dynamic: Option<Vec<String>>,
 }
 
@@ -103,6 +116,7 @@ impl TryFrom<PyprojectTomlWire> for Project {
             requires_python: wire.requires_python,
             dependencies: wire.dependencies,
             optional_dependencies: wire.optional_dependencies,
+            license: wire.license,
             dynamic: wire.dynamic,
         })
     }
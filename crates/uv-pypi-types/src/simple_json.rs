@@ -51,6 +51,10 @@ pub struct PypiFile {
     pub core_metadata: Option<CoreMetadata>,
     pub filename: SmallString,
     pub hashes: Hashes,
+    /// The URL of the [PEP 740] provenance object for the file, if any.
+    ///
+    /// [PEP 740]: https://peps.python.org/pep-0740/
+    pub provenance: Option<SmallString>,
     pub requires_python: Option<Result<VersionSpecifiers, VersionSpecifiersParseError>>,
     pub size: Option<u64>,
     pub upload_time: Option<Timestamp>,
@@ -79,6 +83,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                 let mut core_metadata = None;
                 let mut filename = None;
                 let mut hashes = None;
+                let mut provenance = None;
                 let mut requires_python = None;
                 let mut size = None;
                 let mut upload_time = None;
@@ -94,6 +99,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                         }
                         "filename" => filename = Some(access.next_value()?),
                         "hashes" => hashes = Some(access.next_value()?),
+                        "provenance" => provenance = access.next_value()?,
                         "requires-python" => {
                             requires_python =
                                 access.next_value::<Option<Cow<'_, str>>>()?.map(|s| {
@@ -116,6 +122,7 @@ impl<'de> Deserialize<'de> for PypiFile {
                     filename: filename
                         .ok_or_else(|| serde::de::Error::missing_field("filename"))?,
                     hashes: hashes.ok_or_else(|| serde::de::Error::missing_field("hashes"))?,
+                    provenance,
                     requires_python,
                     size,
                     upload_time,
@@ -557,10 +564,34 @@ impl std::fmt::Display for HashDigest {
     }
 }
 
+impl HashDigest {
+    /// Parse a hash in Subresource Integrity (SRI) format, as in: `sha256-<base64>`.
+    ///
+    /// The digest is normalized to lowercase hex, such that it compares equal to the same digest
+    /// in `<algorithm>:<hash>` format.
+    fn from_sri(s: &str) -> Result<Self, HashError> {
+        let (name, value) = s
+            .split_once('-')
+            .ok_or_else(|| HashError::InvalidStructure(s.to_string()))?;
+        let algorithm = HashAlgorithm::from_str(name)?;
+        let digest = data_encoding::BASE64
+            .decode(value.as_bytes())
+            .map_err(|_| HashError::InvalidSriDigest(s.to_string()))?;
+        Ok(Self {
+            algorithm,
+            digest: SmallString::from(data_encoding::HEXLOWER.encode(&digest)),
+        })
+    }
+}
+
 impl FromStr for HashDigest {
     type Err = HashError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains(':') {
+            return Self::from_sri(s);
+        }
+
         let mut parts = s.split(':');
 
         // Extract the key and value.
@@ -736,6 +767,9 @@ pub enum HashError {
     #[error("Unexpected hash (expected `<algorithm>:<hash>`): {0}")]
     InvalidStructure(String),
 
+    #[error("Invalid base64 digest in Subresource Integrity hash: {0}")]
+    InvalidSriDigest(String),
+
     #[error("Unexpected fragment (expected `#sha256=...` or similar) on URL: {0}")]
     InvalidFragment(String),
 
@@ -747,7 +781,39 @@ pub enum HashError {
 
 #[cfg(test)]
 mod tests {
-    use crate::{HashError, Hashes};
+    use std::str::FromStr;
+
+    use crate::{HashAlgorithm, HashDigest, HashError, Hashes, PypiFile};
+
+    #[test]
+    fn parse_provenance() {
+        let file: PypiFile = serde_json::from_str(
+            r#"{
+                "filename": "Jinja2-3.1.2-py3-none-any.whl",
+                "hashes": {},
+                "provenance": "https://pypi.org/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance",
+                "url": "https://files.pythonhosted.org/packages/Jinja2-3.1.2-py3-none-any.whl"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            file.provenance.as_deref(),
+            Some(
+                "https://pypi.org/integrity/jinja2/3.1.2/Jinja2-3.1.2-py3-none-any.whl/provenance"
+            )
+        );
+
+        let file: PypiFile = serde_json::from_str(
+            r#"{
+                "filename": "Jinja2-3.1.2-py3-none-any.whl",
+                "hashes": {},
+                "provenance": null,
+                "url": "https://files.pythonhosted.org/packages/Jinja2-3.1.2-py3-none-any.whl"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(file.provenance, None);
+    }
 
     #[test]
     fn parse_hashes() -> Result<(), HashError> {
@@ -836,6 +902,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn parse_sri_digest() -> Result<(), HashError> {
+        let sri = HashDigest::from_str("sha256-tqhYcaedLjsi0tG5SsKCQiamPGt0HIj3rpdfGLZ3g3Q=")?;
+        let hex = HashDigest::from_str(
+            "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+        )?;
+        assert_eq!(sri.algorithm, HashAlgorithm::Sha256);
+        assert_eq!(sri, hex);
+
+        let result = HashDigest::from_str("sha256-not base64");
+        assert!(matches!(result, Err(HashError::InvalidSriDigest(_))));
+
+        let result = HashDigest::from_str("sha1-tqhYcaedLjsi0tG5SsKCQiamPGt0HIj3rpdfGLZ3g3Q=");
+        assert!(matches!(
+            result,
+            Err(HashError::UnsupportedHashAlgorithm(_))
+        ));
+
+        Ok(())
+    }
 }
 
 /// Response from the Simple API root endpoint (index) listing all available projects,
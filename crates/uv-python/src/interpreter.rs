@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::str::FromStr;
 use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
 use std::{env, io};
 
 use configparser::ini::Ini;
@@ -15,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
 use uv_fs::{
@@ -63,12 +64,13 @@ pub struct Interpreter {
     gil_disabled: bool,
     real_executable: PathBuf,
     debug_enabled: bool,
+    cached: bool,
 }
 
 impl Interpreter {
     /// Detect the interpreter info for the given Python executable.
     pub fn query(executable: impl AsRef<Path>, cache: &Cache) -> Result<Self, Error> {
-        let info = InterpreterInfo::query_cached(executable.as_ref(), cache)?;
+        let (info, cached) = InterpreterInfo::query_cached(executable.as_ref(), cache)?;
 
         debug_assert!(
             info.sys_executable.is_absolute(),
@@ -97,9 +99,16 @@ impl Interpreter {
             target: None,
             prefix: None,
             real_executable: executable.as_ref().to_path_buf(),
+            cached,
         })
     }
 
+    /// Returns `true` if the interpreter metadata was read from the cache, rather than by
+    /// querying the executable.
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
+
     /// Return a new [`Interpreter`] with the given virtual environment root.
     #[must_use]
     pub fn with_virtualenv(self, virtualenv: VirtualEnvironment) -> Self {
@@ -256,6 +265,7 @@ impl Interpreter {
                     gil_disabled: self.gil_disabled,
                     debug_enabled: self.debug_enabled,
                     is_cross: false,
+                    x86_64_level: None,
                 },
             )?;
             self.tags.set(tags).expect("tags should not be set");
@@ -643,6 +653,29 @@ impl Interpreter {
             .chain(interpreter.into_iter().flatten().map(Cow::Borrowed))
     }
 
+    /// Returns an iterator over the `dist-packages` directories that are managed by the system
+    /// package manager, as on Debian and Ubuntu.
+    ///
+    /// Debian's interpreters install packages into `/usr/local/lib/python3.X/dist-packages`, while
+    /// packages installed via `apt` live in `/usr/lib/python3/dist-packages`. The latter are on the
+    /// runtime `sys.path`, but are not part of the `sysconfig` scheme, and so are excluded from
+    /// [`Interpreter::site_packages`].
+    ///
+    /// Always empty for virtual environments, and for `--target` and `--prefix` installations.
+    pub fn system_site_packages(&self) -> impl Iterator<Item = &Path> {
+        let enabled = !self.is_virtualenv() && !self.is_target() && !self.is_prefix();
+        self.site_packages
+            .iter()
+            .filter(move |path| {
+                enabled
+                    && path.file_name().is_some_and(|name| name == "dist-packages")
+                    && !self
+                        .site_packages()
+                        .any(|site_packages| site_packages.as_ref() == path.as_path())
+            })
+            .map(PathBuf::as_path)
+    }
+
     /// Whether or not this Python interpreter is from a default Python executable name, like
     /// `python`, `python3`, or `python.exe`.
     pub(crate) fn has_default_executable_name(&self) -> bool {
@@ -1116,8 +1149,11 @@ impl InterpreterInfo {
     ///
     /// Running a Python script is (relatively) expensive, and the markers won't change
     /// unless the Python executable changes, so we use the executable's last modified
-    /// time as a cache key.
-    fn query_cached(executable: &Path, cache: &Cache) -> Result<Self, Error> {
+    /// time and size as a cache key. Cached results expire after [`INTERPRETER_CACHE_TTL`], and
+    /// are ignored entirely with `--refresh`.
+    ///
+    /// Returns the [`InterpreterInfo`], along with whether it was read from the cache.
+    fn query_cached(executable: &Path, cache: &Cache) -> Result<(Self, bool), Error> {
         let absolute = std::path::absolute(executable)?;
 
         // Provide a better error message if the link is broken or the file does not exist. Since
@@ -1169,9 +1205,11 @@ impl InterpreterInfo {
             format!("{}.msgpack", cache_digest(&(&absolute, &canonical))),
         );
 
-        // We check the timestamp of the canonicalized executable to check if an underlying
-        // interpreter has been modified.
-        let modified = Timestamp::from_path(canonical).map_err(handle_io_error)?;
+        // We check the timestamp and size of the canonicalized executable to check if an
+        // underlying interpreter has been modified.
+        let metadata = fs_err::metadata(&canonical).map_err(handle_io_error)?;
+        let modified = Timestamp::from_metadata(&metadata);
+        let size = metadata.len();
 
         // Read from the cache.
         if cache
@@ -1179,15 +1217,21 @@ impl InterpreterInfo {
             .is_ok_and(Freshness::is_fresh)
         {
             if let Ok(data) = fs::read(cache_entry.path()) {
-                match rmp_serde::from_slice::<CachedByTimestamp<Self>>(&data) {
+                match rmp_serde::from_slice::<CachedInterpreterInfo>(&data) {
                     Ok(cached) => {
-                        if cached.timestamp == modified {
+                        if cached.timestamp == modified
+                            && cached.size == size
+                            && cached
+                                .queried_at
+                                .elapsed()
+                                .is_ok_and(|elapsed| elapsed < INTERPRETER_CACHE_TTL)
+                        {
                             trace!(
                                 "Found cached interpreter info for Python {}, skipping query of: {}",
                                 cached.data.markers.python_full_version(),
                                 executable.user_display()
                             );
-                            return Ok(cached.data);
+                            return Ok((cached.data, true));
                         }
 
                         trace!(
@@ -1211,7 +1255,16 @@ impl InterpreterInfo {
             "Querying interpreter executable at {}",
             executable.display()
         );
-        let info = Self::query(executable, cache)?;
+        let queried_at = SystemTime::now();
+        let info = match Self::query(executable, cache) {
+            Ok(info) => info,
+            Err(err) => {
+                // If the interpreter can no longer be queried (e.g., it fails to spawn), discard
+                // any existing cache entry.
+                let _ = fs_err::remove_file(cache_entry.path());
+                return Err(err);
+            }
+        };
 
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
@@ -1219,17 +1272,38 @@ impl InterpreterInfo {
             fs::create_dir_all(cache_entry.dir())?;
             write_atomic_sync(
                 cache_entry.path(),
-                rmp_serde::to_vec(&CachedByTimestamp {
+                rmp_serde::to_vec(&CachedInterpreterInfo {
                     timestamp: modified,
+                    size,
+                    queried_at,
                     data: info.clone(),
                 })?,
             )?;
         }
 
-        Ok(info)
+        Ok((info, false))
     }
 }
 
+/// The duration for which a cached interpreter query remains valid.
+///
+/// The cache entry is also invalidated if the executable's timestamp or size changes, so this
+/// only bounds how long we trust an interpreter whose dependencies (e.g., shared libraries) may
+/// have changed without modifying the executable itself.
+const INTERPRETER_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// A cached [`InterpreterInfo`], along with the metadata used to validate it.
+#[derive(Deserialize, Serialize)]
+struct CachedInterpreterInfo {
+    /// The timestamp of the canonicalized executable.
+    timestamp: Timestamp,
+    /// The size of the canonicalized executable, in bytes.
+    size: u64,
+    /// The time at which the interpreter was queried.
+    queried_at: SystemTime,
+    data: InterpreterInfo,
+}
+
 /// Find the Python executable that should be considered the "base" for a virtual environment.
 ///
 /// Assumes that the provided executable is that of a standalone Python interpreter.
@@ -1333,16 +1407,14 @@ mod tests {
     use indoc::{formatdoc, indoc};
     use tempfile::tempdir;
 
-    use uv_cache::Cache;
+    use uv_cache::{Cache, CacheBucket};
     use uv_pep440::Version;
 
     use crate::Interpreter;
 
-    #[tokio::test]
-    async fn test_cache_invalidation() {
-        let mock_dir = tempdir().unwrap();
-        let mocked_interpreter = mock_dir.path().join("python");
-        let json = indoc! {r##"
+    /// The interpreter info reported by a mocked Python 3.12 executable.
+    fn mock_interpreter_info() -> &'static str {
+        indoc! {r##"
         {
             "result": "success",
             "platform": {
@@ -1399,7 +1471,14 @@ mod tests {
             "gil_disabled": true,
             "debug_enabled": false
         }
-    "##};
+    "##}
+    }
+
+    #[tokio::test]
+    async fn test_cache_invalidation() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = mock_interpreter_info();
 
         let cache = Cache::temp().unwrap().init().await.unwrap();
 
@@ -1436,4 +1515,57 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[tokio::test]
+    async fn test_cache_hit() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        // The interpreter must report itself as `sys.executable` to be cached.
+        let json = mock_interpreter_info().replace(
+            "/home/ferris/projects/uv/.venv/bin/python",
+            mocked_interpreter.to_str().unwrap(),
+        );
+
+        let cache = Cache::temp().unwrap().init().await.unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo '{json}'
+        "},
+        )
+        .unwrap();
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert!(!interpreter.is_cached());
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert!(interpreter.is_cached());
+
+        // Count the cached interpreter entries, across all shards.
+        let cache_entries = || {
+            fs::read_dir(cache.bucket(CacheBucket::Interpreter))
+                .unwrap()
+                .map(|shard| fs::read_dir(shard.unwrap().path()).unwrap().count())
+                .sum::<usize>()
+        };
+        assert_eq!(cache_entries(), 1);
+
+        // If the interpreter fails to run, the cache entry is discarded.
+        fs::write(
+            &mocked_interpreter,
+            indoc! {r"
+        #!/bin/sh
+        exit 1
+        "},
+        )
+        .unwrap();
+        assert!(Interpreter::query(&mocked_interpreter, &cache).is_err());
+        assert_eq!(cache_entries(), 0);
+    }
 }
@@ -220,7 +220,8 @@ mod tests {
     use uv_cache::Cache;
 
     use crate::{
-        PythonDownloads, PythonNotFound, PythonRequest, PythonSource, PythonVersion,
+        PYTHON_VERSION_FILENAME, PythonDownloads, PythonNotFound, PythonRequest, PythonSource,
+        PythonVersion, PythonVersionFile, VersionFileDiscoveryOptions,
         find_all_python_installations, find_python_installations,
         implementation::ImplementationName, installation::PythonInstallation,
         managed::ManagedPythonInstallations, virtualenv::virtualenv_python_executable,
@@ -3132,6 +3133,93 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn find_python_implementation_pin_round_trip() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[
+            (true, ImplementationName::CPython, "python", "3.12.0"),
+            (true, ImplementationName::PyPy, "pypy", "3.10.1"),
+            (true, ImplementationName::GraalPy, "graalpy", "3.11.2"),
+        ])?;
+
+        for (pin, implementation, version) in [
+            ("pypy@3.10", ImplementationName::PyPy, "3.10.1"),
+            ("graalpy@3.11", ImplementationName::GraalPy, "3.11.2"),
+            ("cpython@3.12", ImplementationName::CPython, "3.12.0"),
+        ] {
+            // Write the pin, then read it back as `uv python find` would
+            PythonVersionFile::new(context.workdir.join(PYTHON_VERSION_FILENAME))
+                .with_versions(vec![PythonRequest::parse(pin)])
+                .write()
+                .await?;
+            assert_eq!(
+                fs_err::read_to_string(context.workdir.join(PYTHON_VERSION_FILENAME))?,
+                format!("{pin}\n")
+            );
+            let request = PythonVersionFile::discover(
+                context.workdir.path(),
+                &VersionFileDiscoveryOptions::default(),
+            )
+            .await?
+            .and_then(PythonVersionFile::into_version)
+            .expect("The pin should be discovered");
+            assert_eq!(request.to_canonical_string(), pin);
+
+            let python = context.run(|| {
+                find_python_installation(
+                    &request,
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    &context.cache,
+                )
+            })??;
+            assert_eq!(
+                python.interpreter().implementation_name(),
+                implementation.long_name(),
+                "We should find the {implementation} interpreter for `{pin}`"
+            );
+            assert_eq!(
+                python.interpreter().python_full_version().to_string(),
+                version
+            );
+
+            // A resolved pin is written as the path to the interpreter, which should select the
+            // same implementation when read back
+            PythonVersionFile::new(context.workdir.join(PYTHON_VERSION_FILENAME))
+                .with_versions(vec![PythonRequest::parse(
+                    &python.interpreter().sys_executable().display().to_string(),
+                )])
+                .write()
+                .await?;
+            let request = PythonVersionFile::discover(
+                context.workdir.path(),
+                &VersionFileDiscoveryOptions::default(),
+            )
+            .await?
+            .and_then(PythonVersionFile::into_version)
+            .expect("The resolved pin should be discovered");
+            let resolved = context.run(|| {
+                find_python_installation(
+                    &request,
+                    EnvironmentPreference::Any,
+                    PythonPreference::OnlySystem,
+                    &context.cache,
+                )
+            })??;
+            assert_eq!(
+                resolved.interpreter().implementation_name(),
+                implementation.long_name(),
+                "The resolved pin for `{pin}` should keep the implementation"
+            );
+            assert_eq!(
+                resolved.interpreter().python_full_version().to_string(),
+                version
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_version_free_threaded() -> Result<()> {
         let mut context = TestContext::new()?;
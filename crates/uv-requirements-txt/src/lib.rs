@@ -8,6 +8,7 @@
 //!  * `-c`
 //!  * `--hash` (postfix)
 //!  * `-e`
+//!  * `# uv: no-build-package=<name>` and `# uv: no-binary-package=<name>` directives
 //!
 //! Unsupported:
 //!  * `<path>`. TBD
@@ -53,6 +54,7 @@ use uv_distribution_types::{
     Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::{Simplified, normalize_path};
+use uv_normalize::PackageName;
 use uv_pep508::{Pep508Error, RequirementOrigin, VerbatimUrl, expand_env_vars};
 use uv_pypi_types::VerbatimParsedUrl;
 #[cfg(feature = "http")]
@@ -96,9 +98,9 @@ enum RequirementsTxtStatement {
     /// `--no-index`
     NoIndex,
     /// `--no-binary`
-    NoBinary(NoBinary),
+    NoBinary(PackageNameSpecifier),
     /// `--only-binary`
-    OnlyBinary(NoBuild),
+    OnlyBinary(PackageNameSpecifier),
     /// An unsupported option (e.g., `--trusted-host`).
     UnsupportedOption(UnsupportedOption),
 }
@@ -198,11 +200,13 @@ impl RequirementsTxt {
             requirements: &mut FxHashSet::default(),
             constraints: &mut FxHashSet::default(),
         };
+        let mut chain = vec![visited_file(requirements_txt.as_ref())];
         Self::parse_impl(
             requirements_txt,
             working_dir,
             client_builder,
             &mut visited,
+            &mut chain,
             cache,
         )
         .await
@@ -226,6 +230,8 @@ impl RequirementsTxt {
             constraints: &mut FxHashSet::default(),
         };
 
+        let mut chain = vec![visited_file(requirements_txt)];
+
         Self::parse_inner(
             content,
             working_dir,
@@ -233,6 +239,7 @@ impl RequirementsTxt {
             client_builder,
             requirements_txt,
             &mut visited,
+            &mut chain,
             source_contents,
         )
         .await
@@ -252,6 +259,7 @@ impl RequirementsTxt {
         working_dir: impl AsRef<Path>,
         client_builder: &BaseClientBuilder<'_>,
         visited: &mut VisitedFiles<'_>,
+        chain: &mut Vec<PathBuf>,
         cache: &mut SourceCache,
     ) -> Result<Self, RequirementsTxtFileError> {
         let requirements_txt = requirements_txt.as_ref();
@@ -331,6 +339,7 @@ impl RequirementsTxt {
             client_builder,
             requirements_txt,
             visited,
+            chain,
             cache,
         )
         .await
@@ -346,8 +355,11 @@ impl RequirementsTxt {
     ///
     /// When parsing, relative paths to requirements (e.g., `-e ../editable/`) are resolved against
     /// the current working directory. However, relative paths to sub-files (e.g., `-r ../requirements.txt`)
-    /// are resolved against the directory of the containing `requirements.txt` file, to match
-    /// `pip`'s behavior.
+    /// are resolved against the directory of the containing `requirements.txt` file (or, for remote
+    /// files, against its URL), to match `pip`'s behavior.
+    ///
+    /// The `chain` contains the files that are currently being parsed, from the root file to
+    /// `requirements_txt`, and is used to detect circular inclusions.
     async fn parse_inner(
         content: &str,
         working_dir: &Path,
@@ -355,6 +367,7 @@ impl RequirementsTxt {
         client_builder: &BaseClientBuilder<'_>,
         requirements_txt: &Path,
         visited: &mut VisitedFiles<'_>,
+        chain: &mut Vec<PathBuf>,
         cache: &mut SourceCache,
     ) -> Result<Self, RequirementsTxtParserError> {
         let mut s = Scanner::new(content);
@@ -369,27 +382,8 @@ impl RequirementsTxt {
                 } => {
                     let filename = expand_env_vars(&filename);
                     let sub_file =
-                        if filename.starts_with("http://") || filename.starts_with("https://") {
-                            PathBuf::from(filename.as_ref())
-                        } else if filename.starts_with("file://") {
-                            requirements_txt.join(
-                                Url::parse(filename.as_ref())
-                                    .map_err(|err| RequirementsTxtParserError::Url {
-                                        source: DisplaySafeUrlError::Url(err).into(),
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?
-                                    .to_file_path()
-                                    .map_err(|()| RequirementsTxtParserError::FileUrl {
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?,
-                            )
-                        } else {
-                            requirements_dir.join(filename.as_ref())
-                        };
+                        resolve_include(&filename, requirements_txt, requirements_dir, start, end)?;
+                    check_circular(&sub_file, chain, start, end)?;
                     match visited {
                         VisitedFiles::Requirements { requirements, .. } => {
                             if !requirements.insert(visited_file(&sub_file)) {
@@ -405,19 +399,23 @@ impl RequirementsTxt {
                             }
                         }
                     }
+                    chain.push(visited_file(&sub_file));
                     let sub_requirements = Box::pin(Self::parse_impl(
                         &sub_file,
                         working_dir,
                         client_builder,
                         visited,
+                        chain,
                         cache,
                     ))
-                    .await
-                    .map_err(|err| RequirementsTxtParserError::Subfile {
-                        source: Box::new(err),
-                        start,
-                        end,
-                    })?;
+                    .await;
+                    chain.pop();
+                    let sub_requirements =
+                        sub_requirements.map_err(|err| RequirementsTxtParserError::Subfile {
+                            source: Box::new(err),
+                            start,
+                            end,
+                        })?;
 
                     // Disallow conflicting `--index-url` in nested `requirements` files.
                     if sub_requirements.index_url.is_some()
@@ -444,27 +442,9 @@ impl RequirementsTxt {
                 } => {
                     let filename = expand_env_vars(&filename);
                     let sub_file =
-                        if filename.starts_with("http://") || filename.starts_with("https://") {
-                            PathBuf::from(filename.as_ref())
-                        } else if filename.starts_with("file://") {
-                            requirements_txt.join(
-                                Url::parse(filename.as_ref())
-                                    .map_err(|err| RequirementsTxtParserError::Url {
-                                        source: DisplaySafeUrlError::Url(err).into(),
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?
-                                    .to_file_path()
-                                    .map_err(|()| RequirementsTxtParserError::FileUrl {
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?,
-                            )
-                        } else {
-                            requirements_dir.join(filename.as_ref())
-                        };
+                        resolve_include(&filename, requirements_txt, requirements_dir, start, end)?;
+
+                    check_circular(&sub_file, chain, start, end)?;
 
                     // Switch to constraints mode, if we aren't in it already.
                     let mut visited = match visited {
@@ -482,19 +462,23 @@ impl RequirementsTxt {
                         }
                     };
 
+                    chain.push(visited_file(&sub_file));
                     let sub_constraints = Box::pin(Self::parse_impl(
                         &sub_file,
                         working_dir,
                         client_builder,
                         &mut visited,
+                        chain,
                         cache,
                     ))
-                    .await
-                    .map_err(|err| RequirementsTxtParserError::Subfile {
-                        source: Box::new(err),
-                        start,
-                        end,
-                    })?;
+                    .await;
+                    chain.pop();
+                    let sub_constraints =
+                        sub_constraints.map_err(|err| RequirementsTxtParserError::Subfile {
+                            source: Box::new(err),
+                            start,
+                            end,
+                        })?;
 
                     // Treat any nested requirements or constraints as constraints. This differs
                     // from `pip`, which seems to treat `-r` requirements in constraints files as
@@ -542,11 +526,11 @@ impl RequirementsTxt {
                 RequirementsTxtStatement::NoIndex => {
                     data.no_index = true;
                 }
-                RequirementsTxtStatement::NoBinary(no_binary) => {
-                    data.no_binary.extend(no_binary);
+                RequirementsTxtStatement::NoBinary(specifier) => {
+                    data.add_no_binary(specifier);
                 }
-                RequirementsTxtStatement::OnlyBinary(only_binary) => {
-                    data.only_binary.extend(only_binary);
+                RequirementsTxtStatement::OnlyBinary(specifier) => {
+                    data.add_only_binary(specifier);
                 }
                 RequirementsTxtStatement::UnsupportedOption(flag) => {
                     if requirements_txt == Path::new("-") {
@@ -604,8 +588,74 @@ impl RequirementsTxt {
         self.extra_index_urls.extend(extra_index_urls);
         self.find_links.extend(find_links);
         self.no_index = self.no_index || no_index;
-        self.no_binary.extend(no_binary);
-        self.only_binary.extend(only_binary);
+
+        // The nested file's options are already resolved, so apply `:all:` first, such that the
+        // package-specific options take precedence over it.
+        if matches!(no_binary, NoBinary::All) {
+            self.add_no_binary(PackageNameSpecifier::All);
+        }
+        if matches!(only_binary, NoBuild::All) {
+            self.add_only_binary(PackageNameSpecifier::All);
+        }
+        if let NoBinary::Packages(packages) = no_binary {
+            for package in packages {
+                self.add_no_binary(PackageNameSpecifier::Package(package));
+            }
+        }
+        if let NoBuild::Packages(packages) = only_binary {
+            for package in packages {
+                self.add_only_binary(PackageNameSpecifier::Package(package));
+            }
+        }
+    }
+
+    /// Apply a `--no-binary` option.
+    ///
+    /// Like pip, `--no-binary` and `--only-binary` are tracked per package: naming a package in
+    /// one removes it from the other, `:all:` resets both, and `:none:` resets the current one.
+    fn add_no_binary(&mut self, specifier: PackageNameSpecifier) {
+        match specifier {
+            PackageNameSpecifier::All => {
+                self.no_binary = NoBinary::All;
+                self.only_binary = NoBuild::None;
+            }
+            PackageNameSpecifier::None => {
+                self.no_binary = NoBinary::None;
+            }
+            PackageNameSpecifier::Package(name) => {
+                if let NoBuild::Packages(packages) = &mut self.only_binary {
+                    packages.retain(|package| *package != name);
+                    if packages.is_empty() {
+                        self.only_binary = NoBuild::None;
+                    }
+                }
+                self.no_binary.extend(NoBinary::Packages(vec![name]));
+            }
+        }
+    }
+
+    /// Apply an `--only-binary` option.
+    ///
+    /// See [`RequirementsTxt::add_no_binary`] for how the two options interact.
+    fn add_only_binary(&mut self, specifier: PackageNameSpecifier) {
+        match specifier {
+            PackageNameSpecifier::All => {
+                self.only_binary = NoBuild::All;
+                self.no_binary = NoBinary::None;
+            }
+            PackageNameSpecifier::None => {
+                self.only_binary = NoBuild::None;
+            }
+            PackageNameSpecifier::Package(name) => {
+                if let NoBinary::Packages(packages) = &mut self.no_binary {
+                    packages.retain(|package| *package != name);
+                    if packages.is_empty() {
+                        self.no_binary = NoBinary::None;
+                    }
+                }
+                self.only_binary.extend(NoBuild::Packages(vec![name]));
+            }
+        }
     }
 }
 
@@ -682,6 +732,10 @@ fn parse_entry(
     // Eat all preceding whitespace, this may run us to the end of file
     eat_wrappable_whitespace(s);
     while s.at(['\n', '\r', '#']) {
+        // Parse `# uv:` directives, e.g., `# uv: no-build-package=foo`.
+        if let Some(statement) = parse_directive(s, content, requirements_txt)? {
+            return Ok(Some(statement));
+        }
         // skip comments
         eat_trailing_line(content, s)?;
         eat_wrappable_whitespace(s);
@@ -857,7 +911,7 @@ fn parse_entry(
                 end: s.cursor(),
             }
         })?;
-        RequirementsTxtStatement::NoBinary(NoBinary::from_pip_arg(specifier))
+        RequirementsTxtStatement::NoBinary(specifier)
     } else if s.eat_if("--only-binary") {
         let given = parse_value("--only-binary", content, s, |c: char| !is_terminal(c))?;
         let given = unquote(given)
@@ -873,7 +927,7 @@ fn parse_entry(
                 end: s.cursor(),
             }
         })?;
-        RequirementsTxtStatement::OnlyBinary(NoBuild::from_pip_arg(specifier))
+        RequirementsTxtStatement::OnlyBinary(specifier)
     } else if s.at(char::is_ascii_alphanumeric) || s.at(|char| matches!(char, '.' | '/' | '$')) {
         let source = if requirements_txt == Path::new("-") {
             None
@@ -908,6 +962,77 @@ fn parse_entry(
     }))
 }
 
+/// Parse a `# uv:` directive, if the scanner is at one.
+///
+/// Directives apply a per-package build option to the requirements file, e.g.,
+/// `# uv: no-build-package=foo` is equivalent to `--only-binary foo`, and
+/// `# uv: no-binary-package=foo` is equivalent to `--no-binary foo`. Other comments are left in
+/// place.
+fn parse_directive(
+    s: &mut Scanner,
+    content: &str,
+    requirements_txt: &Path,
+) -> Result<Option<RequirementsTxtStatement>, RequirementsTxtParserError> {
+    let start = s.cursor();
+    if !s.eat_if('#') {
+        return Ok(None);
+    }
+    s.eat_while([' ', '\t']);
+    if !s.eat_if("uv:") {
+        s.jump(start);
+        return Ok(None);
+    }
+    s.eat_while([' ', '\t']);
+    let directive = s.eat_until(['\r', '\n']).trim_end();
+
+    let Some((key, value)) = directive.split_once('=') else {
+        let (line, column) = calculate_row_column(content, start);
+        return Err(RequirementsTxtParserError::Parser {
+            message: format!(
+                "Expected a `# uv:` directive of the form `<key>=<value>`, found `{directive}`"
+            ),
+            line,
+            column,
+        });
+    };
+    let (key, value) = (key.trim(), value.trim());
+
+    let parse_package = |value: &str| {
+        PackageName::from_str(value).map_err(|err| {
+            let (line, column) = calculate_row_column(content, start);
+            RequirementsTxtParserError::Parser {
+                message: format!("Invalid package name in `# uv: {key}` directive: {err}"),
+                line,
+                column,
+            }
+        })
+    };
+
+    match key {
+        "no-build-package" => Ok(Some(RequirementsTxtStatement::OnlyBinary(
+            PackageNameSpecifier::Package(parse_package(value)?),
+        ))),
+        "no-binary-package" => Ok(Some(RequirementsTxtStatement::NoBinary(
+            PackageNameSpecifier::Package(parse_package(value)?),
+        ))),
+        _ => {
+            if requirements_txt == Path::new("-") {
+                uv_warnings::warn_user!(
+                    "Ignoring unsupported directive from stdin: `# uv: {key}`",
+                    key = key.green()
+                );
+            } else {
+                uv_warnings::warn_user!(
+                    "Ignoring unsupported directive in `{path}`: `# uv: {key}`",
+                    path = requirements_txt.user_display().cyan(),
+                    key = key.green()
+                );
+            }
+            Ok(None)
+        }
+    }
+}
+
 /// Eat whitespace and ignore newlines escaped with a backslash
 fn eat_wrappable_whitespace<'a>(s: &mut Scanner<'a>) -> &'a str {
     let start = s.cursor();
@@ -1201,6 +1326,11 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    CircularInclude {
+        chain: Vec<PathBuf>,
+        start: usize,
+        end: usize,
+    },
     NonUnicodeUrl {
         url: PathBuf,
     },
@@ -1268,6 +1398,13 @@ impl Display for RequirementsTxtParserError {
             Self::Subfile { start, .. } => {
                 write!(f, "Error parsing included file at position {start}")
             }
+            Self::CircularInclude { chain, start, .. } => {
+                write!(
+                    f,
+                    "Circular inclusion of requirements file at position {start}: {}",
+                    display_chain(chain)
+                )
+            }
             Self::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1317,6 +1454,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Pep508 { source, .. } => Some(source),
             Self::ParsedUrl { source, .. } => Some(source),
             Self::Subfile { source, .. } => Some(source.as_ref()),
+            Self::CircularInclude { .. } => None,
             Self::Parser { .. } => None,
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
@@ -1442,6 +1580,14 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::CircularInclude { chain, start, .. } => {
+                write!(
+                    f,
+                    "Circular inclusion of requirements file in `{}` at position {start}: {}",
+                    self.file.user_display(),
+                    display_chain(chain)
+                )
+            }
             RequirementsTxtParserError::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1520,6 +1666,83 @@ fn visited_file(path: &Path) -> PathBuf {
     }
 }
 
+/// Resolve the path to a file included from `requirements_txt` via `-r` or `-c`.
+///
+/// Relative paths are resolved against the including file, rather than the working directory. If
+/// the including file is remote, relative paths are resolved against its URL.
+fn resolve_include(
+    filename: &str,
+    requirements_txt: &Path,
+    requirements_dir: &Path,
+    start: usize,
+    end: usize,
+) -> Result<PathBuf, RequirementsTxtParserError> {
+    if filename.starts_with("http://") || filename.starts_with("https://") {
+        Ok(PathBuf::from(filename))
+    } else if filename.starts_with("file://") {
+        Ok(requirements_txt.join(
+            Url::parse(filename)
+                .map_err(|err| RequirementsTxtParserError::Url {
+                    source: DisplaySafeUrlError::Url(err).into(),
+                    url: filename.to_string(),
+                    start,
+                    end,
+                })?
+                .to_file_path()
+                .map_err(|()| RequirementsTxtParserError::FileUrl {
+                    url: filename.to_string(),
+                    start,
+                    end,
+                })?,
+        ))
+    } else if requirements_txt.starts_with("http://") || requirements_txt.starts_with("https://") {
+        // Ex) `-r base.txt` in `https://example.com/requirements/dev.txt`
+        let url = Url::parse(&requirements_txt.to_string_lossy())
+            .and_then(|base| base.join(filename))
+            .map_err(|err| RequirementsTxtParserError::Url {
+                source: DisplaySafeUrlError::Url(err).into(),
+                url: filename.to_string(),
+                start,
+                end,
+            })?;
+        Ok(PathBuf::from(url.as_str()))
+    } else {
+        Ok(requirements_dir.join(filename))
+    }
+}
+
+/// Returns an error if including `sub_file` would form a cycle with the files that are currently
+/// being parsed, with the full chain of inclusions from the root file.
+fn check_circular(
+    sub_file: &Path,
+    chain: &[PathBuf],
+    start: usize,
+    end: usize,
+) -> Result<(), RequirementsTxtParserError> {
+    let sub_file = visited_file(sub_file);
+    if !chain.contains(&sub_file) {
+        return Ok(());
+    }
+    Err(RequirementsTxtParserError::CircularInclude {
+        chain: chain
+            .iter()
+            .cloned()
+            .chain(std::iter::once(sub_file))
+            .collect(),
+        start,
+        end,
+    })
+}
+
+/// Format a chain of included files, e.g., `` `a.txt` -> `b.txt` -> `a.txt` ``.
+fn display_chain(chain: &[PathBuf]) -> String {
+    chain
+        .iter()
+        .map(|file| format!("`{}`", file.user_display()))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 /// Calculates the column and line offset of a given cursor based on the
 /// number of Unicode codepoints.
 fn calculate_row_column(content: &str, position: usize) -> (usize, usize) {
@@ -1577,7 +1800,7 @@ mod test {
 
     use uv_fs::Simplified;
 
-    use crate::{RequirementsTxt, calculate_row_column};
+    use crate::{RequirementsTxt, calculate_row_column, resolve_include};
 
     fn workspace_test_data_dir() -> PathBuf {
         Path::new("./test-data").simple_canonicalize().unwrap()
@@ -2162,6 +2385,135 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn no_binary_only_binary_per_package() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        // The last option for a package wins, and `:none:` only resets packages named before it.
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            --no-binary flask
+            --no-binary jinja2
+            --only-binary flask
+            --only-binary markupsafe
+            --only-binary :none:
+            --only-binary werkzeug
+            -r child.txt
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            --no-binary werkzeug
+        "})?;
+
+        let requirements = RequirementsTxt::parse(requirements_txt.path(), temp_dir.path())
+            .await
+            .unwrap();
+
+        insta::assert_debug_snapshot!((requirements.no_binary, requirements.only_binary), @r#"
+        (
+            Packages(
+                [
+                    PackageName(
+                        "jinja2",
+                    ),
+                    PackageName(
+                        "werkzeug",
+                    ),
+                ],
+            ),
+            None,
+        )
+        "#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn uv_directives() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        // Each directive applies to the named package only, like the equivalent CLI option.
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            # uv: no-build-package=flask
+            # uv: no-binary-package = jinja2
+            #uv: no-binary-package=Werkzeug
+            # A regular comment, mentioning uv: no-build-package=markupsafe
+            flask
+            jinja2
+        "})?;
+
+        let requirements = RequirementsTxt::parse(requirements_txt.path(), temp_dir.path())
+            .await
+            .unwrap();
+
+        insta::assert_debug_snapshot!((requirements.no_binary, requirements.only_binary), @r#"
+        (
+            Packages(
+                [
+                    PackageName(
+                        "jinja2",
+                    ),
+                    PackageName(
+                        "werkzeug",
+                    ),
+                ],
+            ),
+            Packages(
+                [
+                    PackageName(
+                        "flask",
+                    ),
+                ],
+            ),
+        )
+        "#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_uv_directive() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            flask
+            # uv: no-build-package
+        "})?;
+
+        let error = RequirementsTxt::parse(requirements_txt.path(), temp_dir.path())
+            .await
+            .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters.clone()
+        }, {
+            insta::assert_snapshot!(errors, @"Expected a `# uv:` directive of the form `<key>=<value>`, found `no-build-package` at <REQUIREMENTS_TXT>:2:1");
+        });
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            # uv: no-binary-package=:all:
+        "})?;
+
+        let error = RequirementsTxt::parse(requirements_txt.path(), temp_dir.path())
+            .await
+            .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @"Invalid package name in `# uv: no-binary-package` directive: Not a valid package or extra name: \":all:\". Names must start and end with a letter or digit and may only contain -, _, ., and alphanumeric characters. at <REQUIREMENTS_TXT>:1:1");
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(not(windows))]
     async fn nested_editable() -> Result<()> {
@@ -3030,4 +3382,142 @@ mod test {
 
         Ok(())
     }
+
+    /// Nested `-r` and `-c` inclusions are resolved relative to the including file, across three
+    /// levels of nesting.
+    #[tokio::test]
+    async fn nested_include_relative_paths() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements = temp_dir.child("requirements.txt");
+        requirements.write_str(indoc! {"
+            -r envs/dev.txt
+        "})?;
+        temp_dir.child("envs").child("dev.txt").write_str(indoc! {"
+            pytest
+            -r ../common/base.txt
+            -c constraints/dev.txt
+        "})?;
+        temp_dir
+            .child("common")
+            .child("base.txt")
+            .write_str(indoc! {"
+            flask
+            -c ../constraints/base.txt
+        "})?;
+        temp_dir
+            .child("envs")
+            .child("constraints")
+            .child("dev.txt")
+            .write_str(indoc! {"
+                pytest<9
+                -c nested.txt
+            "})?;
+        temp_dir
+            .child("envs")
+            .child("constraints")
+            .child("nested.txt")
+            .write_str(indoc! {"
+                pluggy<2
+                -c ../../constraints/base.txt
+            "})?;
+        temp_dir
+            .child("constraints")
+            .child("base.txt")
+            .write_str(indoc! {"
+                werkzeug<3
+            "})?;
+
+        // Use a working directory that differs from that of any of the files.
+        let working_dir = temp_dir.child("elsewhere");
+        working_dir.create_dir_all()?;
+
+        let parsed = RequirementsTxt::parse(&requirements, working_dir.path()).await?;
+
+        let requirements: Vec<String> = parsed
+            .requirements
+            .iter()
+            .map(|entry| entry.requirement.to_string())
+            .collect();
+        let constraints: Vec<String> = parsed.constraints.iter().map(ToString::to_string).collect();
+
+        assert_debug_snapshot!(requirements, @r#"
+        [
+            "pytest",
+            "flask",
+        ]
+        "#);
+        assert_debug_snapshot!(constraints, @r#"
+        [
+            "werkzeug<3",
+            "pytest<9",
+            "pluggy<2",
+        ]
+        "#);
+
+        Ok(())
+    }
+
+    /// Circular inclusions are rejected, reporting the full chain of inclusions.
+    #[tokio::test]
+    async fn circular_include_chain() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements = temp_dir.child("a.txt");
+        requirements.write_str(indoc! {"
+            pkg-a
+            -r b.txt
+        "})?;
+        temp_dir.child("b.txt").write_str(indoc! {"
+            pkg-b
+            -r nested/c.txt
+        "})?;
+        temp_dir.child("nested").child("c.txt").write_str(indoc! {"
+            pkg-c
+            -r ../a.txt
+        "})?;
+
+        let error = RequirementsTxt::parse(&requirements, temp_dir.path())
+            .await
+            .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        insta::with_settings!({
+            filters => path_filters(&path_filter(temp_dir.path())),
+        }, {
+            insta::assert_snapshot!(errors, @"
+            Error parsing included file in `<REQUIREMENTS_DIR>/a.txt` at position 6
+            Error parsing included file in `<REQUIREMENTS_DIR>/b.txt` at position 6
+            Circular inclusion of requirements file in `<REQUIREMENTS_DIR>/nested/c.txt` at position 6: `<REQUIREMENTS_DIR>/a.txt` -> `<REQUIREMENTS_DIR>/b.txt` -> `<REQUIREMENTS_DIR>/nested/c.txt` -> `<REQUIREMENTS_DIR>/a.txt`
+            ");
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_include_relative_path() {
+        let requirements_txt = Path::new("https://example.com/requirements/dev/dev.txt");
+        let requirements_dir = requirements_txt.parent().unwrap();
+
+        let sub_file =
+            resolve_include("../base.txt", requirements_txt, requirements_dir, 0, 0).unwrap();
+        assert_eq!(
+            sub_file,
+            PathBuf::from("https://example.com/requirements/base.txt")
+        );
+
+        let sub_file = resolve_include(
+            "https://example.org/constraints.txt",
+            requirements_txt,
+            requirements_dir,
+            0,
+            0,
+        )
+        .unwrap();
+        assert_eq!(
+            sub_file,
+            PathBuf::from("https://example.org/constraints.txt")
+        );
+    }
 }
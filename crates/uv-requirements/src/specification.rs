@@ -38,7 +38,8 @@ use url::Url;
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    DependencyGroups, ExcludeDependency, NoBinary, NoBuild, Override, PackageOverride,
+    ConstraintsScope, DependencyGroups, ExcludeDependency, NoBinary, NoBuild, Override,
+    PackageOverride,
 };
 use uv_distribution_types::{Index, Requirement};
 use uv_distribution_types::{
@@ -63,6 +64,9 @@ pub struct RequirementsSpecification {
     pub requirements: Vec<UnresolvedRequirementSpecification>,
     /// The constraints for the project.
     pub constraints: Vec<NameRequirementSpecification>,
+    /// The constraints that apply to the build dependencies of source distributions, as read from
+    /// constraints files with a [`ConstraintsScope`] that includes builds.
+    pub build_constraints: Vec<NameRequirementSpecification>,
     /// The overrides for the project.
     pub overrides: Vec<UnresolvedRequirementSpecification>,
     /// The overrides that have already been lowered to named requirements.
@@ -404,6 +408,32 @@ impl RequirementsSpecification {
         excludes: &[RequirementsSource],
         groups: Option<&GroupsSpecification>,
         client_builder: &BaseClientBuilder<'_>,
+    ) -> Result<Self> {
+        Self::from_sources_with_scope(
+            requirements,
+            constraints,
+            ConstraintsScope::Runtime,
+            overrides,
+            excludes,
+            groups,
+            client_builder,
+        )
+        .await
+    }
+
+    /// Read the combined requirements and constraints from a set of sources, applying the
+    /// constraints from the constraints sources to the given [`ConstraintsScope`].
+    ///
+    /// Constraints that are included from a requirements file (e.g., via `-c constraints.txt`)
+    /// always apply to the runtime resolution.
+    pub async fn from_sources_with_scope(
+        requirements: &[RequirementsSource],
+        constraints: &[RequirementsSource],
+        constraints_scope: ConstraintsScope,
+        overrides: &[RequirementsSource],
+        excludes: &[RequirementsSource],
+        groups: Option<&GroupsSpecification>,
+        client_builder: &BaseClientBuilder<'_>,
     ) -> Result<Self> {
         let mut spec = Self::default();
         let mut cache = SourceCache::default();
@@ -597,12 +627,13 @@ impl RequirementsSpecification {
 
         // Read all constraints, treating both requirements _and_ constraints as constraints.
         // Overrides are ignored.
+        let mut constraint_entries = Vec::new();
         for source in constraints {
             let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
             for entry in source.requirements {
                 match entry.requirement {
                     UnresolvedRequirement::Named(requirement) => {
-                        spec.constraints.push(NameRequirementSpecification {
+                        constraint_entries.push(NameRequirementSpecification {
                             requirement,
                             hashes: entry.hashes,
                         });
@@ -614,7 +645,7 @@ impl RequirementsSpecification {
                     }
                 }
             }
-            spec.constraints.extend(source.constraints);
+            constraint_entries.extend(source.constraints);
 
             if let Some(index_url) = source.index_url {
                 if let Some(existing) = spec.index_url
@@ -633,6 +664,15 @@ impl RequirementsSpecification {
             spec.no_build.extend(source.no_build);
         }
 
+        // Apply the constraints to the requested scope.
+        if constraints_scope.includes_build() {
+            spec.build_constraints
+                .extend(constraint_entries.iter().cloned());
+        }
+        if constraints_scope.includes_runtime() {
+            spec.constraints.extend(constraint_entries);
+        }
+
         // Read all overrides, treating both requirements _and_ overrides as overrides.
         // Constraints are ignored.
         for source in overrides {
@@ -9,9 +9,9 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    DependencySelection, Installable, Lock, LockError, LockVersion, Metadata, Package, PackageMap,
-    PylockToml, PylockTomlError, PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest,
-    SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json,
+    DependencySelection, EnvironmentYmlExport, Installable, Lock, LockError, LockVersion, Metadata,
+    Package, PackageMap, PylockToml, PylockTomlError, PylockTomlErrorKind, RequirementsTxtExport,
+    ResolverManifest, SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
@@ -20,12 +20,13 @@ pub use prerelease::PrereleaseMode;
 pub use pubgrub::PubGrubHint;
 pub use python_requirement::PythonRequirement;
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, LicensePolicyChecker,
+    LicensePolicyError, LicenseViolation, ResolutionExplanation, ResolverOutput,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
     DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
-    Reporter as ResolverReporter, Resolver, ResolverEnvironment, ResolverProvider,
+    PrefetchSummary, Reporter as ResolverReporter, Resolver, ResolverEnvironment, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
@@ -52,6 +52,7 @@ const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
 struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
     package_to_component_map: HashMap<&'a PackageId, Component>,
+    provenance: bool, // Whether to include the provenance recorded in the lockfile
 }
 
 impl<'a> ComponentBuilder<'a> {
@@ -218,6 +219,10 @@ impl<'a> ComponentBuilder<'a> {
             ));
         }
 
+        if self.provenance {
+            Self::push_provenance_properties(package, &mut properties);
+        }
+
         Component {
             component_type: Classification::Library,
             name: NormalizedString::new(name),
@@ -252,11 +257,50 @@ impl<'a> ComponentBuilder<'a> {
         }
     }
 
+    /// Add the provenance recorded in the lockfile for a registry package, i.e., the index it was
+    /// resolved from, along with the size and upload time of each of its artifacts.
+    fn push_provenance_properties(package: &Package, properties: &mut Vec<Property>) {
+        let Source::Registry(registry_source) = &package.id.source else {
+            return;
+        };
+        properties.push(Property::new(
+            "uv:package:index",
+            &registry_source.to_string(),
+        ));
+
+        let sdist = package.sdist.iter().filter_map(|sdist| {
+            Some((
+                sdist.filename()?.to_string(),
+                sdist.size(),
+                sdist.upload_time(),
+            ))
+        });
+        let wheels = package
+            .wheels
+            .iter()
+            .map(|wheel| (wheel.filename.to_string(), wheel.size, wheel.upload_time));
+        for (filename, size, upload_time) in sdist.chain(wheels) {
+            if let Some(size) = size {
+                properties.push(Property::new(
+                    &format!("uv:artifact:{filename}:size"),
+                    &size.to_string(),
+                ));
+            }
+            if let Some(upload_time) = upload_time {
+                properties.push(Property::new(
+                    &format!("uv:artifact:{filename}:upload-time"),
+                    &upload_time.to_string(),
+                ));
+            }
+        }
+    }
+
     fn get_component(&self, id: &PackageId) -> Option<&Component> {
         self.package_to_component_map.get(id)
     }
 }
 
+#[expect(clippy::fn_params_excessive_bools)]
 pub fn from_lock<'lock>(
     target: &impl Installable<'lock>,
     prune: &[PackageName],
@@ -266,6 +310,7 @@ pub fn from_lock<'lock>(
     install_options: &'lock InstallOptions,
     preview: Preview,
     all_packages: bool,
+    provenance: bool,
 ) -> Result<Bom, LockError> {
     if !preview.is_enabled(PreviewFeature::SbomExport) {
         warn_user!(
@@ -298,7 +343,10 @@ pub fn from_lock<'lock>(
     }
     .or_else(|| target.lock().root()); // Fallback to project root
 
-    let mut component_builder = ComponentBuilder::default();
+    let mut component_builder = ComponentBuilder {
+        provenance,
+        ..ComponentBuilder::default()
+    };
 
     let mut metadata = Metadata {
         component: root
@@ -0,0 +1,171 @@
+use std::fmt::{Formatter, Write};
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::lock::Source;
+use crate::lock::export::requirements_txt::{direct_url, git_url};
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::{Installable, LockError};
+
+/// An export of a [`Lock`] that renders in conda `environment.yml` format.
+///
+/// The locked packages are listed in the `pip` section of the environment, alongside a `python`
+/// dependency; no conda packages are resolved. Packages that can't be installed from the `pip`
+/// section (editables, and local path and directory dependencies) are omitted, and can be
+/// retrieved via [`EnvironmentYmlExport::skipped`].
+#[derive(Debug)]
+pub struct EnvironmentYmlExport<'lock> {
+    name: Option<String>,
+    python: Option<String>,
+    pip_options: Vec<String>,
+    nodes: Vec<ExportableRequirement<'lock>>,
+    skipped: Vec<&'lock PackageName>,
+    hashes: bool,
+}
+
+impl<'lock> EnvironmentYmlExport<'lock> {
+    pub fn from_lock(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        dev: &DependencyGroupsWithDefaults,
+        name: Option<String>,
+        python: Option<&Version>,
+        pip_options: Vec<String>,
+        hashes: bool,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file.
+        let ExportableRequirements(nodes) =
+            ExportableRequirements::from_lock(target, prune, extras, dev, false, install_options)?;
+
+        // Omit any packages that can't be installed from the `pip` section.
+        let mut skipped = Vec::new();
+        let mut nodes = nodes
+            .into_iter()
+            .filter(|node| match &node.package.id.source {
+                Source::Registry(_) | Source::Git(..) | Source::Direct(..) => true,
+                Source::Path(_) | Source::Directory(_) | Source::Editable(_) => {
+                    skipped.push(&node.package.id.name);
+                    false
+                }
+                Source::Virtual(_) => false,
+            })
+            .collect::<Vec<_>>();
+        nodes.sort_unstable_by(|a, b| a.package.id.cmp(&b.package.id));
+        skipped.sort_unstable();
+
+        // Pin the interpreter version, if known; otherwise, use the lockfile's Python requirement.
+        let python = if let Some(python) = python {
+            Some(format!("python={python}"))
+        } else {
+            let requires_python = target.lock().requires_python();
+            (!requires_python.is_unbounded()).then(|| format!("python{requires_python}"))
+        };
+
+        Ok(Self {
+            name,
+            python,
+            pip_options,
+            nodes,
+            skipped,
+            hashes,
+        })
+    }
+
+    /// Returns the names of the packages that were omitted from the export.
+    pub fn skipped(&self) -> &[&'lock PackageName] {
+        &self.skipped
+    }
+}
+
+impl std::fmt::Display for EnvironmentYmlExport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            writeln!(f, "name: {}", yaml_scalar(name))?;
+        }
+        writeln!(f, "dependencies:")?;
+        if let Some(python) = &self.python {
+            writeln!(f, "  - {}", yaml_scalar(python))?;
+        }
+        writeln!(f, "  - pip")?;
+
+        if self.pip_options.is_empty() && self.nodes.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "  - pip:")?;
+        for option in &self.pip_options {
+            writeln!(f, "      - {}", yaml_scalar(option))?;
+        }
+        for ExportableRequirement {
+            package, marker, ..
+        } in &self.nodes
+        {
+            let mut requirement = match &package.id.source {
+                Source::Registry(_) => {
+                    let version = package
+                        .id
+                        .version
+                        .as_ref()
+                        .expect("registry package without version");
+                    format!("{}=={}", package.id.name, version)
+                }
+                Source::Git(url, git) => {
+                    format!("{} @ {}", package.id.name, git_url(url, git)?)
+                }
+                Source::Direct(url, direct) => {
+                    format!(
+                        "{} @ {}",
+                        package.id.name,
+                        direct_url(url, direct)?.displayable_with_credentials()
+                    )
+                }
+                Source::Path(_)
+                | Source::Directory(_)
+                | Source::Editable(_)
+                | Source::Virtual(_) => continue,
+            };
+
+            if let Some(contents) = marker.contents() {
+                write!(requirement, " ; {contents}")?;
+            }
+
+            // Each entry is written to a separate line of a requirements file, so hashes must
+            // follow the requirement on the same line.
+            if self.hashes {
+                let mut hashes = package.hashes();
+                hashes.sort_unstable();
+                for hash in hashes.iter() {
+                    write!(requirement, " --hash={hash}")?;
+                }
+            }
+
+            writeln!(f, "      - {}", yaml_scalar(&requirement))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Render a string as a YAML scalar, quoting it if it can't be represented as a plain scalar.
+fn yaml_scalar(value: &str) -> String {
+    let plain = !value.is_empty()
+        && !value.starts_with([
+            '!', '&', '*', '{', '}', '[', ']', '|', '>', '\'', '"', '%', '@', '`', '#', ',', '?',
+            ':', ' ',
+        ])
+        && !value.starts_with("- ")
+        && !value.ends_with([':', ' '])
+        && !value.contains(": ")
+        && !value.contains(" #");
+    if plain {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
@@ -17,6 +17,7 @@ use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::Reachable;
 use crate::lock::LockErrorKind;
+pub use crate::lock::export::environment_yml::EnvironmentYmlExport;
 pub use crate::lock::export::metadata::Metadata;
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlError, PylockTomlErrorKind};
@@ -25,6 +26,7 @@ use crate::universal_marker::resolve_activated_extras;
 use crate::{Installable, LockError, Package};
 
 pub mod cyclonedx_json;
+mod environment_yml;
 mod metadata;
 mod pylock_toml;
 mod requirements_txt;
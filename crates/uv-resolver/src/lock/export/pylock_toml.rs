@@ -1449,6 +1449,7 @@ impl PylockTomlWheel {
             dist_info_metadata: false,
             filename: SmallString::from(filename.to_string()),
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -1607,6 +1608,7 @@ impl PylockTomlSdist {
             dist_info_metadata: false,
             filename,
             hashes: HashDigests::from(self.hashes.clone()),
+            provenance: None,
             requires_python: None,
             size: self.size,
             upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
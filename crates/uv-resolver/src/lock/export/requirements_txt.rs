@@ -9,6 +9,7 @@ use uv_configuration::{
     DependencyGroupsWithDefaults, EditableMode, ExtrasSpecificationWithDefaults, InstallOptions,
 };
 use uv_distribution_filename::{DistExtension, SourceDistExtension};
+use uv_distribution_types::UrlString;
 use uv_fs::Simplified;
 use uv_git_types::GitReference;
 use uv_normalize::PackageName;
@@ -16,7 +17,7 @@ use uv_pypi_types::{ParsedArchiveUrl, ParsedGitDirectoryUrl, ParsedGitPathUrl};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{Package, PackageId, Source};
+use crate::lock::{DirectSource, GitSource, Package, PackageId, Source};
 use crate::{Installable, LockError};
 
 /// An export of a [`Lock`] that renders in `requirements.txt` format.
@@ -80,52 +81,16 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
                     write!(f, "{}=={}", package.id.name, version)?;
                 }
                 Source::Git(url, git) => {
-                    // Remove the fragment and query from the URL; they're already present in the
-                    // `GitSource`.
-                    let mut url = url.to_url().map_err(|_| std::fmt::Error)?;
-                    url.set_fragment(None);
-                    url.set_query(None);
-
-                    // Reconstruct the `GitUrl` from the `GitSource`.
-                    let git_url = uv_git_types::GitUrl::from_commit(
-                        url,
-                        GitReference::from(git.kind.clone()),
-                        git.precise,
-                        git.lfs,
-                    )
-                    .expect("Internal Git URLs must have supported schemes");
-
-                    // Reconstruct the PEP 508-compatible URL from the `GitSource`.
-                    let url = if let Some(install_path) = git.path.as_ref() {
-                        let ext =
-                            DistExtension::from_path(install_path).map_err(|_| std::fmt::Error)?;
-                        DisplaySafeUrl::from(ParsedGitPathUrl {
-                            url: git_url.clone(),
-                            install_path: install_path.clone(),
-                            ext,
-                        })
-                    } else {
-                        DisplaySafeUrl::from(ParsedGitDirectoryUrl {
-                            url: git_url.clone(),
-                            subdirectory: git.subdirectory.clone(),
-                        })
-                    };
-
-                    write!(f, "{} @ {}", package.id.name, url)?;
+                    write!(f, "{} @ {}", package.id.name, git_url(url, git)?)?;
                 }
                 Source::Direct(url, direct) => {
-                    let url = DisplaySafeUrl::from(ParsedArchiveUrl {
-                        url: url.to_url().map_err(|_| std::fmt::Error)?,
-                        subdirectory: direct.subdirectory.clone(),
-                        ext: DistExtension::Source(SourceDistExtension::TarGz),
-                    });
                     write!(
                         f,
                         "{} @ {}",
                         package.id.name,
                         // TODO(zanieb): We should probably omit passwords here by default, but we
                         // should change it in a breaking release and allow opt-in to include them.
-                        url.displayable_with_credentials()
+                        direct_url(url, direct)?.displayable_with_credentials()
                     )?;
                 }
                 Source::Path(path) | Source::Directory(path) => {
@@ -228,3 +193,47 @@ fn anchor(path: &Path) -> Cow<'_, Path> {
         _ => Cow::Owned(PathBuf::from("./").join(path)),
     }
 }
+
+/// Reconstruct the PEP 508-compatible URL for a Git source.
+pub(super) fn git_url(url: &UrlString, git: &GitSource) -> Result<DisplaySafeUrl, std::fmt::Error> {
+    // Remove the fragment and query from the URL; they're already present in the `GitSource`.
+    let mut url = url.to_url().map_err(|_| std::fmt::Error)?;
+    url.set_fragment(None);
+    url.set_query(None);
+
+    // Reconstruct the `GitUrl` from the `GitSource`.
+    let git_url = uv_git_types::GitUrl::from_commit(
+        url,
+        GitReference::from(git.kind.clone()),
+        git.precise,
+        git.lfs,
+    )
+    .expect("Internal Git URLs must have supported schemes");
+
+    // Reconstruct the PEP 508-compatible URL from the `GitSource`.
+    if let Some(install_path) = git.path.as_ref() {
+        let ext = DistExtension::from_path(install_path).map_err(|_| std::fmt::Error)?;
+        Ok(DisplaySafeUrl::from(ParsedGitPathUrl {
+            url: git_url,
+            install_path: install_path.clone(),
+            ext,
+        }))
+    } else {
+        Ok(DisplaySafeUrl::from(ParsedGitDirectoryUrl {
+            url: git_url,
+            subdirectory: git.subdirectory.clone(),
+        }))
+    }
+}
+
+/// Reconstruct the PEP 508-compatible URL for a direct URL source.
+pub(super) fn direct_url(
+    url: &UrlString,
+    direct: &DirectSource,
+) -> Result<DisplaySafeUrl, std::fmt::Error> {
+    Ok(DisplaySafeUrl::from(ParsedArchiveUrl {
+        url: url.to_url().map_err(|_| std::fmt::Error)?,
+        subdirectory: direct.subdirectory.clone(),
+        ext: DistExtension::Source(SourceDistExtension::TarGz),
+    }))
+}
@@ -83,6 +83,7 @@ pub trait Installable<'lock> {
     ) -> Result<Resolution, LockError> {
         let roots = self
             .roots()
+            .filter(|root_name| self.supports_root(root_name, marker_env))
             .map(|root_name| {
                 self.lock()
                     .find_by_name(root_name)
@@ -110,6 +111,22 @@ pub trait Installable<'lock> {
         )
     }
 
+    /// Returns `true` if the given root should be installed into the given marker environment.
+    ///
+    /// When installing multiple workspace members, members whose `requires-python` (as recorded in
+    /// the lockfile) excludes the environment's Python version are skipped. A single, explicitly
+    /// targeted project is always installed.
+    fn supports_root(
+        &self,
+        root_name: &PackageName,
+        marker_env: &ResolverMarkerEnvironment,
+    ) -> bool {
+        self.project_name().is_some()
+            || self
+                .lock()
+                .member_supports_python(root_name, &marker_env.python_full_version().version)
+    }
+
     /// Create an installable [`Node`] from a [`Package`].
     fn installable_node(
         &self,
@@ -118,6 +135,7 @@ pub trait Installable<'lock> {
         marker_env: &ResolverMarkerEnvironment,
         build_options: &BuildOptions,
     ) -> Result<Node, LockError> {
+        package.validate_local_index(self.install_path())?;
         let tag_policy = TagPolicy::Required(tags);
         let HashedDist { dist, hashes } =
             package.to_dist(self.install_path(), tag_policy, build_options, marker_env)?;
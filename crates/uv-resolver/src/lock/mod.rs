@@ -41,7 +41,7 @@ use uv_fs::{
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
 use uv_git_types::{GitLfs, GitOid, GitReference, GitUrl, GitUrlParseError};
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{
     MarkerEnvironment, MarkerTree, Scheme, VerbatimUrl, VerbatimUrlError, split_scheme,
 };
@@ -61,12 +61,15 @@ use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{
-    Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind, cyclonedx_json,
+    EnvironmentYmlExport, Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind,
+    cyclonedx_json,
 };
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
-use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
+use crate::resolution::{
+    AnnotatedDist, LicensePolicyChecker, LicenseViolation, ResolutionGraphNode, sort_violations,
+};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerSpan, ExcludeNewerValue,
@@ -799,6 +802,23 @@ impl Lock {
         &self.manifest.members
     }
 
+    /// Returns the `requires-python` of each workspace member, if the members were resolved in
+    /// separate forks due to disjoint bounds.
+    pub fn members_requires_python(&self) -> &BTreeMap<PackageName, VersionSpecifiers> {
+        &self.manifest.requires_python
+    }
+
+    /// Returns `true` if the given workspace member supports the given Python version.
+    ///
+    /// Members are only excluded if the workspace was locked with disjoint per-member
+    /// `requires-python` bounds.
+    pub fn member_supports_python(&self, name: &PackageName, version: &Version) -> bool {
+        self.manifest
+            .requires_python
+            .get(name)
+            .is_none_or(|specifiers| specifiers.contains(version))
+    }
+
     /// Returns the root requirements that were used to generate this lock.
     fn requirements(&self) -> &BTreeSet<Requirement> {
         &self.manifest.requirements
@@ -1015,7 +1035,7 @@ impl Lock {
         // downstream views (e.g. index lookup) have access to the lockfile
         // package.
         let mut by_name_version: BTreeMap<(&PackageName, &Version), &Package> = BTreeMap::default();
-        self.walk_auditable(extras, groups, collect_filter, |package, version| {
+        let parents = self.walk_auditable(extras, groups, collect_filter, |package, version| {
             by_name_version
                 .entry((package.name(), version))
                 .or_insert(package);
@@ -1024,7 +1044,7 @@ impl Lock {
             .into_iter()
             .map(|((_, version), package)| (package, version))
             .collect();
-        Auditable { packages }
+        Auditable { packages, parents }
     }
 
     /// Walk the auditable dependency graph, invoking `visit` once per
@@ -1036,13 +1056,18 @@ impl Lock {
     /// respecting the provided extras and dependency-group filters. The same
     /// package may be visited more than once if it is reached through multiple
     /// extras — callers should deduplicate as appropriate.
+    ///
+    /// Returns the package through which each package was first reached, or
+    /// `None` for the packages that seeded the traversal. Since the traversal is
+    /// breadth-first, following these links yields a shortest dependency chain.
     fn walk_auditable<'lock, F>(
         &'lock self,
         extras: &'lock ExtrasSpecificationWithDefaults,
         groups: &'lock DependencyGroupsWithDefaults,
         collect_filter: impl Fn(&Package) -> bool,
         mut visit: F,
-    ) where
+    ) -> FxHashMap<&'lock PackageId, Option<&'lock PackageId>>
+    where
         F: FnMut(&'lock Package, &'lock Version),
     {
         // Enqueue a dependency for auditability checks: base package (no extra) first, then each activated extra.
@@ -1050,9 +1075,12 @@ impl Lock {
             lock: &'lock Lock,
             seen: &mut FxHashSet<(&'lock PackageId, Option<&'lock ExtraName>)>,
             queue: &mut VecDeque<(&'lock Package, Option<&'lock ExtraName>)>,
+            parents: &mut FxHashMap<&'lock PackageId, Option<&'lock PackageId>>,
+            parent: &'lock Package,
             dep: &'lock Dependency,
         ) {
             let dep_pkg = lock.find_by_id(&dep.package_id);
+            parents.entry(&dep.package_id).or_insert(Some(&parent.id));
             for maybe_extra in std::iter::once(None).chain(dep.extra.iter().map(Some)) {
                 if seen.insert((&dep.package_id, maybe_extra)) {
                     queue.push_back((dep_pkg, maybe_extra));
@@ -1074,6 +1102,7 @@ impl Lock {
         // Lockfile traversal state: (package, optional extra to activate on that package).
         let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
         let mut seen: FxHashSet<(&PackageId, Option<&ExtraName>)> = FxHashSet::default();
+        let mut parents: FxHashMap<&PackageId, Option<&PackageId>> = FxHashMap::default();
 
         // Seed from workspace members. Always queue with `None` so that we can traverse
         // their dependency groups; only queue extras when prod mode is active.
@@ -1085,6 +1114,7 @@ impl Lock {
             if seen.insert((&package.id, None)) {
                 queue.push_back((package, None));
             }
+            parents.entry(&package.id).or_insert(None);
             if groups.prod() {
                 for extra in extras.extra_names(package.optional_dependencies.keys()) {
                     if seen.insert((&package.id, Some(extra))) {
@@ -1104,6 +1134,7 @@ impl Lock {
                 if seen.insert((&package.id, None)) {
                     queue.push_back((package, None));
                 }
+                parents.entry(&package.id).or_insert(None);
                 for extra in &*requirement.extras {
                     if seen.insert((&package.id, Some(extra))) {
                         queue.push_back((package, Some(extra)));
@@ -1127,6 +1158,7 @@ impl Lock {
                     if seen.insert((&package.id, None)) {
                         queue.push_back((package, None));
                     }
+                    parents.entry(&package.id).or_insert(None);
                     for extra in &*requirement.extras {
                         if seen.insert((&package.id, Some(extra))) {
                             queue.push_back((package, Some(extra)));
@@ -1160,7 +1192,7 @@ impl Lock {
                     .filter(|(group, _)| groups.contains(group))
                     .flat_map(|(_, deps)| deps)
                 {
-                    enqueue_dep(self, &mut seen, &mut queue, dep);
+                    enqueue_dep(self, &mut seen, &mut queue, &mut parents, package, dep);
                 }
             }
 
@@ -1177,9 +1209,11 @@ impl Lock {
             };
 
             for dep in dependencies {
-                enqueue_dep(self, &mut seen, &mut queue, dep);
+                enqueue_dep(self, &mut seen, &mut queue, &mut parents, package, dep);
             }
         }
+
+        parents
     }
 
     /// Return the workspace root used to generate this lock.
@@ -1574,6 +1608,14 @@ impl Lock {
                 }
             }
 
+            if !self.manifest.requires_python.is_empty() {
+                let mut requires_python = Table::new();
+                for (name, specifiers) in &self.manifest.requires_python {
+                    requires_python.insert(name.as_ref(), value(specifiers.to_string()));
+                }
+                manifest_table.insert("requires-python", Item::Table(requires_python));
+            }
+
             if !self.manifest.dependency_metadata.is_empty() {
                 let mut tables = ArrayOfTables::new();
                 for metadata in &self.manifest.dependency_metadata {
@@ -1805,6 +1847,102 @@ impl Lock {
         Ok(SatisfiesResult::Satisfied)
     }
 
+    /// Evaluate the licenses of the packages in the lockfile against a license policy, fetching
+    /// the metadata for each package from the distribution database.
+    ///
+    /// Workspace members, and any packages listed as exceptions, are exempt from the policy.
+    #[instrument(skip_all)]
+    pub async fn license_violations<Context: BuildContext>(
+        &self,
+        checker: &LicensePolicyChecker<'_>,
+        members: &BTreeSet<PackageName>,
+        root: &Path,
+        tags: &Tags,
+        markers: &MarkerEnvironment,
+        build_options: &BuildOptions,
+        hasher: &HashStrategy,
+        index: &InMemoryIndex,
+        database: &DistributionDatabase<'_, Context>,
+    ) -> Result<Vec<LicenseViolation>, LockError> {
+        // Find the shortest chain of packages from a workspace member to each package.
+        let mut parents: FxHashMap<&PackageId, &PackageId> = FxHashMap::default();
+        let mut queue = self
+            .packages
+            .iter()
+            .filter(|package| members.contains(&package.id.name))
+            .map(|package| &package.id)
+            .collect::<VecDeque<_>>();
+        let mut seen = queue.iter().copied().collect::<FxHashSet<_>>();
+        while let Some(id) = queue.pop_front() {
+            let package = self.find_by_id(id);
+            for dependency in package
+                .dependencies
+                .iter()
+                .chain(package.optional_dependencies.values().flatten())
+                .chain(package.dependency_groups.values().flatten())
+            {
+                if seen.insert(&dependency.package_id) {
+                    parents.insert(&dependency.package_id, id);
+                    queue.push_back(&dependency.package_id);
+                }
+            }
+        }
+
+        let mut violations = Vec::new();
+        for package in &self.packages {
+            if members.contains(&package.id.name) || checker.is_exempt(&package.id.name) {
+                continue;
+            }
+            let Some(version) = package.id.version.as_ref() else {
+                continue;
+            };
+
+            let HashedDist { dist, .. } =
+                package.to_dist(root, TagPolicy::Preferred(tags), build_options, markers)?;
+            let id = dist.distribution_id();
+            let license = if let Some(archive) =
+                index
+                    .distributions()
+                    .get(&id)
+                    .as_deref()
+                    .and_then(|response| {
+                        if let MetadataResponse::Found(archive, ..) = response {
+                            Some(archive)
+                        } else {
+                            None
+                        }
+                    }) {
+                archive.metadata.license_expression.clone()
+            } else {
+                let archive = database
+                    .get_or_build_wheel_metadata(&dist, hasher.get(&dist))
+                    .await
+                    .map_err(|err| LockErrorKind::Resolution {
+                        id: package.id.clone(),
+                        err,
+                    })?;
+                let license = archive.metadata.license_expression.clone();
+                index
+                    .distributions()
+                    .done(id, Arc::new(MetadataResponse::Found(archive)));
+                license
+            };
+
+            let mut chain = vec![package.id.name.clone()];
+            let mut next = parents.get(&package.id);
+            while let Some(parent) = next {
+                chain.push(parent.name.clone());
+                next = parents.get(parent);
+            }
+            chain.reverse();
+
+            violations.extend(checker.check(&package.id.name, version, license, chain));
+        }
+
+        sort_violations(&mut violations);
+        Ok(violations)
+    }
+
     /// Check whether the lock matches the project structure, requirements and configuration.
     #[instrument(skip_all)]
     pub async fn satisfies<Context: BuildContext>(
@@ -2196,6 +2334,12 @@ impl Lock {
                 }
             }
 
+            // A direct URL package must record a hash for its artifact, such that any changes to the
+            // remote file are detected at install time.
+            if matches!(&package.id.source, Source::Direct(..)) && package.hashes().is_empty() {
+                return Ok(SatisfiesResult::MissingUrlHash(&package.id.name));
+            }
+
             // If the package is immutable, we don't need to validate it (or its dependencies).
             if package.id.source.is_immutable() {
                 continue;
@@ -2577,6 +2721,8 @@ impl Lock {
 pub struct Auditable<'lock> {
     /// Packages deduplicated by `(name, version)` and sorted by the same key.
     packages: Vec<(&'lock Package, &'lock Version)>,
+    /// The package through which each package was first reached during the walk.
+    parents: FxHashMap<&'lock PackageId, Option<&'lock PackageId>>,
 }
 
 struct SourceTreeRequiresDist {
@@ -2602,6 +2748,26 @@ impl<'lock> Auditable<'lock> {
             .map(|(package, version)| (package.name(), *version))
     }
 
+    /// Return a shortest dependency chain through which the given package is
+    /// reached, starting from a workspace member (or other root requirement)
+    /// and ending with the package itself.
+    pub fn chain(&self, name: &PackageName, version: &Version) -> Vec<&'lock PackageName> {
+        let Some(&(package, _)) = self.packages.iter().find(|(package, package_version)| {
+            package.name() == name && *package_version == version
+        }) else {
+            return Vec::new();
+        };
+
+        let mut chain = vec![&package.id.name];
+        let mut id = &package.id;
+        while let Some(&Some(parent)) = self.parents.get(id) {
+            chain.push(&parent.name);
+            id = parent;
+        }
+        chain.reverse();
+        chain
+    }
+
     /// Return the distinct registry-hosted projects among the auditable
     /// packages, deduplicated by `(name, index URL)`. Non-registry sources
     /// (Git, direct URL, path, editable) are excluded.
@@ -2701,6 +2867,8 @@ pub enum SatisfiesResult<'lock> {
     ),
     /// The lockfile is missing a version.
     MissingVersion(&'lock PackageName),
+    /// The lockfile is missing an artifact hash for a direct URL package.
+    MissingUrlHash(&'lock PackageName),
 }
 
 /// We discard the lockfile if these options match.
@@ -2799,6 +2967,10 @@ pub struct ResolverManifest {
     /// The static metadata provided to the resolver.
     #[serde(default)]
     dependency_metadata: BTreeSet<StaticMetadata>,
+    /// The `requires-python` of each workspace member, if the members declare disjoint bounds and
+    /// were resolved in separate forks.
+    #[serde(default)]
+    requires_python: BTreeMap<PackageName, VersionSpecifiers>,
 }
 
 impl ResolverManifest {
@@ -2826,9 +2998,21 @@ impl ResolverManifest {
                 .map(|(group, requirements)| (group, requirements.into_iter().collect()))
                 .collect(),
             dependency_metadata: dependency_metadata.into_iter().collect(),
+            requires_python: BTreeMap::new(),
         }
     }
 
+    /// Set the `requires-python` of each workspace member, for workspaces whose members declare
+    /// disjoint bounds.
+    #[must_use]
+    pub fn with_requires_python(
+        mut self,
+        requires_python: impl IntoIterator<Item = (PackageName, VersionSpecifiers)>,
+    ) -> Self {
+        self.requires_python = requires_python.into_iter().collect();
+        self
+    }
+
     /// Convert the manifest to a relative form using the given workspace.
     pub fn relative_to(self, root: &Path) -> Result<Self, io::Error> {
         Ok(Self {
@@ -2882,6 +3066,7 @@ impl ResolverManifest {
                 })
                 .collect::<Result<BTreeMap<_, _>, _>>()?,
             dependency_metadata: self.dependency_metadata,
+            requires_python: self.requires_python,
         })
     }
 }
@@ -3185,6 +3370,44 @@ impl Package {
         Ok(())
     }
 
+    /// Validate that the local index for the [`Package`] (e.g., a `find-links` directory) still
+    /// exists, and still contains at least one of the locked distributions.
+    fn validate_local_index(&self, workspace_root: &Path) -> Result<(), LockError> {
+        let Source::Registry(RegistrySource::Path(path)) = &self.id.source else {
+            return Ok(());
+        };
+
+        let directory = absolute_path(workspace_root, path)?;
+        if !directory.is_dir() {
+            return Err(LockErrorKind::MissingLocalIndex {
+                name: self.id.name.clone(),
+                path: directory,
+            }
+            .into());
+        }
+
+        let wheels = self.wheels.iter().filter_map(|wheel| match &wheel.url {
+            WheelWireSource::Path { path } => Some(path),
+            WheelWireSource::Url { .. } | WheelWireSource::Filename { .. } => None,
+        });
+        let sdist = self.sdist.iter().filter_map(|sdist| match sdist {
+            SourceDist::Path { path, .. } => Some(path),
+            SourceDist::Url { .. } | SourceDist::Metadata { .. } => None,
+        });
+        if !wheels
+            .chain(sdist)
+            .any(|path| directory.join(path).is_file())
+        {
+            return Err(LockErrorKind::EmptyLocalIndex {
+                name: self.id.name.clone(),
+                path: directory,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     /// Convert the [`Package`] to a [`Dist`] that can be used in installation, along with its hash.
     fn to_dist(
         &self,
@@ -3574,6 +3797,7 @@ impl Package {
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -3649,6 +3873,7 @@ impl Package {
                     hashes: sdist.hash().map_or(HashDigests::empty(), |hash| {
                         HashDigests::from(hash.0.clone())
                     }),
+                    provenance: None,
                     requires_python: None,
                     size: sdist.size(),
                     upload_time_utc_ms: sdist.upload_time().map(Timestamp::as_millisecond),
@@ -3862,6 +4087,11 @@ impl Package {
         self.id.version.as_ref()
     }
 
+    /// Returns `true` if the package is a direct URL source.
+    pub fn is_direct_url(&self) -> bool {
+        matches!(self.id.source, Source::Direct(..))
+    }
+
     /// Returns the Git SHA of the package, if it is a Git source.
     pub fn git_sha(&self) -> Option<&GitOid> {
         match &self.id.source {
@@ -5484,6 +5714,7 @@ impl Wheel {
                     dist_info_metadata: false,
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -5534,6 +5765,7 @@ impl Wheel {
                     dist_info_metadata: false,
                     filename: SmallString::from(filename.to_string()),
                     hashes: self.hash.iter().map(|h| h.0.clone()).collect(),
+                    provenance: None,
                     requires_python: None,
                     size: self.size,
                     upload_time_utc_ms: self.upload_time.map(Timestamp::as_millisecond),
@@ -6132,12 +6364,26 @@ fn normalize_requirement(
         } => {
             // Round-trip the index to remove anything apart from the URL.
             let index = index
-                .map(|index| index.url.into_url())
-                .map(|mut index| {
-                    index.remove_credentials();
-                    index
+                .map(|index| {
+                    // Resolve relative paths to local indexes (e.g., `find-links` sources) against
+                    // the workspace root, as in the lockfile.
+                    if let IndexUrl::Path(url) = &index.url
+                        && let Some(given) = url.given()
+                        && !url.was_given_absolute()
+                    {
+                        let path = normalize_path(root.join(given));
+                        let url = VerbatimUrl::from_normalized_path(&path)
+                            .map_err(LockErrorKind::RequirementVerbatimUrl)?
+                            .with_given(given);
+                        return Ok::<_, LockError>(IndexMetadata::from(IndexUrl::from(url)));
+                    }
+                    let mut url = index.url.into_url();
+                    url.remove_credentials();
+                    Ok(IndexMetadata::from(IndexUrl::from(VerbatimUrl::from_url(
+                        url,
+                    ))))
                 })
-                .map(|index| IndexMetadata::from(IndexUrl::from(VerbatimUrl::from_url(index))));
+                .transpose()?;
             Ok(Requirement {
                 name: requirement.name,
                 extras: requirement.extras,
@@ -6712,6 +6958,24 @@ enum LockErrorKind {
         /// The kind of the invalid source.
         source_type: &'static str,
     },
+    /// An error that occurs when a distribution is sourced from a local index that no longer
+    /// exists.
+    #[error("Package `{name}` references a local index that does not exist: `{path}`", name = name.cyan(), path = path.user_display())]
+    MissingLocalIndex {
+        /// The name of the distribution.
+        name: PackageName,
+        /// The absolute path to the local index.
+        path: PathBuf,
+    },
+    /// An error that occurs when a distribution is sourced from a local index that no longer
+    /// contains any of its locked files.
+    #[error("Package `{name}` references a local index that does not contain any distributions for `{name}`: `{path}`", name = name.cyan(), path = path.user_display())]
+    EmptyLocalIndex {
+        /// The name of the distribution.
+        name: PackageName,
+        /// The absolute path to the local index.
+        path: PathBuf,
+    },
     /// An error that occurs when a distribution indicates that it is sourced from a remote
     /// registry, but is missing a URL.
     #[error("Found registry distribution `{name}` ({version}) without a valid URL", name = name.cyan(), version = format!("v{version}").cyan())]
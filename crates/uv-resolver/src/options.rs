@@ -12,12 +12,14 @@ pub struct Options {
     pub prerelease_mode: PrereleaseMode,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
+    pub prefetch_limit: Option<usize>,
     pub exclude_newer: ExcludeNewer,
     pub index_strategy: IndexStrategy,
     pub artifact_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub fail_fast: bool,
 }
 
 /// Builder for [`Options`].
@@ -27,12 +29,14 @@ pub struct OptionsBuilder {
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
+    prefetch_limit: Option<usize>,
     exclude_newer: ExcludeNewer,
     index_strategy: IndexStrategy,
     artifact_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    fail_fast: bool,
 }
 
 impl OptionsBuilder {
@@ -69,6 +73,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of versions to prefetch per package, where `0` disables
+    /// prefetching.
+    #[must_use]
+    pub fn prefetch_limit(mut self, prefetch_limit: Option<usize>) -> Self {
+        self.prefetch_limit = prefetch_limit;
+        self
+    }
+
     /// Sets the exclusion date.
     #[must_use]
     pub fn exclude_newer(mut self, exclude_newer: ExcludeNewer) -> Self {
@@ -111,6 +123,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to abort the resolution on the first distribution error, rather than
+    /// continuing to fetch metadata until the resolver reaches the failing distribution.
+    #[must_use]
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -118,12 +138,14 @@ impl OptionsBuilder {
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
+            prefetch_limit: self.prefetch_limit,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
             artifact_environments: self.artifact_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            fail_fast: self.fail_fast,
         }
     }
 }
@@ -12,9 +12,11 @@ use reqwest::StatusCode;
 use rustc_hash::FxHashMap;
 
 use uv_configuration::{IndexStrategy, NoBinary, NoBuild};
+use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
-    IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index, IndexCapabilities,
-    IndexLocations, IndexMetadata, IndexUrl, RequiresPython,
+    HashComparison, IncompatibleDist, IncompatibleSource, IncompatibleWheel, Index,
+    IndexCapabilities, IndexLocations, IndexMetadata, IndexUrl, RequiresPython,
+    SourceDistCompatibility, WheelCompatibility,
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
@@ -804,6 +806,19 @@ impl PubGrubReportFormatter<'_> {
                                 }
                                 _ => {}
                             }
+
+                            // List the reason each artifact was rejected.
+                            if let Some(hint) = self.rejected_artifacts_hint(
+                                name,
+                                set,
+                                index,
+                                selector,
+                                fork_indexes,
+                                env,
+                                tags,
+                            ) {
+                                output_hints.insert(hint);
+                            }
                         }
                     }
                 }
@@ -873,11 +888,14 @@ impl PubGrubReportFormatter<'_> {
                                     index,
                                     fork_indexes,
                                 );
+                                let excluded =
+                                    self.exclude_newer_excluded_count(name, &version_hint_set);
                                 output_hints.insert(PubGrubHint::ExcludeNewer {
                                     package: name.clone(),
                                     source,
                                     exclude_newer,
                                     matching_version,
+                                    excluded,
                                 });
                             }
                         }
@@ -1054,6 +1072,20 @@ impl PubGrubReportFormatter<'_> {
         })
     }
 
+    /// Count the versions of a package in the given range that were available, but excluded by
+    /// `exclude-newer`.
+    fn exclude_newer_excluded_count(&self, name: &PackageName, set: &Range<Version>) -> usize {
+        let Some(available) = self.available_versions.get(name) else {
+            return 0;
+        };
+        let included = self.included_versions.get(name);
+        available
+            .iter()
+            .filter(|version| set.contains(version))
+            .filter(|version| included.is_none_or(|included| !included.contains(*version)))
+            .count()
+    }
+
     /// Generate a [`PubGrubHint`] for a package that doesn't have any wheels matching the current
     /// Python version, ABI, or platform.
     fn tag_hint(
@@ -1152,6 +1184,150 @@ impl PubGrubReportFormatter<'_> {
         }
     }
 
+    /// Generate a hint listing the artifacts of a package that were rejected, along with the
+    /// reason each can't be used.
+    ///
+    /// By default, only the artifacts of the best candidate version are listed, up to a limit; in
+    /// verbose mode, the artifacts of every version in the range are listed.
+    fn rejected_artifacts_hint(
+        &self,
+        name: &PackageName,
+        set: &Range<Version>,
+        index: &InMemoryIndex,
+        selector: &CandidateSelector,
+        fork_indexes: &ForkIndexes,
+        env: &ResolverEnvironment,
+        tags: Option<&Tags>,
+    ) -> Option<PubGrubHint> {
+        /// The maximum number of artifacts to list, unless in verbose mode.
+        const MAX_ARTIFACTS: usize = 10;
+
+        let response = if let Some(url) = fork_indexes.get(name).map(IndexMetadata::url) {
+            index.explicit().get(&(name.clone(), url.clone()))
+        } else {
+            index.implicit().get(name)
+        }?;
+
+        let VersionsResponse::Found(version_maps) = &*response else {
+            return None;
+        };
+
+        let all_versions = tracing::enabled!(tracing::Level::DEBUG);
+        let candidates = if all_versions {
+            // Prefer the first index that provides a given version, as in candidate selection.
+            let mut candidates = BTreeMap::new();
+            for version_map in version_maps {
+                for version in version_map
+                    .versions()
+                    .filter(|version| set.contains(version))
+                {
+                    if let Some(dist) = version_map.get(version) {
+                        candidates.entry(version.clone()).or_insert(dist);
+                    }
+                }
+            }
+            candidates.into_iter().rev().collect::<Vec<_>>()
+        } else {
+            let candidate = selector.select_no_preference(name, set, version_maps, env)?;
+            vec![(candidate.version().clone(), candidate.prioritized()?)]
+        };
+
+        let mut versions = Vec::new();
+        let mut omitted = 0;
+        for (version, dist) in candidates {
+            let mut artifacts = dist
+                .wheels()
+                .filter_map(|(wheel, compatibility)| {
+                    let reason = self.wheel_rejection(&wheel.filename, compatibility, tags)?;
+                    Some((wheel.filename.to_string(), reason))
+                })
+                .chain(dist.source().and_then(|(sdist, compatibility)| {
+                    let reason = source_rejection(compatibility)?;
+                    Some((sdist.file.filename.to_string(), reason))
+                }))
+                .collect::<Vec<_>>();
+            artifacts.sort_unstable();
+
+            if !all_versions && artifacts.len() > MAX_ARTIFACTS {
+                omitted += artifacts.len() - MAX_ARTIFACTS;
+                artifacts.truncate(MAX_ARTIFACTS);
+            }
+            if !artifacts.is_empty() {
+                versions.push((version, artifacts));
+            }
+        }
+
+        if versions.is_empty() {
+            return None;
+        }
+
+        Some(PubGrubHint::RejectedArtifacts {
+            package: name.clone(),
+            versions,
+            omitted,
+        })
+    }
+
+    /// Describe why a wheel can't be used, or `None` if it's compatible.
+    fn wheel_rejection(
+        &self,
+        filename: &WheelFilename,
+        compatibility: &WheelCompatibility,
+        tags: Option<&Tags>,
+    ) -> Option<String> {
+        let incompatibility = match compatibility {
+            WheelCompatibility::Compatible(hash, ..) => return hash_rejection(*hash),
+            WheelCompatibility::Incompatible(incompatibility) => incompatibility,
+        };
+
+        Some(match incompatibility {
+            IncompatibleWheel::Tag(tag) => {
+                let (kind, wheel_tags, best) = match tag {
+                    IncompatibleTag::Invalid => return Some("invalid wheel tags".to_string()),
+                    IncompatibleTag::Python => (
+                        "Python implementation",
+                        filename.python_tags().iter().join("."),
+                        tags.and_then(Tags::python_tag).map(|tag| tag.to_string()),
+                    ),
+                    IncompatibleTag::Abi | IncompatibleTag::FreethreadedAbi => (
+                        "Python ABI",
+                        filename.abi_tags().iter().join("."),
+                        tags.and_then(Tags::abi_tag).map(|tag| tag.to_string()),
+                    ),
+                    IncompatibleTag::AbiPythonVersion => (
+                        "Python version",
+                        filename.abi_tags().iter().join("."),
+                        self.python_requirement
+                            .target()
+                            .abi_tag()
+                            .map(|tag| tag.to_string()),
+                    ),
+                    IncompatibleTag::Platform => (
+                        "platform",
+                        filename.platform_tags().iter().join("."),
+                        tags.and_then(Tags::platform_tag).map(ToString::to_string),
+                    ),
+                };
+                if let Some(best) = best {
+                    format!(
+                        "incompatible {kind} tag `{}` (e.g., expected `{}`)",
+                        wheel_tags.cyan(),
+                        best.cyan()
+                    )
+                } else {
+                    format!("incompatible {kind} tag `{}`", wheel_tags.cyan())
+                }
+            }
+            IncompatibleWheel::NoBinary => "using wheels is disabled".to_string(),
+            IncompatibleWheel::RequiresPython(..)
+            | IncompatibleWheel::Yanked(..)
+            | IncompatibleWheel::ExcludeNewer(..)
+            | IncompatibleWheel::MissingPlatform(..) => {
+                IncompatibleDist::Wheel(incompatibility.clone()).singular_message()
+            }
+        })
+    }
+
     fn index_hints(
         name: &PackageName,
         set: &Range<Version>,
@@ -1577,6 +1753,15 @@ pub enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         tags: BTreeSet<PlatformTag>,
     },
+    /// The distributions of a package were rejected, listed with the reason each artifact (i.e.,
+    /// wheel or source distribution) can't be used.
+    RejectedArtifacts {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        versions: Vec<(Version, Vec<(String, String)>)>,
+        // excluded from `PartialEq` and `Hash`
+        omitted: usize,
+    },
     /// Versions of a package were excluded by `exclude-newer`.
     ExcludeNewer {
         package: PackageName,
@@ -1585,6 +1770,8 @@ pub enum PubGrubHint {
         exclude_newer: Timestamp,
         // excluded from `PartialEq` and `Hash`
         matching_version: Option<ExcludeNewerVersionDetail>,
+        // excluded from `PartialEq` and `Hash`
+        excluded: usize,
     },
     /// The resolution failed for a Python version that is different from the current Python version.
     DisjointPythonVersion {
@@ -1675,6 +1862,9 @@ enum PubGrubHintCore {
     PlatformTags {
         package: PackageName,
     },
+    RejectedArtifacts {
+        package: PackageName,
+    },
     ExcludeNewer {
         package: PackageName,
         source: EffectiveExcludeNewerSource,
@@ -1753,6 +1943,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
             PubGrubHint::LanguageTags { package, .. } => Self::LanguageTags { package },
             PubGrubHint::AbiTags { package, .. } => Self::AbiTags { package },
             PubGrubHint::PlatformTags { package, .. } => Self::PlatformTags { package },
+            PubGrubHint::RejectedArtifacts { package, .. } => Self::RejectedArtifacts { package },
             PubGrubHint::ExcludeNewer {
                 package, source, ..
             } => Self::ExcludeNewer { package, source },
@@ -2054,10 +2245,13 @@ impl std::fmt::Display for PubGrubHint {
             Self::NoBuild { package, option } => {
                 let option = match option {
                     NoBuild::All => "for all packages (i.e., with `--no-build`)".to_string(),
-                    NoBuild::Packages(_) => {
+                    NoBuild::Packages(packages) if packages.contains(package) => {
                         format!("for `{package}` (i.e., with `--no-build-package {package}`)")
                     }
-                    NoBuild::None => unreachable!(),
+                    // Otherwise, the package was excluded by the `allow-build` allowlist.
+                    NoBuild::Packages(_) | NoBuild::None => {
+                        format!("for `{package}` (i.e., it is not included in `allow-build`)")
+                    }
                 };
                 write!(
                     f,
@@ -2167,12 +2361,61 @@ impl std::fmt::Display for PubGrubHint {
                         .join(", "),
                 )
             }
+            Self::RejectedArtifacts {
+                package,
+                versions,
+                omitted,
+            } => {
+                if let [(version, artifacts)] = versions.as_slice() {
+                    write!(
+                        f,
+                        "The following distributions of `{}` ({}) were rejected:",
+                        package.cyan(),
+                        format!("v{version}").cyan(),
+                    )?;
+                    for (filename, reason) in artifacts {
+                        write!(f, "\n    {filename}: {reason}")?;
+                    }
+                } else {
+                    write!(
+                        f,
+                        "The following distributions of `{}` were rejected:",
+                        package.cyan(),
+                    )?;
+                    for (version, artifacts) in versions {
+                        write!(f, "\n    {}:", format!("v{version}").cyan())?;
+                        for (filename, reason) in artifacts {
+                            write!(f, "\n        {filename}: {reason}")?;
+                        }
+                    }
+                }
+                if *omitted > 0 {
+                    write!(
+                        f,
+                        "\n    ...and {omitted} more (use `{}` to show all distributions, for all versions)",
+                        "-v".green(),
+                    )?;
+                }
+                Ok(())
+            }
             Self::ExcludeNewer {
                 package,
                 source,
                 exclude_newer,
                 matching_version,
+                excluded,
             } => {
+                let excluded = match excluded {
+                    0 => String::new(),
+                    1 => format!(
+                        " 1 release of `{}` satisfying the requirement was excluded by the cutoff.",
+                        package.cyan()
+                    ),
+                    excluded => format!(
+                        " {excluded} releases of `{}` satisfying the requirement were excluded by the cutoff.",
+                        package.cyan()
+                    ),
+                };
                 let latest = match matching_version {
                     Some(ExcludeNewerVersionDetail {
                         version,
@@ -2211,7 +2454,7 @@ impl std::fmt::Display for PubGrubHint {
                     EffectiveExcludeNewerSource::Package => write!(
                         f,
                         "`{}` was filtered by `{}` to only include packages uploaded \
-                        before {}.{latest} Consider removing the setting or updating it to a later date.",
+                        before {}.{excluded}{latest} Consider removing the setting or updating it to a later date.",
                         package.cyan(),
                         "exclude-newer-package".green(),
                         exclude_newer.cyan(),
@@ -2219,7 +2462,7 @@ impl std::fmt::Display for PubGrubHint {
                     EffectiveExcludeNewerSource::Global => write!(
                         f,
                         "`{}` was filtered by `{}` to only include packages uploaded \
-                        before {}.{latest} Consider using `{}` to override the cutoff for this package.",
+                        before {}.{excluded}{latest} Consider using `{}` to override the cutoff for this package.",
                         package.cyan(),
                         "exclude-newer".green(),
                         exclude_newer.cyan(),
@@ -2228,7 +2471,7 @@ impl std::fmt::Display for PubGrubHint {
                     EffectiveExcludeNewerSource::Index => write!(
                         f,
                         "`{}` was filtered by the index-specific `{}` setting to only include \
-                        packages uploaded before {}.{latest} Consider updating that index's cutoff, setting \
+                        packages uploaded before {}.{excluded}{latest} Consider updating that index's cutoff, setting \
                         it to `false`, or using `{}` to override the cutoff for this package.",
                         package.cyan(),
                         "exclude-newer".green(),
@@ -2258,6 +2501,29 @@ impl std::fmt::Display for PubGrubHint {
     }
 }
 
+/// Describe why a source distribution can't be used, or `None` if it's compatible.
+fn source_rejection(compatibility: &SourceDistCompatibility) -> Option<String> {
+    match compatibility {
+        SourceDistCompatibility::Compatible(hash) => hash_rejection(*hash),
+        SourceDistCompatibility::Incompatible(IncompatibleSource::NoBuild) => {
+            Some("building from source is disabled".to_string())
+        }
+        SourceDistCompatibility::Incompatible(incompatibility) => {
+            Some(IncompatibleDist::Source(incompatibility.clone()).singular_message())
+        }
+    }
+}
+
+/// Describe why an artifact was rejected by the hash-checking policy, or `None` if its hash
+/// matches.
+fn hash_rejection(hash: HashComparison) -> Option<String> {
+    match hash {
+        HashComparison::Mismatched => Some("hash doesn't match the required hashes".to_string()),
+        HashComparison::Missing => Some("no hash available, but hashes are required".to_string()),
+        HashComparison::Matched => None,
+    }
+}
+
 /// A [`Term`] and [`PubGrubPackage`] combination for display.
 struct PackageTerm<'a> {
     package: &'a PubGrubPackage,
@@ -1,15 +1,19 @@
 use std::collections::BTreeSet;
 
 use owo_colors::OwoColorize;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
-use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
+use uv_distribution_types::{
+    DistributionMetadata, Name, Requirement, SourceAnnotation, SourceAnnotations,
+};
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
+use crate::universal_marker::UniversalMarker;
 use crate::{ResolverEnvironment, ResolverOutput};
 
 /// A [`std::fmt::Display`] implementation for the resolution graph.
@@ -35,6 +39,9 @@ pub struct DisplayResolutionGraph<'a> {
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The requirements from which the displayed packages must be reachable, if the output should
+    /// be limited to a subset of the resolution.
+    roots: Option<&'a [Requirement]>,
 }
 
 #[derive(Debug)]
@@ -76,9 +83,20 @@ impl<'a> DisplayResolutionGraph<'a> {
             show_hashes,
             include_extras,
             include_markers,
-            include_annotations,
+            include_annotations: include_annotations && annotation_style != AnnotationStyle::None,
             include_index_annotation,
             annotation_style,
+            roots: None,
+        }
+    }
+
+    /// Limit the output to the packages reachable from the given requirements (e.g., the
+    /// dependencies of a single extra), rather than the entire resolution.
+    #[must_use]
+    pub fn with_roots(self, roots: &'a [Requirement]) -> Self {
+        Self {
+            roots: Some(roots),
+            ..self
         }
     }
 }
@@ -90,9 +108,18 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         let sources = if self.include_annotations {
             let mut sources = SourceAnnotations::default();
 
-            for requirement in self.resolution.requirements.iter().filter(|requirement| {
-                requirement.evaluate_markers(self.env.marker_environment(), &[])
-            }) {
+            for requirement in self
+                .resolution
+                .requirements
+                .iter()
+                .filter(|requirement| {
+                    requirement.evaluate_markers(self.env.marker_environment(), &[])
+                })
+                .filter(|requirement| {
+                    self.roots
+                        .is_none_or(|roots| roots.iter().any(|root| root.name == requirement.name))
+                })
+            {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
@@ -170,17 +197,31 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         // We assign each package its propagated markers: In `requirements.txt`, we want a flat list
         // that for each package tells us if it should be installed on the current platform, without
         // looking at which packages depend on it.
-        let graph = self.resolution.graph.map(
-            |_index, node| match node {
-                ResolutionGraphNode::Root => DisplayResolutionGraphNode::Root,
-                ResolutionGraphNode::Dist(dist) => {
-                    let dist = RequirementsTxtDist::from_annotated_dist(dist);
-                    DisplayResolutionGraphNode::Dist(dist)
+        //
+        // If the output is limited to a set of roots, we also drop any packages that aren't
+        // reachable from them.
+        let reachable = self
+            .roots
+            .map(|roots| reachable_nodes(&self.resolution.graph, roots));
+        let graph = self.resolution.graph.filter_map(
+            |index, node| {
+                if reachable
+                    .as_ref()
+                    .is_some_and(|reachable| !reachable.contains(&index))
+                {
+                    return None;
+                }
+                match node {
+                    ResolutionGraphNode::Root => Some(DisplayResolutionGraphNode::Root),
+                    ResolutionGraphNode::Dist(dist) => {
+                        let dist = RequirementsTxtDist::from_annotated_dist(dist);
+                        Some(DisplayResolutionGraphNode::Dist(dist))
+                    }
                 }
             },
             // We can drop the edge markers, while retaining their existence and direction for the
             // annotations.
-            |_index, _edge| (),
+            |_index, _edge| Some(()),
         );
 
         // Reduce the graph, removing or combining extras for a given package.
@@ -296,6 +337,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                             annotation = Some((separator, comment));
                         }
                     },
+                    AnnotationStyle::None => {}
                 }
             }
 
@@ -336,6 +378,44 @@ pub enum AnnotationStyle {
     /// Render each annotation on its own line.
     #[default]
     Split,
+    /// Omit the annotations, as with `--no-annotate`.
+    None,
+}
+
+/// Return the nodes that are reachable from the given root requirements.
+///
+/// A requirement on `foo[bar]` reaches both `foo` and `foo[bar]`, but a requirement on `foo`
+/// doesn't reach `foo[bar]`.
+fn reachable_nodes(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker, Directed>,
+    roots: &[Requirement],
+) -> FxHashSet<NodeIndex> {
+    let mut queue = graph
+        .node_indices()
+        .filter(|index| match &graph[*index] {
+            ResolutionGraphNode::Root => false,
+            ResolutionGraphNode::Dist(dist) => {
+                dist.group.is_none()
+                    && roots.iter().any(|root| {
+                        root.name == dist.name
+                            && dist
+                                .extra
+                                .as_ref()
+                                .is_none_or(|extra| root.extras.contains(extra))
+                    })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut reachable = queue.iter().copied().collect::<FxHashSet<_>>();
+    while let Some(index) = queue.pop() {
+        for neighbor in graph.neighbors_directed(index, Direction::Outgoing) {
+            if reachable.insert(neighbor) {
+                queue.push(neighbor);
+            }
+        }
+    }
+    reachable
 }
 
 /// We don't need the edge markers anymore since we switched to propagated markers.
@@ -0,0 +1,223 @@
+use std::collections::Bound;
+use std::fmt::{Display, Formatter};
+
+use owo_colors::OwoColorize;
+use petgraph::Direction;
+use pubgrub::Ranges;
+
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::ResolverOutput;
+use crate::resolution::ResolutionGraphNode;
+
+/// An explanation of the version that was selected for a package, in terms of the requirements
+/// that constrained it.
+#[derive(Debug, Clone)]
+pub struct ResolutionExplanation {
+    name: PackageName,
+    version: Version,
+    sources: Vec<ConstraintSource>,
+}
+
+/// A requirement that constrained the version of a package.
+#[derive(Debug, Clone)]
+struct ConstraintSource {
+    origin: ConstraintOrigin,
+    specifiers: VersionSpecifiers,
+    /// Whether the requirement provides the tightest lower bound on the version.
+    lower: bool,
+    /// Whether the requirement provides the tightest upper bound on the version.
+    upper: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConstraintOrigin {
+    /// A direct requirement, e.g., from the command line or a `requirements.txt` file.
+    Requirement,
+    /// A constraint, e.g., from `--constraint`.
+    Constraint,
+    /// An override, e.g., from `--override`.
+    Override,
+    /// A dependency of another package in the resolution.
+    Dependency(PackageName, Version),
+}
+
+impl ResolverOutput {
+    /// Explain the version that was selected for the given package, if it's part of the
+    /// resolution.
+    pub fn explain(&self, name: &PackageName) -> Option<ResolutionExplanation> {
+        let version = self
+            .base_dists()
+            .find(|(_, dist)| dist.name == *name)
+            .map(|(_, dist)| dist.version.clone())?;
+
+        let mut sources = Vec::new();
+
+        // If the package is overridden, the overrides replace all other requirements.
+        let overrides = self
+            .overrides
+            .global_requirements()
+            .filter(|requirement| requirement.name == *name)
+            .filter_map(specifiers)
+            .collect::<Vec<_>>();
+        if overrides.is_empty() {
+            for specifiers in self
+                .requirements
+                .iter()
+                .filter(|requirement| requirement.name == *name)
+                .filter_map(specifiers)
+            {
+                sources.push((ConstraintOrigin::Requirement, specifiers.clone()));
+            }
+
+            // Collect the requirements of every package that depends on the target.
+            for index in self.graph.node_indices() {
+                let ResolutionGraphNode::Dist(dist) = &self.graph[index] else {
+                    continue;
+                };
+                if dist.name != *name {
+                    continue;
+                }
+                for parent in self.graph.neighbors_directed(index, Direction::Incoming) {
+                    let ResolutionGraphNode::Dist(parent) = &self.graph[parent] else {
+                        continue;
+                    };
+                    let Some(metadata) = &parent.metadata else {
+                        continue;
+                    };
+                    for specifiers in metadata
+                        .requires_dist
+                        .iter()
+                        .filter(|requirement| requirement.name == *name)
+                        .filter_map(specifiers)
+                    {
+                        let origin = ConstraintOrigin::Dependency(
+                            parent.name.clone(),
+                            parent.version.clone(),
+                        );
+                        if !sources.contains(&(origin.clone(), specifiers.clone())) {
+                            sources.push((origin, specifiers.clone()));
+                        }
+                    }
+                }
+            }
+
+            for specifiers in self
+                .constraints
+                .get(name)
+                .into_iter()
+                .flatten()
+                .filter_map(specifiers)
+            {
+                sources.push((ConstraintOrigin::Constraint, specifiers.clone()));
+            }
+        } else {
+            for specifiers in overrides {
+                sources.push((ConstraintOrigin::Override, specifiers.clone()));
+            }
+        }
+
+        // Identify the requirements that provide the tightest bounds.
+        let bounds = sources
+            .iter()
+            .map(|(_, specifiers)| {
+                Ranges::from(specifiers.clone())
+                    .bounding_range()
+                    .map(|(lower, upper)| (lower.cloned(), upper.cloned()))
+                    .unwrap_or((Bound::Unbounded, Bound::Unbounded))
+            })
+            .collect::<Vec<_>>();
+        let lower = bounds
+            .iter()
+            .filter_map(|(lower, _)| lower_key(lower))
+            .max();
+        let upper = bounds
+            .iter()
+            .filter_map(|(_, upper)| upper_key(upper))
+            .min();
+
+        let sources = sources
+            .into_iter()
+            .zip(&bounds)
+            .map(
+                |((origin, specifiers), (lower_bound, upper_bound))| ConstraintSource {
+                    origin,
+                    specifiers,
+                    lower: lower.is_some() && lower_key(lower_bound) == lower,
+                    upper: upper.is_some() && upper_key(upper_bound) == upper,
+                },
+            )
+            .collect();
+
+        Some(ResolutionExplanation {
+            name: name.clone(),
+            version,
+            sources,
+        })
+    }
+}
+
+/// Return the version specifiers for a registry requirement.
+fn specifiers(requirement: &Requirement) -> Option<&VersionSpecifiers> {
+    match &requirement.source {
+        RequirementSource::Registry { specifier, .. } => Some(specifier),
+        _ => None,
+    }
+}
+
+/// Return a key for a lower bound, such that tighter bounds compare greater.
+fn lower_key(bound: &Bound<Version>) -> Option<(&Version, bool)> {
+    match bound {
+        Bound::Included(version) => Some((version, false)),
+        Bound::Excluded(version) => Some((version, true)),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Return a key for an upper bound, such that tighter bounds compare lesser.
+fn upper_key(bound: &Bound<Version>) -> Option<(&Version, bool)> {
+    match bound {
+        Bound::Excluded(version) => Some((version, false)),
+        Bound::Included(version) => Some((version, true)),
+        Bound::Unbounded => None,
+    }
+}
+
+impl Display for ResolutionExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} was selected",
+            format!("{}=={}", self.name, self.version).bold()
+        )?;
+        let constrained = self
+            .sources
+            .iter()
+            .filter(|source| !source.specifiers.is_empty())
+            .collect::<Vec<_>>();
+        if constrained.is_empty() {
+            return write!(f, ", without any version constraints");
+        }
+        write!(f, ", subject to:")?;
+        for source in constrained {
+            write!(f, "\n  {}{}", self.name, source.specifiers)?;
+            match &source.origin {
+                ConstraintOrigin::Requirement => write!(f, " (requested)")?,
+                ConstraintOrigin::Constraint => write!(f, " (constraint)")?,
+                ConstraintOrigin::Override => write!(f, " (override)")?,
+                ConstraintOrigin::Dependency(name, version) => {
+                    write!(f, " (required by {name}=={version})")?;
+                }
+            }
+            match (source.lower, source.upper) {
+                (true, true) => write!(f, " {}", "[lower and upper bound]".green())?,
+                (true, false) => write!(f, " {}", "[lower bound]".green())?,
+                (false, true) => write!(f, " {}", "[upper bound]".green())?,
+                (false, false) => {}
+            }
+        }
+        Ok(())
+    }
+}
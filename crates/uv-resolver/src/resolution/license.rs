@@ -0,0 +1,256 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+
+use petgraph::Direction;
+use petgraph::graph::NodeIndex;
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_workspace::pyproject::{LicensePolicy, LicensePolicyAction};
+
+use crate::ResolverOutput;
+use crate::resolution::ResolutionGraphNode;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LicensePolicyError {
+    #[error("Invalid SPDX license expression in `tool.uv.license-policy`: `{0}`")]
+    InvalidExpression(String, #[source] spdx::error::ParseError),
+}
+
+/// A package in the resolution that violates the [`LicensePolicy`].
+#[derive(Debug, Clone)]
+pub struct LicenseViolation {
+    name: PackageName,
+    version: Version,
+    license: Option<String>,
+    kind: LicenseViolationKind,
+    action: LicensePolicyAction,
+    /// The chain of packages that led to the violating package, starting from a workspace
+    /// member or root requirement and ending with the package itself.
+    chain: Vec<PackageName>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LicenseViolationKind {
+    /// The package doesn't declare a license expression.
+    Missing,
+    /// The package declares a license expression that isn't valid SPDX.
+    Invalid,
+    /// The license isn't satisfied by the `allow` list.
+    NotAllowed,
+    /// The license can't be satisfied without a license in the `deny` list.
+    Denied,
+    /// The license can't be satisfied without a license in the `warn` list.
+    Warned,
+}
+
+impl LicenseViolation {
+    /// Returns the action to take for the violation.
+    pub fn action(&self) -> LicensePolicyAction {
+        self.action
+    }
+}
+
+/// A [`LicensePolicy`] with each list parsed into SPDX license requirements.
+#[derive(Debug)]
+pub struct LicensePolicyChecker<'policy> {
+    policy: &'policy LicensePolicy,
+    allow: Vec<spdx::LicenseReq>,
+    deny: Vec<spdx::LicenseReq>,
+    warn: Vec<spdx::LicenseReq>,
+}
+
+impl<'policy> LicensePolicyChecker<'policy> {
+    /// Parse the SPDX license expressions in the given [`LicensePolicy`].
+    pub fn new(policy: &'policy LicensePolicy) -> Result<Self, LicensePolicyError> {
+        Ok(Self {
+            policy,
+            allow: parse_requirements(&policy.allow)?,
+            deny: parse_requirements(&policy.deny)?,
+            warn: parse_requirements(&policy.warn)?,
+        })
+    }
+
+    /// Returns `true` if the package is exempt from the policy.
+    pub(crate) fn is_exempt(&self, name: &PackageName) -> bool {
+        self.policy.exceptions.contains(name)
+    }
+
+    /// Evaluate the license of a package against the policy, returning `None` if it complies.
+    pub(crate) fn check(
+        &self,
+        name: &PackageName,
+        version: &Version,
+        license: Option<String>,
+        chain: Vec<PackageName>,
+    ) -> Option<LicenseViolation> {
+        let (kind, action) = match license.as_deref().map(spdx::Expression::parse) {
+            None => (LicenseViolationKind::Missing, self.policy.unknown),
+            Some(Err(_)) => (LicenseViolationKind::Invalid, self.policy.unknown),
+            Some(Ok(expression)) => match self.classify(&expression)? {
+                kind @ LicenseViolationKind::Warned => (kind, LicensePolicyAction::Warn),
+                kind => (kind, LicensePolicyAction::Deny),
+            },
+        };
+        Some(LicenseViolation {
+            name: name.clone(),
+            version: version.clone(),
+            license,
+            kind,
+            action,
+            chain,
+        })
+    }
+
+    /// Classify a license expression against the policy, returning `None` if it complies.
+    fn classify(&self, expression: &spdx::Expression) -> Option<LicenseViolationKind> {
+        if !expression.evaluate(|req| !self.deny.contains(req)) {
+            return Some(LicenseViolationKind::Denied);
+        }
+        if !self.allow.is_empty()
+            && !expression.evaluate(|req| self.allow.contains(req) || self.warn.contains(req))
+        {
+            return Some(LicenseViolationKind::NotAllowed);
+        }
+        if !expression.evaluate(|req| !self.warn.contains(req)) {
+            return Some(LicenseViolationKind::Warned);
+        }
+        None
+    }
+}
+
+/// Parse a list of SPDX license expressions into the license requirements they reference.
+fn parse_requirements(expressions: &[String]) -> Result<Vec<spdx::LicenseReq>, LicensePolicyError> {
+    let mut requirements = Vec::new();
+    for expression in expressions {
+        let parsed = spdx::Expression::parse(expression)
+            .map_err(|err| LicensePolicyError::InvalidExpression(expression.clone(), err))?;
+        requirements.extend(parsed.requirements().map(|req| req.req.clone()));
+    }
+    Ok(requirements)
+}
+
+/// Sort the violations by package name and version.
+pub(crate) fn sort_violations(violations: &mut [LicenseViolation]) {
+    violations.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version)));
+}
+
+impl ResolverOutput {
+    /// Evaluate the licenses of the packages in the resolution against the given
+    /// [`LicensePolicy`].
+    ///
+    /// Workspace members, and any packages listed as exceptions, are exempt from the policy.
+    pub fn license_violations(
+        &self,
+        checker: &LicensePolicyChecker,
+        members: &BTreeSet<PackageName>,
+    ) -> Vec<LicenseViolation> {
+        let mut violations = Vec::new();
+        let mut seen = BTreeSet::new();
+        for (index, dist) in self.base_dists() {
+            if members.contains(&dist.name) || checker.is_exempt(&dist.name) {
+                continue;
+            }
+            if !seen.insert((dist.name.clone(), dist.version.clone())) {
+                continue;
+            }
+
+            let license = dist
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.license_expression.clone());
+            violations.extend(checker.check(
+                &dist.name,
+                &dist.version,
+                license,
+                self.requirement_chain(index),
+            ));
+        }
+
+        sort_violations(&mut violations);
+        violations
+    }
+
+    /// Find the shortest chain of packages from the root of the graph to the given node.
+    fn requirement_chain(&self, target: NodeIndex) -> Vec<PackageName> {
+        let mut parents = FxHashMap::default();
+        let mut queue = VecDeque::from([target]);
+        let mut start = None;
+        while let Some(index) = queue.pop_front() {
+            if matches!(self.graph[index], ResolutionGraphNode::Root) {
+                start = Some(index);
+                break;
+            }
+            for parent in self.graph.neighbors_directed(index, Direction::Incoming) {
+                if parent != target && !parents.contains_key(&parent) {
+                    parents.insert(parent, index);
+                    queue.push_back(parent);
+                }
+            }
+        }
+
+        // Walk back down from the root to the target, collapsing extras and groups into their
+        // base package.
+        let mut chain: Vec<PackageName> = Vec::new();
+        let mut next = start.and_then(|start| parents.get(&start).copied());
+        while let Some(index) = next {
+            if let ResolutionGraphNode::Dist(dist) = &self.graph[index]
+                && chain.last() != Some(&dist.name)
+            {
+                chain.push(dist.name.clone());
+            }
+            next = parents.get(&index).copied();
+        }
+        if chain.is_empty()
+            && let ResolutionGraphNode::Dist(dist) = &self.graph[target]
+        {
+            chain.push(dist.name.clone());
+        }
+        chain
+    }
+}
+
+impl Display for LicenseViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let package = format!("{}=={}", self.name, self.version);
+        match (self.kind, self.license.as_deref()) {
+            (LicenseViolationKind::Missing, _) | (_, None) => {
+                write!(f, "`{package}` does not declare a license expression")?;
+            }
+            (LicenseViolationKind::Invalid, Some(license)) => {
+                write!(
+                    f,
+                    "`{package}` declares an invalid license expression: `{license}`"
+                )?;
+            }
+            (LicenseViolationKind::NotAllowed, Some(license)) => {
+                write!(
+                    f,
+                    "`{package}` is licensed under `{license}`, which is not allowed by the license policy"
+                )?;
+            }
+            (LicenseViolationKind::Denied, Some(license)) => {
+                write!(
+                    f,
+                    "`{package}` is licensed under `{license}`, which is denied by the license policy"
+                )?;
+            }
+            (LicenseViolationKind::Warned, Some(license)) => {
+                write!(f, "`{package}` is licensed under `{license}`")?;
+            }
+        }
+        if self.chain.len() > 1 {
+            write!(
+                f,
+                " (required by: {})",
+                self.chain
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -10,12 +10,17 @@ use uv_pep440::Version;
 use uv_pypi_types::HashDigests;
 
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
+pub use crate::resolution::explain::ResolutionExplanation;
+pub(crate) use crate::resolution::license::sort_violations;
+pub use crate::resolution::license::{LicensePolicyChecker, LicensePolicyError, LicenseViolation};
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
 pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 use crate::universal_marker::UniversalMarker;
 
 mod display;
+mod explain;
+mod license;
 mod output;
 mod requirements_txt;
 
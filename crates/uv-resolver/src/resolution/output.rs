@@ -28,7 +28,7 @@ use crate::preferences::Preferences;
 use crate::redirect::url_to_precise;
 use crate::resolution::AnnotatedDist;
 use crate::resolution_mode::ResolutionStrategy;
-use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
+use crate::resolver::{PrefetchSummary, Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     InMemoryIndex, MetadataResponse, Options, PythonRequirement, ResolveError, VersionsResponse,
@@ -57,6 +57,8 @@ pub struct ResolverOutput {
     pub(crate) overrides: Overrides,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// The metadata prefetches that were issued while building the graph.
+    pub(crate) prefetch: PrefetchSummary,
 }
 
 #[derive(Debug, Clone)]
@@ -238,6 +240,7 @@ impl ResolverOutput {
             overrides: overrides.clone(),
             options,
             fork_markers,
+            prefetch: PrefetchSummary::default(),
         };
 
         // We only do conflicting distribution detection when no
@@ -628,6 +631,18 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Return the [`PrefetchSummary`] for the resolution.
+    pub fn prefetch(&self) -> PrefetchSummary {
+        self.prefetch
+    }
+
+    /// Set the [`PrefetchSummary`] for the resolution.
+    #[must_use]
+    pub(crate) fn with_prefetch(mut self, prefetch: PrefetchSummary) -> Self {
+        self.prefetch = prefetch;
+        self
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
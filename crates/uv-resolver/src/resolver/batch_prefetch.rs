@@ -1,5 +1,6 @@
 use std::cmp::min;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use itertools::Itertools;
 use pubgrub::{Range, Ranges, Term};
@@ -13,11 +14,60 @@ use crate::resolver::Request;
 use crate::{
     InMemoryIndex, PythonRequirement, ResolveError, ResolverEnvironment, VersionsResponse,
 };
-use uv_distribution_types::{CompatibleDist, Identifier, IndexCapabilities, IndexMetadata};
+use uv_distribution_types::{
+    CompatibleDist, DistributionId, Identifier, IndexCapabilities, IndexMetadata,
+};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 
+/// The maximum number of versions to prefetch for a package at once, unless configured otherwise.
+pub(crate) const DEFAULT_PREFETCH_LIMIT: usize = 50;
+
+/// Tracks how many of the speculatively fetched distributions the resolver ended up using.
+#[derive(Debug, Default)]
+pub(crate) struct PrefetchStats {
+    /// The distributions that were prefetched, but not (yet) requested by the resolver.
+    pending: Mutex<FxHashSet<DistributionId>>,
+    /// The number of distributions that were prefetched.
+    prefetched: AtomicUsize,
+    /// The number of prefetched distributions that the resolver requested.
+    used: AtomicUsize,
+}
+
+impl PrefetchStats {
+    /// Record that the metadata for a distribution was requested speculatively.
+    pub(crate) fn prefetched(&self, id: DistributionId) {
+        self.prefetched.fetch_add(1, Ordering::Relaxed);
+        self.pending.lock().unwrap().insert(id);
+    }
+
+    /// Record that the resolver requested the metadata for a distribution that was already
+    /// registered, counting it as used if it was prefetched.
+    pub(crate) fn requested(&self, id: &DistributionId) {
+        if self.pending.lock().unwrap().remove(id) {
+            self.used.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Summarize how many prefetches were used.
+    pub(crate) fn summary(&self) -> PrefetchSummary {
+        let prefetched = self.prefetched.load(Ordering::Relaxed);
+        let used = self.used.load(Ordering::Relaxed);
+        debug!("Prefetched metadata for {prefetched} distributions, {used} of which were used");
+        PrefetchSummary { prefetched, used }
+    }
+}
+
+/// The number of distributions whose metadata was fetched speculatively during a resolution.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchSummary {
+    /// The number of distributions that were prefetched.
+    pub prefetched: usize,
+    /// The number of prefetched distributions that the resolver requested.
+    pub used: usize,
+}
+
 enum BatchPrefetchStrategy {
     /// Go through the next versions assuming the existing selection and its constraints
     /// remain.
@@ -45,6 +95,8 @@ pub(crate) struct BatchPrefetcher {
     // Types to determine whether we need to prefetch.
     tried_versions: FxHashMap<PackageName, FxHashSet<Version>>,
     last_prefetch: FxHashMap<PackageName, usize>,
+    /// The maximum number of versions to prefetch at once.
+    limit: usize,
     // Types to execute the prefetch.
     prefetch_runner: BatchPrefetcherRunner,
 }
@@ -58,6 +110,7 @@ pub(crate) struct BatchPrefetcherRunner {
     capabilities: IndexCapabilities,
     index: InMemoryIndex,
     request_sink: Sender<Request>,
+    stats: Arc<PrefetchStats>,
 }
 
 impl BatchPrefetcher {
@@ -65,14 +118,18 @@ impl BatchPrefetcher {
         capabilities: IndexCapabilities,
         index: InMemoryIndex,
         request_sink: Sender<Request>,
+        limit: usize,
+        stats: Arc<PrefetchStats>,
     ) -> Self {
         Self {
             tried_versions: FxHashMap::default(),
             last_prefetch: FxHashMap::default(),
+            limit,
             prefetch_runner: BatchPrefetcherRunner {
                 capabilities,
                 index,
                 request_sink,
+                stats,
             },
         }
     }
@@ -103,7 +160,10 @@ impl BatchPrefetcher {
         if !do_prefetch {
             return Ok(());
         }
-        let total_prefetch = min(num_tried, 50);
+        let total_prefetch = min(num_tried, self.limit);
+        if total_prefetch == 0 {
+            return Ok(());
+        }
 
         // This is immediate, we already fetched the version map.
         let versions_response = if let Some(index) = index {
@@ -160,8 +220,9 @@ impl BatchPrefetcher {
     }
 
     /// After 5, 10, 20, 40 tried versions, prefetch that many versions to start early but not
-    /// too aggressive. Later we schedule the prefetch of 50 versions every 20 versions, this gives
-    /// us a good buffer until we see prefetch again and is high enough to saturate the task pool.
+    /// too aggressive. Later we schedule the prefetch of 50 versions (by default) every 20
+    /// versions, this gives us a good buffer until we see prefetch again and is high enough to
+    /// saturate the task pool.
     fn should_prefetch(&self, next: &PubGrubPackage) -> (usize, bool) {
         let PubGrubPackageInner::Package {
             name,
@@ -314,6 +375,7 @@ impl BatchPrefetcherRunner {
             prefetch_count += 1;
 
             if self.index.distributions().register(dist.distribution_id()) {
+                self.stats.prefetched(dist.distribution_id());
                 let request = Request::from(dist);
                 self.request_sink.blocking_send(request)?;
             }
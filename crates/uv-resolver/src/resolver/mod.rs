@@ -25,8 +25,8 @@ use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_distribution_types::{
     BuiltDist, CompatibleDist, DerivationChain, Dist, DistErrorKind, Identifier, IncompatibleDist,
     IncompatibleSource, IncompatibleWheel, IndexCapabilities, IndexLocations, IndexMetadata,
-    IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource, Requirement, ResolvedDist,
-    ResolvedDistRef, SourceDist, VersionOrUrlRef, implied_markers,
+    IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource, Requirement,
+    RequirementSource, ResolvedDist, ResolvedDistRef, SourceDist, VersionOrUrlRef, implied_markers,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -61,7 +61,8 @@ pub(crate) use crate::resolver::availability::{
     ResolverVersion, UnavailableErrorChain, UnavailablePackage, UnavailableReason,
     UnavailableVersion,
 };
-use crate::resolver::batch_prefetch::BatchPrefetcher;
+pub use crate::resolver::batch_prefetch::PrefetchSummary;
+use crate::resolver::batch_prefetch::{BatchPrefetcher, DEFAULT_PREFETCH_LIMIT, PrefetchStats};
 use crate::resolver::derivation::DerivationChainBuilder;
 pub use crate::resolver::environment::ResolverEnvironment;
 use crate::resolver::environment::{
@@ -136,6 +137,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     incomplete_packages: Box<HashMap<PackageName, HashMap<Version, MetadataUnavailable>>>,
     /// The options that were used to configure this resolver.
     options: Options,
+    /// How many of the prefetched distributions were used.
+    prefetch_stats: Arc<PrefetchStats>,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -255,6 +258,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             unavailable_packages: Box::default(),
             incomplete_packages: Box::default(),
             options,
+            prefetch_stats: Arc::default(),
             reporter: None,
         };
         Self { state, provider }
@@ -305,8 +309,9 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         // Wait for both to complete.
         let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
 
+        let prefetch = state.prefetch_stats.summary();
         state.on_complete();
-        resolution
+        resolution.map(|resolution| resolution.with_prefetch(prefetch))
     }
 }
 
@@ -336,6 +341,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.capabilities.clone(),
             self.index.clone(),
             request_sink.clone(),
+            self.prefetch_limit(),
+            self.prefetch_stats.clone(),
         );
         let state = ForkState::new(
             pubgrub,
@@ -345,6 +352,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         );
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
+
+        // Fetch the metadata for the root requirements upfront, rather than in the order in
+        // which the solver discovers them.
+        if self.dependency_mode.is_transitive() && self.prefetch_limit() > 0 {
+            self.prefetch_requirements(request_sink)?;
+        }
         let mut resolutions = vec![];
 
         'FORK: while let Some(mut state) = forked_states.pop() {
@@ -385,7 +398,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         }
 
                         // Pre-visit all candidate packages, to allow metadata to be fetched in parallel.
-                        if self.dependency_mode.is_transitive() {
+                        if self.dependency_mode.is_transitive() && self.prefetch_limit() > 0 {
                             Self::pre_visit(
                                 state.pubgrub.partial_solution.prioritized_packages().map(
                                     |(id, range)| (id, &state.pubgrub.package_store[id], range),
@@ -1044,6 +1057,51 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(())
     }
 
+    /// Speculatively fetch the metadata for the most likely version of each root requirement.
+    ///
+    /// Only registry requirements on the default indexes are prefetched; the candidate is selected
+    /// from the range implied by the requirement and any constraints on the package.
+    fn prefetch_requirements(&self, request_sink: &Sender<Request>) -> Result<(), ResolveError> {
+        for requirement in self.overrides.apply(&self.requirements) {
+            let RequirementSource::Registry {
+                specifier,
+                index: None,
+                ..
+            } = &requirement.source
+            else {
+                continue;
+            };
+            let name = &requirement.name;
+            if self.urls.any_url(name)
+                || self.indexes.contains_key(name)
+                || self.excludes.contains(name)
+                || !self.env.included_by_marker(requirement.marker)
+            {
+                continue;
+            }
+
+            let mut range = Range::from(specifier.clone());
+            for constraint in self.constraints.get(name).into_iter().flatten() {
+                let RequirementSource::Registry { specifier, .. } = &constraint.source else {
+                    continue;
+                };
+                if self.env.included_by_marker(constraint.marker) {
+                    range = range.intersection(&Range::from(specifier.clone()));
+                }
+            }
+
+            if self.index.implicit().register(name.clone()) {
+                request_sink.blocking_send(Request::Package(name.clone(), None))?;
+            }
+            request_sink.blocking_send(Request::Prefetch(
+                name.clone(),
+                range,
+                self.python_requirement.clone(),
+            ))?;
+        }
+        Ok(())
+    }
+
     /// Visit the set of [`PubGrubPackage`] candidates prior to selection. This allows us to fetch
     /// metadata for all packages in parallel.
     fn pre_visit<'data>(
@@ -1727,6 +1785,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                     let request = Request::from(dist);
                     request_sink.blocking_send(request)?;
+                } else {
+                    self.prefetch_stats.requested(&dist.distribution_id());
                 }
             }
         }
@@ -2433,12 +2493,32 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         .distributions()
                         .done(dist.distribution_id(), Arc::new(metadata));
                 }
-                Some(Response::Dist { dist, metadata }) => {
+                Some(Response::Dist {
+                    dist,
+                    metadata,
+                    prefetch,
+                }) => {
                     let dist_kind = match dist {
                         Dist::Built(_) => "built",
                         Dist::Source(_) => "source",
                     };
                     trace!("Received {dist_kind} distribution metadata for: {dist}");
+
+                    // If requested, abort the resolution (and any in-flight requests) rather than
+                    // waiting for the solver to reach the failing distribution. Prefetched
+                    // distributions are speculative, and may never be selected, so their errors
+                    // are only reported if the solver reaches them.
+                    if self.options.fail_fast && !prefetch {
+                        if let MetadataResponse::Error(dist, err) = &metadata {
+                            return Err(ResolveError::Dist(
+                                DistErrorKind::from_requested_dist(dist, &**err),
+                                dist.clone(),
+                                DerivationChain::default(),
+                                err.clone(),
+                            ));
+                        }
+                    }
+
                     if let MetadataResponse::Unavailable(reason) = &metadata {
                         let message = UnavailableVersion::from(reason).singular_message();
                         if let Some(err) = reason.source() {
@@ -2501,6 +2581,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                         metadata: MetadataResponse::Found(
                                             ArchiveMetadata::from_metadata23(metadata),
                                         ),
+                                        prefetch: false,
                                     }));
                                 }
                             }
@@ -2524,6 +2605,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     metadata: MetadataResponse::Found(
                                         ArchiveMetadata::from_metadata23(metadata),
                                     ),
+                                    prefetch: false,
                                 }));
                             }
                         }
@@ -2545,7 +2627,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 }
 
-                Ok(Some(Response::Dist { dist, metadata }))
+                Ok(Some(Response::Dist {
+                    dist,
+                    metadata,
+                    prefetch: false,
+                }))
             }
 
             Request::Installed(dist) => {
@@ -2648,6 +2734,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 ResolvedDist::Installable { dist, .. } => Response::Dist {
                                     dist: (*dist).clone(),
                                     metadata,
+                                    prefetch: true,
                                 },
                                 ResolvedDist::Installed { dist } => Response::Installed {
                                     dist: (*dist).clone(),
@@ -2704,6 +2791,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 // Emit a request to fetch the metadata for this version.
                 let dist = dist.for_resolution();
                 if self.index.distributions().register(dist.distribution_id()) {
+                    self.prefetch_stats.prefetched(dist.distribution_id());
                     let dist = dist.to_owned();
                     if &package_name != dist.name() {
                         return Err(ResolveError::MismatchedPackageName {
@@ -2723,6 +2811,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             Response::Dist {
                                 dist: (*dist).clone(),
                                 metadata,
+                                prefetch: true,
                             }
                         }
                         ResolvedDist::Installed { dist } => {
@@ -2918,6 +3007,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// The maximum number of versions to prefetch for a package at once, where `0` disables
+    /// prefetching.
+    fn prefetch_limit(&self) -> usize {
+        self.options
+            .prefetch_limit
+            .unwrap_or(DEFAULT_PREFETCH_LIMIT)
+    }
+
     fn on_complete(&self) {
         if let Some(reporter) = self.reporter.as_ref() {
             reporter.on_complete();
@@ -3706,6 +3803,8 @@ enum Response {
     Dist {
         dist: Dist,
         metadata: MetadataResponse,
+        /// Whether the distribution was prefetched, rather than requested by the resolver.
+        prefetch: bool,
     },
     /// The returned metadata for an already-installed distribution.
     Installed {
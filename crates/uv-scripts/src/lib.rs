@@ -24,6 +24,20 @@ pub use uv_workspace::pyproject::OverrideDependency;
 
 static FINDER: LazyLock<Finder> = LazyLock::new(|| Finder::new(b"# /// script"));
 
+/// The shebang to use for scripts that should be executed with `uv run`.
+pub const SHEBANG: &str = "#!/usr/bin/env -S uv run --script";
+
+/// The contents of a script created with [`Pep723Script::create`].
+#[derive(Debug)]
+pub enum ScriptContents {
+    /// Add inline metadata to the existing contents of a file, preserving any shebang.
+    Existing(Vec<u8>),
+    /// Only write the inline metadata.
+    Bare,
+    /// Write a `uv run` shebang and the inline metadata, followed by the given body.
+    Template(String),
+}
+
 /// A PEP 723 item, either read from a script on disk or provided via `stdin`.
 #[derive(Debug)]
 pub enum Pep723Item {
@@ -164,18 +178,17 @@ impl Pep723Script {
         let contents = fs_err::tokio::read(&file).await?;
 
         // Extract the `script` tag.
-        let ScriptTag {
-            prelude,
-            metadata,
-            postlude,
-        } = match ScriptTag::parse(&contents) {
+        let tag = match ScriptTag::parse(&contents) {
             Ok(Some(tag)) => tag,
             Ok(None) => return Ok(None),
             Err(err) => return Err(err),
         };
 
         // Parse the metadata.
-        let metadata = Pep723Metadata::from_str(&metadata)?;
+        let metadata = tag.parse_metadata()?;
+        let ScriptTag {
+            prelude, postlude, ..
+        } = tag;
 
         Ok(Some(Self {
             path: std::path::absolute(file)?,
@@ -255,58 +268,58 @@ impl Pep723Script {
     pub async fn create(
         file: impl AsRef<Path>,
         requires_python: &VersionSpecifiers,
-        existing_contents: Option<Vec<u8>>,
-        bare: bool,
+        dependencies: &[uv_pep508::Requirement<VerbatimParsedUrl>],
+        contents: ScriptContents,
     ) -> Result<(), Pep723Error> {
         let file = file.as_ref();
 
-        let script_name = file
-            .file_name()
-            .and_then(|name| name.to_str())
-            .ok_or_else(|| Pep723Error::InvalidFilename(file.to_string_lossy().to_string()))?;
-
+        let dependencies = if dependencies.is_empty() {
+            "[]".to_string()
+        } else {
+            let mut array = String::from("[\n");
+            for dependency in dependencies {
+                array.push_str("  ");
+                array.push_str(&toml::Value::String(dependency.to_string()).to_string());
+                array.push_str(",\n");
+            }
+            array.push(']');
+            array
+        };
         let default_metadata = indoc::formatdoc! {r#"
             requires-python = "{requires_python}"
-            dependencies = []
+            dependencies = {dependencies}
             "#,
         };
         let metadata = serialize_metadata(&default_metadata);
 
-        let script = if let Some(existing_contents) = existing_contents {
-            let (mut shebang, contents) = extract_shebang(&existing_contents)?;
-            if !shebang.is_empty() {
-                shebang.push_str("\n#\n");
-                // If the shebang doesn't contain `uv`, it's probably something like
-                // `#! /usr/bin/env python`, which isn't going to respect the inline metadata.
-                // Issue a warning for users who might not know that.
-                // TODO: There are a lot of mistakes we could consider detecting here, like
-                // `uv run` without `--script` when the file doesn't end in `.py`.
-                if !regex::Regex::new(r"\buv\b").unwrap().is_match(&shebang) {
-                    warn_user!(
-                        "If you execute {} directly, it might ignore its inline metadata.\nConsider replacing its shebang with: {}",
-                        file.to_string_lossy().cyan(),
-                        "#!/usr/bin/env -S uv run --script".cyan(),
-                    );
+        let script = match contents {
+            ScriptContents::Existing(existing_contents) => {
+                let (mut shebang, contents) = extract_shebang(&existing_contents)?;
+                if !shebang.is_empty() {
+                    shebang.push_str("\n#\n");
+                    // If the shebang doesn't contain `uv`, it's probably something like
+                    // `#! /usr/bin/env python`, which isn't going to respect the inline metadata.
+                    // Issue a warning for users who might not know that.
+                    // TODO: There are a lot of mistakes we could consider detecting here, like
+                    // `uv run` without `--script` when the file doesn't end in `.py`.
+                    if !regex::Regex::new(r"\buv\b").unwrap().is_match(&shebang) {
+                        warn_user!(
+                            "If you execute {} directly, it might ignore its inline metadata.\nConsider replacing its shebang with: {}",
+                            file.to_string_lossy().cyan(),
+                            SHEBANG.cyan(),
+                        );
+                    }
                 }
+                indoc::formatdoc! {r"
+                {shebang}{metadata}
+                {contents}" }
             }
-            indoc::formatdoc! {r"
-            {shebang}{metadata}
-            {contents}" }
-        } else if bare {
-            metadata
-        } else {
-            indoc::formatdoc! {r#"
-            {metadata}
-
-            def main() -> None:
-                print("Hello from {name}!")
-
-
-            if __name__ == "__main__":
-                main()
-        "#,
-                metadata = metadata,
-                name = script_name,
+            ScriptContents::Bare => metadata,
+            ScriptContents::Template(body) => {
+                indoc::formatdoc! {r"
+                {SHEBANG}
+                {metadata}
+                {body}" }
             }
         };
 
@@ -358,14 +371,14 @@ impl Pep723Metadata {
     /// Parse the PEP 723 metadata from `stdin`.
     pub fn parse(contents: &[u8]) -> Result<Option<Self>, Pep723Error> {
         // Extract the `script` tag.
-        let ScriptTag { metadata, .. } = match ScriptTag::parse(contents) {
+        let tag = match ScriptTag::parse(contents) {
             Ok(Some(tag)) => tag,
             Ok(None) => return Ok(None),
             Err(err) => return Err(err),
         };
 
         // Parse the metadata.
-        Ok(Some(Self::from_str(&metadata)?))
+        Ok(Some(tag.parse_metadata()?))
     }
 
     /// Read the PEP 723 `script` metadata from a Python file, if it exists.
@@ -377,14 +390,14 @@ impl Pep723Metadata {
         let contents = fs_err::tokio::read(&file).await?;
 
         // Extract the `script` tag.
-        let ScriptTag { metadata, .. } = match ScriptTag::parse(&contents) {
+        let tag = match ScriptTag::parse(&contents) {
             Ok(Some(tag)) => tag,
             Ok(None) => return Ok(None),
             Err(err) => return Err(err),
         };
 
         // Parse the metadata.
-        Ok(Some(Self::from_str(&metadata)?))
+        Ok(Some(tag.parse_metadata()?))
     }
 }
 
@@ -439,8 +452,12 @@ pub enum Pep723Error {
     Utf8(#[from] std::str::Utf8Error),
     #[error(transparent)]
     Toml(#[from] toml::de::Error),
-    #[error("Invalid filename `{0}` supplied")]
-    InvalidFilename(String),
+    #[error("Invalid PEP 723 metadata at line {line}, column {column}: {message}")]
+    InvalidMetadata {
+        line: usize,
+        column: usize,
+        message: String,
+    },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -632,6 +649,32 @@ impl ScriptTag {
             postlude,
         }))
     }
+
+    /// Parse the metadata block, reporting any errors relative to the script itself (rather than
+    /// the extracted TOML).
+    fn parse_metadata(&self) -> Result<Pep723Metadata, Pep723Error> {
+        Pep723Metadata::from_str(&self.metadata).map_err(|err| {
+            let Some(span) = err.span() else {
+                return Pep723Error::Toml(err);
+            };
+
+            // The metadata begins on the line after the opening `# /// script` tag.
+            let preceding = &self.metadata[..span.start];
+            let line = self.prelude.matches('\n').count() + 1 + preceding.matches('\n').count() + 1;
+
+            // Account for the `# ` prefix that was stripped from each line.
+            let column = preceding
+                .rfind('\n')
+                .map_or(preceding.len(), |index| preceding.len() - index - 1)
+                + 3;
+
+            Pep723Error::InvalidMetadata {
+                line,
+                column,
+                message: err.message().to_string(),
+            }
+        })
+    }
 }
 
 /// Extracts the shebang line from the given file contents and returns it along with the remaining
@@ -694,7 +737,7 @@ fn serialize_metadata(metadata: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Pep723Error, Pep723Script, ScriptTag, serialize_metadata};
+    use crate::{Pep723Error, Pep723Metadata, Pep723Script, ScriptTag, serialize_metadata};
     use std::str::FromStr;
 
     #[test]
@@ -745,6 +788,24 @@ mod tests {
         assert_eq!(ScriptTag::parse(contents.as_bytes()).unwrap(), None);
     }
 
+    #[test]
+    fn invalid_metadata_line() {
+        let contents = indoc::indoc! {r#"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python >= "3.11"
+        # ///
+
+        import requests
+    "#};
+
+        // The error should point at the line in the script, not in the extracted metadata.
+        assert!(matches!(
+            Pep723Metadata::parse(contents.as_bytes()),
+            Err(Pep723Error::InvalidMetadata { line: 3, .. })
+        ));
+    }
+
     #[test]
     fn simple() {
         let contents = indoc::indoc! {r"
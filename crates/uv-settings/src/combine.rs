@@ -1,5 +1,7 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
-use std::{collections::BTreeMap, num::NonZeroUsize};
 
 use url::Url;
 
@@ -20,7 +22,7 @@ use uv_resolver::{
     ForkStrategy, PrereleaseMode, ResolutionMode,
 };
 use uv_torch::TorchMode;
-use uv_workspace::pyproject::ExtraBuildDependencies;
+use uv_workspace::pyproject::{ExtraBuildDependencies, LicensePolicy};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::{AuditOptions, FilesystemOptions, Options, PipOptions, PreviewOption};
@@ -39,6 +41,81 @@ pub trait Combine {
     /// ...with one exception: we place items with higher precedence earlier in the merged array.
     #[must_use]
     fn combine(self, other: Self) -> Self;
+
+    /// Whether values from multiple sources are merged (e.g., lists and tables), rather than the
+    /// value with the highest precedence taking effect.
+    const MERGE: bool = false;
+}
+
+/// The sources that contribute to each setting, keyed by their dotted names (e.g.,
+/// `pip.index-url`).
+#[derive(Debug, Clone, Default)]
+pub struct Provenance(BTreeMap<String, Vec<usize>>);
+
+impl Provenance {
+    /// Record that a source provides a value for the given setting.
+    ///
+    /// Sources must be recorded in order of precedence, following the semantics of [`Combine`]: a
+    /// source is only retained alongside those with a higher precedence if the values are merged.
+    pub fn record(&mut self, key: String, source: usize, merge: bool) {
+        match self.0.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(vec![source]);
+            }
+            Entry::Occupied(mut entry) => {
+                if merge {
+                    entry.get_mut().push(source);
+                }
+            }
+        }
+    }
+
+    /// Return the sources that contribute to the given setting, in order of precedence.
+    pub fn sources(&self, key: &str) -> &[usize] {
+        self.0.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Record the origin of each setting in a set of options, as combined via [`Combine`].
+pub trait CombineOrigins {
+    /// Record the settings provided by `self` as originating from the given source.
+    fn record_origins(&self, prefix: &str, source: usize, provenance: &mut Provenance);
+
+    /// Add the settings whose values are merged across sources (see [`Combine::MERGE`]).
+    fn merged_settings(prefix: &str, settings: &mut BTreeSet<String>);
+}
+
+impl<T: CombineOrigins> CombineOrigins for Option<T> {
+    fn record_origins(&self, prefix: &str, source: usize, provenance: &mut Provenance) {
+        if let Some(value) = self {
+            value.record_origins(prefix, source, provenance);
+        }
+    }
+
+    fn merged_settings(prefix: &str, settings: &mut BTreeSet<String>) {
+        T::merged_settings(prefix, settings);
+    }
+}
+
+impl CombineOrigins for PreviewOption {
+    fn record_origins(&self, prefix: &str, source: usize, provenance: &mut Provenance) {
+        let key = match self {
+            Self::Preview(_) => "preview",
+            Self::PreviewFeatures(_) => "preview-features",
+        };
+        provenance.record(setting_key(prefix, key), source, false);
+    }
+
+    fn merged_settings(_prefix: &str, _settings: &mut BTreeSet<String>) {}
+}
+
+/// Return the dotted name of a setting within the given prefix, e.g., `pip.index-url`.
+pub fn setting_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
 }
 
 impl Combine for Option<FilesystemOptions> {
@@ -102,6 +179,7 @@ impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
+impl_combine_or!(LicensePolicy);
 impl_combine_or!(LinkMode);
 impl_combine_or!(DisplaySafeUrl);
 impl_combine_or!(NonZeroUsize);
@@ -127,6 +205,8 @@ impl_combine_or!(Url);
 impl_combine_or!(bool);
 
 impl<T> Combine for Option<Vec<T>> {
+    const MERGE: bool = true;
+
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
     /// both `Some`.
     fn combine(self, other: Self) -> Self {
@@ -141,6 +221,8 @@ impl<T> Combine for Option<Vec<T>> {
 }
 
 impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
+    const MERGE: bool = true;
+
     /// Combine two maps of vecs by combining their vecs
     fn combine(self, other: Self) -> Self {
         match (self, other) {
@@ -156,6 +238,8 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
 }
 
 impl Combine for Option<ExcludeNewerPackage> {
+    const MERGE: bool = true;
+
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
         match (self, other) {
@@ -172,6 +256,8 @@ impl Combine for Option<ExcludeNewerPackage> {
 }
 
 impl Combine for Option<ConfigSettings> {
+    const MERGE: bool = true;
+
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
     fn combine(self, other: Self) -> Self {
@@ -183,6 +269,8 @@ impl Combine for Option<ConfigSettings> {
 }
 
 impl Combine for Option<PackageConfigSettings> {
+    const MERGE: bool = true;
+
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
     fn combine(self, other: Self) -> Self {
@@ -194,6 +282,8 @@ impl Combine for Option<PackageConfigSettings> {
 }
 
 impl Combine for Option<NoSources> {
+    const MERGE: bool = true;
+
     /// Combine two source strategies by using the `combine` method if they're both `Some`.
     fn combine(self, other: Self) -> Self {
         match (self, other) {
@@ -204,6 +294,8 @@ impl Combine for Option<NoSources> {
 }
 
 impl Combine for Option<Upgrade> {
+    const MERGE: bool = true;
+
     fn combine(self, other: Self) -> Self {
         match (self, other) {
             (Some(a), Some(b)) => Some(a.combine(b)),
@@ -213,6 +305,8 @@ impl Combine for Option<Upgrade> {
 }
 
 impl Combine for Option<Reinstall> {
+    const MERGE: bool = true;
+
     fn combine(self, other: Self) -> Self {
         match (self, other) {
             (Some(a), Some(b)) => Some(a.combine(b)),
@@ -222,6 +316,8 @@ impl Combine for Option<Reinstall> {
 }
 
 impl Combine for Option<BuildIsolation> {
+    const MERGE: bool = true;
+
     fn combine(self, other: Self) -> Self {
         match (self, other) {
             (Some(a), Some(b)) => Some(a.combine(b)),
@@ -282,6 +378,8 @@ impl Combine for ExtraBuildDependencies {
 }
 
 impl Combine for Option<ExtraBuildDependencies> {
+    const MERGE: bool = true;
+
     fn combine(self, other: Self) -> Self {
         match (self, other) {
             (Some(a), Some(b)) => Some(a.combine(b)),
@@ -311,6 +409,8 @@ impl Combine for ExtraBuildVariables {
 }
 
 impl Combine for Option<ExtraBuildVariables> {
+    const MERGE: bool = true;
+
     fn combine(self, other: Self) -> Self {
         match (self, other) {
             (Some(a), Some(b)) => Some(a.combine(b)),
@@ -1,3 +1,4 @@
+use serde::de::DeserializeOwned;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
@@ -7,20 +8,24 @@ use tracing::info_span;
 use uv_client::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD};
 use uv_configuration::RequiredVersion;
 use uv_dirs::{system_config_file, user_config_dir};
-use uv_distribution_types::Origin;
+use uv_distribution_types::{DependencyMetadata, DependencyMetadataError, Origin};
 use uv_flags::EnvironmentFlags;
 use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
 use uv_redacted::DisplaySafeUrl;
 use uv_static::{EnvVars, InvalidEnvironmentVariable, parse_boolish_environment_variable};
+use uv_toml::{InterpolationError, interpolate_document};
 use uv_torch::AmdGpuArchitecture;
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::INTERPOLATED_KEYS;
 
 pub use crate::combine::*;
+pub use crate::provenance::*;
 pub use crate::settings::*;
 
 mod combine;
+mod provenance;
 mod settings;
 
 /// The [`Options`] as loaded from a configuration file on disk.
@@ -124,9 +129,10 @@ impl FilesystemOptions {
         let path = dir.join("uv.toml");
         match fs_err::read_to_string(&path) {
             Ok(content) => {
+                let document = interpolate(&path, &content, "")?;
                 let options =
                     info_span!("toml::from_str filesystem options uv.toml", path = %path.display())
-                        .in_scope(|| toml::from_str::<Options>(&content))
+                        .in_scope(|| deserialize::<Options>(&content, document))
                         .map_err(|err| {
                             check_uv_toml_required_version(
                                 &path,
@@ -151,6 +157,7 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
+                validate_dependency_metadata(&path, &options)?;
                 return Ok(Some(Self(options.with_origin(Origin::Project))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -162,9 +169,10 @@ impl FilesystemOptions {
         match fs_err::read_to_string(&path) {
             Ok(content) => {
                 // Parse, but skip any `pyproject.toml` that doesn't have a `[tool.uv]` section.
+                let document = interpolate(&path, &content, "tool.uv")?;
                 let pyproject =
                     info_span!("toml::from_str filesystem options pyproject.toml", path = %path.display())
-                        .in_scope(|| toml::from_str::<PyProjectToml>(&content))
+                        .in_scope(|| deserialize::<PyProjectToml>(&content, document))
                         .map_err(|err| {
                             check_pyproject_required_version(&path, &content, err)
                         })?;
@@ -186,6 +194,7 @@ impl FilesystemOptions {
                 let options = options.relative_to(&std::path::absolute(dir)?)?;
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
+                validate_dependency_metadata(&path, &options)?;
                 return Ok(Some(Self(options)));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -215,8 +224,9 @@ impl From<Options> for FilesystemOptions {
 /// Load [`Options`] from a `uv.toml` file.
 fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
+    let document = interpolate(path, &content, "")?;
     let options = info_span!("toml::from_str filesystem options uv.toml", path = %path.display())
-        .in_scope(|| toml::from_str::<Options>(&content))
+        .in_scope(|| deserialize::<Options>(&content, document))
         .map_err(|err| {
             check_uv_toml_required_version(
                 path,
@@ -229,9 +239,31 @@ fn read_file(path: &Path) -> Result<Options, Error> {
     } else {
         options
     };
+    validate_dependency_metadata(path, &options)?;
     Ok(options)
 }
 
+/// Interpolate any environment variables referenced by the settings in a configuration file,
+/// relative to the table at `prefix`.
+///
+/// See [`INTERPOLATED_KEYS`] for the settings that support interpolation.
+fn interpolate(path: &Path, content: &str, prefix: &str) -> Result<Option<toml::Table>, Error> {
+    interpolate_document(content, prefix, INTERPOLATED_KEYS)
+        .map_err(|err| Error::Interpolation(path.to_path_buf(), err))
+}
+
+/// Deserialize a configuration file, using the interpolated document, if any.
+fn deserialize<T: DeserializeOwned>(
+    content: &str,
+    document: Option<toml::Table>,
+) -> Result<T, toml::de::Error> {
+    if let Some(document) = document {
+        toml::Value::Table(document).try_into()
+    } else {
+        toml::from_str(content)
+    }
+}
+
 /// If `required_version` is set and incompatible with the running uv, return the corresponding
 /// [`Error::RequiredVersion`].
 fn required_version_mismatch(required_version: Option<RequiredVersion>) -> Option<Error> {
@@ -280,6 +312,20 @@ fn check_uv_toml_required_version(path: &Path, content: &str, source: Error) ->
     required_version_mismatch(uv_toml.required_version).unwrap_or(source)
 }
 
+/// Validate that the `dependency-metadata` entries in an [`Options`] schema don't contradict one
+/// another.
+fn validate_dependency_metadata(path: &Path, options: &Options) -> Result<(), Error> {
+    let entries = options.top_level.dependency_metadata.iter().flatten();
+    DependencyMetadata::validate(entries)
+        .map_err(|err| Error::DependencyMetadata(path.to_path_buf(), err))?;
+    if let Some(pip) = &options.pip {
+        let entries = pip.dependency_metadata.iter().flatten();
+        DependencyMetadata::validate(entries)
+            .map_err(|err| Error::DependencyMetadata(path.to_path_buf(), err))?;
+    }
+    Ok(())
+}
+
 /// Validate that an [`Options`] schema is compatible with `uv.toml`.
 fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     // A `required-version` mismatch takes precedence over a schema error.
@@ -295,6 +341,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         audit: _,
         pip: _,
         cache_keys: _,
+        allow_build: _,
+        build_sandbox: _,
+        build_sandbox_exempt: _,
         override_dependencies: _,
         exclude_dependencies: _,
         constraint_dependencies: _,
@@ -302,6 +351,8 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         environments,
         required_environments,
         conflicts,
+        license_policy,
+        lock_provenance,
         workspace,
         sources,
         dev_dependencies,
@@ -317,6 +368,18 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if conflicts.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
     }
+    if license_policy.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "license-policy",
+        ));
+    }
+    if lock_provenance.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "lock-provenance",
+        ));
+    }
     if workspace.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "workspace"));
     }
@@ -392,6 +455,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 http_proxy,
                 https_proxy,
                 no_proxy,
+                min_tls_version,
             },
         top_level:
             ResolverInstallerSchema {
@@ -405,6 +469,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 resolution,
                 prerelease,
                 fork_strategy,
+                prefetch_limit,
+                resolution_cache,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -441,9 +507,13 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 check_url,
             },
         add: AddOptions { add_bounds },
+        sync: SyncOptions { sync_exact },
         audit: _,
         pip,
         cache_keys,
+        allow_build,
+        build_sandbox,
+        build_sandbox_exempt,
         override_dependencies,
         exclude_dependencies,
         constraint_dependencies,
@@ -451,6 +521,8 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         environments: _,
         required_environments: _,
         conflicts: _,
+        license_policy: _,
+        lock_provenance: _,
         workspace: _,
         sources: _,
         dev_dependencies: _,
@@ -513,6 +585,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if no_proxy.is_some() {
         masked_fields.push("no-proxy");
     }
+    if min_tls_version.is_some() {
+        masked_fields.push("min-tls-version");
+    }
     if index.is_some() {
         masked_fields.push("index");
     }
@@ -543,6 +618,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if prefetch_limit.is_some() {
+        masked_fields.push("prefetch-limit");
+    }
+    if resolution_cache.is_some() {
+        masked_fields.push("resolution-cache");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -627,12 +708,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if sync_exact.is_some() {
+        masked_fields.push("sync-exact");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
+    if allow_build.is_some() {
+        masked_fields.push("allow-build");
+    }
+    if build_sandbox.is_some() {
+        masked_fields.push("build-sandbox");
+    }
+    if build_sandbox_exempt.is_some() {
+        masked_fields.push("build-sandbox-exempt");
+    }
     if override_dependencies.is_some() {
         masked_fields.push("override-dependencies");
     }
@@ -668,6 +761,12 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to interpolate environment variables in: `{}`", _0.user_display())]
+    Interpolation(PathBuf, #[source] InterpolationError),
+
+    #[error("Invalid `dependency-metadata` in: `{}`", _0.user_display())]
+    DependencyMetadata(PathBuf, #[source] DependencyMetadataError),
+
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1
     )]
     PyprojectOnlyField(PathBuf, &'static str),
@@ -720,6 +819,7 @@ pub struct EnvironmentOptions {
     pub ty_path: Option<PathBuf>,
     pub skip_wheel_filename_check: Option<bool>,
     pub hide_build_output: Option<bool>,
+    pub no_uv_version_notice: Option<bool>,
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
     pub python_no_registry: EnvFlag,
@@ -798,6 +898,9 @@ impl EnvironmentOptions {
                 EnvVars::UV_SKIP_WHEEL_FILENAME_CHECK,
             )?,
             hide_build_output: parse_boolish_environment_variable(EnvVars::UV_HIDE_BUILD_OUTPUT)?,
+            no_uv_version_notice: parse_boolish_environment_variable(
+                EnvVars::UV_NO_UV_VERSION_NOTICE,
+            )?,
             python_install_bin: parse_boolish_environment_variable(EnvVars::UV_PYTHON_INSTALL_BIN)?,
             python_install_registry: parse_boolish_environment_variable(
                 EnvVars::UV_PYTHON_INSTALL_REGISTRY,
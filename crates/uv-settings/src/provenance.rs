@@ -0,0 +1,463 @@
+//! Report the effective value of each setting alongside its origin.
+//!
+//! The settings used by commands are combined from the command line, environment variables, and
+//! configuration files via [`Combine`](crate::Combine), which discards where each value came from.
+//! Here, each layer is retained and queried per setting, following the same precedence and merge
+//! rules, so that the origin of every value can be reported.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use uv_dirs::{system_config_file, user_config_dir};
+use uv_fs::Simplified;
+use uv_options_metadata::{OptionField, OptionSet, OptionsMetadata, Visit};
+use uv_static::{EnvVars, parse_boolish_environment_variable};
+use uv_toml::{InterpolationMode, interpolate_table};
+use uv_workspace::pyproject::INTERPOLATED_KEYS;
+
+use crate::{CombineOrigins, Error, Options, Provenance};
+
+/// The origin of the value of a setting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum SettingOrigin {
+    /// A command-line flag, e.g., `--offline`.
+    CommandLine { flag: String },
+    /// An environment variable, e.g., `UV_OFFLINE`.
+    Environment { name: String },
+    /// A key in a configuration file, e.g., `tool.uv.offline` in a `pyproject.toml`.
+    File { path: PathBuf, key: String },
+    /// The default value.
+    Default,
+}
+
+impl Display for SettingOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CommandLine { flag } => write!(f, "command line: {flag}"),
+            Self::Environment { name } => write!(f, "environment: {name}"),
+            Self::File { path, key } => write!(f, "file: {} ({key})", path.user_display()),
+            Self::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// A setting provided on the command line.
+#[derive(Debug, Clone)]
+pub struct CommandLineSetting {
+    /// The setting key, e.g., `index-url`.
+    pub key: String,
+    /// The flag used to provide the setting, e.g., `--index-url`.
+    pub flag: String,
+    /// The values passed to the flag, which are empty for boolean flags.
+    pub values: Vec<String>,
+}
+
+/// The effective value of a setting, along with its origins.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedSetting {
+    /// The setting key, e.g., `pip.no-build-isolation`.
+    pub key: String,
+    /// The effective value, if any.
+    pub value: Option<toml::Value>,
+    /// The origins of the value, in order of precedence.
+    ///
+    /// Multiple origins are reported for lists and tables, which are merged across sources.
+    pub origins: Vec<SettingOrigin>,
+}
+
+/// The raw contents of a configuration file, retained to report the origin of each setting.
+#[derive(Debug, Clone)]
+pub struct ConfigurationFile {
+    /// The path to the `uv.toml` or `pyproject.toml` file.
+    path: PathBuf,
+    /// The uv settings table, i.e., the entire `uv.toml` file or the `[tool.uv]` table.
+    table: toml::Table,
+    /// The settings parsed from the table, used to determine which settings the file provides.
+    options: Options,
+    /// Whether the settings were read from the `[tool.uv]` table of a `pyproject.toml` file.
+    pyproject: bool,
+}
+
+impl ConfigurationFile {
+    /// Find the configuration file for the given path, following the same rules as
+    /// [`FilesystemOptions::find`](crate::FilesystemOptions::find).
+    pub fn find(path: &Path) -> Result<Option<Self>, Error> {
+        for ancestor in path.ancestors() {
+            let uv_toml = ancestor.join("uv.toml");
+            if uv_toml.is_file() {
+                return Self::from_file(&uv_toml).map(Some);
+            }
+
+            let pyproject_toml = ancestor.join("pyproject.toml");
+            if pyproject_toml.is_file() {
+                let content = fs_err::read_to_string(&pyproject_toml)?;
+                // Skip any `pyproject.toml` that is invalid or has no `[tool.uv]` table.
+                let Ok(mut document) = toml::from_str::<toml::Table>(&content) else {
+                    continue;
+                };
+                let Some(toml::Value::Table(table)) =
+                    document.remove("tool").and_then(|tool| match tool {
+                        toml::Value::Table(mut tool) => tool.remove("uv"),
+                        _ => None,
+                    })
+                else {
+                    continue;
+                };
+                return Self::new(pyproject_toml, table, true).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Load the user configuration file, if any.
+    pub fn user() -> Result<Option<Self>, Error> {
+        let Some(dir) = user_config_dir() else {
+            return Ok(None);
+        };
+        let path = dir.join("uv").join("uv.toml");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Self::from_file(&path).map(Some)
+    }
+
+    /// Load the system configuration file, if any.
+    pub fn system() -> Result<Option<Self>, Error> {
+        if parse_boolish_environment_variable(EnvVars::UV_NO_SYSTEM_CONFIG)? == Some(true) {
+            return Ok(None);
+        }
+        let Some(path) = system_config_file() else {
+            return Ok(None);
+        };
+        Self::from_file(&path).map(Some)
+    }
+
+    /// Load a `uv.toml` file.
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let content = fs_err::read_to_string(path)?;
+        let table = toml::from_str::<toml::Table>(&content)
+            .map_err(|err| Error::UvToml(path.to_path_buf(), Box::new(err)))?;
+        Self::new(path.to_path_buf(), table, false)
+    }
+
+    /// Create a [`ConfigurationFile`] from the uv settings table of a file.
+    ///
+    /// Any environment variables referenced by the settings are interpolated, but redacted, such
+    /// that secrets (e.g., tokens in index URLs) are never reported.
+    fn new(path: PathBuf, mut table: toml::Table, pyproject: bool) -> Result<Self, Error> {
+        let prefix = if pyproject { "tool.uv" } else { "" };
+
+        // Parse the settings from the resolved values, as when loading the file for a command.
+        let mut resolved = table.clone();
+        if let Err(err) = interpolate_table(
+            &mut resolved,
+            prefix,
+            INTERPOLATED_KEYS,
+            InterpolationMode::Resolve,
+        ) {
+            return Err(Error::Interpolation(path, err));
+        }
+        let options = match toml::Value::Table(resolved).try_into::<Options>() {
+            Ok(options) => options,
+            Err(err) if pyproject => return Err(Error::PyprojectToml(path, Box::new(err))),
+            Err(err) => return Err(Error::UvToml(path, Box::new(err))),
+        };
+
+        if let Err(err) = interpolate_table(
+            &mut table,
+            prefix,
+            INTERPOLATED_KEYS,
+            InterpolationMode::Redact,
+        ) {
+            return Err(Error::Interpolation(path, err));
+        }
+        Ok(Self {
+            path,
+            table,
+            options,
+            pyproject,
+        })
+    }
+
+    /// Return the path to the configuration file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the value of a dotted setting key, e.g., `pip.no-build-isolation`.
+    fn get(&self, key: &str) -> Option<&toml::Value> {
+        let mut parts = key.split('.');
+        let mut value = self.table.get(parts.next()?)?;
+        for part in parts {
+            value = value.as_table()?.get(part)?;
+        }
+        Some(value)
+    }
+
+    /// Return the origin of a setting key in this file.
+    fn origin(&self, key: &str) -> SettingOrigin {
+        SettingOrigin::File {
+            path: self.path.clone(),
+            key: if self.pyproject {
+                format!("tool.uv.{key}")
+            } else {
+                key.to_string()
+            },
+        }
+    }
+}
+
+/// The sources of settings, from highest to lowest precedence.
+#[derive(Debug, Clone, Default)]
+pub struct SettingSources {
+    command_line: Vec<CommandLineSetting>,
+    environment: BTreeMap<String, String>,
+    files: Vec<ConfigurationFile>,
+    /// The files that provide each setting, as indices into `files`.
+    provenance: Provenance,
+    /// The settings whose values are merged across sources.
+    merged: BTreeSet<String>,
+}
+
+impl SettingSources {
+    /// Create the sources from the settings provided on the command line, the environment
+    /// variables that correspond to each setting, and the configuration files, in order of
+    /// precedence.
+    ///
+    /// Environment variables are keyed by the name of the setting, without the section (e.g.,
+    /// `system` for `pip.system`).
+    pub fn new(
+        command_line: Vec<CommandLineSetting>,
+        environment: BTreeMap<String, String>,
+        files: Vec<ConfigurationFile>,
+    ) -> Self {
+        let mut provenance = Provenance::default();
+        for (index, file) in files.iter().enumerate() {
+            file.options.record_origins("", index, &mut provenance);
+        }
+        let mut merged = BTreeSet::new();
+        Options::merged_settings("", &mut merged);
+        Self {
+            command_line,
+            environment,
+            files,
+            provenance,
+            merged,
+        }
+    }
+
+    /// Resolve every known setting.
+    pub fn resolve_all(&self) -> Vec<ResolvedSetting> {
+        setting_fields()
+            .into_iter()
+            .map(|(key, field)| self.resolve_field(key, &field))
+            .collect()
+    }
+
+    /// Resolve a single setting, returning `None` if the key is not a known setting.
+    pub fn resolve(&self, key: &str) -> Option<ResolvedSetting> {
+        let field = setting_fields().remove(key)?;
+        Some(self.resolve_field(key.to_string(), &field))
+    }
+
+    fn resolve_field(&self, key: String, field: &OptionField) -> ResolvedSetting {
+        let merge = self.merged.contains(&key);
+        let mut layers = Vec::new();
+
+        // Flags on the command line only apply to the top-level settings.
+        if let Some(setting) = self.command_line.iter().find(|setting| setting.key == key) {
+            let value = if setting.values.is_empty() {
+                toml::Value::Boolean(true)
+            } else {
+                parse_value(&key, field.value_type, merge, &setting.values)
+            };
+            layers.push((
+                value,
+                SettingOrigin::CommandLine {
+                    flag: setting.flag.clone(),
+                },
+            ));
+        }
+
+        // Environment variables apply to the setting in the `pip` section as well.
+        let name = key.rsplit('.').next().unwrap_or(&key);
+        if let Some(name) = self.environment.get(name)
+            && let Some(value) = std::env::var_os(name)
+            && !value.is_empty()
+        {
+            let value = value.to_string_lossy();
+            let values = if merge {
+                value.split_whitespace().map(ToString::to_string).collect()
+            } else {
+                vec![value.to_string()]
+            };
+            layers.push((
+                parse_value(&key, field.value_type, merge, &values),
+                SettingOrigin::Environment { name: name.clone() },
+            ));
+        }
+
+        for &index in self.provenance.sources(&key) {
+            let file = &self.files[index];
+            if let Some(value) = file.get(&key) {
+                layers.push((value.clone(), file.origin(&key)));
+            }
+        }
+
+        let Some(((first, origin), rest)) = layers.split_first() else {
+            return ResolvedSetting {
+                key,
+                value: parse_default(field.default),
+                origins: vec![SettingOrigin::Default],
+            };
+        };
+
+        // Follow the semantics of `Combine`: lists are extended and tables are merged across
+        // sources, while any other value is taken from the source with the highest precedence.
+        let mut value = first.clone();
+        let mut origins = vec![origin.clone()];
+        if merge {
+            for (other, origin) in rest {
+                match (&mut value, other) {
+                    (toml::Value::Array(values), toml::Value::Array(others)) => {
+                        values.extend(others.iter().cloned());
+                        origins.push(origin.clone());
+                    }
+                    (toml::Value::Table(values), toml::Value::Table(others)) => {
+                        for (key, other) in others {
+                            values.entry(key.clone()).or_insert_with(|| other.clone());
+                        }
+                        origins.push(origin.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        ResolvedSetting {
+            key,
+            value: Some(value),
+            origins,
+        }
+    }
+}
+
+/// Return the known settings, keyed by their dotted names (e.g., `pip.no-build-isolation`).
+fn setting_fields() -> BTreeMap<String, OptionField> {
+    struct FieldVisitor<'a> {
+        prefix: Option<&'a str>,
+        fields: &'a mut BTreeMap<String, OptionField>,
+    }
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_field(&mut self, name: &str, field: OptionField) {
+            let key = match self.prefix {
+                Some(prefix) => format!("{prefix}.{name}"),
+                None => name.to_string(),
+            };
+            self.fields.insert(key, field);
+        }
+
+        fn record_set(&mut self, name: &str, group: OptionSet) {
+            let prefix = match self.prefix {
+                Some(prefix) => format!("{prefix}.{name}"),
+                None => name.to_string(),
+            };
+            group.record(&mut FieldVisitor {
+                prefix: Some(&prefix),
+                fields: self.fields,
+            });
+        }
+    }
+
+    let mut fields = BTreeMap::new();
+    Options::record(&mut FieldVisitor {
+        prefix: None,
+        fields: &mut fields,
+    });
+    fields
+}
+
+/// Parse the string values of a setting provided on the command line or in the environment.
+fn parse_value(key: &str, value_type: &str, merge: bool, values: &[String]) -> toml::Value {
+    // Indexes are provided as `name=url` or `url`, but are represented as a list of tables.
+    if key == "index" {
+        return toml::Value::Array(
+            values
+                .iter()
+                .map(|value| {
+                    let mut table = toml::Table::new();
+                    if let Some((name, url)) = value.split_once('=')
+                        && !name.contains(['/', ':'])
+                    {
+                        table.insert("name".to_string(), toml::Value::String(name.to_string()));
+                        table.insert("url".to_string(), toml::Value::String(url.to_string()));
+                    } else {
+                        table.insert("url".to_string(), toml::Value::String(value.clone()));
+                    }
+                    toml::Value::Table(table)
+                })
+                .collect(),
+        );
+    }
+    if merge && value_type.starts_with("list") {
+        return toml::Value::Array(
+            values
+                .iter()
+                .map(|value| toml::Value::String(value.clone()))
+                .collect(),
+        );
+    }
+    if merge && value_type == "dict" {
+        return toml::Value::Table(
+            values
+                .iter()
+                .filter_map(|value| value.split_once('='))
+                .map(|(key, value)| (key.to_string(), toml::Value::String(value.to_string())))
+                .collect(),
+        );
+    }
+    let value = values.last().map(String::as_str).unwrap_or_default();
+    match value_type {
+        "bool" => match value.to_ascii_lowercase().as_str() {
+            // Match the literals accepted by Clap and `parse_boolish_environment_variable`.
+            "y" | "yes" | "t" | "true" | "on" | "1" => toml::Value::Boolean(true),
+            "n" | "no" | "f" | "false" | "off" | "0" => toml::Value::Boolean(false),
+            _ => toml::Value::String(value.to_string()),
+        },
+        "int" => value
+            .parse()
+            .map(toml::Value::Integer)
+            .unwrap_or_else(|_| toml::Value::String(value.to_string())),
+        _ => toml::Value::String(value.to_string()),
+    }
+}
+
+/// Parse the documented default of a setting, returning `None` if the setting is unset by default.
+fn parse_default(default: &str) -> Option<toml::Value> {
+    if matches!(default, "None" | "null") {
+        return None;
+    }
+    let Some(value) = parse_toml_value(default) else {
+        return Some(toml::Value::String(default.to_string()));
+    };
+    // Some defaults are documented as a quoted TOML value, e.g., `"[]"` for a list of tables.
+    if let toml::Value::String(inner) = &value
+        && let Some(inner @ (toml::Value::Array(_) | toml::Value::Table(_))) =
+            parse_toml_value(inner)
+    {
+        return Some(inner);
+    }
+    Some(value)
+}
+
+/// Parse a single TOML value, e.g., `"managed"` or `[]`.
+fn parse_toml_value(value: &str) -> Option<toml::Value> {
+    toml::from_str::<toml::Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+}
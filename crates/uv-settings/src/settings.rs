@@ -6,12 +6,14 @@ use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    BuildIsolation, BuildSandbox, ExcludeDependency, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, Origin,
-    PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    PackageConfigSettings, PackageNameGlob, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
+    TlsVersion,
 };
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
@@ -27,7 +29,7 @@ use uv_resolver::{
     serialize_exclude_newer_package_with_spans,
 };
 use uv_torch::TorchMode;
-use uv_workspace::pyproject::{ExtraBuildDependencies, OverrideDependency};
+use uv_workspace::pyproject::{ExtraBuildDependencies, LicensePolicy, OverrideDependency};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
@@ -92,6 +94,9 @@ pub struct Options {
     #[serde(flatten)]
     pub add: AddOptions,
 
+    #[serde(flatten)]
+    pub sync: SyncOptions,
+
     #[option_group]
     pub audit: Option<AuditOptions>,
 
@@ -142,6 +147,54 @@ pub struct Options {
     )]
     pub cache_keys: Option<Vec<CacheKey>>,
 
+    /// Only build source distributions for packages that match the given patterns.
+    ///
+    /// Building a source distribution runs arbitrary code from the package's build backend. When
+    /// `allow-build` is set, packages that don't match any of the given patterns must be installed
+    /// from a pre-built wheel, both when extracting metadata during resolution and when installing;
+    /// if no compatible wheel is available, the operation fails. Patterns are package names that
+    /// may contain `*` wildcards, e.g., `internal-*`.
+    ///
+    /// Editable installs (e.g., of workspace members) are always permitted. Unlike `no-build`,
+    /// which disables all builds, `allow-build` restricts builds to a known set of packages.
+    #[option(
+        default = "null",
+        value_type = "list[str]",
+        example = r#"
+            allow-build = ["numpy", "internal-*"]
+        "#
+    )]
+    pub allow_build: Option<Vec<PackageNameGlob>>,
+
+    /// Run build backends in a sandbox.
+    ///
+    /// With `strict`, build backends run without network access, and with filesystem access limited
+    /// to the build directory, the cache, the interpreter, and system toolchain directories. On
+    /// Linux, isolation is provided by Landlock and network namespaces; on macOS, by
+    /// `sandbox-exec`. Where isolation isn't available, uv warns and builds without it.
+    ///
+    /// Packages that require network access at build time can be exempted with
+    /// `build-sandbox-exempt`.
+    #[option(
+        default = "\"disabled\"",
+        value_type = "str",
+        example = r#"
+            build-sandbox = "strict"
+        "#,
+        possible_values = true
+    )]
+    pub build_sandbox: Option<BuildSandbox>,
+
+    /// Packages whose build backends run outside the sandbox configured by `build-sandbox`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            build-sandbox-exempt = ["grpcio"]
+        "#
+    )]
+    pub build_sandbox_exempt: Option<Vec<PackageName>>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -169,6 +222,12 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) conflicts: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub license_policy: Option<LicensePolicy>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) lock_provenance: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) workspace: Option<serde::de::IgnoredAny>,
 
@@ -434,6 +493,19 @@ pub struct GlobalOptions {
         "#
     )]
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
+    /// The minimum TLS version to accept when connecting to any host.
+    ///
+    /// Accepts `1.2` or `1.3`. Connections to hosts that can't negotiate at least the given
+    /// version will fail. A stricter minimum can be set for individual indexes via the
+    /// `min-tls-version` index setting.
+    #[option(
+        default = "\"1.2\"",
+        value_type = "str",
+        example = r#"
+            min-tls-version = "1.3"
+        "#
+    )]
+    pub min_tls_version: Option<TlsVersion>,
 }
 
 /// Like [`GlobalOptions`], but with any `#[serde(flatten)]` fields inlined.
@@ -460,6 +532,7 @@ struct GlobalOptionsWire {
     https_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
+    min_tls_version: Option<TlsVersion>,
 }
 
 impl TryFrom<GlobalOptionsWire> for GlobalOptions {
@@ -485,6 +558,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            min_tls_version,
         } = value;
 
         Ok(Self {
@@ -504,6 +578,7 @@ impl TryFrom<GlobalOptionsWire> for GlobalOptions {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            min_tls_version,
         })
     }
 }
@@ -545,6 +620,8 @@ pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub prefetch_limit: Option<usize>,
+    pub resolution_cache: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -578,6 +655,8 @@ pub struct ResolverInstallerOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub prefetch_limit: Option<usize>,
+    pub resolution_cache: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -612,6 +691,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            prefetch_limit,
+            resolution_cache,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -646,6 +727,8 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            prefetch_limit,
+            resolution_cache,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -899,6 +982,39 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The maximum number of versions to prefetch for a package at once during resolution.
+    ///
+    /// Once the resolver knows which packages it needs, it fetches the metadata for their most
+    /// likely versions in parallel, along with batches of further versions for packages that
+    /// require backtracking. This speeds up resolution on high-latency connections, at the cost
+    /// of fetching some metadata that ends up unused.
+    ///
+    /// Set to `0` to disable prefetching.
+    #[option(
+        default = "50",
+        value_type = "int",
+        example = r#"
+            prefetch-limit = 10
+        "#
+    )]
+    pub prefetch_limit: Option<usize>,
+    /// Cache resolutions, and reuse them when the resolver inputs are unchanged.
+    ///
+    /// Resolutions are keyed by the inputs to the resolver (e.g., the requirements, constraints,
+    /// indexes, and settings), and stored in the uv cache. A cached resolution is ignored if the
+    /// cache is refreshed (e.g., with `--refresh`), or if the index responses for any of its
+    /// packages have been updated since it was written.
+    ///
+    /// Only resolutions that consist entirely of packages from a registry are cached. Set to
+    /// `false` to disable the reuse of cached resolutions.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            resolution-cache = false
+        "#
+    )]
+    pub resolution_cache: Option<bool>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1647,6 +1763,22 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The maximum number of versions to prefetch for a package at once during resolution.
+    ///
+    /// Once the resolver knows which packages it needs, it fetches the metadata for their most
+    /// likely versions in parallel, along with batches of further versions for packages that
+    /// require backtracking. This speeds up resolution on high-latency connections, at the cost
+    /// of fetching some metadata that ends up unused.
+    ///
+    /// Set to `0` to disable prefetching.
+    #[option(
+        default = "50",
+        value_type = "int",
+        example = r#"
+            prefetch-limit = 10
+        "#
+    )]
+    pub prefetch_limit: Option<usize>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -2127,6 +2259,8 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prefetch_limit: value.prefetch_limit,
+            resolution_cache: value.resolution_cache,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2417,6 +2551,8 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prefetch_limit: None,
+            resolution_cache: None,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2474,9 +2610,12 @@ struct OptionsWire {
     https_proxy: Option<ProxyUrl>,
     no_proxy: Option<Vec<String>>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
+    min_tls_version: Option<TlsVersion>,
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    prefetch_limit: Option<usize>,
+    resolution_cache: Option<bool>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2516,9 +2655,16 @@ struct OptionsWire {
     // add: AddOptions
     add_bounds: Option<AddBoundsKind>,
 
+    // #[serde(flatten)]
+    // sync: SyncOptions
+    sync_exact: Option<bool>,
+
     audit: Option<AuditOptions>,
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    allow_build: Option<Vec<PackageNameGlob>>,
+    build_sandbox: Option<BuildSandbox>,
+    build_sandbox_exempt: Option<Vec<PackageName>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -2534,6 +2680,8 @@ struct OptionsWire {
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're only respected in `pyproject.toml` files, and should be rejected in `uv.toml` files.
     conflicts: Option<serde::de::IgnoredAny>,
+    license_policy: Option<LicensePolicy>,
+    lock_provenance: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
@@ -2579,9 +2727,12 @@ impl TryFrom<OptionsWire> for Options {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            min_tls_version,
             resolution,
             prerelease,
             fork_strategy,
+            prefetch_limit,
+            resolution_cache,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -2605,6 +2756,9 @@ impl TryFrom<OptionsWire> for Options {
             audit,
             pip,
             cache_keys,
+            allow_build,
+            build_sandbox,
+            build_sandbox_exempt,
             override_dependencies,
             exclude_dependencies,
             constraint_dependencies,
@@ -2612,6 +2766,8 @@ impl TryFrom<OptionsWire> for Options {
             environments,
             required_environments,
             conflicts,
+            license_policy,
+            lock_provenance,
             publish_url,
             trusted_publishing,
             check_url,
@@ -2625,6 +2781,7 @@ impl TryFrom<OptionsWire> for Options {
             managed,
             package,
             add_bounds: bounds,
+            sync_exact,
             // Used by the build backend
             build_backend,
         } = value;
@@ -2648,6 +2805,7 @@ impl TryFrom<OptionsWire> for Options {
                 no_proxy,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
+                min_tls_version,
             },
             top_level: ResolverInstallerSchema {
                 index,
@@ -2660,6 +2818,8 @@ impl TryFrom<OptionsWire> for Options {
                 resolution,
                 prerelease,
                 fork_strategy,
+                prefetch_limit,
+                resolution_cache,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -2685,6 +2845,9 @@ impl TryFrom<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            allow_build,
+            build_sandbox,
+            build_sandbox_exempt,
             build_backend,
             override_dependencies,
             exclude_dependencies,
@@ -2698,12 +2861,15 @@ impl TryFrom<OptionsWire> for Options {
                 python_downloads_json_url,
             },
             conflicts,
+            license_policy,
+            lock_provenance,
             publish: PublishOptions {
                 publish_url,
                 trusted_publishing,
                 check_url,
             },
             add: AddOptions { add_bounds: bounds },
+            sync: SyncOptions { sync_exact },
             audit,
             workspace,
             sources,
@@ -2794,6 +2960,27 @@ pub struct AddOptions {
     pub add_bounds: Option<AddBoundsKind>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SyncOptions {
+    /// Whether `uv sync` should remove extraneous packages from the environment by default.
+    ///
+    /// When enabled (the default), `uv sync` removes any packages that are not present in the
+    /// lockfile, as if `--exact` were provided. When disabled, extraneous packages are retained
+    /// and listed in the output, as if `--inexact` were provided.
+    ///
+    /// The `--exact` and `--inexact` flags take precedence over this setting.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            sync-exact = false
+        "#
+    )]
+    pub sync_exact: Option<bool>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
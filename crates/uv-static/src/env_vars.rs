@@ -239,7 +239,10 @@ impl EnvVars {
     #[attr_added_in("0.3.3")]
     pub const UV_COMPILE_BYTECODE: &'static str = "UV_COMPILE_BYTECODE";
 
-    /// Timeout (in seconds) for bytecode compilation.
+    /// Timeout (in seconds) for bytecode compiling a single file.
+    ///
+    /// A file that exceeds the timeout is reported as a failure, and the worker that was
+    /// compiling it restarts its interpreter. Set to `0` to disable the timeout.
     #[attr_added_in("0.7.22")]
     pub const UV_COMPILE_BYTECODE_TIMEOUT: &'static str = "UV_COMPILE_BYTECODE_TIMEOUT";
 
@@ -664,6 +667,11 @@ impl EnvVars {
     #[attr_added_in("0.11.22")]
     pub const UV_INTERNAL__BUILD_DIR: &'static str = "UV_INTERNAL__BUILD_DIR";
 
+    /// Used to report the resource that the build sandbox denied to a failed PEP 517 build hook.
+    #[attr_hidden]
+    #[attr_added_in("0.11.27")]
+    pub const UV_INTERNAL__SANDBOX_REPORT: &'static str = "UV_INTERNAL__SANDBOX_REPORT";
+
     /// Used to force showing the derivation tree during resolver error reporting.
     #[attr_hidden]
     #[attr_added_in("0.3.0")]
@@ -937,6 +945,11 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const HOME: &'static str = "HOME";
 
+    /// The standard `TMPDIR` env var, used to direct sandboxed build backends to a private
+    /// temporary directory.
+    #[attr_added_in("0.11.27")]
+    pub const TMPDIR: &'static str = "TMPDIR";
+
     /// The standard `SHELL` posix env var.
     #[attr_added_in("0.1.16")]
     pub const SHELL: &'static str = "SHELL";
@@ -1374,6 +1387,11 @@ impl EnvVars {
     #[attr_added_in("0.6.9")]
     pub const UV_TORCH_BACKEND: &'static str = "UV_TORCH_BACKEND";
 
+    /// Equivalent to the `--python-platform` command-line argument for `uv pip compile` and
+    /// `uv pip install` (e.g., `x86_64-unknown-linux-gnu`).
+    #[attr_added_in("0.11.27")]
+    pub const UV_PYTHON_PLATFORM: &'static str = "UV_PYTHON_PLATFORM";
+
     /// Equivalent to the `--project` command-line argument.
     #[attr_added_in("0.4.4")]
     pub const UV_PROJECT: &'static str = "UV_PROJECT";
@@ -1497,6 +1515,11 @@ impl EnvVars {
     #[attr_added_in("0.9.15")]
     pub const UV_HIDE_BUILD_OUTPUT: &'static str = "UV_HIDE_BUILD_OUTPUT";
 
+    /// Suppress the notice printed when the running version of uv does not match the version
+    /// pinned in a `.uv-version` file.
+    #[attr_added_in("0.11.27")]
+    pub const UV_NO_UV_VERSION_NOTICE: &'static str = "UV_NO_UV_VERSION_NOTICE";
+
     /// The time in seconds uv waits for a file lock to become available.
     ///
     /// Defaults to 300s (5 min).
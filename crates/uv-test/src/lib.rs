@@ -1406,6 +1406,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv workspace check` command with options shared across scenarios.
+    pub fn workspace_check(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("check");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = self.new_command();
@@ -1641,6 +1649,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv config list` command.
+    pub fn config_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("config").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv config get` command.
+    pub fn config_get(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("config").arg("get");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
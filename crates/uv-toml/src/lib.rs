@@ -7,6 +7,10 @@ use toml_parser::lexer::Token;
 use toml_parser::parser::{EventReceiver, parse_document};
 use toml_parser::{ErrorSink, Source, Span};
 
+pub use crate::interpolate::*;
+
+mod interpolate;
+
 /// Deserialize a map while ensuring all keys are unique.
 pub fn deserialize_unique_map<'de, D, K, V, F>(
     deserializer: D,
@@ -2,7 +2,7 @@ use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
 
 use serde::Deserialize;
-use toml_edit::{Array, Item, Table, Value, value};
+use toml_edit::{Array, InlineTable, Item, Table, Value, value};
 
 use uv_configuration::ExcludeDependency;
 use uv_distribution_types::Requirement;
@@ -335,17 +335,8 @@ impl Tool {
             value(entrypoints)
         });
 
-        if self.options != ToolOptions::default() {
-            let serialized = serde::Serialize::serialize(
-                &ToolOptionsWire::from(self.options.clone()),
-                toml_edit::ser::ValueSerializer::new(),
-            )?;
-            let Value::InlineTable(serialized) = serialized else {
-                return Err(toml_edit::ser::Error::Custom(
-                    "Expected an inline table".to_string(),
-                ));
-            };
-            table.insert("options", Item::Table(serialized.into_table()));
+        if let Some(options) = self.options_to_toml()? {
+            table.insert("options", Item::Table(options.into_table()));
         }
 
         Ok(table)
@@ -382,6 +373,23 @@ impl Tool {
     pub fn options(&self) -> &ToolOptions {
         &self.options
     }
+
+    /// Returns the TOML representation of the options persisted for this tool, if any are set.
+    pub fn options_to_toml(&self) -> Result<Option<InlineTable>, toml_edit::ser::Error> {
+        if self.options == ToolOptions::default() {
+            return Ok(None);
+        }
+        let serialized = serde::Serialize::serialize(
+            &ToolOptionsWire::from(self.options.clone()),
+            toml_edit::ser::ValueSerializer::new(),
+        )?;
+        let Value::InlineTable(serialized) = serialized else {
+            return Err(toml_edit::ser::Error::Custom(
+                "Expected an inline table".to_string(),
+            ));
+        };
+        Ok(Some(serialized))
+    }
 }
 
 impl ToolEntrypoint {
@@ -114,6 +114,31 @@ impl HashStrategy {
         }
     }
 
+    /// Returns an error if a distribution for any of the given packages in the [`Resolution`]
+    /// lacks a hash.
+    pub fn require_package_hashes(
+        &self,
+        resolution: &Resolution,
+        packages: &[PackageName],
+    ) -> Result<(), HashStrategyError> {
+        if packages.is_empty() {
+            return Ok(());
+        }
+        for dist in resolution.distributions() {
+            if !packages.contains(dist.name()) {
+                continue;
+            }
+            let has_hashes = match self.get(dist) {
+                HashPolicy::Any(digests) | HashPolicy::All(digests) => !digests.is_empty(),
+                HashPolicy::None | HashPolicy::Generate(_) => false,
+            };
+            if !has_hashes {
+                return Err(HashStrategyError::MissingPackageHashes(dist.to_string()));
+            }
+        }
+        Ok(())
+    }
+
     /// Return a [`HashStrategy`] augmented with archive URL hashes discovered in additional
     /// requirements after the initial command-line parse.
     pub fn augment_with_requirements<'a>(
@@ -475,6 +500,10 @@ pub enum HashStrategyError {
     UnpinnedRequirement(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but none were provided for: {0}")]
     MissingHashes(String, HashCheckingMode),
+    #[error(
+        "A hash is required for `{0}` (as requested by `--require-hashes-package`), but none were provided"
+    )]
+    MissingPackageHashes(String),
     #[error(
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
@@ -35,12 +35,14 @@ use uv_pypi_types::{
     VerbatimParsedUrl,
 };
 use uv_redacted::DisplaySafeUrl;
-use uv_toml::deserialize_unique_map;
+use uv_toml::{InterpolationError, deserialize_unique_map, interpolate_document};
 
 #[derive(Error, Debug)]
 pub enum PyprojectTomlError {
     #[error(transparent)]
     Toml(#[from] toml::de::Error),
+    #[error(transparent)]
+    Interpolation(#[from] InterpolationError),
     #[error("Failed to parse `tool.uv.sources`")]
     Source(
         #[from]
@@ -57,6 +59,29 @@ pub enum PyprojectTomlError {
     MissingVersion,
 }
 
+/// The settings of the `[tool.uv]` table and `uv.toml` files that support environment variable
+/// interpolation (e.g., `${UV_INDEX_TOKEN}`), as dotted keys in which `*` matches any key.
+pub const INTERPOLATED_KEYS: &[&str] = &[
+    "index-url",
+    "extra-index-url",
+    "find-links",
+    "keyring-provider",
+    "index.url",
+    "index.publish-url",
+    "publish-url",
+    "check-url",
+    "python-install-mirror",
+    "pypy-install-mirror",
+    "python-downloads-json-url",
+    "sources.*.url",
+    "sources.*.git",
+    "pip.index-url",
+    "pip.extra-index-url",
+    "pip.find-links",
+    "pip.keyring-provider",
+    "pip.index.url",
+];
+
 fn deserialize_optional_dependencies<'de, D, V>(
     deserializer: D,
 ) -> Result<Option<BTreeMap<ExtraName, V>>, D::Error>
@@ -94,8 +119,16 @@ impl PyProjectToml {
     /// Parse a `PyProjectToml` from a raw TOML string.
     #[instrument("toml::from_str workspace", skip_all, fields(path = %_path.as_ref().display()))]
     pub fn from_string(raw: String, _path: impl AsRef<Path>) -> Result<Self, PyprojectTomlError> {
-        let sources_wire =
-            toml::from_str::<PyProjectTomlSourcesWire>(&raw).map_err(PyprojectTomlError::Toml)?;
+        // Interpolate any environment variables referenced in the `[tool.uv]` table, in which case
+        // the interpolated document is deserialized in lieu of the raw document.
+        let document = interpolate_document(&raw, "tool.uv", INTERPOLATED_KEYS)?;
+
+        let sources_wire = if let Some(document) = &document {
+            toml::Value::Table(document.clone()).try_into::<PyProjectTomlSourcesWire>()
+        } else {
+            toml::from_str::<PyProjectTomlSourcesWire>(&raw)
+        }
+        .map_err(PyprojectTomlError::Toml)?;
         let sources = sources_wire
             .tool
             .and_then(|tool| tool.uv)
@@ -103,7 +136,12 @@ impl PyProjectToml {
             .map(ToolUvSources::try_from)
             .transpose()?;
 
-        let mut pyproject: Self = toml::from_str(&raw).map_err(PyprojectTomlError::Toml)?;
+        let mut pyproject: Self = if let Some(document) = document {
+            toml::Value::Table(document).try_into()
+        } else {
+            toml::from_str(&raw)
+        }
+        .map_err(PyprojectTomlError::Toml)?;
         if let Some(sources) = sources {
             let tool_uv = pyproject
                 .tool
@@ -196,6 +234,9 @@ pub struct Project {
     pub dependencies: Option<Vec<String>>,
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
+    /// The fields of the project that are provided by the build backend.
+    #[serde(default, skip_serializing)]
+    dynamic: Option<Vec<String>>,
 
     /// Used to determine whether a `gui-scripts` section is present.
     #[serde(default, skip_serializing)]
@@ -242,12 +283,22 @@ impl TryFrom<ProjectWire> for Project {
             requires_python: value.requires_python,
             dependencies: value.dependencies,
             optional_dependencies: value.optional_dependencies,
+            dynamic: value.dynamic,
             gui_scripts: value.gui_scripts,
             scripts: value.scripts,
         })
     }
 }
 
+impl Project {
+    /// Returns `true` if the given field is listed in `project.dynamic`.
+    pub fn is_dynamic(&self, field: &str) -> bool {
+        self.dynamic
+            .as_ref()
+            .is_some_and(|dynamic| dynamic.iter().any(|entry| entry == field))
+    }
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -697,6 +748,58 @@ pub struct ToolUv {
     )]
     pub(crate) conflicts: Option<SchemaConflicts>,
 
+    /// A policy for the licenses of the packages in the resolution, enforced during `uv lock`,
+    /// and by `uv pip install --check-licenses`.
+    ///
+    /// Each of `allow`, `deny`, and `warn` accepts a list of SPDX license identifiers or
+    /// expressions, which are compared against the `License-Expression` metadata of every
+    /// package in the resolution (excluding workspace members). If `allow` is non-empty, any
+    /// package whose license can't be satisfied by the allowed licenses is a violation; a
+    /// package whose license can't be satisfied without a denied license is a violation, too.
+    /// Licenses that can't be satisfied without a `warn` license are reported, but don't fail
+    /// the lock.
+    ///
+    /// Packages listed in `exceptions` are exempt from the policy. Packages with missing or
+    /// unparseable license metadata are reported as a warning, unless `unknown = "deny"`.
+    ///
+    /// !!! note
+    ///     uv will only read `license-policy` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members or `uv.toml` files.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(description = "A policy for the licenses of the packages in the resolution.")
+    )]
+    #[option(
+        default = r#"{}"#,
+        value_type = "dict",
+        example = r#"
+            # Reject copyleft licenses, and require an explicit exception for packages
+            # without license metadata.
+            license-policy = { deny = ["GPL-3.0-only", "AGPL-3.0-only"], exceptions = ["my-internal-package"], unknown = "deny" }
+        "#
+    )]
+    pub(crate) license_policy: Option<LicensePolicy>,
+
+    /// Whether to enforce the provenance recorded in the lockfile.
+    ///
+    /// The lockfile records the index that each registry package was resolved from, along with
+    /// the size and upload time of each of its artifacts. When enabled, `uv sync` verifies that
+    /// each registry package would still be resolved from its recorded index, and that the index
+    /// still serves the locked artifact, before installing it (including with `--frozen`), and
+    /// `uv export --format cyclonedx1.5` includes the recorded provenance as component properties.
+    ///
+    /// !!! note
+    ///     uv will only read `lock-provenance` from the `pyproject.toml` at the workspace root,
+    ///     and will ignore any declarations in other workspace members or `uv.toml` files.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            lock-provenance = true
+        "#
+    )]
+    pub(crate) lock_provenance: Option<bool>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -707,6 +810,44 @@ pub struct ToolUv {
     build_backend: Option<BuildBackendSettingsSchema>,
 }
 
+/// A policy for the licenses of the packages in a resolution, as declared in
+/// `tool.uv.license-policy`.
+#[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LicensePolicy {
+    /// SPDX license identifiers or expressions that are permitted. If non-empty, any other
+    /// license is a violation.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// SPDX license identifiers or expressions that are forbidden.
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// SPDX license identifiers or expressions that are permitted, but reported as a warning.
+    #[serde(default)]
+    pub warn: Vec<String>,
+    /// Packages that are exempt from the policy.
+    #[serde(default)]
+    pub exceptions: Vec<PackageName>,
+    /// How to treat packages with missing or unparseable license metadata.
+    #[serde(default)]
+    pub unknown: LicensePolicyAction,
+}
+
+/// The action to take when a package violates a [`LicensePolicy`].
+#[derive(Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum LicensePolicyAction {
+    /// Report the violation as a warning.
+    #[default]
+    Warn,
+    /// Fail with an error.
+    Deny,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -1234,6 +1375,23 @@ pub enum Source {
         extra: Option<ExtraName>,
         group: Option<GroupName>,
     },
+    /// A dependency resolved from a local directory of distributions (a "wheelhouse"), e.g.,
+    /// `torch = { find-links = "./wheels" }`.
+    ///
+    /// Unlike a global `--find-links` entry, the directory is only consulted for the named package.
+    FindLinks {
+        /// The path to the directory containing the distributions.
+        #[serde(rename = "find-links")]
+        find_links: PortablePathBuf,
+        #[serde(
+            skip_serializing_if = "uv_pep508::marker::ser::is_empty",
+            serialize_with = "uv_pep508::marker::ser::serialize",
+            default
+        )]
+        marker: MarkerTree,
+        extra: Option<ExtraName>,
+        group: Option<GroupName>,
+    },
     /// A dependency on another package in the workspace.
     Workspace {
         /// When set to `false`, the package will be fetched from the remote index, rather than
@@ -1273,6 +1431,7 @@ impl<'de> Deserialize<'de> for Source {
             editable: Option<bool>,
             package: Option<bool>,
             index: Option<IndexName>,
+            find_links: Option<PortablePathBuf>,
             workspace: Option<bool>,
             #[serde(
                 skip_serializing_if = "uv_pep508::marker::ser::is_empty",
@@ -1297,6 +1456,7 @@ impl<'de> Deserialize<'de> for Source {
             editable,
             package,
             index,
+            find_links,
             workspace,
             marker,
             extra,
@@ -1317,6 +1477,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `git` and `index`",
                 ));
             }
+            if find_links.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `git` and `find-links`",
+                ));
+            }
             if workspace.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `git` and `workspace`",
@@ -1384,6 +1549,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `index`",
                 ));
             }
+            if find_links.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `find-links`",
+                ));
+            }
             if workspace.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `workspace`",
@@ -1441,6 +1611,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `index`",
                 ));
             }
+            if find_links.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `find-links`",
+                ));
+            }
             if workspace.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `path` and `workspace`",
@@ -1496,6 +1671,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `workspace`",
                 ));
             }
+            if find_links.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `find-links`",
+                ));
+            }
             if git.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `git`",
@@ -1545,6 +1725,52 @@ impl<'de> Deserialize<'de> for Source {
             });
         }
 
+        // If the `find-links` field is set, we're dealing with a local wheelhouse source.
+        if let Some(find_links) = find_links {
+            if workspace.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `workspace`",
+                ));
+            }
+            if rev.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `rev`",
+                ));
+            }
+            if tag.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `tag`",
+                ));
+            }
+            if branch.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `branch`",
+                ));
+            }
+            if subdirectory.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `subdirectory`",
+                ));
+            }
+            if editable.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `editable`",
+                ));
+            }
+            if package.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `find-links` and `package`",
+                ));
+            }
+
+            return Ok(Self::FindLinks {
+                find_links,
+                marker,
+                extra,
+                group,
+            });
+        }
+
         // If the `workspace` field is set, we're dealing with a workspace source.
         if let Some(workspace) = workspace {
             if index.is_some() {
@@ -1552,6 +1778,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `workspace` and `index`",
                 ));
             }
+            if find_links.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `find-links`",
+                ));
+            }
             if git.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `workspace` and `git`",
@@ -1599,7 +1830,7 @@ impl<'de> Deserialize<'de> for Source {
 
         // If none of the fields are set, we're dealing with an error.
         Err(serde::de::Error::custom(
-            "expected one of `git`, `url`, `path`, `index`, or `workspace`",
+            "expected one of `git`, `url`, `path`, `index`, `find-links`, or `workspace`",
         ))
     }
 }
@@ -1908,6 +2139,7 @@ impl Source {
             Self::Url { marker, .. } => *marker,
             Self::Path { marker, .. } => *marker,
             Self::Registry { marker, .. } => *marker,
+            Self::FindLinks { marker, .. } => *marker,
             Self::Workspace { marker, .. } => *marker,
         }
     }
@@ -1919,6 +2151,7 @@ impl Source {
             Self::Url { extra, .. } => extra.as_ref(),
             Self::Path { extra, .. } => extra.as_ref(),
             Self::Registry { extra, .. } => extra.as_ref(),
+            Self::FindLinks { extra, .. } => extra.as_ref(),
             Self::Workspace { extra, .. } => extra.as_ref(),
         }
     }
@@ -1930,6 +2163,7 @@ impl Source {
             Self::Url { group, .. } => group.as_ref(),
             Self::Path { group, .. } => group.as_ref(),
             Self::Registry { group, .. } => group.as_ref(),
+            Self::FindLinks { group, .. } => group.as_ref(),
             Self::Workspace { group, .. } => group.as_ref(),
         }
     }
@@ -427,6 +427,28 @@ impl PyProjectTomlMut {
         Ok(edit)
     }
 
+    /// Adds a constraint to `tool.uv.constraint-dependencies`, replacing any existing constraint
+    /// on the same package.
+    pub fn add_constraint_dependency(&mut self, req: &Requirement) -> Result<ArrayEdit, Error> {
+        // Get or create `tool.uv.constraint-dependencies`.
+        let constraint_dependencies = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedSources)?
+            .entry("constraint-dependencies")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedDependencies)?;
+
+        add_dependency(req, constraint_dependencies, false, false)
+    }
+
     /// Add an [`Index`] to `tool.uv.index`.
     pub fn add_index(&mut self, index: &Index) -> Result<(), Error> {
         let size = self.doc.len();
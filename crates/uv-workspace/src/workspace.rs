@@ -6,11 +6,12 @@ use std::fmt;
 use std::fmt::Display;
 use std::hash::BuildHasherDefault;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use glob::{GlobError, PatternError, glob};
 use itertools::Itertools;
-use rustc_hash::{FxHashSet, FxHasher};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use tracing::{debug, trace, warn};
 
 use uv_cache::Cache;
@@ -27,8 +28,8 @@ use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    OverrideDependency, Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources,
-    ToolUvWorkspace,
+    LicensePolicy, OverrideDependency, Project, PyProjectToml, PyprojectTomlError, Source, Sources,
+    ToolUvSources, ToolUvWorkspace,
 };
 
 /// The workspace project environment selected by configuration and command-line options.
@@ -204,6 +205,10 @@ pub enum WorkspaceErrorKind {
         first: PathBuf,
         second: PathBuf,
     },
+    #[error("Workspace members form a dependency cycle: {}", display_cycle(_0))]
+    MemberCycle(Vec<PackageName>),
+    #[error("Found {} problems with workspace members:\n{}", _0.len(), display_problems(_0))]
+    InvalidMembers(Vec<Self>),
     #[error("pyproject.toml section is declared as dynamic, but must be static: `{0}`")]
     DynamicNotAllowed(&'static str),
     #[error(
@@ -228,6 +233,47 @@ pub enum WorkspaceErrorKind {
     Normalize(#[source] std::io::Error),
 }
 
+impl WorkspaceErrorKind {
+    /// Combine the problems found while discovering workspace members into a single error.
+    ///
+    /// Returns `None` if there are no problems. A single problem is returned as-is.
+    fn from_problems(mut problems: Vec<Self>) -> Option<Self> {
+        match problems.len() {
+            0 => None,
+            1 => problems.pop(),
+            _ => Some(Self::InvalidMembers(problems)),
+        }
+    }
+}
+
+/// Display a dependency cycle between workspace members, closing the cycle, e.g., `a -> b -> a`.
+fn display_cycle(cycle: &[PackageName]) -> String {
+    cycle
+        .iter()
+        .chain(cycle.first())
+        .map(|name| format!("`{name}`"))
+        .join(" -> ")
+}
+
+/// Display a list of workspace member problems, including their causes, as a bulleted list.
+fn display_problems(problems: &[WorkspaceErrorKind]) -> String {
+    problems
+        .iter()
+        .map(|problem| {
+            let mut message = format!("  - {problem}");
+            let mut source = problem.source();
+            while let Some(cause) = source {
+                for line in cause.to_string().lines() {
+                    message.push_str("\n    ");
+                    message.push_str(line);
+                }
+                source = cause.source();
+            }
+            message
+        })
+        .join("\n")
+}
+
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
 pub enum MemberDiscovery {
     /// Discover all workspace members.
@@ -713,6 +759,25 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the license policy for the workspace.
+    pub fn license_policy(&self) -> Option<&LicensePolicy> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.license_policy.as_ref())
+    }
+
+    /// Returns `true` if the workspace enforces the provenance recorded in the lockfile.
+    pub fn lock_provenance(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.lock_provenance)
+            .unwrap_or(false)
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Result<Conflicts, WorkspaceError> {
         let mut conflicting = Conflicts::empty();
@@ -965,6 +1030,95 @@ impl Workspace {
         &self.packages
     }
 
+    /// Returns the dependency cycles between workspace members, e.g., `a -> b -> a`.
+    ///
+    /// Only dependencies (including optional dependencies) with a `workspace = true` source are
+    /// considered. A member that depends on itself (e.g., to enable its own extras) is not
+    /// considered a cycle. Every elementary cycle is returned, rotated to start at its
+    /// lexicographically smallest member.
+    pub fn member_cycles(&self) -> Vec<Vec<PackageName>> {
+        // Build the graph of workspace dependencies between members.
+        let mut edges: BTreeMap<&PackageName, BTreeSet<&PackageName>> = BTreeMap::new();
+        for (name, member) in &self.packages {
+            let member_sources = member
+                .pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.sources.as_ref())
+                .map(ToolUvSources::inner);
+
+            let requirements = member.project.dependencies.iter().flatten().chain(
+                member
+                    .project
+                    .optional_dependencies
+                    .iter()
+                    .flat_map(|optional_dependencies| optional_dependencies.values().flatten()),
+            );
+            for requirement in requirements {
+                let Ok(requirement) =
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                else {
+                    continue;
+                };
+                if requirement.name == *name {
+                    continue;
+                }
+                let Some((dependency, _)) = self.packages.get_key_value(&requirement.name) else {
+                    continue;
+                };
+
+                // Member-level sources take precedence over workspace-level sources.
+                let is_workspace = member_sources
+                    .and_then(|sources| sources.get(dependency))
+                    .or_else(|| self.sources.get(dependency))
+                    .is_some_and(|sources| {
+                        sources.iter().any(|source| {
+                            matches!(
+                                source,
+                                Source::Workspace {
+                                    workspace: true,
+                                    ..
+                                }
+                            )
+                        })
+                    });
+                if is_workspace {
+                    edges.entry(name).or_default().insert(dependency);
+                }
+            }
+        }
+
+        // Every cycle lies within a strongly connected component, so search each component with
+        // more than one member for its elementary cycles.
+        let mut cycles = BTreeSet::new();
+        for component in strongly_connected_components(&edges) {
+            if component.len() < 2 {
+                continue;
+            }
+            for &start in &component {
+                let mut path = Vec::new();
+                find_cycles(start, start, &component, &edges, &mut path, &mut cycles);
+            }
+        }
+        cycles.into_iter().collect()
+    }
+
+    /// Return an error if the workspace members form any dependency cycles.
+    ///
+    /// All cycles are reported at once.
+    pub fn validate_member_cycles(&self) -> Result<(), WorkspaceError> {
+        let problems = self
+            .member_cycles()
+            .into_iter()
+            .map(WorkspaceErrorKind::MemberCycle)
+            .collect();
+        match WorkspaceErrorKind::from_problems(problems) {
+            Some(err) => Err(WorkspaceError::from(err)),
+            None => Ok(()),
+        }
+    }
+
     /// The sources table from the workspace `pyproject.toml`.
     pub fn sources(&self) -> &BTreeMap<PackageName, Sources> {
         &self.sources
@@ -1111,6 +1265,8 @@ impl Workspace {
         let mut workspace_members = BTreeMap::new();
         // Avoid reading a `pyproject.toml` more than once.
         let mut seen = FxHashSet::default();
+        // Collect problems with individual members, to report them all at once.
+        let mut problems = Vec::new();
 
         let external_cache_root = options
             .stop_discovery_at
@@ -1275,10 +1431,13 @@ impl Workspace {
                         return Err(err.into());
                     }
                 };
-                let pyproject_toml = PyProjectToml::from_string(contents, &pyproject_path)
-                    .map_err(|err| {
-                        WorkspaceErrorKind::Toml(pyproject_path.clone(), Box::new(err))
-                    })?;
+                let pyproject_toml = match PyProjectToml::from_string(contents, &pyproject_path) {
+                    Ok(pyproject_toml) => pyproject_toml,
+                    Err(err) => {
+                        problems.push(WorkspaceErrorKind::Toml(pyproject_path, Box::new(err)));
+                        continue;
+                    }
+                };
 
                 // Check if the current project is explicitly marked as unmanaged.
                 if pyproject_toml
@@ -1322,15 +1481,19 @@ impl Workspace {
                         pyproject_toml,
                     },
                 ) {
-                    return Err(WorkspaceError::from(WorkspaceErrorKind::DuplicatePackage {
+                    problems.push(WorkspaceErrorKind::DuplicatePackage {
                         name: existing.project.name,
                         first: existing.root.clone(),
                         second: member_root,
-                    }));
+                    });
                 }
             }
         }
 
+        if let Some(err) = WorkspaceErrorKind::from_problems(problems) {
+            return Err(WorkspaceError::from(err));
+        }
+
         // Test for nested workspaces.
         for member in workspace_members.values() {
             if member.root() != workspace_root
@@ -1351,6 +1514,96 @@ impl Workspace {
     }
 }
 
+/// Return the strongly connected components of the dependency graph, using Tarjan's algorithm.
+fn strongly_connected_components<'a>(
+    edges: &BTreeMap<&'a PackageName, BTreeSet<&'a PackageName>>,
+) -> Vec<BTreeSet<&'a PackageName>> {
+    struct Tarjan<'a, 'graph> {
+        edges: &'graph BTreeMap<&'a PackageName, BTreeSet<&'a PackageName>>,
+        index: FxHashMap<&'a PackageName, usize>,
+        low_link: FxHashMap<&'a PackageName, usize>,
+        stack: Vec<&'a PackageName>,
+        on_stack: FxHashSet<&'a PackageName>,
+        components: Vec<BTreeSet<&'a PackageName>>,
+    }
+
+    impl<'a> Tarjan<'a, '_> {
+        fn visit(&mut self, name: &'a PackageName) {
+            let index = self.index.len();
+            self.index.insert(name, index);
+            self.low_link.insert(name, index);
+            self.stack.push(name);
+            self.on_stack.insert(name);
+
+            let edges = self.edges;
+            for &dependency in edges.get(name).into_iter().flatten() {
+                if !self.index.contains_key(dependency) {
+                    self.visit(dependency);
+                    let low_link = self.low_link[name].min(self.low_link[dependency]);
+                    self.low_link.insert(name, low_link);
+                } else if self.on_stack.contains(dependency) {
+                    let low_link = self.low_link[name].min(self.index[dependency]);
+                    self.low_link.insert(name, low_link);
+                }
+            }
+
+            // If `name` is the root of a component, pop the component off the stack.
+            if self.low_link[name] == index {
+                let mut component = BTreeSet::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.insert(member);
+                    if member == name {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        edges,
+        index: FxHashMap::default(),
+        low_link: FxHashMap::default(),
+        stack: Vec::new(),
+        on_stack: FxHashSet::default(),
+        components: Vec::new(),
+    };
+    for name in edges.keys() {
+        if !tarjan.index.contains_key(name) {
+            tarjan.visit(name);
+        }
+    }
+    tarjan.components
+}
+
+/// Record every elementary cycle through `start` within a strongly connected component.
+///
+/// Only members that sort after `start` are visited, such that each cycle is recorded once,
+/// starting at its smallest member.
+fn find_cycles<'a>(
+    start: &'a PackageName,
+    name: &'a PackageName,
+    component: &BTreeSet<&'a PackageName>,
+    edges: &BTreeMap<&'a PackageName, BTreeSet<&'a PackageName>>,
+    path: &mut Vec<&'a PackageName>,
+    cycles: &mut BTreeSet<Vec<PackageName>>,
+) {
+    path.push(name);
+    for &dependency in edges.get(name).into_iter().flatten() {
+        if dependency == start {
+            cycles.insert(path.iter().map(|entry| (*entry).clone()).collect());
+        } else if dependency > start
+            && component.contains(dependency)
+            && !path.contains(&dependency)
+        {
+            find_cycles(start, dependency, component, edges, path, cycles);
+        }
+    }
+    path.pop();
+}
+
 /// A project in a workspace.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(test, derive(serde::Serialize))]
@@ -2239,6 +2492,7 @@ mod tests {
     use assert_fs::fixture::ChildPath;
     use assert_fs::prelude::*;
     use insta::{assert_json_snapshot, assert_snapshot};
+    use itertools::Itertools;
 
     use uv_cache::Cache;
     use uv_normalize::{GroupName, PackageName};
@@ -2511,6 +2765,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -2612,6 +2868,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -2947,6 +3205,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -3057,6 +3317,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -3180,6 +3442,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -3277,6 +3541,8 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
+                      "lock-provenance": null,
                       "build-backend": null
                     }
                   },
@@ -3474,4 +3740,71 @@ foo_bar = ["iniconfig"]
 
         Ok(())
     }
+
+    /// A cycle through a member that was already visited via another path is still reported.
+    #[tokio::test]
+    async fn member_cycles_through_shared_member() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        // `a` depends on `b` and `c`, `b` depends on `c`, and `c` depends on `a`, so `c` is
+        // visited via `b` before the direct `a -> c` dependency is considered.
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "a"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["b", "c"]
+
+            [tool.uv.sources]
+            b = { workspace = true }
+            c = { workspace = true }
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+            "#,
+        )?;
+        root.child("packages")
+            .child("b")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "b"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["c"]
+
+            [tool.uv.sources]
+            c = { workspace = true }
+            "#,
+            )?;
+        root.child("packages")
+            .child("c")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+            [project]
+            name = "c"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["a"]
+
+            [tool.uv.sources]
+            a = { workspace = true }
+            "#,
+            )?;
+
+        let (project, _) = temporary_test(root.as_ref()).await.unwrap();
+        let cycles = project
+            .workspace()
+            .member_cycles()
+            .into_iter()
+            .map(|cycle| cycle.iter().join(" -> "))
+            .collect::<Vec<_>>();
+        assert_eq!(cycles, ["a -> b -> c", "a -> c"]);
+
+        Ok(())
+    }
 }
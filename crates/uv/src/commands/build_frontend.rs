@@ -229,6 +229,8 @@ async fn build_impl(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        prefetch_limit: _,
+        resolution_cache: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
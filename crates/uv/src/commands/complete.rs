@@ -0,0 +1,328 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::{Command, CommandFactory};
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cli::Cli;
+use uv_distribution_types::{IndexUrl, PYPI_URL};
+use uv_normalize::PackageName;
+use uv_pep508::VerbatimUrl;
+use uv_pypi_types::DependencyGroupSpecifier;
+use uv_python::downloads::{ManagedPythonDownloadList, PythonDownloadRequest};
+use uv_python::{ImplementationName, LenientImplementationName, PythonRequest};
+use uv_workspace::pyproject::PyProjectToml;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The values that can be completed dynamically.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CompletionKind {
+    /// The direct dependencies of the current project.
+    Dependency,
+    /// The dependency groups declared by the current project.
+    Group,
+    /// The extras declared by the current project.
+    Extra,
+    /// The Python versions available for download.
+    PythonVersion,
+    /// The names of packages in the local cache of the PyPI index.
+    PackageName,
+}
+
+impl CompletionKind {
+    /// Determine what should be completed, given the words preceding the word being completed.
+    fn from_words(preceding: &[String]) -> Option<Self> {
+        // Shells like Bash split `--group=dev` into `--group`, `=`, and `dev`.
+        let preceding = match preceding {
+            [rest @ .., equals] if equals == "=" => rest,
+            _ => preceding,
+        };
+
+        let mut command = Cli::command().ignore_errors(true);
+        let matches = match command.try_get_matches_from_mut(preceding) {
+            Ok(matches) => matches,
+            Err(err) => {
+                debug!("Failed to parse the command line for completion: {err}");
+                return None;
+            }
+        };
+
+        // Find the innermost subcommand, and the chain of commands leading to it.
+        let mut path = Vec::new();
+        let mut commands = vec![&command];
+        let mut matches = &matches;
+        while let Some((name, sub_matches)) = matches.subcommand() {
+            let Some(subcommand) = commands
+                .last()
+                .and_then(|command| command.find_subcommand(name))
+            else {
+                break;
+            };
+            path.push(name);
+            commands.push(subcommand);
+            matches = sub_matches;
+        }
+
+        // If the previous word is an option, complete its value.
+        if let Some(previous) = preceding.last().filter(|word| word.starts_with('-')) {
+            match previous.as_str() {
+                "--group" | "--only-group" | "--no-group" => return Some(Self::Group),
+                "--extra" | "--only-extra" | "--no-extra" => return Some(Self::Extra),
+                _ if takes_value(&commands, previous) => return None,
+                _ => {}
+            }
+        }
+
+        // Otherwise, complete the positional arguments of the subcommand.
+        match path.as_slice() {
+            ["remove"] => Some(Self::Dependency),
+            ["add"] => Some(Self::PackageName),
+            ["python", "install"] => Some(Self::PythonVersion),
+            _ => None,
+        }
+    }
+
+    /// Return the candidates for this kind of value.
+    fn candidates(self, project_dir: &Path, cache: &Cache) -> BTreeSet<String> {
+        match self {
+            Self::Dependency => ProjectNames::find(project_dir)
+                .map(|names| names.dependencies)
+                .unwrap_or_default(),
+            Self::Group => ProjectNames::find(project_dir)
+                .map(|names| names.groups)
+                .unwrap_or_default(),
+            Self::Extra => ProjectNames::find(project_dir)
+                .map(|names| names.extras)
+                .unwrap_or_default(),
+            Self::PythonVersion => python_versions(),
+            Self::PackageName => cached_package_names(cache),
+        }
+    }
+}
+
+/// Returns `true` if the given short or long option takes a value in any of the given commands.
+fn takes_value(commands: &[&Command], word: &str) -> bool {
+    commands.iter().any(|command| {
+        command.get_arguments().any(|arg| {
+            let matches = if let Some(long) = word.strip_prefix("--") {
+                arg.get_long() == Some(long)
+                    || arg
+                        .get_all_aliases()
+                        .is_some_and(|aliases| aliases.contains(&long))
+            } else if let Some(short) = word.strip_prefix('-') {
+                short.len() == 1 && arg.get_short().is_some_and(|c| short.starts_with(c))
+            } else {
+                false
+            };
+            matches && arg.get_action().takes_values()
+        })
+    })
+}
+
+/// The names declared by the nearest `pyproject.toml`.
+#[derive(Debug, Default)]
+struct ProjectNames {
+    dependencies: BTreeSet<String>,
+    groups: BTreeSet<String>,
+    extras: BTreeSet<String>,
+}
+
+impl ProjectNames {
+    /// Read the names from the nearest `pyproject.toml` in the given directory or its parents.
+    fn find(project_dir: &Path) -> Option<Self> {
+        let path = project_dir
+            .ancestors()
+            .map(|directory| directory.join("pyproject.toml"))
+            .find(|path| path.is_file())?;
+        let contents = fs_err::read_to_string(&path).ok()?;
+        let pyproject = PyProjectToml::from_string(contents, &path).ok()?;
+
+        let mut names = Self::default();
+        let mut requirements = Vec::new();
+
+        if let Some(project) = &pyproject.project {
+            requirements.extend(project.dependencies.iter().flatten());
+            for (extra, extra_requirements) in project.optional_dependencies.iter().flatten() {
+                names.extras.insert(extra.to_string());
+                requirements.extend(extra_requirements);
+            }
+        }
+
+        for (group, specifiers) in pyproject
+            .dependency_groups
+            .iter()
+            .flat_map(|groups| groups.iter())
+        {
+            names.groups.insert(group.to_string());
+            requirements.extend(specifiers.iter().filter_map(|specifier| match specifier {
+                DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                _ => None,
+            }));
+        }
+
+        if let Some(dev_dependencies) = pyproject
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.dev_dependencies.as_ref())
+        {
+            names.groups.insert("dev".to_string());
+            names.dependencies.extend(
+                dev_dependencies
+                    .iter()
+                    .map(|requirement| requirement.name.to_string()),
+            );
+        }
+
+        names
+            .dependencies
+            .extend(requirements.into_iter().filter_map(|requirement| {
+                uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement)
+                    .ok()
+                    .map(|requirement| requirement.name.to_string())
+            }));
+
+        Some(names)
+    }
+}
+
+/// Return the CPython versions available for download on the current platform.
+fn python_versions() -> BTreeSet<String> {
+    let Ok(download_list) = ManagedPythonDownloadList::new_only_embedded() else {
+        return BTreeSet::new();
+    };
+    let Some(request) = PythonDownloadRequest::from_request(&PythonRequest::Any)
+        .and_then(|request| request.fill_platform().ok())
+        .map(|request| request.with_prereleases(true))
+    else {
+        return BTreeSet::new();
+    };
+
+    let mut versions = BTreeSet::new();
+    for download in download_list.iter_matching(&request) {
+        let key = download.key();
+        if key.variant().is_debug()
+            || *key.implementation()
+                != LenientImplementationName::Known(ImplementationName::CPython)
+        {
+            continue;
+        }
+        versions.insert(format!("{}.{}", key.major(), key.minor()));
+        versions.insert(key.version().to_string());
+    }
+    versions
+}
+
+/// Return the names of the packages with cached PyPI index metadata.
+///
+/// This is a proxy for the packages the user is likely to add, without requiring network access.
+fn cached_package_names(cache: &Cache) -> BTreeSet<String> {
+    let pypi = IndexUrl::Pypi(Arc::new(VerbatimUrl::from_url(PYPI_URL.clone())));
+    let directory = cache
+        .bucket(CacheBucket::Simple)
+        .join(WheelCache::Index(&pypi).root());
+    let Ok(entries) = fs_err::read_dir(directory) else {
+        return BTreeSet::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().is_none_or(|extension| extension != "rkyv") {
+                return None;
+            }
+            let stem = path.file_stem()?.to_str()?;
+            PackageName::from_str(stem)
+                .ok()
+                .map(|name| name.to_string())
+        })
+        .collect()
+}
+
+/// Print the dynamic completion candidates for the last of the given words.
+///
+/// Nothing is printed if the word can't be completed dynamically, in which case the shell falls
+/// back to the static completions.
+pub(crate) fn complete(
+    words: &[String],
+    project_dir: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let Some((current, preceding)) = words.split_last() else {
+        return Ok(ExitStatus::Success);
+    };
+
+    // Leave options to the static completions.
+    if current.starts_with('-') {
+        return Ok(ExitStatus::Success);
+    }
+
+    let Some(kind) = CompletionKind::from_words(preceding) else {
+        return Ok(ExitStatus::Success);
+    };
+    debug!("Completing `{current}` as {kind:?}");
+
+    for candidate in kind.candidates(project_dir, cache) {
+        if candidate.starts_with(current.as_str()) {
+            writeln!(printer.stdout(), "{candidate}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the script that wires the dynamic completions into the given shell, to be appended to
+/// the static completion script.
+pub(crate) fn dynamic_completion_script(
+    shell: &clap_complete_command::Shell,
+) -> Option<&'static str> {
+    match shell {
+        clap_complete_command::Shell::Bash => Some(BASH_DYNAMIC_COMPLETION),
+        clap_complete_command::Shell::Zsh => Some(ZSH_DYNAMIC_COMPLETION),
+        clap_complete_command::Shell::Fish => Some(FISH_DYNAMIC_COMPLETION),
+        _ => None,
+    }
+}
+
+const BASH_DYNAMIC_COMPLETION: &str = r#"
+_uv_dynamic() {
+    local IFS=$'\n'
+    local candidates
+    candidates=($("${COMP_WORDS[0]}" __complete -- "${COMP_WORDS[@]:0:COMP_CWORD+1}" 2>/dev/null))
+    if [[ ${#candidates[@]} -gt 0 ]]; then
+        COMPREPLY=("${candidates[@]}")
+        return 0
+    fi
+    _uv "$@"
+}
+
+complete -F _uv_dynamic -o bashdefault -o default uv
+"#;
+
+const ZSH_DYNAMIC_COMPLETION: &str = r#"
+_uv_dynamic() {
+    local -a candidates
+    candidates=("${(@f)$("${words[1]}" __complete -- "${(@)words[1,CURRENT]}" 2>/dev/null)}")
+    candidates=("${(@)candidates:#}")
+    if (( ${#candidates} )); then
+        compadd -a candidates
+        return 0
+    fi
+    _uv "$@"
+}
+
+compdef _uv_dynamic uv
+"#;
+
+const FISH_DYNAMIC_COMPLETION: &str = r"
+complete -c uv -a '(uv __complete -- (commandline -opc) (commandline -ct) 2>/dev/null)'
+";
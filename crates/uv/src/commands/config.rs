@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command, CommandFactory};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_cli::{Cli, ConfigFormat};
+use uv_settings::{CommandLineSetting, ConfigurationFile, ResolvedSetting, SettingSources};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the effective value of every setting.
+pub(crate) async fn config_list(
+    show_origin: bool,
+    format: ConfigFormat,
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    matches: &ArgMatches,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let sources = setting_sources(project_dir, config_file, no_config, matches, cache).await?;
+    let settings = sources
+        .resolve_all()
+        .into_iter()
+        .filter(|setting| setting.value.is_some())
+        .collect::<Vec<_>>();
+
+    match format {
+        ConfigFormat::Text => {
+            for setting in &settings {
+                write_setting(setting, show_origin, printer)?;
+            }
+        }
+        ConfigFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&settings)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Show the effective value of a single setting.
+pub(crate) async fn config_get(
+    key: &str,
+    show_origin: bool,
+    format: ConfigFormat,
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    matches: &ArgMatches,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let sources = setting_sources(project_dir, config_file, no_config, matches, cache).await?;
+    let Some(setting) = sources.resolve(key) else {
+        bail!("Unknown setting `{key}`");
+    };
+
+    match format {
+        ConfigFormat::Text => {
+            if setting.value.is_none() {
+                bail!("The setting `{key}` is not set");
+            }
+            write_setting(&setting, show_origin, printer)?;
+        }
+        ConfigFormat::Json => {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&setting)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write a setting as a `key = value` line, optionally followed by its origins.
+fn write_setting(setting: &ResolvedSetting, show_origin: bool, printer: Printer) -> Result<()> {
+    let Some(value) = &setting.value else {
+        return Ok(());
+    };
+    if show_origin {
+        let origins = setting.origins.iter().join(", ");
+        writeln!(
+            printer.stdout(),
+            "{} = {value}  {}",
+            setting.key,
+            format!("# {origins}").dimmed()
+        )?;
+    } else {
+        writeln!(printer.stdout(), "{} = {value}", setting.key)?;
+    }
+    Ok(())
+}
+
+/// Collect the sources of settings, following the same discovery rules as other commands.
+async fn setting_sources(
+    project_dir: &Path,
+    config_file: Option<&Path>,
+    no_config: bool,
+    matches: &ArgMatches,
+    cache: &Cache,
+) -> Result<SettingSources> {
+    let files = if let Some(config_file) = config_file {
+        vec![ConfigurationFile::from_file(config_file)?]
+    } else if no_config {
+        Vec::new()
+    } else {
+        // Read the project configuration from the workspace root, if any.
+        let root = Workspace::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            cache,
+            &WorkspaceCache::default(),
+        )
+        .await
+        .map(|workspace| workspace.install_path().clone())
+        .unwrap_or_else(|_| project_dir.to_path_buf());
+        [
+            ConfigurationFile::find(&root)?,
+            ConfigurationFile::user()?,
+            ConfigurationFile::system()?,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    };
+
+    let command = Cli::command();
+    Ok(SettingSources::new(
+        command_line_settings(&command, matches),
+        environment_variables(&command),
+        files,
+    ))
+}
+
+/// Return the settings provided via global flags on the command line.
+fn command_line_settings(command: &Command, matches: &ArgMatches) -> Vec<CommandLineSetting> {
+    // Global arguments are propagated down to the innermost subcommand.
+    let mut matches = matches;
+    while let Some((_, sub_matches)) = matches.subcommand() {
+        matches = sub_matches;
+    }
+
+    command
+        .get_arguments()
+        .filter(|arg| arg.is_global_set())
+        .filter_map(|arg| {
+            let long = arg.get_long()?;
+            let id = arg.get_id().as_str();
+            if matches.value_source(id) != Some(ValueSource::CommandLine) {
+                return None;
+            }
+            let values = if arg.get_action().takes_values() {
+                matches
+                    .try_get_raw(id)
+                    .ok()
+                    .flatten()?
+                    .map(|value| value.to_string_lossy().into_owned())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            Some(CommandLineSetting {
+                key: long.to_string(),
+                flag: format!("--{long}"),
+                values,
+            })
+        })
+        .collect()
+}
+
+/// Return the environment variable for each setting, as declared by the corresponding arguments
+/// (e.g., `UV_SYSTEM_PYTHON` for `--system`).
+///
+/// Variables are keyed by the long name of the argument, and by the name of the variable itself
+/// (e.g., `python-install-mirror` for `UV_PYTHON_INSTALL_MIRROR`), for settings that are exposed
+/// under a different flag.
+fn environment_variables(command: &Command) -> BTreeMap<String, String> {
+    fn collect(
+        command: &Command,
+        flags: &mut BTreeMap<String, String>,
+        names: &mut BTreeMap<String, String>,
+    ) {
+        for arg in command.get_arguments() {
+            let Some(env) = arg.get_env().and_then(|env| env.to_str()) else {
+                continue;
+            };
+            if let Some(long) = arg.get_long() {
+                flags
+                    .entry(long.to_string())
+                    .or_insert_with(|| env.to_string());
+            }
+            if let Some(name) = env.strip_prefix("UV_") {
+                names
+                    .entry(name.to_ascii_lowercase().replace('_', "-"))
+                    .or_insert_with(|| env.to_string());
+            }
+        }
+        for subcommand in command.get_subcommands() {
+            collect(subcommand, flags, names);
+        }
+    }
+
+    let mut flags = BTreeMap::new();
+    let mut names = BTreeMap::new();
+    collect(command, &mut flags, &mut names);
+    for (name, env) in names {
+        flags.entry(name).or_insert(env);
+    }
+    flags
+}
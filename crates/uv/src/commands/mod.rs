@@ -7,6 +7,7 @@ use std::{fmt::Write, process::ExitCode};
 use anstream::AutoStream;
 use anyhow::{Context, bail};
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
 use tracing::debug;
 use uv_warnings::warn_user;
 
@@ -20,9 +21,11 @@ pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use complete::{complete, dynamic_completion_script};
+pub(crate) use config::{config_get, config_list};
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
-pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::compile::{SplitExtras, pip_compile};
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
@@ -35,7 +38,7 @@ pub(crate) use project::audit::audit;
 pub(crate) use project::check::check;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
-pub(crate) use project::init::{InitKind, InitProjectKind, init};
+pub(crate) use project::init::{InitKind, InitProjectKind, InitScriptOptions, init};
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{ParsedRunCommand, RunCommand, run};
@@ -51,6 +54,7 @@ pub(crate) use python::install::install as python_install;
 pub(crate) use python::install::{PythonUpgrade, PythonUpgradeSource};
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::shims::list as python_shims_list;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
@@ -66,11 +70,13 @@ pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
 use uv_configuration::Concurrency;
 pub(crate) use uv_console::human_readable_bytes;
-use uv_fs::{CWD, Simplified};
-use uv_installer::compile_tree;
+use uv_fs::{CWD, Simplified, normalize_path_under};
+use uv_installer::{SitePackages, compile_files, compile_tree};
+use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
+pub(crate) use workspace::check::check as workspace_check;
 pub(crate) use workspace::dir::dir;
 pub(crate) use workspace::list::list;
 pub(crate) use workspace::metadata::metadata;
@@ -85,6 +91,8 @@ mod cache_clean;
 mod cache_dir;
 mod cache_prune;
 mod cache_size;
+mod complete;
+mod config;
 pub(crate) mod diagnostics;
 mod editable;
 mod help;
@@ -94,9 +102,11 @@ mod publish;
 mod pylock;
 mod python;
 pub(crate) mod reporters;
+mod resolution_cache;
 #[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
+mod uv_lock;
 mod venv;
 mod workspace;
 
@@ -249,15 +259,22 @@ pub(super) struct ChangeEvent<'a> {
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
-/// See the `--compile` option on `pip sync` and `pip install`.
+/// If `packages` is provided, only the source files belonging to the given packages are compiled.
+///
+/// See the `--compile` and `--compile-package` options on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    packages: Option<&[PackageName]>,
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
+    let mut sources = packages
+        .map(|packages| package_sources(venv, packages))
+        .transpose()?;
     let mut files = 0;
+    let mut failures = Vec::new();
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
         if !site_packages.exists() {
@@ -267,19 +284,35 @@ pub(super) async fn compile_bytecode(
             );
             continue;
         }
-        files += compile_tree(
-            &site_packages,
-            venv.python_executable(),
-            concurrency,
-            cache.root(),
-        )
-        .await
+        let summary = if let Some(sources) = sources.as_mut() {
+            let Some(sources) = sources.remove(&site_packages) else {
+                continue;
+            };
+            compile_files(
+                &site_packages,
+                sources,
+                venv.python_executable(),
+                concurrency,
+                cache.root(),
+            )
+            .await
+        } else {
+            compile_tree(
+                &site_packages,
+                venv.python_executable(),
+                concurrency,
+                cache.root(),
+            )
+            .await
+        }
         .with_context(|| {
             format!(
                 "Failed to bytecode-compile Python file in: {}",
                 site_packages.user_display()
             )
         })?;
+        files += summary.files;
+        failures.extend(summary.failures);
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -292,9 +325,74 @@ pub(super) async fn compile_bytecode(
         )
         .dimmed()
     )?;
+    if !failures.is_empty() {
+        let s = if failures.len() == 1 { "" } else { "s" };
+        let details = failures
+            .iter()
+            .map(|failure| {
+                format!(
+                    "\n  {}: {}",
+                    failure.path.user_display().cyan(),
+                    failure.message
+                )
+            })
+            .collect::<String>();
+        warn_user!(
+            "Failed to bytecode-compile {} file{s}:{details}",
+            failures.len()
+        );
+    }
     Ok(())
 }
 
+/// Collect the Python source files of the given packages from their `RECORD` files, keyed by
+/// site-packages directory.
+fn package_sources(
+    venv: &PythonEnvironment,
+    packages: &[PackageName],
+) -> anyhow::Result<FxHashMap<PathBuf, Vec<PathBuf>>> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    let mut sources: FxHashMap<PathBuf, Vec<PathBuf>> = FxHashMap::default();
+    for package in packages {
+        let installed = site_packages.get_packages(package);
+        if installed.is_empty() {
+            warn_user!(
+                "Skipping bytecode compilation for `{}`, which is not installed",
+                package.cyan()
+            );
+            continue;
+        }
+        for dist in installed {
+            let Some(root) = dist.install_path().parent().map(|root| CWD.join(root)) else {
+                continue;
+            };
+            let record = match fs_err::File::open(dist.install_path().join("RECORD")) {
+                Ok(record) => uv_install_wheel::read_record(record)
+                    .with_context(|| format!("Failed to read `RECORD` for: {dist}"))?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    warn_user!(
+                        "Skipping bytecode compilation for `{}`, which is missing a `RECORD` file",
+                        dist.cyan()
+                    );
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let files = sources.entry(root.clone()).or_default();
+            for entry in record {
+                // Only compile files within site-packages, like pip.
+                let Some(path) = normalize_path_under(root.join(&entry.path), &root) else {
+                    continue;
+                };
+                if path.extension().is_some_and(|ext| ext == "py") && path.is_file() {
+                    files.push(path);
+                }
+            }
+        }
+    }
+    Ok(sources)
+}
+
 /// A multicasting writer that writes to both the standard output and an output file, if present.
 struct OutputWriter<'a> {
     stdout: Option<AutoStream<std::io::Stdout>>,
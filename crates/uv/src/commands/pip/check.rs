@@ -5,7 +5,7 @@ use anyhow::Result;
 use owo_colors::OwoColorize;
 
 use uv_cache::Cache;
-use uv_configuration::TargetTriple;
+use uv_configuration::{PlatformMarkerOverrides, TargetTriple};
 use uv_distribution_types::{DependencyMetadata, Diagnostic, InstalledDist};
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
 use uv_python::{
@@ -56,7 +56,12 @@ pub(crate) fn pip_check(
     )?;
 
     // Determine the markers and tags to use for resolution.
-    let markers = resolution_markers(python_version, python_platform, environment.interpreter());
+    let markers = resolution_markers(
+        python_version,
+        python_platform,
+        &PlatformMarkerOverrides::default(),
+        environment.interpreter(),
+    );
     let tags = resolution_tags(python_version, python_platform, environment.interpreter())?;
 
     // Run the diagnostics.
@@ -1,23 +1,26 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::ffi::OsStr;
-use std::io::Write;
-use std::path::Path;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{Result, anyhow};
+use clap::{Command, CommandFactory, ValueHint};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::Cli;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, ExcludeDependency, ExtrasSpecification,
     IndexStrategy, NoBinary, NoBuild, NoSources, Override, PipCompileFormat, Reinstall, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, PlatformMarkerOverrides, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
@@ -28,10 +31,10 @@ use uv_distribution_types::{
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_install_wheel::LinkMode;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
 use uv_preview::Preview;
-use uv_pypi_types::{Conflicts, SupportedEnvironments};
+use uv_pypi_types::{Conflicts, PyProjectToml, SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, VersionRequest,
@@ -54,7 +57,9 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    operations, resolution_markers, resolution_tags, resolution_torch_mode,
+};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
@@ -76,10 +81,12 @@ pub(crate) async fn pip_compile(
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
+    split_extras: Option<SplitExtras>,
     format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
+    prefetch_limit: Option<usize>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -111,6 +118,7 @@ pub(crate) async fn pip_compile(
     install_mirrors: PythonInstallMirrors,
     mut python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
     python_downloads: PythonDownloads,
     universal: bool,
     exclude_newer: ExcludeNewer,
@@ -139,6 +147,14 @@ pub(crate) async fn pip_compile(
         ));
     }
 
+    // When writing a separate file for each extra, the output files are determined by the
+    // template instead.
+    let output_file = if split_extras.is_some() {
+        None
+    } else {
+        output_file
+    };
+
     // Determine the output format.
     let format = format.unwrap_or_else(|| {
         let extension = output_file.and_then(Path::extension);
@@ -165,6 +181,18 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Determine the output files for the base dependencies and each extra, if requested.
+    let split_outputs = if let Some(split_extras) = split_extras.as_ref() {
+        if matches!(format, PipCompileFormat::PylockToml) {
+            return Err(anyhow!(
+                "`--split-extras` is not supported for `pylock.toml` output"
+            ));
+        }
+        Some(split_extras.outputs(requirements, &extras)?)
+    } else {
+        None
+    };
+
     // Respect `UV_PYTHON`
     if python.is_none() && python_version.is_none() {
         if let Ok(request) = std::env::var(EnvVars::UV_PYTHON) {
@@ -216,6 +244,7 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
+        build_constraints: _,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -405,6 +434,7 @@ pub(crate) async fn pip_compile(
         let marker_env = resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            platform_markers,
             &interpreter,
         );
         (Some(tags), ResolverEnvironment::specific(marker_env))
@@ -445,7 +475,7 @@ pub(crate) async fn pip_compile(
                 TorchSource::default()
             };
             TorchStrategy::from_mode(
-                mode,
+                resolution_torch_mode(mode, python_platform.as_ref()),
                 source,
                 python_platform
                     .map(TargetTriple::platform)
@@ -469,7 +499,19 @@ pub(crate) async fn pip_compile(
 
     // Read the lockfile, if present.
     let LockedRequirements { preferences, git } =
-        if let Some(output_file) = output_file.filter(|output_file| output_file.exists()) {
+        if let Some(split_outputs) = split_outputs.as_ref() {
+            // Prefer the versions pinned in any of the existing output files, which were derived from
+            // the same resolution.
+            let mut preferences = Vec::new();
+            for path in split_outputs
+                .iter()
+                .filter_map(|output| output.path.as_deref())
+                .filter(|path| path.exists())
+            {
+                preferences.extend(read_requirements_txt(path, &upgrade).await?);
+            }
+            LockedRequirements::from_preferences(preferences)
+        } else if let Some(output_file) = output_file.filter(|output_file| output_file.exists()) {
             match format {
                 PipCompileFormat::RequirementsTxt => LockedRequirements::from_preferences(
                     read_requirements_txt(output_file, &upgrade).await?,
@@ -558,6 +600,7 @@ pub(crate) async fn pip_compile(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .fork_strategy(fork_strategy)
+        .prefetch_limit(prefetch_limit)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
@@ -609,110 +652,121 @@ pub(crate) async fn pip_compile(
         }
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
+    // Determine the files to which the resolution should be written.
+    let split = split_outputs.is_some();
+    let outputs = split_outputs.unwrap_or_else(|| {
+        vec![CompileOutput {
+            path: output_file.map(Path::to_path_buf),
+            roots: None,
+        }]
+    });
 
-    if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
+    for output in &outputs {
+        // Write the resolved dependencies to the output channel.
+        let mut writer = OutputWriter::new(
+            !split && (!quiet || output.path.is_none()),
+            output.path.as_deref(),
+        );
+
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
                 )
-            )
-            .green()
-        )?;
-    }
+                .green()
+            )?;
+        }
 
-    match format {
-        PipCompileFormat::RequirementsTxt => {
-            if include_marker_expression {
-                if let Some(marker_env) = resolver_env.marker_environment() {
-                    let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
-                    if let Some(relevant_markers) = relevant_markers.contents() {
-                        writeln!(
-                            writer,
-                            "{}",
-                            "# Pinned dependencies known to be valid for:".green()
-                        )?;
-                        writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+        match format {
+            PipCompileFormat::RequirementsTxt => {
+                if include_marker_expression {
+                    if let Some(marker_env) = resolver_env.marker_environment() {
+                        let relevant_markers =
+                            resolution.marker_tree(&top_level_index, marker_env)?;
+                        if let Some(relevant_markers) = relevant_markers.contents() {
+                            writeln!(
+                                writer,
+                                "{}",
+                                "# Pinned dependencies known to be valid for:".green()
+                            )?;
+                            writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+                        }
                     }
                 }
-            }
 
-            let mut wrote_preamble = false;
+                let mut wrote_preamble = false;
 
-            // If necessary, include the `--index-url` and `--extra-index-url` locations.
-            if include_index_url {
-                if let Some(index) = index_locations.default_index() {
-                    writeln!(writer, "--index-url {}", index.url().verbatim())?;
-                    wrote_preamble = true;
-                }
-                let mut seen = FxHashSet::default();
-                for extra_index in index_locations.implicit_indexes() {
-                    if seen.insert(extra_index.url()) {
-                        writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
+                // If necessary, include the `--index-url` and `--extra-index-url` locations.
+                if include_index_url {
+                    if let Some(index) = index_locations.default_index() {
+                        writeln!(writer, "--index-url {}", index.url().verbatim())?;
                         wrote_preamble = true;
                     }
+                    let mut seen = FxHashSet::default();
+                    for extra_index in index_locations.implicit_indexes() {
+                        if seen.insert(extra_index.url()) {
+                            writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
+                            wrote_preamble = true;
+                        }
+                    }
                 }
-            }
-
-            // If necessary, include the `--find-links` locations.
-            if include_find_links {
-                for flat_index in index_locations.flat_indexes() {
-                    writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-                    wrote_preamble = true;
-                }
-            }
 
-            // If necessary, include the `--no-binary` and `--only-binary` options.
-            if include_build_options {
-                match build_options.no_binary() {
-                    NoBinary::None => {}
-                    NoBinary::All => {
-                        writeln!(writer, "--no-binary :all:")?;
+                // If necessary, include the `--find-links` locations.
+                if include_find_links {
+                    for flat_index in index_locations.flat_indexes() {
+                        writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
                         wrote_preamble = true;
                     }
-                    NoBinary::Packages(packages) => {
-                        for package in packages {
-                            writeln!(writer, "--no-binary {package}")?;
+                }
+
+                // If necessary, include the `--no-binary` and `--only-binary` options.
+                if include_build_options {
+                    match build_options.no_binary() {
+                        NoBinary::None => {}
+                        NoBinary::All => {
+                            writeln!(writer, "--no-binary :all:")?;
                             wrote_preamble = true;
                         }
+                        NoBinary::Packages(packages) => {
+                            for package in packages {
+                                writeln!(writer, "--no-binary {package}")?;
+                                wrote_preamble = true;
+                            }
+                        }
                     }
-                }
-                match build_options.no_build() {
-                    NoBuild::None => {}
-                    NoBuild::All => {
-                        writeln!(writer, "--only-binary :all:")?;
-                        wrote_preamble = true;
-                    }
-                    NoBuild::Packages(packages) => {
-                        for package in packages {
-                            writeln!(writer, "--only-binary {package}")?;
+                    match build_options.no_build() {
+                        NoBuild::None => {}
+                        NoBuild::All => {
+                            writeln!(writer, "--only-binary :all:")?;
                             wrote_preamble = true;
                         }
+                        NoBuild::Packages(packages) => {
+                            for package in packages {
+                                writeln!(writer, "--only-binary {package}")?;
+                                wrote_preamble = true;
+                            }
+                        }
                     }
                 }
-            }
 
-            // If we wrote an index, add a newline to separate it from the requirements
-            if wrote_preamble {
-                writeln!(writer)?;
-            }
+                // If we wrote an index, add a newline to separate it from the requirements
+                if wrote_preamble {
+                    writeln!(writer)?;
+                }
 
-            write!(
-                writer,
-                "{}",
-                DisplayResolutionGraph::new(
+                let display = DisplayResolutionGraph::new(
                     &resolution,
                     &resolver_env,
                     &no_emit_packages,
@@ -722,82 +776,223 @@ pub(crate) async fn pip_compile(
                     include_annotations,
                     include_index_annotation,
                     annotation_style,
-                )
-            )?;
-        }
-        PipCompileFormat::PylockToml => {
-            if include_marker_expression {
-                warn_user!(
-                    "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_index_url {
-                warn_user!(
-                    "The `--emit-index-url` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_find_links {
-                warn_user!(
-                    "The `--emit-find-links` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_build_options {
-                warn_user!(
-                    "The `--emit-build-options` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_index_annotation {
-                warn_user!(
-                    "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
                 );
+                if let Some(roots) = output.roots.as_deref() {
+                    write!(writer, "{}", display.with_roots(roots))?;
+                } else {
+                    write!(writer, "{display}")?;
+                }
             }
+            PipCompileFormat::PylockToml => {
+                if include_marker_expression {
+                    warn_user!(
+                        "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_index_url {
+                    warn_user!(
+                        "The `--emit-index-url` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_find_links {
+                    warn_user!(
+                        "The `--emit-find-links` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_build_options {
+                    warn_user!(
+                        "The `--emit-build-options` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_index_annotation {
+                    warn_user!(
+                        "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
+                    );
+                }
 
-            // Determine the directory relative to which the output file should be written.
-            let output_file = output_file.map(std::path::absolute).transpose()?;
-            let install_path = if let Some(output_file) = output_file.as_deref() {
-                output_file.parent().unwrap()
-            } else {
-                &*CWD
-            };
+                // Determine the directory relative to which the output file should be written.
+                let output_file = output_file.map(std::path::absolute).transpose()?;
+                let install_path = if let Some(output_file) = output_file.as_deref() {
+                    output_file.parent().unwrap()
+                } else {
+                    &*CWD
+                };
+
+                // Convert the resolution to a `pylock.toml` file.
+                let export = PylockToml::from_resolution(
+                    &resolution,
+                    &no_emit_packages,
+                    install_path,
+                    tags.as_deref(),
+                    &build_options,
+                )?;
+                write!(writer, "{}", export.to_toml()?)?;
+            }
+        }
 
-            // Convert the resolution to a `pylock.toml` file.
-            let export = PylockToml::from_resolution(
-                &resolution,
-                &no_emit_packages,
-                install_path,
-                tags.as_deref(),
-                &build_options,
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following packages were excluded from the output:".green()
             )?;
-            write!(writer, "{}", export.to_toml()?)?;
+            for package in excluded {
+                writeln!(writer, "# {package}")?;
+            }
         }
+
+        // Commit the output to disk.
+        writer.commit().await?;
     }
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
-        .into_iter()
-        .filter(|name| resolution.contains(name))
-        .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
-        writeln!(
-            writer,
-            "{}",
-            "# The following packages were excluded from the output:".green()
-        )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
+    // If we wrote a separate file for each extra, list the files on stdout.
+    if split {
+        for path in outputs.iter().filter_map(|output| output.path.as_deref()) {
+            writeln!(printer.stdout(), "{}", path.user_display())?;
         }
     }
 
-    // Commit the output to disk.
-    writer.commit().await?;
-
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     Ok(ExitStatus::Success)
 }
 
+/// The options for writing a separate output file for the base dependencies and each extra.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitExtras {
+    /// The directory in which to write the output files.
+    pub(crate) output_dir: PathBuf,
+    /// The file name template, in which `{extra}` is replaced with the name of each extra.
+    pub(crate) template: String,
+}
+
+impl SplitExtras {
+    /// Determine the output files for the base dependencies and each requested extra of the
+    /// `pyproject.toml` provided as input.
+    fn outputs(
+        &self,
+        requirements: &[RequirementsSource],
+        extras: &ExtrasSpecification,
+    ) -> Result<Vec<CompileOutput>> {
+        if !self.template.contains("{extra}") {
+            return Err(anyhow!(
+                "The output template must contain an `{{extra}}` placeholder, but got: `{}`",
+                self.template
+            ));
+        }
+
+        // Read the static dependencies and optional dependencies from the `pyproject.toml`.
+        let [RequirementsSource::PyprojectToml(path)] = requirements else {
+            return Err(anyhow!(
+                "`--split-extras` requires a single `pyproject.toml` as input"
+            ));
+        };
+        let contents = fs_err::read_to_string(path)?;
+        let pyproject_toml = PyProjectToml::from_toml(&contents, path.user_display())?;
+        let Some(project) = pyproject_toml.project else {
+            return Err(anyhow!(
+                "`--split-extras` requires a `[project]` table in `{}`",
+                path.user_display()
+            ));
+        };
+        if project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "dependencies" || field == "optional-dependencies")
+        {
+            return Err(anyhow!(
+                "`--split-extras` requires static `dependencies` and `optional-dependencies` in `{}`",
+                path.user_display()
+            ));
+        }
+
+        let directory = path.parent().unwrap_or(&*CWD);
+        let parse = |requirements: Vec<String>| {
+            requirements
+                .iter()
+                .map(|requirement| {
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::parse(requirement, directory)
+                        .map(Requirement::from)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let base = parse(project.dependencies.unwrap_or_default())?;
+        let optional = project
+            .optional_dependencies
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(extra, requirements)| Ok((extra, parse(requirements)?)))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        // Collect the requirements for the base dependencies and the given extra, expanding any
+        // extras that the project requests from itself (e.g., `project[test]`).
+        let roots = |extra: Option<&ExtraName>| {
+            let mut roots = Vec::new();
+            let mut seen = FxHashSet::default();
+            let mut queue = vec![base.as_slice()];
+            if let Some(extra) = extra {
+                seen.insert(extra);
+                queue.extend(optional.get(extra).map(Vec::as_slice));
+            }
+            while let Some(requirements) = queue.pop() {
+                for requirement in requirements {
+                    if requirement.name != project.name {
+                        roots.push(requirement.clone());
+                        continue;
+                    }
+                    for extra in &requirement.extras {
+                        if seen.insert(extra) {
+                            queue.extend(optional.get(extra).map(Vec::as_slice));
+                        }
+                    }
+                }
+            }
+            roots
+        };
+
+        let mut outputs = vec![CompileOutput {
+            path: Some(self.path("base")),
+            roots: Some(roots(None)),
+        }];
+        for extra in extras.extra_names(optional.keys()) {
+            if extra.as_str() == "base" {
+                return Err(anyhow!(
+                    "`--split-extras` can't write a separate file for the extra `base`, as its name is reserved for the base dependencies"
+                ));
+            }
+            outputs.push(CompileOutput {
+                path: Some(self.path(extra.as_str())),
+                roots: Some(roots(Some(extra))),
+            });
+        }
+        Ok(outputs)
+    }
+
+    /// Return the path to the output file for the given extra.
+    fn path(&self, extra: &str) -> PathBuf {
+        self.output_dir
+            .join(self.template.replace("{extra}", extra))
+    }
+}
+
+/// A file to which the output of `uv pip compile` is written.
+#[derive(Debug)]
+struct CompileOutput {
+    /// The path to the output file, or `None` to write to stdout.
+    path: Option<PathBuf>,
+    /// The requirements from which the packages in the output must be reachable, or `None` to
+    /// include the entire resolution.
+    roots: Option<Vec<Requirement>>,
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd(
     include_index_url: bool,
@@ -809,12 +1004,48 @@ fn cmd(
     }
     let args = env::args_os()
         .skip(1)
-        .map(|arg| arg.to_string_lossy().to_string())
-        .scan(None, move |skip_next, arg| {
-            if matches!(skip_next, Some(true)) {
-                // Reset state; skip this iteration.
-                *skip_next = None;
-                return Some(None);
+        .map(|arg| arg.to_string_lossy().to_string());
+    compile_command(args, include_index_url, include_find_links, cfg!(windows))
+}
+
+/// How to treat the next argument when rendering the compile command.
+#[derive(Debug, Clone, Copy)]
+enum Next {
+    /// The next argument is a flag or a source file.
+    Arg,
+    /// The next argument is the value of an omitted flag, and should be skipped.
+    Skip,
+    /// The next argument is the path value of a flag.
+    Path,
+    /// The next argument is the (non-path) value of a flag.
+    Value,
+}
+
+/// Render the compile command from the given arguments (excluding the executable).
+///
+/// If `windows` is set, paths are rendered with forward slashes.
+fn compile_command(
+    args: impl IntoIterator<Item = String>,
+    include_index_url: bool,
+    include_find_links: bool,
+    windows: bool,
+) -> String {
+    let flags = value_flags();
+    let normalize = move |path: &str| {
+        if windows {
+            path.replace('\\', "/")
+        } else {
+            path.to_string()
+        }
+    };
+    let args = args
+        .into_iter()
+        .scan(Next::Arg, move |next, arg| {
+            match std::mem::replace(next, Next::Arg) {
+                Next::Skip => return Some(None),
+                Next::Path => return Some(Some(normalize(&arg))),
+                Next::Value => return Some(Some(arg)),
+                Next::Arg => {}
             }
 
             // Skip any index URLs, unless requested.
@@ -825,8 +1056,7 @@ fn cmd(
                     || arg.starts_with("--index=")
                     || arg.starts_with("--default-index=")
                 {
-                    // Reset state; skip this iteration.
-                    *skip_next = None;
+                    // Skip this iteration.
                     return Some(None);
                 }
 
@@ -837,7 +1067,7 @@ fn cmd(
                     || arg == "--index"
                     || arg == "--default-index"
                 {
-                    *skip_next = Some(true);
+                    *next = Next::Skip;
                     return Some(None);
                 }
             }
@@ -846,61 +1076,92 @@ fn cmd(
             if !include_find_links {
                 // Always skip the `--find-links` and mark the next item to be skipped
                 if arg == "--find-links" || arg == "-f" {
-                    *skip_next = Some(true);
+                    *next = Next::Skip;
                     return Some(None);
                 }
 
                 // Skip only this argument if option and value are together
                 if arg.starts_with("--find-links=") || arg.starts_with("-f") {
-                    // Reset state; skip this iteration.
-                    *skip_next = None;
+                    // Skip this iteration.
                     return Some(None);
                 }
             }
 
             // Always skip the `--upgrade` flag.
             if arg == "--upgrade" || arg == "-U" {
-                *skip_next = None;
                 return Some(None);
             }
 
             // Always skip the `--upgrade-package` and mark the next item to be skipped
             if arg == "--upgrade-package" || arg == "-P" {
-                *skip_next = Some(true);
+                *next = Next::Skip;
                 return Some(None);
             }
 
             // Skip only this argument if option and value are together
             if arg.starts_with("--upgrade-package=") || arg.starts_with("-P") {
-                // Reset state; skip this iteration.
-                *skip_next = None;
+                // Skip this iteration.
                 return Some(None);
             }
 
             // Always skip the `--quiet` flag.
             if arg == "--quiet" || arg == "-q" {
-                *skip_next = None;
                 return Some(None);
             }
 
             // Always skip the `--verbose` flag.
             if arg == "--verbose" || arg == "-v" {
-                *skip_next = None;
                 return Some(None);
             }
 
             // Always skip the `--no-progress` flag.
             if arg == "--no-progress" {
-                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--timings` flag.
+            if arg == "--timings" {
                 return Some(None);
             }
 
             // Always skip the `--native-tls` flag.
             if arg == "--native-tls" {
-                *skip_next = None;
                 return Some(None);
             }
 
+            // Render paths with forward slashes, such that the header is identical across
+            // platforms, regardless of whether the paths exist.
+            if arg.starts_with('-')
+                && let Some((flag, value)) = arg.split_once('=')
+            {
+                if flags.get(flag) == Some(&true) {
+                    return Some(Some(format!("{flag}={}", normalize(value))));
+                }
+                return Some(Some(arg));
+            }
+            match flags.get(arg.as_str()) {
+                Some(true) => {
+                    *next = Next::Path;
+                    return Some(Some(arg));
+                }
+                Some(false) => {
+                    *next = Next::Value;
+                    return Some(Some(arg));
+                }
+                None => {}
+            }
+            if !arg.starts_with("--")
+                && let Some(flag) = arg.get(..2)
+                && flags.get(flag) == Some(&true)
+            {
+                // Ex) `-orequirements.txt`
+                return Some(Some(format!("{flag}{}", normalize(&arg[2..]))));
+            }
+            if !arg.starts_with('-') {
+                // Ex) The source files, e.g., `requirements.in`.
+                return Some(Some(normalize(&arg)));
+            }
+
             // Return the argument.
             Some(Some(arg))
         })
@@ -908,3 +1169,90 @@ fn cmd(
         .join(" ");
     format!("uv {args}")
 }
+
+/// Return the flags accepted by `uv pip compile` (including global flags) that take a value,
+/// mapped to whether the value is a path.
+fn value_flags() -> FxHashMap<String, bool> {
+    let command = Cli::command();
+    let compile = command
+        .find_subcommand("pip")
+        .and_then(|pip| pip.find_subcommand("compile"));
+    let mut flags = FxHashMap::default();
+    for arg in command
+        .get_arguments()
+        .chain(compile.into_iter().flat_map(Command::get_arguments))
+    {
+        if arg.is_positional() || !arg.get_action().takes_values() {
+            continue;
+        }
+        let is_path = matches!(
+            arg.get_value_hint(),
+            ValueHint::AnyPath
+                | ValueHint::FilePath
+                | ValueHint::DirPath
+                | ValueHint::ExecutablePath
+        );
+        for long in arg.get_long_and_visible_aliases().into_iter().flatten() {
+            flags.insert(format!("--{long}"), is_path);
+        }
+        for short in arg.get_short_and_visible_aliases().into_iter().flatten() {
+            flags.insert(format!("-{short}"), is_path);
+        }
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_command;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn compile_command_windows_paths() {
+        // Paths are rendered with forward slashes, whether or not they exist.
+        let command = compile_command(
+            args(&[
+                "pip",
+                "compile",
+                r"requirements\base.in",
+                "-o",
+                r"requirements\base.txt",
+                r"--constraints=constraints\base.txt",
+                r"-boverrides\build.txt",
+                "--python-version",
+                "3.12",
+                "--index-url",
+                r"C:\index",
+            ]),
+            false,
+            false,
+            true,
+        );
+        assert_eq!(
+            command,
+            "uv pip compile requirements/base.in -o requirements/base.txt \
+             --constraints=constraints/base.txt -boverrides/build.txt --python-version 3.12"
+        );
+
+        // Paths are rendered verbatim on other platforms.
+        let command = compile_command(
+            args(&[
+                "pip",
+                "compile",
+                r"requirements\base.in",
+                "-o",
+                r"out\base.txt",
+            ]),
+            false,
+            false,
+            false,
+        );
+        assert_eq!(
+            command,
+            r"uv pip compile requirements\base.in -o out\base.txt"
+        );
+    }
+}
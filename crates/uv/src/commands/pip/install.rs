@@ -1,32 +1,42 @@
 use std::collections::BTreeSet;
-use std::path::PathBuf;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
 use thiserror::Error;
 use tracing::{Level, debug, enabled, warn};
 
 use uv_errors::{Hint, Hints};
 
+use uv_auth::Credentials;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, EditableMode,
-    ExcludeDependency, ExtrasSpecification, HashCheckingMode, IndexStrategy, NoSources, Override,
-    Reinstall, Upgrade,
+    BuildIsolation, BuildOptions, CompileBytecode, Concurrency, Constraints, ConstraintsScope,
+    DryRun, EditableMode, ExcludeDependency, ExtrasSpecification, HashCheckingMode, IndexStrategy,
+    NoSources, Override, Reinstall, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, PlatformMarkerOverrides, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
+    ConfigSettings, DependencyMetadata, DistributionMetadata, ExtraBuildVariables, Index,
+    IndexLocations, IndexUrl, Name, NameRequirementSpecification, Origin, PackageConfigSettings,
+    Requirement, RequirementSource, Resolution, UnresolvedRequirement,
+    UnresolvedRequirementSpecification, VersionId,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
-use uv_pep440::Version;
+use uv_installer::{
+    BrokenDistribution, InstallationStrategy, LazySitePackages, SatisfiesResult, SitePackages,
+};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::Conflicts;
 use uv_python::{
@@ -35,23 +45,30 @@ use uv_python::{
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, LicensePolicyChecker, OptionsBuilder, Preference,
+    PrereleaseMode, PythonRequirement, ResolutionMode, ResolverEnvironment,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{AmdGpuArchitecture, TorchMode, TorchSource, TorchStrategy};
 use uv_types::{HashStrategy, SourceTreeEditablePolicy};
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
-use uv_workspace::pyproject::ExtraBuildDependencies;
+use uv_workspace::pyproject::{ExtraBuildDependencies, LicensePolicy};
 
 use crate::commands::editable::apply_editable_mode;
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, InstallLogger, ResolveLogger,
+};
+use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::resolution_cache::PipResolutionCache;
+use crate::commands::pip::{
+    operations, resolution_markers, resolution_tags, resolution_torch_mode,
+};
 use crate::commands::pylock::{read_pylock_toml, resolve_pylock_toml};
 use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::resolution_cache::ResolutionInputs;
+use crate::commands::uv_lock::{read_uv_lock, resolve_uv_lock};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 
@@ -79,9 +96,11 @@ impl Hint for ExternallyManagedError {
 pub(crate) async fn pip_install(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
+    constraints_scope: ConstraintsScope,
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    from_lock: Option<&Path>,
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Override<Requirement>>,
     excludes_from_workspace: Vec<ExcludeDependency>,
@@ -91,30 +110,47 @@ pub(crate) async fn pip_install(
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prefetch_limit: Option<usize>,
+    resolution_explain: Vec<PackageName>,
+    hash_coverage_report: bool,
+    require_hashes_package: Vec<PackageName>,
+    report: Option<PathBuf>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
+    index_cache_ttl: Option<Duration>,
+    index_credentials: Option<Credentials>,
+    check_index: bool,
+    fail_fast: bool,
+    license_policy: Option<&LicensePolicy>,
+    require_attestations: bool,
+    resolution_cache: Option<&Path>,
+    build_backend_timeout: Option<Duration>,
     torch_backend: Option<TorchMode>,
     cuda_driver_version: Option<Version>,
     amd_gpu_architecture: Option<AmdGpuArchitecture>,
     dependency_metadata: DependencyMetadata,
+    dependency_metadata_file: Option<&Path>,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     reinstall: Reinstall,
+    reinstall_broken: bool,
     link_mode: LinkMode,
-    compile: bool,
+    compile: CompileBytecode,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
     build_isolation: BuildIsolation,
+    clean_build_deps: bool,
     extra_build_dependencies: &ExtraBuildDependencies,
     extra_build_variables: &ExtraBuildVariables,
     build_options: BuildOptions,
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
@@ -137,10 +173,44 @@ pub(crate) async fn pip_install(
 
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
+    // Validate the license policy upfront, rather than after resolving.
+    let license_policy = license_policy.map(LicensePolicyChecker::new).transpose()?;
+
+    // When installing from a `uv.lock`, the groups refer to the lockfile, rather than to a
+    // `pyproject.toml`.
+    let lock_groups = if from_lock.is_some() {
+        let mut names = Vec::with_capacity(groups.groups.len());
+        for group in &groups.groups {
+            if group.path.is_some() {
+                return Err(anyhow::anyhow!(
+                    "Cannot specify paths for groups with `--from-lock`; all groups must refer to the `uv.lock` file"
+                ));
+            }
+            names.push(group.name.clone());
+        }
+        Some(names)
+    } else {
+        None
+    };
+
+    // Stdin can only be read once, so it can't provide both requirements and dependency metadata.
+    if dependency_metadata_file.is_some_and(|path| path == Path::new("-"))
+        && [requirements, constraints, overrides, excludes, build_constraints]
+            .into_iter()
+            .flatten()
+            .any(|source| {
+                matches!(source, RequirementsSource::Extensionless(path) if path == Path::new("-"))
+            })
+    {
+        return Err(anyhow::anyhow!(
+            "Cannot read both requirements and `--dependency-metadata` from stdin; write one of them to a file instead"
+        ));
+    }
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
-        requirements,
+        mut requirements,
         constraints,
         overrides,
         mut override_dependencies,
@@ -155,19 +225,30 @@ pub(crate) async fn pip_install(
         no_binary,
         no_build,
         extras: _,
+        build_constraints: scoped_build_constraints,
     } = operations::read_requirements(
         requirements,
         constraints,
+        constraints_scope,
         overrides,
         excludes,
         extras,
-        Some(groups),
+        lock_groups.is_none().then_some(groups),
         &client_builder,
     )
     .await?;
 
     override_dependencies.extend(overrides_from_workspace);
 
+    // Read any additional dependency metadata, e.g., as generated by another tool and piped to
+    // stdin.
+    let dependency_metadata = if let Some(path) = dependency_metadata_file {
+        let entries = DependencyMetadata::read_json_lines(path)?;
+        DependencyMetadata::from_entries(dependency_metadata.values().cloned().chain(entries))
+    } else {
+        dependency_metadata
+    };
+
     if pylock.is_some() {
         if !preview.is_enabled(PreviewFeature::Pylock) {
             warn_user!(
@@ -192,11 +273,12 @@ pub(crate) async fn pip_install(
         .chain(excludes_from_workspace)
         .collect();
 
-    // Read build constraints.
+    // Read build constraints, including any constraints scoped to builds via `--constraints-scope`.
     let build_constraints: Vec<NameRequirementSpecification> =
         operations::read_constraints(build_constraints, &client_builder)
             .await?
             .into_iter()
+            .chain(scoped_build_constraints)
             .chain(
                 build_constraints_from_workspace
                     .iter()
@@ -300,6 +382,7 @@ pub(crate) async fn pip_install(
     let marker_env = resolution_markers(
         python_version.as_ref(),
         python_platform.as_ref(),
+        platform_markers,
         interpreter,
     );
     let tags = resolution_tags(
@@ -308,8 +391,82 @@ pub(crate) async fn pip_install(
         interpreter,
     )?;
 
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    if enabled!(Level::DEBUG) {
+        debug!(
+            "Using marker environment: implementation_name={}, implementation_version={}, os_name={}, platform_machine={}, platform_python_implementation={}, platform_release={}, platform_system={}, python_full_version={}, python_version={}, sys_platform={}",
+            marker_env.implementation_name(),
+            marker_env.implementation_version(),
+            marker_env.os_name(),
+            marker_env.platform_machine(),
+            marker_env.platform_python_implementation(),
+            marker_env.platform_release(),
+            marker_env.platform_system(),
+            marker_env.python_full_version(),
+            marker_env.python_version(),
+            marker_env.sys_platform(),
+        );
+        debug!(
+            "Using resolution tags (highest priority first): {}",
+            tags.to_string().lines().join(", ")
+        );
+    }
+
+    // Determine the set of installed packages. Distributions are only read once they're needed,
+    // such that checking a few requirements against a large environment stays cheap.
+    let site_packages = LazySitePackages::from_environment(&environment)?;
+
+    // If requested, reinstall any broken distributions. Broken registry distributions are added to
+    // the requirements, and pinned to their installed version where possible.
+    let mut broken_preferences = Vec::new();
+    let reinstall = if reinstall_broken {
+        let broken = SitePackages::from_environment(&environment)?.broken();
+        if broken.is_empty() {
+            writeln!(printer.stderr(), "{}", "No broken packages found".dimmed())?;
+        }
+
+        let mut reinstall = reinstall;
+        for BrokenDistribution { dist, reason } in broken {
+            writeln!(
+                printer.stderr(),
+                "Detected broken package {}: {reason}",
+                dist.to_string().bold()
+            )?;
+
+            if let Some(preference) = Preference::from_installed(&dist) {
+                let requested = requirements.iter().any(|requirement| {
+                    matches!(
+                        &requirement.requirement,
+                        UnresolvedRequirement::Named(named) if named.name == *dist.name()
+                    )
+                });
+                if !requested {
+                    requirements.push(UnresolvedRequirementSpecification::from(Requirement {
+                        name: dist.name().clone(),
+                        extras: Box::new([]),
+                        groups: Box::new([]),
+                        marker: MarkerTree::TRUE,
+                        source: RequirementSource::Registry {
+                            specifier: VersionSpecifiers::empty(),
+                            index: None,
+                            conflict: None,
+                        },
+                        origin: None,
+                    }));
+                }
+                broken_preferences.push(preference);
+            } else {
+                debug!(
+                    "Broken package `{}` was not installed from a registry; it will only be reinstalled if requested",
+                    dist.name()
+                );
+            }
+
+            reinstall = reinstall.with_package(dist.name().clone());
+        }
+        reinstall
+    } else {
+        reinstall
+    };
 
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
@@ -319,6 +476,9 @@ pub(crate) async fn pip_install(
         && source_trees.is_empty()
         && groups.is_empty()
         && pylock.is_none()
+        && from_lock.is_none()
+        && resolution_explain.is_empty()
+        && !hash_coverage_report
         && matches!(modifications, Modifications::Sufficient)
     {
         match site_packages.satisfies_spec(
@@ -350,6 +510,10 @@ pub(crate) async fn pip_install(
                 }
                 DefaultInstallLogger.on_check(requirements.len(), start, printer, dry_run)?;
 
+                if let Some(report) = report {
+                    InstallReport::default().write(&report, printer)?;
+                }
+
                 return Ok(ExitStatus::Success);
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -358,6 +522,9 @@ pub(crate) async fn pip_install(
         }
     }
 
+    // Read the remaining installed packages, which are needed for resolution and installation.
+    let site_packages = site_packages.index()?;
+
     // Determine the Python requirement, if the user requested a specific version.
     let python_requirement = if let Some(python_version) = python_version.as_ref() {
         PythonRequirement::from_python_version(interpreter, python_version)
@@ -365,6 +532,24 @@ pub(crate) async fn pip_install(
         PythonRequirement::from_interpreter(interpreter)
     };
 
+    // When reporting hash coverage, report missing hashes rather than rejecting them.
+    let hash_checking = if hash_coverage_report {
+        Some(HashCheckingMode::Verify)
+    } else {
+        hash_checking
+    };
+
+    // Track the direct requirements, to distinguish them from transitive dependencies when
+    // reporting hash coverage and marking packages as requested.
+    let (direct_names, direct_urls): (FxHashSet<PackageName>, FxHashSet<VersionId>) = requirements
+        .iter()
+        .partition_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Either::Left(requirement.name.clone()),
+            UnresolvedRequirement::Unnamed(requirement) => {
+                Either::Right(VersionId::from_parsed_url(&requirement.url.parsed_url))
+            }
+        });
+
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
         HashStrategy::from_requirements(
@@ -398,6 +583,24 @@ pub(crate) async fn pip_install(
         no_index,
     );
 
+    // Apply any credentials provided via `--index-username` and `--index-password` to the
+    // default index, without requiring them to be embedded in its URL.
+    if let Some(credentials) = index_credentials {
+        let Some(index) = index_locations
+            .default_index()
+            .filter(|index| !matches!(index.url, IndexUrl::Pypi(_)))
+        else {
+            return Err(anyhow::anyhow!(
+                "`--index-username` and `--index-password` require an `--index-url`"
+            ));
+        };
+        debug!("Using provided credentials for index: {}", index.url);
+        if let Some(root_url) = index.root_url() {
+            client_builder.store_credentials(&root_url, credentials.clone());
+        }
+        client_builder.store_credentials(index.raw_url(), credentials);
+    }
+
     // Determine the PyTorch backend.
     let torch_backend = torch_backend
         .map(|mode| {
@@ -409,7 +612,7 @@ pub(crate) async fn pip_install(
                 TorchSource::default()
             };
             TorchStrategy::from_mode(
-                mode,
+                resolution_torch_mode(mode, python_platform.as_ref()),
                 source,
                 python_platform
                     .map(TargetTriple::platform)
@@ -426,11 +629,17 @@ pub(crate) async fn pip_install(
     let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
         .index_locations(index_locations.clone())
         .index_strategy(index_strategy)
+        .index_cache_ttl(index_cache_ttl)
         .torch_backend(torch_backend.clone())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build()?;
 
+    // Check that each index is reachable, if requested, before resolving against them.
+    if check_index {
+        operations::check_indexes(&client, &index_locations, printer).await?;
+    }
+
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
@@ -500,9 +709,31 @@ pub(crate) async fn pip_install(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_backend_timeout(build_backend_timeout);
+
+    let (resolution, hasher) = if let (Some(from_lock), Some(groups)) = (from_lock, lock_groups) {
+        if !resolution_explain.is_empty() {
+            warn_user!("`--resolution-explain` has no effect when installing from a `uv.lock`");
+        }
+        let (install_path, lock) = read_uv_lock(from_lock).await?;
 
-    let (resolution, hasher) = if let Some(pylock) = pylock {
+        resolve_uv_lock(
+            &lock,
+            &install_path,
+            interpreter,
+            python_version.as_ref(),
+            python_platform.as_ref(),
+            platform_markers,
+            extras,
+            groups,
+            &build_options,
+            hash_checking,
+        )?
+    } else if let Some(pylock) = pylock {
+        if !resolution_explain.is_empty() {
+            warn_user!("`--resolution-explain` has no effect when installing from a `pylock.toml`");
+        }
         let (install_path, lock) = read_pylock_toml(&pylock, &client_builder).await?;
 
         // Convert the extras and groups specifications into a concrete form.
@@ -528,71 +759,193 @@ pub(crate) async fn pip_install(
             interpreter,
             python_version.as_ref(),
             python_platform.as_ref(),
+            platform_markers,
             &extras,
             &groups,
             &build_options,
             hash_checking,
         )?
     } else {
-        // When resolving, don't take any external preferences into account.
-        let preferences = Vec::default();
+        // When resolving, don't take any external preferences into account, apart from the
+        // installed versions of any broken packages that are being reinstalled.
+        let preferences = broken_preferences;
 
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .prefetch_limit(prefetch_limit)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .fail_fast(fail_fast)
             .build();
 
-        // Resolve the requirements.
-        let (resolution, hasher) = match operations::resolve(
-            requirements,
-            constraints,
-            overrides,
-            override_dependencies,
-            excludes,
-            source_trees,
-            project,
-            BTreeSet::default(),
-            extras,
-            &groups,
-            preferences,
-            site_packages.clone(),
-            &hasher,
-            &reinstall,
-            &upgrade,
-            Some(&tags),
-            ResolverEnvironment::specific(marker_env.clone()),
-            python_requirement,
-            interpreter.markers(),
-            Conflicts::empty(),
-            &client,
-            &flat_index,
-            state.index(),
-            &build_dispatch,
-            &concurrency,
-            options,
-            Box::new(DefaultResolveLogger),
-            printer,
-        )
-        .await
+        // Key the resolution cache, if enabled, on the resolver inputs. The installed packages are
+        // included, since they're used as preferences. Source trees and dependency groups are
+        // excluded, since their requirements are read from files that aren't part of the inputs,
+        // as are PyTorch backends, which depend on the detected hardware. Resolutions that are
+        // checked against a license policy are excluded, since the check requires the license
+        // metadata of each package, as are resolutions that require attestations, since the
+        // provenance of each file isn't cached.
+        let resolution_cache = if let Some(directory) = resolution_cache
+            && license_policy.is_none()
+            && !require_attestations
+            && hash_checking.is_none()
+            && resolution_explain.is_empty()
+            && upgrade.is_none()
+            && source_trees.is_empty()
+            && groups.is_empty()
+            && torch_backend.is_none()
         {
-            Ok((graph, hasher)) => (Resolution::from(graph), hasher),
-            Err(err) => {
-                return diagnostics::OperationDiagnostic::with_system_certs(
-                    client_builder.system_certs(),
+            let inputs = ResolutionInputs::default()
+                .serialized(
+                    "requirements",
+                    &requirements
+                        .iter()
+                        .map(|entry| (entry.requirement.to_string(), &entry.hashes))
+                        .collect::<Vec<_>>(),
                 )
-                .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
-            }
+                .serialized(
+                    "constraints",
+                    &constraints
+                        .iter()
+                        .map(|entry| (&entry.requirement, &entry.hashes))
+                        .collect::<Vec<_>>(),
+                )
+                .serialized(
+                    "overrides",
+                    &overrides
+                        .iter()
+                        .map(|entry| (entry.requirement.to_string(), &entry.hashes))
+                        .collect::<Vec<_>>(),
+                )
+                .serialized("override-dependencies", &override_dependencies)
+                .serialized("excludes", &excludes)
+                .serialized("project", &project)
+                .displayed("preferences", &preferences)
+                .displayed(
+                    "installed",
+                    site_packages.iter().map(ToString::to_string).sorted(),
+                )
+                .displayed(
+                    "no-deps",
+                    [matches!(dependency_mode, DependencyMode::Direct)],
+                )
+                .serialized("resolution", &resolution_mode)
+                .serialized("prerelease", &prerelease_mode)
+                .serialized("exclude-newer", &exclude_newer)
+                .serialized("index-locations", &index_locations)
+                .serialized("index-strategy", &index_strategy)
+                .serialized("build-options", &build_options)
+                .serialized(
+                    "dependency-metadata",
+                    &dependency_metadata.values().sorted().collect::<Vec<_>>(),
+                )
+                .serialized("markers", marker_env.markers())
+                .displayed("tags", [&tags]);
+            PipResolutionCache::new(directory, &inputs)
+        } else {
+            None
         };
 
-        (resolution, hasher)
+        let start = std::time::Instant::now();
+        if let Some(resolution) = resolution_cache
+            .as_ref()
+            .and_then(|cache| cache.read(marker_env.markers(), &tags, &build_options))
+        {
+            DefaultResolveLogger.on_complete(resolution.len(), start, printer)?;
+            (resolution, hasher)
+        } else {
+            // Resolve the requirements.
+            let (resolution, hasher) = match operations::resolve(
+                requirements,
+                constraints,
+                overrides,
+                override_dependencies,
+                excludes,
+                source_trees,
+                project,
+                BTreeSet::default(),
+                extras,
+                &groups,
+                preferences,
+                site_packages.clone(),
+                &hasher,
+                &reinstall,
+                &upgrade,
+                Some(&tags),
+                ResolverEnvironment::specific(marker_env.clone()),
+                python_requirement,
+                interpreter.markers(),
+                Conflicts::empty(),
+                &client,
+                &flat_index,
+                state.index(),
+                &build_dispatch,
+                &concurrency,
+                options,
+                Box::new(DefaultResolveLogger),
+                printer,
+            )
+            .await
+            {
+                Ok((graph, hasher)) => {
+                    // Explain the selected versions, if requested.
+                    for name in &resolution_explain {
+                        if let Some(explanation) = graph.explain(name) {
+                            writeln!(printer.stderr(), "{explanation}")?;
+                        } else {
+                            warn_user!("`{name}` was not included in the resolution");
+                        }
+                    }
+
+                    // Check the licenses of the resolved packages, if requested.
+                    if let Some(license_policy) = &license_policy {
+                        operations::enforce_license_policy(
+                            graph.license_violations(license_policy, &BTreeSet::default()),
+                        )?;
+                    }
+
+                    // Write the resolution to the cache, if enabled.
+                    let lock = resolution_cache.as_ref().and_then(|resolution_cache| {
+                        resolution_cache.lock(&graph, &tags, &build_options)
+                    });
+                    let resolution = Resolution::from(graph);
+                    if let (Some(resolution_cache), Some(lock)) = (&resolution_cache, lock) {
+                        resolution_cache.write(lock, &resolution).await;
+                    }
+
+                    (resolution, hasher)
+                }
+                Err(err) => {
+                    return diagnostics::OperationDiagnostic::with_system_certs(
+                        client_builder.system_certs(),
+                    )
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                }
+            };
+
+            (resolution, hasher)
+        }
     };
 
+    // Report the hash coverage of the resolution, if requested, rather than installing it.
+    if hash_coverage_report {
+        report_hash_coverage(&resolution, &hasher, &direct_names, &direct_urls, printer)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Require hashes for any packages that opted in to hash-checking individually.
+    hasher.require_package_hashes(&resolution, &require_hashes_package)?;
+
+    // Require a provenance object for each distribution fetched from an index, if requested.
+    if require_attestations {
+        operations::require_attestations(&resolution)?;
+    }
+
     // If necessary, convert editable distributions to non-editable.
     let resolution = apply_editable_mode(resolution, editable);
 
@@ -624,7 +977,31 @@ pub(crate) async fn pip_install(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_build_backend_timeout(build_backend_timeout);
+
+    // Without build isolation, builds run in the target environment and may leave packages behind;
+    // track the packages that were present beforehand, so that they can be detected afterwards.
+    let preexisting = if matches!(build_isolation, BuildIsolation::Isolate) || dry_run.enabled() {
+        None
+    } else {
+        Some(
+            site_packages
+                .iter()
+                .map(|dist| dist.name().clone())
+                .collect::<FxHashSet<_>>(),
+        )
+    };
+
+    // Mark the direct requirements as requested, such that transitive dependencies can be
+    // identified later on (e.g., by `uv pip uninstall --requested-only`).
+    let requested = resolution
+        .distributions()
+        .filter(|dist| {
+            direct_names.contains(dist.name()) || direct_urls.contains(&dist.version_id())
+        })
+        .map(|dist| dist.name().clone())
+        .collect::<FxHashSet<_>>();
 
     // Sync the environment.
     match operations::install(
@@ -635,7 +1012,7 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        &compile,
         &hasher,
         &tags,
         &client,
@@ -646,13 +1023,18 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        Some(&requested),
         dry_run,
         printer,
         preview,
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => {
+            if let Some(report) = report {
+                InstallReport::from_changelog(&changelog, &direct_names).write(&report, printer)?;
+            }
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::with_system_certs(
                 client_builder.system_certs(),
@@ -662,6 +1044,18 @@ pub(crate) async fn pip_install(
         }
     }
 
+    // Notify the user of any packages left behind by builds that ran without build isolation.
+    if let Some(preexisting) = preexisting {
+        report_leaked_build_dependencies(
+            &resolution,
+            &preexisting,
+            &environment,
+            clean_build_deps,
+            printer,
+        )
+        .await?;
+    }
+
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
@@ -679,3 +1073,192 @@ pub(crate) async fn pip_install(
 
     Ok(ExitStatus::Success)
 }
+
+/// A JSON report of the packages installed by `uv pip install --report`.
+#[derive(Debug, Serialize)]
+struct InstallReport {
+    /// The version of the report format.
+    version: &'static str,
+    /// The installed packages.
+    install: Vec<InstallReportEntry>,
+}
+
+/// A package in an [`InstallReport`].
+#[derive(Debug, Serialize)]
+struct InstallReportEntry {
+    name: PackageName,
+    version: Option<Version>,
+    /// Whether the package was requested directly, as opposed to a transitive dependency.
+    requested: bool,
+    /// The number of bytes downloaded for the package.
+    download_size: u64,
+    /// Whether the package was installed without downloading it, i.e., from the cache.
+    cached: bool,
+    /// Whether the package was built from source.
+    build_required: bool,
+}
+
+impl Default for InstallReport {
+    fn default() -> Self {
+        Self {
+            version: "1",
+            install: Vec::new(),
+        }
+    }
+}
+
+impl InstallReport {
+    /// Create an [`InstallReport`] from the distributions installed (or reinstalled) by an
+    /// operation.
+    fn from_changelog(changelog: &Changelog, requested: &FxHashSet<PackageName>) -> Self {
+        let install = changelog
+            .installed
+            .iter()
+            .chain(&changelog.reinstalled)
+            .map(|dist| {
+                let acquisition = changelog
+                    .acquisitions
+                    .get(dist.name())
+                    .cloned()
+                    .unwrap_or_default();
+                InstallReportEntry {
+                    name: dist.name().clone(),
+                    version: dist.version().cloned(),
+                    requested: requested.contains(dist.name()),
+                    download_size: acquisition.download_size.unwrap_or_default(),
+                    cached: acquisition.cached,
+                    build_required: acquisition.build_required,
+                }
+            })
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+            .collect();
+        Self {
+            install,
+            ..Self::default()
+        }
+    }
+
+    /// Write the report to the given path, or to stdout if the path is `-`.
+    fn write(&self, path: &Path, printer: Printer) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        if path == Path::new("-") {
+            writeln!(printer.stdout(), "{json}")?;
+        } else {
+            fs_err::write(path, format!("{json}\n"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Detect packages that are present in the environment after installation, but were neither
+/// present beforehand nor part of the [`Resolution`]. Such packages can only have been installed
+/// by a build backend running in the target environment, i.e., without build isolation.
+///
+/// If `clean` is set, the packages are uninstalled; otherwise, a warning is shown.
+async fn report_leaked_build_dependencies(
+    resolution: &Resolution,
+    preexisting: &FxHashSet<PackageName>,
+    environment: &PythonEnvironment,
+    clean: bool,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let resolved = resolution
+        .distributions()
+        .map(|dist| dist.name())
+        .collect::<FxHashSet<_>>();
+    let site_packages = SitePackages::from_environment(environment)?;
+    let mut leaked = site_packages
+        .iter()
+        .filter(|dist| !preexisting.contains(dist.name()) && !resolved.contains(dist.name()))
+        .collect::<Vec<_>>();
+    if leaked.is_empty() {
+        return Ok(());
+    }
+    leaked.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+
+    if !clean {
+        warn_user!(
+            "The following packages were installed by a build without build isolation, but are not required: {}. Pass `--clean-build-deps` to remove them.",
+            leaked.iter().map(|dist| format!("`{dist}`")).join(", ")
+        );
+        return Ok(());
+    }
+
+    let layout = environment.interpreter().layout();
+    for dist in &leaked {
+        uv_installer::uninstall(dist, &layout).await?;
+        writeln!(
+            printer.stderr(),
+            " {} {} {}",
+            "-".red(),
+            dist.bold(),
+            "(build dependency)".dimmed()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Report the packages in the [`Resolution`] that lack hashes, along with the hashes reported by
+/// the index, in a form that can be pasted into a requirements file.
+fn report_hash_coverage(
+    resolution: &Resolution,
+    hasher: &HashStrategy,
+    direct_names: &FxHashSet<PackageName>,
+    direct_urls: &FxHashSet<VersionId>,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    let hashes = resolution.hashes().collect::<Vec<_>>();
+    let total = hashes.len();
+    let mut missing = hashes
+        .into_iter()
+        .filter(|(dist, _)| !hasher.get(*dist).requires_validation())
+        .collect::<Vec<_>>();
+    missing.sort_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+
+    writeln!(
+        printer.stderr(),
+        "Found hashes for {} of {} {}",
+        (total - missing.len()).to_string().bold(),
+        total.to_string().bold(),
+        if total == 1 { "package" } else { "packages" },
+    )?;
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(printer.stderr(), "Missing hashes for:")?;
+    for (dist, digests) in &missing {
+        let direct = direct_names.contains(dist.name()) || direct_urls.contains(&dist.version_id());
+        let mut annotations = Vec::new();
+        if !direct {
+            annotations.push("transitive");
+        }
+        if digests.is_empty() {
+            annotations.push("no hashes available");
+        }
+        if annotations.is_empty() {
+            writeln!(printer.stderr(), " {} {}", "-".red(), dist.bold())?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                " {} {} {}",
+                "-".red(),
+                dist.bold(),
+                format!("({})", annotations.join(", ")).dimmed()
+            )?;
+        }
+    }
+
+    // Print the requirements with their hashes, in `requirements.txt` format.
+    for (dist, digests) in &missing {
+        let mut line = dist.to_string();
+        for digest in *digests {
+            write!(line, " \\\n    --hash={digest}")?;
+        }
+        writeln!(printer.stdout(), "{line}")?;
+    }
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use std::borrow::Cow;
 
-use uv_configuration::TargetTriple;
+use tracing::debug;
+
+use uv_configuration::{PlatformMarkerOverrides, TargetTriple};
+use uv_platform::ArchVariant;
 use uv_platform_tags::{Tags, TagsError, TagsOptions};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
+use uv_torch::TorchMode;
 
 pub(crate) mod check;
 pub(crate) mod compile;
@@ -13,6 +17,7 @@ pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod resolution_cache;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
@@ -21,15 +26,17 @@ pub(crate) mod uninstall;
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
     interpreter: &Interpreter,
 ) -> ResolverMarkerEnvironment {
     match (python_platform, python_version) {
         (Some(python_platform), Some(python_version)) => ResolverMarkerEnvironment::from(
-            python_version.markers(&python_platform.markers(interpreter.markers())),
+            python_version
+                .markers(&python_platform.markers(interpreter.markers(), platform_markers)),
+        ),
+        (Some(python_platform), None) => ResolverMarkerEnvironment::from(
+            python_platform.markers(interpreter.markers(), platform_markers),
         ),
-        (Some(python_platform), None) => {
-            ResolverMarkerEnvironment::from(python_platform.markers(interpreter.markers()))
-        }
         (None, Some(python_version)) => {
             ResolverMarkerEnvironment::from(python_version.markers(interpreter.markers()))
         }
@@ -71,7 +78,76 @@ pub(crate) fn resolution_tags<'env>(
             gil_disabled: interpreter.gil_disabled(),
             debug_enabled: interpreter.debug_enabled(),
             is_cross: true,
+            x86_64_level: python_platform
+                .and_then(|python_platform| python_platform.arch_variant())
+                .map(ArchVariant::level),
         },
     )?;
     Ok(Cow::Owned(tags))
 }
+
+/// Determine the [`TorchMode`] to use when resolving for the given target platform.
+///
+/// When resolving for a platform that doesn't support GPU-accelerated PyTorch builds (e.g.,
+/// macOS), `--torch-backend=auto` falls back to the CPU index, rather than selecting an index
+/// based on the accelerators detected on the current machine.
+pub(crate) fn resolution_torch_mode(
+    mode: TorchMode,
+    python_platform: Option<&TargetTriple>,
+) -> TorchMode {
+    match (mode, python_platform) {
+        (TorchMode::Auto, Some(python_platform)) if !python_platform.is_gpu_capable() => {
+            debug!(
+                "Target platform does not support GPU-accelerated PyTorch builds; using the CPU backend"
+            );
+            TorchMode::Cpu
+        }
+        _ => mode,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_configuration::TargetTriple;
+    use uv_pep440::Version;
+    use uv_torch::{TorchMode, TorchSource, TorchStrategy};
+
+    use super::resolution_torch_mode;
+
+    /// Resolve the PyTorch index URLs for the given target, as if a CUDA driver were present.
+    fn index_urls(python_platform: TargetTriple) -> Vec<String> {
+        let strategy = TorchStrategy::from_mode(
+            resolution_torch_mode(TorchMode::Auto, Some(&python_platform)),
+            TorchSource::PyTorch,
+            python_platform.platform().os(),
+            Some(Version::new([580, 65, 6])),
+            None,
+        )
+        .unwrap();
+        strategy
+            .index_urls()
+            .map(|index_url| index_url.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn torch_auto_macos_never_cuda() {
+        for python_platform in [
+            TargetTriple::Macos,
+            TargetTriple::Aarch64AppleDarwin,
+            TargetTriple::X8664AppleDarwin,
+        ] {
+            assert_eq!(
+                index_urls(python_platform),
+                vec!["https://download.pytorch.org/whl/cpu".to_string()],
+                "{python_platform:?} should only use the CPU index"
+            );
+        }
+    }
+
+    #[test]
+    fn torch_auto_linux_cuda() {
+        let index_urls = index_urls(TargetTriple::X8664Manylinux228);
+        assert!(index_urls.iter().any(|url| url.contains("/whl/cu")));
+    }
+}
@@ -3,27 +3,31 @@
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, ExcludeDependency, Excludes,
-    ExtrasSpecification, Override, Overrides, Reinstall, Upgrade,
+    BuildOptions, CompileBytecode, Concurrency, Constraints, ConstraintsScope, DependencyGroups,
+    DryRun, ExcludeDependency, Excludes, ExtrasSpecification, Override, Overrides, Reinstall,
+    Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, Diagnostic, Dist, InstalledDist, InstalledVersion, LocalDist,
-    NameRequirementSpecification, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
-    UnresolvedRequirementSpecification, VersionOrUrlRef,
+    BuildableSource, CachedDist, DependencyMetadata, Diagnostic, Dist, IndexLocations,
+    InstalledDist, InstalledDistKind, InstalledVersion, LocalDist, NameRequirementSpecification,
+    Requirement, ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
+    VersionOrUrlRef,
 };
 use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
+use uv_extract::hash::HashStatistics;
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
@@ -35,27 +39,31 @@ use uv_preview::Preview;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
 use uv_python::managed::{ManagedPythonInstallation, PythonMinorVersionLink};
 use uv_python::{PythonEnvironment, PythonInstallation};
+use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{
     GroupsSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
     RequirementsSpecification, SourceTree, SourceTreeResolution, SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput, UpgradePackages,
+    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, LicenseViolation, Manifest, Options,
+    Preference, Preferences, PrefetchSummary, PythonRequirement, Resolver, ResolverEnvironment,
+    ResolverOutput, UpgradePackages,
 };
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::LicensePolicyAction;
 
-use crate::commands::compile_bytecode;
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
+use crate::commands::{compile_bytecode, elapsed, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
 pub(crate) async fn read_requirements(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
+    constraints_scope: ConstraintsScope,
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
     extras: &ExtrasSpecification,
@@ -72,9 +80,10 @@ pub(crate) async fn read_requirements(
     }
 
     // Read all requirements from the provided sources.
-    Ok(RequirementsSpecification::from_sources(
+    Ok(RequirementsSpecification::from_sources_with_scope(
         requirements,
         constraints,
+        constraints_scope,
         overrides,
         excludes,
         groups,
@@ -95,6 +104,79 @@ pub(crate) async fn read_constraints(
     )
 }
 
+/// Check that each of the configured indexes is reachable, before resolving against them.
+///
+/// Reports each unreachable index, and fails if any are found. Skipped when offline.
+pub(crate) async fn check_indexes(
+    client: &RegistryClient,
+    index_locations: &IndexLocations,
+    printer: Printer,
+) -> Result<(), Error> {
+    if client.connectivity().is_offline() {
+        debug!("Skipping index checks in offline mode");
+        return Ok(());
+    }
+
+    let start = std::time::Instant::now();
+
+    let indexes = index_locations
+        .fetch_indexes()
+        .chain(index_locations.explicit_indexes())
+        .collect::<Vec<_>>();
+    let results = futures::future::join_all(
+        indexes
+            .iter()
+            .map(|index| async { (*index, client.check_index(&index.url).await) }),
+    )
+    .await;
+
+    let mut unreachable = 0usize;
+    for (index, result) in results {
+        let Err(err) = result else {
+            continue;
+        };
+        unreachable += 1;
+
+        // Report the innermost cause, which is typically the most actionable (e.g., "Connection
+        // refused").
+        let mut cause: &dyn std::error::Error = &err;
+        while let Some(source) = cause.source() {
+            cause = source;
+        }
+        warn_user!(
+            "Index `{}` is unreachable: {cause}",
+            index.url.without_credentials().cyan()
+        );
+    }
+
+    if unreachable > 0 {
+        return Err(anyhow!(
+            "Failed to reach {unreachable} of {} {}",
+            indexes.len(),
+            if indexes.len() == 1 {
+                "index"
+            } else {
+                "indexes"
+            }
+        )
+        .into());
+    }
+
+    let s = if indexes.len() == 1 { "" } else { "es" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Checked {} {}",
+            format!("{} index{s}", indexes.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(())
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -215,16 +297,25 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         }
 
         for (pyproject_path, groups) in groups {
-            let metadata = SourcedDependencyGroups::from_virtual_project(
-                pyproject_path,
-                None,
-                build_dispatch.locations(),
-                build_dispatch.sources().clone(),
-                build_dispatch.cache(),
-                build_dispatch.workspace_cache(),
-                client.credentials_cache(),
-            )
-            .await
+            // A standalone groups file (e.g., from `--group-file`) is read as-is, rather than
+            // discovering the enclosing project.
+            let metadata = if pyproject_path
+                .file_name()
+                .is_some_and(|name| name == "pyproject.toml")
+            {
+                SourcedDependencyGroups::from_virtual_project(
+                    pyproject_path,
+                    None,
+                    build_dispatch.locations(),
+                    build_dispatch.sources().clone(),
+                    build_dispatch.cache(),
+                    build_dispatch.workspace_cache(),
+                    client.credentials_cache(),
+                )
+                .await
+            } else {
+                SourcedDependencyGroups::from_groups_file(pyproject_path).await
+            }
             .with_context(|| {
                 format!(
                     "Failed to read dependency groups from: {}",
@@ -392,11 +483,61 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         resolver.resolve().await?
     };
 
+    report_timings(&resolution, printer)?;
     logger.on_complete(resolution.len(), start, printer)?;
 
     Ok((resolution, hasher))
 }
 
+/// Report the performance statistics for a resolution, if requested with `--timings`.
+fn report_timings(resolution: &ResolverOutput, printer: Printer) -> Result<(), Error> {
+    if !uv_flags::contains(uv_flags::EnvironmentFlags::TIMINGS) {
+        return Ok(());
+    }
+
+    let PrefetchSummary { prefetched, used } = resolution.prefetch();
+    let s = if prefetched == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Prefetched metadata for {} {}",
+            format!("{prefetched} package version{s}").bold(),
+            format!("({used} used)").dimmed()
+        )
+        .dimmed()
+    )?;
+    Ok(())
+}
+
+/// Report the hashing throughput across all prepared distributions, if requested with `--timings`.
+fn report_hash_timings(printer: Printer) -> Result<(), Error> {
+    if !uv_flags::contains(uv_flags::EnvironmentFlags::TIMINGS) {
+        return Ok(());
+    }
+
+    let statistics = HashStatistics::total();
+    if statistics.bytes == 0 {
+        return Ok(());
+    }
+
+    let (bytes, unit) = human_readable_bytes(statistics.bytes);
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (throughput, throughput_unit) = human_readable_bytes(statistics.throughput() as u64);
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Hashed {} in {} {}",
+            format!("{bytes:.1}{unit}").bold(),
+            elapsed(statistics.elapsed),
+            format!("({throughput:.1}{throughput_unit}/s)").dimmed()
+        )
+        .dimmed()
+    )?;
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Modifications {
     /// Use `pip install` semantics, whereby existing installations are left as-is, unless they are
@@ -500,6 +641,23 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<ChangedDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<ChangedDist>,
+    /// How each installed distribution was obtained, i.e., whether it was downloaded, built, or
+    /// read from the cache.
+    pub(crate) acquisitions: FxHashMap<PackageName, Acquisition>,
+    /// The extraneous distributions that were left in place, i.e., those that are installed but
+    /// not required, when using [`Modifications::Sufficient`].
+    pub(crate) extraneous: Vec<InstalledDist>,
+}
+
+/// How a distribution was obtained while preparing it for installation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Acquisition {
+    /// The number of bytes downloaded, or `None` if the distribution was not downloaded.
+    pub(crate) download_size: Option<u64>,
+    /// Whether the distribution was built from source.
+    pub(crate) build_required: bool,
+    /// Whether the distribution was read from the cache, without downloading or building it.
+    pub(crate) cached: bool,
 }
 
 impl Changelog {
@@ -522,6 +680,8 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            acquisitions: FxHashMap::default(),
+            extraneous: Vec::new(),
         }
     }
 
@@ -542,6 +702,25 @@ impl Changelog {
         Self::from_local(installed, Vec::new())
     }
 
+    /// Create a [`Changelog`] from a list of uninstalled distributions.
+    pub(crate) fn from_uninstalled(uninstalled: Vec<InstalledDist>) -> Self {
+        Self::from_local(Vec::new(), uninstalled)
+    }
+
+    /// Set how each installed distribution was obtained.
+    #[must_use]
+    fn with_acquisitions(mut self, acquisitions: FxHashMap<PackageName, Acquisition>) -> Self {
+        self.acquisitions = acquisitions;
+        self
+    }
+
+    /// Set the extraneous distributions that were left in place.
+    #[must_use]
+    fn with_extraneous(mut self, extraneous: Vec<InstalledDist>) -> Self {
+        self.extraneous = extraneous;
+        self
+    }
+
     /// Returns `true` if the changelog includes a distribution with the given name, either via
     /// an installation or uninstallation.
     pub(crate) fn includes(&self, name: &PackageName) -> bool {
@@ -566,7 +745,7 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
-    compile: bool,
+    compile: &CompileBytecode,
     hasher: &HashStrategy,
     tags: &Tags,
     client: &RegistryClient,
@@ -577,6 +756,7 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    requested: Option<&FxHashSet<PackageName>>,
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
@@ -622,10 +802,10 @@ pub(crate) async fn install(
         extraneous,
     } = plan;
 
-    // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
+    // If we're in `install` mode, retain any extraneous distributions.
+    let (extraneous, retained) = match modifications {
+        Modifications::Sufficient => (vec![], extraneous),
+        Modifications::Exact => (extraneous, vec![]),
     };
 
     // Nothing to do.
@@ -633,10 +813,10 @@ pub(crate) async fn install(
         && cached.is_empty()
         && reinstalls.is_empty()
         && extraneous.is_empty()
-        && !compile
+        && compile.is_none()
     {
         logger.on_check(resolution.len(), start, printer, dry_run)?;
-        return Ok(Changelog::default());
+        return Ok(Changelog::default().with_extraneous(retained));
     }
 
     // Partition into two sets: those that require build isolation, and those that disable it. This
@@ -655,10 +835,11 @@ pub(crate) async fn install(
 
     let mut installs = vec![];
     let mut uninstalls = vec![];
+    let mut acquisitions = FxHashMap::default();
 
     // Execute the isolated-build phase.
     if has_isolated_phase {
-        let (isolated_installs, isolated_uninstalls) = execute_plan(
+        let (isolated_installs, isolated_uninstalls, isolated_acquisitions) = execute_plan(
             isolated_phase,
             None,
             resolution,
@@ -674,16 +855,18 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            requested,
             printer,
             preview,
         )
         .await?;
         installs.extend(isolated_installs);
         uninstalls.extend(isolated_uninstalls);
+        acquisitions.extend(isolated_acquisitions);
     }
 
     if has_shared_phase {
-        let (shared_installs, shared_uninstalls) = execute_plan(
+        let (shared_installs, shared_uninstalls, shared_acquisitions) = execute_plan(
             shared_phase,
             if has_isolated_phase {
                 Some(InstallPhase::Shared)
@@ -703,20 +886,30 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            requested,
             printer,
             preview,
         )
         .await?;
         installs.extend(shared_installs);
         uninstalls.extend(shared_uninstalls);
+        acquisitions.extend(shared_acquisitions);
     }
 
-    if compile {
-        compile_bytecode(venv, concurrency, cache, printer).await?;
+    match compile {
+        CompileBytecode::None => {}
+        CompileBytecode::All => {
+            compile_bytecode(venv, None, concurrency, cache, printer).await?;
+        }
+        CompileBytecode::Packages(packages) => {
+            compile_bytecode(venv, Some(packages), concurrency, cache, printer).await?;
+        }
     }
 
     // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::from_local(installs, uninstalls);
+    let changelog = Changelog::from_local(installs, uninstalls)
+        .with_acquisitions(acquisitions)
+        .with_extraneous(retained);
 
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer, dry_run)?;
@@ -755,9 +948,17 @@ async fn execute_plan(
     venv: &PythonEnvironment,
     logger: &dyn InstallLogger,
     installer_metadata: bool,
+    requested: Option<&FxHashSet<PackageName>>,
     printer: Printer,
     preview: Preview,
-) -> Result<(Vec<CachedDist>, Vec<InstalledDist>), Error> {
+) -> Result<
+    (
+        Vec<CachedDist>,
+        Vec<InstalledDist>,
+        FxHashMap<PackageName, Acquisition>,
+    ),
+    Error,
+> {
     let Plan {
         cached,
         remote,
@@ -766,11 +967,14 @@ async fn execute_plan(
     } = plan;
 
     // Download, build, and unzip any missing distributions.
-    let wheels = if remote.is_empty() {
-        vec![]
+    let (wheels, acquisitions) = if remote.is_empty() {
+        (vec![], FxHashMap::default())
     } else {
         let start = std::time::Instant::now();
 
+        let recorder = Arc::new(AcquisitionRecorder::new(Arc::new(
+            PrepareReporter::from(printer).with_length(remote.len() as u64),
+        )));
         let preparer = Preparer::new(
             cache,
             tags,
@@ -782,9 +986,7 @@ async fn execute_plan(
                 concurrency.downloads_semaphore.clone(),
             ),
         )
-        .with_reporter(Arc::new(
-            PrepareReporter::from(printer).with_length(remote.len() as u64),
-        ));
+        .with_reporter(recorder.clone());
 
         let wheels = preparer
             .prepare(remote.clone(), in_flight, resolution)
@@ -797,10 +999,68 @@ async fn execute_plan(
             printer,
             DryRun::Disabled,
         )?;
+        report_hash_timings(printer)?;
 
-        wheels
+        (wheels, recorder.take_acquisitions())
     };
 
+    // Record the cache hits: the distributions that were already unzipped in the cache, along with
+    // any remote distributions that were prepared from the cache, without being downloaded or
+    // built. Local distributions are read from disk, rather than the cache.
+    let mut acquisitions = acquisitions;
+    for dist in &cached {
+        acquisitions.entry(dist.name().clone()).or_default().cached = true;
+    }
+    for dist in remote.iter().filter(|dist| !dist.is_local()) {
+        let acquisition = acquisitions.entry(dist.name().clone()).or_default();
+        acquisition.cached = acquisition.download_size.is_none() && !acquisition.build_required;
+    }
+
+    // When replacing an installed distribution with a wheel, identify the files that are identical
+    // in both, which can be left in place rather than removed and relinked. Symlinked files point
+    // into the cache entry of the installed wheel, so they're always relinked.
+    let mut unchanged = FxHashMap::default();
+    if !link_mode.is_symlink() {
+        let layout = venv.interpreter().layout();
+        for dist_info in &reinstalls {
+            if !matches!(
+                dist_info.kind,
+                InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+            ) {
+                continue;
+            }
+            let Some(wheel) = wheels
+                .iter()
+                .chain(&cached)
+                .find(|wheel| wheel.name() == dist_info.name())
+            else {
+                continue;
+            };
+            match uv_install_wheel::unchanged_files(dist_info.install_path(), wheel.path(), &layout)
+            {
+                Ok(Some(files)) => {
+                    debug!(
+                        "Preserving {} unchanged file{} of {}",
+                        files.len(),
+                        if files.len() == 1 { "" } else { "s" },
+                        dist_info.name()
+                    );
+                    unchanged.insert(dist_info.name().clone(), files);
+                }
+                Ok(None) => {
+                    debug!("Performing a full reinstall of {}", dist_info.name());
+                }
+                Err(err) => {
+                    debug!(
+                        "Failed to compare {} against {}, performing a full reinstall: {err}",
+                        dist_info.name(),
+                        wheel.filename()
+                    );
+                }
+            }
+        }
+    }
+
     // Remove any upgraded or extraneous installations.
     let uninstalls = extraneous.into_iter().chain(reinstalls).collect::<Vec<_>>();
     if !uninstalls.is_empty() {
@@ -808,7 +1068,8 @@ async fn execute_plan(
 
         let layout = venv.interpreter().layout();
         for dist_info in &uninstalls {
-            match uv_installer::uninstall(dist_info, &layout).await {
+            let preserve = unchanged.get(dist_info.name()).cloned().unwrap_or_default();
+            match uv_installer::uninstall_preserving(dist_info, &layout, preserve).await {
                 Ok(summary) => {
                     debug!(
                         "Uninstalled {} ({} file{}, {} director{})",
@@ -846,22 +1107,154 @@ async fn execute_plan(
     let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !installs.is_empty() {
         let start = std::time::Instant::now();
-        installs = uv_installer::Installer::new(venv, preview)
+        let mut installer = uv_installer::Installer::new(venv, preview)
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            .with_unchanged_files(unchanged)
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
-            ))
-            // This technically can block the runtime, but we are on the main thread and
-            // have no other running tasks at this point, so this lets us avoid spawning a blocking
-            // task.
-            .install_blocking(installs)?;
+            ));
+        if let Some(requested) = requested {
+            installer = installer.with_requested(requested.clone());
+        }
+        // This technically can block the runtime, but we are on the main thread and
+        // have no other running tasks at this point, so this lets us avoid spawning a blocking
+        // task.
+        installs = installer.install_blocking(installs)?;
 
         logger.on_install(installs.len(), start, printer, DryRun::Disabled)?;
     }
 
-    Ok((installs, uninstalls))
+    Ok((installs, uninstalls, acquisitions))
+}
+
+/// A [`uv_installer::PrepareReporter`] that records the downloads and builds performed for each
+/// distribution, forwarding all events to the underlying reporter.
+struct AcquisitionRecorder {
+    reporter: Arc<dyn uv_installer::PrepareReporter>,
+    state: Mutex<AcquisitionState>,
+}
+
+#[derive(Default)]
+struct AcquisitionState {
+    /// The in-flight downloads, keyed by ID.
+    downloads: FxHashMap<usize, InFlightDownload>,
+    /// The ID to assign to the next download.
+    next_id: usize,
+    /// How each distribution was obtained.
+    acquisitions: FxHashMap<PackageName, Acquisition>,
+}
+
+/// A download that is in progress.
+struct InFlightDownload {
+    /// The ID assigned to the download by the underlying reporter.
+    id: usize,
+    name: PackageName,
+    /// The expected size of the download, if known.
+    size: Option<u64>,
+    /// The number of bytes downloaded so far.
+    bytes: u64,
+}
+
+impl AcquisitionRecorder {
+    fn new(reporter: Arc<dyn uv_installer::PrepareReporter>) -> Self {
+        Self {
+            reporter,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Take the record of how each distribution was obtained.
+    fn take_acquisitions(&self) -> FxHashMap<PackageName, Acquisition> {
+        std::mem::take(&mut self.state.lock().unwrap().acquisitions)
+    }
+}
+
+impl uv_installer::PrepareReporter for AcquisitionRecorder {
+    fn on_progress(&self, dist: &CachedDist) {
+        self.reporter.on_progress(dist);
+    }
+
+    fn on_complete(&self) {
+        self.reporter.on_complete();
+    }
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        // The underlying reporter may not assign unique IDs (e.g., when progress is hidden), so
+        // assign our own.
+        let id = self.reporter.on_download_start(name, size);
+        let mut state = self.state.lock().unwrap();
+        let download = state.next_id;
+        state.next_id += 1;
+        state.downloads.insert(
+            download,
+            InFlightDownload {
+                id,
+                name: name.clone(),
+                size,
+                bytes: 0,
+            },
+        );
+        download
+    }
+
+    fn on_download_progress(&self, index: usize, bytes: u64) {
+        let id = {
+            let mut state = self.state.lock().unwrap();
+            let Some(download) = state.downloads.get_mut(&index) else {
+                return;
+            };
+            download.bytes += bytes;
+            download.id
+        };
+        self.reporter.on_download_progress(id, bytes);
+    }
+
+    fn on_download_complete(&self, name: &PackageName, index: usize) {
+        let download = {
+            let mut state = self.state.lock().unwrap();
+            let download = state.downloads.remove(&index);
+            if let Some(download) = &download {
+                // If no progress was reported, fall back to the expected size.
+                let bytes = if download.bytes > 0 {
+                    download.bytes
+                } else {
+                    download.size.unwrap_or_default()
+                };
+                let acquisition = state.acquisitions.entry(download.name.clone()).or_default();
+                *acquisition.download_size.get_or_insert(0) += bytes;
+            }
+            download
+        };
+        if let Some(download) = download {
+            self.reporter.on_download_complete(name, download.id);
+        }
+    }
+
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let Some(name) = source.name() {
+            let mut state = self.state.lock().unwrap();
+            state
+                .acquisitions
+                .entry(name.clone())
+                .or_default()
+                .build_required = true;
+        }
+        self.reporter.on_build_start(source)
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_complete(source, id);
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        self.reporter.on_checkout_start(url, rev)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, index: usize) {
+        self.reporter.on_checkout_complete(url, rev, index);
+    }
 }
 
 /// Display a message about the interpreter that was selected for the operation.
@@ -997,16 +1390,16 @@ fn report_dry_run(
         extraneous,
     } = plan;
 
-    // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
+    // If we're in `install` mode, retain any extraneous distributions.
+    let (extraneous, retained) = match modifications {
+        Modifications::Sufficient => (vec![], extraneous),
+        Modifications::Exact => (extraneous, vec![]),
     };
 
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         logger.on_check(resolution.len(), start, printer, dry_run)?;
-        return Ok(Changelog::default());
+        return Ok(Changelog::default().with_extraneous(retained));
     }
 
     // Download, build, and unzip any missing distributions.
@@ -1041,7 +1434,7 @@ fn report_dry_run(
             .map(|dist| ChangedDist::Local(dist.into())),
     );
 
-    let changelog = Changelog::new(installed, uninstalled);
+    let changelog = Changelog::new(installed, uninstalled).with_extraneous(retained);
 
     logger.on_complete(&changelog, printer, dry_run)?;
 
@@ -1069,6 +1462,39 @@ pub(crate) fn diagnose_resolution(
     Ok(())
 }
 
+/// Report any license policy violations, failing if any of them are denied by the policy.
+pub(crate) fn enforce_license_policy(violations: Vec<LicenseViolation>) -> Result<(), Error> {
+    let (denied, warned): (Vec<_>, Vec<_>) = violations
+        .into_iter()
+        .partition(|violation| violation.action() == LicensePolicyAction::Deny);
+    for violation in warned {
+        warn_user!("{violation}");
+    }
+    if denied.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::LicensePolicyViolation(denied))
+    }
+}
+
+/// Require a [PEP 740] provenance object for every distribution that will be fetched from an
+/// index, failing with the distributions that don't publish one.
+///
+/// [PEP 740]: https://peps.python.org/pep-0740/
+pub(crate) fn require_attestations(resolution: &Resolution) -> Result<(), Error> {
+    let missing = resolution
+        .distributions()
+        .filter(|dist| dist.index().is_some() && dist.provenance().is_none())
+        .map(ToString::to_string)
+        .sorted()
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::MissingAttestations(missing))
+    }
+}
+
 /// Report any diagnostics on installed distributions in the Python environment.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
@@ -1125,6 +1551,18 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment(Box<Changelog>),
+
+    #[error(
+        "The resolution violates the license policy:\n  - {}",
+        _0.iter().join("\n  - ")
+    )]
+    LicensePolicyViolation(Vec<LicenseViolation>),
+
+    #[error(
+        "The following distributions don't publish a provenance object:\n  - {}",
+        _0.iter().join("\n  - ")
+    )]
+    MissingAttestations(Vec<String>),
 }
 
 impl uv_errors::Hint for Error {
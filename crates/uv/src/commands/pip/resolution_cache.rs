@@ -0,0 +1,160 @@
+//! An opt-in, on-disk cache of `uv pip install` resolutions, keyed by the inputs to the resolver.
+//!
+//! Resolutions are stored as `pylock.toml` files, such that a cache hit can be installed without
+//! querying the index. Unlike the resolution cache used by `uv lock`, entries never expire: the
+//! cache is intended for repeated, identical runs (e.g., across the rows of a CI matrix), and any
+//! change to the inputs results in a different entry.
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_configuration::BuildOptions;
+use uv_distribution_types::{BuiltDist, Dist, Name, Resolution, ResolvedDist, SourceDist};
+use uv_pep508::MarkerEnvironment;
+use uv_platform_tags::Tags;
+use uv_resolver::{PylockToml, ResolverOutput};
+
+use crate::commands::resolution_cache::ResolutionInputs;
+
+/// A cached resolution for a given set of resolver inputs.
+#[derive(Debug)]
+pub(crate) struct PipResolutionCache {
+    path: PathBuf,
+}
+
+impl PipResolutionCache {
+    /// Initialize a [`PipResolutionCache`] in the given directory for the given resolver inputs.
+    ///
+    /// The `inputs` should capture everything that can affect the outcome of the resolution,
+    /// other than the contents of the index. Returns `None` if any of the inputs couldn't be
+    /// recorded.
+    pub(crate) fn new(directory: &Path, inputs: &ResolutionInputs) -> Option<Self> {
+        let digest = inputs.digest()?;
+        let path = directory.join(format!("{digest}.toml"));
+        Some(Self { path })
+    }
+
+    /// Read the cached resolution, if it exists.
+    pub(crate) fn read(
+        &self,
+        markers: &MarkerEnvironment,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Option<Resolution> {
+        let encoded = match fs_err::read_to_string(&self.path) {
+            Ok(encoded) => encoded,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No cached resolution at: {}", self.path.display());
+                return None;
+            }
+            Err(err) => {
+                debug!(
+                    "Failed to read cached resolution at `{}`: {err}",
+                    self.path.display()
+                );
+                return None;
+            }
+        };
+
+        let resolution = toml::from_str::<PylockToml>(&encoded)
+            .map_err(anyhow::Error::from)
+            .and_then(|lock| {
+                Ok(lock.to_resolution(
+                    self.install_path(),
+                    markers,
+                    &[],
+                    &[],
+                    tags,
+                    build_options,
+                )?)
+            });
+        match resolution {
+            Ok(resolution) => {
+                debug!("Using cached resolution at: {}", self.path.display());
+                Some(resolution)
+            }
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid cached resolution at `{}`: {err}",
+                    self.path.display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Convert the resolver output to a `pylock.toml`, for use with [`PipResolutionCache::write`].
+    pub(crate) fn lock(
+        &self,
+        output: &ResolverOutput,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Option<PylockToml> {
+        match PylockToml::from_resolution(
+            output,
+            &[],
+            self.install_path(),
+            Some(tags),
+            build_options,
+        ) {
+            Ok(lock) => Some(lock),
+            Err(err) => {
+                debug!("Failed to convert resolution for the resolution cache: {err}");
+                None
+            }
+        }
+    }
+
+    /// Write the resolution to the cache.
+    ///
+    /// Resolutions that include any packages from outside the index (e.g., Git or path
+    /// dependencies), or that reuse installed packages, are not cached, since they can change
+    /// without affecting the resolver inputs.
+    pub(crate) async fn write(&self, mut lock: PylockToml, resolution: &Resolution) {
+        let mut indexes = FxHashMap::default();
+        for dist in resolution.distributions() {
+            let ResolvedDist::Installable { dist, .. } = dist else {
+                debug!("Skipping resolution cache for resolution with installed packages");
+                return;
+            };
+            let (Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))) =
+                &**dist
+            else {
+                debug!("Skipping resolution cache for resolution with non-registry sources");
+                return;
+            };
+            if let Some(index) = dist.index() {
+                indexes.insert(
+                    dist.name().clone(),
+                    index.without_credentials().into_owned(),
+                );
+            }
+        }
+
+        // Record the index for each package, such that a cache hit uses the same cache entries
+        // for its distributions as the original resolution.
+        for package in &mut lock.packages {
+            package.index = indexes.get(&package.name).cloned();
+        }
+
+        let result = async {
+            let encoded = lock.to_toml()?;
+            fs_err::tokio::create_dir_all(self.install_path()).await?;
+            uv_fs::write_atomic(&self.path, encoded).await?;
+            Ok::<(), anyhow::Error>(())
+        }
+        .await;
+        if let Err(err) = result {
+            debug!(
+                "Failed to write cached resolution to `{}`: {err}",
+                self.path.display()
+            );
+        }
+    }
+
+    /// The directory containing the cached resolution.
+    fn install_path(&self) -> &Path {
+        self.path.parent().unwrap_or(Path::new(""))
+    }
+}
@@ -8,10 +8,10 @@ use tracing::{debug, warn};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, NoSources, Reinstall, Upgrade,
+    BuildIsolation, BuildOptions, CompileBytecode, Concurrency, Constraints, ConstraintsScope,
+    DryRun, ExtrasSpecification, HashCheckingMode, IndexStrategy, NoSources, Reinstall, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, PlatformMarkerOverrides, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
@@ -44,7 +44,9 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    operations, resolution_markers, resolution_tags, resolution_torch_mode,
+};
 use crate::commands::pylock::{read_pylock_toml, resolve_pylock_toml};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics};
@@ -60,7 +62,7 @@ pub(crate) async fn pip_sync(
     groups: &GroupsSpecification,
     reinstall: Reinstall,
     link_mode: LinkMode,
-    compile: bool,
+    compile: CompileBytecode,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -80,6 +82,7 @@ pub(crate) async fn pip_sync(
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
@@ -126,9 +129,11 @@ pub(crate) async fn pip_sync(
         no_binary,
         no_build,
         extras: _,
+        build_constraints: _,
     } = operations::read_requirements(
         requirements,
         constraints,
+        ConstraintsScope::Runtime,
         overrides,
         excludes,
         extras,
@@ -255,6 +260,7 @@ pub(crate) async fn pip_sync(
     let marker_env = resolution_markers(
         python_version.as_ref(),
         python_platform.as_ref(),
+        platform_markers,
         interpreter,
     );
     let tags = resolution_tags(
@@ -306,7 +312,7 @@ pub(crate) async fn pip_sync(
                 TorchSource::default()
             };
             TorchStrategy::from_mode(
-                mode,
+                resolution_torch_mode(mode, python_platform.as_ref()),
                 source,
                 python_platform
                     .map(TargetTriple::platform)
@@ -433,6 +439,7 @@ pub(crate) async fn pip_sync(
             interpreter,
             python_version.as_ref(),
             python_platform.as_ref(),
+            platform_markers,
             &extras,
             &groups,
             &build_options,
@@ -536,7 +543,7 @@ pub(crate) async fn pip_sync(
         &reinstall,
         &build_options,
         link_mode,
-        compile,
+        &compile,
         &hasher,
         &tags,
         &client,
@@ -547,6 +554,7 @@ pub(crate) async fn pip_sync(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        None,
         dry_run,
         printer,
         preview,
@@ -9,7 +9,7 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DryRun, KeyringProviderType};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
+use uv_distribution_types::{Diagnostic, InstalledMetadata, Name, UnresolvedRequirement};
 use uv_fs::Simplified;
 use uv_pep508::UnnamedRequirement;
 use uv_pypi_types::VerbatimParsedUrl;
@@ -28,12 +28,15 @@ pub(crate) async fn pip_uninstall(
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
+    allow_system_managed: bool,
+    requested_only: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
     cache: Cache,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     dry_run: DryRun,
+    simulate_deps: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -179,9 +182,45 @@ pub(crate) async fn pip_uninstall(
         // Deduplicate, since a package could be listed both by name and editable URL.
         distributions.sort_unstable_by_key(|dist| dist.install_path());
         distributions.dedup_by_key(|dist| dist.install_path());
+
+        // If requested, skip any packages that were installed as dependencies.
+        if requested_only {
+            distributions.retain(|distribution| {
+                if distribution.is_requested() {
+                    true
+                } else {
+                    debug!(
+                        "Skipping `{}`, which was not explicitly requested",
+                        distribution.name()
+                    );
+                    false
+                }
+            });
+        }
+
         distributions
     };
 
+    // Refuse to uninstall any packages that are owned by the system package manager, e.g., in
+    // Debian's `dist-packages`.
+    for distribution in &distributions {
+        let Some(directory) = site_packages.system_managed(distribution) else {
+            continue;
+        };
+        if allow_system_managed {
+            debug!(
+                "Uninstalling system-managed package `{}` due to `--allow-system-managed`",
+                distribution.name()
+            );
+        } else {
+            return Err(anyhow::anyhow!(
+                "The package `{}` is managed by the system package manager at {}. Instead, remove it with the system package manager (e.g., `apt`), or pass `--allow-system-managed` to uninstall it anyway.",
+                distribution.name(),
+                directory.user_display().cyan(),
+            ));
+        }
+    }
+
     if distributions.is_empty() {
         if dry_run.enabled() {
             writeln!(printer.stderr(), "Would make no changes")?;
@@ -237,7 +276,7 @@ pub(crate) async fn pip_uninstall(
         )?;
     }
 
-    for distribution in distributions {
+    for distribution in &distributions {
         writeln!(
             printer.stderr(),
             " {} {}{}",
@@ -247,5 +286,36 @@ pub(crate) async fn pip_uninstall(
         )?;
     }
 
+    // Report the remaining packages whose dependencies would be missing after uninstalling.
+    if simulate_deps {
+        let markers = environment.interpreter().resolver_marker_environment();
+        let diagnostics = site_packages.missing_after_removal(&distributions, &markers);
+        if diagnostics.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                "No installed packages would have missing dependencies".dimmed()
+            )?;
+        } else {
+            let dependencies = if diagnostics.len() == 1 {
+                "dependency"
+            } else {
+                "dependencies"
+            };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Would leave {}",
+                    format!("{} missing {}", diagnostics.len(), dependencies).bold()
+                )
+                .dimmed()
+            )?;
+            for diagnostic in &diagnostics {
+                writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+            }
+        }
+    }
+
     Ok(ExitStatus::Success)
 }
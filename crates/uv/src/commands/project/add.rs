@@ -696,6 +696,15 @@ pub(crate) async fn add(
         }
     }
 
+    // Scripts can't reference a constraints file, so record the constraints inline.
+    if !raw && matches!(target, AddTarget::Script(..)) {
+        for constraint in &constraints {
+            toml.add_constraint_dependency(&uv_pep508::Requirement::from(
+                constraint.requirement.clone(),
+            ))?;
+        }
+    }
+
     let content = toml.to_string();
 
     // Save the modified `pyproject.toml` or script.
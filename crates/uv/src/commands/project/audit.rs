@@ -1,7 +1,7 @@
 use itertools::Itertools as _;
 use owo_colors::OwoColorize;
 use std::fmt::Write as _;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::commands::ExitStatus;
 use crate::commands::diagnostics;
@@ -18,21 +18,27 @@ use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, ResolverSettings};
 
 use anyhow::Result;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::trace;
 use uv_audit::{
-    AdverseStatus, Dependency, Finding, ProjectStatus, ProjectStatusAudit, Vulnerability,
+    AdverseStatus, Dependency, Finding, ProjectStatus, ProjectStatusAudit, Severity, Vulnerability,
     VulnerabilityID, VulnerabilityServiceFormat, osv,
 };
 use uv_cache::Cache;
-use uv_cli::AuditOutputFormat;
-use uv_client::{BaseClientBuilder, CachedClient, RegistryClientBuilder};
-use uv_configuration::{Concurrency, DependencyGroups, ExtrasSpecification, TargetTriple};
-use uv_distribution_types::{IndexCapabilities, IndexUrl};
-use uv_fs::{CWD, find_git_repository_root, relative_to};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_cli::{AuditFailOn, AuditOutputFormat};
+use uv_client::{BaseClient, BaseClientBuilder, CachedClient, RegistryClientBuilder};
+use uv_configuration::{
+    Concurrency, DependencyGroups, ExtrasSpecification, PlatformMarkerOverrides, TargetTriple,
+};
+use uv_distribution_types::{IndexCapabilities, IndexUrl, Name};
+use uv_fs::{CWD, Simplified, find_git_repository_root, relative_to};
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::{Preview, PreviewFeature};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest,
+    PythonVersion,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
@@ -60,9 +66,12 @@ pub(crate) async fn audit(
     cache: Cache,
     printer: Printer,
     preview: Preview,
+    installed: bool,
     output_format: AuditOutputFormat,
+    fail_on: AuditFailOn,
     service: VulnerabilityServiceFormat,
     service_url: Option<String>,
+    service_snapshot: Option<PathBuf>,
     ignore: Vec<VulnerabilityID>,
     ignore_until_fixed: Vec<VulnerabilityID>,
 ) -> Result<ExitStatus> {
@@ -82,6 +91,63 @@ pub(crate) async fn audit(
         );
     }
 
+    // Audit the packages installed in the current environment, rather than the lockfile.
+    if installed {
+        let environment = PythonEnvironment::find(
+            &PythonRequest::default(),
+            EnvironmentPreference::from_system_flag(false, false),
+            python_preference,
+            &cache,
+        )?;
+        let site_packages = SitePackages::from_environment(&environment)?;
+
+        // Editable installations are typically local projects, which have no external package
+        // identity.
+        let dependencies: Vec<Dependency> = site_packages
+            .iter()
+            .filter(|dist| !dist.is_editable())
+            .map(|dist| Dependency::new(dist.name().clone(), dist.version().clone()))
+            .sorted_by(|first, second| {
+                first
+                    .name()
+                    .cmp(second.name())
+                    .then_with(|| first.version().cmp(second.version()))
+            })
+            .dedup()
+            .collect();
+
+        let reporter = AuditReporter::from(printer);
+        trace!(
+            "Auditing {n} installed packages in {}",
+            environment.root().user_display(),
+            n = dependencies.len()
+        );
+        let findings = query_vulnerabilities(
+            &dependencies,
+            service,
+            service_url.as_deref(),
+            service_snapshot.as_deref(),
+            client_builder.build()?,
+            concurrency,
+            &cache,
+        )
+        .await?;
+        reporter.on_audit_complete();
+
+        let display = AuditResults {
+            printer,
+            n_packages: dependencies.len(),
+            output_format,
+            fail_on,
+            findings: filter_ignored(findings, &ignore, &ignore_until_fixed),
+            chains: FxHashMap::default(),
+            project_statuses: false,
+            fix_command: FixCommand::Install,
+            artifact_uri: artifact_uri(environment.root()),
+        };
+        return display.render();
+    }
+
     let workspace_cache = WorkspaceCache::default();
     let workspace;
     let target = if let Some(script) = script.as_ref() {
@@ -211,6 +277,7 @@ pub(crate) async fn audit(
         resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            &PlatformMarkerOverrides::default(),
             interpreter.as_ref().unwrap(),
         )
     });
@@ -247,20 +314,15 @@ pub(crate) async fn audit(
     let status_audit =
         ProjectStatusAudit::new(&registry_client, &capabilities, concurrency.clone());
 
-    let osv_future = async {
-        match service {
-            VulnerabilityServiceFormat::Osv => {
-                let osv_url = service_url
-                    .as_deref()
-                    .map(|url| url.parse().expect("invalid OSV service URL"))
-                    .unwrap_or_else(|| osv::API_BASE.clone());
-                let client = CachedClient::new(base_client);
-                let service = osv::Osv::new(client, Some(osv_url), concurrency, cache.clone());
-                trace!("Auditing {n} dependencies against OSV", n = auditable.len());
-                service.query_batch(&dependencies, osv::Filter::All).await
-            }
-        }
-    };
+    let osv_future = query_vulnerabilities(
+        &dependencies,
+        service,
+        service_url.as_deref(),
+        service_snapshot.as_deref(),
+        base_client,
+        concurrency,
+        &cache,
+    );
     let status_future = async {
         trace!(
             "Auditing {n} projects for adverse status",
@@ -274,10 +336,100 @@ pub(crate) async fn audit(
 
     reporter.on_audit_complete();
 
-    // Filter out ignored vulnerabilities, tracking how many were ignored
-    // and which ignore rules actually matched.
+    let all_findings = filter_ignored(all_findings, &ignore, &ignore_until_fixed);
+
+    // Determine the dependency chain that introduces each vulnerable package.
+    let chains: FxHashMap<Dependency, Vec<PackageName>> = all_findings
+        .iter()
+        .filter_map(|finding| match finding {
+            Finding::Vulnerability(vulnerability) => Some(&vulnerability.dependency),
+            Finding::ProjectStatus(_) => None,
+        })
+        .map(|dependency| {
+            let chain = auditable
+                .chain(dependency.name(), dependency.version())
+                .into_iter()
+                .cloned()
+                .collect();
+            (dependency.clone(), chain)
+        })
+        .collect();
+
+    let display = AuditResults {
+        printer,
+        n_packages: auditable.len(),
+        output_format,
+        fail_on,
+        findings: all_findings,
+        chains,
+        project_statuses: true,
+        fix_command: FixCommand::Lock(match target {
+            LockTarget::Script(script) => {
+                format!("uv lock --script {}", script.path.user_display())
+            }
+            LockTarget::Workspace(_) => "uv lock".to_string(),
+        }),
+        artifact_uri: {
+            let lock_path = target.lock_path();
+            // If we've run `uv audit --script`, we might only have an in-memory lockfile.
+            // In that case, use the script's own path as the artifact path.
+            let artifact_path = if let LockTarget::Script(script) = target
+                && !lock_path.is_file()
+            {
+                script.path.as_path()
+            } else {
+                lock_path.as_path()
+            };
+            artifact_uri(artifact_path)
+        },
+    };
+    display.render()
+}
+
+/// Query the vulnerability service, or a local snapshot of it, for the given dependencies.
+async fn query_vulnerabilities(
+    dependencies: &[Dependency],
+    service: VulnerabilityServiceFormat,
+    service_url: Option<&str>,
+    service_snapshot: Option<&Path>,
+    base_client: BaseClient,
+    concurrency: Concurrency,
+    cache: &Cache,
+) -> Result<Vec<Finding>> {
+    match service {
+        VulnerabilityServiceFormat::Osv => {
+            if let Some(service_snapshot) = service_snapshot {
+                trace!(
+                    "Auditing {n} dependencies against the OSV snapshot at {}",
+                    service_snapshot.user_display(),
+                    n = dependencies.len()
+                );
+                let snapshot = osv::OsvSnapshot::read(service_snapshot)?;
+                return Ok(snapshot.query_batch(dependencies, osv::Filter::All));
+            }
+            let osv_url = service_url
+                .map(|url| url.parse().expect("invalid OSV service URL"))
+                .unwrap_or_else(|| osv::API_BASE.clone());
+            let client = CachedClient::new(base_client);
+            let service = osv::Osv::new(client, Some(osv_url), concurrency, cache.clone());
+            trace!(
+                "Auditing {n} dependencies against OSV",
+                n = dependencies.len()
+            );
+            Ok(service.query_batch(dependencies, osv::Filter::All).await?)
+        }
+    }
+}
+
+/// Filter out ignored vulnerabilities, warning about any ignore rules that didn't match.
+fn filter_ignored(
+    findings: Vec<Finding>,
+    ignore: &[VulnerabilityID],
+    ignore_until_fixed: &[VulnerabilityID],
+) -> Vec<Finding> {
+    // Track which ignore rules actually matched.
     let mut matched_ignores: FxHashSet<&VulnerabilityID> = FxHashSet::default();
-    let all_findings: Vec<_> = all_findings
+    let findings: Vec<_> = findings
         .into_iter()
         .filter(|finding| match finding {
             Finding::Vulnerability(vulnerability) => {
@@ -310,46 +462,47 @@ pub(crate) async fn audit(
         }
     }
 
-    let display = AuditResults {
-        printer,
-        n_packages: auditable.len(),
-        output_format,
-        findings: all_findings,
-        artifact_uri: {
-            let lock_path = target.lock_path();
-            // If we've run `uv audit --script`, we might only have an in-memory lockfile.
-            // In that case, use the script's own path as the artifact path.
-            let artifact_path = if let LockTarget::Script(script) = target
-                && !lock_path.is_file()
-            {
-                script.path.as_path()
-            } else {
-                lock_path.as_path()
-            };
-            // SARIF consumers resolve artifact locations from the repository root, regardless of
-            // the directory from which uv was invoked. Fall back to the invocation directory for
-            // projects that aren't in a Git repository.
-            let artifact_path = if let Some(repository_root) =
-                find_git_repository_root(artifact_path)
-                && let Ok(relative) = relative_to(artifact_path, repository_root)
-            {
-                relative
-            } else if let Ok(relative) = artifact_path.strip_prefix(&*CWD) {
-                relative.to_path_buf()
-            } else {
-                artifact_path.to_path_buf()
-            };
-            artifact_path.to_string_lossy().replace('\\', "/")
-        },
+    findings
+}
+
+/// Return the URI of the audited artifact (e.g., the lockfile), for SARIF output.
+fn artifact_uri(artifact_path: &Path) -> String {
+    // SARIF consumers resolve artifact locations from the repository root, regardless of
+    // the directory from which uv was invoked. Fall back to the invocation directory for
+    // projects that aren't in a Git repository.
+    let artifact_path = if let Some(repository_root) = find_git_repository_root(artifact_path)
+        && let Ok(relative) = relative_to(artifact_path, repository_root)
+    {
+        relative
+    } else if let Ok(relative) = artifact_path.strip_prefix(&*CWD) {
+        relative.to_path_buf()
+    } else {
+        artifact_path.to_path_buf()
     };
-    display.render()
+    artifact_path.to_string_lossy().replace('\\', "/")
+}
+
+/// The command to suggest for upgrading vulnerable packages to a fixed version.
+enum FixCommand {
+    /// Upgrade the packages in the lockfile with the given `uv lock` invocation.
+    Lock(String),
+    /// Upgrade the packages installed in the environment.
+    Install,
 }
 
 struct AuditResults {
     printer: Printer,
     n_packages: usize,
     output_format: AuditOutputFormat,
+    fail_on: AuditFailOn,
     findings: Vec<Finding>,
+    /// The dependency chain that introduces each vulnerable package, starting from a root
+    /// requirement.
+    chains: FxHashMap<Dependency, Vec<PackageName>>,
+    /// Whether the packages were audited for adverse project statuses.
+    project_statuses: bool,
+    /// The command to suggest for upgrading vulnerable packages.
+    fix_command: FixCommand,
     artifact_uri: String,
 }
 
@@ -372,14 +525,23 @@ impl AuditResults {
     }
 
     fn exit_status(&self) -> ExitStatus {
+        let threshold = match self.fail_on {
+            AuditFailOn::Any => None,
+            AuditFailOn::High => Some(Severity::High),
+            AuditFailOn::Critical => Some(Severity::Critical),
+        };
+
         // NOTE: intentional: we don't currently fail if there are any adverse statuses,
         // only when there are vulnerabilities. We will likely change this once we allow users
         // to ignore adverse statuses and configure policies.
-        if self
-            .findings
-            .iter()
-            .any(|finding| matches!(finding, Finding::Vulnerability(_)))
-        {
+        if self.findings.iter().any(|finding| match finding {
+            Finding::Vulnerability(vulnerability) => threshold.is_none_or(|threshold| {
+                vulnerability
+                    .severity
+                    .is_some_and(|severity| severity >= threshold)
+            }),
+            Finding::ProjectStatus(_) => false,
+        }) {
             ExitStatus::Failure
         } else {
             ExitStatus::Success
@@ -413,20 +575,36 @@ impl AuditResults {
             "no adverse project statuses".bold().to_string()
         };
 
-        writeln!(
-            self.printer.stderr(),
-            "Found {vulnerability_banner} and {status_banner} in {packages}",
-            packages = format!(
-                "{npackages} {label}",
-                npackages = self.n_packages,
-                label = if self.n_packages == 1 {
-                    "package"
-                } else {
-                    "packages"
-                }
-            )
-            .bold()
-        )?;
+        let packages = format!(
+            "{npackages} {label}",
+            npackages = self.n_packages,
+            label = if self.n_packages == 1 {
+                "package"
+            } else {
+                "packages"
+            }
+        )
+        .bold();
+        if self.project_statuses {
+            writeln!(
+                self.printer.stderr(),
+                "Found {vulnerability_banner} and {status_banner} in {packages}",
+            )?;
+        } else {
+            writeln!(
+                self.printer.stderr(),
+                "Found {vulnerability_banner} in {packages}",
+            )?;
+        }
+
+        // Suggest upgrading any vulnerable packages for which a fix is available.
+        let fixable = vulnerabilities
+            .iter()
+            .copied()
+            .filter(|vulnerability| !vulnerability.fix_versions.is_empty())
+            .map(|vulnerability| vulnerability.dependency.name())
+            .unique()
+            .collect::<Vec<_>>();
 
         if !vulnerabilities.is_empty() {
             writeln!(self.printer.stdout_important(), "\nVulnerabilities:\n")?;
@@ -445,7 +623,7 @@ impl AuditResults {
 
                 writeln!(
                     self.printer.stdout_important(),
-                    "{name_version} has {n} known vulnerabilit{ies}:\n",
+                    "{name_version} has {n} known vulnerabilit{ies}:",
                     name_version = format!("{name} {version}").bold(),
                     n = vulnerabilities.len(),
                     ies = if vulnerabilities.len() == 1 {
@@ -455,11 +633,31 @@ impl AuditResults {
                     },
                 )?;
 
+                // For transitive dependencies, show the chain of dependencies that introduces
+                // the vulnerable package.
+                if let Some(chain) = self
+                    .chains
+                    .get(&Dependency::new(name.clone(), version.clone()))
+                    && chain.len() > 2
+                {
+                    writeln!(
+                        self.printer.stdout_important(),
+                        "  Introduced via: {}",
+                        chain.iter().join(" -> ").dimmed()
+                    )?;
+                }
+                writeln!(self.printer.stdout_important())?;
+
                 for vulnerability in vulnerabilities {
+                    let id = if let Some(severity) = vulnerability.severity {
+                        format!("{} ({severity})", vulnerability.best_id().as_str())
+                    } else {
+                        vulnerability.best_id().as_str().to_string()
+                    };
                     writeln!(
                         self.printer.stdout_important(),
                         "- {id}: {description}",
-                        id = vulnerability.best_id().as_str().bold(),
+                        id = id.bold(),
                         description = vulnerability
                             .summary
                             .as_deref()
@@ -517,6 +715,28 @@ impl AuditResults {
             }
         }
 
+        if !fixable.is_empty() {
+            let command = match &self.fix_command {
+                FixCommand::Lock(lock_command) => {
+                    let mut command = lock_command.clone();
+                    for name in &fixable {
+                        write!(command, " --upgrade-package {name}")?;
+                    }
+                    command
+                }
+                FixCommand::Install => {
+                    format!("uv pip install --upgrade {}", fixable.iter().join(" "))
+                }
+            };
+            writeln!(
+                self.printer.stderr(),
+                "{}{} To upgrade the vulnerable packages to a fixed version, run: `{}`",
+                "hint".bold().cyan(),
+                ":".bold(),
+                command.green(),
+            )?;
+        }
+
         Ok(self.exit_status())
     }
 
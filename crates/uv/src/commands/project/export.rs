@@ -17,9 +17,11 @@ use uv_configuration::{
 use uv_distribution_types::Verbatim;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{Interpreter, PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{
+    EnvironmentYmlExport, Installable, PylockToml, RequirementsTxtExport, cyclonedx_json,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
@@ -76,6 +78,7 @@ pub(crate) async fn export(
     include_header: bool,
     include_index_url: bool,
     include_find_links: bool,
+    strict: bool,
     script: Option<Pep723Script>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -336,6 +339,12 @@ pub(crate) async fn export(
             .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
         {
             ExportFormat::RequirementsTxt
+        } else if output_file
+            .as_deref()
+            .and_then(Path::extension)
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml"))
+        {
+            ExportFormat::EnvironmentYml
         } else if output_file
             .as_deref()
             .and_then(Path::file_name)
@@ -348,6 +357,13 @@ pub(crate) async fn export(
         }
     });
 
+    // Only `environment.yml` omits packages that can't be represented in the output format.
+    if strict && !matches!(format, ExportFormat::EnvironmentYml) {
+        return Err(anyhow!(
+            "`--strict` is only supported when exporting to `environment.yml`"
+        ));
+    }
+
     // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
     if !matches!(format, ExportFormat::CycloneDX1_5) {
         detect_conflicts(&target, &extras, &groups)?;
@@ -468,10 +484,84 @@ pub(crate) async fn export(
                 &install_options,
                 preview,
                 all_packages,
+                target.lock_provenance(),
             )?;
 
             export.output_as_json_v1_5(&mut writer)?;
         }
+        ExportFormat::EnvironmentYml => {
+            // Carry over the index locations as `pip` options.
+            let mut pip_options = Vec::new();
+            let mut seen = FxHashSet::default();
+            if let Some(index) = settings.index_locations.default_index() {
+                pip_options.push(format!("--index-url {}", index.url().verbatim()));
+                seen.insert(index.url());
+            }
+            for index in settings
+                .index_locations
+                .implicit_indexes()
+                .chain(settings.index_locations.explicit_indexes())
+            {
+                if seen.insert(index.url()) {
+                    pip_options.push(format!("--extra-index-url {}", index.url().verbatim()));
+                }
+            }
+            for flat_index in settings.index_locations.flat_indexes() {
+                pip_options.push(format!("--find-links {}", flat_index.url().verbatim()));
+            }
+
+            // Name the environment after the project, script, or workspace directory.
+            let name = match &target {
+                InstallTarget::Script { script, .. } => script
+                    .path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string()),
+                _ => target.project_name().map(ToString::to_string).or_else(|| {
+                    target
+                        .install_path()
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                }),
+            };
+
+            let export = EnvironmentYmlExport::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                name,
+                interpreter.as_ref().map(Interpreter::python_version),
+                pip_options,
+                hashes,
+                &install_options,
+            )?;
+
+            if !export.skipped().is_empty() {
+                let skipped = export
+                    .skipped()
+                    .iter()
+                    .map(|name| format!("`{}`", name.cyan()))
+                    .join(", ");
+                if strict {
+                    return Err(anyhow!(
+                        "The following packages can't be represented in `environment.yml` (editable and local path dependencies are not supported): {skipped}"
+                    ));
+                }
+                warn_user!(
+                    "Omitting packages that can't be represented in `environment.yml` (editable and local path dependencies are not supported): {skipped}"
+                );
+            }
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
     }
 
     writer.commit().await?;
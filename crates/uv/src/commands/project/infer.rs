@@ -0,0 +1,620 @@
+//! Infer a `requires-python` lower bound from an existing codebase.
+//!
+//! The inference is a best-effort heuristic: Python sources are scanned with a lightweight lexer
+//! for syntax that was introduced in a specific Python version, and any `python_requires` or trove
+//! classifiers declared in a `setup.cfg` or `setup.py` are taken into account.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_pep440::{Operator, Version, VersionSpecifiers};
+
+/// Directories that never contain first-party sources.
+const IGNORED_DIRECTORIES: &[&str] = &[
+    "__pycache__",
+    "build",
+    "dist",
+    "node_modules",
+    "site-packages",
+    "venv",
+];
+
+/// Syntax that requires a minimum Python version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum SyntaxFeature {
+    /// An assignment expression, e.g., `(n := len(items))` (PEP 572).
+    AssignmentExpression,
+    /// A `match` statement (PEP 634).
+    MatchStatement,
+    /// Type parameter syntax, e.g., `def f[T](x: T)` or `type Alias = int` (PEP 695).
+    TypeParameters,
+    /// An f-string that reuses its own quotes in a replacement field (PEP 701).
+    NestedFString,
+}
+
+impl SyntaxFeature {
+    /// The minimum Python minor version (i.e., `3.x`) that supports the feature.
+    fn minor(self) -> u8 {
+        match self {
+            Self::AssignmentExpression => 8,
+            Self::MatchStatement => 10,
+            Self::TypeParameters | Self::NestedFString => 12,
+        }
+    }
+}
+
+impl fmt::Display for SyntaxFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssignmentExpression => f.write_str("assignment expression (`:=`)"),
+            Self::MatchStatement => f.write_str("`match` statement"),
+            Self::TypeParameters => f.write_str("type parameter syntax"),
+            Self::NestedFString => f.write_str("nested f-string quotes"),
+        }
+    }
+}
+
+/// A piece of evidence for the minimum supported Python version.
+#[derive(Debug, Clone)]
+pub(crate) enum Evidence {
+    /// Version-specific syntax in a Python source file.
+    Syntax {
+        feature: SyntaxFeature,
+        path: PathBuf,
+        line: usize,
+    },
+    /// A `python_requires` declaration in a `setup.cfg` or `setup.py`.
+    PythonRequires {
+        specifiers: VersionSpecifiers,
+        path: PathBuf,
+    },
+    /// The lowest `Programming Language :: Python :: 3.x` classifier in a `setup.cfg` or
+    /// `setup.py`.
+    Classifier { minor: u8, path: PathBuf },
+}
+
+impl Evidence {
+    /// The minimum Python minor version (i.e., `3.x`) implied by the evidence.
+    fn minor(&self) -> u8 {
+        match self {
+            Self::Syntax { feature, .. } => feature.minor(),
+            Self::PythonRequires { specifiers, .. } => lower_bound(specifiers).unwrap_or(0),
+            Self::Classifier { minor, .. } => *minor,
+        }
+    }
+}
+
+impl fmt::Display for Evidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax {
+                feature,
+                path,
+                line,
+            } => write!(
+                f,
+                "{feature} in `{}:{line}` (Python 3.{}+)",
+                path.user_display(),
+                feature.minor()
+            ),
+            Self::PythonRequires { specifiers, path } => write!(
+                f,
+                "`python_requires = \"{specifiers}\"` in `{}`",
+                path.user_display()
+            ),
+            Self::Classifier { minor, path } => write!(
+                f,
+                "`Programming Language :: Python :: 3.{minor}` classifier in `{}`",
+                path.user_display()
+            ),
+        }
+    }
+}
+
+/// The inferred lower bound for `requires-python`, along with its justification.
+#[derive(Debug, Clone)]
+pub(crate) struct InferredRequiresPython {
+    /// The inferred minimum version, e.g., `3.10`.
+    pub(crate) version: Version,
+    /// The evidence used to infer the version, with the most restrictive first.
+    pub(crate) evidence: Vec<Evidence>,
+}
+
+/// Infer the minimum supported Python version for the codebase at the given root.
+///
+/// Returns `None` if no version-specific syntax or metadata was found.
+pub(crate) fn infer_requires_python(root: &Path) -> Option<InferredRequiresPython> {
+    let mut evidence = Vec::new();
+
+    // Read any declared metadata from a legacy `setup.cfg` or `setup.py`.
+    for name in ["setup.cfg", "setup.py"] {
+        let path = root.join(name);
+        let Ok(contents) = fs_err::read_to_string(&path) else {
+            continue;
+        };
+        let relative = PathBuf::from(name);
+        if let Some(specifiers) = find_python_requires(&contents) {
+            evidence.push(Evidence::PythonRequires {
+                specifiers,
+                path: relative.clone(),
+            });
+        }
+        if let Some(minor) = find_lowest_classifier(&contents) {
+            evidence.push(Evidence::Classifier {
+                minor,
+                path: relative,
+            });
+        }
+    }
+
+    // Scan the Python sources, recording the first occurrence of each feature.
+    let mut seen = Vec::new();
+    let walker = ignore::WalkBuilder::new(root)
+        .require_git(false)
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry
+                    .file_type()
+                    .is_some_and(|file_type| file_type.is_dir())
+                || entry
+                    .file_name()
+                    .to_str()
+                    .is_none_or(|name| !IGNORED_DIRECTORIES.contains(&name))
+        })
+        .sort_by_file_path(Path::cmp)
+        .build();
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                debug!("Failed to read entry while inferring `requires-python`: {err}");
+                continue;
+            }
+        };
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "py") {
+            continue;
+        }
+        let Ok(source) = fs_err::read_to_string(path) else {
+            continue;
+        };
+        for (feature, line) in scan_source(&source) {
+            if seen.contains(&feature) {
+                continue;
+            }
+            seen.push(feature);
+            evidence.push(Evidence::Syntax {
+                feature,
+                path: path.strip_prefix(root).unwrap_or(path).to_path_buf(),
+                line,
+            });
+        }
+    }
+
+    let minor = evidence.iter().map(Evidence::minor).max()?;
+    evidence.sort_by_key(|evidence| std::cmp::Reverse(evidence.minor()));
+
+    Some(InferredRequiresPython {
+        version: Version::new([3, u64::from(minor)]),
+        evidence,
+    })
+}
+
+/// Return the Python 3 minor version of the lower bound of the given specifiers, if any.
+fn lower_bound(specifiers: &VersionSpecifiers) -> Option<u8> {
+    specifiers
+        .iter()
+        .filter(|specifier| {
+            matches!(
+                *specifier.operator(),
+                Operator::GreaterThanEqual
+                    | Operator::GreaterThan
+                    | Operator::TildeEqual
+                    | Operator::Equal
+                    | Operator::EqualStar
+            )
+        })
+        .filter_map(|specifier| {
+            let release = specifier.version().release();
+            if release.first() != Some(&3) {
+                return None;
+            }
+            u8::try_from(release.get(1).copied().unwrap_or(0)).ok()
+        })
+        .max()
+}
+
+/// Find a `python_requires` declaration in a `setup.cfg` or `setup.py`.
+fn find_python_requires(contents: &str) -> Option<VersionSpecifiers> {
+    let (_, rest) = contents.split_once("python_requires")?;
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+
+    // In a `setup.py`, the value is a string literal; in a `setup.cfg`, it's the rest of the line.
+    let value = if let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) {
+        rest[1..].split(quote).next()?
+    } else {
+        rest.lines().next()?
+    };
+    VersionSpecifiers::from_str(value.trim()).ok()
+}
+
+/// Find the lowest Python 3 minor version listed in a trove classifier.
+fn find_lowest_classifier(contents: &str) -> Option<u8> {
+    contents
+        .match_indices("Programming Language :: Python :: 3.")
+        .filter_map(|(index, prefix)| {
+            let digits = contents[index + prefix.len()..]
+                .split(|c: char| !c.is_ascii_digit())
+                .next()?;
+            digits.parse::<u8>().ok()
+        })
+        .min()
+}
+
+/// Scan Python source code for syntax that requires a minimum Python version.
+///
+/// Returns each detected feature along with the (one-based) line on which it was found.
+pub(crate) fn scan_source(source: &str) -> Vec<(SyntaxFeature, usize)> {
+    let mut lexer = Lexer::new(source);
+    lexer.run();
+
+    let mut features = lexer.features;
+    for (index, (line, code)) in lexer.lines.iter().enumerate() {
+        let code = code.trim();
+        if code.contains(":=") {
+            features.push((SyntaxFeature::AssignmentExpression, *line));
+        }
+        if is_match_statement(code)
+            && lexer.lines[index + 1..]
+                .iter()
+                .map(|(_, code)| code.trim())
+                .find(|code| !code.is_empty())
+                .is_some_and(|code| is_keyword_statement(code, "case"))
+        {
+            features.push((SyntaxFeature::MatchStatement, *line));
+        }
+        if has_type_parameters(code) {
+            features.push((SyntaxFeature::TypeParameters, *line));
+        }
+    }
+    features.sort_by_key(|(_, line)| *line);
+    features
+}
+
+/// Returns `true` if the logical line begins with the given soft keyword, followed by an
+/// expression.
+fn is_keyword_statement(code: &str, keyword: &str) -> bool {
+    code.strip_prefix(keyword)
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_whitespace() || matches!(c, '(' | '[' | '{' | '"' | '\'' | '-'))
+}
+
+/// Returns `true` if the logical line is the header of a `match` statement.
+fn is_match_statement(code: &str) -> bool {
+    is_keyword_statement(code, "match") && code.ends_with(':')
+}
+
+/// Returns `true` if the logical line declares a generic function or class, or a type alias.
+fn has_type_parameters(code: &str) -> bool {
+    let code = code.strip_prefix("async ").map_or(code, str::trim_start);
+    for keyword in ["def ", "class "] {
+        if let Some(rest) = code.strip_prefix(keyword) {
+            let rest = rest.trim_start();
+            let name_len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            return name_len > 0 && rest[name_len..].trim_start().starts_with('[');
+        }
+    }
+    if let Some(rest) = code.strip_prefix("type ") {
+        let rest = rest.trim_start();
+        let name_len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            return false;
+        }
+        let rest = rest[name_len..].trim_start();
+        let rest = if rest.starts_with('[') {
+            match rest.find(']') {
+                Some(end) => rest[end + 1..].trim_start(),
+                None => return false,
+            }
+        } else {
+            rest
+        };
+        return rest.starts_with('=') && !rest.starts_with("==");
+    }
+    false
+}
+
+/// A minimal Python lexer that splits source code into logical lines, with string literals and
+/// comments removed, and detects f-strings that reuse their own quotes.
+struct Lexer {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+    /// The logical lines of code, along with the line on which each starts.
+    lines: Vec<(usize, String)>,
+    /// Features detected while lexing string literals.
+    features: Vec<(SyntaxFeature, usize)>,
+}
+
+impl Lexer {
+    fn new(source: &str) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            position: 0,
+            line: 1,
+            lines: Vec::new(),
+            features: Vec::new(),
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.position += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Lex the source into logical lines.
+    fn run(&mut self) {
+        let mut code = String::new();
+        let mut start = self.line;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek(0) {
+            match c {
+                '#' => {
+                    while self.peek(0).is_some_and(|c| c != '\n') {
+                        self.bump();
+                    }
+                }
+                '\\' if self.peek(1) == Some('\n') => {
+                    self.bump();
+                    self.bump();
+                    code.push(' ');
+                }
+                '\n' => {
+                    self.bump();
+                    if depth == 0 {
+                        self.lines.push((start, std::mem::take(&mut code)));
+                        start = self.line;
+                    } else {
+                        code.push(' ');
+                    }
+                }
+                '"' | '\'' => {
+                    let is_fstring = string_prefix(&code)
+                        .is_some_and(|prefix| prefix.contains(['f', 'F', 't', 'T']));
+                    self.string(is_fstring);
+                    code.push_str("\"\"");
+                }
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    code.push(c);
+                    self.bump();
+                }
+                ')' | ']' | '}' => {
+                    depth = depth.saturating_sub(1);
+                    code.push(c);
+                    self.bump();
+                }
+                _ => {
+                    code.push(c);
+                    self.bump();
+                }
+            }
+        }
+        if !code.trim().is_empty() {
+            self.lines.push((start, code));
+        }
+    }
+
+    /// Lex a string literal starting at the current (quote) character.
+    fn string(&mut self, is_fstring: bool) {
+        let quote = self.peek(0).expect("string should start with a quote");
+        let triple = self.peek(1) == Some(quote) && self.peek(2) == Some(quote);
+        let delimiter_len = if triple { 3 } else { 1 };
+        for _ in 0..delimiter_len {
+            self.bump();
+        }
+
+        while let Some(c) = self.peek(0) {
+            if c == '\\' {
+                self.bump();
+                self.bump();
+            } else if c == quote
+                && (!triple || (self.peek(1) == Some(quote) && self.peek(2) == Some(quote)))
+            {
+                for _ in 0..delimiter_len {
+                    self.bump();
+                }
+                return;
+            } else if c == '\n' && !triple {
+                // Unterminated string; recover at the end of the line.
+                return;
+            } else if is_fstring && c == '{' {
+                if self.peek(1) == Some('{') {
+                    self.bump();
+                    self.bump();
+                } else {
+                    self.bump();
+                    self.replacement_field(quote, triple);
+                }
+            } else {
+                self.bump();
+            }
+        }
+    }
+
+    /// Lex an f-string replacement field, after its opening brace, up to and including its closing
+    /// brace.
+    fn replacement_field(&mut self, quote: char, triple: bool) {
+        let mut depth = 0usize;
+        let mut format_spec = false;
+        while let Some(c) = self.peek(0) {
+            match c {
+                '}' if depth == 0 => {
+                    self.bump();
+                    return;
+                }
+                '{' if format_spec => {
+                    self.bump();
+                    self.replacement_field(quote, triple);
+                }
+                _ if format_spec => {
+                    if c == '\n' && !triple {
+                        return;
+                    }
+                    self.bump();
+                }
+                ':' if depth == 0 && self.peek(1) != Some('=') => {
+                    format_spec = true;
+                    self.bump();
+                }
+                '"' | '\'' => {
+                    // Prior to Python 3.12, a replacement field can't contain the quotes that
+                    // delimit the enclosing f-string.
+                    let nested_triple = self.peek(1) == Some(c) && self.peek(2) == Some(c);
+                    if c == quote && (!triple || nested_triple) {
+                        self.features
+                            .push((SyntaxFeature::NestedFString, self.line));
+                    }
+                    let prefix_start = self.chars[..self.position]
+                        .iter()
+                        .rev()
+                        .take_while(|c| c.is_ascii_alphabetic())
+                        .count();
+                    let prefix: String = self.chars[self.position - prefix_start..self.position]
+                        .iter()
+                        .collect();
+                    self.string(prefix.contains(['f', 'F', 't', 'T']));
+                }
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    self.bump();
+                }
+                ')' | ']' | '}' => {
+                    depth = depth.saturating_sub(1);
+                    self.bump();
+                }
+                '\n' if !triple => return,
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+}
+
+/// Return the string prefix (e.g., `rb` or `f`) at the end of the given code, if any.
+fn string_prefix(code: &str) -> Option<&str> {
+    let start = code
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map_or(code.len(), |(index, _)| index);
+    let prefix = &code[start..];
+    if prefix.len() <= 2
+        && prefix
+            .chars()
+            .all(|c| matches!(c.to_ascii_lowercase(), 'r' | 'u' | 'b' | 'f' | 't'))
+    {
+        Some(prefix)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan() {
+        let source = indoc::indoc! {r#"
+            import sys
+
+            # match x:
+            message = "match x:"
+
+            if (n := len(sys.argv)) > 1:
+                pass
+
+            match sys.argv:
+                case [_, "--help"]:
+                    pass
+
+            def first[T](items: list[T]) -> T:
+                return items[0]
+
+            type Alias = int
+            type = 1
+        "#};
+        assert_eq!(
+            scan_source(source),
+            vec![
+                (SyntaxFeature::AssignmentExpression, 6),
+                (SyntaxFeature::MatchStatement, 9),
+                (SyntaxFeature::TypeParameters, 13),
+                (SyntaxFeature::TypeParameters, 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_fstrings() {
+        // Distinct quotes are allowed on all versions.
+        assert_eq!(scan_source(r#"f"{x['key']}""#), vec![]);
+        assert_eq!(scan_source(r#"f"{x:'>10}""#), vec![]);
+        assert_eq!(scan_source(r#"f"{{literal}}""#), vec![]);
+        assert_eq!(scan_source(r#"f'''{x["a"]} {f"{y}"}'''"#), vec![]);
+
+        // Reusing the enclosing quotes requires Python 3.12.
+        assert_eq!(
+            scan_source("x = {}\nf\"{x[\"key\"]}\""),
+            vec![(SyntaxFeature::NestedFString, 2)]
+        );
+        assert_eq!(
+            scan_source(r#"f"{", ".join(items)}""#),
+            vec![(SyntaxFeature::NestedFString, 1)]
+        );
+
+        // A walrus within a string is ignored.
+        assert_eq!(scan_source(r#"x = "(a := b)""#), vec![]);
+    }
+
+    #[test]
+    fn metadata() {
+        let setup_cfg = indoc::indoc! {"
+            [metadata]
+            classifiers =
+                Programming Language :: Python :: 3.9
+                Programming Language :: Python :: 3.11
+
+            [options]
+            python_requires = >=3.8
+        "};
+        assert_eq!(
+            find_python_requires(setup_cfg).map(|specifiers| specifiers.to_string()),
+            Some(">=3.8".to_string())
+        );
+        assert_eq!(find_lowest_classifier(setup_cfg), Some(9));
+
+        let setup_py = r#"setup(name="foo", python_requires=">=3.7, <4")"#;
+        let specifiers = find_python_requires(setup_py).unwrap();
+        assert_eq!(lower_bound(&specifiers), Some(7));
+    }
+}
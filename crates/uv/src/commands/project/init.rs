@@ -9,35 +9,44 @@ use owo_colors::OwoColorize;
 use toml_edit::{InlineTable, Value};
 use tracing::{debug, trace, warn};
 
-use uv_cache::Cache;
-use uv_cli::AuthorFrom;
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_cli::{AuthorFrom, ScriptTemplate};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    DependencyGroupsWithDefaults, ProjectBuildBackend, VersionControlError, VersionControlSystem,
+    Concurrency, DependencyGroupsWithDefaults, DryRun, ProjectBuildBackend, VersionControlError,
+    VersionControlSystem,
 };
 use uv_distribution_types::RequiresPython;
 use uv_fs::{CWD, Simplified};
 use uv_git::GIT;
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, Requirement};
+use uv_preview::Preview;
+use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
     VersionRequest,
 };
-use uv_scripts::{Pep723Script, ScriptTag};
+use uv_scripts::{Pep723Script, ScriptContents, ScriptTag};
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::Source;
 use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{
     DiscoveryOptions, MemberDiscovery, Workspace, WorkspaceCache, WorkspaceErrorKind,
 };
 
 use crate::commands::ExitStatus;
+use crate::commands::project::infer;
+use crate::commands::project::lock::lock;
 use crate::commands::project::{find_requires_python, init_script_python_requirement};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::printer::Printer;
+use crate::settings::{LockCheck, ResolverSettings};
 
 /// Add one or more packages to the project requirements.
 #[expect(clippy::single_match_else, clippy::fn_params_excessive_bools)]
@@ -47,6 +56,7 @@ pub(crate) async fn init(
     name: Option<PackageName>,
     package: bool,
     init_kind: InitKind,
+    script: InitScriptOptions,
     bare: bool,
     description: Option<String>,
     no_description: bool,
@@ -55,15 +65,21 @@ pub(crate) async fn init(
     no_readme: bool,
     author_from: Option<AuthorFrom>,
     pin_python: bool,
+    infer_requires_python: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
+    depends_on: Vec<PackageName>,
+    no_lock: bool,
+    settings: ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    concurrency: Concurrency,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
+    preview: Preview,
 ) -> Result<ExitStatus> {
     match init_kind {
         InitKind::Script => {
@@ -73,6 +89,7 @@ pub(crate) async fn init(
 
             init_script(
                 path,
+                script,
                 bare,
                 python,
                 install_mirrors,
@@ -141,7 +158,7 @@ pub(crate) async fn init(
                 }
             };
 
-            Box::pin(init_project(
+            let workspace_root = Box::pin(init_project(
                 &path,
                 &name,
                 package,
@@ -154,9 +171,11 @@ pub(crate) async fn init(
                 no_readme,
                 author_from,
                 pin_python,
-                python,
-                install_mirrors,
+                infer_requires_python,
+                python.clone(),
+                install_mirrors.clone(),
                 no_workspace,
+                &depends_on,
                 client_builder,
                 python_preference,
                 python_downloads,
@@ -191,6 +210,41 @@ pub(crate) async fn init(
                     )?;
                 }
             }
+
+            // If the project was added to a workspace with an existing lockfile, update the
+            // lockfile to include the new member.
+            if let Some(workspace_root) = workspace_root {
+                if no_lock {
+                    debug!("Skipping lockfile update due to `--no-lock`");
+                } else if workspace_root.join("uv.lock").is_file() {
+                    return Box::pin(lock(
+                        &workspace_root,
+                        LockCheck::Disabled,
+                        None,
+                        DryRun::Disabled,
+                        Refresh::None(Timestamp::now()),
+                        python,
+                        install_mirrors,
+                        settings,
+                        client_builder.clone(),
+                        None,
+                        python_preference,
+                        python_downloads,
+                        concurrency,
+                        no_config,
+                        cache,
+                        &WorkspaceCache::default(),
+                        printer,
+                        preview,
+                    ))
+                    .await;
+                } else {
+                    debug!(
+                        "Skipping lockfile update; no `uv.lock` found in `{}`",
+                        workspace_root.user_display()
+                    );
+                }
+            }
         }
     }
 
@@ -200,6 +254,7 @@ pub(crate) async fn init(
 #[expect(clippy::fn_params_excessive_bools)]
 async fn init_script(
     script_path: &Path,
+    options: InitScriptOptions,
     bare: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -228,10 +283,34 @@ async fn init_script(
         warn_user_once!("`--package` is a no-op for Python scripts, which are standalone");
     }
 
+    let Some(script_name) = script_path.file_name().and_then(|name| name.to_str()) else {
+        anyhow::bail!(
+            "Invalid script path `{}`",
+            script_path.simplified_display().cyan()
+        );
+    };
+
+    // Validate the requested dependencies before touching the file.
+    let mut dependencies = options
+        .dependencies
+        .iter()
+        .map(|dependency| {
+            Requirement::<VerbatimParsedUrl>::from_str(dependency)
+                .with_context(|| format!("Failed to parse dependency: `{dependency}`"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
     let reporter = PythonDownloadReporter::single(printer);
 
     // If the file already exists, read its content.
-    let content = match fs_err::tokio::read(script_path).await {
+    let contents = match fs_err::tokio::read(script_path).await {
+        Ok(_) if options.force => {
+            debug!(
+                "Overwriting existing file at `{}`",
+                script_path.user_display()
+            );
+            None
+        }
         Ok(metadata) => {
             // If the file is already a script, raise an error.
             if ScriptTag::parse(&metadata)?.is_some() {
@@ -241,10 +320,27 @@ async fn init_script(
                     "uv run".green()
                 );
             }
+            if !options.convert {
+                anyhow::bail!(
+                    "`{}` already exists; use `{}` to add inline metadata to it, or `{}` to overwrite it",
+                    script_path.simplified_display().cyan(),
+                    "--convert".green(),
+                    "--force".green()
+                );
+            }
 
             Some(metadata)
         }
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            if options.convert {
+                anyhow::bail!(
+                    "`{}` does not exist; omit `{}` to create a new script",
+                    script_path.simplified_display().cyan(),
+                    "--convert".green()
+                );
+            }
+            None
+        }
         Err(err) => {
             return Err(err).with_context(|| {
                 format!(
@@ -273,12 +369,131 @@ async fn init_script(
         fs_err::tokio::create_dir_all(parent).await?;
     }
 
-    Pep723Script::create(script_path, requires_python.specifiers(), content, bare).await?;
+    let contents = match contents {
+        Some(contents) => ScriptContents::Existing(contents),
+        None if bare => ScriptContents::Bare,
+        None => {
+            // Add the template's own requirements, unless the user requested them explicitly.
+            for requirement in script_template_dependencies(options.template) {
+                let requirement = Requirement::<VerbatimParsedUrl>::from_str(requirement)?;
+                if !dependencies
+                    .iter()
+                    .any(|dependency| dependency.name == requirement.name)
+                {
+                    dependencies.push(requirement);
+                }
+            }
+            ScriptContents::Template(script_template(options.template, script_name))
+        }
+    };
+    #[cfg(unix)]
+    let executable = matches!(contents, ScriptContents::Template(_));
+
+    Pep723Script::create(
+        script_path,
+        requires_python.specifiers(),
+        &dependencies,
+        contents,
+    )
+    .await?;
+
+    // Allow new scripts to be executed directly via their shebang.
+    #[cfg(unix)]
+    if executable {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut permissions = fs_err::tokio::metadata(script_path).await?.permissions();
+        // Grant execute permissions to everyone who can read the file.
+        permissions.set_mode(permissions.mode() | ((permissions.mode() & 0o444) >> 2));
+        fs_err::tokio::set_permissions(script_path, permissions).await?;
+    }
 
     Ok(())
 }
 
+/// Options for initializing a PEP 723 script.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InitScriptOptions {
+    /// Requirements to add to the script's inline metadata.
+    pub(crate) dependencies: Vec<String>,
+    /// The starter content for a new script.
+    pub(crate) template: ScriptTemplate,
+    /// Overwrite the script if it already exists.
+    pub(crate) force: bool,
+    /// Add inline metadata to an existing script.
+    pub(crate) convert: bool,
+}
+
+/// The requirements needed by the given [`ScriptTemplate`].
+fn script_template_dependencies(template: ScriptTemplate) -> &'static [&'static str] {
+    match template {
+        ScriptTemplate::Default | ScriptTemplate::Cli | ScriptTemplate::NotebookExport => &[],
+        ScriptTemplate::Fastapi => &["fastapi", "uvicorn"],
+    }
+}
+
+/// The body of a new script (i.e., the content following the inline metadata) for the given
+/// [`ScriptTemplate`].
+fn script_template(template: ScriptTemplate, name: &str) -> String {
+    match template {
+        ScriptTemplate::Default => indoc::formatdoc! {r#"
+
+            def main() -> None:
+                print("Hello from {name}!")
+
+
+            if __name__ == "__main__":
+                main()
+        "#},
+        ScriptTemplate::Cli => indoc::formatdoc! {r#"
+
+            import argparse
+
+
+            def main() -> None:
+                parser = argparse.ArgumentParser(prog="{name}")
+                parser.add_argument("name", nargs="?", default="world")
+                args = parser.parse_args()
+                print(f"Hello, {{args.name}}!")
+
+
+            if __name__ == "__main__":
+                main()
+        "#},
+        ScriptTemplate::NotebookExport => indoc::formatdoc! {r#"
+
+            # %% [markdown]
+            # # {name}
+
+            # %%
+            message = "Hello from {name}!"
+
+            # %%
+            print(message)
+        "#},
+        ScriptTemplate::Fastapi => indoc::formatdoc! {r#"
+
+            from fastapi import FastAPI
+
+            app = FastAPI()
+
+
+            @app.get("/")
+            def read_root() -> dict[str, str]:
+                return {{"message": "Hello from {name}!"}}
+
+
+            if __name__ == "__main__":
+                import uvicorn
+
+                uvicorn.run(app)
+        "#},
+    }
+}
+
 /// Initialize a project (and, implicitly, a workspace root) at the given path.
+///
+/// Returns the root of the workspace the project was added to, if any.
 #[expect(clippy::fn_params_excessive_bools)]
 async fn init_project(
     path: &Path,
@@ -294,16 +509,18 @@ async fn init_project(
     no_readme: bool,
     author_from: Option<AuthorFrom>,
     pin_python: bool,
+    infer_requires_python: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
+    depends_on: &[PackageName],
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<()> {
+) -> Result<Option<PathBuf>> {
     // Discover the current workspace, if it exists.
     let workspace_cache = WorkspaceCache::default();
     let workspace = {
@@ -366,10 +583,68 @@ async fn init_project(
         }
     };
 
+    // Validate that any requested dependencies refer to members of the enclosing workspace.
+    if !depends_on.is_empty() {
+        let Some(workspace) = workspace.as_deref() else {
+            bail!(
+                "`{}` requires the project to be initialized inside a workspace",
+                "--depends-on".green()
+            );
+        };
+        if workspace.excludes(path)? {
+            bail!(
+                "`{}` cannot be used for a project that is excluded from workspace `{}`",
+                "--depends-on".green(),
+                workspace.install_path().simplified_display().cyan()
+            );
+        }
+        for dependency in depends_on {
+            if !workspace.packages().contains_key(dependency) {
+                bail!(
+                    "`{}` is not a member of workspace `{}`",
+                    dependency.cyan(),
+                    workspace.install_path().simplified_display().cyan()
+                );
+            }
+        }
+    }
+
     let reporter = PythonDownloadReporter::single(printer);
 
+    // If requested, infer the minimum supported Python version from the existing codebase. An
+    // explicit `--python` request takes precedence.
+    let inferred_request = if !infer_requires_python {
+        None
+    } else if python.is_some() {
+        warn_user!("`--infer-requires-python` has no effect when `--python` is provided");
+        None
+    } else if let Some(inferred) = infer::infer_requires_python(path) {
+        writeln!(
+            printer.stderr(),
+            "Inferred {} from:",
+            format!("`requires-python = \">={}\"`", inferred.version).cyan()
+        )?;
+        for evidence in &inferred.evidence {
+            writeln!(printer.stderr(), "  - {evidence}")?;
+        }
+        Some(PythonRequest::Version(VersionRequest::Range(
+            VersionSpecifiers::from(VersionSpecifier::greater_than_equal_version(
+                inferred.version,
+            )),
+            PythonVariant::Default,
+        )))
+    } else {
+        warn_user!(
+            "Unable to infer a minimum Python version from the existing codebase; using the default"
+        );
+        None
+    };
+
     // First, determine if there is an request for Python
-    let python_request = if let Some(request) = python {
+    let python_request = if let Some(request) = inferred_request {
+        // (0) Inferred from the existing codebase
+        Some(request)
+    } else if let Some(request) = python {
         // (1) Explicit request from user
         Some(PythonRequest::parse(&request))
     } else if let Some(file) = PythonVersionFile::discover(
@@ -420,7 +695,7 @@ async fn init_project(
     )?;
 
     if let Some(workspace) = workspace {
-        if workspace.excludes(path)? {
+        let workspace_root = if workspace.excludes(path)? {
             // If the member is excluded by the workspace, ignore it.
             writeln!(
                 printer.stderr(),
@@ -428,6 +703,7 @@ async fn init_project(
                 name.cyan(),
                 workspace.install_path().simplified_display().cyan()
             )?;
+            None
         } else if workspace.includes(path)? {
             // If the member is already included in the workspace, skip the `members` addition.
             writeln!(
@@ -436,6 +712,7 @@ async fn init_project(
                 name.cyan(),
                 workspace.install_path().simplified_display().cyan()
             )?;
+            Some(workspace.install_path().clone())
         } else {
             // Add the package to the workspace.
             let mut pyproject = PyProjectTomlMut::from_toml(
@@ -456,7 +733,14 @@ async fn init_project(
                 name.cyan(),
                 workspace.install_path().simplified_display().cyan()
             )?;
+            Some(workspace.install_path().clone())
+        };
+
+        // Add any requested workspace members as dependencies of the new project.
+        if !depends_on.is_empty() {
+            add_workspace_dependencies(path, name, depends_on, printer)?;
         }
+
         // Write .python-version if it doesn't exist in the workspace or if the version differs
         if let Some(python_request) = python_pin {
             if PythonVersionFile::discover(path, &VersionFileDiscoveryOptions::default())
@@ -476,6 +760,8 @@ async fn init_project(
                     .await?;
             }
         }
+
+        Ok(workspace_root)
     } else {
         // Write .python-version if it doesn't exist in the project directory.
         if let Some(python_request) = python_pin {
@@ -491,7 +777,53 @@ async fn init_project(
                     .await?;
             }
         }
+
+        Ok(None)
     }
+}
+
+/// Add dependencies on the given workspace members to the project at the given path.
+///
+/// Each dependency is added to `project.dependencies`, with a `{ workspace = true }` entry in
+/// `tool.uv.sources`.
+fn add_workspace_dependencies(
+    path: &Path,
+    name: &PackageName,
+    depends_on: &[PackageName],
+    printer: Printer,
+) -> Result<()> {
+    let pyproject_path = path.join("pyproject.toml");
+    let mut pyproject = PyProjectTomlMut::from_toml(
+        &fs_err::read_to_string(&pyproject_path)?,
+        DependencyTarget::PyProjectToml,
+    )?;
+
+    let source = Source::Workspace {
+        workspace: true,
+        editable: None,
+        marker: MarkerTree::TRUE,
+        extra: None,
+        group: None,
+    };
+    for dependency in depends_on {
+        let requirement = Requirement {
+            name: dependency.clone(),
+            extras: Box::new([]),
+            version_or_url: None,
+            marker: MarkerTree::TRUE,
+            origin: None,
+        };
+        pyproject.add_dependency(&requirement, Some(&source), false)?;
+
+        writeln!(
+            printer.stderr(),
+            "Adding workspace member `{}` as a dependency of `{}`",
+            dependency.cyan(),
+            name.cyan()
+        )?;
+    }
+
+    fs_err::write(pyproject_path, pyproject.to_string())?;
 
     Ok(())
 }
@@ -142,6 +142,7 @@ impl<'lock> InstallTarget<'lock> {
         if use_concrete_roots
             && let Some(roots) = self
                 .roots()
+                .filter(|root_name| self.supports_root(root_name, marker_env))
                 .map(|root_name| self.lock().find_by_name(root_name).ok().flatten())
                 .collect::<Option<Vec<_>>>()
         {
@@ -202,6 +203,17 @@ impl<'lock> InstallTarget<'lock> {
         }
     }
 
+    /// Returns `true` if the target enforces the provenance recorded in the lockfile.
+    pub(crate) fn lock_provenance(self) -> bool {
+        match self {
+            Self::Project { workspace, .. }
+            | Self::Projects { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace.lock_provenance(),
+            Self::Script { .. } => false,
+        }
+    }
+
     /// Return an iterator over all [`Sources`] defined by the target.
     pub(crate) fn sources(&self) -> impl Iterator<Item = &Source> {
         match self {
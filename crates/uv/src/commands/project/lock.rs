@@ -5,11 +5,13 @@ use std::fmt::Write;
 use std::path::Path;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cache_key::CanonicalUrl;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExcludeDependency,
@@ -25,13 +27,14 @@ use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::{ExtrasResolver, LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    FlatIndex, InMemoryIndex, LicensePolicyChecker, Lock, Options, OptionsBuilder, Package,
+    PythonRequirement, ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
@@ -50,6 +53,7 @@ use crate::commands::project::{
     WorkspacePython, init_script_python_requirement, script_extra_build_requires,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
+use crate::commands::resolution_cache::{ResolutionCache, ResolutionInputs};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
 use crate::printer::Printer;
 use crate::settings::{FrozenSource, LockCheck, LockCheckSource, ResolverSettings};
@@ -86,6 +90,8 @@ pub(crate) async fn lock(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    show_forks: bool,
+    refresh_url_hashes: bool,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -198,6 +204,35 @@ pub(crate) async fn lock(
         }
     };
 
+    // If requested, refresh any direct URL packages in the existing lockfile, such that their
+    // artifacts are re-downloaded and their hashes recomputed.
+    let refreshed_cache;
+    let (refresh, cache) = if refresh_url_hashes && let Some(lock) = target.read().await? {
+        let packages = lock
+            .packages()
+            .iter()
+            .filter(|package| package.is_direct_url())
+            .map(|package| package.name().clone())
+            .collect::<Vec<_>>();
+        if packages.is_empty() {
+            (refresh, cache)
+        } else {
+            debug!(
+                "Refreshing hashes for direct URL packages: {}",
+                packages.iter().join(", ")
+            );
+            let refresh = refresh.combine(Refresh::from_args(None, packages));
+            refreshed_cache = cache.clone().with_refresh(
+                refresh
+                    .clone()
+                    .combine(Refresh::from(settings.upgrade.clone())),
+            );
+            (refresh, &refreshed_cache)
+        }
+    } else {
+        (refresh, cache)
+    };
+
     // Initialize any shared state.
     let state = UniversalState::default();
 
@@ -265,6 +300,10 @@ pub(crate) async fn lock(
                 }
             }
 
+            if show_forks {
+                report_forks(lock.lock(), printer)?;
+            }
+
             Ok(ExitStatus::Success)
         }
         // Lock mismatches from `--check`/`--locked` are expected validation failures.
@@ -282,6 +321,49 @@ pub(crate) async fn lock(
     }
 }
 
+/// Report the forks of a universal resolution, along with the packages that differ between them.
+fn report_forks(lock: &Lock, printer: Printer) -> anyhow::Result<()> {
+    if lock.fork_markers().is_empty() {
+        writeln!(printer.stdout(), "The resolution did not fork")?;
+        return Ok(());
+    }
+
+    // Identify the packages that were resolved to more than one version (or source).
+    let mut counts = FxHashMap::<&PackageName, usize>::default();
+    for package in lock.packages() {
+        *counts.entry(package.name()).or_default() += 1;
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Resolved {} forks:",
+        lock.fork_markers().len()
+    )?;
+    for fork in lock.fork_markers() {
+        let marker = lock.simplify_environment(fork.pep508());
+        writeln!(
+            printer.stdout(),
+            "{}",
+            marker
+                .try_to_string()
+                .unwrap_or_else(|| "*".to_string())
+                .bold()
+        )?;
+        for package in lock.packages() {
+            if counts[package.name()] < 2 || !package.is_included_by_marker(fork.pep508()) {
+                continue;
+            }
+            if let Some(version) = package.version() {
+                writeln!(printer.stdout(), "  {}=={version}", package.name())?;
+            } else {
+                writeln!(printer.stdout(), "  {}", package.name())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -299,6 +381,7 @@ pub(crate) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
     refresh: Option<&'env Refresh>,
+    allow_index_fallback: bool,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -328,6 +411,7 @@ impl<'env> LockOperation<'env> {
             mode,
             constraints: vec![],
             refresh: None,
+            allow_index_fallback: true,
             settings,
             client_builder,
             state,
@@ -357,16 +441,23 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set whether locked packages may be resolved from a different index than the one recorded
+    /// in the existing lockfile.
+    #[must_use]
+    pub(crate) fn with_index_fallback(mut self, allow_index_fallback: bool) -> Self {
+        self.allow_index_fallback = allow_index_fallback;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(crate) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
             LockMode::Frozen(source) => {
                 // Read the existing lockfile, but don't attempt to lock the project.
                 let lock_filename = target.lock_filename();
-                let existing = target
-                    .read()
-                    .await?
-                    .ok_or(ProjectError::MissingLockfile(source, lock_filename))?;
+                let existing = target.read().await?.ok_or_else(|| {
+                    ProjectError::MissingLockfile(source, lock_filename, target.into())
+                })?;
 
                 // Check if the discovered workspace members match the locked workspace members.
                 if let LockTarget::Workspace(workspace) = target {
@@ -384,10 +475,9 @@ impl<'env> LockOperation<'env> {
             LockMode::Locked(interpreter, lock_source) => {
                 // Read the existing lockfile.
                 let lock_filename = target.lock_filename();
-                let existing = target.read().await?.ok_or(ProjectError::MissingLockfile(
-                    lock_source.into(),
-                    lock_filename,
-                ))?;
+                let existing = target.read().await?.ok_or_else(|| {
+                    ProjectError::MissingLockfile(lock_source.into(), lock_filename, target.into())
+                })?;
 
                 // Perform the lock operation, but don't write the lockfile to disk.
                 let result = Box::pin(do_lock(
@@ -414,6 +504,7 @@ impl<'env> LockOperation<'env> {
                         prev.map(Box::new),
                         Box::new(cur),
                         lock_source,
+                        target.into(),
                     ));
                 }
 
@@ -452,6 +543,19 @@ impl<'env> LockOperation<'env> {
                 ))
                 .await?;
 
+                // Unless allowed, reject any locked package that would now be resolved from a
+                // different index.
+                if !self.allow_index_fallback {
+                    if let LockResult::Changed(Some(prev), cur) = &result {
+                        check_locked_indexes(
+                            prev,
+                            cur,
+                            &self.settings.index_locations,
+                            target.install_path(),
+                        )?;
+                    }
+                }
+
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
@@ -465,6 +569,61 @@ impl<'env> LockOperation<'env> {
     }
 }
 
+/// Verify that each package version in the existing lockfile is resolved from the same index in
+/// the updated lockfile, as long as the locked index is still configured.
+fn check_locked_indexes(
+    prev: &Lock,
+    cur: &Lock,
+    index_locations: &IndexLocations,
+    root: &Path,
+) -> Result<(), ProjectError> {
+    let indexes = index_locations.allowed_indexes();
+    for package in cur.packages() {
+        let Some(index) = package.index(root)? else {
+            continue;
+        };
+        let Some(version) = package.version() else {
+            continue;
+        };
+
+        // Collect the indexes from which this version was previously locked. (The same version
+        // may be locked from multiple indexes, e.g., in disjoint forks.)
+        let mut locked_indexes = Vec::new();
+        for locked in prev.packages() {
+            if locked.name() == package.name() && locked.version() == Some(version) {
+                if let Some(locked_index) = locked.index(root)? {
+                    locked_indexes.push(locked_index);
+                }
+            }
+        }
+
+        let url = CanonicalUrl::new(index.url());
+        if locked_indexes
+            .iter()
+            .any(|locked_index| CanonicalUrl::new(locked_index.url()) == url)
+        {
+            continue;
+        }
+
+        // If the locked index is no longer configured, the change is intentional.
+        let Some(locked_index) = locked_indexes.iter().find(|locked_index| {
+            let locked_url = CanonicalUrl::new(locked_index.url());
+            indexes
+                .iter()
+                .any(|allowed| CanonicalUrl::new(allowed.url.url()) == locked_url)
+        }) else {
+            continue;
+        };
+
+        return Err(ProjectError::LockedIndexFallback(
+            format!("{}=={version}", package.name()),
+            locked_index.without_credentials().to_string(),
+            index.without_credentials().to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Lock the project requirements into a lockfile.
 async fn do_lock(
     target: LockTarget<'_>,
@@ -492,6 +651,8 @@ async fn do_lock(
         resolution,
         prerelease,
         fork_strategy,
+        prefetch_limit,
+        resolution_cache,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -508,6 +669,11 @@ async fn do_lock(
         amd_gpu_architecture: _,
     } = settings;
 
+    // Fail fast if the workspace members form a dependency cycle.
+    if let LockTarget::Workspace(workspace) = target {
+        workspace.validate_member_cycles()?;
+    }
+
     // Collect the requirements, etc.
     let members = target.members();
     let packages = target.packages();
@@ -698,6 +864,25 @@ async fn do_lock(
         default
     };
 
+    // Collect the license policy.
+    let license_policy = target
+        .license_policy()
+        .map(LicensePolicyChecker::new)
+        .transpose()?;
+
+    // If the workspace members declare disjoint `requires-python` bounds, resolve each member in
+    // its own fork.
+    let members_requires_python = target.members_requires_python()?;
+    if !members_requires_python.is_empty() {
+        debug!(
+            "Resolving workspace members against disjoint Python requirements: {}",
+            members_requires_python
+                .iter()
+                .map(|(name, requires_python)| format!("{name} ({requires_python})"))
+                .join(", ")
+        );
+    }
+
     // If any of the forks are incompatible with the Python requirement, error.
     for environment in environments
         .map(SupportedEnvironments::as_markers)
@@ -769,6 +954,7 @@ async fn do_lock(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefetch_limit(*prefetch_limit)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -843,6 +1029,70 @@ async fn do_lock(
         concurrency.downloads_semaphore.clone(),
     );
 
+    // Key the resolution cache, if enabled, on the resolver inputs. The existing lockfile is
+    // included, since its versions are used as preferences. Members with dynamic dependencies are
+    // excluded, since their requirements can't be determined without invoking the build backend.
+    let resolution_cache = if !*resolution_cache || !upgrade.is_none() {
+        None
+    } else if packages.values().any(|member| {
+        member.project().is_dynamic("dependencies")
+            || member.project().is_dynamic("optional-dependencies")
+    }) {
+        debug!("Skipping resolution cache for workspace with dynamic dependencies");
+        None
+    } else {
+        let inputs = ResolutionInputs::default()
+            .displayed("install-path", [target.install_path().display()])
+            .displayed(
+                "pyproject-toml",
+                packages
+                    .values()
+                    .map(|member| member.pyproject_toml().raw.as_str()),
+            )
+            .displayed("members", &members)
+            .serialized("requirements", &requirements)
+            .serialized("constraints", &constraints)
+            .serialized("overrides", &overrides)
+            .serialized("excludes", &excludes)
+            .serialized("build-constraints", &build_constraints)
+            .serialized("dependency-groups", &dependency_groups)
+            .serialized(
+                "external",
+                &external
+                    .iter()
+                    .map(|external| (&external.requirement, &external.hashes))
+                    .collect::<Vec<_>>(),
+            )
+            .serialized(
+                "conflicts",
+                &conflicts
+                    .iter()
+                    .map(|set| set.iter().collect::<Vec<_>>())
+                    .collect::<Vec<_>>(),
+            )
+            .serialized("environments", &environments)
+            .serialized("required-environments", &required_environments)
+            .serialized("requires-python", &requires_python)
+            .serialized("members-requires-python", &members_requires_python)
+            .serialized(
+                "dependency-metadata",
+                &dependency_metadata.values().sorted().collect::<Vec<_>>(),
+            )
+            .serialized("index-locations", index_locations)
+            .serialized("index-strategy", index_strategy)
+            .serialized("resolution", resolution)
+            .serialized("prerelease", prerelease)
+            .serialized("fork-strategy", fork_strategy)
+            .serialized("exclude-newer", exclude_newer)
+            .serialized("build-options", build_options)
+            .serialized("markers", interpreter.markers())
+            .displayed(
+                "existing-lock",
+                existing_lock.as_ref().and_then(|lock| lock.to_toml().ok()),
+            );
+        ResolutionCache::new(cache, &inputs)
+    };
+
     // If any of the resolution-determining settings changed, invalidate the lock.
     let existing_lock = if let Some(existing_lock) = existing_lock {
         match ValidatedLock::validate(
@@ -863,6 +1113,7 @@ async fn do_lock(
             dependency_metadata,
             interpreter,
             &requires_python,
+            &members_requires_python,
             index_locations,
             upgrade,
             refresh,
@@ -899,12 +1150,64 @@ async fn do_lock(
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
+            // Enforce the license policy against the existing lockfile.
+            if let Some(license_policy) = &license_policy {
+                let violations = lock
+                    .license_violations(
+                        license_policy,
+                        &packages.keys().cloned().collect(),
+                        target.install_path(),
+                        interpreter.tags()?,
+                        interpreter.markers(),
+                        build_options,
+                        &hasher,
+                        state.index(),
+                        &database,
+                    )
+                    .await?;
+                pip::operations::enforce_license_policy(violations)?;
+            }
+
             Ok(LockResult::Unchanged(lock))
         }
 
         // The lockfile did not contain enough information to obtain a resolution, fallback
         // to a fresh resolve.
         _ => {
+            // If the resolver inputs match a previous resolution, reuse it.
+            if let Some(lock) = resolution_cache
+                .as_ref()
+                .and_then(|resolution_cache| resolution_cache.read_lock(target.install_path()))
+            {
+                // Print the success message after completing resolution.
+                logger.on_complete(lock.len(), start, printer)?;
+
+                // Enforce the license policy against the cached resolution.
+                if let Some(license_policy) = &license_policy {
+                    let violations = lock
+                        .license_violations(
+                            license_policy,
+                            &packages.keys().cloned().collect(),
+                            target.install_path(),
+                            interpreter.tags()?,
+                            interpreter.markers(),
+                            build_options,
+                            &hasher,
+                            state.index(),
+                            &database,
+                        )
+                        .await?;
+                    pip::operations::enforce_license_policy(violations)?;
+                }
+
+                let previous = existing_lock.map(ValidatedLock::into_lock);
+                return if previous.as_ref().is_some_and(|previous| *previous == lock) {
+                    Ok(LockResult::Unchanged(lock))
+                } else {
+                    Ok(LockResult::Changed(previous, lock))
+                };
+            }
+
             // Determine whether we can reuse the existing package versions.
             let versions_lock = existing_lock.as_ref().and_then(|lock| match &lock {
                 ValidatedLock::Satisfies(lock) => Some(lock),
@@ -948,10 +1251,11 @@ async fn do_lock(
                             .collect()
                     })
                     .unwrap_or_else(|| {
-                        environments
+                        let environments = environments
                             .cloned()
                             .map(SupportedEnvironments::into_markers)
-                            .unwrap_or_default()
+                            .unwrap_or_default();
+                        member_forks(environments, &requires_python, &members_requires_python)
                     }),
             );
 
@@ -959,7 +1263,14 @@ async fn do_lock(
             let (resolution, _) = pip::operations::resolve(
                 ExtrasResolver::new(&hasher, state.index(), database)
                     .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                    .resolve(target.members_requirements())
+                    .resolve(target.members_requirements().map(|mut requirement| {
+                        // Limit each member to its own Python requirement, if the members are
+                        // resolved in separate forks.
+                        if let Some(member) = members_requires_python.get(&requirement.name) {
+                            requirement.marker.and(member.to_marker_tree());
+                        }
+                        requirement
+                    }))
                     .await
                     .map_err(|err| ProjectError::Operation(err.into()))?
                     .into_iter()
@@ -1014,6 +1325,14 @@ async fn do_lock(
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // Enforce the license policy.
+            if let Some(license_policy) = &license_policy {
+                pip::operations::enforce_license_policy(
+                    resolution
+                        .license_violations(license_policy, &packages.keys().cloned().collect()),
+                )?;
+            }
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
@@ -1024,6 +1343,11 @@ async fn do_lock(
                 dependency_groups,
                 dependency_metadata.values().cloned(),
             )
+            .with_requires_python(
+                members_requires_python
+                    .iter()
+                    .map(|(name, member)| (name.clone(), member.specifiers().clone())),
+            )
             .relative_to(target.install_path())?;
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
@@ -1036,6 +1360,12 @@ async fn do_lock(
             .with_conflicts(conflicts)
             .with_required_environments(lock_required_environments.into_markers());
 
+            if let Some(resolution_cache) = &resolution_cache {
+                resolution_cache
+                    .write_lock(&lock, &members, target.install_path())
+                    .await;
+            }
+
             if previous.as_ref().is_some_and(|previous| *previous == lock) {
                 Ok(LockResult::Unchanged(lock))
             } else {
@@ -1045,6 +1375,42 @@ async fn do_lock(
     }
 }
 
+/// Split the initial forks of a resolution along the `requires-python` bounds of the workspace
+/// members, such that each member is resolved in the forks covered by its bound.
+///
+/// If the workspace doesn't declare any supported environments, the forks partition the
+/// workspace-wide `requires-python` bound.
+fn member_forks(
+    environments: Vec<MarkerTree>,
+    requires_python: &RequiresPython,
+    members_requires_python: &BTreeMap<PackageName, RequiresPython>,
+) -> Vec<MarkerTree> {
+    if members_requires_python.is_empty() {
+        return environments;
+    }
+
+    let mut forks = if environments.is_empty() {
+        vec![requires_python.to_marker_tree()]
+    } else {
+        environments
+    };
+    for member in members_requires_python.values() {
+        let marker = member.to_marker_tree();
+        forks = forks
+            .into_iter()
+            .flat_map(|fork| {
+                let mut included = fork;
+                included.and(marker);
+                let mut excluded = fork;
+                excluded.and(marker.negate());
+                [included, excluded]
+            })
+            .filter(|fork| !fork.is_false())
+            .collect();
+    }
+    forks
+}
+
 #[derive(Debug)]
 pub(crate) enum ValidatedLock {
     /// An existing lockfile was provided, but its contents should be ignored.
@@ -1079,6 +1445,7 @@ impl ValidatedLock {
         dependency_metadata: &DependencyMetadata,
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
+        members_requires_python: &BTreeMap<PackageName, RequiresPython>,
         index_locations: &IndexLocations,
         upgrade: &Upgrade,
         refresh: Option<&Refresh>,
@@ -1225,6 +1592,21 @@ impl ValidatedLock {
             };
         }
 
+        // If the per-member Requires-Python bounds have changed, we have to perform a clean
+        // resolution, since the members are resolved in separate forks.
+        if !lock
+            .members_requires_python()
+            .iter()
+            .eq(members_requires_python
+                .iter()
+                .map(|(name, requires_python)| (name, requires_python.specifiers())))
+        {
+            debug!(
+                "Resolving despite existing lockfile due to change in member Python requirements"
+            );
+            return Ok(Self::Versions(lock));
+        }
+
         // If the pre-release mode has changed, we have to re-resolve, but can retain the existing
         // versions and forks.
         if lock.prerelease_mode() != options.prerelease_mode {
@@ -1460,6 +1842,10 @@ impl ValidatedLock {
                 debug!("Resolving despite existing lockfile due to missing version: `{name}`");
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MissingUrlHash(name) => {
+                debug!("Resolving despite existing lockfile due to missing URL hash: `{name}`");
+                Ok(Self::Preferable(lock))
+            }
         }
     }
 
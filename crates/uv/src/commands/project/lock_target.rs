@@ -13,10 +13,10 @@ use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
 use uv_scripts::Pep723Script;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
-use uv_workspace::pyproject::OverrideDependency;
+use uv_workspace::pyproject::{LicensePolicy, OverrideDependency};
 use uv_workspace::{Editability, Workspace, WorkspaceMember};
 
-use crate::commands::project::{ProjectError, find_requires_python};
+use crate::commands::project::{ProjectError, find_members_requires_python, find_requires_python};
 
 /// A target that can be resolved into a lockfile.
 #[derive(Debug, Copy, Clone)]
@@ -207,6 +207,14 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the license policy for the [`LockTarget`].
+    pub(crate) fn license_policy(self) -> Option<&'lock LicensePolicy> {
+        match self {
+            Self::Workspace(workspace) => workspace.license_policy(),
+            Self::Script(_) => None,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Result<Conflicts, ProjectError> {
         match self {
@@ -259,6 +267,21 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Return the `Requires-Python` bound for each workspace member in the [`LockTarget`], if the
+    /// members declare disjoint bounds and should be resolved in separate forks.
+    pub(crate) fn members_requires_python(
+        self,
+    ) -> Result<BTreeMap<PackageName, RequiresPython>, ProjectError> {
+        match self {
+            Self::Workspace(workspace) => {
+                // As above, don't consider the requires-python bounds on groups when locking.
+                let groups = DependencyGroupsWithDefaults::none();
+                find_members_requires_python(workspace, &groups)
+            }
+            Self::Script(_) => Ok(BTreeMap::new()),
+        }
+    }
+
     /// Return the path to the lock root.
     pub(crate) fn install_path(self) -> &'lock Path {
         match self {
@@ -13,8 +13,9 @@ use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::{cache_digest, cache_name};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    GitLfsSetting, Override, PackageOverride, Reinstall, TargetTriple, Upgrade,
+    CompileBytecode, Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun,
+    ExtrasSpecification, GitLfsSetting, Override, PackageOverride, PlatformMarkerOverrides,
+    Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
@@ -59,6 +60,7 @@ use uv_workspace::{ProjectEnvironmentSelection, RequiresPythonSources, Workspace
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
@@ -73,6 +75,7 @@ pub(crate) mod check;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod format;
+pub(crate) mod infer;
 pub(crate) mod init;
 pub(crate) mod install_target;
 pub(crate) mod lock;
@@ -129,6 +132,50 @@ impl From<LockCheckSource> for MissingLockfileSource {
     }
 }
 
+/// The owner of a lockfile, used to describe how to update it.
+#[derive(Debug, Clone)]
+pub(crate) enum LockfileOwner {
+    /// The `uv.lock` of a project or workspace.
+    Workspace,
+    /// The `.lock` file adjacent to a PEP 723 script.
+    Script(PathBuf),
+}
+
+impl LockfileOwner {
+    /// The path to the lockfile, for display.
+    fn lockfile(&self) -> String {
+        match self {
+            Self::Workspace => "uv.lock".to_string(),
+            Self::Script(path) => format!("{}.lock", path.user_display()),
+        }
+    }
+
+    /// The command that updates the lockfile.
+    fn lock_command(&self) -> String {
+        match self {
+            Self::Workspace => "uv lock".to_string(),
+            Self::Script(path) => format!("uv lock --script {}", path.user_display()),
+        }
+    }
+
+    /// The command that updates the lockfile and syncs the environment.
+    fn sync_command(&self) -> String {
+        match self {
+            Self::Workspace => "uv sync".to_string(),
+            Self::Script(path) => format!("uv sync --script {}", path.user_display()),
+        }
+    }
+}
+
+impl From<LockTarget<'_>> for LockfileOwner {
+    fn from(target: LockTarget<'_>) -> Self {
+        match target {
+            LockTarget::Workspace(_) => Self::Workspace,
+            LockTarget::Script(script) => Self::Script(script.path.clone()),
+        }
+    }
+}
+
 impl From<FrozenSource> for MissingLockfileSource {
     fn from(source: FrozenSource) -> Self {
         match source {
@@ -142,14 +189,18 @@ impl From<FrozenSource> for MissingLockfileSource {
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
     #[error(
-        "The lockfile at `uv.lock` needs to be updated, but `{2}` was provided. To update the lockfile, run `uv lock`."
+        "The lockfile at `{}` needs to be updated, but `{2}` was provided. To update the lockfile, run `{}`.",
+        .3.lockfile(),
+        .3.lock_command()
     )]
-    LockMismatch(Option<Box<Lock>>, Box<Lock>, LockCheckSource),
+    LockMismatch(Option<Box<Lock>>, Box<Lock>, LockCheckSource, LockfileOwner),
 
     #[error(
-        "Unable to find lockfile at `{1}`, but {0} was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag."
+        "Unable to find lockfile at `{1}`, but {0} was provided. To create a lockfile, run `{}` or `{}` without the flag.",
+        .2.lock_command(),
+        .2.sync_command()
     )]
-    MissingLockfile(MissingLockfileSource, PathBuf),
+    MissingLockfile(MissingLockfileSource, PathBuf, LockfileOwner),
 
     #[error(
         "The lockfile at `uv.lock` needs to be updated, but `--frozen` was provided: Missing workspace member `{0}`. To update the lockfile, run `uv lock`."
@@ -179,6 +230,26 @@ pub(crate) enum ProjectError {
     )]
     LockedPlatformIncompatibility(String),
 
+    #[error(
+        "`{0}` was locked from `{1}`, which is not a configured index (required by `tool.uv.lock-provenance`)"
+    )]
+    LockedIndexMismatch(String, String),
+
+    #[error(
+        "`{0}` was locked from `{1}`, but would now be resolved from `{2}` (required by `tool.uv.lock-provenance`)"
+    )]
+    LockedIndexChanged(String, String, String),
+
+    #[error(
+        "`{0}` was locked from `{1}`, which no longer serves it (required by `tool.uv.lock-provenance`)"
+    )]
+    LockedArtifactMissing(String, String),
+
+    #[error(
+        "`{0}` was locked from `{1}`, but would now be resolved from `{2}` (pass `--allow-index-fallback` to allow resolving from a different index)"
+    )]
+    LockedIndexFallback(String, String, String),
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -323,6 +394,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Lock(#[from] uv_resolver::LockError),
 
+    #[error(transparent)]
+    LicensePolicy(#[from] uv_resolver::LicensePolicyError),
+
     #[error(transparent)]
     Operation(#[from] pip::operations::Error),
 
@@ -557,8 +631,10 @@ impl PlatformState {
 
 /// Compute the `Requires-Python` bound for the [`Workspace`].
 ///
-/// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the union of the
-/// `Requires-Python` bounds of all the packages.
+/// For a [`Workspace`] with multiple packages, the `Requires-Python` bound is the intersection of
+/// the `Requires-Python` bounds of all the packages. If the members declare disjoint bounds, the
+/// `Requires-Python` bound is instead the union of the per-member bounds, and each member is
+/// resolved against its own bound (see [`find_members_requires_python`]).
 pub(crate) fn find_requires_python(
     workspace: &Workspace,
     groups: &DependencyGroupsWithDefaults,
@@ -592,10 +668,60 @@ pub(crate) fn find_requires_python(
             }
         }
     }
-    match RequiresPython::intersection(requires_python.iter().map(|(.., specifiers)| specifiers)) {
-        Some(requires_python) => Ok(Some(requires_python)),
-        None => Err(ProjectError::DisjointRequiresPython(requires_python)),
+    if let Some(requires_python) =
+        RequiresPython::intersection(requires_python.iter().map(|(.., specifiers)| specifiers))
+    {
+        return Ok(Some(requires_python));
+    }
+
+    // If the members are individually satisfiable, but disjoint with one another, span all of
+    // them.
+    let members = intersect_members_requires_python(requires_python)?;
+    Ok(RequiresPython::union(
+        members.values().map(RequiresPython::specifiers),
+    ))
+}
+
+/// Compute the `Requires-Python` bound for each member of the [`Workspace`], if the members
+/// declare disjoint bounds.
+///
+/// When the members' bounds intersect (the common case), the workspace is resolved against the
+/// intersection and this returns an empty map. Otherwise, each member is locked in its own fork
+/// of the resolution, and installed only into environments that satisfy its bound.
+pub(crate) fn find_members_requires_python(
+    workspace: &Workspace,
+    groups: &DependencyGroupsWithDefaults,
+) -> Result<BTreeMap<PackageName, RequiresPython>, ProjectError> {
+    let requires_python = workspace.requires_python(groups)?;
+    if RequiresPython::intersection(requires_python.iter().map(|(.., specifiers)| specifiers))
+        .is_some()
+    {
+        return Ok(BTreeMap::new());
+    }
+    intersect_members_requires_python(requires_python)
+}
+
+/// Intersect the `Requires-Python` bounds declared by each workspace member (i.e., for the
+/// project itself and any enabled dependency groups).
+///
+/// Returns an error if the bounds of any individual member are disjoint.
+fn intersect_members_requires_python(
+    requires_python: RequiresPythonSources,
+) -> Result<BTreeMap<PackageName, RequiresPython>, ProjectError> {
+    let mut members = BTreeMap::new();
+    for (package, sources) in &requires_python
+        .into_iter()
+        .chunk_by(|((package, _), _)| package.clone())
+    {
+        let sources = sources.collect::<RequiresPythonSources>();
+        let Some(member) =
+            RequiresPython::intersection(sources.iter().map(|(.., specifiers)| specifiers))
+        else {
+            return Err(ProjectError::DisjointRequiresPython(sources));
+        };
+        members.insert(package, member);
     }
+    Ok(members)
 }
 
 /// Returns an error if the [`Interpreter`] does not satisfy the [`Workspace`] `requires-python`.
@@ -2150,6 +2276,8 @@ pub(crate) async fn resolve_names(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy: _,
+                prefetch_limit: _,
+                resolution_cache: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2347,6 +2475,8 @@ pub(crate) async fn resolve_environment(
         resolution,
         prerelease,
         fork_strategy,
+        prefetch_limit,
+        resolution_cache: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -2381,7 +2511,12 @@ pub(crate) async fn resolve_environment(
     let (tags, resolver_environment) = match resolution_scope {
         EnvironmentResolution::Specific => {
             let tags = pip::resolution_tags(None, python_platform, interpreter)?;
-            let marker_environment = pip::resolution_markers(None, python_platform, interpreter);
+            let marker_environment = pip::resolution_markers(
+                None,
+                python_platform,
+                &PlatformMarkerOverrides::default(),
+                interpreter,
+            );
             (
                 Some(tags),
                 ResolverEnvironment::specific(marker_environment),
@@ -2453,6 +2588,7 @@ pub(crate) async fn resolve_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefetch_limit(*prefetch_limit)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2687,7 +2823,7 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         link_mode,
-        compile_bytecode,
+        &CompileBytecode::from(compile_bytecode),
         &hasher,
         tags,
         &client,
@@ -2698,6 +2834,7 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        None,
         dry_run,
         printer,
         preview,
@@ -2759,6 +2896,8 @@ pub(crate) async fn update_environment(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy,
+                prefetch_limit,
+                resolution_cache: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2794,7 +2933,12 @@ pub(crate) async fn update_environment(
 
     // Determine markers and tags to use for resolution.
     let interpreter = venv.interpreter();
-    let marker_env = pip::resolution_markers(None, python_platform, interpreter);
+    let marker_env = pip::resolution_markers(
+        None,
+        python_platform,
+        &PlatformMarkerOverrides::default(),
+        interpreter,
+    );
     let tags = pip::resolution_tags(None, python_platform, interpreter)?;
 
     // Check if the current environment satisfies the requirements
@@ -2891,6 +3035,7 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefetch_limit(*prefetch_limit)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2988,7 +3133,7 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
-        *compile_bytecode,
+        &CompileBytecode::from(*compile_bytecode),
         &hasher,
         &tags,
         &client,
@@ -2999,6 +3144,7 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        None,
         dry_run,
         printer,
         preview,
@@ -7,6 +7,7 @@ use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, anyhow, bail};
+use console::Term;
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -15,7 +16,8 @@ use tokio::process::Command;
 use tracing::{debug, trace, warn};
 use url::Url;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheArgs, CacheBucket, CacheShard};
+use uv_cache_key::cache_digest;
 use uv_cli::{ExternalCommand, GlobalArgs};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
@@ -24,11 +26,13 @@ use uv_configuration::{
 };
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::Requirement;
+use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -77,8 +81,8 @@ use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics, project, read_env_files};
 use crate::printer::Printer;
 use crate::settings::{
-    FrozenSource, GlobalSettings, LockCheck, LockCheckSource, ResolverInstallerSettings,
-    ResolverSettings,
+    CacheSettings, FrozenSource, GlobalSettings, LockCheck, LockCheckSource,
+    ResolverInstallerSettings, ResolverSettings,
 };
 
 /// Run a command.
@@ -1401,6 +1405,9 @@ pub(crate) enum RunCommand {
     /// Search `sys.path` for the named module and execute its contents as the `__main__` module.
     /// Equivalent to `python -m module`.
     PythonModule(OsString, Vec<OsString>),
+    /// Execute a snippet of Python code.
+    /// Equivalent to `python -c code`.
+    PythonCode(OsString, Vec<OsString>),
     /// Execute a `pythonw` GUI script.
     PythonGuiScript(PathBuf, Vec<OsString>),
     /// Execute a Python package containing a `__main__.py` file.
@@ -1414,7 +1421,7 @@ pub(crate) enum RunCommand {
     /// Execute a `pythonw` script provided via `stdin`.
     PythonGuiStdin(Vec<u8>, Vec<OsString>),
     /// Execute a Python script downloaded from a remote URL.
-    PythonRemote(tempfile::NamedTempFile, Vec<OsString>),
+    PythonRemote(RemoteScript, Vec<OsString>),
     /// Execute an external command.
     External(OsString, Vec<OsString>),
     /// Execute an empty command (in practice, `python` with no arguments).
@@ -1433,23 +1440,137 @@ pub(crate) enum ParsedRunCommand {
 /// The information needed to fetch and execute a remote `uv run` target.
 #[derive(Debug)]
 pub(crate) struct PendingRemoteRunCommand {
-    /// The remote URL to download.
+    /// The remote URL to download, without any hash fragment.
     url: DisplaySafeUrl,
+    /// The expected SHA-256 digest of the script, if pinned via a `#sha256=...` fragment.
+    sha256: Option<String>,
+    /// Whether to skip the confirmation prompt (i.e., `--yes`).
+    yes: bool,
     /// The arguments to forward after the downloaded script path.
     args: Vec<OsString>,
 }
 
 impl PendingRemoteRunCommand {
-    /// Download the remote script and return the URL, temporary file, and forwarded arguments.
+    /// Download the remote script into the cache and return the URL, script, and forwarded
+    /// arguments.
+    ///
+    /// If the script is pinned to a hash that is already present in the cache, it's used without
+    /// consulting the network.
     async fn download(
         self,
         client_builder: &BaseClientBuilder<'_>,
-    ) -> anyhow::Result<(DisplaySafeUrl, tempfile::NamedTempFile, Vec<OsString>)> {
-        let url = self.url.clone();
-        let downloaded_script =
-            ParsedRunCommand::download_remote_script(&self.url, client_builder).await?;
-        Ok((url, downloaded_script, self.args))
+        cache: Cache,
+    ) -> anyhow::Result<(DisplaySafeUrl, RemoteScript, Vec<OsString>)> {
+        let shard = cache.shard(CacheBucket::Scripts, cache_digest(&*self.url));
+
+        // Unless the contents are pinned, require confirmation before downloading.
+        let confirm = self.sha256.is_none() && !self.yes;
+
+        if let Some(sha256) = self.sha256.as_deref()
+            && let Some(path) = find_cached_script(&shard.shard(sha256))?
+        {
+            debug!("Using cached remote script at: {}", path.user_display());
+            return Ok((
+                self.url,
+                RemoteScript {
+                    path,
+                    _cache: cache,
+                },
+                self.args,
+            ));
+        }
+
+        if confirm {
+            confirm_remote_script(&format!(
+                "Download and run the script at `{}`?",
+                self.url.cyan()
+            ))?;
+        }
+
+        let (contents, file_stem) =
+            ParsedRunCommand::download_remote_script(&self.url, confirm, client_builder).await?;
+
+        // Hash the script, and verify it against the pinned digest, if any.
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        HashReader::new(contents.as_slice(), &mut hashers)
+            .finish()
+            .await?;
+        let digest = HashDigest::from(hashers.remove(0));
+        if let Some(sha256) = self.sha256.as_deref()
+            && sha256 != digest.digest.as_str()
+        {
+            bail!(
+                "Hash mismatch for remote script `{}`\n\nExpected:\n  sha256:{sha256}\n\nComputed:\n  {digest}",
+                self.url
+            );
+        }
+
+        // Persist the script to the cache, keyed by its URL and hash.
+        let entry = shard
+            .shard(digest.digest.as_str())
+            .entry(format!("{file_stem}.py"));
+        fs_err::tokio::create_dir_all(entry.dir()).await?;
+        uv_fs::write_atomic(entry.path(), &contents).await?;
+
+        Ok((
+            self.url,
+            RemoteScript {
+                path: entry.into_path_buf(),
+                _cache: cache,
+            },
+            self.args,
+        ))
+    }
+}
+
+/// A remote script that has been downloaded into the cache.
+#[derive(Debug)]
+pub(crate) struct RemoteScript {
+    /// The path to the downloaded script.
+    path: PathBuf,
+    /// The cache containing the script, retained such that a temporary cache (i.e., with
+    /// `--no-cache`) outlives the script's execution.
+    _cache: Cache,
+}
+
+impl RemoteScript {
+    /// Return the path to the downloaded script.
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Return the previously downloaded script in the given cache shard, if any.
+fn find_cached_script(shard: &CacheShard) -> io::Result<Option<PathBuf>> {
+    let entries = match fs_err::read_dir(shard) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("py"))
+        {
+            return Ok(Some(path));
+        }
     }
+    Ok(None)
+}
+
+/// Prompt the user to confirm that a remote script should be run.
+fn confirm_remote_script(prompt: &str) -> anyhow::Result<()> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        bail!(
+            "Running a remote script requires confirmation; pass `--yes` or pin the script's contents with a `#sha256=<digest>` URL fragment"
+        );
+    }
+    if !uv_console::confirm(prompt, &term, false)? {
+        bail!("Declined to run the remote script");
+    }
+    Ok(())
 }
 
 impl ParsedRunCommand {
@@ -1465,6 +1586,7 @@ impl ParsedRunCommand {
     pub(crate) async fn resolve(
         self,
         global_args: &GlobalArgs,
+        cache_args: &CacheArgs,
         filesystem: Option<&FilesystemOptions>,
         environment: &EnvironmentOptions,
     ) -> anyhow::Result<(Option<Pep723Item>, RunCommand)> {
@@ -1486,11 +1608,19 @@ impl ParsedRunCommand {
                 )
                 .http_proxy(settings.network_settings.http_proxy)
                 .https_proxy(settings.network_settings.https_proxy)
-                .no_proxy(settings.network_settings.no_proxy);
+                .no_proxy(settings.network_settings.no_proxy)
+                .min_tls_version(settings.network_settings.min_tls_version)
+                .tls_info(settings.network_settings.tls_info);
+
+                let cache_settings = CacheSettings::resolve(cache_args.clone(), filesystem);
+                let cache =
+                    Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+                        .init()
+                        .await?;
 
                 let (url, downloaded_script, args) =
-                    remote_command.download(&client_builder).await?;
-                let script = match Pep723Metadata::read(&downloaded_script).await {
+                    remote_command.download(&client_builder, cache).await?;
+                let script = match Pep723Metadata::read(downloaded_script.path()).await {
                     Ok(Some(metadata)) => Some(Pep723Item::Remote(metadata, url)),
                     Ok(None) => None,
                     Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
@@ -1503,17 +1633,27 @@ impl ParsedRunCommand {
     }
 
     /// Determine the [`ParsedRunCommand`] for a given set of arguments.
+    #[expect(clippy::fn_params_excessive_bools)]
     pub(crate) fn from_args(
         command: &ExternalCommand,
         module: bool,
+        code: bool,
         script: bool,
         gui_script: bool,
+        yes: bool,
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
         let Some(target) = target else {
             return Ok(Self::Ready(RunCommand::Empty));
         };
 
+        if code {
+            return Ok(Self::Ready(RunCommand::PythonCode(
+                target.clone(),
+                args.to_vec(),
+            )));
+        }
+
         if target.eq_ignore_ascii_case("-") {
             let mut buf = Vec::with_capacity(1024);
             std::io::stdin().read_to_end(&mut buf)?;
@@ -1536,9 +1676,30 @@ impl ParsedRunCommand {
             // We don't do this check on Windows since the file path would
             // be invalid anyway, and thus couldn't refer to a local file.
             if !cfg!(unix) || matches!(target_path.try_exists(), Ok(false)) {
-                let url = DisplaySafeUrl::parse(&target.to_string_lossy())?;
+                let mut url = DisplaySafeUrl::parse(&target.to_string_lossy())?;
+
+                // Extract the pinned hash, if provided (e.g., `#sha256=...`).
+                let sha256 = url
+                    .fragment()
+                    .and_then(|fragment| fragment.strip_prefix("sha256="))
+                    .map(|digest| {
+                        if digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()) {
+                            Ok(digest.to_ascii_lowercase())
+                        } else {
+                            Err(anyhow!(
+                                "Invalid SHA-256 digest in remote script URL: `{digest}`"
+                            ))
+                        }
+                    })
+                    .transpose()?;
+                if sha256.is_some() {
+                    url.set_fragment(None);
+                }
+
                 return Ok(Self::PendingRemote(PendingRemoteRunCommand {
                     url,
+                    sha256,
+                    yes,
                     args: args.to_vec(),
                 }));
             }
@@ -1605,16 +1766,23 @@ impl ParsedRunCommand {
     }
 
     /// Download a remote script target into a temporary file ready for execution.
+    ///
+    /// If `confirm` is set, the user is prompted again if a request is redirected to a different
+    /// host.
     async fn download_remote_script(
         mut url: &DisplaySafeUrl,
+        confirm: bool,
         client_builder: &BaseClientBuilder<'_>,
-    ) -> anyhow::Result<tempfile::NamedTempFile> {
+    ) -> anyhow::Result<(Vec<u8>, String)> {
         let client = client_builder.build()?;
         let mut response = client
             .for_host(url)
             .get(Url::from(url.clone()))
             .send()
             .await?;
+        if confirm {
+            confirm_redirect(url, &response)?;
+        }
 
         let gist_url;
         // If it's a Gist URL, use the GitHub API to get the raw URL.
@@ -1628,27 +1796,27 @@ impl ParsedRunCommand {
                 .get(Url::from(url.clone()))
                 .send()
                 .await?;
+            if confirm {
+                confirm_redirect(url, &response)?;
+            }
         }
 
         let file_stem = url
             .path_segments()
             .and_then(Iterator::last)
             .and_then(|segment| segment.strip_suffix(".py"))
-            .unwrap_or("script");
-        let file = tempfile::Builder::new()
-            .prefix(file_stem)
-            .suffix(".py")
-            .tempfile()?;
-
-        // Stream the response to the file.
-        let mut writer = file.as_file();
+            .unwrap_or("script")
+            .to_string();
+
+        // Read the response into memory; scripts are small enough that streaming to disk is
+        // unnecessary.
+        let mut contents = Vec::new();
         let mut reader = response.bytes_stream();
         while let Some(chunk) = reader.next().await {
-            use std::io::Write;
-            writer.write_all(&chunk?)?;
+            contents.extend_from_slice(&chunk?);
         }
 
-        Ok(file)
+        Ok((contents, file_stem))
     }
 }
 
@@ -1673,6 +1841,7 @@ impl RunCommand {
             | Self::PythonPackage(..)
             | Self::PythonZipapp(..)
             | Self::PythonModule(..)
+            | Self::PythonCode(..)
             | Self::PythonRemote(..)
             | Self::External(..)
             | Self::Empty => Ok(None),
@@ -1691,6 +1860,7 @@ impl RunCommand {
             // the available scripts in the interpreter — we could improve this message
             Self::PythonPackage(target, ..) => target.to_string_lossy(),
             Self::PythonModule(..) => Cow::Borrowed("python -m"),
+            Self::PythonCode(..) => Cow::Borrowed("python -c"),
             Self::PythonGuiScript(..) => {
                 if cfg!(windows) {
                     Cow::Borrowed("pythonw")
@@ -1754,21 +1924,15 @@ impl RunCommand {
                 process.args(args);
                 process
             }
+            Self::PythonCode(code, args) => {
+                let mut process = Command::new(interpreter.sys_executable());
+                process.arg("-c");
+                process.arg(code);
+                process.args(args);
+                process
+            }
             Self::PythonGuiScript(target, args) => {
-                let python_executable = interpreter.sys_executable();
-
-                // Use `pythonw.exe` if it exists, otherwise fall back to `python.exe`.
-                // See `install-wheel-rs::get_script_executable`.gd
-                let pythonw_executable = python_executable
-                    .file_name()
-                    .map(|name| {
-                        let new_name = name.to_string_lossy().replace("python", "pythonw");
-                        python_executable.with_file_name(new_name)
-                    })
-                    .filter(|path| path.is_file())
-                    .unwrap_or_else(|| python_executable.to_path_buf());
-
-                let mut process = Command::new(&pythonw_executable);
+                let mut process = Command::new(gui_executable(interpreter));
                 process.arg(target);
                 process.args(args);
                 process
@@ -1793,20 +1957,7 @@ impl RunCommand {
                 process
             }
             Self::PythonGuiStdin(script, args) => {
-                let python_executable = interpreter.sys_executable();
-
-                // Use `pythonw.exe` if it exists, otherwise fall back to `python.exe`.
-                // See `install-wheel-rs::get_script_executable`.gd
-                let pythonw_executable = python_executable
-                    .file_name()
-                    .map(|name| {
-                        let new_name = name.to_string_lossy().replace("python", "pythonw");
-                        python_executable.with_file_name(new_name)
-                    })
-                    .filter(|path| path.is_file())
-                    .unwrap_or_else(|| python_executable.to_path_buf());
-
-                let mut process = Command::new(&pythonw_executable);
+                let mut process = Command::new(gui_executable(interpreter));
                 process.arg("-c");
 
                 #[cfg(unix)]
@@ -1846,6 +1997,7 @@ impl RunCommand {
             Self::PythonPackage(_, path, _) => path.parent(),
             Self::Python(_)
             | Self::PythonModule(..)
+            | Self::PythonCode(..)
             | Self::PythonStdin(..)
             | Self::PythonGuiStdin(..)
             | Self::PythonRemote(..)
@@ -1889,6 +2041,13 @@ impl std::fmt::Display for RunCommand {
                 }
                 Ok(())
             }
+            Self::PythonCode(_, args) => {
+                write!(f, "python -c")?;
+                for arg in args {
+                    write!(f, " {}", arg.to_string_lossy())?;
+                }
+                Ok(())
+            }
             Self::PythonGuiScript(target, args) => {
                 write!(f, "pythonw {}", target.display())?;
                 for arg in args {
@@ -1919,6 +2078,35 @@ impl std::fmt::Display for RunCommand {
     }
 }
 
+/// Prompt the user to re-confirm a remote script if the request was redirected to another host.
+fn confirm_redirect(url: &DisplaySafeUrl, response: &reqwest::Response) -> anyhow::Result<()> {
+    let redirect = DisplaySafeUrl::ref_cast(response.url());
+    if redirect.host_str() == url.host_str() {
+        return Ok(());
+    }
+    confirm_remote_script(&format!(
+        "`{}` redirected to `{}`. Download and run the script?",
+        url.cyan(),
+        redirect.cyan()
+    ))
+}
+
+/// Return the executable to use for GUI scripts, i.e., `pythonw.exe` if it exists, falling back
+/// to `python.exe`.
+///
+/// See `uv_install_wheel::get_script_executable`.
+fn gui_executable(interpreter: &Interpreter) -> PathBuf {
+    let python_executable = interpreter.sys_executable();
+    python_executable
+        .file_name()
+        .map(|name| {
+            let new_name = name.to_string_lossy().replace("python", "pythonw");
+            python_executable.with_file_name(new_name)
+        })
+        .filter(|path| path.is_file())
+        .unwrap_or_else(|| python_executable.to_path_buf())
+}
+
 /// Resolve a GitHub Gist URL to its raw file URL using the GitHub API.
 async fn resolve_gist_url(
     url: &DisplaySafeUrl,
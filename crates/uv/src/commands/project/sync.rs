@@ -11,16 +11,23 @@ use tracing::{trace, warn};
 use uv_audit::Dependency;
 use uv_audit::osv::{self, Filter};
 use uv_cache::Cache;
+use uv_cache_key::CanonicalUrl;
 use uv_cli::SyncFormat;
-use uv_client::{BaseClientBuilder, CachedClient, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{
+    BaseClientBuilder, CachedClient, FlatIndexClient, MetadataFormat, RegistryClient,
+    RegistryClientBuilder, VersionFiles,
+};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    TargetTriple, Upgrade,
+    CompileBytecode, Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults,
+    DryRun, EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode,
+    InstallOptions, PlatformMarkerOverrides, TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::{Dist, Index, Name, Requirement, Resolution, ResolvedDist, SourceDist};
+use uv_distribution_types::{
+    BuiltDist, Dist, Index, IndexCapabilities, IndexLocations, IndexMetadataRef, IndexUrl,
+    InstalledMetadata, Name, Requirement, Resolution, ResolvedDist, SourceDist,
+};
 use uv_fs::{PortablePathBuf, Simplified};
 use uv_installer::{InstallationStrategy, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
@@ -46,9 +53,10 @@ use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
-    EnvironmentUpdate, LinkErrorReporting, MalwareFindings, PlatformState, ProjectEnvironment,
-    ProjectError, ScriptEnvironment, UniversalState, default_dependency_groups, detect_conflicts,
-    script_extra_build_requires, script_specification, update_environment,
+    EnvironmentUpdate, LinkErrorReporting, LockfileOwner, MalwareFindings, PlatformState,
+    ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState, default_dependency_groups,
+    detect_conflicts, find_members_requires_python, script_extra_build_requires,
+    script_specification, update_environment,
 };
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
@@ -63,6 +71,7 @@ pub(crate) async fn sync(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    allow_index_fallback: bool,
     active: Option<bool>,
     all_packages: bool,
     package: Vec<PackageName>,
@@ -71,6 +80,7 @@ pub(crate) async fn sync(
     editable: Option<EditableMode>,
     install_options: InstallOptions,
     modifications: Modifications,
+    remove_extraneous: Vec<PackageName>,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
@@ -79,6 +89,7 @@ pub(crate) async fn sync(
     settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<Pep723Script>,
+    project_environment: Option<(VirtualProject, PythonEnvironment)>,
     installer_metadata: bool,
     concurrency: Concurrency,
     no_config: bool,
@@ -96,9 +107,18 @@ pub(crate) async fn sync(
         );
     }
 
+    if matches!(modifications, Modifications::Exact) && !remove_extraneous.is_empty() {
+        warn_user!(
+            "`--remove-extraneous` has no effect on an exact sync, which removes all extraneous packages; use `--inexact` to retain the others"
+        );
+    }
+
     // Identify the target.
-    let target = if let Some(script) = script {
-        SyncTarget::Script(script)
+    let (target, project_environment) = if let Some(script) = script {
+        (SyncTarget::Script(script), None)
+    } else if let Some((project, environment)) = project_environment {
+        // The project and its environment were already discovered, e.g., by `uv venv --sync`.
+        (SyncTarget::Project(project), Some(environment))
     } else {
         // Identify the project.
         let project = if frozen.is_some() {
@@ -139,7 +159,7 @@ pub(crate) async fn sync(
             project
         };
 
-        SyncTarget::Project(project)
+        (SyncTarget::Project(project), None)
     };
 
     // Determine the groups and extras to include.
@@ -154,46 +174,98 @@ pub(crate) async fn sync(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
+    // If the workspace members declare disjoint Python requirements, a single project is synced
+    // against its own Python requirement, rather than that of the entire workspace.
+    let member_requires_python = match &target {
+        SyncTarget::Project(project) if !all_packages => {
+            let member = match package.as_slice() {
+                [name] => Some(name),
+                _ => project.project_name(),
+            };
+            member.and_then(|name| {
+                find_members_requires_python(project.workspace(), &groups)
+                    .map(|mut members| members.remove(name).map(|member| (name, member)))
+                    .transpose()
+            })
+        }
+        _ => None,
+    }
+    .transpose()?;
+
+    let python_request = python.as_deref().map(PythonRequest::parse).or_else(|| {
+        member_requires_python
+            .as_ref()
+            .and_then(|(_, requires_python)| PythonRequest::from_requires_python(requires_python))
+    });
+
     // Discover or create the virtual environment.
-    let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
-            ProjectEnvironment::get_or_init(
-                project.workspace(),
-                &groups,
-                python.as_deref().map(PythonRequest::parse),
-                &install_mirrors,
-                &client_builder,
-                python_preference,
-                python_downloads,
-                false,
-                no_config,
-                active,
-                cache,
-                dry_run,
-                LinkErrorReporting::User,
-                printer,
-            )
-            .await?,
-        ),
-        SyncTarget::Script(script) => SyncEnvironment::Script(
-            ScriptEnvironment::get_or_init(
-                script.into(),
-                python.as_deref().map(PythonRequest::parse),
-                &client_builder,
-                python_preference,
-                python_downloads,
-                &install_mirrors,
-                false,
-                no_config,
-                active,
-                cache,
-                dry_run,
-                printer,
-            )
-            .await?,
-        ),
+    let environment = if let Some(environment) = project_environment {
+        SyncEnvironment::Project(ProjectEnvironment::Created(environment))
+    } else {
+        match &target {
+            SyncTarget::Project(project) => SyncEnvironment::Project(
+                ProjectEnvironment::get_or_init(
+                    project.workspace(),
+                    &groups,
+                    python_request,
+                    &install_mirrors,
+                    &client_builder,
+                    python_preference,
+                    python_downloads,
+                    false,
+                    no_config,
+                    active,
+                    cache,
+                    dry_run,
+                    LinkErrorReporting::User,
+                    printer,
+                )
+                .await?,
+            ),
+            SyncTarget::Script(script) => SyncEnvironment::Script(
+                ScriptEnvironment::get_or_init(
+                    script.into(),
+                    python.as_deref().map(PythonRequest::parse),
+                    &client_builder,
+                    python_preference,
+                    python_downloads,
+                    &install_mirrors,
+                    false,
+                    no_config,
+                    active,
+                    cache,
+                    dry_run,
+                    printer,
+                )
+                .await?,
+            ),
+        }
     };
 
+    // Ensure that the interpreter satisfies the Python requirement of the synced member.
+    if let (SyncTarget::Project(project), Some((name, requires_python))) =
+        (&target, &member_requires_python)
+    {
+        let version = environment.interpreter().python_version();
+        if !requires_python.contains(version) {
+            let sources = project
+                .workspace()
+                .requires_python(&groups)?
+                .into_iter()
+                .filter(|((package, _), specifiers)| {
+                    package == *name && !specifiers.contains(version)
+                })
+                .collect();
+            return Err(ProjectError::RequestedPythonProjectIncompatibility(
+                version.clone(),
+                requires_python.clone(),
+                sources,
+                true,
+            )
+            .into());
+        }
+    }
+
     let _lock = environment
         .lock()
         .await
@@ -290,6 +362,19 @@ pub(crate) async fn sync(
             .await
             {
                 Ok(EnvironmentUpdate { changelog, .. }) => {
+                    let changelog = match modifications {
+                        Modifications::Sufficient => {
+                            remove_extraneous_packages(
+                                changelog,
+                                &remove_extraneous,
+                                &environment,
+                                dry_run,
+                                printer,
+                            )
+                            .await?
+                        }
+                        Modifications::Exact => changelog,
+                    };
                     write_sync_report(
                         &target,
                         &environment,
@@ -361,6 +446,7 @@ pub(crate) async fn sync(
             printer,
             preview,
         )
+        .with_index_fallback(allow_index_fallback)
         .execute(lock_target),
     )
     .await
@@ -373,16 +459,16 @@ pub(crate) async fn sync(
             .report(err)
             .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
-        Err(ProjectError::LockMismatch(prev, cur, lock_source)) => {
+        Err(ProjectError::LockMismatch(prev, cur, lock_source, owner)) => {
             if dry_run.enabled() {
                 // The lockfile is mismatched, but we're in dry-run mode. We should proceed with the
                 // sync operation, but exit with a non-zero status.
-                Outcome::LockMismatch(prev, cur, lock_source)
+                Outcome::LockMismatch(prev, cur, lock_source, owner)
             } else {
                 writeln!(
                     printer.stderr(),
                     "{}",
-                    ProjectError::LockMismatch(prev, cur, lock_source)
+                    ProjectError::LockMismatch(prev, cur, lock_source, owner)
                         .to_string()
                         .bold()
                 )?;
@@ -400,6 +486,29 @@ pub(crate) async fn sync(
     // Identify the installation target.
     let sync_target = identify_installation_target(&target, outcome.lock(), all_packages, &package);
 
+    // Report any workspace members that are incompatible with the environment's interpreter, if
+    // the members were locked against disjoint Python requirements.
+    if sync_target.project_name().is_none() {
+        let version = environment.interpreter().python_version();
+        for name in sync_target.roots() {
+            if outcome.lock().member_supports_python(name, version) {
+                continue;
+            }
+            let Some(requires_python) = outcome.lock().members_requires_python().get(name) else {
+                continue;
+            };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Skipping workspace member `{}`, which requires Python `{requires_python}` (found {version})",
+                    name.cyan()
+                )
+                .dimmed()
+            )?;
+        }
+    }
+
     // TODO(lucab): improve warning content
     // <https://github.com/astral-sh/uv/issues/7428>
     if let SyncTarget::Project(project) = &target {
@@ -471,6 +580,21 @@ pub(crate) async fn sync(
         Err(err) => return Err(err.into()),
     };
 
+    // Remove any extraneous packages that were explicitly requested, and report on the rest.
+    let changelog = match modifications {
+        Modifications::Sufficient => {
+            remove_extraneous_packages(
+                changelog,
+                &remove_extraneous,
+                &environment,
+                dry_run,
+                printer,
+            )
+            .await?
+        }
+        Modifications::Exact => changelog,
+    };
+
     write_sync_report(
         &target,
         &environment,
@@ -483,11 +607,11 @@ pub(crate) async fn sync(
 
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
-        Outcome::LockMismatch(prev, cur, lock_source) => {
+        Outcome::LockMismatch(prev, cur, lock_source, owner) => {
             writeln!(
                 printer.stderr(),
                 "{}",
-                ProjectError::LockMismatch(prev, cur, lock_source)
+                ProjectError::LockMismatch(prev, cur, lock_source, owner)
                     .to_string()
                     .bold()
             )?;
@@ -503,7 +627,7 @@ enum Outcome {
     /// The `lock` operation was successful.
     Success(LockResult),
     /// The `lock` operation successfully resolved, but failed due to a mismatch (e.g., with `--locked`).
-    LockMismatch(Option<Box<Lock>>, Box<Lock>, LockCheckSource),
+    LockMismatch(Option<Box<Lock>>, Box<Lock>, LockCheckSource, LockfileOwner),
 }
 
 impl Outcome {
@@ -514,7 +638,7 @@ impl Outcome {
                 LockResult::Changed(_, lock) => lock,
                 LockResult::Unchanged(lock) => lock,
             },
-            Self::LockMismatch(_prev, cur, _lock_source) => cur,
+            Self::LockMismatch(_prev, cur, _lock_source, _owner) => cur,
         }
     }
 }
@@ -699,6 +823,8 @@ pub(crate) async fn do_sync(
                 dependency_metadata: dependency_metadata.clone(),
                 exclude_newer: exclude_newer.clone(),
                 fork_strategy: ForkStrategy::default(),
+                prefetch_limit: None,
+                resolution_cache: false,
                 index_locations: index_locations.clone(),
                 index_strategy,
                 keyring_provider,
@@ -748,7 +874,12 @@ pub(crate) async fn do_sync(
     target.validate_groups(groups)?;
 
     // Determine the markers to use for resolution.
-    let marker_env = resolution_markers(None, python_platform, venv.interpreter());
+    let marker_env = resolution_markers(
+        None,
+        python_platform,
+        &PlatformMarkerOverrides::default(),
+        venv.interpreter(),
+    );
 
     // Validate that the platform is supported by the lockfile.
     let environments = target.lock().supported_environments();
@@ -807,6 +938,12 @@ pub(crate) async fn do_sync(
         .platform(venv.interpreter().platform())
         .build()?;
 
+    // If the target enforces the provenance recorded in the lockfile, verify that each registry
+    // package would still be resolved from the index recorded in the lockfile.
+    if target.lock_provenance() {
+        verify_locked_indexes(&resolution, &client, index_locations, concurrency).await?;
+    }
+
     // Determine whether to enable build isolation.
     let build_isolation = match build_isolation {
         uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
@@ -892,7 +1029,7 @@ pub(crate) async fn do_sync(
         reinstall,
         build_options,
         link_mode,
-        compile_bytecode,
+        &CompileBytecode::from(compile_bytecode),
         &hasher,
         &tags,
         &client,
@@ -903,6 +1040,7 @@ pub(crate) async fn do_sync(
         venv,
         logger,
         installer_metadata,
+        None,
         dry_run,
         printer,
         preview,
@@ -912,6 +1050,77 @@ pub(crate) async fn do_sync(
     Ok(changelog)
 }
 
+/// Remove the given packages from the extraneous packages retained by an inexact sync, then
+/// report on any extraneous packages that remain in the environment.
+async fn remove_extraneous_packages(
+    mut changelog: Changelog,
+    packages: &[PackageName],
+    venv: &PythonEnvironment,
+    dry_run: DryRun,
+    printer: Printer,
+) -> Result<Changelog> {
+    let start = std::time::Instant::now();
+
+    for package in packages {
+        if !changelog
+            .extraneous
+            .iter()
+            .any(|dist| dist.name() == package)
+        {
+            warn_user!("`{package}` is not an extraneous package in the environment; skipping");
+        }
+    }
+
+    let (removals, retained): (Vec<_>, Vec<_>) = std::mem::take(&mut changelog.extraneous)
+        .into_iter()
+        .partition(|dist| packages.contains(dist.name()));
+    changelog.extraneous = retained;
+
+    if !removals.is_empty() {
+        if !dry_run.enabled() {
+            let layout = venv.interpreter().layout();
+            for dist in &removals {
+                uv_installer::uninstall(dist, &layout).await?;
+            }
+        }
+
+        let logger = DefaultInstallLogger;
+        logger.on_uninstall(removals.len(), start, printer, dry_run)?;
+        let removed = Changelog::from_uninstalled(removals);
+        logger.on_complete(&removed, printer, dry_run)?;
+        changelog.uninstalled.extend(removed.uninstalled);
+    }
+
+    if !changelog.extraneous.is_empty() {
+        let count = changelog.extraneous.len();
+        let s = if count == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Retained {} not in the lockfile",
+                format!("{count} extraneous package{s}").bold()
+            )
+            .dimmed()
+        )?;
+        for dist in changelog
+            .extraneous
+            .iter()
+            .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+        {
+            writeln!(
+                printer.stderr(),
+                " {} {}{}",
+                "?".yellow(),
+                dist.name().bold(),
+                dist.installed_version().dimmed()
+            )?;
+        }
+    }
+
+    Ok(changelog)
+}
+
 /// Run a malware check against OSV before installing dependencies.
 ///
 /// This queries the OSV batch endpoint with [`Filter::Malware`] to detect only `MAL-`-prefixed
@@ -1001,6 +1210,132 @@ async fn check_malware(
     }
 }
 
+/// Verify that each registry distribution in the [`Resolution`] would be resolved from the index
+/// recorded in the lockfile, and that the index still serves the locked artifact.
+///
+/// Packages locked from an explicit index are looked up on that index alone, as they can only be
+/// assigned to it via `tool.uv.sources`; packages locked from a `--find-links` location are looked
+/// up across the `--find-links` locations; and all other packages are looked up across the
+/// configured indexes, respecting the index strategy.
+async fn verify_locked_indexes(
+    resolution: &Resolution,
+    client: &RegistryClient,
+    index_locations: &IndexLocations,
+    concurrency: &Concurrency,
+) -> Result<(), ProjectError> {
+    let indexes = index_locations.allowed_indexes();
+    let capabilities = IndexCapabilities::default();
+
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist: inner, .. } = dist else {
+            continue;
+        };
+        let (locked, file) = match inner.as_ref() {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (&wheel.index, &wheel.file)
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (&sdist.index, &sdist.file),
+            _ => continue,
+        };
+        let locked_url = CanonicalUrl::new(locked.url());
+
+        // The locked index must still be configured.
+        let Some(index) = indexes
+            .iter()
+            .find(|index| CanonicalUrl::new(index.url.url()) == locked_url)
+        else {
+            return Err(ProjectError::LockedIndexMismatch(
+                dist.to_string(),
+                locked.without_credentials().to_string(),
+            ));
+        };
+
+        // Collect each index that would serve the package, along with whether it serves the
+        // locked artifact.
+        let mut candidates: Vec<(IndexUrl, bool)> = Vec::new();
+        if index_locations
+            .flat_indexes()
+            .any(|flat| CanonicalUrl::new(flat.url.url()) == locked_url)
+        {
+            for entry in client
+                .find_links_entries(dist.name(), &concurrency.downloads_semaphore)
+                .await?
+            {
+                let (_, entry_file, entry_index) = entry.into_parts();
+                let served = entry_file.filename == file.filename;
+                match candidates.iter_mut().find(|(url, _)| *url == entry_index) {
+                    Some((_, existing)) => *existing |= served,
+                    None => candidates.push((entry_index, served)),
+                }
+            }
+        } else {
+            let archives = match client
+                .simple_detail(
+                    dist.name(),
+                    index.explicit.then(|| IndexMetadataRef::from(*index)),
+                    &capabilities,
+                    &concurrency.downloads_semaphore,
+                )
+                .await
+            {
+                Ok(archives) => archives,
+                Err(err)
+                    if matches!(err.kind(), uv_client::ErrorKind::RemotePackageNotFound(_)) =>
+                {
+                    Vec::new()
+                }
+                Err(err) => return Err(err.into()),
+            };
+            for (url, archive) in archives {
+                let served = match archive {
+                    MetadataFormat::Simple(archive) => archive.iter().any(|datum| {
+                        rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                            .expect("archived version files always deserializes")
+                            .all()
+                            .any(|(_, entry_file)| entry_file.filename == file.filename)
+                    }),
+                    MetadataFormat::Flat(entries) => entries.into_iter().any(|entry| {
+                        let (_, entry_file, _) = entry.into_parts();
+                        entry_file.filename == file.filename
+                    }),
+                };
+                candidates.push((url.clone(), served));
+            }
+        }
+
+        // If the locked index serves the locked artifact, the package is resolved from it.
+        if candidates
+            .iter()
+            .any(|(url, served)| *served && CanonicalUrl::new(url.url()) == locked_url)
+        {
+            continue;
+        }
+
+        // Otherwise, prefer an index that serves the locked artifact, then any index that serves
+        // the package.
+        let resolved = candidates
+            .iter()
+            .find(|(_, served)| *served)
+            .or_else(|| candidates.first())
+            .map(|(url, _)| url)
+            .filter(|url| CanonicalUrl::new(url.url()) != locked_url);
+        return Err(match resolved {
+            Some(resolved) => ProjectError::LockedIndexChanged(
+                dist.to_string(),
+                locked.without_credentials().to_string(),
+                resolved.without_credentials().to_string(),
+            ),
+            None => ProjectError::LockedArtifactMissing(
+                file.filename.to_string(),
+                locked.without_credentials().to_string(),
+            ),
+        });
+    }
+
+    Ok(())
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -1404,6 +1739,11 @@ impl PackageChangesReport {
                 .chain(changelog.reinstalled.iter().map(|dist| {
                     PackageChangeReport::from_dist(dist, PackageChangeAction::Reinstalled)
                 }))
+                .chain(changelog.extraneous.iter().map(|dist| PackageChangeReport {
+                    name: dist.name().clone(),
+                    version: Some(dist.version().clone()),
+                    action: PackageChangeAction::Extraneous,
+                }))
                 .collect();
 
         changes.sort_by(|a, b| {
@@ -1445,6 +1785,8 @@ enum PackageChangeAction {
     Uninstalled,
     Installed,
     Reinstalled,
+    /// The package is not in the lockfile, but was retained in the environment.
+    Extraneous,
 }
 
 /// The report for a lock operation.
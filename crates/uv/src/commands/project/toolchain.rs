@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use uv_configuration::{
     BuildOptions, DependencyGroupsWithDefaults, ExtrasSpecification, InstallOptions,
+    PlatformMarkerOverrides,
 };
 use uv_distribution_types::Resolution;
 use uv_normalize::{DefaultExtras, GroupName, PackageName};
@@ -68,7 +69,8 @@ pub(crate) fn resolution_from_lock(
     interpreter: &Interpreter,
     build_options: &BuildOptions,
 ) -> Result<Resolution> {
-    let marker_environment = resolution_markers(None, None, interpreter);
+    let marker_environment =
+        resolution_markers(None, None, &PlatformMarkerOverrides::default(), interpreter);
     let tags = resolution_tags(None, None, interpreter)?;
     let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
     let groups = DependencyGroupsWithDefaults::none();
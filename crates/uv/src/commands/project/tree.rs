@@ -6,7 +6,7 @@ use futures::StreamExt;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
-use uv_configuration::{Concurrency, DependencyGroups, TargetTriple};
+use uv_configuration::{Concurrency, DependencyGroups, PlatformMarkerOverrides, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
 use uv_normalize::DefaultGroups;
 use uv_normalize::PackageName;
@@ -183,6 +183,7 @@ pub(crate) async fn tree(
         resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            &PlatformMarkerOverrides::default(),
             interpreter.as_ref().unwrap(),
         )
     });
@@ -214,6 +215,8 @@ pub(crate) async fn tree(
                 resolution: _,
                 prerelease: _,
                 fork_strategy: _,
+                prefetch_limit: _,
+                resolution_cache: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
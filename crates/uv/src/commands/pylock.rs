@@ -7,7 +7,7 @@ use anyhow::Context;
 use tracing::info_span;
 
 use uv_client::BaseClientBuilder;
-use uv_configuration::{BuildOptions, HashCheckingMode, TargetTriple};
+use uv_configuration::{BuildOptions, HashCheckingMode, PlatformMarkerOverrides, TargetTriple};
 use uv_distribution_types::Resolution;
 use uv_fs::Simplified;
 use uv_normalize::{ExtraName, GroupName};
@@ -63,6 +63,7 @@ pub(crate) fn resolve_pylock_toml(
     interpreter: &Interpreter,
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
     extras: &[ExtraName],
     groups: &[GroupName],
     build_options: &BuildOptions,
@@ -79,7 +80,12 @@ pub(crate) fn resolve_pylock_toml(
     }
 
     let tags = resolution_tags(python_version, python_platform, interpreter)?;
-    let marker_env = resolution_markers(python_version, python_platform, interpreter);
+    let marker_env = resolution_markers(
+        python_version,
+        python_platform,
+        platform_markers,
+        interpreter,
+    );
 
     let resolution = lock.to_resolution(
         install_path,
@@ -1,6 +1,8 @@
 use anyhow::Result;
+use rustc_hash::FxHashSet;
 use std::fmt::Write;
 use std::path::Path;
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
@@ -9,6 +11,7 @@ use uv_errors::ErrorWithHints;
 use uv_fs::Simplified;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    find_all_python_installations,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -22,12 +25,15 @@ use crate::commands::{
 use crate::printer::Printer;
 
 /// Find a Python interpreter.
+///
+/// With `all`, show every interpreter that satisfies the request in discovery order.
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
     resolve_links: bool,
+    all: bool,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -93,6 +99,17 @@ pub(crate) async fn find(
         python_preference,
         cache,
     )?;
+    if python.interpreter().is_cached() {
+        debug!(
+            "Using cached interpreter info for: `{}`",
+            python.interpreter().sys_executable().user_display()
+        );
+    } else {
+        debug!(
+            "Queried interpreter info for: `{}`",
+            python.interpreter().sys_executable().user_display()
+        );
+    }
     python
         .download_and_warn_if_outdated_prerelease(
             &python_request,
@@ -117,19 +134,37 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            python.interpreter().python_version()
-        )?;
+    let installations = if all {
+        find_all_python_installations(
+            &python_request,
+            environment_preference,
+            python_preference,
+            cache,
+        )?
     } else {
+        vec![python]
+    };
+
+    // The same interpreter can be discovered from multiple sources, only show it once.
+    let mut seen = FxHashSet::default();
+    for installation in installations {
         let path = if resolve_links {
-            dunce::canonicalize(python.interpreter().sys_executable())?
+            dunce::canonicalize(installation.interpreter().sys_executable())?
         } else {
-            std::path::absolute(python.interpreter().sys_executable())?
+            std::path::absolute(installation.interpreter().sys_executable())?
         };
-        writeln!(printer.stdout(), "{}", path.simplified_display())?;
+        if !seen.insert(path.clone()) {
+            continue;
+        }
+        if show_version {
+            writeln!(
+                printer.stdout(),
+                "{}",
+                installation.interpreter().python_version()
+            )?;
+        } else {
+            writeln!(printer.stdout(), "{}", path.simplified_display())?;
+        }
     }
 
     Ok(ExitStatus::Success)
@@ -11,15 +11,17 @@ use indexmap::IndexSet;
 use itertools::Itertools;
 use owo_colors::{AnsiColors, OwoColorize};
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use tokio::sync::mpsc;
 use tracing::{debug, trace, warn};
 
 use uv_cache::Cache;
+use uv_cli::PythonInstallFormat;
 use uv_client::BaseClientBuilder;
 use uv_configuration::Concurrency;
 use uv_errors::{ErrorOptions, write_error_chain_with_options};
 use uv_fs::Simplified;
-use uv_platform::{Arch, Libc};
+use uv_platform::{Arch, Libc, Platform};
 use uv_preview::{Preview, PreviewFeature};
 use uv_python::downloads::{
     self, ArchRequest, DownloadResult, ManagedPythonDownload, ManagedPythonDownloadList,
@@ -27,7 +29,7 @@ use uv_python::downloads::{
 };
 use uv_python::managed::{
     ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
-    compare_build_versions, create_link_to_executable, python_executable_dir,
+    compare_build_versions, python_executable_dir,
 };
 use uv_python::{
     ImplementationName, Interpreter, PythonDownloads, PythonInstallationKey,
@@ -38,8 +40,9 @@ use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::warn_user;
 
+use crate::commands::python::shims::{ShimTransaction, warn_if_shadowed};
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
-use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::reporters::{PythonDownloadJsonReporter, PythonDownloadReporter};
 use crate::commands::{ExitStatus, conjunction, elapsed};
 use crate::printer::Printer;
 
@@ -115,6 +118,8 @@ struct Changelog {
     installed: FxHashSet<PythonInstallationKey>,
     uninstalled: FxHashSet<PythonInstallationKey>,
     installed_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>>,
+    /// The installations that failed to download or unpack, with the reason for the failure.
+    failed: FxHashMap<PythonInstallationKey, String>,
 }
 
 impl Changelog {
@@ -140,6 +145,10 @@ impl Changelog {
                 key: key.clone(),
                 kind: ChangeEventKind::Reinstalled,
             }))
+            .chain(self.failed.keys().map(|key| ChangeEvent {
+                key: key.clone(),
+                kind: ChangeEventKind::Failed,
+            }))
             .sorted_unstable_by(|a, b| a.key.cmp(&b.key).then_with(|| a.kind.cmp(&b.kind)))
     }
 }
@@ -147,6 +156,7 @@ impl Changelog {
 #[derive(Debug, Clone, Copy)]
 enum InstallErrorKind {
     DownloadUnpack,
+    Verify,
     Bin,
     #[cfg_attr(not(windows), allow(dead_code))]
     Registry,
@@ -185,6 +195,7 @@ pub(crate) async fn install(
     targets: Vec<String>,
     reinstall: bool,
     upgrade: PythonUpgrade,
+    skip_if_present: bool,
     bin: Option<bool>,
     registry: Option<bool>,
     force: bool,
@@ -196,11 +207,22 @@ pub(crate) async fn install(
     python_downloads: PythonDownloads,
     no_config: bool,
     compile_bytecode: bool,
+    verify: bool,
+    output_format: PythonInstallFormat,
     concurrency: &Concurrency,
     cache: &Cache,
     preview: Preview,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    if matches!(output_format, PythonInstallFormat::Json)
+        && !preview.is_enabled(PreviewFeature::JsonOutput)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeature::JsonOutput
+        );
+    }
+
     let (sender, mut receiver) = mpsc::unbounded_channel();
     let compiler = async {
         let mut total_files = 0;
@@ -232,6 +254,7 @@ pub(crate) async fn install(
         targets,
         reinstall,
         upgrade,
+        skip_if_present,
         bin,
         registry,
         force,
@@ -243,6 +266,8 @@ pub(crate) async fn install(
         python_downloads,
         no_config,
         compile_bytecode.then_some(sender),
+        verify,
+        output_format,
         concurrency,
         preview,
         printer,
@@ -291,6 +316,7 @@ async fn perform_install(
     targets: Vec<String>,
     reinstall: bool,
     upgrade: PythonUpgrade,
+    skip_if_present: bool,
     bin: Option<bool>,
     registry: Option<bool>,
     force: bool,
@@ -302,6 +328,8 @@ async fn perform_install(
     python_downloads: PythonDownloads,
     no_config: bool,
     bytecode_compilation_sender: Option<mpsc::UnboundedSender<ManagedPythonInstallation>>,
+    verify: bool,
+    output_format: PythonInstallFormat,
     concurrency: &Concurrency,
     preview: Preview,
     printer: Printer,
@@ -333,6 +361,45 @@ async fn perform_install(
         .inspect(|installation| trace!("Found existing installation {}", installation.key()))
         .collect();
 
+    // With `--skip-if-present`, exit before fetching the download list if every requested version
+    // is already installed.
+    if skip_if_present {
+        let requests = if targets.is_empty() {
+            PythonVersionFile::discover(
+                project_dir,
+                &VersionFileDiscoveryOptions::default()
+                    .with_no_config(no_config)
+                    .with_preference(VersionFilePreference::Versions),
+            )
+            .await?
+            .map(PythonVersionFile::into_versions)
+            .unwrap_or_else(|| vec![PythonRequest::Default])
+        } else {
+            targets
+                .iter()
+                .map(|target| PythonRequest::parse(target.as_str()))
+                .collect()
+        };
+        let is_present = requests.iter().all(|request| {
+            PythonDownloadRequest::from_request(request)
+                .and_then(|download_request| download_request.fill().ok())
+                .is_some_and(|download_request| {
+                    existing_installations
+                        .iter()
+                        .any(|installation| download_request.satisfied_by_key(installation.key()))
+                })
+        });
+        if is_present {
+            if let [request] = requests.as_slice() {
+                writeln!(printer.stderr(), "{request} is already installed")?;
+            } else {
+                writeln!(printer.stderr(), "All requested versions already installed")?;
+            }
+            return Ok(ExitStatus::Success);
+        }
+        debug!("Not all requested Python versions are installed");
+    }
+
     // Resolve the requests
     let mut is_default_install = false;
     let mut is_unspecified_upgrade = false;
@@ -599,7 +666,13 @@ async fn perform_install(
         .collect::<Vec<_>>();
 
     // Download and unpack the Python versions concurrently
-    let reporter = PythonDownloadReporter::new(printer, Some(downloads.len() as u64));
+    let reporter: Box<dyn downloads::Reporter> = match output_format {
+        PythonInstallFormat::Text => Box::new(PythonDownloadReporter::new(
+            printer,
+            Some(downloads.len() as u64),
+        )),
+        PythonInstallFormat::Json => Box::new(PythonDownloadJsonReporter::new(printer)),
+    };
 
     let mut tasks = futures::stream::iter(&downloads)
         .map(async |download| {
@@ -614,7 +687,7 @@ async fn perform_install(
                         reinstall,
                         python_install_mirror.as_deref(),
                         pypy_install_mirror.as_deref(),
-                        Some(&reporter),
+                        Some(reporter.as_ref()),
                     )
                     .await,
             )
@@ -655,10 +728,14 @@ async fn perform_install(
                 downloaded.push(installation.clone());
             }
             Err(err) => {
+                let err = anyhow::Error::new(err);
+                changelog
+                    .failed
+                    .insert(download.key().clone(), err.root_cause().to_string());
                 errors.push((
                     InstallErrorKind::DownloadUnpack,
                     download.key().clone(),
-                    anyhow::Error::new(err),
+                    err,
                 ));
             }
         }
@@ -670,6 +747,15 @@ async fn perform_install(
         Some(python_executable_dir()?)
     };
 
+    // Ensure that newly installed interpreters can load the system libraries they depend on.
+    if verify {
+        for installation in &downloaded {
+            if let Err(err) = verify_installation(installation).await {
+                errors.push((InstallErrorKind::Verify, installation.key().clone(), err));
+            }
+        }
+    }
+
     let installations: Vec<_> = downloaded.iter().chain(satisfied.iter().copied()).collect();
 
     // Ensure that the installations are _complete_ for both downloaded installations and existing
@@ -690,6 +776,7 @@ async fn perform_install(
             create_bin_links(
                 installation,
                 bin_dir,
+                installations_dir,
                 reinstall,
                 force,
                 default,
@@ -794,10 +881,13 @@ async fn perform_install(
                 writeln!(printer.stderr(), "All requested versions already installed")?;
             }
         }
+        if matches!(output_format, PythonInstallFormat::Json) {
+            write_done_event(&changelog, printer)?;
+        }
         return Ok(ExitStatus::Success);
     }
 
-    if !changelog.installed.is_empty() {
+    if !changelog.installed.is_empty() || !changelog.failed.is_empty() {
         for install_key in &changelog.installed {
             // Make a note if the selected python is non-native for the architecture, if none of the
             // matching user requests were explicit.
@@ -838,15 +928,40 @@ async fn perform_install(
                 }
             }
         }
-        if changelog.installed.len() == 1 {
+        // Ex) ", 1 failed"
+        let failed = if changelog.failed.is_empty() {
+            String::new()
+        } else {
+            format!(", {} failed", changelog.failed.len())
+        };
+        if changelog.installed.is_empty() {
+            // Ex) "Failed to install Python 3.9.7 in 1.68s"
+            let versions = if changelog.failed.len() == 1 {
+                let failed = changelog.failed.keys().next().unwrap();
+                format!("Python {}", failed.version())
+            } else {
+                format!("{} versions", changelog.failed.len())
+            };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Failed to install {} {}",
+                    versions.bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        } else if changelog.installed.len() == 1 {
             let installed = changelog.installed.iter().next().unwrap();
             // Ex) "Installed Python 3.9.7 in 1.68s"
             writeln!(
                 printer.stderr(),
                 "{}",
                 format!(
-                    "Installed {} {}",
+                    "Installed {}{} {}",
                     format!("Python {}", installed.version()).bold(),
+                    failed.bold(),
                     format!("in {}", elapsed(start.elapsed())).dimmed()
                 )
                 .dimmed()
@@ -857,8 +972,9 @@ async fn perform_install(
                 printer.stderr(),
                 "{}",
                 format!(
-                    "Installed {} {}",
+                    "Installed {}{} {}",
                     format!("{} versions", changelog.installed.len()).bold(),
+                    failed.bold(),
                     format!("in {}", elapsed(start.elapsed())).dimmed()
                 )
                 .dimmed()
@@ -892,20 +1008,41 @@ async fn perform_install(
                         event.key.bold(),
                     )?;
                 }
+                ChangeEventKind::Failed => {
+                    let reason = changelog
+                        .failed
+                        .get(&event.key)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    writeln!(
+                        printer.stderr(),
+                        " {} {} {}",
+                        "×".red(),
+                        event.key.bold(),
+                        format!("({reason})").dimmed(),
+                    )?;
+                }
             }
         }
 
-        if let Some(bin_dir) = bin_dir.as_ref() {
+        if !changelog.installed.is_empty()
+            && let Some(bin_dir) = bin_dir.as_ref()
+        {
             warn_if_not_on_path(bin_dir);
         }
     }
 
+    if matches!(output_format, PythonInstallFormat::Json) {
+        write_done_event(&changelog, printer)?;
+    }
+
     if !errors.is_empty() {
         // If there are only side-effect install errors and the user didn't opt-in, we're only going
         // to warn
         let fatal = !errors.iter().all(|(kind, _, _)| match kind {
             InstallErrorKind::Bin => bin.is_none(),
             InstallErrorKind::Registry => registry.is_none(),
+            InstallErrorKind::Verify => true,
             InstallErrorKind::DownloadUnpack => false,
         });
 
@@ -920,6 +1057,15 @@ async fn perform_install(
                         ErrorOptions::default().with_stream(printer.stderr()),
                     )?;
                 }
+                InstallErrorKind::Verify => {
+                    write_error_chain_with_options(
+                        err.context(format!("Failed to verify {key}")).as_ref(),
+                        ErrorOptions::default()
+                            .with_level("warning")
+                            .with_color(AnsiColors::Yellow)
+                            .with_stream(printer.stderr()),
+                    )?;
+                }
                 InstallErrorKind::Bin => {
                     let (level, color) = match bin {
                         None => ("warning", AnsiColors::Yellow),
@@ -964,6 +1110,38 @@ async fn perform_install(
     Ok(ExitStatus::Success)
 }
 
+/// The final event written with `--output-format json`, summarizing the changes.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename = "done")]
+struct PythonInstallDone {
+    changes: Vec<PythonInstallChange>,
+}
+
+#[derive(Debug, Serialize)]
+struct PythonInstallChange {
+    key: String,
+    action: ChangeEventKind,
+}
+
+/// Write the final JSON event summarizing the changes to stdout.
+fn write_done_event(changelog: &Changelog, printer: Printer) -> Result<()> {
+    let done = PythonInstallDone {
+        changes: changelog
+            .events()
+            .map(|event| PythonInstallChange {
+                key: event.key.to_string(),
+                action: event.kind,
+            })
+            .collect(),
+    };
+    writeln!(
+        printer.stdout_important(),
+        "{}",
+        serde_json::to_string(&done)?
+    )?;
+    Ok(())
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 ///
 /// This function is fallible, but errors are pushed to `errors` instead of being thrown.
@@ -971,6 +1149,7 @@ async fn perform_install(
 fn create_bin_links(
     installation: &ManagedPythonInstallation,
     bin: &Path,
+    python_dir: &Path,
     reinstall: bool,
     force: bool,
     default: bool,
@@ -999,10 +1178,19 @@ fn create_bin_links(
         vec![installation.key().executable_name_minor()]
     };
 
+    let targets = targets
+        .into_iter()
+        .map(|target| bin.join(target))
+        .collect::<Vec<_>>();
+
+    // When installing the default executables, the links are created transactionally, such that a
+    // failure doesn't leave behind a mix of executables for different installations.
+    let mut shims = ShimTransaction::new(python_dir);
+    let mut failed = false;
+
     let mut existing_unmanaged = Vec::new();
 
-    for target in targets {
-        let target = bin.join(target);
+    for target in targets.iter().cloned() {
         if upgrade && !target.try_exists().unwrap_or_default() {
             continue;
         }
@@ -1018,7 +1206,7 @@ fn create_bin_links(
             installation.executable(false)
         };
 
-        match create_link_to_executable(&target, &executable) {
+        match shims.create(&target, &executable) {
             Ok(()) => {
                 debug!(
                     "Installed executable at `{}` for {}",
@@ -1079,6 +1267,7 @@ fn create_bin_links(
                                     // Defer reporting to allow grouping.
                                     existing_unmanaged.push(target.clone());
                                 }
+                                failed = true;
                                 continue;
                             }
                             debug!(
@@ -1134,6 +1323,20 @@ fn create_bin_links(
                                     target.simplified_display(),
                                     installation.key(),
                                 );
+                            } else if should_create_default_links {
+                                // Leaving the executable in place would leave behind a mix of
+                                // executables for different installations.
+                                errors.push((
+                                    InstallErrorKind::Bin,
+                                    installation.key().clone(),
+                                    anyhow::anyhow!(
+                                        "Executable already exists for `{}` at `{}`; use `--force` to replace it",
+                                        existing.key(),
+                                        target.simplified_display()
+                                    ),
+                                ));
+                                failed = true;
+                                continue;
                             } else {
                                 debug!(
                                     "Executable already exists for `{}` at `{}`. Use `--force` to replace it",
@@ -1146,16 +1349,17 @@ fn create_bin_links(
                     }
                 }
 
-                // Replace the existing link
-                if let Err(err) = fs_err::remove_file(&target) {
+                // Atomically replace the existing link
+                if let Err(err) = shims.replace(&target, &executable) {
                     errors.push((
                         InstallErrorKind::Bin,
                         installation.key().clone(),
                         anyhow::anyhow!(
-                            "Executable already exists at `{}` but could not be removed: {err}",
+                            "Executable already exists at `{}` but could not be replaced: {err}",
                             target.simplified_display()
                         ),
                     ));
+                    failed = true;
                     continue;
                 }
 
@@ -1169,18 +1373,6 @@ fn create_bin_links(
                         .remove(&target);
                 }
 
-                if let Err(err) = create_link_to_executable(&target, &executable) {
-                    errors.push((
-                        InstallErrorKind::Bin,
-                        installation.key().clone(),
-                        anyhow::anyhow!(
-                            "Failed to create link at `{}`: {err}",
-                            target.simplified_display()
-                        ),
-                    ));
-                    continue;
-                }
-
                 debug!(
                     "Updated executable at `{}` to {}",
                     target.simplified_display(),
@@ -1199,10 +1391,37 @@ fn create_bin_links(
                     installation.key().clone(),
                     Error::new(err),
                 ));
+                failed = true;
             }
         }
     }
 
+    if should_create_default_links {
+        if failed {
+            debug!(
+                "Rolling back executables for {} after a failure",
+                installation.key()
+            );
+            if let Some(executables) = changelog.installed_executables.get_mut(installation.key()) {
+                for path in shims.paths() {
+                    executables.remove(path);
+                }
+            }
+            shims.rollback();
+        } else if let Err(err) = shims.commit(&[
+            bin.join(installation.key().executable_name_major()),
+            bin.join(installation.key().executable_name()),
+        ]) {
+            errors.push((
+                InstallErrorKind::Bin,
+                installation.key().clone(),
+                err.context("Failed to record the installed executables"),
+            ));
+        } else {
+            warn_if_shadowed(&targets);
+        }
+    }
+
     match existing_unmanaged.as_slice() {
         [] => {}
         [executable] => errors.push((
@@ -1264,6 +1483,8 @@ async fn compile_stdlib_bytecode(
         }
     };
 
+    // The standard library contains test files that are intentionally invalid, so compilation
+    // failures are expected here.
     let files = uv_installer::compile_tree(
         &stdlib_path,
         &installation.executable(false),
@@ -1271,13 +1492,143 @@ async fn compile_stdlib_bytecode(
         cache.root(),
     )
     .await
-    .with_context(|| format!("Error compiling bytecode in: {}", stdlib_path.display()))?;
+    .with_context(|| format!("Error compiling bytecode in: {}", stdlib_path.display()))?
+    .files;
     if files == 0 {
         return Ok(None);
     }
     Ok(Some((files, start.elapsed())))
 }
 
+/// The standard library modules that most often fail to import due to missing system libraries.
+const VERIFY_MODULES: &[&str] = &["ssl", "zlib", "bz2", "lzma", "ctypes", "sqlite3"];
+
+/// System packages that provide commonly missing shared libraries, as (library prefix,
+/// Debian/Ubuntu package, Fedora/RHEL package, Alpine package).
+const LIBRARY_PACKAGES: &[(&str, &str, &str, &str)] = &[
+    ("libgcc_s.so", "libgcc-s1", "libgcc", "libgcc"),
+    ("libstdc++.so", "libstdc++6", "libstdc++", "libstdc++"),
+    ("libz.so", "zlib1g", "zlib", "zlib"),
+    ("libbz2.so", "libbz2-1.0", "bzip2-libs", "libbz2"),
+    ("liblzma.so", "liblzma5", "xz-libs", "xz-libs"),
+    ("libffi.so", "libffi8", "libffi", "libffi"),
+    (
+        "libsqlite3.so",
+        "libsqlite3-0",
+        "sqlite-libs",
+        "sqlite-libs",
+    ),
+    ("libcrypt.so", "libcrypt1", "libxcrypt", "libxcrypt"),
+];
+
+/// Verify that a newly installed [`ManagedPythonInstallation`] can import the standard library
+/// modules that depend on system shared libraries.
+///
+/// Installations that cannot run on the current platform are skipped.
+async fn verify_installation(installation: &ManagedPythonInstallation) -> Result<()> {
+    // Explicit matching so this heuristic is updated for future additions
+    match installation.implementation() {
+        ImplementationName::CPython => (),
+        ImplementationName::GraalPy | ImplementationName::PyPy | ImplementationName::Pyodide => {
+            return Ok(());
+        }
+    }
+
+    let key = installation.key();
+    let target = Platform::new(*key.os(), *key.arch(), *key.libc());
+    if !Platform::from_env().is_ok_and(|host| host.supports(&target)) {
+        debug!("Skipping verification of {key}, which cannot run on the current platform");
+        return Ok(());
+    }
+
+    let executable = installation.executable(false);
+    let output = tokio::process::Command::new(&executable)
+        .arg("-I")
+        .arg("-c")
+        .arg(format!("import {}", VERIFY_MODULES.join(", ")))
+        .output()
+        .await
+        .with_context(|| format!("Failed to run `{}`", executable.user_display()))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let libraries = missing_shared_libraries(&stderr);
+    if libraries.is_empty() {
+        let message = stderr.lines().last().unwrap_or_default().trim();
+        return Err(anyhow::anyhow!(
+            "Failed to import the standard library modules {}: {message}",
+            VERIFY_MODULES
+                .iter()
+                .map(|module| format!("`{module}`"))
+                .join(", ")
+        ));
+    }
+
+    let mut message = format!(
+        "The interpreter could not load the following system libraries: {}",
+        libraries
+            .iter()
+            .map(|library| format!("`{library}`"))
+            .join(", ")
+    );
+
+    // Suggest the distribution packages that provide the missing libraries.
+    let packages = libraries
+        .iter()
+        .filter_map(|library| {
+            LIBRARY_PACKAGES
+                .iter()
+                .find(|(prefix, ..)| library.starts_with(prefix))
+        })
+        .unique()
+        .collect::<Vec<_>>();
+    if !packages.is_empty() {
+        let hint = if key.libc().is_musl() {
+            format!(
+                "`apk add {}`",
+                packages.iter().map(|(.., alpine)| alpine).join(" ")
+            )
+        } else {
+            format!(
+                "`apt-get install {}` (Debian/Ubuntu) or `dnf install {}` (Fedora/RHEL)",
+                packages.iter().map(|(_, debian, ..)| debian).join(" "),
+                packages.iter().map(|(_, _, fedora, _)| fedora).join(" "),
+            )
+        };
+        write!(message, "; install them with {hint}")?;
+    }
+
+    Err(anyhow::anyhow!(message))
+}
+
+/// Extract the names of the shared libraries that the dynamic loader failed to find from the
+/// output of a failed interpreter invocation.
+fn missing_shared_libraries(stderr: &str) -> Vec<String> {
+    let mut libraries = Vec::new();
+    for line in stderr.lines() {
+        let library = line
+            // e.g., `error while loading shared libraries: libz.so.1: cannot open shared object
+            // file` from glibc, or `ImportError: libz.so.1: cannot open shared object file`.
+            .split_once(": cannot open shared object file")
+            .and_then(|(prefix, _)| prefix.rsplit([' ', ':']).next())
+            // e.g., `Error loading shared library libgcc_s.so.1: No such file or directory` from
+            // musl.
+            .or_else(|| {
+                line.split_once("Error loading shared library ")
+                    .and_then(|(_, suffix)| suffix.split(':').next())
+            });
+        let Some(library) = library.map(str::trim) else {
+            continue;
+        };
+        if library.contains(".so") && !libraries.iter().any(|existing| existing == library) {
+            libraries.push(library.to_string());
+        }
+    }
+    libraries
+}
+
 pub(crate) fn format_executables(
     event: &ChangeEvent,
     executables: &FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>>,
@@ -1375,3 +1726,30 @@ fn matches_build(download_build: Option<&str>, installation_build: Option<&str>)
         (None, _) => true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_missing_shared_libraries() {
+        // glibc
+        let stderr = "python3.12: error while loading shared libraries: libgcc_s.so.1: cannot open shared object file: No such file or directory\n";
+        assert_eq!(missing_shared_libraries(stderr), vec!["libgcc_s.so.1"]);
+
+        // Python
+        let stderr = "Traceback (most recent call last):\n  File \"<string>\", line 1, in <module>\nImportError: libz.so.1: cannot open shared object file: No such file or directory\n";
+        assert_eq!(missing_shared_libraries(stderr), vec!["libz.so.1"]);
+
+        // musl
+        let stderr = "Error loading shared library libstdc++.so.6: No such file or directory (needed by /python)\nError loading shared library libgcc_s.so.1: No such file or directory (needed by /python)\nError loading shared library libgcc_s.so.1: No such file or directory (needed by /python)\n";
+        assert_eq!(
+            missing_shared_libraries(stderr),
+            vec!["libstdc++.so.6", "libgcc_s.so.1"]
+        );
+
+        // Unrelated failures
+        let stderr = "ModuleNotFoundError: No module named '_ssl'\n";
+        assert!(missing_shared_libraries(stderr).is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
-use uv_cli::PythonListFormat;
+use uv_cli::{PythonListFormat, PythonListSort};
 use uv_pep440::Version;
 
 use anyhow::Result;
@@ -61,6 +61,8 @@ pub(crate) async fn list(
     all_platforms: bool,
     all_arches: bool,
     show_urls: bool,
+    sort: PythonListSort,
+    reverse: bool,
     output_format: PythonListFormat,
     python_downloads_json_url: Option<String>,
     python_install_mirror: Option<String>,
@@ -231,6 +233,26 @@ pub(crate) async fn list(
         include.push((key, uri));
     }
 
+    // The entries are already ordered by implementation, then by version; use a stable sort to
+    // preserve that order among ties.
+    match sort {
+        PythonListSort::Implementation => {}
+        PythonListSort::Version => {
+            include.sort_by(|(a, _), (b, _)| b.version().cmp(&a.version()));
+        }
+        PythonListSort::Path => {
+            include.sort_by(|(_, a), (_, b)| match (a, b) {
+                (Either::Left(a), Either::Left(b)) => a.cmp(b),
+                (Either::Left(_), Either::Right(_)) => std::cmp::Ordering::Less,
+                (Either::Right(_), Either::Left(_)) => std::cmp::Ordering::Greater,
+                (Either::Right(_), Either::Right(_)) => std::cmp::Ordering::Equal,
+            });
+        }
+    }
+    if reverse {
+        include.reverse();
+    }
+
     match output_format {
         PythonListFormat::Json => {
             let data = include
@@ -3,17 +3,23 @@ pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
+pub(crate) mod shims;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
 pub(super) enum ChangeEventKind {
     /// The Python version was uninstalled.
+    #[serde(rename = "uninstalled")]
     Removed,
     /// The Python version was installed.
+    #[serde(rename = "installed")]
     Added,
     /// The Python version was reinstalled.
     Reinstalled,
+    /// The Python version failed to install.
+    Failed,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,504 @@
+//! Management of the Python executables ("shims") that uv installs into the Python executable
+//! directory, e.g., `python`, `python3`, and `python3.13`.
+use std::fmt::Write;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use uv_fs::Simplified;
+use uv_python::managed::{
+    ManagedPythonInstallation, ManagedPythonInstallations, create_link_to_executable,
+    python_executable_dir, replace_link_to_executable,
+};
+use uv_shell::Shell;
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The name of the file, in the Python installation directory, that records the state of each
+/// shim before uv first replaced it.
+const RECORD: &str = ".default-shims.toml";
+
+/// The name of the directory, in the Python installation directory, that holds backups of any
+/// regular files that were replaced by a shim.
+const BACKUPS: &str = ".default-shims";
+
+/// The state of a shim path before uv replaced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum PreviousShim {
+    /// Nothing existed at the path.
+    Missing,
+    /// A symbolic link to the given target.
+    Symlink { target: PathBuf },
+    /// A regular file, which was backed up to the given path.
+    File { backup: PathBuf },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ShimEntry {
+    path: PathBuf,
+    previous: PreviousShim,
+}
+
+/// The record of the shims installed with `uv python install --default`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ShimRecord {
+    #[serde(default)]
+    shim: Vec<ShimEntry>,
+}
+
+impl ShimRecord {
+    /// Read the record from the given Python installation directory.
+    fn read(python_dir: &Path) -> Result<Self> {
+        let path = python_dir.join(RECORD);
+        match fs_err::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse: `{}`", path.user_display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write the record to the given Python installation directory, removing it if empty.
+    fn write(&self, python_dir: &Path) -> Result<()> {
+        let path = python_dir.join(RECORD);
+        if self.shim.is_empty() {
+            match fs_err::remove_file(&path) {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(err.into()),
+            }
+        } else {
+            uv_fs::write_atomic_sync(&path, toml::to_string(self)?)?;
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if the record includes the given shim path.
+    fn contains(&self, path: &Path) -> bool {
+        self.shim.iter().any(|entry| entry.path == path)
+    }
+}
+
+/// A set of changes to the shims in the Python executable directory.
+///
+/// Each shim is staged and atomically swapped into place, and the prior state of each shim is
+/// retained, such that the changes can be rolled back together if any of them fail.
+pub(crate) struct ShimTransaction<'a> {
+    python_dir: &'a Path,
+    /// A temporary directory holding backups of any regular files replaced by this transaction.
+    staging: Option<tempfile::TempDir>,
+    changes: Vec<ShimEntry>,
+}
+
+impl<'a> ShimTransaction<'a> {
+    pub(crate) fn new(python_dir: &'a Path) -> Self {
+        Self {
+            python_dir,
+            staging: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Returns the paths of the shims changed by the transaction.
+    pub(crate) fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.changes.iter().map(|change| change.path.as_path())
+    }
+
+    /// Create a shim at `path` linking to `executable`, failing if a file already exists.
+    pub(crate) fn create(
+        &mut self,
+        path: &Path,
+        executable: &Path,
+    ) -> Result<(), uv_python::managed::Error> {
+        create_link_to_executable(path, executable)?;
+        self.changes.push(ShimEntry {
+            path: path.to_path_buf(),
+            previous: PreviousShim::Missing,
+        });
+        Ok(())
+    }
+
+    /// Atomically replace the file at `path` with a shim linking to `executable`.
+    pub(crate) fn replace(&mut self, path: &Path, executable: &Path) -> Result<()> {
+        let previous = self.snapshot(path)?;
+        replace_link_to_executable(path, executable)?;
+        self.changes.push(ShimEntry {
+            path: path.to_path_buf(),
+            previous,
+        });
+        Ok(())
+    }
+
+    /// Capture the current state of the given path, backing up any regular file.
+    fn snapshot(&mut self, path: &Path) -> Result<PreviousShim> {
+        let metadata = match fs_err::symlink_metadata(path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(PreviousShim::Missing),
+            Err(err) => return Err(err.into()),
+        };
+        if metadata.is_symlink() {
+            return Ok(PreviousShim::Symlink {
+                target: fs_err::read_link(path)?,
+            });
+        }
+
+        let staging = match self.staging.take() {
+            Some(staging) => staging,
+            None => tempfile::tempdir_in(self.python_dir)?,
+        };
+        let backup = staging.path().join(self.changes.len().to_string());
+        let result = fs_err::copy(path, &backup);
+        self.staging = Some(staging);
+        result?;
+        Ok(PreviousShim::File { backup })
+    }
+
+    /// Undo the changes, restoring each shim to its prior state.
+    pub(crate) fn rollback(self) {
+        for change in self.changes.into_iter().rev() {
+            match restore(&change.path, &change.previous) {
+                Ok(()) => debug!("Rolled back `{}`", change.path.simplified_display()),
+                Err(err) => warn!(
+                    "Failed to roll back `{}`: {err}",
+                    change.path.simplified_display()
+                ),
+            }
+        }
+    }
+
+    /// Record the prior state of each of the given shims, if changed by the transaction, such that
+    /// it can be restored by `uv python uninstall --default-shims`.
+    ///
+    /// If a shim was already recorded by an earlier installation, its original state is retained.
+    pub(crate) fn commit(self, shims: &[PathBuf]) -> Result<()> {
+        let mut record = ShimRecord::read(self.python_dir)?;
+        for change in self.changes {
+            if !shims.contains(&change.path) || record.contains(&change.path) {
+                continue;
+            }
+            let previous = match change.previous {
+                PreviousShim::File { backup } => {
+                    let backups = self.python_dir.join(BACKUPS);
+                    fs_err::create_dir_all(&backups)?;
+                    let destination = backups.join(format!(
+                        "{}-{}",
+                        record.shim.len(),
+                        change
+                            .path
+                            .file_name()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                    ));
+                    fs_err::rename(&backup, &destination)?;
+                    PreviousShim::File {
+                        backup: destination,
+                    }
+                }
+                previous => previous,
+            };
+            record.shim.push(ShimEntry {
+                path: change.path,
+                previous,
+            });
+        }
+        record.write(self.python_dir)
+    }
+}
+
+/// Restore the given path to its prior state.
+fn restore(path: &Path, previous: &PreviousShim) -> Result<()> {
+    match previous {
+        PreviousShim::Missing => match fs_err::remove_file(path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        },
+        PreviousShim::Symlink { target } => {
+            #[cfg(unix)]
+            {
+                uv_fs::replace_symlink(target, path)?;
+            }
+            #[cfg(windows)]
+            {
+                match fs_err::remove_file(path) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
+                fs_err::os::windows::fs::symlink_file(target, path)?;
+            }
+        }
+        PreviousShim::File { backup } => {
+            fs_err::rename(backup, path)?;
+        }
+    }
+    Ok(())
+}
+
+/// A tool that manages its own Python shims, which may shadow uv's shims on the `PATH`.
+#[derive(Debug, Copy, Clone)]
+enum ShimManager {
+    Pyenv,
+    Asdf,
+    Mise,
+}
+
+impl ShimManager {
+    /// Detect the tool that owns the executable at the given path, if any.
+    fn from_path(path: &Path) -> Option<Self> {
+        let shims = path.parent()?;
+        if shims.file_name()? != "shims" {
+            return None;
+        }
+        match shims.parent()?.file_name()?.to_str()? {
+            ".pyenv" | "pyenv" | "pyenv-win" => Some(Self::Pyenv),
+            ".asdf" | "asdf" => Some(Self::Asdf),
+            "mise" | ".mise" => Some(Self::Mise),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ShimManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pyenv => f.write_str("pyenv"),
+            Self::Asdf => f.write_str("asdf"),
+            Self::Mise => f.write_str("mise"),
+        }
+    }
+}
+
+/// Find the executable that takes precedence over the given shim on the `PATH`, if any.
+///
+/// Returns `None` if the shim is the first match on the `PATH`, or if its directory is not on the
+/// `PATH` at all.
+fn find_shadowing(shim: &Path) -> Option<PathBuf> {
+    let name = shim.file_name()?;
+    let bin = dunce::canonicalize(shim.parent()?).ok()?;
+    let path = std::env::var_os("PATH")?;
+    for directory in std::env::split_paths(&path) {
+        if dunce::canonicalize(&directory).is_ok_and(|directory| directory == bin) {
+            return None;
+        }
+        let candidate = directory.join(name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Describe the executable that shadows a shim, e.g., "`~/.pyenv/shims/python` (a pyenv shim)".
+fn describe_shadowing(shadow: &Path) -> String {
+    if let Some(manager) = ShimManager::from_path(shadow) {
+        format!(
+            "`{}` (a {manager} shim)",
+            shadow.simplified_display().cyan()
+        )
+    } else {
+        format!("`{}`", shadow.simplified_display().cyan())
+    }
+}
+
+/// Warn if any of the given shims are shadowed by another executable on the `PATH`, e.g., a pyenv
+/// or asdf shim.
+pub(crate) fn warn_if_shadowed(shims: &[PathBuf]) {
+    for shim in shims {
+        if let Some(shadow) = find_shadowing(shim) {
+            warn_user!(
+                "The `{}` executable installed by uv is shadowed by {}, which appears earlier on the PATH",
+                shim.file_name().unwrap_or_default().to_string_lossy(),
+                describe_shadowing(&shadow),
+            );
+        }
+    }
+}
+
+/// Find the managed installation that the shim at the given path links to, if any.
+fn find_installation<'a>(
+    installations: &'a [ManagedPythonInstallation],
+    path: &Path,
+) -> Option<&'a ManagedPythonInstallation> {
+    installations
+        .iter()
+        .find(|installation| installation.is_bin_link(path))
+}
+
+/// List the shims in the Python executable directory.
+pub(crate) fn list(printer: Printer) -> Result<ExitStatus> {
+    let installations = ManagedPythonInstallations::from_settings(None)?;
+    let managed = installations.find_all()?.collect::<Vec<_>>();
+    let record = ShimRecord::read(installations.root())?;
+    let bin = python_executable_dir()?;
+
+    let mut shims = bin
+        .read_dir()
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| !file_type.is_dir()))
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let installation = find_installation(&managed, &path);
+            (installation.is_some() || record.contains(&path)).then_some((path, installation))
+        })
+        .collect::<Vec<_>>();
+    shims.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    if shims.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No Python executables installed in `{}`",
+            bin.simplified_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (path, installation) in &shims {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let target = if let Some(installation) = installation {
+            installation
+                .executable(false)
+                .simplified_display()
+                .to_string()
+        } else {
+            "(not managed by uv)".dimmed().to_string()
+        };
+        if let Some(shadow) = find_shadowing(path) {
+            writeln!(
+                printer.stdout(),
+                "{} -> {target} {}",
+                name.bold(),
+                format!("(shadowed by {})", describe_shadowing(&shadow)).yellow()
+            )?;
+        } else {
+            writeln!(printer.stdout(), "{} -> {target}", name.bold())?;
+        }
+    }
+
+    if !Shell::contains_path(&bin) {
+        warn_user!(
+            "`{}` is not on your PATH, so the executables above will not be used",
+            bin.simplified_display().cyan()
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Restore (or remove) the shims installed by `uv python install --default`.
+pub(crate) fn uninstall_default_shims(
+    installations: &ManagedPythonInstallations,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let record = ShimRecord::read(installations.root())?;
+    if record.shim.is_empty() {
+        writeln!(printer.stderr(), "No default shims to restore")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let managed = installations.find_all()?.collect::<Vec<_>>();
+    for entry in &record.shim {
+        // Avoid clobbering an executable that was changed since uv installed the shim.
+        let exists = fs_err::symlink_metadata(&entry.path).is_ok();
+        if exists && find_installation(&managed, &entry.path).is_none() {
+            warn_user!(
+                "Skipping `{}`, which is no longer managed by uv",
+                entry.path.simplified_display().cyan()
+            );
+            continue;
+        }
+
+        restore(&entry.path, &entry.previous)?;
+        match &entry.previous {
+            PreviousShim::Missing => {
+                if exists {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}",
+                        "-".red(),
+                        entry.path.simplified_display().bold()
+                    )?;
+                }
+            }
+            PreviousShim::Symlink { target } => {
+                writeln!(
+                    printer.stderr(),
+                    " {} {} -> {}",
+                    "~".yellow(),
+                    entry.path.simplified_display().bold(),
+                    target.simplified_display()
+                )?;
+            }
+            PreviousShim::File { .. } => {
+                writeln!(
+                    printer.stderr(),
+                    " {} {} (restored from backup)",
+                    "~".yellow(),
+                    entry.path.simplified_display().bold()
+                )?;
+            }
+        }
+    }
+
+    ShimRecord::default().write(installations.root())?;
+    // Remove the backup directory, if it's now empty.
+    let _ = fs_err::remove_dir(installations.root().join(BACKUPS));
+
+    Ok(ExitStatus::Success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn rollback() -> Result<()> {
+        let python_dir = tempfile::tempdir()?;
+        let bin = tempfile::tempdir()?;
+
+        let executable = python_dir.path().join("python3.13");
+        fs_err::write(&executable, "")?;
+        let previous = python_dir.path().join("python3.12");
+        fs_err::write(&previous, "")?;
+
+        // A shim that doesn't exist yet.
+        let missing = bin.path().join("python3.13");
+        // A shim that links to another executable.
+        let symlink = bin.path().join("python3");
+        fs_err::os::unix::fs::symlink(&previous, &symlink)?;
+        // A shim that is a regular file.
+        let file = bin.path().join("python");
+        fs_err::write(&file, "#!/bin/sh\n")?;
+
+        let mut shims = ShimTransaction::new(python_dir.path());
+        shims.create(&missing, &executable)?;
+        shims.replace(&symlink, &executable)?;
+        shims.replace(&file, &executable)?;
+        for path in [&missing, &symlink, &file] {
+            assert_eq!(fs_err::read_link(path)?, executable);
+        }
+
+        shims.rollback();
+
+        // Each shim is restored to its prior state.
+        assert!(fs_err::symlink_metadata(&missing).is_err());
+        assert_eq!(fs_err::read_link(&symlink)?, previous);
+        assert!(!fs_err::symlink_metadata(&file)?.is_symlink());
+        assert_eq!(fs_err::read_to_string(&file)?, "#!/bin/sh\n");
+
+        // Nothing is recorded for `uv python uninstall --default-shims`.
+        assert!(!python_dir.path().join(RECORD).exists());
+
+        Ok(())
+    }
+}
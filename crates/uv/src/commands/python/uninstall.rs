@@ -16,9 +16,12 @@ use uv_python::downloads::PythonDownloadRequest;
 use uv_python::managed::{
     ManagedPythonInstallations, PythonMinorVersionLink, python_executable_dir,
 };
-use uv_python::{PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest};
+use uv_python::{
+    PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest, PythonVersion,
+};
 
 use crate::commands::python::install::format_executables;
+use crate::commands::python::shims::uninstall_default_shims;
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
@@ -28,14 +31,20 @@ pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    older_than: Option<PythonVersion>,
+    default_shims: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
 
     let _lock = installations.lock().await?;
 
+    if default_shims {
+        return uninstall_default_shims(&installations, printer);
+    }
+
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer).await?;
+    do_uninstall(&installations, targets, all, older_than.as_ref(), printer).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -63,11 +72,12 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    older_than: Option<&PythonVersion>,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
-    let requests = if all {
+    let requests = if all || older_than.is_some() {
         vec![PythonRequest::Default]
     } else {
         let targets = targets.into_iter().collect::<BTreeSet<_>>();
@@ -90,7 +100,13 @@ async fn do_uninstall(
     let installed_installations: Vec<_> = installations.find_all()?.collect();
     let mut matching_installations = BTreeSet::default();
     for (request, download_request) in requests.iter().zip(download_requests) {
-        if matches!(requests.as_slice(), [PythonRequest::Default]) {
+        if let Some(older_than) = older_than {
+            writeln!(
+                printer.stderr(),
+                "Searching for Python versions older than: {}",
+                older_than.cyan()
+            )?;
+        } else if matches!(requests.as_slice(), [PythonRequest::Default]) {
             writeln!(printer.stderr(), "Searching for Python installations")?;
         } else {
             writeln!(
@@ -103,6 +119,11 @@ async fn do_uninstall(
         for installation in installed_installations
             .iter()
             .filter(|installation| download_request.satisfied_by_key(installation.key()))
+            .filter(|installation| {
+                older_than.is_none_or(|older_than| {
+                    installation.key().version().version() < older_than.version()
+                })
+            })
         {
             found = true;
             matching_installations.insert(installation.clone());
@@ -116,6 +137,15 @@ async fn do_uninstall(
                 );
             }
 
+            if let Some(older_than) = older_than {
+                writeln!(
+                    printer.stderr(),
+                    "No Python installations found older than: {}",
+                    older_than.cyan()
+                )?;
+                return Ok(ExitStatus::Failure);
+            }
+
             if matches!(requests.as_slice(), [PythonRequest::Default]) {
                 writeln!(printer.stderr(), "No Python installations found")?;
                 return Ok(ExitStatus::Failure);
@@ -8,6 +8,7 @@ use std::time::Duration;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use crate::commands::human_readable_bytes;
 use crate::printer::Printer;
@@ -691,6 +692,140 @@ impl uv_python::downloads::Reporter for PythonDownloadReporter {
     }
 }
 
+/// A [`uv_python::downloads::Reporter`] that writes progress events to stdout as
+/// newline-delimited JSON, for consumption by other tools.
+///
+/// Progress is reported in steps of 10%, such that the events are independent of how the
+/// response is chunked.
+#[derive(Debug)]
+pub(crate) struct PythonDownloadJsonReporter {
+    printer: Printer,
+    state: Mutex<PythonDownloadJsonState>,
+}
+
+#[derive(Debug, Default)]
+struct PythonDownloadJsonState {
+    next_id: usize,
+    requests: FxHashMap<usize, PythonDownloadJsonRequest>,
+}
+
+#[derive(Debug)]
+struct PythonDownloadJsonRequest {
+    key: String,
+    phase: String,
+    size: Option<u64>,
+    bytes: u64,
+    percent: u64,
+}
+
+/// A progress event for a Python download, written as a single line of JSON.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum PythonDownloadEvent<'a> {
+    /// A download or extraction started.
+    Start {
+        phase: &'a str,
+        key: &'a str,
+        size: Option<u64>,
+    },
+    /// A download or extraction reached the given percentage.
+    Progress {
+        phase: &'a str,
+        key: &'a str,
+        percent: u64,
+    },
+    /// A download or extraction completed.
+    Complete { phase: &'a str, key: &'a str },
+}
+
+impl PythonDownloadJsonReporter {
+    pub(crate) fn new(printer: Printer) -> Self {
+        Self {
+            printer,
+            state: Mutex::default(),
+        }
+    }
+
+    fn write(&self, event: &PythonDownloadEvent) {
+        if let Ok(line) = serde_json::to_string(event) {
+            let _ = writeln!(self.printer.stdout_important(), "{line}");
+        }
+    }
+}
+
+impl uv_python::downloads::Reporter for PythonDownloadJsonReporter {
+    fn on_request_start(
+        &self,
+        direction: uv_python::downloads::Direction,
+        name: &PythonInstallationKey,
+        size: Option<u64>,
+    ) -> usize {
+        let request = PythonDownloadJsonRequest {
+            key: name.to_string(),
+            phase: direction.to_string(),
+            size,
+            bytes: 0,
+            percent: 0,
+        };
+        self.write(&PythonDownloadEvent::Start {
+            phase: &request.phase,
+            key: &request.key,
+            size,
+        });
+
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.requests.insert(id, request);
+        id
+    }
+
+    fn on_request_progress(&self, id: usize, inc: u64) {
+        let mut state = self.state.lock().unwrap();
+        let Some(request) = state.requests.get_mut(&id) else {
+            return;
+        };
+        let Some(size) = request.size.filter(|size| *size > 0) else {
+            return;
+        };
+        request.bytes += inc;
+
+        // Report each 10% step that was crossed.
+        let percent = (request.bytes.saturating_mul(100) / size).min(100) / 10 * 10;
+        while request.percent < percent {
+            request.percent += 10;
+            self.write(&PythonDownloadEvent::Progress {
+                phase: &request.phase,
+                key: &request.key,
+                percent: request.percent,
+            });
+        }
+    }
+
+    fn on_request_complete(&self, _direction: uv_python::downloads::Direction, id: usize) {
+        let Some(mut request) = self.state.lock().unwrap().requests.remove(&id) else {
+            return;
+        };
+
+        // Report any remaining steps, e.g., if the extraction finished before reading the
+        // trailing bytes of the archive.
+        if request.size.is_some_and(|size| size > 0) {
+            while request.percent < 100 {
+                request.percent += 10;
+                self.write(&PythonDownloadEvent::Progress {
+                    phase: &request.phase,
+                    key: &request.key,
+                    percent: request.percent,
+                });
+            }
+        }
+        self.write(&PythonDownloadEvent::Complete {
+            phase: &request.phase,
+            key: &request.key,
+        });
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct PublishReporter {
     reporter: ProgressReporter,
@@ -0,0 +1,211 @@
+//! An on-disk cache of project resolutions, keyed by a digest of the inputs to the resolver, used
+//! by `uv lock` (and the commands that lock implicitly).
+//!
+//! When the inputs to a resolution are unchanged (e.g., after removing the lockfile, or when
+//! switching back to a previously locked branch), the cached resolution is reused in lieu of
+//! resolving again. Resolutions are stored as `uv.lock` files.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry, Freshness, WheelCache};
+use uv_cache_key::cache_digest;
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_resolver::{Lock, VERSION};
+
+/// The inputs to a resolution, other than the contents of the index.
+///
+/// Each input is recorded in a stable form (i.e., its serialized or display representation), such
+/// that the digest is consistent across invocations, and changes whenever any input does.
+#[derive(Debug, Default)]
+pub(crate) struct ResolutionInputs {
+    inputs: BTreeMap<&'static str, Vec<String>>,
+    /// Whether any input failed to serialize, in which case the resolution can't be cached.
+    invalid: bool,
+}
+
+impl ResolutionInputs {
+    /// Record the serialized representation of the given value as an input.
+    #[must_use]
+    pub(crate) fn serialized(mut self, name: &'static str, value: &impl Serialize) -> Self {
+        match serde_json::to_string(value) {
+            Ok(value) => self.inputs.entry(name).or_default().push(value),
+            Err(err) => {
+                debug!("Failed to serialize resolution input `{name}`: {err}");
+                self.invalid = true;
+            }
+        }
+        self
+    }
+
+    /// Record the display representation of each of the given values as an input.
+    #[must_use]
+    pub(crate) fn displayed(
+        mut self,
+        name: &'static str,
+        values: impl IntoIterator<Item = impl Display>,
+    ) -> Self {
+        self.inputs
+            .entry(name)
+            .or_default()
+            .extend(values.into_iter().map(|value| value.to_string()));
+        self
+    }
+
+    /// Return a digest of the recorded inputs, or `None` if any input couldn't be recorded.
+    pub(crate) fn digest(&self) -> Option<String> {
+        if self.invalid {
+            return None;
+        }
+        Some(cache_digest(&(uv_version::version(), &self.inputs)))
+    }
+}
+
+/// A cached resolution for a given set of resolver inputs.
+#[derive(Debug)]
+pub(crate) struct ResolutionCache<'a> {
+    cache: &'a Cache,
+    entry: CacheEntry,
+}
+
+impl<'a> ResolutionCache<'a> {
+    /// Initialize a [`ResolutionCache`] for the given resolver inputs.
+    ///
+    /// Returns `None` if any of the inputs couldn't be recorded.
+    pub(crate) fn new(cache: &'a Cache, inputs: &ResolutionInputs) -> Option<Self> {
+        let digest = inputs.digest()?;
+        let entry = cache.entry(CacheBucket::Resolutions, "", format!("{digest}.toml"));
+        Some(Self { cache, entry })
+    }
+
+    /// Read the cached `uv.lock`, if it exists and is still fresh.
+    pub(crate) fn read_lock(&self, root: &Path) -> Option<Lock> {
+        let encoded = self.read()?;
+        let lock = match toml::from_str::<Lock>(&encoded) {
+            Ok(lock) if lock.version() == VERSION => lock,
+            Ok(_) => return None,
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid cached resolution at `{}`: {err}",
+                    self.entry.path().display()
+                );
+                return None;
+            }
+        };
+
+        let mut indexes = Vec::new();
+        for package in lock.packages() {
+            if let Ok(Some(index)) = package.index(root) {
+                indexes.push((package.name().clone(), index));
+            }
+        }
+        if !self.is_index_fresh(&indexes) {
+            return None;
+        }
+
+        debug!(
+            "Using cached resolution at: {}",
+            self.entry.path().display()
+        );
+        Some(lock)
+    }
+
+    /// Write the `uv.lock` to the cache.
+    ///
+    /// Resolutions that include any packages from outside the index (e.g., Git or path
+    /// dependencies), other than the given workspace members, are not cached, since their
+    /// metadata can change without affecting the resolver inputs.
+    pub(crate) async fn write_lock(&self, lock: &Lock, members: &[PackageName], root: &Path) {
+        let cacheable = lock.packages().iter().all(|package| {
+            members.contains(package.name())
+                || package.index(root).is_ok_and(|index| index.is_some())
+        });
+        if !cacheable {
+            debug!("Skipping resolution cache for lockfile with non-registry sources");
+            return;
+        }
+        match lock.to_toml() {
+            Ok(encoded) => self.write(encoded).await,
+            Err(err) => debug!("Failed to serialize lockfile for the resolution cache: {err}"),
+        }
+    }
+
+    /// Read the cached resolution, unless the [`Cache`] requires revalidation (e.g., due to
+    /// `--refresh`).
+    fn read(&self) -> Option<String> {
+        if !self
+            .cache
+            .freshness(&self.entry, None, None)
+            .is_ok_and(Freshness::is_fresh)
+        {
+            debug!(
+                "Ignoring stale resolution at: {}",
+                self.entry.path().display()
+            );
+            return None;
+        }
+        match fs_err::read_to_string(self.entry.path()) {
+            Ok(encoded) => Some(encoded),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No cached resolution at: {}", self.entry.path().display());
+                None
+            }
+            Err(err) => {
+                debug!(
+                    "Failed to read cached resolution at `{}`: {err}",
+                    self.entry.path().display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the index responses for each of the given packages haven't been updated
+    /// since the resolution was cached, in which case the index may now contain versions that
+    /// weren't available to the resolver.
+    fn is_index_fresh(&self, indexes: &[(PackageName, IndexUrl)]) -> bool {
+        let Ok(modified) =
+            fs_err::metadata(self.entry.path()).and_then(|metadata| metadata.modified())
+        else {
+            return false;
+        };
+        for (name, index) in indexes {
+            let simple = self.cache.entry(
+                CacheBucket::Simple,
+                WheelCache::Index(index).root(),
+                format!("{name}.rkyv"),
+            );
+            let is_fresh = fs_err::metadata(simple.path())
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|simple| simple <= modified);
+            if !is_fresh {
+                debug!(
+                    "Ignoring resolution at `{}`: index was updated for `{name}`",
+                    self.entry.path().display()
+                );
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Write the encoded resolution to the cache.
+    async fn write(&self, encoded: String) {
+        let result = async {
+            fs_err::tokio::create_dir_all(self.entry.dir()).await?;
+            uv_fs::write_atomic(self.entry.path(), encoded).await?;
+            Ok::<(), std::io::Error>(())
+        }
+        .await;
+        if let Err(err) = result {
+            debug!(
+                "Failed to write cached resolution to `{}`: {err}",
+                self.entry.path().display()
+            );
+        }
+    }
+}
@@ -15,7 +15,8 @@ use uv_cache::{Cache, Refresh};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroupsWithDefaults, ExcludeDependency,
-    ExtrasSpecification, GitLfsSetting, InstallOptions, Override, TargetTriple,
+    ExtrasSpecification, GitLfsSetting, InstallOptions, Override, PlatformMarkerOverrides,
+    TargetTriple,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{
@@ -415,6 +416,8 @@ impl ToolLock {
             resolution,
             prerelease,
             fork_strategy,
+            prefetch_limit,
+            resolution_cache: _,
             dependency_metadata,
             config_setting,
             config_settings_package,
@@ -458,6 +461,7 @@ impl ToolLock {
             .resolution_mode(*resolution)
             .prerelease_mode(*prerelease)
             .fork_strategy(*fork_strategy)
+            .prefetch_limit(*prefetch_limit)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(*index_strategy)
             .build_options(build_options.clone())
@@ -589,7 +593,12 @@ impl ToolLock {
             }
         }
 
-        let markers = pip::resolution_markers(None, python_platform, interpreter);
+        let markers = pip::resolution_markers(
+            None,
+            python_platform,
+            &PlatformMarkerOverrides::default(),
+            interpreter,
+        );
         let tags = pip::resolution_tags(None, python_platform, interpreter)?;
         Ok(ToolLockInstallTarget {
             tool_lock: self,
@@ -10,7 +10,7 @@ use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DryRun, Excludes, GitLfsSetting, HashCheckingMode, Overrides,
-    Reinstall, TargetTriple, Upgrade,
+    PlatformMarkerOverrides, Reinstall, TargetTriple, Upgrade,
 };
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
@@ -70,6 +70,7 @@ pub(crate) async fn install(
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    args: ResolverInstallerOptions,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
@@ -455,8 +456,6 @@ pub(crate) async fn install(
             .map(|constraint| constraint.requirement)
             .collect::<Vec<_>>();
 
-    // Convert to tool options.
-    let options = ToolOptions::from(options);
     let lock_manifest = ToolLock::manifest(
         &requirements,
         &receipt_constraints,
@@ -503,6 +502,28 @@ pub(crate) async fn install(
             }
         };
 
+    // When reinstalling an existing tool, re-apply the options stored in its receipt, preferring:
+    // CLI > receipt > user.
+    let (options, settings) = match existing_tool_receipt.as_ref() {
+        Some(receipt) if !settings.reinstall.is_none() => {
+            let options = args.combine(
+                ResolverInstallerOptions::from(receipt.options().clone()).combine(options),
+            );
+            let mut receipt_settings = ResolverInstallerSettings::from(options.clone());
+            // Preserve any upgrades and reinstalls implied by the command itself.
+            receipt_settings.resolver.upgrade = settings.resolver.upgrade;
+            receipt_settings.reinstall = settings.reinstall;
+            if settings.resolver.torch_backend.is_some() {
+                receipt_settings.resolver.torch_backend = settings.resolver.torch_backend;
+            }
+            (options, receipt_settings)
+        }
+        _ => (options, settings),
+    };
+
+    // Convert to tool options.
+    let options = ToolOptions::from(options);
+
     let existing_environment = if force {
         None
     } else {
@@ -600,6 +621,7 @@ pub(crate) async fn install(
                 let markers = resolution_markers(
                     None,
                     python_platform.as_ref(),
+                    &PlatformMarkerOverrides::default(),
                     environment.environment().interpreter(),
                 );
                 let tags = resolution_tags(
@@ -33,6 +33,7 @@ pub(crate) async fn list(
     show_with: bool,
     show_extras: bool,
     show_python: bool,
+    show_options: bool,
     outdated: bool,
     args: ResolverInstallerOptions,
     filesystem: ResolverInstallerOptions,
@@ -254,6 +255,20 @@ pub(crate) async fn list(
             })
             .unwrap_or_default();
 
+        let options = if show_options {
+            tool.options_to_toml()?
+                .map(|options| {
+                    let options = options
+                        .iter()
+                        .map(|(key, value)| format!("{key} = {}", value.to_string().trim()))
+                        .join(", ");
+                    format!(" [options: {options}]")
+                })
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
         let latest_version = if outdated {
             latest
                 .get(&name)
@@ -269,7 +284,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{} ({})",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{options}{latest_version}"
                 )
                 .bold(),
                 installed_tools.tool_dir(&name).simplified_display().cyan(),
@@ -279,7 +294,7 @@ pub(crate) async fn list(
                 printer.stdout(),
                 "{}",
                 format!(
-                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{latest_version}"
+                    "{name} v{version}{version_specifier}{extra_requirements}{with_requirements}{python_version}{options}{latest_version}"
                 )
                 .bold()
             )?;
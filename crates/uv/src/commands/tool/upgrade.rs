@@ -596,14 +596,17 @@ async fn upgrade_tool(
             tool_lock.as_ref(),
             printer,
         )?;
-    } else if tool_locks {
-        ToolLock::write(&tool_dir, tool_lock.as_ref())?;
-        installed_tools.add_tool_receipt(
-            name,
-            existing_tool_receipt
-                .clone()
-                .with_options(ToolOptions::from(options)),
-        )?;
+    } else {
+        if tool_locks {
+            ToolLock::write(&tool_dir, tool_lock.as_ref())?;
+        }
+
+        // Persist any options provided on the command line, even if the environment is unchanged.
+        let options = ToolOptions::from(options);
+        if tool_locks || *existing_tool_receipt.options() != options {
+            installed_tools
+                .add_tool_receipt(name, existing_tool_receipt.clone().with_options(options))?;
+        }
     }
 
     let constraint = match &outcome {
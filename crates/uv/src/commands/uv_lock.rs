@@ -0,0 +1,159 @@
+//! Shared helpers for reading a `uv.lock` outside of a project and deriving a [`Resolution`] and
+//! [`HashStrategy`] from it, used by `uv pip install --from-lock`.
+
+use std::path::{Path, PathBuf};
+
+use tracing::info_span;
+
+use uv_configuration::{
+    BuildOptions, DependencyGroups, ExtrasSpecification, HashCheckingMode, InstallOptions,
+    PlatformMarkerOverrides, TargetTriple,
+};
+use uv_distribution_types::Resolution;
+use uv_fs::Simplified;
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName};
+use uv_python::{Interpreter, PythonVersion};
+use uv_resolver::{Lock, LockVersion, VERSION};
+use uv_types::HashStrategy;
+
+use crate::commands::pip::{resolution_markers, resolution_tags};
+
+/// Read a `uv.lock` from a local path and parse it.
+///
+/// Returns the `install_path` (used to resolve relative package sources in the lock) alongside
+/// the parsed [`Lock`].
+pub(crate) async fn read_uv_lock(path: &Path) -> anyhow::Result<(PathBuf, Lock)> {
+    let absolute = std::path::absolute(path)?;
+    let install_path = absolute
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(PathBuf::new);
+    let content = fs_err::tokio::read_to_string(path).await?;
+
+    let lock = match info_span!("toml::from_str lock", path = %path.display())
+        .in_scope(|| toml::from_str::<Lock>(&content))
+    {
+        Ok(lock) => lock,
+        Err(err) => {
+            if let Ok(lock) = toml::from_str::<LockVersion>(&content)
+                && lock.version() != VERSION
+            {
+                anyhow::bail!(
+                    "The lockfile at `{}` uses an unsupported schema version (v{}, but only v{VERSION} is supported)",
+                    path.user_display(),
+                    lock.version(),
+                );
+            }
+            return Err(anyhow::Error::new(err).context(format!(
+                "Not a valid `uv.lock` file: {}",
+                path.user_display()
+            )));
+        }
+    };
+
+    if lock.version() != VERSION {
+        anyhow::bail!(
+            "The lockfile at `{}` uses an unsupported schema version (v{}, but only v{VERSION} is supported)",
+            path.user_display(),
+            lock.version(),
+        );
+    }
+
+    Ok((install_path, lock))
+}
+
+/// Verify Python compatibility and convert a parsed [`Lock`] into a [`Resolution`] with its
+/// [`HashStrategy`].
+///
+/// The lockfile's workspace root (or, for virtual workspaces, its members) are used as the roots
+/// of the resolution. If any `groups` are provided, only those dependency groups are installed,
+/// omitting the project itself and its dependencies.
+pub(crate) fn resolve_uv_lock(
+    lock: &Lock,
+    install_path: &Path,
+    interpreter: &Interpreter,
+    python_version: Option<&PythonVersion>,
+    python_platform: Option<&TargetTriple>,
+    platform_markers: &PlatformMarkerOverrides,
+    extras: &ExtrasSpecification,
+    groups: Vec<GroupName>,
+    build_options: &BuildOptions,
+    hash_checking: Option<HashCheckingMode>,
+) -> anyhow::Result<(Resolution, HashStrategy)> {
+    if !lock
+        .requires_python()
+        .contains(interpreter.python_version())
+    {
+        return Err(anyhow::anyhow!(
+            "The requested interpreter resolved to Python {}, which is incompatible with the `uv.lock`'s Python requirement: `{}`",
+            interpreter.python_version(),
+            lock.requires_python(),
+        ));
+    }
+
+    // Use the workspace root as the root of the resolution; in a virtual workspace, use each of
+    // the workspace members instead.
+    let roots = if let Some(root) = lock.root() {
+        vec![root]
+    } else {
+        lock.packages()
+            .iter()
+            .filter(|package| lock.members().contains(package.name()))
+            .collect()
+    };
+
+    for group in &groups {
+        if !roots
+            .iter()
+            .any(|root| root.dependency_groups().contains_key(group))
+        {
+            return Err(anyhow::anyhow!(
+                "Group `{group}` is not defined in the `uv.lock`"
+            ));
+        }
+    }
+
+    let tags = resolution_tags(python_version, python_platform, interpreter)?;
+    let marker_env = resolution_markers(
+        python_version,
+        python_platform,
+        platform_markers,
+        interpreter,
+    );
+
+    let extras = extras.with_defaults(DefaultExtras::default());
+    let groups = if groups.is_empty() {
+        DependencyGroups::default()
+    } else {
+        DependencyGroups::from_args(
+            false,
+            false,
+            false,
+            Vec::new(),
+            Vec::new(),
+            false,
+            groups,
+            false,
+        )
+    }
+    .with_defaults(DefaultGroups::List(Vec::new()));
+
+    let resolution = lock.to_resolution(
+        install_path,
+        roots,
+        None,
+        &marker_env,
+        &tags,
+        &extras,
+        &groups,
+        build_options,
+        &InstallOptions::default(),
+    )?;
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_resolution(&resolution, hash_checking)?
+    } else {
+        HashStrategy::None
+    };
+
+    Ok((resolution, hasher))
+}
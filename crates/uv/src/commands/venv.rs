@@ -24,7 +24,8 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
 use uv_preview::Preview;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
@@ -36,7 +37,6 @@ use uv_virtualenv::{OnExisting, RemovalReason};
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
-use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
 use crate::commands::pip::operations::{Changelog, report_interpreter};
 use crate::commands::project::{
@@ -64,6 +64,36 @@ enum VenvError {
     FlatIndex(#[source] uv_client::FlatIndexError),
 }
 
+/// A virtual environment created by [`venv`].
+#[derive(Debug)]
+pub(crate) struct CreatedVirtualEnvironment {
+    /// The root of the virtual environment.
+    pub(crate) root: PathBuf,
+    /// The link to the environment from the project, for centralized project environments.
+    pub(crate) link: Option<PathBuf>,
+    /// Whether an existing virtual environment was retained (i.e., with `--allow-existing`).
+    pub(crate) retained: bool,
+    /// The created environment.
+    pub(crate) environment: PythonEnvironment,
+    /// The project discovered from the working directory, if any.
+    pub(crate) project: Option<VirtualProject>,
+}
+
+impl CreatedVirtualEnvironment {
+    /// Remove the virtual environment, along with any link to it.
+    ///
+    /// Environments that existed prior to the invocation are left in place.
+    pub(crate) fn remove(&self) -> std::io::Result<()> {
+        if self.retained {
+            return Ok(());
+        }
+        if let Some(link) = &self.link {
+            uv_fs::remove_symlink(link)?;
+        }
+        uv_fs::remove_virtualenv(&self.root)
+    }
+}
+
 /// Create a virtual environment.
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn venv(
@@ -92,7 +122,7 @@ pub(crate) async fn venv(
     printer: Printer,
     relocatable: bool,
     preview: Preview,
-) -> Result<ExitStatus> {
+) -> Result<CreatedVirtualEnvironment> {
     let project = if no_project {
         None
     } else {
@@ -258,6 +288,10 @@ pub(crate) async fn venv(
         _ => on_existing,
     };
 
+    // Track whether we're retaining an existing environment, such that it isn't removed if a
+    // subsequent sync fails.
+    let retained = matches!(on_existing, OnExisting::Allow) && uv_fs::is_virtualenv_base(&path);
+
     // Create the virtual environment.
     let venv = uv_virtualenv::create_venv(
         &path,
@@ -376,11 +410,15 @@ pub(crate) async fn venv(
     }
 
     // Determine the appropriate environment path.
-    let scripts = if let Some(workspace) = centralized_workspace
-        && update_project_environment_link(&venv, workspace, LinkErrorReporting::User)
+    let link = centralized_workspace
+        .filter(|workspace| {
+            update_project_environment_link(&venv, workspace, LinkErrorReporting::User)
+        })
+        .map(|workspace| workspace.install_path().join(".venv"));
+    let scripts = if let Some(link) = &link
         && let Ok(suffix) = venv.scripts().strip_prefix(&path)
     {
-        workspace.install_path().join(".venv").join(suffix)
+        link.join(suffix)
     } else {
         venv.scripts().to_path_buf()
     };
@@ -410,5 +448,11 @@ pub(crate) async fn venv(
         writeln!(printer.stderr(), "Activate with: {}", act.green())?;
     }
 
-    Ok(ExitStatus::Success)
+    Ok(CreatedVirtualEnvironment {
+        root: path,
+        link,
+        retained,
+        environment: venv,
+        project,
+    })
 }
@@ -0,0 +1,77 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Validate the members of the workspace.
+///
+/// Invalid or duplicate members are reported during workspace discovery; dependency cycles between
+/// members are reported here.
+pub(crate) async fn check(
+    project_dir: &Path,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        cache,
+        workspace_cache,
+    )
+    .await?;
+
+    let cycles = workspace.member_cycles();
+    if cycles.is_empty() {
+        let members = workspace.packages().len();
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Checked {} workspace {}",
+                members,
+                if members == 1 { "member" } else { "members" }
+            )
+            .dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Found {}",
+            format!(
+                "{} dependency {}",
+                cycles.len(),
+                if cycles.len() == 1 { "cycle" } else { "cycles" }
+            )
+            .bold()
+        )
+        .dimmed()
+    )?;
+    for cycle in &cycles {
+        // Close the cycle, e.g., `a -> b -> a`.
+        let path = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|name| format!("`{}`", name.cyan()))
+            .join(" -> ");
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Workspace members form a dependency cycle: {path}").bold()
+        )?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
@@ -1,3 +1,4 @@
+pub(crate) mod check;
 pub(crate) mod dir;
 pub(crate) mod list;
 pub(crate) mod metadata;
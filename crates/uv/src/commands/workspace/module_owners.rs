@@ -5,7 +5,7 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
     Concurrency, DependencyGroups, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    ExtrasSpecificationWithDefaults, InstallOptions, Reinstall,
+    ExtrasSpecificationWithDefaults, InstallOptions, PlatformMarkerOverrides, Reinstall,
 };
 use uv_distribution_types::{Dist, Name, ResolvedDist};
 use uv_fs::PortablePathBuf;
@@ -118,7 +118,12 @@ fn selected_package_ids(
     groups: &DependencyGroupsWithDefaults,
     settings: &ResolverSettings,
 ) -> Result<Option<BTreeMap<PackageName, String>>> {
-    let marker_env = resolution_markers(None, None, venv.interpreter());
+    let marker_env = resolution_markers(
+        None,
+        None,
+        &PlatformMarkerOverrides::default(),
+        venv.interpreter(),
+    );
     let tags = resolution_tags(None, None, venv.interpreter())?;
 
     let resolution = target.to_resolution(
@@ -13,7 +13,7 @@ use std::sync::atomic::Ordering;
 
 use anyhow::{Result, anyhow, bail};
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::{ArgMatches, CommandFactory, FromArgMatches};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -28,12 +28,13 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
+    CacheNamespace, Cli, Commands, ConfigCommand, ConfigNamespace, PipCommand, PipNamespace,
+    ProjectCommand, PythonCommand, PythonInstallFormat, PythonNamespace, PythonShimsCommand,
+    PythonShimsNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
     WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
-use uv_configuration::min_stack_size;
+use uv_configuration::{CompileBytecode, min_stack_size};
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified, normalize_path};
 #[cfg(feature = "self-update")]
@@ -65,6 +66,7 @@ mod install_source;
 mod logging;
 pub(crate) mod printer;
 pub(crate) mod settings;
+mod version_file;
 
 /// uv was installed through an external package manager and cannot update itself.
 #[cfg(not(feature = "self-update"))]
@@ -89,7 +91,7 @@ impl uv_errors::Hint for ExternallyInstalledError {
     }
 }
 #[instrument(skip_all)]
-async fn run(cli: Cli) -> Result<ExitStatus> {
+async fn run(cli: Cli, matches: ArgMatches) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
     if cli.top_level.global_args.quiet == 0 {
         uv_warnings::enable();
@@ -111,13 +113,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         && let ProjectCommand::Run(uv_cli::RunArgs {
             command: Some(ref command),
             module,
+            code,
             script,
             gui_script,
+            yes,
             ..
         }) = **command
     {
         Some(ParsedRunCommand::from_args(
-            command, module, script, gui_script,
+            command, module, code, script, gui_script, yes,
         )?)
     } else {
         None
@@ -316,6 +320,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         let (script, run_command) = parsed_run_command
             .resolve(
                 &cli.top_level.global_args,
+                &cli.top_level.cache_args,
                 filesystem.as_ref(),
                 &environment,
             )
@@ -457,13 +462,25 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     );
 
     // Set the global flags.
-    uv_flags::init(EnvironmentFlags::from(&environment))
-        .map_err(|()| anyhow::anyhow!("Flags are already initialized"))?;
+    let mut flags = EnvironmentFlags::from(&environment);
+    if cli.top_level.global_args.timings {
+        flags.insert(EnvironmentFlags::TIMINGS);
+    }
+    uv_flags::init(flags).map_err(|()| anyhow::anyhow!("Flags are already initialized"))?;
 
     debug!("uv {}", uv_cli::version::uv_self_version());
     if let Some(config_file) = cli.top_level.config_file.as_ref() {
         debug!("Using configuration file: {}", config_file.user_display());
     }
+    if directory.is_some() {
+        debug!("Using working directory: {}", CWD.simplified_display());
+    }
+    if *project_dir != **CWD {
+        debug!(
+            "Using project directory: {}",
+            project_dir.simplified_display()
+        );
+    }
     if globals.preview.all_enabled() {
         debug!("All preview features are enabled");
     } else if globals.preview.any_enabled() {
@@ -519,6 +536,14 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         uv_warnings::enable();
     }
 
+    // Notify the user if the running version differs from the one pinned in a `.uv-version` file.
+    if !cli.top_level.no_config
+        && !environment.no_uv_version_notice.unwrap_or(false)
+        && !matches!(&*cli.command, Commands::Self_(_))
+    {
+        version_file::warn_on_mismatch(&project_dir);
+    }
+
     anstream::ColorChoice::write_global(globals.color.into());
 
     miette::set_hook(Box::new(|_| {
@@ -607,7 +632,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     )
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
-    .no_proxy(globals.network_settings.no_proxy.clone());
+    .no_proxy(globals.network_settings.no_proxy.clone())
+    .min_tls_version(globals.network_settings.min_tls_version)
+    .tls_info(globals.network_settings.tls_info);
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -753,10 +780,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.extras,
                 groups,
                 args.settings.output_file.as_deref(),
+                args.split_extras,
                 args.format,
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.fork_strategy,
+                args.settings.prefetch_limit,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -788,6 +817,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.install_mirrors,
                 args.settings.python_version,
                 args.settings.python_platform,
+                &args.platform_markers,
                 globals.python_downloads,
                 args.settings.universal,
                 args.settings.exclude_newer,
@@ -855,7 +885,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.reinstall,
                 args.settings.link_mode,
-                args.settings.compile_bytecode,
+                CompileBytecode::from_args(args.settings.compile_bytecode, args.compile_package),
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -875,6 +905,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                &args.platform_markers,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
@@ -998,12 +1029,25 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
+            let license_policy = if args.check_licenses {
+                if args.license_policy.is_none() {
+                    warn_user!(
+                        "`--check-licenses` was provided, but no `tool.uv.license-policy` was found in `pyproject.toml`"
+                    );
+                }
+                args.license_policy.as_ref()
+            } else {
+                None
+            };
+
             Box::pin(commands::pip_install(
                 &requirements,
                 &constraints,
+                args.constraints_scope,
                 &overrides,
                 &excludes,
                 &build_constraints,
+                args.from_lock.as_deref(),
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
                 args.excludes_from_workspace,
@@ -1013,30 +1057,49 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prefetch_limit,
+                args.resolution_explain,
+                args.hash_coverage_report,
+                args.require_hashes_package,
+                args.report,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
                 args.settings.index_strategy,
+                args.index_cache_ttl,
+                args.index_credentials,
+                args.check_index,
+                args.fail_fast,
+                license_policy,
+                args.require_attestations,
+                args.resolution_cache.as_deref(),
+                args.build_backend_timeout,
                 args.settings.torch_backend,
                 args.settings.cuda_driver_version,
                 args.settings.amd_gpu_architecture,
                 args.settings.dependency_metadata,
+                args.dependency_metadata_file.as_deref(),
                 args.settings.keyring_provider,
-                &client_builder.subcommand(vec!["pip".to_owned(), "install".to_owned()]),
+                &client_builder
+                    .subcommand(vec!["pip".to_owned(), "install".to_owned()])
+                    .cache_credentials(!args.no_index_cache_credentials),
                 args.settings.reinstall,
+                args.reinstall_broken,
                 args.settings.link_mode,
-                args.settings.compile_bytecode,
+                CompileBytecode::from_args(args.settings.compile_bytecode, args.compile_package),
                 args.settings.hash_checking,
                 globals.installer_metadata,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
                 args.settings.build_isolation.clone(),
+                args.clean_build_deps,
                 &args.settings.extra_build_dependencies,
                 &args.settings.extra_build_variables,
                 args.settings.build_options,
                 args.modifications,
                 args.settings.python_version,
                 args.settings.python_platform,
+                &args.platform_markers,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
@@ -1084,12 +1147,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
+                args.allow_system_managed,
+                args.requested_only,
                 args.settings.target,
                 args.settings.prefix,
                 cache,
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "uninstall".to_owned()]),
                 args.dry_run,
+                args.simulate_deps,
                 printer,
             )
             .await
@@ -1253,6 +1319,37 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::List(args),
+        }) => {
+            commands::config_list(
+                args.show_origin,
+                args.format,
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                cli.top_level.no_config,
+                &matches,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Get(args),
+        }) => {
+            commands::config_get(
+                &args.key,
+                args.show_origin,
+                args.format,
+                &project_dir,
+                cli.top_level.config_file.as_deref(),
+                cli.top_level.no_config,
+                &matches,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1363,7 +1460,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 },
             );
 
-            Box::pin(commands::venv(
+            let environment = Box::pin(commands::venv(
                 &project_dir,
                 args.path,
                 python_request,
@@ -1394,7 +1491,66 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                         && !args.no_relocatable),
                 globals.preview,
             ))
-            .await
+            .await?;
+
+            // If requested, sync the project into the new environment.
+            let Some(sync) = args.sync else {
+                return Ok(ExitStatus::Success);
+            };
+            let result = Box::pin(commands::sync(
+                &project_dir,
+                sync.lock_check,
+                sync.frozen,
+                sync.dry_run,
+                sync.allow_index_fallback,
+                sync.active,
+                sync.all_packages,
+                sync.package,
+                sync.extras,
+                sync.groups,
+                sync.editable,
+                sync.install_options,
+                sync.modifications,
+                sync.remove_extraneous,
+                sync.python,
+                sync.python_platform,
+                sync.install_mirrors,
+                globals.python_preference,
+                globals.python_downloads,
+                sync.settings,
+                client_builder.subcommand(vec!["venv".to_owned()]),
+                None,
+                // Sync into the environment that was just created, rather than rediscovering it.
+                environment
+                    .project
+                    .clone()
+                    .map(|project| (project, environment.environment.clone())),
+                globals.installer_metadata,
+                globals.concurrency,
+                cli.top_level.no_config,
+                &cache,
+                &workspace_cache,
+                printer,
+                globals.preview,
+                sync.output_format,
+                sync.malware_settings,
+            ))
+            .await;
+
+            if args.rm_on_failure && !matches!(result, Ok(ExitStatus::Success)) {
+                debug!(
+                    "Removing virtual environment after failed sync: `{}`",
+                    environment.root.user_display()
+                );
+                if let Err(err) = environment.remove() {
+                    warn_user!(
+                        "Failed to remove virtual environment at `{}`: {err}",
+                        environment.root.user_display()
+                    );
+                }
+            }
+
+            result
         }
         Commands::Project(project) => {
             Box::pin(run_project(
@@ -1418,14 +1574,17 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             command:
                 SelfCommand::Update(SelfUpdateArgs {
                     target_version,
+                    channel,
                     token,
                     dry_run,
                 }),
         }) => {
             commands::self_update(
                 target_version,
+                channel,
                 token,
                 dry_run,
+                &project_dir,
                 printer,
                 client_builder.subcommand(vec!["self".to_owned(), "update".to_owned()]),
             )
@@ -1450,8 +1609,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         }
         Commands::GenerateShellCompletion(args) => {
             args.shell.generate(&mut Cli::command(), &mut stdout());
+            if let Some(script) = commands::dynamic_completion_script(&args.shell) {
+                std::io::Write::write_all(&mut stdout(), script.as_bytes())?;
+            }
             Ok(ExitStatus::Success)
         }
+        Commands::Complete(args) => commands::complete(&args.words, &project_dir, &cache, printer),
         Commands::Tool(ToolNamespace {
             command: run_variant @ (ToolCommand::Uvx(_) | ToolCommand::Run(_)),
         }) => {
@@ -1664,6 +1827,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.python_platform,
                 args.install_mirrors,
                 args.force,
+                args.args,
                 args.options,
                 args.settings,
                 client_builder.subcommand(vec!["tool".to_owned(), "install".to_owned()]),
@@ -1696,6 +1860,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.show_with,
                 args.show_extras,
                 args.show_python,
+                args.show_options,
                 args.outdated,
                 args.args,
                 args.filesystem,
@@ -1780,6 +1945,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.all_platforms,
                 args.all_arches,
                 args.show_urls,
+                args.sort,
+                args.reverse,
                 args.output_format,
                 args.python_downloads_json_url,
                 args.python_install_mirror,
@@ -1808,6 +1975,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 args.reinstall,
                 args.upgrade,
+                args.skip_if_present,
                 args.bin,
                 args.registry,
                 args.force,
@@ -1819,6 +1987,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.python_downloads,
                 cli.top_level.no_config,
                 args.compile_bytecode,
+                args.verify,
+                args.output_format,
                 &globals.concurrency,
                 &cache,
                 globals.preview,
@@ -1843,6 +2013,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 args.reinstall,
                 upgrade,
+                false,
                 args.bin,
                 args.registry,
                 args.force,
@@ -1854,6 +2025,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.python_downloads,
                 cli.top_level.no_config,
                 args.compile_bytecode,
+                args.verify,
+                PythonInstallFormat::Text,
                 &globals.concurrency,
                 &cache,
                 globals.preview,
@@ -1868,8 +2041,22 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonUninstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::python_uninstall(args.install_dir, args.targets, args.all, printer).await
+            commands::python_uninstall(
+                args.install_dir,
+                args.targets,
+                args.all,
+                args.older_than,
+                args.default_shims,
+                printer,
+            )
+            .await
         }
+        Commands::Python(PythonNamespace {
+            command:
+                PythonCommand::Shims(PythonShimsNamespace {
+                    command: PythonShimsCommand::List,
+                }),
+        }) => commands::python_shims_list(printer),
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
         }) => {
@@ -1899,6 +2086,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     args.request,
                     args.show_version,
                     args.resolve_links,
+                    args.all,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -2073,6 +2261,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 )
                 .await
             }
+            WorkspaceCommand::Check => {
+                commands::workspace_check(&project_dir, &cache, &workspace_cache, printer).await
+            }
         },
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
@@ -2228,6 +2419,7 @@ async fn run_project(
                 args.name,
                 args.package,
                 args.kind,
+                args.script,
                 args.bare,
                 args.description,
                 args.no_description,
@@ -2236,15 +2428,21 @@ async fn run_project(
                 args.no_readme,
                 args.author_from,
                 args.pin_python,
+                args.infer_requires_python,
                 args.python,
                 args.install_mirrors,
                 args.no_workspace,
+                args.depends_on,
+                args.no_lock,
+                args.settings,
                 &client_builder.subcommand(vec!["init".to_owned()]),
                 globals.python_preference,
                 globals.python_downloads,
+                globals.concurrency,
                 no_config,
                 &cache,
                 printer,
+                globals.preview,
             ))
             .await
         }
@@ -2348,6 +2546,7 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.allow_index_fallback,
                 args.active,
                 args.all_packages,
                 args.package,
@@ -2356,6 +2555,7 @@ async fn run_project(
                 args.editable,
                 args.install_options,
                 args.modifications,
+                args.remove_extraneous,
                 args.python,
                 args.python_platform,
                 args.install_mirrors,
@@ -2364,6 +2564,7 @@ async fn run_project(
                 args.settings,
                 client_builder.subcommand(vec!["sync".to_owned()]),
                 script,
+                None,
                 globals.installer_metadata,
                 globals.concurrency,
                 no_config,
@@ -2411,6 +2612,8 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.show_forks,
+                args.refresh_url_hashes,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2766,6 +2969,7 @@ async fn run_project(
                 args.include_header,
                 args.include_index_url,
                 args.include_find_links,
+                args.strict,
                 script,
                 args.python,
                 args.install_mirrors,
@@ -2893,9 +3097,12 @@ async fn run_project(
                 cache,
                 printer,
                 globals.preview,
+                args.installed,
                 args.output_format,
+                args.fail_on,
                 args.service_format,
                 args.service_url,
+                args.service_snapshot,
                 args.ignore,
                 args.ignore_until_fixed,
             ))
@@ -2940,8 +3147,17 @@ where
 
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
-        Ok(cli) => cli,
+    //
+    // The matches are retained to determine the origin of each setting (e.g., in `uv config`).
+    let parsed = Cli::command()
+        .try_get_matches_from(args)
+        .and_then(|matches| {
+            let cli =
+                Cli::from_arg_matches(&matches).map_err(|err| err.format(&mut Cli::command()))?;
+            Ok((cli, matches))
+        });
+    let (cli, matches) = match parsed {
+        Ok(parsed) => parsed,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
             {
@@ -2998,7 +3214,7 @@ where
             .build()
             .expect("Failed building the Runtime");
         // Box the large main future to avoid stack overflows.
-        let result = runtime.block_on(Box::pin(run(cli)));
+        let result = runtime.block_on(Box::pin(run(cli, matches)));
         // Avoid waiting for pending tasks to complete.
         //
         // The resolver may have kicked off HTTP requests during resolution that
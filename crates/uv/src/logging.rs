@@ -85,7 +85,10 @@ pub(crate) fn setup_logging(
             ColorChoice::Never => (false, anstream::ColorChoice::Never),
             ColorChoice::Auto => unreachable!("anstream can't return auto as choice"),
         };
-    let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
+    let writer = std::sync::Mutex::new(RedactedWriter(anstream::AutoStream::new(
+        std::io::stderr(),
+        color_choice,
+    )));
 
     if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
@@ -117,6 +120,25 @@ pub(crate) fn setup_logging(
     Ok(())
 }
 
+/// A log writer that asserts that no credentials leak into the logs (in debug builds).
+///
+/// URLs should only reach the logs via [`uv_redacted::DisplaySafeUrl`], which redacts credentials
+/// on display.
+struct RedactedWriter<W>(W);
+
+impl<W: std::io::Write> std::io::Write for RedactedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if cfg!(debug_assertions) {
+            uv_redacted::debug_assert_redacted(&String::from_utf8_lossy(buf));
+        }
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
 /// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
 #[cfg(feature = "tracing-durations-export")]
 pub(crate) fn setup_durations(
@@ -9,18 +9,18 @@ use anyhow::{Result, bail};
 use rustc_hash::FxHashSet;
 
 use uv_audit::{VulnerabilityID, VulnerabilityServiceFormat};
-use uv_auth::Service;
+use uv_auth::{Credentials, Service};
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuditArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs,
-    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
-    VersionFormat,
+    AddArgs, AuditArgs, AuditFailOn, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs,
+    AuthTokenArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
+    MetadataArgs, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs,
+    PythonInstallFormat, PythonListArgs, PythonListFormat, PythonListSort, PythonPinArgs,
+    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
+    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, UpgradeArgs, VenvArgs,
+    VenvSyncArgs, VersionArgs, VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
@@ -33,15 +33,16 @@ use uv_cli::{
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
-    ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode,
-    IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, Override,
-    PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    BuildIsolation, BuildOptions, Concurrency, ConstraintsScope, DependencyGroups, DryRun,
+    EditableMode, EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting,
+    HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    NoSources, Override, PackageOverride, PipCompileFormat, PlatformMarkerOverrides,
+    ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings, Requirement,
+    PackageConfigSettings, Requirement, TlsVersion,
 };
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
@@ -63,12 +64,15 @@ use uv_settings::{
 use uv_static::EnvVars;
 use uv_torch::{AmdGpuArchitecture, TorchMode};
 use uv_warnings::warn_user_once;
-use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies, OverrideDependency};
+use uv_workspace::pyproject::{
+    DependencyType, ExtraBuildDependencies, LicensePolicy, OverrideDependency,
+};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::{
-    InitKind, InitProjectKind, PythonUpgrade, PythonUpgradeSource, ToolRunCommand,
+    InitKind, InitProjectKind, InitScriptOptions, PythonUpgrade, PythonUpgradeSource, SplitExtras,
+    ToolRunCommand,
 };
 
 /// The default publish URL.
@@ -271,6 +275,8 @@ pub(crate) struct NetworkSettings {
     pub(crate) https_proxy: Option<ProxyUrl>,
     pub(crate) no_proxy: Option<Vec<String>>,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) min_tls_version: Option<TlsVersion>,
+    pub(crate) tls_info: bool,
     pub(crate) read_timeout: Duration,
     pub(crate) connect_timeout: Duration,
     pub(crate) retries: u32,
@@ -381,6 +387,7 @@ impl NetworkSettings {
         let http_proxy = workspace.and_then(|workspace| workspace.globals.http_proxy.clone());
         let https_proxy = workspace.and_then(|workspace| workspace.globals.https_proxy.clone());
         let no_proxy = workspace.and_then(|workspace| workspace.globals.no_proxy.clone());
+        let min_tls_version = workspace.and_then(|workspace| workspace.globals.min_tls_version);
 
         Self {
             connectivity,
@@ -390,6 +397,8 @@ impl NetworkSettings {
             https_proxy,
             no_proxy,
             allow_insecure_host,
+            min_tls_version,
+            tls_info: args.tls_info,
             read_timeout: environment.http_read_timeout,
             connect_timeout: environment.http_connect_timeout,
             retries: environment.http_retries,
@@ -439,6 +448,7 @@ pub(crate) struct InitSettings {
     pub(crate) name: Option<PackageName>,
     pub(crate) package: bool,
     pub(crate) kind: InitKind,
+    pub(crate) script: InitScriptOptions,
     pub(crate) bare: bool,
     pub(crate) description: Option<String>,
     pub(crate) no_description: bool,
@@ -448,8 +458,12 @@ pub(crate) struct InitSettings {
     pub(crate) author_from: Option<AuthorFrom>,
     pub(crate) pin_python: bool,
     pub(crate) no_workspace: bool,
+    pub(crate) depends_on: Vec<PackageName>,
+    pub(crate) no_lock: bool,
+    pub(crate) infer_requires_python: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) settings: ResolverSettings,
 }
 
 impl InitSettings {
@@ -470,6 +484,10 @@ impl InitSettings {
             app,
             lib,
             script,
+            dependencies,
+            template,
+            force,
+            convert,
             description,
             no_description,
             vcs,
@@ -479,6 +497,9 @@ impl InitSettings {
             no_pin_python,
             pin_python,
             no_workspace,
+            depends_on,
+            no_lock,
+            infer_requires_python,
             python,
             ..
         } = args;
@@ -486,6 +507,7 @@ impl InitSettings {
         let bare = resolve_flag(bare, "bare", environment.init_bare).is_enabled();
 
         let filesystem_install_mirrors = filesystem
+            .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
 
@@ -580,6 +602,12 @@ impl InitSettings {
             name,
             package,
             kind,
+            script: InitScriptOptions {
+                dependencies: dependencies.into_iter().flatten().collect(),
+                template: template.unwrap_or_default(),
+                force,
+                convert,
+            },
             bare,
             description,
             no_description,
@@ -589,7 +617,15 @@ impl InitSettings {
             author_from,
             pin_python: flag(pin_python, no_pin_python, "pin-python").unwrap_or(!bare),
             no_workspace,
+            depends_on,
+            no_lock,
+            infer_requires_python,
             python: python.and_then(Maybe::into_option),
+            settings: ResolverSettings::combine(
+                ResolverOptions::default(),
+                filesystem,
+                &environment,
+            ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -728,8 +764,10 @@ impl RunSettings {
             no_editable_package,
             inexact,
             exact,
+            code: _,
             script: _,
             gui_script: _,
+            yes: _,
             command: _,
             with,
             with_editable,
@@ -1032,6 +1070,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) refresh: Refresh,
+    pub(crate) args: ResolverInstallerOptions,
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
@@ -1070,11 +1109,11 @@ impl ToolInstallSettings {
 
         let filesystem_options = filesystem.map(FilesystemOptions::into_options);
 
-        let options = resolver_installer_options_with_environment(
+        let args = resolver_installer_options_with_environment(
             resolver_installer_options(installer, build),
             &environment,
-        )
-        .combine(ResolverInstallerOptions::from(
+        );
+        let options = args.clone().combine(ResolverInstallerOptions::from(
             filesystem_options
                 .as_ref()
                 .map(|options| options.top_level.clone())
@@ -1132,6 +1171,7 @@ impl ToolInstallSettings {
             force,
             editable,
             refresh: Refresh::from(refresh),
+            args,
             options,
             settings,
             install_mirrors: environment
@@ -1176,6 +1216,8 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            prefetch,
+            no_resolution_cache,
             config_setting,
             config_setting_package: config_settings_package,
             no_build_isolation,
@@ -1214,6 +1256,8 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            prefetch,
+            no_resolution_cache,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -1265,6 +1309,7 @@ pub(crate) struct ToolListSettings {
     pub(crate) show_with: bool,
     pub(crate) show_extras: bool,
     pub(crate) show_python: bool,
+    pub(crate) show_options: bool,
     pub(crate) outdated: bool,
     pub(crate) args: ResolverInstallerOptions,
     pub(crate) filesystem: ResolverInstallerOptions,
@@ -1279,6 +1324,7 @@ impl ToolListSettings {
             show_with,
             show_extras,
             show_python,
+            show_options,
             outdated,
             no_outdated,
             exclude_newer,
@@ -1298,6 +1344,7 @@ impl ToolListSettings {
             show_with,
             show_extras,
             show_python,
+            show_options,
             outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
             args: ResolverInstallerOptions {
                 exclude_newer,
@@ -1360,6 +1407,8 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_arches: bool,
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
+    pub(crate) sort: PythonListSort,
+    pub(crate) reverse: bool,
     pub(crate) output_format: PythonListFormat,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) python_install_mirror: Option<String>,
@@ -1382,6 +1431,8 @@ impl PythonListSettings {
             only_installed,
             only_downloads,
             show_urls,
+            sort,
+            reverse,
             output_format,
             python_downloads_json_url: python_downloads_json_url_arg,
         } = args;
@@ -1434,6 +1485,8 @@ impl PythonListSettings {
             all_arches,
             all_versions,
             show_urls,
+            sort,
+            reverse,
             output_format,
             python_downloads_json_url,
             python_install_mirror,
@@ -1466,6 +1519,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) reinstall: bool,
     pub(crate) force: bool,
     pub(crate) upgrade: PythonUpgrade,
+    pub(crate) skip_if_present: bool,
     pub(crate) bin: Option<bool>,
     pub(crate) registry: Option<bool>,
     pub(crate) python_install_mirror: Option<String>,
@@ -1473,6 +1527,8 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
     pub(crate) compile_bytecode: bool,
+    pub(crate) verify: bool,
+    pub(crate) output_format: PythonInstallFormat,
 }
 
 impl PythonInstallSettings {
@@ -1507,10 +1563,13 @@ impl PythonInstallSettings {
             no_registry,
             force,
             upgrade,
+            skip_if_present,
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
             default,
+            no_verify,
+            output_format,
             compile_bytecode,
         } = args;
 
@@ -1524,6 +1583,7 @@ impl PythonInstallSettings {
             } else {
                 PythonUpgrade::Disabled
             },
+            skip_if_present,
             bin: flag(bin, no_bin, "bin").or(environment.python_install_bin),
             registry: match flag(registry, no_registry, "registry") {
                 Some(registry) => Some(registry),
@@ -1545,6 +1605,8 @@ impl PythonInstallSettings {
                 "compile-bytecode",
             )
             .unwrap_or_default(),
+            verify: !no_verify,
+            output_format,
         }
     }
 }
@@ -1563,6 +1625,7 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) verify: bool,
     pub(crate) compile_bytecode: bool,
 }
 
@@ -1606,6 +1669,7 @@ impl PythonUpgradeSettings {
             pypy_mirror: _,
             reinstall,
             python_downloads_json_url: _,
+            no_verify,
             compile_bytecode,
         } = args;
 
@@ -1620,6 +1684,7 @@ impl PythonUpgradeSettings {
             python_downloads_json_url,
             default,
             bin,
+            verify: !no_verify,
             compile_bytecode: flag(
                 compile_bytecode.compile_bytecode,
                 compile_bytecode.no_compile_bytecode,
@@ -1636,6 +1701,8 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) older_than: Option<PythonVersion>,
+    pub(crate) default_shims: bool,
 }
 
 impl PythonUninstallSettings {
@@ -1648,12 +1715,16 @@ impl PythonUninstallSettings {
             install_dir,
             targets,
             all,
+            older_than,
+            default_shims,
         } = args;
 
         Self {
             install_dir,
             targets,
             all,
+            older_than,
+            default_shims,
         }
     }
 }
@@ -1664,6 +1735,7 @@ pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
     pub(crate) resolve_links: bool,
+    pub(crate) all: bool,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -1680,6 +1752,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            all,
             no_project,
             system,
             no_system,
@@ -1708,6 +1781,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            all,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
             python_downloads_json_url,
@@ -1772,6 +1846,7 @@ pub(crate) struct SyncSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) allow_index_fallback: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
     pub(crate) extras: ExtrasSpecification,
@@ -1779,6 +1854,7 @@ pub(crate) struct SyncSettings {
     pub(crate) editable: Option<EditableMode>,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) remove_extraneous: Vec<PackageName>,
     pub(crate) all_packages: bool,
     pub(crate) package: Vec<PackageName>,
     pub(crate) python: Option<String>,
@@ -1815,6 +1891,7 @@ impl SyncSettings {
             no_editable_package,
             inexact,
             exact,
+            remove_extraneous,
             no_install_project,
             only_install_project,
             no_install_workspace,
@@ -1828,6 +1905,7 @@ impl SyncSettings {
             active,
             no_active,
             dry_run,
+            allow_index_fallback,
             installer,
             build,
             refresh,
@@ -1844,6 +1922,7 @@ impl SyncSettings {
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
+        let sync_exact = filesystem.as_ref().and_then(|fs| fs.sync.sync_exact);
 
         let settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
@@ -1929,6 +2008,7 @@ impl SyncSettings {
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             dry_run,
+            allow_index_fallback,
             script,
             active: flag(active, no_active, "active"),
             extras: ExtrasSpecification::from_args(
@@ -1968,11 +2048,15 @@ impl SyncSettings {
                 no_install_package,
                 only_install_package,
             ),
-            modifications: if flag(exact, inexact, "inexact").unwrap_or(true) {
+            modifications: if flag(exact, inexact, "inexact")
+                .or(sync_exact)
+                .unwrap_or(true)
+            {
                 Modifications::Exact
             } else {
                 Modifications::Sufficient
             },
+            remove_extraneous,
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
@@ -1993,6 +2077,8 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) show_forks: bool,
+    pub(crate) refresh_url_hashes: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -2012,6 +2098,8 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            show_forks,
+            refresh_url_hashes,
             script,
             resolver,
             build,
@@ -2041,6 +2129,8 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            show_forks,
+            refresh_url_hashes,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -2775,6 +2865,7 @@ pub(crate) struct ExportSettings {
     pub(crate) include_header: bool,
     pub(crate) include_index_url: bool,
     pub(crate) include_find_links: bool,
+    pub(crate) strict: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -2814,6 +2905,7 @@ impl ExportSettings {
             no_emit_index_url,
             emit_find_links,
             no_emit_find_links,
+            strict,
             editable,
             no_editable,
             no_editable_package,
@@ -2917,6 +3009,7 @@ impl ExportSettings {
                 .unwrap_or(false),
             include_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links")
                 .unwrap_or(false),
+            strict,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -3112,9 +3205,12 @@ pub(crate) struct AuditSettings {
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) settings: ResolverSettings,
+    pub(crate) installed: bool,
     pub(crate) output_format: AuditOutputFormat,
+    pub(crate) fail_on: AuditFailOn,
     pub(crate) service_format: VulnerabilityServiceFormat,
     pub(crate) service_url: Option<String>,
+    pub(crate) service_snapshot: Option<PathBuf>,
     pub(crate) ignore: Vec<VulnerabilityID>,
     pub(crate) ignore_until_fixed: Vec<VulnerabilityID>,
 }
@@ -3138,13 +3234,16 @@ impl AuditSettings {
             python_platform,
             locked,
             frozen,
+            installed,
             output_format,
+            fail_on,
             build,
             resolver,
             ignore,
             ignore_until_fixed,
             service_format,
             service_url,
+            service_snapshot,
         } = args;
 
         let filesystem_install_mirrors = filesystem
@@ -3202,9 +3301,12 @@ impl AuditSettings {
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
+            installed,
             output_format,
+            fail_on,
             service_format,
             service_url,
+            service_snapshot,
             ignore: {
                 let config_ignore = filesystem_audit.ignore.unwrap_or_default();
                 let mut merged = ignore;
@@ -3270,6 +3372,8 @@ pub(crate) struct PipCompileSettings {
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
     pub(crate) required_environments: SupportedEnvironments,
+    pub(crate) platform_markers: PlatformMarkerOverrides,
+    pub(crate) split_extras: Option<SplitExtras>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3295,6 +3399,9 @@ impl PipCompileSettings {
             deps,
             group,
             output_file,
+            split_extras,
+            output_dir,
+            output_template,
             format,
             no_strip_extras,
             strip_extras,
@@ -3318,6 +3425,8 @@ impl PipCompileSettings {
             only_binary,
             python_version,
             python_platform,
+            platform_release,
+            platform_version,
             universal,
             no_universal,
             no_emit_package,
@@ -3414,6 +3523,19 @@ impl PipCompileSettings {
             build_constraints_from_workspace,
             environments,
             required_environments,
+            platform_markers: PlatformMarkerOverrides {
+                platform_release,
+                platform_version,
+            },
+            split_extras: if split_extras {
+                output_dir.map(|output_dir| SplitExtras {
+                    output_dir,
+                    template: output_template
+                        .unwrap_or_else(|| "requirements-{extra}.txt".to_string()),
+                })
+            } else {
+                None
+            },
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -3472,6 +3594,8 @@ pub(crate) struct PipSyncSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) compile_package: Vec<PackageName>,
+    pub(crate) platform_markers: PlatformMarkerOverrides,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3492,6 +3616,7 @@ impl PipSyncSettings {
             no_all_extras,
             group,
             installer,
+            compile_package,
             refresh,
             require_hashes,
             no_require_hashes,
@@ -3512,6 +3637,8 @@ impl PipSyncSettings {
             only_binary,
             python_version,
             python_platform,
+            platform_release,
+            platform_version,
             strict,
             no_strict,
             dry_run,
@@ -3530,6 +3657,11 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            compile_package,
+            platform_markers: PlatformMarkerOverrides {
+                platform_release,
+                platform_version,
+            },
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -3574,18 +3706,39 @@ pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) editables: Vec<String>,
+    pub(crate) from_lock: Option<PathBuf>,
     pub(crate) editable: Option<EditableMode>,
     pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) constraints_scope: ConstraintsScope,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) compile_package: Vec<PackageName>,
+    pub(crate) resolution_explain: Vec<PackageName>,
+    pub(crate) hash_coverage_report: bool,
+    pub(crate) require_hashes_package: Vec<PackageName>,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Override<Requirement>>,
     pub(crate) excludes_from_workspace: Vec<ExcludeDependency>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) platform_markers: PlatformMarkerOverrides,
     pub(crate) refresh: Refresh,
+    pub(crate) index_cache_ttl: Option<Duration>,
+    pub(crate) no_index_cache_credentials: bool,
+    pub(crate) index_credentials: Option<Credentials>,
+    pub(crate) check_index: bool,
+    pub(crate) fail_fast: bool,
+    pub(crate) check_licenses: bool,
+    pub(crate) license_policy: Option<LicensePolicy>,
+    pub(crate) require_attestations: bool,
+    pub(crate) dependency_metadata_file: Option<PathBuf>,
+    pub(crate) resolution_cache: Option<PathBuf>,
+    pub(crate) reinstall_broken: bool,
+    pub(crate) build_backend_timeout: Option<Duration>,
+    pub(crate) clean_build_deps: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -3606,14 +3759,32 @@ impl PipInstallSettings {
             overrides,
             excludes,
             build_constraints,
+            constraints_all,
+            constraints_scope,
             extra,
             all_extras,
             no_all_extras,
             installer,
+            compile_package,
             refresh,
+            index_cache_ttl,
+            no_index_cache_credentials,
+            index_username,
+            index_password,
+            check_index,
+            fail_fast,
+            check_licenses,
+            require_attestations,
+            dependency_metadata,
+            resolution_cache,
+            reinstall_broken,
+            build_backend_timeout,
+            clean_build_deps,
             no_deps,
             deps,
             group,
+            group_file,
+            from_lock,
             require_hashes,
             no_require_hashes,
             verify_hashes,
@@ -3631,11 +3802,17 @@ impl PipInstallSettings {
             only_binary,
             python_version,
             python_platform,
+            platform_release,
+            platform_version,
             inexact,
             exact,
             strict,
             no_strict,
             dry_run,
+            resolution_explain,
+            hash_coverage_report,
+            require_hashes_package,
+            report,
             torch_backend,
             compat_args: _,
         } = args;
@@ -3679,14 +3856,33 @@ impl PipInstallSettings {
             Vec::new()
         };
 
+        let license_policy = filesystem
+            .as_ref()
+            .and_then(|configuration| configuration.license_policy.clone());
+
+        // Groups without an explicit path are read from the `--group-file`, if provided.
+        let group = group
+            .into_iter()
+            .map(|group| match (&group.path, &group_file) {
+                (None, Some(group_file)) => PipGroupName {
+                    path: Some(group_file.clone()),
+                    name: group.name,
+                },
+                _ => group,
+            })
+            .collect();
+
         Self {
             package,
             requirements,
             editables: editable,
+            from_lock,
             constraints: constraints
                 .into_iter()
+                .chain(constraints_all.iter().cloned())
                 .filter_map(Maybe::into_option)
                 .collect(),
+            constraints_scope,
             overrides: overrides
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -3697,9 +3893,15 @@ impl PipInstallSettings {
                 .collect(),
             build_constraints: build_constraints
                 .into_iter()
+                .chain(constraints_all)
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            compile_package,
+            resolution_explain,
+            hash_coverage_report,
+            require_hashes_package,
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -3717,7 +3919,25 @@ impl PipInstallSettings {
                 },
                 no_editable_package,
             ),
+            platform_markers: PlatformMarkerOverrides {
+                platform_release,
+                platform_version,
+            },
             refresh: Refresh::from(refresh),
+            index_cache_ttl: index_cache_ttl.map(Duration::from_secs),
+            no_index_cache_credentials,
+            index_credentials: (index_username.is_some() || index_password.is_some())
+                .then(|| Credentials::basic(index_username, index_password)),
+            check_index,
+            fail_fast,
+            check_licenses,
+            license_policy,
+            require_attestations,
+            dependency_metadata_file: dependency_metadata,
+            resolution_cache,
+            reinstall_broken,
+            build_backend_timeout: build_backend_timeout.map(Duration::from_secs),
+            clean_build_deps,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3757,6 +3977,9 @@ pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) simulate_deps: bool,
+    pub(crate) allow_system_managed: bool,
+    pub(crate) requested_only: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -3776,16 +3999,22 @@ impl PipUninstallSettings {
             no_system,
             break_system_packages,
             no_break_system_packages,
+            allow_system_managed,
+            requested_only,
             target,
             prefix,
             dry_run,
+            simulate_deps,
             compat_args: _,
         } = args;
 
         Self {
             package,
             requirements,
-            dry_run: DryRun::from_args(dry_run),
+            dry_run: DryRun::from_args(dry_run || simulate_deps),
+            simulate_deps,
+            allow_system_managed,
+            requested_only,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -4173,6 +4402,8 @@ pub(crate) struct VenvSettings {
     pub(crate) relocatable: bool,
     pub(crate) no_relocatable: bool,
     pub(crate) no_project: bool,
+    pub(crate) sync: Option<SyncSettings>,
+    pub(crate) rm_on_failure: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -4198,6 +4429,7 @@ impl VenvSettings {
             system_site_packages,
             relocatable,
             no_relocatable,
+            sync_args,
             index_args,
             index_strategy,
             keyring_provider,
@@ -4208,6 +4440,18 @@ impl VenvSettings {
             compat_args: _,
             exclude_newer_package,
         } = args;
+        let VenvSyncArgs {
+            sync,
+            rm_on_failure,
+            extra,
+            all_extras,
+            no_dev,
+            group,
+            no_group,
+            no_default_groups,
+            only_group,
+            all_groups,
+        } = sync_args;
 
         // Resolve flags from CLI and environment variables.
         let seed = seed || environment.venv_seed.value == Some(true);
@@ -4228,6 +4472,36 @@ impl VenvSettings {
             None,
         );
 
+        // If requested, resolve the settings for the subsequent project sync, as in `uv sync`.
+        let sync = sync.then(|| {
+            SyncSettings::resolve(
+                SyncArgs {
+                    extra,
+                    all_extras,
+                    no_dev,
+                    group,
+                    no_group,
+                    no_default_groups,
+                    only_group,
+                    all_groups,
+                    installer: ResolverInstallerArgs {
+                        index_args: index_args.clone(),
+                        index_strategy,
+                        keyring_provider,
+                        exclude_newer: exclude_newer.clone(),
+                        exclude_newer_package: exclude_newer_package.clone(),
+                        link_mode,
+                        ..ResolverInstallerArgs::default()
+                    },
+                    refresh: refresh.clone(),
+                    python: python.clone(),
+                    ..SyncArgs::default()
+                },
+                filesystem.clone(),
+                environment.clone(),
+            )
+        });
+
         Self {
             seed,
             allow_existing,
@@ -4240,6 +4514,8 @@ impl VenvSettings {
             no_project,
             relocatable: relocatable.into(),
             no_relocatable: no_relocatable.into(),
+            sync,
+            rm_on_failure,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -4303,6 +4579,8 @@ pub(crate) struct ResolverSettings {
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prefetch_limit: Option<usize>,
+    pub(crate) resolution_cache: bool,
     pub(crate) resolution: ResolutionMode,
     pub(crate) sources: NoSources,
     pub(crate) torch_backend: Option<TorchMode>,
@@ -4328,6 +4606,18 @@ impl ResolverSettings {
             .no_sources_package
             .or(environment.no_sources_package.clone());
 
+        let allow_build = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.allow_build.clone());
+        let build_sandbox = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.build_sandbox)
+            .unwrap_or_default();
+        let build_sandbox_exempt = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.build_sandbox_exempt.clone())
+            .unwrap_or_default();
+
         // The problem is that for `upgrade`... we want to combine the two `Upgrade` structs,
         // not the individual fields.
         let options = args.combine(ResolverOptions::from(
@@ -4337,10 +4627,15 @@ impl ResolverSettings {
                 .unwrap_or_default(),
         ));
 
+        let base = Self::from(options);
         Self {
             cuda_driver_version: environment.cuda_driver_version.clone(),
             amd_gpu_architecture: environment.amd_gpu_architecture,
-            ..Self::from(options)
+            build_options: base
+                .build_options
+                .with_allow_build(allow_build)
+                .with_sandbox(build_sandbox, build_sandbox_exempt),
+            ..base
         }
     }
 }
@@ -4368,6 +4663,8 @@ impl From<ResolverOptions> for ResolverSettings {
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            prefetch_limit: value.prefetch_limit,
+            resolution_cache: value.resolution_cache.unwrap_or(true),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -4423,6 +4720,18 @@ impl ResolverInstallerSettings {
         filesystem: Option<FilesystemOptions>,
         environment: &EnvironmentOptions,
     ) -> Self {
+        let allow_build = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.allow_build.clone());
+        let build_sandbox = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.build_sandbox)
+            .unwrap_or_default();
+        let build_sandbox_exempt = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.build_sandbox_exempt.clone())
+            .unwrap_or_default();
+
         let options = resolver_installer_options_with_environment(args, environment).combine(
             ResolverInstallerOptions::from(
                 filesystem
@@ -4437,6 +4746,11 @@ impl ResolverInstallerSettings {
             resolver: ResolverSettings {
                 cuda_driver_version: environment.cuda_driver_version.clone(),
                 amd_gpu_architecture: environment.amd_gpu_architecture,
+                build_options: base
+                    .resolver
+                    .build_options
+                    .with_allow_build(allow_build)
+                    .with_sandbox(build_sandbox, build_sandbox_exempt),
                 ..base.resolver
             },
             ..base
@@ -4510,6 +4824,8 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
                 prerelease: value.prerelease.unwrap_or_default(),
+                prefetch_limit: value.prefetch_limit,
+                resolution_cache: value.resolution_cache.unwrap_or(true),
                 resolution: value.resolution.unwrap_or_default(),
                 sources: NoSources::from_args(
                     value.no_sources,
@@ -4556,6 +4872,7 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) prefetch_limit: Option<usize>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -4596,6 +4913,9 @@ impl PipSettings {
             top_level,
             pip,
             install_mirrors: filesystem_install_mirrors,
+            allow_build,
+            build_sandbox,
+            build_sandbox_exempt,
             ..
         } = filesystem
             .map(FilesystemOptions::into_options)
@@ -4632,6 +4952,7 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            prefetch_limit,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -4677,6 +4998,8 @@ impl PipSettings {
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            prefetch_limit: top_level_prefetch_limit,
+            resolution_cache: _,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
@@ -4715,6 +5038,7 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let prefetch_limit = prefetch_limit.combine(top_level_prefetch_limit);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -4788,6 +5112,7 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            prefetch_limit: args.prefetch_limit.combine(prefetch_limit),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -4950,6 +5275,11 @@ impl PipSettings {
                     top_level_no_build,
                     top_level_no_build_package.unwrap_or_default(),
                 )),
+            )
+            .with_allow_build(allow_build)
+            .with_sandbox(
+                build_sandbox.unwrap_or_default(),
+                build_sandbox_exempt.unwrap_or_default(),
             ),
             install_mirrors: environment
                 .install_mirrors
@@ -0,0 +1,140 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_dirs::user_uv_config_dir;
+use uv_fs::Simplified;
+use uv_pep440::Version;
+use uv_warnings::warn_user_once;
+
+/// The file name for uv version pins.
+pub(crate) static UV_VERSION_FILENAME: &str = ".uv-version";
+
+/// A `.uv-version` file, pinning the version of uv that should be used.
+#[derive(Debug, Clone)]
+pub(crate) struct UvVersionFile {
+    /// The path to the version file.
+    path: PathBuf,
+    /// The pinned uv version.
+    version: Version,
+}
+
+impl UvVersionFile {
+    /// Find a `.uv-version` file in the given directory or any of its parents, falling back to
+    /// the user configuration directory.
+    pub(crate) fn discover(working_directory: &Path) -> Result<Option<Self>> {
+        let path = working_directory
+            .ancestors()
+            .map(|directory| directory.join(UV_VERSION_FILENAME))
+            .find(|path| path.is_file())
+            .or_else(|| {
+                user_uv_config_dir()
+                    .map(|directory| directory.join(UV_VERSION_FILENAME))
+                    .filter(|path| path.is_file())
+            });
+
+        let Some(path) = path else {
+            debug!(
+                "No uv version file found in ancestors of working directory: {}",
+                working_directory.display()
+            );
+            return Ok(None);
+        };
+
+        Self::try_from_path(path)
+    }
+
+    /// Read a `.uv-version` file, returning `None` if it does not exist.
+    fn try_from_path(path: PathBuf) -> Result<Option<Self>> {
+        let content = match fs_err::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Use the first line that isn't blank or a comment.
+        let Some(line) = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+        else {
+            debug!("Ignoring empty uv version file: {}", path.user_display());
+            return Ok(None);
+        };
+
+        let version =
+            Version::from_str(line.strip_prefix('v').unwrap_or(line)).with_context(|| {
+                format!(
+                    "Failed to parse uv version `{line}` in `{}`",
+                    path.user_display()
+                )
+            })?;
+
+        debug!(
+            "Found uv version `{version}` pinned in: {}",
+            path.user_display()
+        );
+        Ok(Some(Self { path, version }))
+    }
+
+    /// Return the path to the version file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the pinned uv version.
+    pub(crate) fn version(&self) -> &Version {
+        &self.version
+    }
+}
+
+/// Warn if the running version of uv differs from the version pinned in a `.uv-version` file.
+pub(crate) fn warn_on_mismatch(working_directory: &Path) {
+    let version_file = match UvVersionFile::discover(working_directory) {
+        Ok(Some(version_file)) => version_file,
+        Ok(None) => return,
+        Err(err) => {
+            warn_user_once!("{err:#}");
+            return;
+        }
+    };
+
+    let Ok(current) = Version::from_str(uv_version::version()) else {
+        return;
+    };
+    if current == *version_file.version() {
+        return;
+    }
+
+    warn_user_once!(
+        "The running uv version (`{current}`) does not match the version pinned in `{}` (`{}`); run `uv self update` to switch",
+        version_file.path().user_display(),
+        version_file.version(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_file() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let path = temp_dir.path().join(UV_VERSION_FILENAME);
+
+        fs_err::write(&path, "# Pinned by the platform team\n\nv0.9.5\n")?;
+        let version_file = UvVersionFile::try_from_path(path.clone())?.unwrap();
+        assert_eq!(version_file.version(), &Version::new([0, 9, 5]));
+
+        fs_err::write(&path, "\n# Nothing pinned\n")?;
+        assert!(UvVersionFile::try_from_path(path.clone())?.is_none());
+
+        fs_err::write(&path, "latest\n")?;
+        assert!(UvVersionFile::try_from_path(path).is_err());
+
+        Ok(())
+    }
+}
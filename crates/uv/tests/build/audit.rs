@@ -271,6 +271,7 @@ async fn audit_vulnerability_found() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 }
 
@@ -619,6 +620,7 @@ async fn audit_multiple_vulnerabilities_same_package() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 2 known vulnerabilities and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 }
 
@@ -928,6 +930,7 @@ async fn audit_ignore_by_id() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 
     // With --ignore, the vulnerability is suppressed.
@@ -1140,6 +1143,7 @@ async fn audit_ignore_until_fixed_with_fix() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 }
 
@@ -1361,6 +1365,7 @@ async fn audit_ignore_partial() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 }
 
@@ -1701,6 +1706,7 @@ async fn audit_script_vulnerability_found() {
     ----- stderr -----
     Resolved 1 package in [TIME]
     Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --script script.py --upgrade-package iniconfig`
     ");
 }
 
@@ -1790,7 +1796,7 @@ async fn audit_script_frozen_missing_lockfile() {
     ----- stdout -----
 
     ----- stderr -----
-    error: Unable to find lockfile at `script.py.lock`, but `--frozen` was provided. To create a lockfile, run `uv lock` or `uv sync` without the flag.
+    error: Unable to find lockfile at `script.py.lock`, but `--frozen` was provided. To create a lockfile, run `uv lock --script script.py` or `uv sync --script script.py` without the flag.
     ");
 }
 
@@ -2252,6 +2258,7 @@ async fn audit_vulnerability_and_project_status() {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     Found 1 known vulnerability and 1 adverse project status in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
     ");
 }
 
@@ -2625,3 +2632,332 @@ async fn audit_sarif_project_artifact_uri() -> Result<()> {
 
     Ok(())
 }
+
+/// Vulnerabilities below the `--fail-on` severity are reported, but don't cause a failure.
+#[tokio::test]
+async fn audit_fail_on_severity() {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+    "#})
+        .unwrap();
+
+    context.lock().assert().success();
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/querybatch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{"vulns": [{"id": "GHSA-xxxx-yyyy-zzzz"}]}]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/vulns/GHSA-xxxx-yyyy-zzzz"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "GHSA-xxxx-yyyy-zzzz",
+            "modified": "2026-01-01T00:00:00Z",
+            "summary": "A moderate vulnerability in iniconfig",
+            "affected": [{
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [
+                        {"introduced": "0"},
+                        {"fixed": "2.1.0"}
+                    ]
+                }]
+            }],
+            "references": [{
+                "type": "ADVISORY",
+                "url": "https://example.com/advisory/GHSA-xxxx-yyyy-zzzz"
+            }],
+            "database_specific": {"severity": "MODERATE"}
+        })))
+        .mount(&server)
+        .await;
+
+    // The vulnerability is below the `high` threshold.
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--fail-on")
+        .arg("high")
+        .arg("--service-url")
+        .arg(server.uri()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    iniconfig 2.0.0 has 1 known vulnerability:
+
+    - GHSA-xxxx-yyyy-zzzz (medium): A moderate vulnerability in iniconfig
+
+      Fixed in: 2.1.0
+
+      Advisory information: https://example.com/advisory/GHSA-xxxx-yyyy-zzzz
+
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
+    ");
+
+    // By default, any vulnerability causes a failure.
+    context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--service-url")
+        .arg(server.uri())
+        .assert()
+        .failure()
+        .code(1);
+}
+
+/// Vulnerabilities in transitive dependencies are reported with the chain that introduces them.
+#[tokio::test]
+async fn audit_transitive_dependency_chain() {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+    "#})
+        .unwrap();
+
+    context.lock().assert().success();
+
+    let server = MockServer::start().await;
+
+    // The dependencies are queried in order: `anyio`, `idna`, and `sniffio`.
+    Mock::given(method("POST"))
+        .and(path("/v1/querybatch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {"vulns": []},
+                {"vulns": [{"id": "PYSEC-2024-0001"}]},
+                {"vulns": []}
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/vulns/PYSEC-2024-0001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "PYSEC-2024-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "summary": "A test vulnerability in idna",
+            "affected": [{
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [
+                        {"introduced": "0"},
+                        {"fixed": "3.7"}
+                    ]
+                }]
+            }],
+            "references": [{
+                "type": "ADVISORY",
+                "url": "https://example.com/advisory/PYSEC-2024-0001"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--service-url")
+        .arg(server.uri()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    idna 3.6 has 1 known vulnerability:
+      Introduced via: project -> anyio -> idna
+
+    - PYSEC-2024-0001: A test vulnerability in idna
+
+      Fixed in: 3.7
+
+      Advisory information: https://example.com/advisory/PYSEC-2024-0001
+
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Found 1 known vulnerability and no adverse project statuses in 3 packages
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package idna`
+    ");
+}
+
+/// Audit the packages installed in the current environment, rather than the lockfile.
+#[tokio::test]
+async fn audit_installed() {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/querybatch"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{"vulns": [{"id": "PYSEC-2023-0001"}]}]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/vulns/PYSEC-2023-0001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "PYSEC-2023-0001",
+            "modified": "2026-01-01T00:00:00Z",
+            "summary": "A test vulnerability in iniconfig",
+            "affected": [{
+                "ranges": [{
+                    "type": "ECOSYSTEM",
+                    "events": [
+                        {"introduced": "0"},
+                        {"fixed": "2.1.0"}
+                    ]
+                }]
+            }],
+            "references": [{
+                "type": "ADVISORY",
+                "url": "https://example.com/advisory/PYSEC-2023-0001"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--installed")
+        .arg("--service-url")
+        .arg(server.uri()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    iniconfig 2.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0001: A test vulnerability in iniconfig
+
+      Fixed in: 2.1.0
+
+      Advisory information: https://example.com/advisory/PYSEC-2023-0001
+
+
+    ----- stderr -----
+    Found 1 known vulnerability in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv pip install --upgrade iniconfig`
+    ");
+}
+
+/// Audit a project offline, against a local snapshot of the vulnerability database.
+#[tokio::test]
+async fn audit_offline_snapshot() {
+    let context = uv_test::test_context!("3.12");
+    write_audit_output_project(&context.temp_dir, "https://pypi.org/simple");
+
+    let snapshot = context.temp_dir.child("snapshot");
+    snapshot
+        .child("PYSEC-2023-0001.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0001",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "A test vulnerability in iniconfig",
+                "affected": [{
+                    "package": {"ecosystem": "PyPI", "name": "iniconfig"},
+                    "ranges": [{
+                        "type": "ECOSYSTEM",
+                        "events": [
+                            {"introduced": "0"},
+                            {"fixed": "2.1.0"}
+                        ]
+                    }]
+                }],
+                "references": [{
+                    "type": "ADVISORY",
+                    "url": "https://example.com/advisory/PYSEC-2023-0001"
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    // A record for a version that isn't locked.
+    snapshot
+        .child("PYSEC-2023-0002.json")
+        .write_str(
+            &json!({
+                "id": "PYSEC-2023-0002",
+                "modified": "2026-01-01T00:00:00Z",
+                "summary": "An older vulnerability in iniconfig",
+                "affected": [{
+                    "package": {"ecosystem": "PyPI", "name": "iniconfig"},
+                    "versions": ["1.1.1"]
+                }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context
+        .audit()
+        .arg("--preview-features")
+        .arg("audit")
+        .arg("--frozen")
+        .arg("--offline")
+        .arg("--service-snapshot")
+        .arg("snapshot"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    Vulnerabilities:
+
+    iniconfig 2.0.0 has 1 known vulnerability:
+
+    - PYSEC-2023-0001: A test vulnerability in iniconfig
+
+      Fixed in: 2.1.0
+
+      Advisory information: https://example.com/advisory/PYSEC-2023-0001
+
+
+    ----- stderr -----
+    Found 1 known vulnerability and no adverse project statuses in 1 package
+    hint: To upgrade the vulnerable packages to a fixed version, run: `uv lock --upgrade-package iniconfig`
+    ");
+}
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use std::process::Command;
+
+use uv_test::{TestContext, uv_snapshot};
+
+/// Invoke the dynamic completion entry point with the given command line.
+fn complete(context: &TestContext, words: &[&str]) -> Command {
+    let mut command = context.command();
+    command.arg("__complete").arg("--").arg("uv").args(words);
+    command
+}
+
+fn write_pyproject(context: &TestContext) -> Result<()> {
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3", "iniconfig ; python_version >= '3.12'"]
+
+        [project.optional-dependencies]
+        cli = ["click"]
+        async = ["trio"]
+
+        [dependency-groups]
+        lint = ["ruff"]
+        test = ["pytest", { include-group = "lint" }]
+        "#,
+    )?;
+    Ok(())
+}
+
+#[test]
+fn complete_remove() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_pyproject(&context)?;
+
+    uv_snapshot!(context.filters(), complete(&context, &["remove", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+    click
+    iniconfig
+    pytest
+    ruff
+    trio
+
+    ----- stderr -----
+    ");
+
+    // Candidates are filtered by the word being completed.
+    uv_snapshot!(context.filters(), complete(&context, &["remove", "anyio", "tr"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    trio
+
+    ----- stderr -----
+    ");
+
+    // Options are left to the static completions.
+    uv_snapshot!(context.filters(), complete(&context, &["remove", "--"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn complete_groups_and_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_pyproject(&context)?;
+
+    uv_snapshot!(context.filters(), complete(&context, &["sync", "--group", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lint
+    test
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), complete(&context, &["run", "--no-group", "l"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lint
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), complete(&context, &["sync", "--extra", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    async
+    cli
+
+    ----- stderr -----
+    ");
+
+    // The values of other options aren't completed dynamically.
+    uv_snapshot!(context.filters(), complete(&context, &["remove", "--python", ""]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn complete_python_install() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    let output = complete(&context, &["python", "install", "3.12"])
+        .output()
+        .expect("`uv __complete` should succeed");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let candidates = stdout.lines().collect::<Vec<_>>();
+    assert!(candidates.contains(&"3.12"), "{candidates:?}");
+    assert!(
+        candidates
+            .iter()
+            .all(|candidate| candidate.starts_with("3.12")),
+        "{candidates:?}"
+    );
+}
+
+#[test]
+fn complete_add_from_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let simple = context.cache_dir.child("simple-v23").child("pypi");
+    simple.child("anyio.rkyv").touch()?;
+    simple.child("annotated-types.rkyv").touch()?;
+    simple.child("idna.rkyv").touch()?;
+
+    uv_snapshot!(context.filters(), complete(&context, &["add", "--dev", "an"]), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    annotated-types
+    anyio
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn generate_shell_completion_dynamic_hooks() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    for shell in ["bash", "zsh", "fish"] {
+        let output = context
+            .command()
+            .arg("generate-shell-completion")
+            .arg(shell)
+            .output()
+            .expect("`uv generate-shell-completion` should succeed");
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("__complete --"), "{shell}: {stdout}");
+    }
+}
@@ -37,6 +37,7 @@ fn help() {
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -68,6 +69,8 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -121,6 +124,7 @@ fn help_flag() {
       publish    Upload distributions to an index
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
+      config     Inspect uv's configuration
       self       Manage the uv executable
       help       Display documentation for a command
 
@@ -151,6 +155,8 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -204,6 +210,7 @@ fn help_short_flag() {
       publish    Upload distributions to an index
       workspace  Inspect uv workspaces
       cache      Manage uv's cache
+      config     Inspect uv's configuration
       self       Manage the uv executable
       help       Display documentation for a command
 
@@ -234,6 +241,8 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -297,6 +306,8 @@ fn help_flag_workspace() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -374,6 +385,7 @@ fn help_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      shims         Inspect the Python executables installed by uv
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -418,7 +430,7 @@ fn help_subcommand() {
               Use quiet output.
 
               Repeating this option, e.g., `-qq`, will enable a silent mode in which uv will write no
-              output to stdout.
+              output to stdout and will only write errors to stderr.
 
       -v, --verbose...
               Use verbose output.
@@ -476,6 +488,13 @@ fn help_subcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --timings
+              Show performance statistics for the command.
+
+              For example, the number of package versions whose metadata was fetched speculatively
+              during resolution and how many of them were used, or the throughput of hashing
+              downloaded distributions.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -646,6 +665,18 @@ fn help_subsubcommand() {
 
               If multiple Python versions are requested, uv will exit with an error.
 
+          --output-format <OUTPUT_FORMAT>
+              Select the output format.
+
+              With `json`, uv writes progress events for each download and extraction to stdout as
+              newline-delimited JSON objects, followed by a final event summarizing the changes.
+
+              [default: text]
+
+              Possible values:
+              - text: Plain text (for humans)
+              - json: Newline-delimited JSON progress events (for computers)
+
           --compile-bytecode
               Compile Python's standard library to bytecode after installation.
 
@@ -702,7 +733,7 @@ fn help_subsubcommand() {
               Use quiet output.
 
               Repeating this option, e.g., `-qq`, will enable a silent mode in which uv will write no
-              output to stdout.
+              output to stdout and will only write errors to stderr.
 
       -v, --verbose...
               Use verbose output.
@@ -760,6 +791,13 @@ fn help_subsubcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --timings
+              Show performance statistics for the command.
+
+              For example, the number of package versions whose metadata was fetched speculatively
+              during resolution and how many of them were used, or the throughput of hashing
+              downloaded distributions.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -828,6 +866,7 @@ fn help_flag_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      shims         Inspect the Python executables installed by uv
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -857,6 +896,8 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -910,6 +951,8 @@ fn help_flag_subsubcommand() {
               Upgrade existing Python installations to the latest patch version
           --default
               Use as the default Python version
+          --output-format <OUTPUT_FORMAT>
+              Select the output format [default: text] [possible values: text, json]
           --compile-bytecode
               Compile Python's standard library to bytecode after installation [env:
               UV_COMPILE_BYTECODE=]
@@ -941,6 +984,8 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1043,6 +1088,7 @@ fn help_unknown_subsubcommand() {
         pin
         dir
         uninstall
+        shims
         update-shell
     ");
 }
@@ -1081,6 +1127,7 @@ fn help_with_global_option() {
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1112,6 +1159,8 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1206,6 +1255,7 @@ fn help_with_no_pager() {
       publish                    Upload distributions to an index
       workspace                  Inspect uv workspaces
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1237,6 +1287,8 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --timings
+              Show performance statistics for the command
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
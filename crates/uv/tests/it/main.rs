@@ -6,6 +6,10 @@ mod auth;
 
 mod branching_urls;
 
+mod complete;
+
+mod config;
+
 #[cfg(all(
     feature = "test-python",
     feature = "test-pypi",
@@ -499,6 +499,8 @@ async fn python_install_http_500() {
     ----- stdout -----
 
     ----- stderr -----
+    Failed to install Python 3.10.0 in [TIME]
+     × cpython-3.10.0-[PLATFORM] (HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/astral-sh/python-build-standalone/releases/download/20211017/cpython-3.10.0-[PLATFORM]-pgo%2Blto-20211017T1616.tar.zst))
     error: Failed to install cpython-3.10.0-[PLATFORM]
       Caused by: Request failed after 3 retries in [TIME]
       Caused by: Failed to download http://[LOCALHOST]/astral-sh/python-build-standalone/releases/download/20211017/cpython-3.10.0-[PLATFORM]-pgo%2Blto-20211017T1616.tar.zst
@@ -529,6 +531,8 @@ async fn python_install_io_error() {
     ----- stdout -----
 
     ----- stderr -----
+    Failed to install Python 3.10.0 in [TIME]
+     × cpython-3.10.0-[PLATFORM] (connection closed before message completed)
     error: Failed to install cpython-3.10.0-[PLATFORM]
       Caused by: Request failed after 3 retries in [TIME]
       Caused by: Failed to download http://[LOCALHOST]/astral-sh/python-build-standalone/releases/download/20211017/cpython-3.10.0-[PLATFORM]-pgo%2Blto-20211017T1616.tar.zst
@@ -367,3 +367,60 @@ async fn self_update_noop_dry_run_quiet() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn self_update_dry_run_uv_version_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_current_version();
+
+    let target_version = "9.9.9";
+    let (receipt_dir, server) = setup_mock_update(&context, target_version).await?;
+    context
+        .temp_dir
+        .child(".uv-version")
+        .write_str("# Vetted by the platform team\n9.9.9\n")?;
+
+    uv_snapshot!(context.filters(), context.self_update()
+        .arg("--dry-run")
+        .env("AXOUPDATER_CONFIG_PATH", receipt_dir.as_os_str())
+        .env(EnvVars::UV_INSTALLER_GHE_BASE_URL, server.uri()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using uv version `9.9.9` pinned in `.uv-version`
+    info: Checking for updates...
+    Would update uv from v[CURRENT_VERSION] to v9.9.9
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn uv_version_file_mismatch_notice() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((escape(env!("CARGO_PKG_VERSION")), "[CURRENT_VERSION]"));
+
+    context.temp_dir.child(".uv-version").write_str("0.0.1\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_list(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The running uv version (`[CURRENT_VERSION]`) does not match the version pinned in `.uv-version` (`0.0.1`); run `uv self update` to switch
+    ");
+
+    // The notice can be suppressed.
+    uv_snapshot!(context.filters(), context.pip_list()
+        .env(EnvVars::UV_NO_UV_VERSION_NOTICE, "1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -5181,6 +5181,61 @@ fn lock_upgrade_log() -> Result<()> {
     Ok(())
 }
 
+/// Show the forks of a universal resolution with `lock --show-forks`.
+#[test]
+fn lock_show_forks() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["markupsafe<2 ; sys_platform != 'win32'", "markupsafe==2.0.0 ; sys_platform == 'win32'", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--show-forks"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Resolved 2 forks:
+    sys_platform != 'win32'
+      markupsafe==1.1.1
+    sys_platform == 'win32'
+      markupsafe==2.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Remove the platform-specific requirements; the resolution should no longer fork.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["markupsafe==2.0.0", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--show-forks"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    The resolution did not fork
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Updated markupsafe v1.1.1, v2.0.0 -> v2.0.0
+    ");
+
+    Ok(())
+}
+
 /// Show updated dependencies on `lock --upgrade`, with a package that resolves to multiple
 /// versions.
 #[test]
@@ -7823,7 +7878,7 @@ fn lock_requires_python_unbounded() -> Result<()> {
     Ok(())
 }
 
-/// Error if `Requires-Python` is disjoint across the workspace.
+/// Lock each member against its own `Requires-Python` if the members are disjoint.
 #[test]
 fn lock_requires_python_disjoint() -> Result<()> {
     let context = uv_test::test_context!("3.11");
@@ -7854,14 +7909,77 @@ fn lock_requires_python_disjoint() -> Result<()> {
     )?;
 
     uv_snapshot!(context.filters(), context.lock(), @"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// The per-member `Requires-Python` bounds are recorded in the lockfile, if the members are
+/// disjoint.
+#[test]
+fn lock_requires_python_disjoint_manifest() -> Result<()> {
+    let context = uv_test::test_context!("3.11");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("child").child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = "==3.10.*"
+        dependencies = []
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let lock = context.read("uv.lock");
+    let manifest = lock
+        .split("\n\n")
+        .filter(|section| section.starts_with("[manifest"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    assert_snapshot!(manifest, @r#"
+    [manifest]
+    members = [
+        "child",
+        "project",
+    ]
+
+    [manifest.requires-python]
+    child = "==3.10.*"
+    project = ">=3.12"
+    "#);
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Found conflicting Python requirements:
-    - child: ==3.10
-    - project: >=3.12
+    Resolved 2 packages in [TIME]
     ");
 
     Ok(())
@@ -11086,6 +11204,61 @@ async fn lock_index_workspace_member() -> Result<()> {
     Ok(())
 }
 
+/// Lock with an index URL that interpolates its credentials from the environment.
+#[tokio::test]
+async fn lock_index_url_interpolation() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2"]
+
+        [[tool.uv.index]]
+        name = "my-index"
+        url = "{url}"
+        default = true
+        "#,
+        url = proxy.authenticated_url(
+            "${UV_TEST_INDEX_USERNAME:-public}",
+            "${UV_TEST_INDEX_PASSWORD}",
+            "/basic-auth/simple"
+        ),
+    })?;
+
+    // Locking without the referenced environment variable should fail.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to interpolate environment variables in: `pyproject.toml`
+      Caused by: Environment variable `UV_TEST_INDEX_PASSWORD` is not set, but is referenced by `tool.uv.index.url` (provide a default with `${UV_TEST_INDEX_PASSWORD:-<default>}`)
+    ");
+
+    uv_snapshot!(context.filters(), context.lock()
+        .env("UV_TEST_INDEX_PASSWORD", "heron"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The interpolated credentials should not be persisted to the lockfile.
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("heron"));
+
+    Ok(())
+}
+
 /// Ensure that development dependencies are omitted for non-workspace members. Below, `bar` depends
 /// on `foo`, but `bar/uv.lock` should omit `anyio`, but should include `typing-extensions`.
 #[test]
@@ -13566,6 +13739,148 @@ fn lock_find_links_relative_url() -> Result<()> {
     Ok(())
 }
 
+/// Route a single package to a local directory via a `find-links` source in `tool.uv.sources`.
+#[test]
+fn lock_find_links_source() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+
+    // Populate the wheelhouse.
+    fs_err::create_dir_all(workspace.join("wheels"))?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        workspace.join("wheels/tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    let pyproject_toml = workspace.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv.sources]
+        tqdm = { find-links = "./wheels" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "tqdm" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "tqdm", index = "wheels" }]
+
+        [[package]]
+        name = "tqdm"
+        version = "1000.0.0"
+        source = { registry = "wheels" }
+        wheels = [
+            { path = "tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    ");
+
+    // The lockfile should remain valid after relocating the workspace.
+    let relocated = context.temp_dir.child("relocated");
+    fs_err::rename(&workspace, &relocated)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&relocated), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    ");
+
+    // If the directory doesn't contain a distribution for the package, we should error.
+    fs_err::remove_file(relocated.join("wheels/tqdm-1000.0.0-py3-none-any.whl"))?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").current_dir(&relocated), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `tqdm` references a local index that does not contain any distributions for `tqdm`: `wheels`
+    ");
+
+    // If the directory doesn't exist, we should error.
+    fs_err::remove_dir(relocated.join("wheels"))?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").current_dir(&relocated), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `tqdm` references a local index that does not exist: `wheels`
+    ");
+
+    Ok(())
+}
+
 /// Lock a local source distribution via `--find-links`.
 #[test]
 fn lock_find_links_local_sdist() -> Result<()> {
@@ -13948,7 +14263,7 @@ fn lock_find_links_explicit_index() -> Result<()> {
         version = "1000.0.0"
         source = { registry = "[TEMP_DIR]/links" }
         wheels = [
-            { path = "[TEMP_DIR]/links/tqdm-1000.0.0-py3-none-any.whl" },
+            { path = "[TEMP_DIR]/links/tqdm-1000.0.0-py3-none-any.whl", hash = "sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13", size = 1017 },
         ]
         "#
         );
@@ -30820,14 +31135,15 @@ fn lock_script() -> Result<()> {
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
-    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    The lockfile at `script.py.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock --script script.py`.
     ");
 
     Ok(())
 }
 
+/// Validate and upgrade a script lockfile with `--check` and `--upgrade-package`.
 #[test]
-fn lock_script_path() -> Result<()> {
+fn lock_script_check() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
     let script = context.temp_dir.child("script.py");
@@ -30835,7 +31151,89 @@ fn lock_script_path() -> Result<()> {
         # /// script
         # requires-python = ">=3.11"
         # dependencies = [
-        #   "anyio",
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+       "#
+    })?;
+
+    // Without a lockfile, `--check` points to the script's lock and sync commands.
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--check"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `script.py.lock`, but `--check` was provided. To create a lockfile, run `uv lock --script script.py` or `uv sync --script script.py` without the flag.
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--upgrade-package").arg("iniconfig"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // Modify the script metadata.
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+       "#
+    })?;
+
+    // The lockfile is out-of-date, and the error should point to the script lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--script").arg("script.py").arg("--check"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The lockfile at `script.py.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock --script script.py`.
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_script_path() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
         #   "child",
         # ]
         #
@@ -34982,7 +35380,7 @@ fn lock_exclude_newer_hint() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2000-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0, published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2000-01-01T00:00:00Z. 6 releases of `iniconfig` satisfying the requirement were excluded by the cutoff. The latest version satisfying the requirement is v2.0.0, published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
     ");
 
     Ok(())
@@ -35032,7 +35430,7 @@ async fn lock_exclude_newer_index_disable() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2024-03-25T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2024-03-25T00:00:00Z. 1 release of `iniconfig` satisfying the requirement was excluded by the cutoff. The latest version satisfying the requirement is v2.0.0. Consider using `exclude-newer-package` to override the cutoff for this package.
     ");
 
     pyproject_toml.write_str(&format!(
@@ -35116,7 +35514,7 @@ async fn lock_exclude_newer_index_value() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. 1 release of `iniconfig` satisfying the requirement was excluded by the cutoff. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
     ");
 
     uv_snapshot!(context.filters(), context
@@ -35133,7 +35531,7 @@ async fn lock_exclude_newer_index_value() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. 1 release of `iniconfig` satisfying the requirement was excluded by the cutoff. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
     ");
 
     pyproject_toml.write_str(&format!(
@@ -35204,7 +35602,7 @@ fn lock_exclude_newer_hint_pinned_version() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because there is no version of iniconfig==2.0.0 and your project depends on iniconfig==2.0.0, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2022-01-01T00:00:00Z. The requested version, v2.0.0, was published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2022-01-01T00:00:00Z. 1 release of `iniconfig` satisfying the requirement was excluded by the cutoff. The requested version, v2.0.0, was published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
     ");
 
     Ok(())
@@ -35243,7 +35641,54 @@ fn lock_exclude_newer_hint_compatible_release() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because only iniconfig<=1.1.1 is available and your project depends on iniconfig>=2.0,<3.dev0, we can conclude that your project's requirements are unsatisfiable.
 
-    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2022-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0, published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
+    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2022-01-01T00:00:00Z. 1 release of `iniconfig` satisfying the requirement was excluded by the cutoff. The latest version satisfying the requirement is v2.0.0, published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
+    ");
+
+    Ok(())
+}
+
+/// Test that the `--exclude-newer` hint reports the number of releases excluded by the cutoff, for
+/// a requirement that is satisfiable without the cutoff.
+#[test]
+fn lock_exclude_newer_hint_excluded_releases() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1.1"]
+        "#,
+    )?;
+
+    // Use a cutoff that excludes `iniconfig 1.1.0`, `1.1.1`, and `2.0.0`.
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--exclude-newer")
+        .arg("2020-10-01T00:00:00Z"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only iniconfig<=1.0.1 is available and your project depends on iniconfig>=1.1, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2020-10-01T00:00:00Z. 3 releases of `iniconfig` satisfying the requirement were excluded by the cutoff. The latest version satisfying the requirement is v2.0.0, published at 2023-01-07T11:08:09.864Z. Consider using `exclude-newer-package` to override the cutoff for this package.
+    ");
+
+    // Without the cutoff, the requirement is satisfiable.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
     ");
 
     Ok(())
@@ -36799,3 +37244,357 @@ fn lock_frozen_warning() -> Result<()> {
 
     Ok(())
 }
+
+/// Reuse a cached resolution when the resolver inputs are unchanged.
+#[test]
+fn lock_resolution_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // By default, the resolution should be cached.
+    let entries = fs_err::read_dir(context.cache_dir.child("resolutions-v0"))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(entries.len(), 1);
+
+    // Modify the cached resolution, such that we can tell when it's reused.
+    let cached = fs_err::read_to_string(&entries[0])?;
+    assert!(cached.contains("size = 20372"));
+    fs_err::write(&entries[0], cached.replace("size = 20372", "size = 20373"))?;
+
+    // Removing the lockfile and re-locking should reuse the cached resolution.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+    assert!(context.read("uv.lock").contains("size = 20373"));
+
+    // Unless the resolution cache is disabled.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock().arg("--no-resolution-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+    assert!(context.read("uv.lock").contains("size = 20372"));
+
+    // Or the cache is refreshed.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock().arg("--refresh"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+    assert!(context.read("uv.lock").contains("size = 20372"));
+
+    Ok(())
+}
+
+/// Enforce a license policy when locking.
+#[test]
+fn lock_license_policy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig==2.0.0"]
+
+        [tool.uv]
+        license-policy = { deny = ["GPL-3.0-only"] }
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = "GPL-3.0-only"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    // `child` uses a denied license, while `iniconfig` doesn't declare a license expression.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: `iniconfig==2.0.0` does not declare a license expression (required by: project -> iniconfig)
+    error: The resolution violates the license policy:
+      - `child==0.1.0` is licensed under `GPL-3.0-only`, which is denied by the license policy (required by: project -> child)
+    ");
+
+    // Exempt `child`, and deny packages without a license expression.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig==2.0.0"]
+
+        [tool.uv]
+        license-policy = { deny = ["GPL-3.0-only"], exceptions = ["child"], unknown = "deny" }
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The resolution violates the license policy:
+      - `iniconfig==2.0.0` does not declare a license expression (required by: project -> iniconfig)
+    ");
+
+    // Exempt `iniconfig`, too.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig==2.0.0"]
+
+        [tool.uv]
+        license-policy = { deny = ["GPL-3.0-only"], exceptions = ["child", "iniconfig"], unknown = "deny" }
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    // Revoke the exemption for `child`. The policy is checked against the existing lockfile,
+    // rather than by resolving again.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "iniconfig==2.0.0"]
+
+        [tool.uv]
+        license-policy = { deny = ["GPL-3.0-only"], exceptions = ["iniconfig"], unknown = "deny" }
+
+        [tool.uv.sources]
+        child = { path = "child" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: The resolution violates the license policy:
+      - `child==0.1.0` is licensed under `GPL-3.0-only`, which is denied by the license policy (required by: project -> child)
+    ");
+
+    assert_eq!(context.read("uv.lock"), lock);
+
+    Ok(())
+}
+
+/// Packages matching a `pinned-only` index's `only-packages` patterns are only fetched from that
+/// index, and can't be pinned to any other.
+#[test]
+fn lock_pinned_only_index() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        only-packages = ["iniconfig*"]
+        pinned-only = true
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // `iniconfig` should be locked from the pinned index, and everything else from PyPI.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(indoc! {r#"
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://test.pypi.org/simple" }
+    "#}));
+    assert!(lock.contains(indoc! {r#"
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+    "#}));
+
+    // Pinning `iniconfig` to another index should fail.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "pypi" }
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        only-packages = ["iniconfig*"]
+        pinned-only = true
+
+        [[tool.uv.index]]
+        name = "pypi"
+        url = "https://pypi.org/simple"
+        explicit = true
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` was requested from `https://pypi.org/simple`, but is pinned to `https://test.pypi.org/simple` (via `pinned-only = true`)
+    ");
+
+    Ok(())
+}
+
+/// Packages that aren't included in `allow-build` can't be built from source.
+#[test]
+fn lock_allow_build() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // `django-allauth==0.51.0` is only available as a source distribution.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "django-allauth==0.51.0"]
+
+        [tool.uv]
+        allow-build = ["numpy", "internal-*"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because django-allauth==0.51.0 has no usable wheels and your project depends on django-allauth==0.51.0, we can conclude that your project's requirements are unsatisfiable.
+
+    hint: Wheels are required for `django-allauth` because building from source is disabled for `django-allauth` (i.e., it is not included in `allow-build`)
+    hint: The following distributions of `django-allauth` (v0.51.0) were rejected:
+        django-allauth-0.51.0.tar.gz: building from source is disabled
+    ");
+
+    // Packages with wheels are unaffected by the allowlist.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        allow-build = ["numpy", "internal-*"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
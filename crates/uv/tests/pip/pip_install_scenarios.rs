@@ -3549,6 +3549,8 @@ fn no_sdist_no_wheels_with_matching_abi() {
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
     hint: You require CPython 3.12 (`cp312`), but we only found wheels for `a` (v1.0.0) with the following Python ABI tag: `graalpy240_310_native`
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-py3-graalpy240_310_native-any.whl: incompatible Python ABI tag `graalpy240_310_native` (e.g., expected `cp312`)
     ");
 
     context.assert_not_installed("a");
@@ -3585,6 +3587,8 @@ fn no_sdist_no_wheels_with_matching_platform() {
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
     hint: Wheels are available for `a` (v1.0.0) on the following platform: `macosx_10_0_ppc64`
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-py3-none-macosx_10_0_ppc64.whl: incompatible platform tag `macosx_10_0_ppc64` (e.g., expected `manylinux_2_17_x86_64`)
     ");
 
     context.assert_not_installed("a");
@@ -3621,6 +3625,8 @@ fn no_sdist_no_wheels_with_matching_python() {
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
     hint: You require CPython 3.12 (`cp312`), but we only found wheels for `a` (v1.0.0) with the following Python implementation tag: `graalpy310`
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-graalpy310-none-any.whl: incompatible Python implementation tag `graalpy310` (e.g., expected `cp312`)
     ");
 
     context.assert_not_installed("a");
@@ -3658,6 +3664,8 @@ fn no_wheels_no_build() {
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
     hint: Wheels are required for `a` because building from source is disabled for `a` (i.e., with `--no-build-package a`)
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0.tar.gz: building from source is disabled
     ");
 
     context.assert_not_installed("a");
@@ -3759,6 +3767,8 @@ fn only_wheels_no_binary() {
           And because you require a, we can conclude that your requirements are unsatisfiable.
 
     hint: A source distribution is required for `a` because using pre-built wheels is disabled for `a` (i.e., with `--no-binary-package a`)
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-py3-none-any.whl: using wheels is disabled
     ");
 
     context.assert_not_installed("a");
@@ -3860,6 +3870,10 @@ fn package_only_yanked_in_range() {
               a==1.0.0
           and a==1.0.0 was yanked, we can conclude that a>0.1.0 cannot be used.
           And because you require a>0.1.0, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-py3-none-any.whl: was yanked
+        a-1.0.0.tar.gz: was yanked
     ");
 
     // Since there are other versions of `a` available, yanked versions should not be selected without explicit opt-in.
@@ -3894,6 +3908,10 @@ fn package_only_yanked() {
       × No solution found when resolving dependencies:
       ╰─▶ Because only a==1.0.0 is available and a==1.0.0 was yanked, we can conclude that all versions of a cannot be used.
           And because you require a, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `a` (v1.0.0) were rejected:
+        a-1.0.0-py3-none-any.whl: was yanked
+        a-1.0.0.tar.gz: was yanked
     ");
 
     // Yanked versions should not be installed, even if they are the only one available.
@@ -4058,6 +4076,10 @@ fn transitive_package_only_yanked_in_range() {
           and b==1.0.0 was yanked, we can conclude that b>0.1 cannot be used.
           And because a==0.1.0 depends on b>0.1, we can conclude that a==0.1.0 cannot be used.
           And because only a==0.1.0 is available and you require a, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `b` (v1.0.0) were rejected:
+        b-1.0.0-py3-none-any.whl: was yanked
+        b-1.0.0.tar.gz: was yanked
     ");
 
     // Yanked versions should not be installed, even if they are the only valid version in a range.
@@ -4097,6 +4119,10 @@ fn transitive_package_only_yanked() {
       ╰─▶ Because only b==1.0.0 is available and b==1.0.0 was yanked, we can conclude that all versions of b cannot be used.
           And because a==0.1.0 depends on b, we can conclude that a==0.1.0 cannot be used.
           And because only a==0.1.0 is available and you require a, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `b` (v1.0.0) were rejected:
+        b-1.0.0-py3-none-any.whl: was yanked
+        b-1.0.0.tar.gz: was yanked
     ");
 
     // Yanked versions should not be installed, even if they are the only one available.
@@ -4198,6 +4224,10 @@ fn transitive_yanked_and_unyanked_dependency() {
       × No solution found when resolving dependencies:
       ╰─▶ Because c==2.0.0 was yanked and a==1.0.0 depends on c==2.0.0, we can conclude that a==1.0.0 cannot be used.
           And because only a==1.0.0 is available and you require a, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `c` (v2.0.0) were rejected:
+        c-2.0.0-py3-none-any.whl: was yanked
+        c-2.0.0.tar.gz: was yanked
     ");
 
     // Since the user did not explicitly select the yanked version, it cannot be used.
@@ -3504,6 +3504,114 @@ fn recompile() -> Result<()> {
     Ok(())
 }
 
+/// Install with bytecode compilation limited to a specific package.
+#[test]
+fn compile_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\niniconfig==2.0.0")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile-package")
+        .arg("markupsafe")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+    Bytecode compiled 2 files in [TIME]
+     + iniconfig==2.0.0
+     + markupsafe==2.1.3
+    "
+    );
+
+    // Only `markupsafe` should be compiled.
+    assert!(
+        context
+            .site_packages()
+            .join("markupsafe")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+    assert!(
+        !context
+            .site_packages()
+            .join("iniconfig")
+            .join("__pycache__")
+            .exists()
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Install with bytecode compilation, where one of the files fails to compile.
+#[test]
+fn compile_syntax_error() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filter((
+        r"\.venv[\\/].*site-packages[\\/]example[\\/]",
+        "[SITE_PACKAGES]/example/",
+    ));
+
+    let example = context.temp_dir.child("example");
+    example.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    let package = example.child("src").child("example");
+    package
+        .child("__init__.py")
+        .write_str("from example.valid import VALUE\n")?;
+    package.child("valid.py").write_str("VALUE = 1\n")?;
+    package.child("broken.py").write_str("VALUE = (\n")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./example")?;
+
+    // The broken file is reported, but doesn't stop the other files from being compiled.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 4 files in [TIME]
+    warning: Failed to bytecode-compile 1 file:
+      [SITE_PACKAGES]/example/broken.py: SyntaxError: '(' was never closed
+     + example==0.1.0 (from file://[TEMP_DIR]/example)
+    "
+    );
+
+    let pycache = context.site_packages().join("example").join("__pycache__");
+    assert!(pycache.join("valid.cpython-312.pyc").exists());
+    assert!(!pycache.join("broken.cpython-312.pyc").exists());
+
+    context.assert_command("import example").success();
+
+    Ok(())
+}
+
 /// Raise an error when an editable's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_editable() -> Result<()> {
@@ -513,6 +513,57 @@ fn dry_run_uninstall_egg_info() -> Result<()> {
     Ok(())
 }
 
+/// Report the packages that would be left with missing dependencies, without uninstalling.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn simulate_deps() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0\nidna==3.6\nsniffio==1.3.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--simulate-deps")
+        .arg("idna"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 1 package
+     - idna==3.6
+    Would leave 1 missing dependency
+    The package `anyio` requires `idna>=2.8`, but it's not installed
+    "
+    );
+
+    // Nothing should have been uninstalled.
+    context.assert_command("import idna").success();
+
+    // Uninstalling a package that nothing depends on leaves the environment consistent.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("--simulate-deps")
+        .arg("anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 1 package
+     - anyio==4.3.0
+    No installed packages would have missing dependencies
+    "
+    );
+
+    Ok(())
+}
+
 /// Uninstall must not remove files outside the install scheme.
 ///
 /// A malformed or malicious wheel can include path-traversal entries
@@ -692,6 +743,66 @@ fn uninstall_egg_info_top_level_drive_relative() -> Result<()> {
     Ok(())
 }
 
+/// With `--requested-only`, packages without a `REQUESTED` marker are left in place.
+#[test]
+fn uninstall_requested_only() {
+    let context = uv_test::test_context!("3.12");
+
+    context.pip_install().arg("anyio==4.3.0").assert().success();
+
+    // Only the direct requirement is marked as requested, not its dependencies.
+    let site_packages = ChildPath::new(context.site_packages());
+    site_packages
+        .child("anyio-4.3.0.dist-info")
+        .child("REQUESTED")
+        .assert(predicates::path::exists());
+    site_packages
+        .child("idna-3.6.dist-info")
+        .child("REQUESTED")
+        .assert(predicates::path::missing());
+    site_packages
+        .child("sniffio-1.3.1.dist-info")
+        .child("REQUESTED")
+        .assert(predicates::path::missing());
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--requested-only")
+        .arg("anyio")
+        .arg("idna")
+        .arg("sniffio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - anyio==4.3.0
+    ");
+
+    site_packages
+        .child("anyio-4.3.0.dist-info")
+        .assert(predicates::path::missing());
+    site_packages
+        .child("idna-3.6.dist-info")
+        .assert(predicates::path::is_dir());
+    site_packages
+        .child("sniffio-1.3.1.dist-info")
+        .assert(predicates::path::is_dir());
+
+    // If none of the packages were requested, there's nothing to uninstall.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--requested-only")
+        .arg("idna")
+        .arg("sniffio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: No packages to uninstall
+    ");
+}
+
 /// `--yes` is accepted for `pip uninstall` compatibility, but emits a warning.
 #[test]
 fn yes_flag() {
@@ -14,6 +14,7 @@ use futures::executor::block_on;
 use futures::io::AllowStdIo;
 use http::StatusCode;
 use indoc::indoc;
+use insta::assert_snapshot;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 use url::Url;
 use wiremock::matchers::{method, path};
@@ -78,6 +79,171 @@ fn compile_requirements_in() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a specific version of `anyio` with metadata prefetching disabled.
+#[test]
+fn compile_requirements_in_no_prefetch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("--no-prefetch")
+        .arg("requirements.in"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --no-prefetch requirements.in
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Report the metadata prefetches issued during resolution with `--timings`.
+#[test]
+fn compile_requirements_in_timings() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    // Whether a prefetch or the resolver itself registers a distribution first is racy.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"Prefetched metadata for \d+ package versions? \(\d+ used\)",
+            "Prefetched metadata for [N] package versions ([N] used)",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context
+        .pip_compile()
+        .arg("--timings")
+        .arg("requirements.in"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Prefetched metadata for [N] package versions ([N] used)
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Backtrack through the versions of a package, which would otherwise trigger batch prefetching,
+/// with metadata prefetching disabled.
+#[test]
+fn compile_backtracking_no_prefetch() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Every version of `package` but the oldest requires a version of `dependency` that doesn't
+    // exist, so the resolver has to try each of them in turn.
+    let mut package_versions = BTreeMap::new();
+    for patch in 0..30 {
+        let version = Version::from_str(&format!("1.0.{patch}"))?;
+        let requires = if patch == 0 {
+            vec![]
+        } else {
+            vec![Requirement::from_str(&format!("dependency=={version}"))?]
+        };
+        package_versions.insert(
+            version,
+            PackageMetadata {
+                requires,
+                sdist: false,
+                wheel: true,
+                ..PackageMetadata::default()
+            },
+        );
+    }
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("package")?,
+        Package {
+            versions: package_versions,
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("dependency")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    sdist: false,
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("package")?;
+
+    let prefetch_stats = |output: &std::process::Output| {
+        String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .find(|line| line.starts_with("Prefetched metadata for"))
+            .map(ToString::to_string)
+    };
+
+    // By default, the resolver prefetches the versions it expects to try next.
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--timings")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .output()?;
+    assert!(output.status.success());
+    let stats = prefetch_stats(&output).expect("Expected the prefetch statistics to be reported");
+    assert!(
+        !stats.starts_with("Prefetched metadata for 0 package versions"),
+        "Expected metadata to be prefetched, found: {stats}"
+    );
+
+    // With `--no-prefetch`, only the versions the resolver tries are fetched.
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--timings")
+        .arg("--no-prefetch")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .output()?;
+    assert!(output.status.success());
+    assert_snapshot!(
+        prefetch_stats(&output).unwrap_or_default(),
+        @"Prefetched metadata for 0 package versions (0 used)"
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `requirements.in` file with a `--annotation-style=line` flag.
 #[test]
 fn compile_requirements_in_annotation_line() -> Result<()> {
@@ -105,6 +271,58 @@ fn compile_requirements_in_annotation_line() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a specific version of `anyio` from a `requirements.in` file with `annotation-style = "none"`.
+#[test]
+fn compile_requirements_in_annotation_none() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("--annotation-style")
+        .arg("none")
+        .arg("requirements.in"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --annotation-style none requirements.in
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // The style, header, and custom compile command can also be set in the configuration.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        annotation-style = "none"
+        custom-compile-command = "./compile.sh"
+    "#})?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    ./compile.sh
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `requirements.in` file on stdin
 /// when passed a path of `-`.
 #[test]
@@ -2328,6 +2546,76 @@ fn compile_sdist_url_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Override the `platform_release` marker for a `--python-platform` target, which otherwise leaves
+/// it empty.
+#[test]
+fn python_platform_release() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig ; platform_release == '6.8.0'")?;
+
+    // Without an override, the dependency is omitted.
+    uv_snapshot!(context.filters(),
+        windows_filters=false,
+        context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-unknown-linux-gnu
+
+    ----- stderr -----
+    Resolved in [TIME]
+    "
+    );
+
+    uv_snapshot!(context.filters(),
+        windows_filters=false,
+        context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--platform-release")
+        .arg("6.8.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-unknown-linux-gnu --platform-release 6.8.0
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // The override requires a target platform.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--platform-release")
+        .arg("6.8.0"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --python-platform <PYTHON_PLATFORM>
+
+    Usage: uv pip compile --cache-dir [CACHE_DIR] --python-platform <PYTHON_PLATFORM> <SRC_FILE|--group <GROUP>>
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "test-git")]
@@ -3393,6 +3681,168 @@ optional-dependencies.bar = [
     Ok(())
 }
 
+/// Write a separate file for the base dependencies and each extra, from a single resolution.
+#[test]
+fn compile_pyproject_toml_split_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["uv_build>=0.7,<10000"]
+build-backend = "uv_build"
+
+[project]
+name = "project"
+version = "0.1.0"
+dependencies = ["anyio==3.7.0"]
+optional-dependencies.foo = [
+    "iniconfig==1.1.1",
+]
+optional-dependencies.bar = [
+    "httpcore==0.18.0",
+]
+optional-dependencies.all = [
+    "project[foo,bar]",
+]
+"#,
+    )?;
+
+    // The paths to the output files are written to stdout.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--all-extras")
+            .arg("--split-extras")
+            .arg("--output-dir")
+            .arg("reqs"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    reqs/requirements-base.txt
+    reqs/requirements-all.txt
+    reqs/requirements-bar.txt
+    reqs/requirements-foo.txt
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        // The base dependencies exclude any packages that are only required by an extra.
+        assert_snapshot!(context.read("reqs/requirements-base.txt"), @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --all-extras --split-extras --output-dir reqs
+        anyio==3.7.0
+            # via project (pyproject.toml)
+        idna==3.6
+            # via anyio
+        sniffio==1.3.1
+            # via anyio
+        ");
+
+        // Packages shared with the base dependencies are pinned to the same version.
+        assert_snapshot!(context.read("reqs/requirements-bar.txt"), @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --all-extras --split-extras --output-dir reqs
+        anyio==3.7.0
+            # via
+            #   project (pyproject.toml)
+            #   httpcore
+        certifi==2024.2.2
+            # via httpcore
+        h11==0.14.0
+            # via httpcore
+        httpcore==0.18.0
+            # via project (pyproject.toml)
+        idna==3.6
+            # via anyio
+        sniffio==1.3.1
+            # via
+            #   anyio
+            #   httpcore
+        ");
+
+        assert_snapshot!(context.read("reqs/requirements-foo.txt"), @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --all-extras --split-extras --output-dir reqs
+        anyio==3.7.0
+            # via project (pyproject.toml)
+        idna==3.6
+            # via anyio
+        iniconfig==1.1.1
+            # via project (pyproject.toml)
+        sniffio==1.3.1
+            # via anyio
+        ");
+
+        // Extras that the project requests from itself are expanded.
+        assert_snapshot!(context.read("reqs/requirements-all.txt"), @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --all-extras --split-extras --output-dir reqs
+        anyio==3.7.0
+            # via
+            #   project (pyproject.toml)
+            #   httpcore
+        certifi==2024.2.2
+            # via httpcore
+        h11==0.14.0
+            # via httpcore
+        httpcore==0.18.0
+            # via project (pyproject.toml)
+        idna==3.6
+            # via anyio
+        iniconfig==1.1.1
+            # via project (pyproject.toml)
+        sniffio==1.3.1
+            # via
+            #   anyio
+            #   httpcore
+        ");
+    });
+
+    // The file names can be customized with a template.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--extra")
+            .arg("foo")
+            .arg("--split-extras")
+            .arg("--output-dir")
+            .arg("reqs")
+            .arg("--output-template")
+            .arg("{extra}.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    reqs/base.txt
+    reqs/foo.txt
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("pyproject.toml")
+            .arg("--all-extras")
+            .arg("--split-extras")
+            .arg("--output-dir")
+            .arg("reqs")
+            .arg("--output-template")
+            .arg("requirements.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The output template must contain an `{extra}` placeholder, but got: `requirements.txt`
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn compile_pyproject_toml_all_extras_annotation_line() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -4166,6 +4616,10 @@ fn compile_yanked_version_indirect() -> Result<()> {
               attrs>=21.2.0
           and attrs==21.1.0 was yanked (reason: Installable but not importable on Python 3.4), we can conclude that attrs>20.3.0,<21.2.0 cannot be used.
           And because you require attrs>20.3.0,<21.2.0, we can conclude that your requirements are unsatisfiable.
+
+    hint: The following distributions of `attrs` (v21.1.0) were rejected:
+        attrs-21.1.0-py2.py3-none-any.whl: was yanked (reason: Installable but not importable on Python 3.4)
+        attrs-21.1.0.tar.gz: was yanked (reason: Installable but not importable on Python 3.4)
     "
     );
 
@@ -8322,6 +8776,60 @@ fn no_strip_markers_transitive_marker() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package with `--no-strip-markers`. In this case, a direct dependency is gated on the
+/// target platform, and its marker is preserved in the output.
+#[test]
+fn no_strip_markers_platform_marker() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig
+        colorama ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-strip-markers")
+            .arg("--python-platform")
+            .arg("windows"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-strip-markers --python-platform windows
+    colorama==0.4.6 ; sys_platform == 'win32'
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    // Without `--no-strip-markers`, the marker is omitted.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-platform")
+            .arg("windows"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform windows
+    colorama==0.4.6
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Perform a universal resolution with a package that has a marker.
 #[test]
 fn universal() -> Result<()> {
@@ -13884,6 +14392,78 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile against the platform set via `UV_PYTHON_PLATFORM`, unless overridden on the command line.
+#[test]
+fn python_platform_env() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black")?;
+
+    uv_snapshot!(context.filters(),
+        windows_filters=false,
+        context.pip_compile()
+        .arg("requirements.in")
+        .env(EnvVars::UV_PYTHON_PLATFORM, "x86_64-pc-windows-msvc"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    black==24.3.0
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    colorama==0.4.6
+        # via click
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    // The command-line argument takes precedence over the environment variable.
+    uv_snapshot!(context.filters(),
+        windows_filters=false,
+        context.pip_compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("aarch64-unknown-linux-gnu")
+        .env(EnvVars::UV_PYTHON_PLATFORM, "x86_64-pc-windows-msvc"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform aarch64-unknown-linux-gnu
+    black==24.3.0
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "test-git")]
@@ -14445,6 +15025,8 @@ fn no_binary_only_binary() -> Result<()> {
           And because you require source-distribution<=0.0.1, we can conclude that your requirements are unsatisfiable.
 
     hint: Wheels are required for `source-distribution` because building from source is disabled for all packages (i.e., with `--no-build`)
+    hint: The following distributions of `source-distribution` (v0.0.1) were rejected:
+        source_distribution-0.0.1.tar.gz: building from source is disabled
     "
     );
 
@@ -4259,6 +4259,10 @@ fn add_inexact() -> Result<()> {
 
     ----- stderr -----
     Checked 1 package in [TIME]
+    Retained 3 extraneous packages not in the lockfile
+     ? anyio==3.7.0
+     ? idna==3.6
+     ? sniffio==1.3.1
     ");
 
     // Install from the lockfile, performing an exact sync.
@@ -7183,6 +7187,110 @@ fn add_script_relative_path() -> Result<()> {
         "#
         );
     });
+
+    // Removing the dependency should remove its source.
+    uv_snapshot!(context.filters(), context.remove().arg("project").arg("--script").arg("script.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated `script.py`
+    ");
+
+    let script_content = context.read("script.py");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = []
+        # ///
+        print("Hello, world!")
+        "#
+        );
+    });
+    Ok(())
+}
+
+/// Record constraints in the inline metadata when adding to a script.
+#[test]
+fn add_script_constraints() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        print("Hello, world!")
+    "#})?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.5")?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--constraints").arg("constraints.txt").arg("--script").arg("script.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let script_content = context.read("script.py");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = [
+        #     "anyio>=4.3.0",
+        # ]
+        #
+        # [tool.uv]
+        # constraint-dependencies = [
+        #     "idna<3.5",
+        # ]
+        # ///
+        print("Hello, world!")
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Report the location of invalid inline metadata relative to the script.
+#[test]
+fn add_script_invalid_metadata() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        #!/usr/bin/env python3
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = [
+        #     "iniconfig"
+        #     "anyio",
+        # ]
+        # ///
+        print("Hello, world!")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--script").arg("script.py"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid PEP 723 metadata at line 6, column 7: missing comma between array elements, expected `,`
+    ");
+
     Ok(())
 }
 
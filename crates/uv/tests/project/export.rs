@@ -9832,3 +9832,136 @@ fn requirements_txt_emit_indexes() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn environment_yml() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig ; sys_platform == 'win32'"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // The editable project itself can't be represented, and is omitted with a warning.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("environment.yml").arg("--no-hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --format environment.yml --no-hashes
+    name: project
+    dependencies:
+      - python=3.12.[X]
+      - pip
+      - pip:
+          - --index-url https://pypi.org/simple
+          - anyio==3.7.0
+          - idna==3.6
+          - iniconfig==2.0.0 ; sys_platform == 'win32'
+          - sniffio==1.3.1
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    warning: Omitting packages that can't be represented in `environment.yml` (editable and local path dependencies are not supported): `project`
+    ");
+
+    // Under `--strict`, unrepresentable packages are an error.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("environment.yml").arg("--strict"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: The following packages can't be represented in `environment.yml` (editable and local path dependencies are not supported): `project`
+    ");
+
+    // `--strict` is rejected for other formats.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("requirements.txt").arg("--strict"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    error: `--strict` is only supported when exporting to `environment.yml`
+    ");
+
+    // Hashes are included on the same line as each requirement.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("environment.yml").arg("--strict").arg("--no-emit-project").arg("--no-header").arg("--prune").arg("idna").arg("--prune").arg("sniffio").arg("--prune").arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    name: project
+    dependencies:
+      - python=3.12.[X]
+      - pip
+      - pip:
+          - --index-url https://pypi.org/simple
+          - anyio==3.7.0 --hash=sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce --hash=sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// The `environment.yml` format is inferred from the output file, and uses the lockfile's Python
+/// requirement when no interpreter is discovered.
+#[test]
+fn environment_yml_frozen() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export()
+        .arg("--frozen")
+        .arg("--no-emit-project")
+        .arg("--no-hashes")
+        .arg("--no-header")
+        .arg("--output-file")
+        .arg("environment.yml"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    name: project
+    dependencies:
+      - python>=3.12
+      - pip
+      - pip:
+          - --index-url https://pypi.org/simple
+          - iniconfig==2.0.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
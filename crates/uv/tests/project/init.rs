@@ -181,6 +181,60 @@ fn init_application() -> Result<()> {
     Ok(())
 }
 
+/// Infer `requires-python` from the syntax and metadata of an existing codebase.
+#[test]
+fn init_infer_requires_python() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.temp_dir.child("foo");
+    child.create_dir_all()?;
+
+    child.child("main.py").write_str(indoc! {r#"
+        import sys
+
+        match sys.argv:
+            case [_, "--help"]:
+                print("Usage: foo")
+            case _:
+                print("Hello from foo!")
+    "#})?;
+    child.child("setup.cfg").write_str(indoc! {"
+        [options]
+        python_requires = >=3.8
+    "})?;
+
+    uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--app").arg("--infer-requires-python"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Inferred `requires-python = ">=3.10"` from:
+      - `match` statement in `main.py:3` (Python 3.10+)
+      - `python_requires = ">=3.8"` in `setup.cfg`
+    Initialized project `foo`
+    "#);
+
+    let pyproject = fs_err::read_to_string(child.join("pyproject.toml"))?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.10"
+        dependencies = []
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// When `main.py` already exists, we don't create it again
 #[test]
 fn init_application_hello_exists() -> Result<()> {
@@ -717,6 +771,7 @@ fn init_script() -> Result<()> {
     }, {
         assert_snapshot!(
             script, @r#"
+        #!/usr/bin/env -S uv run --script
         # /// script
         # requires-python = ">=3.12"
         # dependencies = []
@@ -806,6 +861,7 @@ fn init_script_python_version() -> Result<()> {
     }, {
         assert_snapshot!(
             script, @r#"
+        #!/usr/bin/env -S uv run --script
         # /// script
         # requires-python = ">=3.11"
         # dependencies = []
@@ -850,6 +906,7 @@ fn init_script_create_directory() -> Result<()> {
     }, {
         assert_snapshot!(
             script, @r#"
+        #!/usr/bin/env -S uv run --script
         # /// script
         # requires-python = ">=3.12"
         # dependencies = []
@@ -869,7 +926,7 @@ fn init_script_create_directory() -> Result<()> {
     Ok(())
 }
 
-// Init script should fail if file is already a PEP 723 script
+// Init script should fail if the file already exists, unless converting it
 #[test]
 fn init_script_file_conflicts() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -899,6 +956,15 @@ fn init_script_file_conflicts() -> Result<()> {
     fs_err::write(child.join("existing_script.py"), contents)?;
 
     uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--script").arg("existing_script.py"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `existing_script.py` already exists; use `--convert` to add inline metadata to it, or `--force` to overwrite it
+    ");
+
+    uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--script").arg("--convert").arg("existing_script.py"), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -932,7 +998,7 @@ fn init_script_shebang() -> Result<()> {
 
     let contents = "#! /usr/bin/env python3\nprint(\"Hello, world!\")";
     fs_err::write(&script_path, contents)?;
-    uv_snapshot!(context.filters(), context.init().arg("--script").arg("script.py"), @"
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("--convert").arg("script.py"), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -958,7 +1024,7 @@ fn init_script_shebang() -> Result<()> {
     // If the shebang already contains `uv`, the result is the same, but we suppress the warning.
     let contents = "#!/usr/bin/env -S uv run --script\nprint(\"Hello, world!\")";
     fs_err::write(&script_path, contents)?;
-    uv_snapshot!(context.filters(), context.init().arg("--script").arg("script.py"), @"
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("--convert").arg("script.py"), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -982,6 +1048,127 @@ fn init_script_shebang() -> Result<()> {
     Ok(())
 }
 
+// Init script should overwrite an existing file with `--force`.
+#[test]
+fn init_script_force() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script_path = context.temp_dir.child("script.py");
+    script_path.write_str("print(\"Hello, world!\")")?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("--bare").arg("--force").arg("script.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `script.py`
+    ");
+
+    let resulting_script = fs_err::read_to_string(&script_path)?;
+    assert_snapshot!(resulting_script, @r#"
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = []
+    # ///
+    "#
+    );
+
+    // `--convert` requires an existing file.
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("--convert").arg("missing.py"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `missing.py` does not exist; omit `--convert` to create a new script
+    ");
+
+    Ok(())
+}
+
+// Init script should add the requested dependencies, along with those required by the template.
+#[test]
+fn init_script_template() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script_path = context.temp_dir.child("app.py");
+
+    uv_snapshot!(context.filters(), context.init()
+        .arg("--script")
+        .arg("--template")
+        .arg("fastapi")
+        .arg("--dependencies")
+        .arg("httpx>=0.27,<1,uvicorn[standard]")
+        .arg("app.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `app.py`
+    ");
+
+    let resulting_script = fs_err::read_to_string(&script_path)?;
+    assert_snapshot!(resulting_script, @r#"
+    #!/usr/bin/env -S uv run --script
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #   "httpx>=0.27, <1",
+    #   "uvicorn[standard]",
+    #   "fastapi",
+    # ]
+    # ///
+
+    from fastapi import FastAPI
+
+    app = FastAPI()
+
+
+    @app.get("/")
+    def read_root() -> dict[str, str]:
+        return {"message": "Hello from app.py!"}
+
+
+    if __name__ == "__main__":
+        import uvicorn
+
+        uvicorn.run(app)
+    "#
+    );
+
+    // New scripts are executable.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs_err::metadata(&script_path)?.permissions().mode();
+        assert_eq!(mode & 0o100, 0o100, "Expected `app.py` to be executable");
+    }
+
+    uv_snapshot!(context.filters(), context.init()
+        .arg("--script")
+        .arg("--dependencies")
+        .arg("pytest@")
+        .arg("invalid.py"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse dependency: `pytest@`
+      Caused by: Expected URL
+    pytest@
+           ^
+    ");
+
+    // The file should not have been created.
+    assert!(!context.temp_dir.child("invalid.py").exists());
+
+    Ok(())
+}
+
 // Make sure that `uv init --script` picks the latest non-pre-release version of Python
 // for the `requires-python` constraint.
 #[cfg(feature = "test-python-patch")]
@@ -1007,6 +1194,7 @@ fn init_script_picks_latest_stable_version() -> Result<()> {
     let resulting_script = fs_err::read_to_string(&script_path)?;
     assert_snapshot!(
         resulting_script, @r#"
+        #!/usr/bin/env -S uv run --script
         # /// script
         # requires-python = ">=3.13"
         # dependencies = []
@@ -1467,6 +1655,8 @@ fn init_workspace() -> Result<()> {
     ----- stderr -----
     Adding `bar` as member of workspace `[TEMP_DIR]/`
     Initialized project `bar`
+    Resolved 6 packages in [TIME]
+    Added bar v0.1.0
     ");
 
     let workspace = context.read("pyproject.toml");
@@ -1520,6 +1710,8 @@ fn init_workspace() -> Result<()> {
     ----- stderr -----
     Adding `baz` as member of workspace `[TEMP_DIR]/`
     Initialized project `baz`
+    Resolved 7 packages in [TIME]
+    Added baz v0.1.0
     ");
 
     let workspace = context.read("pyproject.toml");
@@ -1740,6 +1932,121 @@ fn init_workspace_outside() -> Result<()> {
     Ok(())
 }
 
+/// Add a workspace member with `--depends-on`, wiring up `{ workspace = true }` sources and
+/// updating the existing lockfile.
+#[test]
+fn init_workspace_depends_on() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--lib").arg("packages/bar"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Project `bar` is already a member of workspace `[TEMP_DIR]/`
+    Initialized project `bar` at `[TEMP_DIR]/packages/bar`
+    ");
+
+    // Run `uv lock` in the workspace.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Depending on a package that isn't a workspace member is an error.
+    uv_snapshot!(context.filters(), context.init().arg("--lib").arg("packages/foo").arg("--depends-on").arg("baz"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `baz` is not a member of workspace `[TEMP_DIR]/`
+    ");
+
+    uv_snapshot!(context.filters(), context.init().arg("--lib").arg("packages/foo").arg("--depends-on").arg("bar"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Project `foo` is already a member of workspace `[TEMP_DIR]/`
+    Adding workspace member `bar` as a dependency of `foo`
+    Initialized project `foo` at `[TEMP_DIR]/packages/foo`
+    Resolved 3 packages in [TIME]
+    Added foo v0.1.0
+    ");
+
+    let pyproject = context.read("packages/foo/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = [
+            "bar",
+        ]
+
+        [build-system]
+        requires = ["uv_build>=[CURRENT_VERSION],<[NEXT_BREAKING]"]
+        build-backend = "uv_build"
+
+        [tool.uv.sources]
+        bar = { workspace = true }
+        "#
+        );
+    });
+
+    // With `--no-lock`, the lockfile is left untouched.
+    uv_snapshot!(context.filters(), context.init().arg("--lib").arg("packages/baz").arg("--depends-on").arg("foo,bar").arg("--no-lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Project `baz` is already a member of workspace `[TEMP_DIR]/`
+    Adding workspace member `foo` as a dependency of `baz`
+    Adding workspace member `bar` as a dependency of `baz`
+    Initialized project `baz` at `[TEMP_DIR]/packages/baz`
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn init_normalized_names() -> Result<()> {
     let context = uv_test::test_context!("3.12");
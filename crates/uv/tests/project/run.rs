@@ -1147,7 +1147,7 @@ fn run_pep723_script_lock() -> Result<()> {
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
-    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    error: The lockfile at `main.py.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock --script main.py`.
     ");
 
     // Re-running the script with `--frozen` should also error, but at runtime.
@@ -3420,6 +3420,131 @@ fn run_from_directory() -> Result<()> {
     Ok(())
 }
 
+/// Run from a subdirectory of a project with `--project`, `--directory`, and both.
+///
+/// `--directory` changes the working directory before anything else, such that relative paths
+/// (e.g., `--env-file` and `--with-requirements`) are resolved from the given directory, while
+/// `--project` only changes the directory used for project discovery (e.g., `.python-version`).
+#[test]
+fn run_nested_directory_and_project() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"]);
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#
+    })?;
+    project.child(PYTHON_VERSION_FILENAME).write_str("3.11")?;
+    project.child(".env").write_str("SOURCE=root")?;
+    project.child("requirements.txt").write_str("sniffio")?;
+
+    // The nested directory has its own `.python-version`, which should be ignored in favor of the
+    // project's in all cases.
+    let nested = project.child("child").child("src");
+    nested.child(PYTHON_VERSION_FILENAME).write_str("3.12")?;
+    nested.child(".env").write_str("SOURCE=child")?;
+    nested.child("requirements.txt").write_str("iniconfig")?;
+    nested.child("extra.txt").write_str("idna")?;
+
+    context
+        .sync()
+        .current_dir(&project)
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .assert()
+        .success();
+
+    let script = "import os, platform; print(platform.python_version(), os.environ['SOURCE'])";
+
+    // With `--project`, relative paths are resolved from the current directory.
+    uv_snapshot!(context.filters(), context.run()
+        .current_dir(&nested)
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--project")
+        .arg("../..")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("--with-requirements")
+        .arg("requirements.txt")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11.[X] child
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // With `--directory`, relative paths are resolved from the given directory.
+    uv_snapshot!(context.filters(), context.run()
+        .current_dir(&nested)
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--directory")
+        .arg("../..")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("--with-requirements")
+        .arg("requirements.txt")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11.[X] root
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    ");
+
+    // When combined, `--directory` is applied first, such that the relative `--project` path and
+    // all other relative paths are resolved from the given directory.
+    uv_snapshot!(context.filters(), context.run()
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--directory")
+        .arg("project/child/src")
+        .arg("--project")
+        .arg("../..")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("--with-requirements")
+        .arg("extra.txt")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11.[X] child
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    ");
+
+    Ok(())
+}
+
 /// By default, omit resolver and installer output.
 #[test]
 fn run_without_output() -> Result<()> {
@@ -3809,6 +3934,45 @@ fn run_module_stdin() {
     ");
 }
 
+/// Run a code snippet equivalent to `python -c "..."`.
+#[test]
+fn run_code() {
+    let context = uv_test::test_context!("3.12");
+
+    // As with `python -c`, `sys.argv[0]` should be `-c`.
+    uv_snapshot!(context.filters(), context.run().arg("-c").arg("import sys; print(sys.argv)").arg("foo").arg("--bar"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['-c', 'foo', '--bar']
+
+    ----- stderr -----
+    ");
+
+    // The exit code should be propagated.
+    uv_snapshot!(context.filters(), context.run().arg("-c").arg("import sys; sys.exit(3)"), @"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // Overlays should be available to the snippet.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("iniconfig").arg("-c").arg("import iniconfig; print(iniconfig.__name__)"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+}
+
 /// Test for how run reacts to a pyproject.toml without a `[project]`
 #[test]
 fn virtual_empty() -> Result<()> {
@@ -4902,7 +5066,7 @@ fn run_remote_pep723_script() {
         r"(?m)^Downloaded remote script to:.*\.py$",
         "Downloaded remote script to: [TEMP_PATH].py",
     ));
-    uv_snapshot!(context.filters(), context.run().arg("https://raw.githubusercontent.com/astral-sh/uv/df45b9ac2584824309ff29a6a09421055ad730f6/scripts/uv-run-remote-script-test.py").arg(EnvVars::CI), @"
+    uv_snapshot!(context.filters(), context.run().arg("--yes").arg("https://raw.githubusercontent.com/astral-sh/uv/df45b9ac2584824309ff29a6a09421055ad730f6/scripts/uv-run-remote-script-test.py").arg(EnvVars::CI), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -4919,6 +5083,80 @@ fn run_remote_pep723_script() {
     ");
 }
 
+/// Remote scripts require confirmation, unless `--yes` is provided or the contents are pinned by
+/// hash.
+#[tokio::test]
+async fn run_remote_script_confirmation() {
+    let context = uv_test::test_context!("3.12");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/script.py"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("print(\"Hello, world!\")\n"))
+        .mount(&server)
+        .await;
+    let url = format!("{}/script.py", server.uri());
+
+    // Without confirmation, the script should not be run.
+    uv_snapshot!(context.filters(), context.run().arg(&url), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Running a remote script requires confirmation; pass `--yes` or pin the script's contents with a `#sha256=<digest>` URL fragment
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--yes").arg(&url), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // A pinned hash doesn't require confirmation.
+    let pinned =
+        format!("{url}#sha256=51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673");
+    uv_snapshot!(context.filters(), context.run().arg(&pinned), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // Once cached, a pinned script can be run offline.
+    uv_snapshot!(context.filters(), context.run().arg("--offline").arg(&pinned), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // A mismatched hash should be rejected.
+    let mismatched =
+        format!("{url}#sha256=0000000000000000000000000000000000000000000000000000000000000000");
+    uv_snapshot!(context.filters(), context.run().arg(&mismatched), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash mismatch for remote script `http://[LOCALHOST]/script.py`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:51d2693342000ac090e8817796032592050e0f0b88d4d3a7ab1112058a169673
+    ");
+}
+
 #[test]
 fn run_remote_requirements_offline_redacts_credentials() -> Result<()> {
     let context = uv_test::test_context!("3.12");
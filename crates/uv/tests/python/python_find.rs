@@ -791,6 +791,68 @@ fn python_find_venv() {
     }
 }
 
+#[test]
+#[cfg(not(windows))]
+fn python_find_all() {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"])
+        .with_filtered_exe_suffix()
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin();
+
+    // Create a virtual environment
+    uv_snapshot!(context.filters(), context.venv().arg("--python").arg("3.12").arg("-q"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // All matching interpreters are shown, starting with the virtual environment
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12").arg("--all"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/[BIN]/[PYTHON]
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // Without a request, interpreters of any version are shown
+    uv_snapshot!(context.filters(), context.python_find().arg("--all"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/[BIN]/[PYTHON]
+    [PYTHON-3.11]
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // With `--system`, the virtual environment is skipped
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12").arg("--all").arg("--system"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // With `--show-version`, the version of each interpreter is shown
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12").arg("--all").arg("--show-version"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12.[X]
+    3.12.[X]
+
+    ----- stderr -----
+    ");
+}
+
 #[cfg(unix)]
 #[test]
 fn python_find_unsupported_version() {
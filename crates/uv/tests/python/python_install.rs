@@ -236,6 +236,69 @@ fn python_reinstall_patch() {
     ");
 }
 
+/// With `--output-format json`, progress events are written to stdout as newline-delimited JSON,
+/// followed by a summary of the changes.
+#[test]
+fn python_install_json() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_managed_python_dirs()
+        .with_empty_python_install_mirror();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r#""size":\d+"#, r#""size":[SIZE]"#)])
+        .collect::<Vec<_>>();
+
+    // Use an empty download cache, such that both the download and the extraction are reported.
+    let python_cache = context.temp_dir.child("python-cache");
+
+    uv_snapshot!(filters, context
+        .python_install()
+        .arg("3.12")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--preview-features")
+        .arg("json-output")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"event":"start","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","size":[SIZE]}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":10}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":20}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":30}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":40}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":50}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":60}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":70}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":80}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":90}
+    {"event":"progress","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":100}
+    {"event":"complete","phase":"download","key":"cpython-3.12.[LATEST]-[PLATFORM]"}
+    {"event":"start","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","size":[SIZE]}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":10}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":20}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":30}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":40}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":50}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":60}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":70}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":80}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":90}
+    {"event":"progress","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]","percent":100}
+    {"event":"complete","phase":"extract","key":"cpython-3.12.[LATEST]-[PLATFORM]"}
+    {"event":"done","changes":[{"key":"cpython-3.12.[LATEST]-[PLATFORM]","action":"installed"}]}
+
+    ----- stderr -----
+    Installed Python 3.12.[LATEST] in [TIME]
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    "#);
+}
+
 #[test]
 fn python_install_automatic() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -915,6 +978,45 @@ fn python_install_multiple_unmanaged_executables() {
     }
 }
 
+/// When one of the default executables is not managed by uv, none of them are installed.
+#[test]
+fn python_install_default_unmanaged_executable_rollback() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    // Create an unmanaged `python` executable.
+    let python = context
+        .bin_dir
+        .child(format!("python{}", std::env::consts::EXE_SUFFIX));
+    python.touch().unwrap();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--default").arg("3.13"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.[LATEST] in [TIME]
+     + cpython-3.13.[LATEST]-[PLATFORM]
+    warning: Failed to install executable for cpython-3.13.[LATEST]-[PLATFORM]
+      Caused by: Executable already exists at `[BIN]/python` but is not managed by uv; use `--force` to replace it
+    ");
+
+    // The other executables are rolled back, rather than pointing at a different installation
+    // than `python`.
+    for name in ["python3", "python3.13"] {
+        context
+            .bin_dir
+            .child(format!("{name}{}", std::env::consts::EXE_SUFFIX))
+            .assert(predicate::path::missing());
+    }
+    assert_eq!(fs_err::read_to_string(python.path()).unwrap(), "");
+}
+
 #[test]
 fn python_install_preview_no_bin() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -2505,6 +2607,77 @@ fn python_install_default_prerelease() {
     bin_python_default.assert(predicate::path::exists());
 }
 
+/// `uv python uninstall --default-shims` restores the executables replaced by `--default`.
+#[test]
+#[cfg(unix)]
+fn python_install_default_shims() -> anyhow::Result<()> {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_latest_python_versions()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    // Create an existing `python` executable that is not managed by uv.
+    let bin_python_default = context.bin_dir.child("python");
+    bin_python_default.write_str("#!/bin/sh\necho system\n")?;
+    let bin_python_major = context.bin_dir.child("python3");
+    let bin_python_minor_14 = context.bin_dir.child("python3.14");
+
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--default")
+        .arg("--force")
+        .arg("--preview-features")
+        .arg("python-install-default")
+        .arg("3.14"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.14.[LATEST] in [TIME]
+     + cpython-3.14.[LATEST]-[PLATFORM] (python, python3, python3.14)
+    ");
+
+    uv_snapshot!(context.filters(), context.command().arg("python").arg("shims").arg("list"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python -> [TEMP_DIR]/managed/cpython-3.14.[LATEST]-[PLATFORM]/bin/python3.14
+    python3 -> [TEMP_DIR]/managed/cpython-3.14.[LATEST]-[PLATFORM]/bin/python3.14
+    python3.14 -> [TEMP_DIR]/managed/cpython-3.14.[LATEST]-[PLATFORM]/bin/python3.14
+
+    ----- stderr -----
+    ");
+
+    // Restoring the default shims should restore `python` and remove `python3`, but leave the
+    // minor version executable in place.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--default-shims"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+     - [BIN]/python3
+     ~ [BIN]/python (restored from backup)
+    ");
+
+    bin_python_default.assert("#!/bin/sh\necho system\n");
+    bin_python_major.assert(predicate::path::missing());
+    bin_python_minor_14.assert(predicate::path::exists());
+
+    // There is nothing left to restore.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--default-shims"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No default shims to restore
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_install_default_from_env() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -2843,6 +3016,29 @@ fn python_install_cached() {
     ----- stdout -----
 
     ----- stderr -----
+    Failed to install Python 3.12.[LATEST] in [TIME]
+     × cpython-3.12.[LATEST]-[PLATFORM] (An offline Python installation was requested, but cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz) is missing in python-cache)
+    error: Failed to install cpython-3.12.[LATEST]-[PLATFORM]
+      Caused by: An offline Python installation was requested, but cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz) is missing in python-cache
+    ");
+
+    // A failure to install one version doesn't prevent the others from being installed, and is
+    // included in the summary
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("3.14")
+        .arg("3.12")
+        .arg("--reinstall")
+        .arg("--offline")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.14.[LATEST], 1 failed in [TIME]
+     × cpython-3.12.[LATEST]-[PLATFORM] (An offline Python installation was requested, but cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz) is missing in python-cache)
+     ~ cpython-3.14.[LATEST]-[PLATFORM] (python3.14)
     error: Failed to install cpython-3.12.[LATEST]-[PLATFORM]
       Caused by: An offline Python installation was requested, but cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz) is missing in python-cache
     ");
@@ -2954,6 +3150,8 @@ fn python_install_no_cache() {
     ----- stdout -----
 
     ----- stderr -----
+    Failed to install Python 3.12.[LATEST] in [TIME]
+     × cpython-3.12.[LATEST]-[PLATFORM] (Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://github.com/astral-sh/python-build-standalone/releases/download/[DATE]/cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz`)
     error: Failed to install cpython-3.12.[LATEST]-[PLATFORM]
       Caused by: Failed to download https://github.com/astral-sh/python-build-standalone/releases/download/[DATE]/cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz
       Caused by: Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://github.com/astral-sh/python-build-standalone/releases/download/[DATE]/cpython-3.12.[PATCH]-[DATE]-[PLATFORM].tar.gz`
@@ -3904,6 +4102,64 @@ fn uninstall_highest_patch_updates_minor_version_link() {
     );
 }
 
+/// Uninstall all managed Python versions older than a given version.
+#[test]
+fn uninstall_older_than() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache()
+        .with_filtered_latest_python_versions();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.10").arg("3.11").arg("3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 3 versions in [TIME]
+     + cpython-3.10.[LATEST]-[PLATFORM] (python3.10)
+     + cpython-3.11.[LATEST]-[PLATFORM] (python3.11)
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    ");
+
+    // Only versions below the bound are removed.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--older-than").arg("3.11"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions older than: 3.11
+    Uninstalled Python 3.10.[LATEST] in [TIME]
+     - cpython-3.10.[LATEST]-[PLATFORM] (python3.10)
+    ");
+
+    // The remaining installations are left untouched.
+    let installed = fs_err::read_dir(context.temp_dir.child("managed"))
+        .unwrap()
+        .filter_map(|entry| entry.unwrap().file_name().into_string().ok())
+        .collect::<Vec<_>>();
+    let is_installed = |minor: &str| {
+        let prefix = format!("cpython-{minor}.");
+        installed.iter().any(|name| name.starts_with(&prefix))
+    };
+    assert!(!is_installed("3.10"));
+    assert!(is_installed("3.11"));
+    assert!(is_installed("3.12"));
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--older-than").arg("3.11"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python versions older than: 3.11
+    No Python installations found older than: 3.11
+    ");
+}
+
 #[cfg(unix)] // Pyodide cannot be used on Windows
 #[test]
 fn python_install_pyodide() {
@@ -4753,3 +5009,59 @@ fn python_install_compile_bytecode_pypy() {
     Bytecode compiled [COUNT] files in [TIME]
     ");
 }
+
+#[test]
+fn python_install_skip_if_present() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_filtered_latest_python_versions()
+        .with_managed_python_dirs()
+        .with_empty_python_install_mirror()
+        .with_python_download_cache();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.[LATEST] in [TIME]
+     + cpython-3.12.[LATEST]-[PLATFORM] (python3.12)
+    ");
+
+    // Point the download list at a file that doesn't exist, such that any attempt to fetch the
+    // available downloads would fail.
+    let downloads_json = context.temp_dir.child("missing.json");
+
+    // With `--skip-if-present`, the installed version is detected without fetching the list
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--skip-if-present")
+        .arg("--python-downloads-json-url")
+        .arg(downloads_json.path())
+        .arg("3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Python 3.12 is already installed
+    ");
+
+    // The flag can't be combined with `--reinstall`
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("--skip-if-present")
+        .arg("--reinstall")
+        .arg("3.12"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--skip-if-present' cannot be used with '--reinstall'
+
+    Usage: uv python install --cache-dir [CACHE_DIR] --skip-if-present --install-dir <INSTALL_DIR> [TARGETS]...
+
+    For more information, try '--help'.
+    ");
+}
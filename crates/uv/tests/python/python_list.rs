@@ -131,6 +131,47 @@ fn python_list() {
     ");
 }
 
+#[test]
+fn python_list_sort() {
+    let context = uv_test::test_context_with_versions!(&["3.11", "3.12"])
+        .with_filtered_python_symlinks()
+        .with_filtered_python_keys()
+        .with_collapsed_whitespace();
+
+    // By version, the newest version is shown first
+    uv_snapshot!(context.filters(), context.python_list().arg("--sort").arg("version"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.[X]-[PLATFORM] [PYTHON-3.12]
+    cpython-3.11.[X]-[PLATFORM] [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+
+    // With `--reverse`, the oldest version is shown first
+    uv_snapshot!(context.filters(), context.python_list().arg("--sort").arg("version").arg("--reverse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.11.[X]-[PLATFORM] [PYTHON-3.11]
+    cpython-3.12.[X]-[PLATFORM] [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // `--reverse` also applies to the default order
+    uv_snapshot!(context.filters(), context.python_list().arg("--reverse"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.11.[X]-[PLATFORM] [PYTHON-3.11]
+    cpython-3.12.[X]-[PLATFORM] [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+}
+
 #[cfg(unix)]
 #[test]
 fn python_list_ignores_noncritical_explicit_path_errors() -> Result<()> {
@@ -485,9 +526,6 @@ fn python_list_downloads_installed() {
     ----- stderr -----
     ");
 
-    // TODO(zanieb): It'd be nice to test `--show-urls` here too but we need special filtering for
-    // the URL
-
     // But not if `--only-installed` is used
     uv_snapshot!(context.filters(), context.python_list().arg("3.10").arg("--only-installed").env_remove(EnvVars::UV_PYTHON_DOWNLOADS), @"
     success: true
@@ -546,6 +584,61 @@ fn python_list_downloads_installed() {
     ");
 }
 
+/// `--show-urls` should replace `<download available>` with the download URL, but leave installed
+/// interpreters displayed by path.
+#[test]
+#[cfg(feature = "test-python-managed")]
+fn python_list_show_urls_installed() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names()
+        .with_managed_python_dirs()
+        .with_collapsed_whitespace()
+        .with_filtered_latest_python_versions()
+        .with_filter((
+            r"(https://releases\.astral\.sh/github/python-build-standalone/releases/download/).*"
+                .to_string(),
+            "$1[FILE-PATH]".to_string(),
+        ))
+        .with_filter((
+            r"(https://downloads\.python\.org/pypy/).*".to_string(),
+            "$1[FILE-PATH]".to_string(),
+        ))
+        .with_filter((
+            r"(https://github\.com/oracle/graalpython/releases/download/).*".to_string(),
+            "$1[FILE-PATH]".to_string(),
+        ));
+
+    // Before installation, every entry is a download and shows its URL.
+    uv_snapshot!(context.filters(), context.python_list().arg("3.10").arg("--show-urls").env_remove(EnvVars::UV_PYTHON_DOWNLOADS), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.10.[LATEST]-[PLATFORM] https://releases.astral.sh/github/python-build-standalone/releases/download/[FILE-PATH]
+    pypy-3.10.16-[PLATFORM] https://downloads.python.org/pypy/[FILE-PATH]
+    graalpy-3.10.0-[PLATFORM] https://github.com/oracle/graalpython/releases/download/[FILE-PATH]
+
+    ----- stderr -----
+    ");
+
+    context.python_install().arg("3.10").assert().success();
+
+    // The installed interpreter is shown by path, while the remaining downloads show their URLs.
+    uv_snapshot!(context.filters(), context.python_list().arg("3.10").arg("--show-urls").env_remove(EnvVars::UV_PYTHON_DOWNLOADS), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.10.[LATEST]-[PLATFORM] managed/cpython-3.10-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
+    pypy-3.10.16-[PLATFORM] https://downloads.python.org/pypy/[FILE-PATH]
+    graalpy-3.10.0-[PLATFORM] https://github.com/oracle/graalpython/releases/download/[FILE-PATH]
+
+    ----- stderr -----
+    ");
+}
+
 /// Test that symlinks installed by `python install` on the search path are correctly
 /// filtered by `--managed-python` and `--no-managed-python`.
 #[test]
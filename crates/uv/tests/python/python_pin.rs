@@ -807,6 +807,105 @@ fn python_pin_resolve() {
     });
 }
 
+/// Implementation-qualified requests are written in their canonical `<implementation>@<version>`
+/// form and are respected when the pin is read back.
+#[test]
+fn python_pin_implementation() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]).with_filtered_python_sources();
+
+    uv_snapshot!(context.filters(), context.python_pin().arg("cpython@3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `cpython@3.12`
+
+    ----- stderr -----
+    ");
+
+    let python_version = context.read(PYTHON_VERSION_FILENAME);
+    assert_snapshot!(python_version, @"cpython@3.12");
+
+    uv_snapshot!(context.filters(), context.python_find(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // The short form is normalized
+    // (skip on Windows because the snapshot is different and the behavior is not platform dependent)
+    #[cfg(unix)]
+    {
+        uv_snapshot!(context.filters(), context.python_pin().arg("pypy3.10"), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Updated `.python-version` from `cpython@3.12` -> `pypy@3.10`
+
+        ----- stderr -----
+        warning: No interpreter found for PyPy 3.10 in [PYTHON SOURCES]
+        ");
+
+        let python_version = context.read(PYTHON_VERSION_FILENAME);
+        assert_snapshot!(python_version, @"pypy@3.10");
+
+        // The CPython interpreter should not satisfy the PyPy pin
+        uv_snapshot!(context.filters(), context.python_find(), @"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for PyPy 3.10 in [PYTHON SOURCES]
+        ");
+
+        uv_snapshot!(context.filters(), context.python_pin().arg("graalpy@3.11"), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Updated `.python-version` from `pypy@3.10` -> `graalpy@3.11`
+
+        ----- stderr -----
+        warning: No interpreter found for GraalPy 3.11 in [PYTHON SOURCES]
+        ");
+
+        uv_snapshot!(context.filters(), context.python_pin(), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        graalpy@3.11
+
+        ----- stderr -----
+        ");
+
+        // A resolved pin requires an interpreter of the requested implementation
+        uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("pypy@3.10"), @"
+        success: false
+        exit_code: 2
+        ----- stdout -----
+
+        ----- stderr -----
+        error: No interpreter found for PyPy 3.10 in [PYTHON SOURCES]
+
+        hint: A managed Python download is available for PyPy 3.10, but Python downloads are set to 'never'
+        ");
+
+        let python_version = context.read(PYTHON_VERSION_FILENAME);
+        assert_snapshot!(python_version, @"graalpy@3.11");
+
+        uv_snapshot!(context.filters(), context.python_pin().arg("--resolved").arg("cpython@3.12"), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Updated `.python-version` from `graalpy@3.11` -> `[PYTHON-3.12]`
+
+        ----- stderr -----
+        ");
+    }
+}
+
 #[test]
 fn python_pin_with_comments() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&[]);
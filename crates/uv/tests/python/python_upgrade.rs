@@ -92,6 +92,42 @@ fn python_upgrade() {
     ");
 }
 
+/// Upgrade without verifying the upgraded interpreter.
+#[test]
+fn python_upgrade_no_verify() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_python_download_cache()
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_filtered_latest_python_versions();
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("3.10.17").arg("--no-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.17 in [TIME]
+     + cpython-3.10.17-[PLATFORM] (python3.10)
+    ");
+
+    // Upgrade patch version, skipping verification
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.10").arg("--no-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.[LATEST] in [TIME]
+     + cpython-3.10.[LATEST]-[PLATFORM] (python3.10)
+    ");
+
+    // The upgraded interpreter is usable
+    context.python_find().arg("3.10").assert().success();
+}
+
 #[test]
 fn python_upgrade_without_version() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -280,6 +280,122 @@ fn create_venv_project_environment() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_venv_sync() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["iniconfig"]
+
+            [project.optional-dependencies]
+            async = ["anyio"]
+            "#,
+    )?;
+
+    // Create the environment and sync the project in a single invocation.
+    uv_snapshot!(context.filters(), context.venv().arg("--sync"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    Resolved 5 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::is_file());
+
+    // Extras are respected.
+    uv_snapshot!(context.filters(), context.venv().arg("--sync").arg("--extra").arg("async").arg("--clear"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    Resolved 5 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "
+    );
+
+    // A path can't be provided, since the project environment is synced.
+    context.venv().arg("--sync").arg("foo").assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn create_venv_sync_rm_on_failure() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["iniconfig==0.0.0"]
+            "#,
+    )?;
+
+    // Without `--rm-on-failure`, the environment is retained.
+    context.venv().arg("--sync").assert().failure();
+    context.venv.assert(predicates::path::is_dir());
+
+    // With `--rm-on-failure`, the environment is removed.
+    uv_snapshot!(context.filters(), context.venv().arg("--sync").arg("--rm-on-failure").arg("--clear"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of iniconfig==0.0.0 and your project depends on iniconfig==0.0.0, we can conclude that your project's requirements are unsatisfiable.
+    "
+    );
+    context.venv.assert(predicates::path::missing());
+
+    // An existing environment that is retained with `--allow-existing` is never removed.
+    context.venv().assert().success();
+    context
+        .venv()
+        .arg("--sync")
+        .arg("--rm-on-failure")
+        .arg("--allow-existing")
+        .assert()
+        .failure();
+    context.venv.assert(predicates::path::is_dir());
+
+    // `--rm-on-failure` requires `--sync`.
+    context.venv().arg("--rm-on-failure").assert().code(2);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn create_venv_project_environment_lock() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&["3.12"]);
@@ -52,6 +52,8 @@ fn pip_compile_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            min_tls_version: None,
+            tls_info: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -95,6 +97,11 @@ fn pip_compile_baseline() {
         required_environments: SupportedEnvironments(
             [],
         ),
+        platform_markers: PlatformMarkerOverrides {
+            platform_release: None,
+            platform_version: None,
+        },
+        split_extras: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -154,6 +161,9 @@ fn pip_compile_baseline() {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_build: None,
+                sandbox: Disabled,
+                sandbox_exempt: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -161,6 +171,7 @@ fn pip_compile_baseline() {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            prefetch_limit: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -239,6 +250,8 @@ fn pip_install_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            min_tls_version: None,
+            tls_info: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -269,17 +282,28 @@ fn pip_install_baseline() {
             "requirements.in",
         ],
         editables: [],
+        from_lock: None,
         editable: None,
         constraints: [],
+        constraints_scope: Runtime,
         overrides: [],
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        compile_package: [],
+        resolution_explain: [],
+        hash_coverage_report: false,
+        require_hashes_package: [],
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        platform_markers: PlatformMarkerOverrides {
+            platform_release: None,
+            platform_version: None,
+        },
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -288,6 +312,19 @@ fn pip_install_baseline() {
                 },
             ),
         ),
+        index_cache_ttl: None,
+        no_index_cache_credentials: false,
+        index_credentials: None,
+        check_index: false,
+        fail_fast: false,
+        check_licenses: false,
+        license_policy: None,
+        require_attestations: false,
+        dependency_metadata_file: None,
+        resolution_cache: None,
+        reinstall_broken: false,
+        build_backend_timeout: None,
+        clean_build_deps: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -339,6 +376,9 @@ fn pip_install_baseline() {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_build: None,
+                sandbox: Disabled,
+                sandbox_exempt: [],
             },
             allow_empty_requirements: false,
             strict: false,
@@ -346,6 +386,7 @@ fn pip_install_baseline() {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            prefetch_limit: None,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -422,6 +463,8 @@ fn lock_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            min_tls_version: None,
+            tls_info: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -450,6 +493,8 @@ fn lock_baseline() {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        show_forks: false,
+        refresh_url_hashes: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -469,6 +514,9 @@ fn lock_baseline() {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_build: None,
+                sandbox: Disabled,
+                sandbox_exempt: [],
             },
             config_setting: ConfigSettings(
                 {},
@@ -502,6 +550,8 @@ fn lock_baseline() {
                 {},
             ),
             prerelease: IfNecessaryOrExplicit,
+            prefetch_limit: None,
+            resolution_cache: true,
             resolution: Highest,
             sources: None,
             torch_backend: None,
@@ -544,6 +594,8 @@ fn version_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            min_tls_version: None,
+            tls_info: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -598,6 +650,9 @@ fn version_baseline() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_build: None,
+                    sandbox: Disabled,
+                    sandbox_exempt: [],
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -631,6 +686,8 @@ fn version_baseline() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prefetch_limit: None,
+                resolution_cache: true,
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
@@ -681,6 +738,8 @@ fn tool_install_baseline() {
             https_proxy: None,
             no_proxy: None,
             allow_insecure_host: [],
+            min_tls_version: None,
+            tls_info: false,
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
@@ -727,6 +786,40 @@ fn tool_install_baseline() {
                 },
             ),
         ),
+        args: ResolverInstallerOptions {
+            index: None,
+            index_url: None,
+            extra_index_url: None,
+            no_index: None,
+            find_links: None,
+            index_strategy: None,
+            keyring_provider: None,
+            resolution: None,
+            prerelease: None,
+            fork_strategy: None,
+            prefetch_limit: None,
+            dependency_metadata: None,
+            config_settings: None,
+            config_settings_package: None,
+            build_isolation: None,
+            extra_build_dependencies: None,
+            extra_build_variables: None,
+            exclude_newer: None,
+            exclude_newer_package: None,
+            link_mode: Some(
+                Clone,
+            ),
+            torch_backend: None,
+            compile_bytecode: None,
+            no_sources: None,
+            no_sources_package: None,
+            upgrade: None,
+            reinstall: None,
+            no_build: None,
+            no_build_package: None,
+            no_binary: None,
+            no_binary_package: None,
+        },
         options: ResolverInstallerOptions {
             index: None,
             index_url: None,
@@ -738,6 +831,7 @@ fn tool_install_baseline() {
             resolution: None,
             prerelease: None,
             fork_strategy: None,
+            prefetch_limit: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
@@ -765,6 +859,9 @@ fn tool_install_baseline() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_build: None,
+                    sandbox: Disabled,
+                    sandbox_exempt: [],
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -798,6 +895,8 @@ fn tool_install_baseline() {
                     {},
                 ),
                 prerelease: IfNecessaryOrExplicit,
+                prefetch_limit: None,
+                resolution_cache: true,
                 resolution: Highest,
                 sources: None,
                 torch_backend: None,
@@ -898,6 +997,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -911,6 +1013,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
@@ -939,6 +1042,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     +        resolution: Highest,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -1051,6 +1155,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1064,6 +1171,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
@@ -1207,6 +1315,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1242,6 +1353,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1297,6 +1411,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1385,6 +1502,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
     +            no_index: true,
@@ -1439,6 +1559,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -1506,6 +1627,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1541,6 +1665,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1564,6 +1691,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -1613,6 +1741,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -1719,6 +1848,7 @@ fn resolve_system_configuration_can_be_disabled() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     ");
@@ -1785,11 +1915,13 @@ fn resolve_tool() -> anyhow::Result<()> {
     +        ),
              prerelease: None,
              fork_strategy: None,
+             prefetch_limit: None,
              dependency_metadata: None,
     ...
                      {},
                  ),
                  prerelease: IfNecessaryOrExplicit,
+                 prefetch_limit: None,
     -            resolution: Highest,
     +            resolution: LowestDirect,
                  sources: None,
@@ -1853,6 +1985,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -1953,6 +2086,9 @@ fn resolve_both() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1966,6 +2102,7 @@ fn resolve_both() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
@@ -2084,6 +2221,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2097,6 +2237,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
@@ -2438,6 +2579,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2451,6 +2595,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
              no_annotate: false,
@@ -2493,7 +2638,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `min-tls-version`, `resolution`, `prerelease`, `fork-strategy`, `prefetch-limit`, `resolution-cache`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `sync-exact`, `audit`, `pip`, `cache-keys`, `allow-build`, `build-sandbox`, `build-sandbox-exempt`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `license-policy`, `lock-provenance`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -2586,6 +2731,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
     +        resolution: LowestDirect,
              prerelease: IfNecessaryOrExplicit,
              fork_strategy: RequiresPython,
+             prefetch_limit: None,
              dependency_metadata: DependencyMetadata(
     ...
     "
@@ -2736,6 +2882,9 @@ fn index_priority() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +                Index {
     +                    name: None,
@@ -2773,6 +2922,9 @@ fn index_priority() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    only_packages: None,
+    +                    pinned_only: false,
+    +                    min_tls_version: None,
     +                },
     +            ],
                  flat_index: [],
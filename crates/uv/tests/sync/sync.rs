@@ -318,6 +318,231 @@ fn frozen() -> Result<()> {
     Ok(())
 }
 
+/// With `tool.uv.lock-provenance`, syncing verifies that each locked registry package is still
+/// served by a configured index, even with `--frozen`.
+#[test]
+fn frozen_lock_provenance() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        lock-provenance = true
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Replace PyPI with a different default index.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        lock-provenance = true
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        default = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `iniconfig==2.0.0` was locked from `https://pypi.org/simple`, which is not a configured index (required by `tool.uv.lock-provenance`)
+    ");
+
+    Ok(())
+}
+
+/// With `tool.uv.lock-provenance`, syncing verifies that each locked registry package would still
+/// be resolved from the locked index, even if the locked index is still configured.
+#[test]
+fn frozen_lock_provenance_index_changed() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        context
+            .temp_dir
+            .join("links/tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv]
+        lock-provenance = true
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // The locked versions are still resolved from PyPI.
+    context.sync().arg("--frozen").assert().success();
+
+    // Add an index that takes precedence over PyPI.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv]
+        lock-provenance = true
+
+        [[tool.uv.index]]
+        name = "local"
+        format = "flat"
+        url = "./links"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `tqdm==4.66.2` was locked from `https://pypi.org/simple`, but would now be resolved from `file://[TEMP_DIR]/links` (required by `tool.uv.lock-provenance`)
+    ");
+
+    Ok(())
+}
+
+/// If updating the lockfile would resolve a locked package version from a different index, the
+/// sync should fail unless `--allow-index-fallback` is passed.
+#[test]
+fn sync_index_fallback() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let first = uv_test::packse::PackseServer::new("simple/single-package.toml");
+    let second = uv_test::packse::PackseServer::new("simple/single-package.toml");
+
+    let filters = [
+        (
+            regex::escape(&first.index_url()),
+            "[FIRST_INDEX]".to_string(),
+        ),
+        (
+            regex::escape(&second.index_url()),
+            "[SECOND_INDEX]".to_string(),
+        ),
+    ]
+    .into_iter()
+    .chain(context.filters())
+    .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+
+        [[tool.uv.index]]
+        name = "first"
+        url = "{first}"
+
+        [[tool.uv.index]]
+        name = "second"
+        url = "{second}"
+        "#,
+        first = first.index_url(),
+        second = second.index_url(),
+    })?;
+
+    uv_snapshot!(filters, context.sync().env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + a==2.0.0
+    ");
+
+    // Prefer the second index, such that `a==2.0.0` would now be resolved from it.
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+
+        [[tool.uv.index]]
+        name = "second"
+        url = "{second}"
+
+        [[tool.uv.index]]
+        name = "first"
+        url = "{first}"
+        "#,
+        first = first.index_url(),
+        second = second.index_url(),
+    })?;
+
+    uv_snapshot!(filters, context.sync().arg("--upgrade").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `a==2.0.0` was locked from `[FIRST_INDEX]`, but would now be resolved from `[SECOND_INDEX]` (pass `--allow-index-fallback` to allow resolving from a different index)
+    ");
+
+    // The lockfile should be unchanged.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(first.index_url().trim_end_matches('/')));
+    assert!(!lock.contains(second.index_url().trim_end_matches('/')));
+
+    uv_snapshot!(filters, context.sync().arg("--upgrade").arg("--allow-index-fallback").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(second.index_url().trim_end_matches('/')));
+
+    Ok(())
+}
+
 #[test]
 fn empty() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -986,6 +1211,79 @@ fn mixed_requires_python() -> Result<()> {
     Ok(())
 }
 
+/// Sync each workspace member on its own interpreter when the members declare disjoint
+/// Python requirements.
+#[test]
+fn disjoint_requires_python_members() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.9", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#,
+    )?;
+
+    // Create a member with a minimum Python requirement of Python 3.12.
+    let app = context.temp_dir.child("packages").child("app");
+    app.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "app"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    // Create a member pinned to Python 3.9.
+    let deploy = context.temp_dir.child("packages").child("deploy");
+    deploy.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "deploy"
+        version = "0.1.0"
+        requires-python = "==3.9.*"
+        dependencies = ["sniffio"]
+        "#,
+    )?;
+
+    // Syncing the workspace on Python 3.12 should skip the incompatible member.
+    uv_snapshot!(context.filters(), context.sync().arg("-p").arg("3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Skipping workspace member `deploy`, which requires Python `==3.9.*` (found 3.12.[X])
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Syncing the pinned member should select a compatible interpreter.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("deploy"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.9.[X] interpreter at: [PYTHON-3.9]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// Ensure that group requires-python solves an actual problem
 #[test]
 #[cfg(not(windows))]
@@ -1339,6 +1637,102 @@ fn check() -> Result<()> {
     Ok(())
 }
 
+/// With `sync-exact = false`, extraneous packages are retained and reported, unless they're
+/// removed explicitly via `--remove-extraneous`.
+#[test]
+fn sync_inexact_extraneous() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        sync-exact = false
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    // Replace the dependency; the packages that are no longer required should be retained.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        sync-exact = false
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Retained 3 extraneous packages not in the lockfile
+     ? anyio==3.7.0
+     ? idna==3.6
+     ? sniffio==1.3.1
+    ");
+
+    // Remove one of the extraneous packages, retaining the rest.
+    uv_snapshot!(context.filters(), context.sync().arg("--remove-extraneous").arg("idna"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+     - idna==3.6
+    Retained 2 extraneous packages not in the lockfile
+     ? anyio==3.7.0
+     ? sniffio==1.3.1
+    ");
+
+    // `--exact` takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.sync().arg("--exact"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+     - anyio==3.7.0
+     - sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// Sync development dependencies in a non-project workspace root.
 #[test]
 fn sync_non_project_dev_dependencies() -> Result<()> {
@@ -12016,6 +12410,111 @@ fn url_hash_mismatch() -> Result<()> {
     Ok(())
 }
 
+/// `uv lock --refresh-url-hashes` accepts a changed artifact for a direct URL dependency.
+#[test]
+fn url_hash_mismatch_refresh() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz" }
+        "#,
+    )?;
+
+    // Write a lockfile with an outdated hash.
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz" }
+        sdist = { hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b4" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz" }]
+    "#})?;
+
+    // Accept the current artifact.
+    uv_snapshot!(context.filters(), context.lock().arg("--refresh-url-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz" }
+        sdist = { hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz" }]
+        "#
+        );
+    });
+
+    // Running `uv sync` should now succeed.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz)
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn path_hash_mismatch() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -12635,7 +13134,7 @@ fn sync_locked_script() -> Result<()> {
     ----- stderr -----
     Using script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
     Resolved 4 packages in [TIME]
-    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    The lockfile at `script.py.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock --script script.py`.
     ");
 
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py"), @"
@@ -12738,7 +13237,7 @@ fn sync_locked_script() -> Result<()> {
     Updating script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
     warning: Resolving despite existing lockfile due to fork markers being disjoint with `requires-python`: `python_full_version >= '3.11'` vs `python_full_version >= '3.8' and python_full_version < '3.11'`
     Resolved 6 packages in [TIME]
-    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    The lockfile at `script.py.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock --script script.py`.
     ");
 
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py"), @"
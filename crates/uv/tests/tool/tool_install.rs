@@ -328,6 +328,87 @@ fn tool_install_from_directory_ignores_global_pin_outside_requires_python_range(
     ");
 }
 
+/// GUI scripts should be installed alongside console scripts, and propagate their exit code.
+#[test]
+fn tool_install_gui_scripts() {
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let foo_dir = context.temp_dir.child("foo");
+    foo_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        foo = "foo.main:run"
+
+        [project.gui-scripts]
+        foo-gui = "foo.main:run_gui"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+        })
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("__init__.py")
+        .touch()
+        .unwrap();
+    foo_dir
+        .child("src")
+        .child("foo")
+        .child("main.py")
+        .write_str(indoc! {r#"
+        import sys
+
+        def run():
+            print("console")
+
+        def run_gui():
+            sys.exit(3)
+        "#
+        })
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg(foo_dir.as_os_str())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+    Installed 2 executables: foo, foo-gui
+    ");
+
+    // The exit code of the GUI script should be propagated through the executable.
+    uv_snapshot!(context.filters(), Command::new("foo-gui")
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn tool_install_from_directory_uses_global_pin_within_requires_python_range() {
     let context = uv_test::test_context_with_versions!(&["3.13", "3.12", "3.11"])
@@ -6728,3 +6809,93 @@ fn tool_install_lock_revalidates_changed_constraints() -> Result<()> {
 
     Ok(())
 }
+
+/// Reinstalling a tool should re-apply the options stored in its receipt, with any options passed
+/// on the command line taking precedence.
+#[test]
+fn tool_install_reinstall_preserves_options() {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` with `--prerelease allow`.
+    context
+        .tool_install()
+        .arg("black==24.1.1")
+        .arg("--prerelease")
+        .arg("allow")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Reinstall without `--prerelease`; the stored option should be retained.
+    context
+        .tool_install()
+        .arg("black==24.1.1")
+        .arg("--reinstall")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r#"
+        [tool]
+        requirements = [{ name = "black", specifier = "==24.1.1" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black", from = "black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd", from = "black" },
+        ]
+
+        [tool.options]
+        prerelease = "allow"
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .arg("--show-options")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.1.1 [options: prerelease = "allow", exclude-newer = "2024-03-25T00:00:00Z"]
+    - black
+    - blackd
+
+    ----- stderr -----
+    "#);
+
+    // Reinstall with `--prerelease disallow`; the command-line option should be persisted.
+    context
+        .tool_install()
+        .arg("black==24.1.1")
+        .arg("--reinstall")
+        .arg("--prerelease")
+        .arg("disallow")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .arg("--show-options")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.1.1 [options: prerelease = "disallow", exclude-newer = "2024-03-25T00:00:00Z"]
+    - black
+    - blackd
+
+    ----- stderr -----
+    "#);
+}
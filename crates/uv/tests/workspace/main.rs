@@ -2,6 +2,8 @@
 
 mod workspace;
 
+mod workspace_check;
+
 mod workspace_dir;
 
 mod workspace_list;
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild, PathCreateDir};
+use indoc::indoc;
+
+use uv_test::{copy_dir_ignore, uv_snapshot};
+
+/// A valid workspace passes the check.
+#[test]
+fn workspace_check_valid() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let workspace = context.temp_dir.child("workspace");
+
+    copy_dir_ignore(
+        context
+            .workspace_root
+            .join("test/workspaces/albatross-virtual-workspace"),
+        &workspace,
+    )?;
+
+    uv_snapshot!(context.filters(), context.workspace_check().current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 3 workspace members
+    "
+    );
+
+    Ok(())
+}
+
+/// Duplicate member names and unparseable members are reported together, by both
+/// `uv workspace check` and `uv lock`.
+#[test]
+fn workspace_check_invalid_members() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#})?;
+
+    let bar = context.temp_dir.child("packages").child("bar");
+    bar.create_dir_all()?;
+    bar.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "bar"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        "#})?;
+
+    let bar_copy = context.temp_dir.child("packages").child("bar-copy");
+    bar_copy.create_dir_all()?;
+    bar_copy.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "bar"
+        version = "0.2.0"
+        requires-python = ">=3.12"
+        "#})?;
+
+    let baz = context.temp_dir.child("packages").child("baz");
+    baz.create_dir_all()?;
+    baz.child("pyproject.toml").write_str(indoc! {r#"
+        [project
+        name = "baz"
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace_check(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Found 2 problems with workspace members:
+      - Two workspace members are both named `bar`: `[TEMP_DIR]/packages/bar` and `[TEMP_DIR]/packages/bar-copy`
+      - Failed to parse: `packages/baz/pyproject.toml`
+        TOML parse error at line 1, column 9
+          |
+        1 | [project
+          |         ^
+        invalid table header
+        expected `.`, `]`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Found 2 problems with workspace members:
+      - Two workspace members are both named `bar`: `[TEMP_DIR]/packages/bar` and `[TEMP_DIR]/packages/bar-copy`
+      - Failed to parse: `packages/baz/pyproject.toml`
+        TOML parse error at line 1, column 9
+          |
+        1 | [project
+          |         ^
+        invalid table header
+        expected `.`, `]`
+    "
+    );
+
+    Ok(())
+}
+
+/// Dependency cycles between workspace members are reported, by both `uv workspace check` and
+/// `uv lock`.
+#[test]
+fn workspace_check_cycle() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        "#})?;
+
+    let package_a = context.temp_dir.child("packages").child("package-a");
+    package_a.create_dir_all()?;
+    package_a.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "package-a"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["package-b"]
+
+        [tool.uv.sources]
+        package-b = { workspace = true }
+        "#})?;
+
+    let package_b = context.temp_dir.child("packages").child("package-b");
+    package_b.create_dir_all()?;
+    package_b.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "package-b"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["package-c"]
+
+        [tool.uv.sources]
+        package-c = { workspace = true }
+        "#})?;
+
+    let package_c = context.temp_dir.child("packages").child("package-c");
+    package_c.create_dir_all()?;
+    package_c.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "package-c"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        cycle = ["package-a", "package-c[other]"]
+
+        [tool.uv.sources]
+        package-a = { workspace = true }
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace_check(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 1 dependency cycle
+    Workspace members form a dependency cycle: `package-a` -> `package-b` -> `package-c` -> `package-a`
+    "
+    );
+
+    // Locking fails before resolving any dependencies.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Workspace members form a dependency cycle: `package-a` -> `package-b` -> `package-c` -> `package-a`
+    "
+    );
+
+    Ok(())
+}